@@ -132,6 +132,188 @@ pub fn keep_alive(env_value: &'static str) -> Arg {
         .value_parser(value_parser!(u64))
 }
 
+pub fn oidc_issuer() -> Arg {
+    Arg::new("oidc-issuer")
+        .long("oidc-issuer-url")
+        .value_name("OIDC_ISSUER_URL")
+        .env("OIDC_ISSUER_URL")
+        .help(
+            "OpenID Connect identity provider's issuer URL. Single sign-on is disabled unless set",
+        )
+        .num_args(1)
+        .requires_all(["oidc-client-id", "oidc-client-secret"])
+}
+
+pub fn oidc_client_id() -> Arg {
+    Arg::new("oidc-client-id")
+        .long("oidc-client-id")
+        .value_name("OIDC_CLIENT_ID")
+        .env("OIDC_CLIENT_ID")
+        .help("Client ID registered with the OpenID Connect identity provider")
+        .num_args(1)
+}
+
+pub fn oidc_client_secret() -> Arg {
+    Arg::new("oidc-client-secret")
+        .long("oidc-client-secret")
+        .value_name("OIDC_CLIENT_SECRET")
+        .env("OIDC_CLIENT_SECRET")
+        .help("Client secret registered with the OpenID Connect identity provider")
+        .num_args(1)
+}
+
+pub fn oidc_redirect_url() -> Arg {
+    Arg::new("oidc-redirect-url")
+        .long("oidc-redirect-url")
+        .value_name("OIDC_REDIRECT_URL")
+        .env("OIDC_REDIRECT_URL")
+        .help("Single sign-on callback URL. Defaults to <THOTH_GRAPHQL_API>/account/oidc/callback")
+        .num_args(1)
+}
+
+pub fn oidc_post_login_url() -> Arg {
+    Arg::new("oidc-post-login-url")
+        .long("oidc-post-login-url")
+        .value_name("OIDC_POST_LOGIN_URL")
+        .env("OIDC_POST_LOGIN_URL")
+        .default_value("http://localhost:8080/login")
+        .help("Thoth GUI's login page, where users are sent back after single sign-on")
+        .num_args(1)
+}
+
+pub fn oidc_provision() -> Arg {
+    Arg::new("oidc-provision")
+        .long("oidc-provision-accounts")
+        .env("OIDC_PROVISION_ACCOUNTS")
+        .help("Create an account for single sign-on users that do not have one")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn asset_storage() -> Arg {
+    Arg::new("asset-storage")
+        .long("asset-storage")
+        .value_name("ASSET_STORAGE")
+        .env("ASSET_STORAGE")
+        .help(
+            "Where to store uploaded covers and publication files. Uploads are disabled unless set",
+        )
+        .num_args(1)
+        .value_parser(["local", "s3"])
+}
+
+pub fn asset_storage_path() -> Arg {
+    Arg::new("asset-storage-path")
+        .long("asset-storage-path")
+        .value_name("ASSET_STORAGE_PATH")
+        .env("ASSET_STORAGE_PATH")
+        .default_value("assets")
+        .help("Directory in which uploaded files are stored, when using local storage")
+        .num_args(1)
+}
+
+pub fn asset_public_url() -> Arg {
+    Arg::new("asset-public-url")
+        .long("asset-public-url")
+        .value_name("ASSET_PUBLIC_URL")
+        .env("ASSET_PUBLIC_URL")
+        .help("Public URL of stored files. Defaults to <THOTH_GRAPHQL_API>/assets for local storage, and <S3_ENDPOINT>/<S3_BUCKET> for S3")
+        .num_args(1)
+}
+
+pub fn asset_max_size() -> Arg {
+    Arg::new("asset-max-size")
+        .long("asset-max-size")
+        .value_name("ASSET_MAX_SIZE")
+        .env("ASSET_MAX_SIZE")
+        .default_value("100")
+        .help("Maximum size of uploaded files, in megabytes")
+        .num_args(1)
+        .value_parser(value_parser!(usize))
+}
+
+pub fn image_converter() -> Arg {
+    Arg::new("image-converter")
+        .long("image-converter")
+        .value_name("IMAGE_CONVERTER")
+        .env("IMAGE_CONVERTER")
+        .help("ImageMagick command used to generate renditions of uploaded covers, e.g. magick. Renditions are not generated unless set")
+        .num_args(1)
+}
+
+pub fn s3_endpoint() -> Arg {
+    Arg::new("s3-endpoint")
+        .long("s3-endpoint")
+        .value_name("S3_ENDPOINT")
+        .env("S3_ENDPOINT")
+        .help("S3-compatible storage endpoint, e.g. http://localhost:9000")
+        .num_args(1)
+        .required_if_eq("asset-storage", "s3")
+}
+
+pub fn s3_bucket() -> Arg {
+    Arg::new("s3-bucket")
+        .long("s3-bucket")
+        .value_name("S3_BUCKET")
+        .env("S3_BUCKET")
+        .help("Bucket in which uploaded files are stored")
+        .num_args(1)
+        .required_if_eq("asset-storage", "s3")
+}
+
+pub fn s3_region() -> Arg {
+    Arg::new("s3-region")
+        .long("s3-region")
+        .value_name("S3_REGION")
+        .env("S3_REGION")
+        .default_value("us-east-1")
+        .help("Region of the S3 bucket")
+        .num_args(1)
+}
+
+pub fn s3_access_key_id() -> Arg {
+    Arg::new("s3-access-key-id")
+        .long("s3-access-key-id")
+        .value_name("S3_ACCESS_KEY_ID")
+        .env("S3_ACCESS_KEY_ID")
+        .help("Access key ID used to write to the S3 bucket")
+        .num_args(1)
+        .required_if_eq("asset-storage", "s3")
+}
+
+pub fn s3_secret_access_key() -> Arg {
+    Arg::new("s3-secret-access-key")
+        .long("s3-secret-access-key")
+        .value_name("S3_SECRET_ACCESS_KEY")
+        .env("S3_SECRET_ACCESS_KEY")
+        .help("Secret access key used to write to the S3 bucket")
+        .num_args(1)
+        .required_if_eq("asset-storage", "s3")
+}
+
+pub fn anonymous_persisted_queries_only() -> Arg {
+    Arg::new("anonymous-persisted-queries-only")
+        .long("anonymous-persisted-queries-only")
+        .env("ANONYMOUS_PERSISTED_QUERIES_ONLY")
+        .help("Only allow anonymous users to run queries registered as persisted queries")
+        .action(ArgAction::SetTrue)
+}
+
+pub fn query_file() -> Arg {
+    Arg::new("query-file")
+        .value_name("FILE")
+        .help("File containing the GraphQL query document to register")
+        .required(true)
+        .num_args(1)
+}
+
+pub fn mapping_file() -> Arg {
+    Arg::new("mapping-file")
+        .value_name("FILE")
+        .help("CSV file with the header ror,crossref_funder_id,isni,ringgold")
+        .required(true)
+        .num_args(1)
+}
+
 pub fn revert() -> Arg {
     Arg::new("revert")
         .long("revert")
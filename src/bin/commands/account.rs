@@ -9,7 +9,7 @@ use thoth::{
         account::{
             model::{Account, LinkedPublisher},
             service::{
-                all_emails, all_publishers, get_account, register as register_account,
+                all_emails, all_publishers, get_account, register as register_account, reset_totp,
                 update_password,
             },
         },
@@ -28,7 +28,10 @@ lazy_static! {
         .subcommand(
             Command::new("publishers").about("Select which publisher(s) this account can manage"),
         )
-        .subcommand(Command::new("password").about("Reset a password"));
+        .subcommand(Command::new("password").about("Reset a password"))
+        .subcommand(
+            Command::new("two-factor").about("Reset two-factor authentication for an account"),
+        );
 }
 
 pub fn register(arguments: &clap::ArgMatches) -> ThothResult<()> {
@@ -71,6 +74,13 @@ pub fn password(arguments: &clap::ArgMatches) -> ThothResult<()> {
     update_password(&email, &password, &pool).map(|_| ())
 }
 
+pub fn two_factor(arguments: &clap::ArgMatches) -> ThothResult<()> {
+    let pool = get_pg_pool(arguments);
+    let email = email_selection(&pool)?;
+
+    reset_totp(&email, &pool).map(|_| ())
+}
+
 fn email_selection(pool: &PgPool) -> ThothResult<String> {
     let all_emails = all_emails(pool).expect("No user accounts present in database.");
     let email_labels: Vec<String> = all_emails
@@ -0,0 +1,31 @@
+use super::get_pg_pool;
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use thoth::{
+    api::model::institution::{InstitutionIdentifierMapping, NewInstitutionIdentifierMapping},
+    errors::ThothResult,
+};
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("institution-mapping")
+        .about("Manage the mapping between institution identifiers")
+        .arg(arguments::database())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("import")
+                .about("Replace the mapping between ROR, Crossref Funder ID, ISNI and Ringgold identifiers")
+                .arg(arguments::mapping_file()),
+        );
+}
+
+pub fn import(arguments: &ArgMatches, import_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_pg_pool(arguments);
+    let path = import_arguments.get_one::<String>("mapping-file").unwrap();
+    let contents = std::fs::read_to_string(path)?;
+    let mappings = NewInstitutionIdentifierMapping::parse_mapping_file(&contents)?;
+    let imported = InstitutionIdentifierMapping::replace_all(&pool, &mappings)?;
+    println!("Imported {imported} institution identifier mappings");
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use super::get_pg_pool;
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use std::str::FromStr;
+use thoth::{
+    api::model::license::{unregistered_licenses, License},
+    errors::ThothResult,
+};
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("license")
+        .about("Manage work licences")
+        .arg(arguments::database())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("report")
+                .about("List works whose licence is not in the controlled vocabulary"),
+        );
+}
+
+pub fn report(arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_pg_pool(arguments);
+    let licenses = unregistered_licenses(&pool)?;
+    for (work_id, license) in &licenses {
+        let status = match License::from_str(license) {
+            Ok(_) => "custom open licence",
+            Err(_) => "invalid, must be corrected when the work is next edited",
+        };
+        println!("{work_id}\t{license}\t{status}");
+    }
+    println!("Found {} works with unregistered licences", licenses.len());
+    Ok(())
+}
@@ -14,6 +14,9 @@ use thoth::{
 
 pub(super) mod account;
 pub(super) mod cache;
+pub(super) mod institution_mapping;
+pub(super) mod license;
+pub(super) mod persisted_query;
 pub(super) mod start;
 
 lazy_static! {
@@ -27,7 +30,24 @@ lazy_static! {
         .arg(arguments::gql_url())
         .arg(arguments::domain())
         .arg(arguments::key())
-        .arg(arguments::session());
+        .arg(arguments::session())
+        .arg(arguments::oidc_issuer())
+        .arg(arguments::oidc_client_id())
+        .arg(arguments::oidc_client_secret())
+        .arg(arguments::oidc_redirect_url())
+        .arg(arguments::oidc_post_login_url())
+        .arg(arguments::oidc_provision())
+        .arg(arguments::asset_storage())
+        .arg(arguments::asset_storage_path())
+        .arg(arguments::asset_public_url())
+        .arg(arguments::asset_max_size())
+        .arg(arguments::image_converter())
+        .arg(arguments::s3_endpoint())
+        .arg(arguments::s3_bucket())
+        .arg(arguments::s3_region())
+        .arg(arguments::s3_access_key_id())
+        .arg(arguments::s3_secret_access_key())
+        .arg(arguments::anonymous_persisted_queries_only());
 }
 
 lazy_static! {
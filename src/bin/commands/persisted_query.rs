@@ -0,0 +1,28 @@
+use super::get_pg_pool;
+use crate::arguments;
+use clap::{ArgMatches, Command};
+use lazy_static::lazy_static;
+use thoth::{api::graphql::persisted_query::register_persisted_query, errors::ThothResult};
+
+lazy_static! {
+    pub(crate) static ref COMMAND: Command = Command::new("persisted-query")
+        .about("Manage persisted GraphQL queries")
+        .arg(arguments::database())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("register")
+                .about("Register a query document so that it can be run by its hash")
+                .arg(arguments::query_file()),
+        );
+}
+
+pub fn register(arguments: &ArgMatches, register_arguments: &ArgMatches) -> ThothResult<()> {
+    let pool = get_pg_pool(arguments);
+    let path = register_arguments.get_one::<String>("query-file").unwrap();
+    // The hash is computed over the exact contents, which clients must send unchanged
+    let query = std::fs::read_to_string(path)?;
+    let persisted_query = register_persisted_query(&query, &pool)?;
+    println!("{}", persisted_query.query_hash);
+    Ok(())
+}
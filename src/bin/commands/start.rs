@@ -1,7 +1,10 @@
 use crate::arguments;
 use clap::{ArgMatches, Command};
 use lazy_static::lazy_static;
-use thoth::{api_server, app_server, errors::ThothResult, export_server};
+use thoth::{
+    api_server, app_server, errors::ThothResult, export_server, AssetStorageConfig, OidcConfig,
+    S3Config, StorageBackend,
+};
 
 lazy_static! {
     pub(crate) static ref COMMAND: Command = Command::new("start")
@@ -19,7 +22,24 @@ lazy_static! {
                 .arg(arguments::gql_url())
                 .arg(arguments::domain())
                 .arg(arguments::key())
-                .arg(arguments::session()),
+                .arg(arguments::session())
+                .arg(arguments::oidc_issuer())
+                .arg(arguments::oidc_client_id())
+                .arg(arguments::oidc_client_secret())
+                .arg(arguments::oidc_redirect_url())
+                .arg(arguments::oidc_post_login_url())
+                .arg(arguments::oidc_provision())
+                .arg(arguments::asset_storage())
+                .arg(arguments::asset_storage_path())
+                .arg(arguments::asset_public_url())
+                .arg(arguments::asset_max_size())
+                .arg(arguments::image_converter())
+                .arg(arguments::s3_endpoint())
+                .arg(arguments::s3_bucket())
+                .arg(arguments::s3_region())
+                .arg(arguments::s3_access_key_id())
+                .arg(arguments::s3_secret_access_key())
+                .arg(arguments::anonymous_persisted_queries_only()),
         )
         .subcommand(
             Command::new("app")
@@ -52,6 +72,66 @@ pub fn graphql_api(arguments: &ArgMatches) -> ThothResult<()> {
     let domain = arguments.get_one::<String>("domain").unwrap().to_owned();
     let secret_str = arguments.get_one::<String>("key").unwrap().to_owned();
     let session_duration = *arguments.get_one::<i64>("duration").unwrap();
+    let oidc_config = arguments
+        .get_one::<String>("oidc-issuer")
+        .map(|issuer_url| OidcConfig {
+            issuer_url: issuer_url.to_owned(),
+            client_id: arguments
+                .get_one::<String>("oidc-client-id")
+                .unwrap()
+                .to_owned(),
+            client_secret: arguments
+                .get_one::<String>("oidc-client-secret")
+                .unwrap()
+                .to_owned(),
+            redirect_url: arguments
+                .get_one::<String>("oidc-redirect-url")
+                .map(|redirect_url| redirect_url.to_owned())
+                .unwrap_or_else(|| format!("{url}/account/oidc/callback")),
+            post_login_url: arguments
+                .get_one::<String>("oidc-post-login-url")
+                .unwrap()
+                .to_owned(),
+            provision_accounts: arguments.get_flag("oidc-provision"),
+        });
+    let asset_storage = arguments.get_one::<String>("asset-storage").map(|storage| {
+        let string_arg = |name: &str| arguments.get_one::<String>(name).unwrap().to_owned();
+        let (backend, default_public_url) = match storage.as_str() {
+            "s3" => {
+                let s3_config = S3Config {
+                    endpoint: string_arg("s3-endpoint"),
+                    bucket: string_arg("s3-bucket"),
+                    region: string_arg("s3-region"),
+                    access_key_id: string_arg("s3-access-key-id"),
+                    secret_access_key: string_arg("s3-secret-access-key"),
+                };
+                let public_url = format!(
+                    "{}/{}",
+                    s3_config.endpoint.trim_end_matches('/'),
+                    s3_config.bucket
+                );
+                (StorageBackend::S3(s3_config), public_url)
+            }
+            _ => (
+                StorageBackend::Local {
+                    path: string_arg("asset-storage-path").into(),
+                },
+                format!("{url}/assets"),
+            ),
+        };
+        AssetStorageConfig {
+            backend,
+            public_url: arguments
+                .get_one::<String>("asset-public-url")
+                .map(|public_url| public_url.to_owned())
+                .unwrap_or(default_public_url),
+            max_file_size: *arguments.get_one::<usize>("asset-max-size").unwrap() * 1024 * 1024,
+            image_converter: arguments
+                .get_one::<String>("image-converter")
+                .map(|command| command.to_owned()),
+        }
+    });
+    let anonymous_persisted_queries_only = arguments.get_flag("anonymous-persisted-queries-only");
     api_server(
         database_url,
         host,
@@ -62,6 +142,9 @@ pub fn graphql_api(arguments: &ArgMatches) -> ThothResult<()> {
         domain,
         secret_str,
         session_duration,
+        oidc_config,
+        asset_storage,
+        anonymous_persisted_queries_only,
     )
     .map_err(|e| e.into())
 }
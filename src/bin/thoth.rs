@@ -12,7 +12,10 @@ lazy_static::lazy_static! {
         .subcommand(commands::start::COMMAND.clone())
         .subcommand(commands::INIT.clone())
         .subcommand(commands::account::COMMAND.clone())
-        .subcommand(commands::cache::COMMAND.clone());
+        .subcommand(commands::cache::COMMAND.clone())
+        .subcommand(commands::persisted_query::COMMAND.clone())
+        .subcommand(commands::institution_mapping::COMMAND.clone())
+        .subcommand(commands::license::COMMAND.clone());
 }
 
 fn main() -> thoth::errors::ThothResult<()> {
@@ -35,12 +38,29 @@ fn main() -> thoth::errors::ThothResult<()> {
             Some(("register", _)) => commands::account::register(arguments),
             Some(("publishers", _)) => commands::account::publishers(arguments),
             Some(("password", _)) => commands::account::password(arguments),
+            Some(("two-factor", _)) => commands::account::two_factor(arguments),
             _ => unreachable!(),
         },
         Some(("cache", arguments)) => match arguments.subcommand() {
             Some(("delete", _)) => commands::cache::delete(arguments),
             _ => unreachable!(),
         },
+        Some(("persisted-query", arguments)) => match arguments.subcommand() {
+            Some(("register", register_arguments)) => {
+                commands::persisted_query::register(arguments, register_arguments)
+            }
+            _ => unreachable!(),
+        },
+        Some(("institution-mapping", arguments)) => match arguments.subcommand() {
+            Some(("import", import_arguments)) => {
+                commands::institution_mapping::import(arguments, import_arguments)
+            }
+            _ => unreachable!(),
+        },
+        Some(("license", arguments)) => match arguments.subcommand() {
+            Some(("report", _)) => commands::license::report(arguments),
+            _ => unreachable!(),
+        },
         _ => unreachable!(),
     }
 }
@@ -1,5 +1,7 @@
 pub use thoth_api as api;
-pub use thoth_api_server::start_server as api_server;
+pub use thoth_api_server::{
+    start_server as api_server, AssetStorageConfig, OidcConfig, S3Config, StorageBackend,
+};
 pub use thoth_app_server::start_server as app_server;
 pub use thoth_errors as errors;
 pub use thoth_export_server::{start_server as export_server, ALL_SPECIFICATIONS};
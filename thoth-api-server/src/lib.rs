@@ -1,34 +1,79 @@
 mod graphiql;
 mod logger;
+mod oidc;
+mod persisted_query;
+mod rate_limit;
+mod rendition;
+mod storage;
 
 use std::{io, sync::Arc, time::Duration};
 
 use actix_cors::Cors;
+use actix_files::Files;
 use actix_identity::{Identity, IdentityMiddleware};
-use actix_session::{config::PersistentSession, storage::CookieSessionStore, SessionMiddleware};
+use actix_multipart::Multipart;
+use actix_session::{
+    config::PersistentSession, storage::CookieSessionStore, Session, SessionMiddleware,
+};
 use actix_web::{
     cookie::{time::Duration as CookieDuration, Key},
+    dev::HttpServiceFactory,
     error, get,
-    http::header,
-    middleware::Compress,
+    http::header::{self, DispositionType},
+    middleware::{Compress, DefaultHeaders},
     post,
-    web::{Data, Json},
+    web::{self, Bytes, Data, Json, Path, Query},
     App, Error, HttpMessage, HttpRequest, HttpResponse, HttpServer, Result,
 };
-use serde::Serialize;
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
 use thoth_api::{
-    account::model::{AccountDetails, DecodedToken, LoginCredentials},
-    account::service::{get_account, get_account_details, login},
+    account::model::{
+        AccountDetails, DecodedToken, LoginCredentials, TotpCode, TwoFactorChallenge,
+        TwoFactorLogin,
+    },
+    account::service::{
+        confirm_totp_enrolment, disable_totp, get_account, get_account_details, login,
+        login_external, login_external_second_factor, regenerate_recovery_codes,
+        start_totp_enrolment,
+    },
     db::{init_pool, PgPool},
     graphql::{
+        limits::{CallerType, GraphqlLimits, QueryAnalyser},
         model::{create_schema, Context, Schema},
-        GraphQLRequest,
+        persisted_query::PersistedQueryRequest,
+    },
+    model::{
+        asset::{AssetType, NewAsset},
+        publication::Publication,
+        work::Work,
+        Crud,
     },
 };
-use thoth_errors::ThothError;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
 
 use crate::graphiql::graphiql_source;
 use crate::logger::{BodyLogger, Logger};
+pub use crate::oidc::OidcConfig;
+use crate::oidc::{AuthorizationRequest, OidcClient};
+use crate::persisted_query::PersistedQueries;
+use crate::rate_limit::{
+    caller_key, client_ip, graphql_error, rate_limit_key, too_many_requests,
+    with_rate_limit_headers, GraphqlRateLimiters, RateLimiter, TrustedProxies,
+    ACCOUNT_RATE_LIMIT_CAPACITY, ACCOUNT_RATE_LIMIT_PERIOD, RATE_LIMIT_LIMIT_HEADER,
+    RATE_LIMIT_REMAINING_HEADER,
+};
+use crate::rendition::CoverRenderer;
+use crate::storage::AssetStorage;
+pub use crate::storage::{AssetStorageConfig, S3Config, StorageBackend};
+
+/// Response header set when a login attempt must be repeated with a two-factor code
+const TWO_FACTOR_HEADER: &str = "X-Thoth-Two-Factor";
+/// Session key under which the pending single sign-on request is stored
+const OIDC_SESSION_KEY: &str = "oidc";
+/// Name of the multipart form field containing an uploaded file
+const UPLOAD_FIELD: &str = "file";
 
 #[derive(Serialize)]
 struct ApiConfig {
@@ -87,19 +132,67 @@ async fn graphql_schema(st: Data<Arc<Schema>>) -> HttpResponse {
     HttpResponse::Ok().body(st.as_sdl())
 }
 
+#[allow(clippy::too_many_arguments)]
 #[post("/graphql")]
 async fn graphql(
+    request: HttpRequest,
     st: Data<Arc<Schema>>,
     pool: Data<PgPool>,
     token: DecodedToken,
-    data: Json<GraphQLRequest>,
+    payload: Json<PersistedQueryRequest>,
+    analyser: Data<QueryAnalyser>,
+    limits: Data<GraphqlLimits>,
+    limiters: Data<GraphqlRateLimiters>,
+    persisted_queries: Data<PersistedQueries>,
 ) -> Result<HttpResponse, Error> {
+    let caller = CallerType::from(&token);
+    let limiter = limiters.for_caller(caller);
+    let remaining = match limiter.check(&caller_key(&request, &token)) {
+        Ok(remaining) => remaining,
+        Err(retry_after) => {
+            return Ok(graphql_error(
+                with_rate_limit_headers(
+                    &mut HttpResponse::TooManyRequests(),
+                    limiter.capacity(),
+                    0,
+                )
+                .insert_header((header::RETRY_AFTER, retry_after.to_string())),
+                format!("Too many requests. Please try again in {retry_after} seconds."),
+            ))
+        }
+    };
+    let data = match persisted_queries.resolve(payload.into_inner(), caller, &pool) {
+        Ok(data) => data,
+        Err(e) => {
+            // Clients following the automatic persisted query protocol expect a successful
+            // response when they have to send the full query
+            let mut response = match e {
+                ThothError::PersistedQueryNotFound => HttpResponse::Ok(),
+                _ => HttpResponse::BadRequest(),
+            };
+            return Ok(graphql_error(
+                with_rate_limit_headers(&mut response, limiter.capacity(), remaining),
+                e.to_string(),
+            ));
+        }
+    };
+    if let Err(e) = analyser.check(&data, limits.for_caller(caller)) {
+        return Ok(graphql_error(
+            with_rate_limit_headers(
+                &mut HttpResponse::BadRequest(),
+                limiter.capacity(),
+                remaining,
+            ),
+            e.to_string(),
+        ));
+    }
     let ctx = Context::new(pool.into_inner(), token);
     let result = data.execute(&st, &ctx).await;
-    match result.is_ok() {
-        true => Ok(HttpResponse::Ok().json(result)),
-        false => Ok(HttpResponse::BadRequest().json(result)),
-    }
+    let mut response = match result.is_ok() {
+        true => HttpResponse::Ok(),
+        false => HttpResponse::BadRequest(),
+    };
+    Ok(with_rate_limit_headers(&mut response, limiter.capacity(), remaining).json(result))
 }
 
 #[post("/account/login")]
@@ -107,10 +200,54 @@ async fn login_credentials(
     request: HttpRequest,
     payload: Json<LoginCredentials>,
     pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
 ) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let ip_address = client_ip(&request);
     let r = payload.into_inner();
 
-    login(&r.email, &r.password, &pool)
+    match login(
+        &r.email,
+        &r.password,
+        r.totp_code.as_deref(),
+        ip_address.as_deref(),
+        &pool,
+    ) {
+        Err(ThothError::TwoFactorRequired) => {
+            return Ok(HttpResponse::Unauthorized()
+                .insert_header((TWO_FACTOR_HEADER, "required"))
+                .json(ThothError::TwoFactorRequired.to_string()))
+        }
+        result => result,
+    }
+    .and_then(|account| {
+        account.issue_token(&pool)?;
+        let details = get_account_details(&account.email, &pool).unwrap();
+        let user_string = serde_json::to_string(&details)
+            .map_err(|_| ThothError::InternalError("Serder error".into()))?;
+        Identity::login(&request.extensions(), user_string)
+            .map_err(|_| ThothError::InternalError("Failed to store session cookie".into()))?;
+        Ok(HttpResponse::Ok().json(details))
+    })
+    .map_err(account_error)
+}
+
+#[post("/account/token/renew")]
+async fn login_session(
+    request: HttpRequest,
+    token: DecodedToken,
+    identity: Option<Identity>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account(&email, &pool)
         .and_then(|account| {
             account.issue_token(&pool)?;
             let details = get_account_details(&account.email, &pool).unwrap();
@@ -120,49 +257,377 @@ async fn login_credentials(
                 .map_err(|_| ThothError::InternalError("Failed to store session cookie".into()))?;
             Ok(HttpResponse::Ok().json(details))
         })
-        .map_err(error::ErrorUnauthorized)
+        .map_err(account_error)
 }
 
-#[post("/account/token/renew")]
-async fn login_session(
+#[get("/account")]
+async fn account_details(
     request: HttpRequest,
     token: DecodedToken,
     identity: Option<Identity>,
     pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
 ) -> Result<HttpResponse, Error> {
-    let email = match identity {
-        Some(session) => {
-            let id = session.id().map_err(|_| ThothError::Unauthorised)?;
-            let details: AccountDetails =
-                serde_json::from_str(&id).map_err(|_| ThothError::Unauthorised)?;
-            details.email
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account_details(&email, &pool)
+        .map(|account_details| HttpResponse::Ok().json(account_details))
+        .map_err(account_error)
+}
+
+#[post("/account/2fa/enrol")]
+async fn totp_enrol(
+    request: HttpRequest,
+    token: DecodedToken,
+    identity: Option<Identity>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account(&email, &pool)
+        .and_then(|account| start_totp_enrolment(&account, &pool))
+        .map(|enrolment| HttpResponse::Ok().json(enrolment))
+        .map_err(two_factor_error)
+}
+
+#[post("/account/2fa/confirm")]
+async fn totp_confirm(
+    request: HttpRequest,
+    token: DecodedToken,
+    identity: Option<Identity>,
+    payload: Json<TotpCode>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account(&email, &pool)
+        .and_then(|account| confirm_totp_enrolment(&account, &payload.code, &pool))
+        .map(|recovery_codes| HttpResponse::Ok().json(recovery_codes))
+        .map_err(two_factor_error)
+}
+
+#[post("/account/2fa/recovery-codes")]
+async fn totp_recovery_codes(
+    request: HttpRequest,
+    token: DecodedToken,
+    identity: Option<Identity>,
+    payload: Json<TotpCode>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account(&email, &pool)
+        .and_then(|account| regenerate_recovery_codes(&account, &payload.code, &pool))
+        .map(|recovery_codes| HttpResponse::Ok().json(recovery_codes))
+        .map_err(two_factor_error)
+}
+
+#[post("/account/2fa/disable")]
+async fn totp_disable(
+    request: HttpRequest,
+    token: DecodedToken,
+    identity: Option<Identity>,
+    payload: Json<TotpCode>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let email = authenticated_email(token, identity)?;
+
+    get_account(&email, &pool)
+        .and_then(|account| disable_totp(&account, &payload.code, &pool))
+        .and_then(|account| get_account_details(&account.email, &pool))
+        .map(|details| HttpResponse::Ok().json(details))
+        .map_err(two_factor_error)
+}
+
+#[get("/account/oidc/login")]
+async fn oidc_login(
+    request: HttpRequest,
+    session: Session,
+    oidc: Option<Data<OidcClient>>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let oidc = oidc.ok_or_else(|| error::ErrorNotFound("Single sign-on is not enabled"))?;
+    let authorization = AuthorizationRequest::new();
+    let metadata = oidc.metadata().await.map_err(error::ErrorBadGateway)?;
+    let authorization_url = oidc.authorization_url(&metadata, &authorization)?;
+    session
+        .insert(OIDC_SESSION_KEY, &authorization)
+        .map_err(|_| ThothError::InternalError("Failed to store session cookie".into()))?;
+    Ok(redirect(&authorization_url))
+}
+
+#[derive(Deserialize)]
+struct OidcCallback {
+    code: Option<String>,
+    state: Option<String>,
+}
+
+#[get("/account/oidc/callback")]
+async fn oidc_callback(
+    request: HttpRequest,
+    query: Query<OidcCallback>,
+    session: Session,
+    oidc: Option<Data<OidcClient>>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let oidc = oidc.ok_or_else(|| error::ErrorNotFound("Single sign-on is not enabled"))?;
+    // The pending request is single use, whatever the outcome
+    let authorization = session
+        .remove_as::<AuthorizationRequest>(OIDC_SESSION_KEY)
+        .and_then(Result::ok);
+    let result = match (authorization, &query.code, &query.state) {
+        (Some(authorization), Some(code), Some(state)) if *state == authorization.state => {
+            complete_oidc_login(&request, &oidc, code, &authorization, &pool).await
         }
-        None => {
-            token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-            let t = token.jwt.unwrap();
-            t.sub
+        _ => Err(ThothError::Unauthorised),
+    };
+    // The token is passed in the fragment so that it is never sent back to a server
+    let post_login_url = &oidc.config.post_login_url;
+    match result {
+        Ok(fragment) => Ok(redirect(&format!("{post_login_url}#{fragment}"))),
+        Err(e) => {
+            log::warn!("Single sign-on failed: {e}");
+            Ok(redirect(&format!("{post_login_url}#error=sso")))
+        }
+    }
+}
+
+/// Exchange the authorization code, map the identity to an account and start a session,
+/// returning the fragment to pass back to the admin interface.
+///
+/// Accounts with two-factor authentication enabled get a short-lived challenge instead of a
+/// token, to be exchanged together with a code at `/account/oidc/2fa`.
+async fn complete_oidc_login(
+    request: &HttpRequest,
+    oidc: &OidcClient,
+    code: &str,
+    authorization: &AuthorizationRequest,
+    pool: &PgPool,
+) -> ThothResult<String> {
+    let claims = oidc.exchange_code(code, authorization).await?;
+    let (name, surname) = claims.names();
+    let email = claims.verified_email()?;
+    let ip_address = client_ip(request);
+    let account = match login_external(
+        email,
+        &name,
+        &surname,
+        oidc.config.provision_accounts,
+        ip_address.as_deref(),
+        pool,
+    ) {
+        Err(ThothError::TwoFactorRequired) => {
+            return TwoFactorChallenge::issue(email)
+                .map(|challenge| format!("two_factor={challenge}"))
         }
+        result => result?,
     };
+    let token = account.issue_token(pool)?;
+    let details = get_account_details(&account.email, pool)?;
+    let user_string = serde_json::to_string(&details)
+        .map_err(|_| ThothError::InternalError("Serder error".into()))?;
+    Identity::login(&request.extensions(), user_string)
+        .map_err(|_| ThothError::InternalError("Failed to store session cookie".into()))?;
+    Ok(format!("token={token}"))
+}
 
-    get_account(&email, &pool)
+#[post("/account/oidc/2fa")]
+async fn oidc_two_factor(
+    request: HttpRequest,
+    payload: Json<TwoFactorLogin>,
+    pool: Data<PgPool>,
+    limiter: Data<RateLimiter>,
+) -> Result<HttpResponse, Error> {
+    if let Err(retry_after) = limiter.check(&rate_limit_key(&request)) {
+        return Ok(too_many_requests(retry_after));
+    }
+    let ip_address = client_ip(&request);
+    let r = payload.into_inner();
+
+    TwoFactorChallenge::verify(&r.challenge)
+        .map_err(|_| ThothError::Unauthorised)
+        .and_then(|challenge| {
+            login_external_second_factor(&challenge.sub, &r.totp_code, ip_address.as_deref(), &pool)
+        })
         .and_then(|account| {
             account.issue_token(&pool)?;
-            let details = get_account_details(&account.email, &pool).unwrap();
+            let details = get_account_details(&account.email, &pool)?;
             let user_string = serde_json::to_string(&details)
                 .map_err(|_| ThothError::InternalError("Serder error".into()))?;
             Identity::login(&request.extensions(), user_string)
                 .map_err(|_| ThothError::InternalError("Failed to store session cookie".into()))?;
             Ok(HttpResponse::Ok().json(details))
         })
-        .map_err(error::ErrorUnauthorized)
+        .map_err(account_error)
 }
 
-#[get("/account")]
-async fn account_details(
+#[post("/upload/cover/{work_id}")]
+async fn upload_cover(
+    work_id: Path<Uuid>,
     token: DecodedToken,
-    identity: Option<Identity>,
+    payload: Multipart,
     pool: Data<PgPool>,
+    storage: Option<Data<AssetStorage>>,
+    renderer: Option<Data<CoverRenderer>>,
 ) -> Result<HttpResponse, Error> {
+    upload_asset(
+        AssetType::Cover,
+        work_id.into_inner(),
+        token,
+        payload,
+        pool,
+        storage,
+        renderer,
+    )
+    .await
+}
+
+#[post("/upload/publication/{publication_id}")]
+async fn upload_publication_file(
+    publication_id: Path<Uuid>,
+    token: DecodedToken,
+    payload: Multipart,
+    pool: Data<PgPool>,
+    storage: Option<Data<AssetStorage>>,
+) -> Result<HttpResponse, Error> {
+    upload_asset(
+        AssetType::PublicationFile,
+        publication_id.into_inner(),
+        token,
+        payload,
+        pool,
+        storage,
+        None,
+    )
+    .await
+}
+
+/// Store an uploaded file and record it against its work or publication.
+///
+/// The file is only stored once the asset is known to be valid, and recorded once stored.
+/// Cover renditions are generated last: failing to do so does not undo the upload.
+async fn upload_asset(
+    asset_type: AssetType,
+    parent_id: Uuid,
+    token: DecodedToken,
+    payload: Multipart,
+    pool: Data<PgPool>,
+    storage: Option<Data<AssetStorage>>,
+    renderer: Option<Data<CoverRenderer>>,
+) -> Result<HttpResponse, Error> {
+    let storage = storage.ok_or_else(|| error::ErrorNotFound("Asset storage is not enabled"))?;
+    let jwt = token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+    let publisher_id = match asset_type {
+        AssetType::Cover => Work::from_id(&pool, &parent_id)?.publisher_id(&pool)?,
+        AssetType::PublicationFile => {
+            Publication::from_id(&pool, &parent_id)?.publisher_id(&pool)?
+        }
+    };
+    token.get_user_permissions().can_edit(publisher_id)?;
+
+    let (filename, contents) = read_upload(payload, storage.config.max_file_size).await?;
+    let mut new_asset = NewAsset::from_upload(&pool, asset_type, parent_id, filename, &contents)?;
+    new_asset.asset_url = storage.url(&new_asset.storage_key);
+    new_asset.can_be_recorded(&pool)?;
+    storage
+        .put(
+            &new_asset.storage_key,
+            &new_asset.mime_type,
+            contents.clone(),
+        )
+        .await?;
+    let asset = new_asset.record(&pool, &jwt.account_id(&pool))?;
+    if let Some(renderer) = renderer {
+        if let Err(e) = renderer.render_all(contents, &asset, &storage, &pool).await {
+            log::warn!(
+                "Failed to generate renditions of cover {}: {e}",
+                asset.asset_id
+            );
+        }
+    }
+    Ok(HttpResponse::Created().json(asset))
+}
+
+/// Read the uploaded file's name and contents, rejecting files above the size limit.
+///
+/// The MIME type declared in the form data is ignored: it is determined from the contents.
+async fn read_upload(mut payload: Multipart, max_file_size: usize) -> ThothResult<(String, Bytes)> {
+    let multipart_error =
+        |e: actix_multipart::MultipartError| ThothError::InvalidAssetError(e.to_string());
+    while let Some(mut field) = payload.try_next().await.map_err(multipart_error)? {
+        if field.name() != Some(UPLOAD_FIELD) {
+            continue;
+        }
+        let filename = field
+            .content_disposition()
+            .and_then(|disposition| disposition.get_filename())
+            .unwrap_or_default()
+            .to_string();
+        let mut contents = Vec::new();
+        while let Some(chunk) = field.try_next().await.map_err(multipart_error)? {
+            if contents.len() + chunk.len() > max_file_size {
+                return Err(ThothError::InvalidAssetError(format!(
+                    "File exceeds the maximum size of {max_file_size} bytes."
+                )));
+            }
+            contents.extend_from_slice(&chunk);
+        }
+        return Ok((filename, contents.into()));
+    }
+    Err(ThothError::InvalidAssetError(format!(
+        "No file was provided in the \"{UPLOAD_FIELD}\" field."
+    )))
+}
+
+/// Serve locally stored files under `/assets`. Images are displayed inline and anything else is
+/// downloaded, and browsers may not second-guess the type, so that no upload is ever rendered
+/// as a document.
+fn asset_files(path: &std::path::Path) -> impl HttpServiceFactory {
+    web::scope("/assets")
+        .wrap(DefaultHeaders::new().add((header::X_CONTENT_TYPE_OPTIONS, "nosniff")))
+        .service(Files::new("", path).mime_override(|name| {
+            if name.as_str() == "image" {
+                DispositionType::Inline
+            } else {
+                DispositionType::Attachment
+            }
+        }))
+}
+
+fn redirect(location: &str) -> HttpResponse {
+    HttpResponse::Found()
+        .insert_header((header::LOCATION, location))
+        .finish()
+}
+
+/// Obtain the email of the logged in user, either from the session cookie or the bearer token
+fn authenticated_email(token: DecodedToken, identity: Option<Identity>) -> Result<String, Error> {
     let email = match identity {
         Some(session) => {
             let id = session.id().map_err(|_| ThothError::Unauthorised)?;
@@ -176,10 +641,22 @@ async fn account_details(
             t.sub
         }
     };
+    Ok(email)
+}
 
-    get_account_details(&email, &pool)
-        .map(|account_details| HttpResponse::Ok().json(account_details))
-        .map_err(error::ErrorUnauthorized)
+/// Report failed credentials as such, while other errors (e.g. a locked account) keep their own status
+fn account_error(e: ThothError) -> Error {
+    match e {
+        ThothError::Unauthorised => error::ErrorUnauthorized(e),
+        _ => e.into(),
+    }
+}
+
+fn two_factor_error(e: ThothError) -> Error {
+    match e {
+        ThothError::TwoFactorError(_) => error::ErrorBadRequest(e),
+        _ => error::ErrorUnauthorized(e),
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -194,8 +671,31 @@ pub async fn start_server(
     domain: String,
     secret_str: String,
     session_duration: i64,
+    oidc_config: Option<OidcConfig>,
+    asset_storage: Option<AssetStorageConfig>,
+    anonymous_persisted_queries_only: bool,
 ) -> io::Result<()> {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+    // Shared across workers so that limits apply to the whole server
+    let account_limiter = Data::new(RateLimiter::new(
+        ACCOUNT_RATE_LIMIT_CAPACITY,
+        ACCOUNT_RATE_LIMIT_PERIOD,
+    ));
+    let trusted_proxies = Data::new(TrustedProxies::from_env());
+    let oidc_client = oidc_config.map(|config| Data::new(OidcClient::new(config)));
+    let cover_renderer = asset_storage
+        .as_ref()
+        .and_then(|config| config.image_converter.clone())
+        .map(|command| Data::new(CoverRenderer::new(command)));
+    let asset_storage = asset_storage.map(|config| Data::new(AssetStorage::new(config)));
+    let schema = Arc::new(create_schema());
+    // The export server fetches every record linked to the works it exports
+    let query_analyser =
+        Data::new(QueryAnalyser::new(&schema.as_sdl()).trust(thoth_client::QUERY_DOCUMENT));
+    let graphql_limits = GraphqlLimits::from_env();
+    let graphql_limiters = Data::new(GraphqlRateLimiters::new(&graphql_limits));
+    let graphql_limits = Data::new(graphql_limits);
+    let persisted_queries = Data::new(PersistedQueries::new(anonymous_persisted_queries_only));
 
     HttpServer::new(move || {
         App::new()
@@ -224,11 +724,37 @@ pub async fn start_server(
                     .allow_any_origin()
                     .allowed_headers(vec![header::AUTHORIZATION, header::ACCEPT])
                     .allowed_header(header::CONTENT_TYPE)
+                    .expose_headers(vec![
+                        header::RETRY_AFTER.as_str(),
+                        TWO_FACTOR_HEADER,
+                        RATE_LIMIT_LIMIT_HEADER,
+                        RATE_LIMIT_REMAINING_HEADER,
+                    ])
                     .supports_credentials(),
             )
             .app_data(Data::new(ApiConfig::new(public_url.clone())))
             .app_data(Data::new(init_pool(&database_url)))
-            .app_data(Data::new(Arc::new(create_schema())))
+            .app_data(Data::new(schema.clone()))
+            .app_data(account_limiter.clone())
+            .app_data(trusted_proxies.clone())
+            .app_data(query_analyser.clone())
+            .app_data(graphql_limits.clone())
+            .app_data(graphql_limiters.clone())
+            .app_data(persisted_queries.clone())
+            .configure(|cfg| {
+                if let Some(oidc_client) = &oidc_client {
+                    cfg.app_data(oidc_client.clone());
+                }
+                if let Some(asset_storage) = &asset_storage {
+                    cfg.app_data(asset_storage.clone());
+                    if let Some(path) = asset_storage.local_path() {
+                        cfg.service(asset_files(path));
+                    }
+                }
+                if let Some(cover_renderer) = &cover_renderer {
+                    cfg.app_data(cover_renderer.clone());
+                }
+            })
             .service(index)
             .service(graphql_index)
             .service(graphql)
@@ -236,7 +762,16 @@ pub async fn start_server(
             .service(login_credentials)
             .service(login_session)
             .service(account_details)
+            .service(totp_enrol)
+            .service(totp_confirm)
+            .service(totp_recovery_codes)
+            .service(totp_disable)
+            .service(oidc_login)
+            .service(oidc_callback)
+            .service(oidc_two_factor)
             .service(graphql_schema)
+            .service(upload_cover)
+            .service(upload_publication_file)
     })
     .workers(threads)
     .keep_alive(Duration::from_secs(keep_alive))
@@ -244,3 +779,216 @@ pub async fn start_server(
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test};
+    use jsonwebtoken::Algorithm;
+    use serde_json::json;
+    use std::sync::Once;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use thoth_api::account::model::{LinkedPublisher, Token};
+    use thoth_api::account::service::{register, MAX_FAILED_LOGIN_ATTEMPTS};
+    use thoth_api::account::totp::{decrypt_secret, totp_code_at};
+    use thoth_api::db::run_migrations;
+    use thoth_api::model::publisher::{NewPublisher, Publisher};
+
+    use crate::oidc::tests::{client, request, start_mock_idp_for, CLIENT_ID, NONCE};
+
+    const TEST_PASSWORD: &str = "Correct-horse-battery-staple-1";
+
+    fn test_pool() -> PgPool {
+        static MIGRATE: Once = Once::new();
+        let database_url =
+            std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+        MIGRATE.call_once(|| run_migrations(&database_url).expect("Failed to run migrations"));
+        init_pool(&database_url)
+    }
+
+    fn test_account(pool: &PgPool) -> String {
+        let email = format!("{}@thoth.test", Uuid::new_v4());
+        register(
+            pool,
+            "Test".to_string(),
+            "Account".to_string(),
+            email.clone(),
+            TEST_PASSWORD.to_string(),
+            false,
+            false,
+        )
+        .unwrap();
+        email
+    }
+
+    #[actix_web::test]
+    async fn test_login_locked_account() {
+        let pool = test_pool();
+        let email = test_account(&pool);
+        let app = test::init_service(
+            App::new()
+                .app_data(Data::new(pool))
+                .app_data(Data::new(RateLimiter::new(100, Duration::from_secs(60))))
+                .service(login_credentials),
+        )
+        .await;
+        let login_request = |password: &str| {
+            test::TestRequest::post()
+                .uri("/account/login")
+                .set_json(json!({ "email": email, "password": password }))
+                .to_request()
+        };
+
+        for _ in 0..MAX_FAILED_LOGIN_ATTEMPTS {
+            let response = test::call_service(&app, login_request("wrong password")).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        // Even the right password is refused while the account is locked
+        let response = test::call_service(&app, login_request(TEST_PASSWORD)).await;
+        assert_eq!(response.status(), StatusCode::LOCKED);
+        let body: String = test::read_body_json(response).await;
+        assert_eq!(body, ThothError::AccountLocked.to_string());
+    }
+
+    #[actix_web::test]
+    async fn test_oidc_login_requires_second_factor() {
+        let pool = test_pool();
+        let email = test_account(&pool);
+        let account = get_account(&email, &pool).unwrap();
+        let publisher = Publisher::create(
+            &pool,
+            &NewPublisher {
+                publisher_name: format!("Two-Factor Press {}", Uuid::new_v4()),
+                publisher_shortname: None,
+                publisher_url: None,
+            },
+        )
+        .and_then(|publisher| {
+            publisher.update_two_factor_requirement(&pool, true, &account.account_id)
+        })
+        .unwrap();
+        account
+            .add_publisher_account(
+                &pool,
+                LinkedPublisher {
+                    publisher_id: publisher.publisher_id,
+                    is_admin: false,
+                },
+            )
+            .unwrap();
+        start_totp_enrolment(&account, &pool).unwrap();
+        let account = get_account(&email, &pool).unwrap();
+        let secret = decrypt_secret(account.totp_secret.as_deref().unwrap()).unwrap();
+        let now = || {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+        confirm_totp_enrolment(&account, &totp_code_at(&secret, now()), &pool).unwrap();
+
+        // The identity provider's assertion alone only earns a challenge, not a token
+        let oidc = client(start_mock_idp_for(
+            &email,
+            CLIENT_ID,
+            NONCE,
+            Algorithm::RS256,
+        ));
+        let http_request = test::TestRequest::default().to_http_request();
+        let fragment = complete_oidc_login(&http_request, &oidc, "valid-code", &request(), &pool)
+            .await
+            .unwrap();
+        let challenge = fragment
+            .strip_prefix("two_factor=")
+            .expect("Expected a two-factor challenge")
+            .to_string();
+        assert!(Token::verify(&challenge).is_err());
+
+        let app = test::init_service(
+            App::new()
+                .wrap(IdentityMiddleware::default())
+                .wrap(SessionMiddleware::new(
+                    CookieSessionStore::default(),
+                    Key::generate(),
+                ))
+                .app_data(Data::new(pool.clone()))
+                .app_data(Data::new(RateLimiter::new(100, Duration::from_secs(60))))
+                .service(oidc_two_factor),
+        )
+        .await;
+        let two_factor_request = |challenge: &str, code: &str| {
+            test::TestRequest::post()
+                .uri("/account/oidc/2fa")
+                .set_json(json!({ "challenge": challenge, "totp_code": code }))
+                .to_request()
+        };
+
+        let response = test::call_service(&app, two_factor_request(&challenge, "000000")).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        // A session token cannot stand in for the challenge
+        let token = account.issue_token(&pool).unwrap();
+        let code = totp_code_at(&secret, now() + 30);
+        let response = test::call_service(&app, two_factor_request(&token, &code)).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = test::call_service(&app, two_factor_request(&challenge, &code)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let details: AccountDetails = test::read_body_json(response).await;
+        assert!(details
+            .resource_access
+            .linked_publishers
+            .iter()
+            .any(|p| p.publisher_id == publisher.publisher_id));
+    }
+
+    #[actix_web::test]
+    async fn test_asset_files_headers() {
+        let path = std::env::temp_dir().join(format!("thoth-assets-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("cover.png"), b"\x89PNG\r\n\x1a\n").unwrap();
+        std::fs::write(path.join("book.pdf"), b"%PDF-1.7\n").unwrap();
+        let app = test::init_service(App::new().service(asset_files(&path))).await;
+
+        let cover = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/assets/cover.png")
+                .to_request(),
+        )
+        .await;
+        assert!(cover.status().is_success());
+        assert_eq!(
+            cover.headers().get(header::X_CONTENT_TYPE_OPTIONS).unwrap(),
+            "nosniff"
+        );
+        assert!(cover
+            .headers()
+            .get(header::CONTENT_DISPOSITION)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("inline"));
+
+        let book = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/assets/book.pdf")
+                .to_request(),
+        )
+        .await;
+        assert!(book.status().is_success());
+        assert_eq!(
+            book.headers().get(header::X_CONTENT_TYPE_OPTIONS).unwrap(),
+            "nosniff"
+        );
+        assert!(book
+            .headers()
+            .get(header::CONTENT_DISPOSITION)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("attachment"));
+
+        std::fs::remove_dir_all(path).unwrap();
+    }
+}
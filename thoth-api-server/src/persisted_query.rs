@@ -0,0 +1,270 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+};
+
+use thoth_api::{
+    db::PgPool,
+    graphql::{
+        limits::CallerType,
+        persisted_query::{get_persisted_query, query_hash, PersistedQueryRequest},
+        GraphQLRequest,
+    },
+};
+use thoth_errors::{ThothError, ThothResult};
+
+/// Number of automatically persisted queries kept in memory
+pub(crate) const AUTOMATIC_PERSISTED_QUERY_CAPACITY: usize = 1000;
+
+/// Queries registered by clients through the automatic persisted query protocol.
+///
+/// Unlike registered persisted queries these are not trusted, so they are only held in memory,
+/// evicting the oldest entries once full.
+pub(crate) struct AutomaticPersistedQueries {
+    capacity: usize,
+    entries: Mutex<(HashMap<String, String>, VecDeque<String>)>,
+}
+
+impl AutomaticPersistedQueries {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+
+    fn get(&self, hash: &str) -> Option<String> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.0.get(hash).cloned()
+    }
+
+    fn insert(&self, hash: String, query: String) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let (queries, order) = &mut *entries;
+        if queries.contains_key(&hash) {
+            return;
+        }
+        if queries.len() >= self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                queries.remove(&oldest);
+            }
+        }
+        order.push_back(hash.clone());
+        queries.insert(hash, query);
+    }
+}
+
+/// How the query documents of incoming requests are obtained
+pub(crate) struct PersistedQueries {
+    automatic: AutomaticPersistedQueries,
+    /// Only let anonymous callers execute queries registered ahead of time
+    anonymous_registered_only: bool,
+}
+
+impl PersistedQueries {
+    pub(crate) fn new(anonymous_registered_only: bool) -> Self {
+        Self {
+            automatic: AutomaticPersistedQueries::new(AUTOMATIC_PERSISTED_QUERY_CAPACITY),
+            anonymous_registered_only,
+        }
+    }
+
+    /// Obtain the query document of a request, either from the request itself or from the
+    /// persisted query it refers to, and register it as an automatic persisted query if allowed
+    pub(crate) fn resolve(
+        &self,
+        mut payload: PersistedQueryRequest,
+        caller: CallerType,
+        pool: &PgPool,
+    ) -> ThothResult<GraphQLRequest> {
+        self.resolve_with(&mut payload, caller, |hash| get_persisted_query(hash, pool))
+            .map(|query| payload.into_graphql_request(query))
+    }
+
+    fn resolve_with<F>(
+        &self,
+        payload: &mut PersistedQueryRequest,
+        caller: CallerType,
+        registered_query: F,
+    ) -> ThothResult<String>
+    where
+        F: Fn(&str) -> ThothResult<Option<String>>,
+    {
+        let restricted = self.anonymous_registered_only && caller == CallerType::Anonymous;
+        let hash = payload.persisted_query_hash()?.map(str::to_lowercase);
+        match (payload.query.take(), hash) {
+            (None, Some(hash)) => {
+                let automatic = match restricted {
+                    true => None,
+                    false => self.automatic.get(&hash),
+                };
+                match automatic {
+                    Some(query) => Ok(query),
+                    None => registered_query(&hash)?.ok_or(ThothError::PersistedQueryNotFound),
+                }
+            }
+            (Some(query), Some(hash)) => {
+                if query_hash(&query) != hash {
+                    return Err(ThothError::PersistedQueryHashMismatch);
+                }
+                if restricted {
+                    registered_query(&hash)?.ok_or(ThothError::PersistedQueryRequired)
+                } else {
+                    self.automatic.insert(hash, query.clone());
+                    Ok(query)
+                }
+            }
+            (Some(query), None) if restricted => {
+                registered_query(&query_hash(&query))?.ok_or(ThothError::PersistedQueryRequired)
+            }
+            (Some(query), None) => Ok(query),
+            (None, None) => Err(ThothError::GraphqlError(
+                "A query or a persisted query hash must be provided.".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const QUERY: &str = "{ workCount }";
+    const REGISTERED_QUERY: &str = "query Works { works { workId } }";
+
+    fn registered_query(hash: &str) -> ThothResult<Option<String>> {
+        Ok((hash == query_hash(REGISTERED_QUERY)).then(|| REGISTERED_QUERY.to_string()))
+    }
+
+    fn payload(query: Option<&str>, hash: Option<String>) -> PersistedQueryRequest {
+        let extensions =
+            hash.map(|hash| json!({ "persistedQuery": { "version": 1, "sha256Hash": hash } }));
+        serde_json::from_value(json!({ "query": query, "extensions": extensions })).unwrap()
+    }
+
+    fn resolve(
+        persisted_queries: &PersistedQueries,
+        query: Option<&str>,
+        hash: Option<String>,
+        caller: CallerType,
+    ) -> ThothResult<String> {
+        persisted_queries.resolve_with(&mut payload(query, hash), caller, registered_query)
+    }
+
+    #[test]
+    fn test_automatic_persisted_query_flow() {
+        let persisted_queries = PersistedQueries::new(false);
+        let hash = query_hash(QUERY);
+        // The hash alone is unknown until the client sends the full query along with it
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                None,
+                Some(hash.clone()),
+                CallerType::Anonymous
+            ),
+            Err(ThothError::PersistedQueryNotFound)
+        );
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                Some(QUERY),
+                Some(hash.clone()),
+                CallerType::Anonymous
+            ),
+            Ok(QUERY.to_string())
+        );
+        assert_eq!(
+            resolve(&persisted_queries, None, Some(hash), CallerType::Anonymous),
+            Ok(QUERY.to_string())
+        );
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                Some(QUERY),
+                Some("0".repeat(64)),
+                CallerType::Anonymous
+            ),
+            Err(ThothError::PersistedQueryHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_registered_persisted_query() {
+        let persisted_queries = PersistedQueries::new(false);
+        let hash = query_hash(REGISTERED_QUERY).to_uppercase();
+        assert_eq!(
+            resolve(&persisted_queries, None, Some(hash), CallerType::Anonymous),
+            Ok(REGISTERED_QUERY.to_string())
+        );
+        assert_eq!(
+            resolve(&persisted_queries, Some(QUERY), None, CallerType::Anonymous),
+            Ok(QUERY.to_string())
+        );
+        assert!(resolve(&persisted_queries, None, None, CallerType::Anonymous).is_err());
+    }
+
+    #[test]
+    fn test_anonymous_registered_only() {
+        let persisted_queries = PersistedQueries::new(true);
+        let hash = query_hash(QUERY);
+        assert_eq!(
+            resolve(&persisted_queries, Some(QUERY), None, CallerType::Anonymous),
+            Err(ThothError::PersistedQueryRequired)
+        );
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                Some(QUERY),
+                Some(hash.clone()),
+                CallerType::Anonymous
+            ),
+            Err(ThothError::PersistedQueryRequired)
+        );
+        // Logged in users can still register and use automatic persisted queries
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                Some(QUERY),
+                Some(hash.clone()),
+                CallerType::Authenticated
+            ),
+            Ok(QUERY.to_string())
+        );
+        assert_eq!(
+            resolve(&persisted_queries, None, Some(hash), CallerType::Anonymous),
+            Err(ThothError::PersistedQueryNotFound)
+        );
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                Some(REGISTERED_QUERY),
+                None,
+                CallerType::Anonymous
+            ),
+            Ok(REGISTERED_QUERY.to_string())
+        );
+        assert_eq!(
+            resolve(
+                &persisted_queries,
+                None,
+                Some(query_hash(REGISTERED_QUERY)),
+                CallerType::Anonymous
+            ),
+            Ok(REGISTERED_QUERY.to_string())
+        );
+    }
+
+    #[test]
+    fn test_automatic_persisted_queries_capacity() {
+        let cache = AutomaticPersistedQueries::new(2);
+        cache.insert("a".into(), "A".into());
+        cache.insert("b".into(), "B".into());
+        cache.insert("a".into(), "A".into());
+        cache.insert("c".into(), "C".into());
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b"), Some("B".to_string()));
+        assert_eq!(cache.get("c"), Some("C".to_string()));
+    }
+}
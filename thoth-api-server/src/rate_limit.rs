@@ -0,0 +1,323 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use actix_web::{http::header, web::Data, HttpRequest, HttpResponse, HttpResponseBuilder};
+use serde_json::json;
+use thoth_api::{
+    account::model::DecodedToken,
+    graphql::limits::{CallerType, GraphqlLimits},
+};
+
+/// Maximum number of requests a single client can burst against account endpoints
+pub(crate) const ACCOUNT_RATE_LIMIT_CAPACITY: u32 = 10;
+/// Time it takes for a single client to regain the full account endpoint allowance
+pub(crate) const ACCOUNT_RATE_LIMIT_PERIOD: Duration = Duration::from_secs(60);
+/// GraphQL allowances are expressed in requests per minute
+const GRAPHQL_RATE_LIMIT_PERIOD: Duration = Duration::from_secs(60);
+pub(crate) const RATE_LIMIT_LIMIT_HEADER: &str = "X-RateLimit-Limit";
+pub(crate) const RATE_LIMIT_REMAINING_HEADER: &str = "X-RateLimit-Remaining";
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+struct Buckets {
+    buckets: HashMap<String, Bucket>,
+    last_pruned: Instant,
+}
+
+/// In-memory token bucket rate limiter, keyed by an arbitrary client identifier
+pub(crate) struct RateLimiter {
+    capacity: u32,
+    period: Duration,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(capacity: u32, period: Duration) -> Self {
+        Self {
+            capacity,
+            period,
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                last_pruned: Instant::now(),
+            }),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    fn refill_rate(&self) -> f64 {
+        self.capacity as f64 / self.period.as_secs_f64()
+    }
+
+    /// Consume one token for the given key at the given instant.
+    ///
+    /// Returns the number of tokens left, or the number of seconds to wait before retrying.
+    fn check_at(&self, key: &str, now: Instant) -> Result<u32, u64> {
+        let capacity = self.capacity as f64;
+        let rate = self.refill_rate();
+        let mut state = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        // Buckets that have had time to refill completely hold no information, drop them.
+        // Sweeping once per period is enough to keep the map bounded by the recent clients.
+        let period = self.period;
+        if now.duration_since(state.last_pruned) >= period {
+            state
+                .buckets
+                .retain(|_, bucket| now.duration_since(bucket.last_refill) < period);
+            state.last_pruned = now;
+        }
+        let bucket = state.buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            last_refill: now,
+        });
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        bucket.last_refill = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(bucket.tokens.floor() as u32)
+        } else {
+            Err(((1.0 - bucket.tokens) / rate).ceil() as u64)
+        }
+    }
+
+    pub(crate) fn check(&self, key: &str) -> Result<u32, u64> {
+        self.check_at(key, Instant::now())
+    }
+}
+
+/// GraphQL rate limiters, one for each type of caller
+pub(crate) struct GraphqlRateLimiters {
+    anonymous: RateLimiter,
+    authenticated: RateLimiter,
+    bot: RateLimiter,
+}
+
+impl GraphqlRateLimiters {
+    pub(crate) fn new(limits: &GraphqlLimits) -> Self {
+        let limiter =
+            |requests_per_minute| RateLimiter::new(requests_per_minute, GRAPHQL_RATE_LIMIT_PERIOD);
+        Self {
+            anonymous: limiter(limits.anonymous.requests_per_minute),
+            authenticated: limiter(limits.authenticated.requests_per_minute),
+            bot: limiter(limits.bot.requests_per_minute),
+        }
+    }
+
+    pub(crate) fn for_caller(&self, caller: CallerType) -> &RateLimiter {
+        match caller {
+            CallerType::Anonymous => &self.anonymous,
+            CallerType::Authenticated => &self.authenticated,
+            CallerType::Bot => &self.bot,
+        }
+    }
+}
+
+/// Logged in callers are limited per account, wherever they connect from, and anonymous ones per IP
+pub(crate) fn caller_key(request: &HttpRequest, token: &DecodedToken) -> String {
+    match &token.jwt {
+        Some(jwt) => format!("account {}", jwt.sub),
+        None => format!("ip {}", client_ip(request).unwrap_or_default()),
+    }
+}
+
+/// Reverse proxies which are trusted to report the address of the client in `X-Forwarded-For`
+#[derive(Debug, Default)]
+pub(crate) struct TrustedProxies(Vec<IpAddr>);
+
+impl TrustedProxies {
+    /// Read a comma-separated list of proxy IP addresses from `TRUSTED_PROXIES`
+    pub(crate) fn from_env() -> Self {
+        std::env::var("TRUSTED_PROXIES")
+            .map(|proxies| Self::parse(&proxies))
+            .unwrap_or_default()
+    }
+
+    fn parse(proxies: &str) -> Self {
+        Self(
+            proxies
+                .split(',')
+                .filter_map(|proxy| proxy.trim().parse().ok())
+                .collect(),
+        )
+    }
+
+    fn trusts(&self, ip: &IpAddr) -> bool {
+        self.0.contains(ip)
+    }
+}
+
+/// Identify the calling client by its IP address.
+///
+/// Forwarding headers can be set by anyone, so they are only read when the connection comes
+/// from a trusted proxy, in which case the last address not belonging to a trusted proxy is used.
+pub(crate) fn client_ip(request: &HttpRequest) -> Option<String> {
+    let peer = request.peer_addr()?.ip();
+    let Some(proxies) = request
+        .app_data::<Data<TrustedProxies>>()
+        .filter(|proxies| proxies.trusts(&peer))
+    else {
+        return Some(peer.to_string());
+    };
+    let forwarded: Vec<IpAddr> = request
+        .headers()
+        .get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect();
+    let client = forwarded
+        .into_iter()
+        .rev()
+        .find(|ip| !proxies.trusts(ip))
+        .unwrap_or(peer);
+    Some(client.to_string())
+}
+
+/// Limits are applied per endpoint, so that e.g. fetching account details does not use up login attempts
+pub(crate) fn rate_limit_key(request: &HttpRequest) -> String {
+    format!(
+        "{} {}",
+        request.path(),
+        client_ip(request).unwrap_or_default()
+    )
+}
+
+pub(crate) fn too_many_requests(retry_after: u64) -> HttpResponse {
+    HttpResponse::TooManyRequests()
+        .insert_header((header::RETRY_AFTER, retry_after.to_string()))
+        .json("Too many requests. Please try again later.")
+}
+
+/// Let the caller know its allowance, so that it can pace its requests
+pub(crate) fn with_rate_limit_headers(
+    response: &mut HttpResponseBuilder,
+    limit: u32,
+    remaining: u32,
+) -> &mut HttpResponseBuilder {
+    response
+        .insert_header((RATE_LIMIT_LIMIT_HEADER, limit.to_string()))
+        .insert_header((RATE_LIMIT_REMAINING_HEADER, remaining.to_string()))
+}
+
+/// Error response in the format of a GraphQL response, so that clients can handle it as such
+pub(crate) fn graphql_error(response: &mut HttpResponseBuilder, message: String) -> HttpResponse {
+    response.json(json!({ "data": null, "errors": [{ "message": message }] }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test::TestRequest;
+
+    #[test]
+    fn test_rate_limiter_exhausts_capacity() {
+        let limiter = RateLimiter::new(3, Duration::from_secs(60));
+        let now = Instant::now();
+        assert_eq!(limiter.check_at("a", now), Ok(2));
+        assert_eq!(limiter.check_at("a", now), Ok(1));
+        assert_eq!(limiter.check_at("a", now), Ok(0));
+        assert_eq!(limiter.check_at("a", now), Err(20));
+        // Other clients are unaffected
+        assert_eq!(limiter.check_at("b", now), Ok(2));
+    }
+
+    #[test]
+    fn test_rate_limiter_refills() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let now = Instant::now();
+        assert!(limiter.check_at("a", now).is_ok());
+        assert!(limiter.check_at("a", now).is_ok());
+        assert!(limiter.check_at("a", now).is_err());
+        assert!(limiter.check_at("a", now + Duration::from_secs(5)).is_ok());
+        assert!(limiter.check_at("a", now + Duration::from_secs(5)).is_err());
+        assert_eq!(limiter.check_at("a", now + Duration::from_secs(60)), Ok(1));
+    }
+
+    #[test]
+    fn test_rate_limiter_prunes_expired_buckets() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+        let keys = || {
+            let state = limiter.buckets.lock().unwrap();
+            let mut keys: Vec<String> = state.buckets.keys().cloned().collect();
+            keys.sort();
+            keys
+        };
+        assert!(limiter.check_at("a", now).is_ok());
+        assert!(limiter.check_at("b", now + Duration::from_secs(30)).is_ok());
+        assert_eq!(keys(), vec!["a", "b"]);
+        // Once a period has passed, buckets that have fully refilled are dropped
+        assert!(limiter.check_at("c", now + Duration::from_secs(61)).is_ok());
+        assert_eq!(keys(), vec!["b", "c"]);
+        // No further sweep until another period has passed
+        assert!(limiter
+            .check_at("d", now + Duration::from_secs(100))
+            .is_ok());
+        assert_eq!(keys(), vec!["b", "c", "d"]);
+        assert!(limiter
+            .check_at("e", now + Duration::from_secs(122))
+            .is_ok());
+        assert_eq!(keys(), vec!["d", "e"]);
+    }
+
+    #[test]
+    fn test_client_ip_ignores_untrusted_forwarding() {
+        let request = TestRequest::default()
+            .peer_addr("203.0.113.7:1234".parse().unwrap())
+            .insert_header(("x-forwarded-for", "198.51.100.1"))
+            .to_http_request();
+        assert_eq!(client_ip(&request), Some("203.0.113.7".to_string()));
+        // Proxies must be listed to be trusted
+        let request = TestRequest::default()
+            .peer_addr("203.0.113.7:1234".parse().unwrap())
+            .insert_header(("x-forwarded-for", "198.51.100.1"))
+            .app_data(Data::new(TrustedProxies::parse("10.0.0.1")))
+            .to_http_request();
+        assert_eq!(client_ip(&request), Some("203.0.113.7".to_string()));
+    }
+
+    #[test]
+    fn test_client_ip_behind_trusted_proxy() {
+        let proxies = || Data::new(TrustedProxies::parse("10.0.0.1, 10.0.0.2"));
+        let request = TestRequest::default()
+            .peer_addr("10.0.0.1:1234".parse().unwrap())
+            .insert_header(("x-forwarded-for", "198.51.100.1"))
+            .app_data(proxies())
+            .to_http_request();
+        assert_eq!(client_ip(&request), Some("198.51.100.1".to_string()));
+        // Addresses prepended by the client itself are ignored
+        let request = TestRequest::default()
+            .peer_addr("10.0.0.1:1234".parse().unwrap())
+            .insert_header(("x-forwarded-for", "192.0.2.99, 198.51.100.1, 10.0.0.2"))
+            .app_data(proxies())
+            .to_http_request();
+        assert_eq!(client_ip(&request), Some("198.51.100.1".to_string()));
+        // Without a forwarding header the proxy itself is the client
+        let request = TestRequest::default()
+            .peer_addr("10.0.0.1:1234".parse().unwrap())
+            .app_data(proxies())
+            .to_http_request();
+        assert_eq!(client_ip(&request), Some("10.0.0.1".to_string()));
+    }
+
+    #[test]
+    fn test_graphql_rate_limiters_per_caller() {
+        let mut limits = GraphqlLimits::default();
+        limits.anonymous.requests_per_minute = 1;
+        limits.authenticated.requests_per_minute = 2;
+        limits.bot.requests_per_minute = 3;
+        let limiters = GraphqlRateLimiters::new(&limits);
+        assert_eq!(limiters.for_caller(CallerType::Anonymous).capacity(), 1);
+        assert_eq!(limiters.for_caller(CallerType::Authenticated).capacity(), 2);
+        assert_eq!(limiters.for_caller(CallerType::Bot).capacity(), 3);
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use actix_web::web::{self, Bytes};
+use thoth_api::{
+    db::PgPool,
+    model::{
+        asset::{Asset, ImageInfo},
+        supporting_resource::{CoverRendition, NewCoverRendition, COVER_RENDITIONS},
+    },
+};
+use thoth_errors::{ThothError, ThothResult};
+
+use crate::storage::AssetStorage;
+
+/// JPEG quality of generated renditions, high enough for retailers' requirements
+const RENDITION_QUALITY: u8 = 90;
+
+/// Generates renditions of uploaded covers using an ImageMagick-compatible command,
+/// such as `magick` or `convert`
+pub(crate) struct CoverRenderer {
+    command: String,
+}
+
+impl CoverRenderer {
+    pub(crate) fn new(command: String) -> Self {
+        Self { command }
+    }
+
+    /// ImageMagick coder used to read a cover of the given (validated) MIME type from standard
+    /// input, so that the command never guesses the format from the contents
+    fn input_coder(mime_type: &str) -> ThothResult<&'static str> {
+        match mime_type {
+            "image/jpeg" => Ok("jpeg:-"),
+            "image/png" => Ok("png:-"),
+            _ => Err(ThothError::ImageProcessingError(format!(
+                "Covers of type {mime_type} cannot be rendered."
+            ))),
+        }
+    }
+
+    /// Read the image from standard input, shrink it to fit the rendition's dimensions,
+    /// and write it to standard output as an sRGB JPEG without metadata.
+    fn arguments(mime_type: &str, rendition: &CoverRendition) -> ThothResult<Vec<String>> {
+        let (width, height) = rendition.max_dimensions();
+        Ok(vec![
+            Self::input_coder(mime_type)?.to_string(),
+            "-resize".to_string(),
+            format!("{width}x{height}>"),
+            "-colorspace".to_string(),
+            "sRGB".to_string(),
+            "-strip".to_string(),
+            "-quality".to_string(),
+            RENDITION_QUALITY.to_string(),
+            "jpg:-".to_string(),
+        ])
+    }
+
+    async fn render(
+        &self,
+        cover: Bytes,
+        mime_type: &str,
+        rendition: &CoverRendition,
+    ) -> ThothResult<Bytes> {
+        let mut command = Command::new(&self.command);
+        command
+            .args(Self::arguments(mime_type, rendition)?)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let output = web::block(move || {
+            let mut child = command.spawn()?;
+            let mut stdin = child.stdin.take().expect("Standard input is piped");
+            // Write from a separate thread so that the command cannot block on a full output pipe
+            let writer = std::thread::spawn(move || stdin.write_all(&cover));
+            let output = child.wait_with_output()?;
+            writer.join().expect("Writer thread does not panic")?;
+            Ok::<_, std::io::Error>(output)
+        })
+        .await
+        .map_err(|e| ThothError::ImageProcessingError(e.to_string()))?
+        .map_err(|e| ThothError::ImageProcessingError(e.to_string()))?;
+        if output.status.success() {
+            Ok(output.stdout.into())
+        } else {
+            Err(ThothError::ImageProcessingError(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ))
+        }
+    }
+
+    /// Generate, store and record every rendition of an uploaded cover
+    pub(crate) async fn render_all(
+        &self,
+        cover: Bytes,
+        asset: &Asset,
+        storage: &AssetStorage,
+        db: &PgPool,
+    ) -> ThothResult<()> {
+        for rendition in &COVER_RENDITIONS {
+            let contents = self
+                .render(cover.clone(), &asset.mime_type, rendition)
+                .await?;
+            let info = ImageInfo::from_bytes(&contents)?;
+            let key = asset.rendition_storage_key(rendition);
+            storage.put(&key, "image/jpeg", contents).await?;
+            NewCoverRendition::new(
+                asset,
+                *rendition,
+                storage.url(&key),
+                info.width as i32,
+                info.height as i32,
+            )
+            .create(db)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arguments() {
+        assert_eq!(
+            CoverRenderer::arguments("image/png", &CoverRendition::Thumbnail).unwrap(),
+            vec![
+                "png:-",
+                "-resize",
+                "200x400>",
+                "-colorspace",
+                "sRGB",
+                "-strip",
+                "-quality",
+                "90",
+                "jpg:-"
+            ]
+        );
+        assert_eq!(
+            CoverRenderer::arguments("image/jpeg", &CoverRendition::Amazon).unwrap()[..3],
+            ["jpeg:-", "-resize", "1600x2560>"]
+        );
+        assert!(CoverRenderer::arguments("image/svg+xml", &CoverRendition::Small).is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_render_failure() {
+        let renderer = CoverRenderer::new("false".to_string());
+        assert!(renderer
+            .render(
+                Bytes::from_static(b"cover"),
+                "image/png",
+                &CoverRendition::Small
+            )
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS account_login_audit;
+
+ALTER TABLE account
+    DROP COLUMN IF EXISTS failed_login_attempts,
+    DROP COLUMN IF EXISTS locked_until;
@@ -0,0 +1,21 @@
+-- Track consecutive failed login attempts and temporary lockouts
+ALTER TABLE account
+    ADD COLUMN failed_login_attempts INTEGER NOT NULL DEFAULT 0 CHECK (failed_login_attempts >= 0),
+    ADD COLUMN locked_until TIMESTAMP;
+
+-- Audit trail of every login attempt, successful or not.
+-- account_id is NULL when the supplied email does not match any account.
+CREATE TABLE account_login_audit (
+    account_login_audit_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    account_id              UUID REFERENCES account(account_id) ON DELETE CASCADE,
+    email                   TEXT NOT NULL CHECK (octet_length(email) >= 1),
+    ip_address              TEXT CHECK (octet_length(ip_address) >= 1),
+    success                 BOOLEAN NOT NULL,
+    failure_reason          TEXT CHECK (octet_length(failure_reason) >= 1),
+    timestamp               TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT account_login_audit_success_failure_reason_check CHECK
+        ((success AND failure_reason IS NULL) OR (NOT success AND failure_reason IS NOT NULL))
+);
+
+CREATE INDEX idx_account_login_audit_account_id ON account_login_audit (account_id);
+CREATE INDEX idx_account_login_audit_timestamp_desc ON account_login_audit (timestamp DESC);
@@ -0,0 +1,10 @@
+ALTER TABLE publisher
+    DROP COLUMN IF EXISTS require_two_factor;
+
+DROP TABLE IF EXISTS account_recovery_code;
+
+ALTER TABLE account
+    DROP CONSTRAINT IF EXISTS account_totp_enabled_secret_check,
+    DROP COLUMN IF EXISTS totp_secret,
+    DROP COLUMN IF EXISTS totp_enabled,
+    DROP COLUMN IF EXISTS totp_last_used_step;
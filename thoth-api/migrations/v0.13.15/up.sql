@@ -0,0 +1,23 @@
+-- Optional TOTP two-factor authentication. The secret is stored encrypted and
+-- only takes effect once the account holder has confirmed enrolment.
+ALTER TABLE account
+    ADD COLUMN totp_secret TEXT CHECK (octet_length(totp_secret) >= 1),
+    ADD COLUMN totp_enabled BOOLEAN NOT NULL DEFAULT False,
+    -- Time step of the last accepted code, so that a code cannot be used twice
+    ADD COLUMN totp_last_used_step BIGINT,
+    ADD CONSTRAINT account_totp_enabled_secret_check CHECK (NOT totp_enabled OR totp_secret IS NOT NULL);
+
+-- Single-use recovery codes, stored as SHA-256 digests
+CREATE TABLE account_recovery_code (
+    account_recovery_code_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    account_id                  UUID NOT NULL REFERENCES account(account_id) ON DELETE CASCADE,
+    code_hash                   TEXT NOT NULL CHECK (octet_length(code_hash) = 64),
+    used_at                     TIMESTAMP NULL,
+    created_at                  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX account_recovery_code_uniq_idx ON account_recovery_code (account_id, code_hash);
+
+-- Allow superusers to require 2FA for every account linked to a publisher
+ALTER TABLE publisher
+    ADD COLUMN require_two_factor BOOLEAN NOT NULL DEFAULT False;
@@ -0,0 +1,9 @@
+-- GraphQL documents registered ahead of time, which clients can refer to by their SHA-256 hash
+CREATE TABLE persisted_query (
+    persisted_query_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    query_hash          TEXT NOT NULL CHECK (query_hash ~ '^[0-9a-f]{64}$'),
+    query               TEXT NOT NULL CHECK (octet_length(query) >= 1),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX persisted_query_hash_uniq_idx ON persisted_query (query_hash);
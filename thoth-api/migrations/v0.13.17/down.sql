@@ -0,0 +1,14 @@
+DROP TRIGGER IF EXISTS sync_titles_and_abstracts ON work;
+DROP FUNCTION IF EXISTS work_sync_titles_and_abstracts();
+DROP FUNCTION IF EXISTS work_canonical_language(UUID);
+
+DROP TABLE work_abstract_history;
+DROP TABLE work_abstract;
+DROP FUNCTION IF EXISTS work_abstract_set_canonical();
+DROP FUNCTION IF EXISTS work_abstract_sync_work();
+DROP TYPE abstract_type;
+
+DROP TABLE work_title_history;
+DROP TABLE work_title;
+DROP FUNCTION IF EXISTS work_title_set_canonical();
+DROP FUNCTION IF EXISTS work_title_sync_work();
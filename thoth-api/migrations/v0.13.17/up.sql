@@ -0,0 +1,209 @@
+-- Titles and abstracts of a work in each of the languages it is described in.
+-- The canonical title and abstracts are kept in sync with the corresponding work fields.
+
+CREATE TABLE work_title (
+    work_title_id       UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    language_code       language_code NOT NULL,
+    full_title          TEXT NOT NULL CHECK (octet_length(full_title) >= 1),
+    title               TEXT NOT NULL CHECK (octet_length(title) >= 1),
+    subtitle            TEXT CHECK (octet_length(subtitle) >= 1),
+    canonical           BOOLEAN NOT NULL DEFAULT False,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('work_title');
+
+CREATE UNIQUE INDEX work_title_uniq_language_idx ON work_title (work_id, language_code);
+CREATE UNIQUE INDEX work_title_uniq_canonical_idx ON work_title (work_id) WHERE canonical;
+
+CREATE TABLE work_title_history (
+    work_title_history_id   UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_title_id           UUID NOT NULL REFERENCES work_title(work_title_id) ON DELETE CASCADE,
+    account_id              UUID NOT NULL REFERENCES account(account_id),
+    data                    JSONB NOT NULL,
+    timestamp               TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TYPE abstract_type AS ENUM (
+    'short',
+    'long'
+);
+
+CREATE TABLE work_abstract (
+    work_abstract_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    language_code       language_code NOT NULL,
+    abstract_type       abstract_type NOT NULL,
+    content             TEXT NOT NULL CHECK (octet_length(content) >= 1),
+    canonical           BOOLEAN NOT NULL DEFAULT False,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('work_abstract');
+
+CREATE UNIQUE INDEX work_abstract_uniq_language_idx ON work_abstract (work_id, abstract_type, language_code);
+CREATE UNIQUE INDEX work_abstract_uniq_canonical_idx ON work_abstract (work_id, abstract_type) WHERE canonical;
+
+CREATE TABLE work_abstract_history (
+    work_abstract_history_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_abstract_id            UUID NOT NULL REFERENCES work_abstract(work_abstract_id) ON DELETE CASCADE,
+    account_id                  UUID NOT NULL REFERENCES account(account_id),
+    data                        JSONB NOT NULL,
+    timestamp                   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Copy existing titles and abstracts, using the work's main language (English if unknown)
+
+WITH work_language AS (
+    SELECT DISTINCT ON (w.work_id) w.work_id, COALESCE(l.language_code, 'eng') AS language_code
+    FROM work w
+        LEFT JOIN language l ON l.work_id = w.work_id AND l.main_language
+    ORDER BY w.work_id, l.language_relation
+)
+INSERT INTO work_title (work_id, language_code, full_title, title, subtitle, canonical)
+    SELECT w.work_id, wl.language_code, w.full_title, w.title, w.subtitle, True
+    FROM work w JOIN work_language wl USING (work_id);
+
+WITH work_language AS (
+    SELECT work_id, language_code FROM work_title WHERE canonical
+)
+INSERT INTO work_abstract (work_id, language_code, abstract_type, content, canonical)
+    SELECT w.work_id, wl.language_code, 'short'::abstract_type, w.short_abstract, True
+    FROM work w JOIN work_language wl USING (work_id)
+    WHERE w.short_abstract IS NOT NULL
+    UNION ALL
+    SELECT w.work_id, wl.language_code, 'long'::abstract_type, w.long_abstract, True
+    FROM work w JOIN work_language wl USING (work_id)
+    WHERE w.long_abstract IS NOT NULL;
+
+-- Only one title, and one abstract of each type, can be canonical: flagging a new one demotes
+-- the previous one, and its values are copied onto the work.
+
+CREATE OR REPLACE FUNCTION work_title_set_canonical() RETURNS trigger AS $$
+BEGIN
+    IF NEW.canonical THEN
+        UPDATE work_title
+        SET canonical = False
+        WHERE work_id = NEW.work_id AND work_title_id <> NEW.work_title_id AND canonical;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_canonical BEFORE INSERT OR UPDATE ON work_title
+    FOR EACH ROW EXECUTE PROCEDURE work_title_set_canonical();
+
+CREATE OR REPLACE FUNCTION work_title_sync_work() RETURNS trigger AS $$
+BEGIN
+    -- Changes originating from the work itself are already in sync
+    IF NEW.canonical AND pg_trigger_depth() = 1 THEN
+        UPDATE work
+        SET full_title = NEW.full_title, title = NEW.title, subtitle = NEW.subtitle
+        WHERE work_id = NEW.work_id
+            AND (full_title, title, subtitle) IS DISTINCT FROM (NEW.full_title, NEW.title, NEW.subtitle);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER sync_work AFTER INSERT OR UPDATE ON work_title
+    FOR EACH ROW EXECUTE PROCEDURE work_title_sync_work();
+
+CREATE OR REPLACE FUNCTION work_abstract_set_canonical() RETURNS trigger AS $$
+BEGIN
+    IF NEW.canonical THEN
+        UPDATE work_abstract
+        SET canonical = False
+        WHERE work_id = NEW.work_id AND abstract_type = NEW.abstract_type
+            AND work_abstract_id <> NEW.work_abstract_id AND canonical;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_canonical BEFORE INSERT OR UPDATE ON work_abstract
+    FOR EACH ROW EXECUTE PROCEDURE work_abstract_set_canonical();
+
+CREATE OR REPLACE FUNCTION work_abstract_sync_work() RETURNS trigger AS $$
+BEGIN
+    IF NEW.canonical AND pg_trigger_depth() = 1 THEN
+        IF NEW.abstract_type = 'short' THEN
+            UPDATE work SET short_abstract = NEW.content
+            WHERE work_id = NEW.work_id AND short_abstract IS DISTINCT FROM NEW.content;
+        ELSE
+            UPDATE work SET long_abstract = NEW.content
+            WHERE work_id = NEW.work_id AND long_abstract IS DISTINCT FROM NEW.content;
+        END IF;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER sync_work AFTER INSERT OR UPDATE ON work_abstract
+    FOR EACH ROW EXECUTE PROCEDURE work_abstract_sync_work();
+
+-- Editing the work's title or abstracts directly updates the canonical entries, and works
+-- without a canonical entry (e.g. newly created ones) get one from their own fields
+
+CREATE OR REPLACE FUNCTION work_canonical_language(work_uuid UUID) RETURNS language_code AS $$
+    SELECT COALESCE(
+        (SELECT language_code FROM work_title WHERE work_id = work_uuid AND canonical),
+        (SELECT language_code FROM language WHERE work_id = work_uuid AND main_language
+            ORDER BY language_relation LIMIT 1),
+        'eng'
+    );
+$$ LANGUAGE sql STABLE;
+
+CREATE OR REPLACE FUNCTION work_sync_titles_and_abstracts() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'UPDATE' AND pg_trigger_depth() = 1 THEN
+        UPDATE work_title
+        SET full_title = NEW.full_title, title = NEW.title, subtitle = NEW.subtitle
+        WHERE work_id = NEW.work_id AND canonical
+            AND (full_title, title, subtitle) IS DISTINCT FROM (NEW.full_title, NEW.title, NEW.subtitle);
+        -- Abstracts removed from the work are no longer canonical, rather than deleted
+        UPDATE work_abstract
+        SET content = COALESCE(NEW.short_abstract, content), canonical = NEW.short_abstract IS NOT NULL
+        WHERE work_id = NEW.work_id AND abstract_type = 'short' AND canonical
+            AND NEW.short_abstract IS DISTINCT FROM OLD.short_abstract;
+        UPDATE work_abstract
+        SET content = COALESCE(NEW.long_abstract, content), canonical = NEW.long_abstract IS NOT NULL
+        WHERE work_id = NEW.work_id AND abstract_type = 'long' AND canonical
+            AND NEW.long_abstract IS DISTINCT FROM OLD.long_abstract;
+    END IF;
+    IF pg_trigger_depth() = 1 THEN
+        -- An entry already recorded in the same language becomes the canonical one
+        INSERT INTO work_title (work_id, language_code, full_title, title, subtitle, canonical)
+            SELECT NEW.work_id, work_canonical_language(NEW.work_id), NEW.full_title, NEW.title, NEW.subtitle, True
+            WHERE NOT EXISTS (SELECT 1 FROM work_title WHERE work_id = NEW.work_id AND canonical)
+            ON CONFLICT (work_id, language_code) DO UPDATE
+            SET full_title = EXCLUDED.full_title, title = EXCLUDED.title,
+                subtitle = EXCLUDED.subtitle, canonical = True;
+        INSERT INTO work_abstract (work_id, language_code, abstract_type, content, canonical)
+            SELECT NEW.work_id, work_canonical_language(NEW.work_id), 'short'::abstract_type, NEW.short_abstract, True
+            WHERE NEW.short_abstract IS NOT NULL AND NOT EXISTS (
+                SELECT 1 FROM work_abstract WHERE work_id = NEW.work_id AND abstract_type = 'short' AND canonical
+            )
+            ON CONFLICT (work_id, abstract_type, language_code) DO UPDATE
+            SET content = EXCLUDED.content, canonical = True;
+        INSERT INTO work_abstract (work_id, language_code, abstract_type, content, canonical)
+            SELECT NEW.work_id, work_canonical_language(NEW.work_id), 'long'::abstract_type, NEW.long_abstract, True
+            WHERE NEW.long_abstract IS NOT NULL AND NOT EXISTS (
+                SELECT 1 FROM work_abstract WHERE work_id = NEW.work_id AND abstract_type = 'long' AND canonical
+            )
+            ON CONFLICT (work_id, abstract_type, language_code) DO UPDATE
+            SET content = EXCLUDED.content, canonical = True;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER sync_titles_and_abstracts AFTER INSERT OR UPDATE ON work
+    FOR EACH ROW EXECUTE PROCEDURE work_sync_titles_and_abstracts();
+
+CREATE TRIGGER set_work_updated_at_with_relations AFTER INSERT OR UPDATE OR DELETE ON work_title
+    FOR EACH ROW EXECUTE PROCEDURE work_updated_at_with_relations();
+
+CREATE TRIGGER set_work_updated_at_with_relations AFTER INSERT OR UPDATE OR DELETE ON work_abstract
+    FOR EACH ROW EXECUTE PROCEDURE work_updated_at_with_relations();
@@ -0,0 +1,7 @@
+DROP TABLE publication_identifier_history;
+DROP TABLE publication_identifier;
+
+DROP TABLE work_identifier_history;
+DROP TABLE work_identifier;
+
+DROP TYPE identifier_type;
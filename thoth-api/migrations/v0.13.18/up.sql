@@ -0,0 +1,52 @@
+-- Additional persistent identifiers for works and publications.
+-- Values are validated and standardised by the API before being stored.
+
+CREATE TYPE identifier_type AS ENUM (
+    'handle',
+    'ark',
+    'urn_nbn',
+    'istc',
+    'wikidata',
+    'sku',
+    'gtin'
+);
+
+CREATE TABLE work_identifier (
+    work_identifier_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    identifier_type     identifier_type NOT NULL CHECK (identifier_type NOT IN ('sku', 'gtin')),
+    identifier_value    TEXT NOT NULL CHECK (octet_length(identifier_value) >= 1),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('work_identifier');
+
+CREATE UNIQUE INDEX work_identifier_uniq_idx ON work_identifier (work_id, identifier_type, identifier_value);
+
+CREATE TABLE work_identifier_history (
+    work_identifier_history_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_identifier_id          UUID NOT NULL REFERENCES work_identifier(work_identifier_id) ON DELETE CASCADE,
+    account_id                  UUID NOT NULL REFERENCES account(account_id),
+    data                        JSONB NOT NULL,
+    timestamp                   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE publication_identifier (
+    publication_identifier_id   UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publication_id              UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    identifier_type             identifier_type NOT NULL CHECK (identifier_type NOT IN ('istc', 'wikidata')),
+    identifier_value            TEXT NOT NULL CHECK (octet_length(identifier_value) >= 1),
+    created_at                  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at                  TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('publication_identifier');
+
+CREATE UNIQUE INDEX publication_identifier_uniq_idx ON publication_identifier (publication_id, identifier_type, identifier_value);
+
+CREATE TABLE publication_identifier_history (
+    publication_identifier_history_id   UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publication_identifier_id           UUID NOT NULL REFERENCES publication_identifier(publication_identifier_id) ON DELETE CASCADE,
+    account_id                          UUID NOT NULL REFERENCES account(account_id),
+    data                                JSONB NOT NULL,
+    timestamp                           TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
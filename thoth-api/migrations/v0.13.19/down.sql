@@ -0,0 +1,15 @@
+DROP TABLE alternative_name_history;
+DROP TABLE alternative_name;
+
+DROP TYPE name_type;
+
+DROP INDEX IF EXISTS contributor_isni_uniq_idx;
+DROP INDEX IF EXISTS contributor_viaf_uniq_idx;
+DROP INDEX IF EXISTS contributor_wikidata_uniq_idx;
+DROP INDEX IF EXISTS contributor_lcnaf_uniq_idx;
+
+ALTER TABLE contributor
+    DROP COLUMN isni,
+    DROP COLUMN viaf,
+    DROP COLUMN wikidata,
+    DROP COLUMN lcnaf;
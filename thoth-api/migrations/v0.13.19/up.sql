@@ -0,0 +1,49 @@
+-- Authority identifiers for contributors. Check characters are validated by the API;
+-- the constraints below only enforce the standardised format.
+
+ALTER TABLE contributor
+    ADD COLUMN isni TEXT CHECK (isni ~ '^\d{15}[\dX]$'),
+    ADD COLUMN viaf TEXT CHECK (viaf ~ '^[1-9]\d{1,21}$'),
+    ADD COLUMN wikidata TEXT CHECK (wikidata ~ '^Q[1-9]\d*$'),
+    ADD COLUMN lcnaf TEXT CHECK (lcnaf ~ '^n[bors]?\d{8}(\d{2})?$');
+
+CREATE UNIQUE INDEX contributor_isni_uniq_idx ON contributor(isni);
+CREATE UNIQUE INDEX contributor_viaf_uniq_idx ON contributor(viaf);
+CREATE UNIQUE INDEX contributor_wikidata_uniq_idx ON contributor(wikidata);
+CREATE UNIQUE INDEX contributor_lcnaf_uniq_idx ON contributor(lcnaf);
+
+-- Alternative forms of a contributor's name, such as pseudonyms or transliterations
+
+CREATE TYPE name_type AS ENUM (
+    'variant',
+    'pseudonym',
+    'earlier',
+    'real',
+    'transliterated',
+    'later'
+);
+
+CREATE TABLE alternative_name (
+    alternative_name_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    contributor_id      UUID NOT NULL REFERENCES contributor(contributor_id) ON DELETE CASCADE,
+    name_type           name_type NOT NULL DEFAULT 'variant',
+    first_name          TEXT CHECK (octet_length(first_name) >= 1),
+    last_name           TEXT NOT NULL CHECK (octet_length(last_name) >= 1),
+    full_name           TEXT NOT NULL CHECK (octet_length(full_name) >= 1),
+    language_code       language_code,
+    -- ISO 15924 four-letter script code, e.g. Cyrl or Hans
+    script_code         TEXT CHECK (script_code ~ '^[A-Z][a-z]{3}$'),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('alternative_name');
+
+CREATE INDEX idx_alternative_name_contributor_id ON alternative_name (contributor_id);
+
+CREATE TABLE alternative_name_history (
+    alternative_name_history_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    alternative_name_id         UUID NOT NULL REFERENCES alternative_name(alternative_name_id) ON DELETE CASCADE,
+    account_id                  UUID NOT NULL REFERENCES account(account_id),
+    data                        JSONB NOT NULL,
+    timestamp                   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,10 @@
+DROP TABLE institution_identifier_mapping;
+
+DROP INDEX IF EXISTS institution_crossref_funder_id_uniq_idx;
+DROP INDEX IF EXISTS institution_isni_uniq_idx;
+DROP INDEX IF EXISTS institution_ringgold_uniq_idx;
+
+ALTER TABLE institution
+    DROP COLUMN crossref_funder_id,
+    DROP COLUMN isni,
+    DROP COLUMN ringgold;
@@ -0,0 +1,32 @@
+-- Additional institution identifiers. Check characters are validated by the API;
+-- the constraints below only enforce the standardised format.
+
+ALTER TABLE institution
+    -- Crossref Funder Registry identifier, stored without its 10.13039 DOI prefix
+    ADD COLUMN crossref_funder_id TEXT CHECK (crossref_funder_id ~ '^[1-9]\d{8,11}$'),
+    ADD COLUMN isni TEXT CHECK (isni ~ '^\d{15}[\dX]$'),
+    ADD COLUMN ringgold TEXT CHECK (ringgold ~ '^[1-9]\d{0,6}$');
+
+CREATE UNIQUE INDEX institution_crossref_funder_id_uniq_idx ON institution(crossref_funder_id);
+CREATE UNIQUE INDEX institution_isni_uniq_idx ON institution(isni);
+CREATE UNIQUE INDEX institution_ringgold_uniq_idx ON institution(ringgold);
+
+-- Locally imported crosswalk between institution identifier schemes, used to resolve
+-- identifiers that have not been recorded against an institution
+
+CREATE TABLE institution_identifier_mapping (
+    institution_identifier_mapping_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    ror                               TEXT CHECK (ror ~ '^https:\/\/ror\.org\/0[a-hjkmnp-z0-9]{6}\d{2}$'),
+    crossref_funder_id                TEXT CHECK (crossref_funder_id ~ '^[1-9]\d{8,11}$'),
+    isni                              TEXT CHECK (isni ~ '^\d{15}[\dX]$'),
+    ringgold                          TEXT CHECK (ringgold ~ '^[1-9]\d{0,6}$'),
+    created_at                        TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT institution_identifier_mapping_identifier_check CHECK (
+        num_nonnulls(ror, crossref_funder_id, isni, ringgold) >= 2
+    )
+);
+
+CREATE INDEX idx_institution_identifier_mapping_ror ON institution_identifier_mapping (ror);
+CREATE INDEX idx_institution_identifier_mapping_crossref_funder_id ON institution_identifier_mapping (crossref_funder_id);
+CREATE INDEX idx_institution_identifier_mapping_isni ON institution_identifier_mapping (isni);
+CREATE INDEX idx_institution_identifier_mapping_ringgold ON institution_identifier_mapping (ringgold);
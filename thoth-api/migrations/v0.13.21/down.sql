@@ -0,0 +1,5 @@
+DROP TABLE sales_rights_history;
+DROP TABLE sales_rights;
+
+DROP TYPE sales_rights_type;
+DROP TYPE sales_restriction_type;
@@ -0,0 +1,55 @@
+-- Territorial sales rights of a publication, expressed as ONIX codelist 46 rights types
+-- applied to ISO 3166-1 countries and ONIX codelist 49 regions.
+
+CREATE TYPE sales_rights_type AS ENUM (
+    'for_sale_with_exclusive_rights',
+    'for_sale_with_non_exclusive_rights',
+    'not_for_sale'
+);
+
+-- Restrictions on the outlets through which a publication may be sold (ONIX codelist 71)
+CREATE TYPE sales_restriction_type AS ENUM (
+    'unspecified',
+    'internal_publisher_use_only',
+    'retailer_exclusive',
+    'retailer_own_brand'
+);
+
+CREATE TABLE sales_rights (
+    sales_rights_id     UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publication_id      UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    sales_rights_type   sales_rights_type NOT NULL,
+    countries_included  country_code[] NOT NULL DEFAULT '{}',
+    countries_excluded  country_code[] NOT NULL DEFAULT '{}',
+    -- ONIX region codes, e.g. WORLD, ECZ, or ISO 3166-2 subdivisions such as GB-SCT
+    regions_included    TEXT[] NOT NULL DEFAULT '{}' CHECK (array_to_string(regions_included, ' ') ~ '^((WORLD|ECZ|[A-Z]{2}-[A-Z0-9]{1,3})( |$))*$'),
+    regions_excluded    TEXT[] NOT NULL DEFAULT '{}' CHECK (array_to_string(regions_excluded, ' ') ~ '^(([A-Z]{2}-[A-Z0-9]{1,3})( |$))*$'),
+    sales_restriction_type  sales_restriction_type,
+    sales_restriction_note  TEXT CHECK (octet_length(sales_restriction_note) >= 1),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- Rights must apply somewhere, and exclusions only make sense within an included region
+    CONSTRAINT sales_rights_territory_check CHECK (
+        cardinality(countries_included) + cardinality(regions_included) > 0
+    ),
+    CONSTRAINT sales_rights_exclusion_check CHECK (
+        cardinality(regions_included) > 0
+        OR cardinality(countries_excluded) + cardinality(regions_excluded) = 0
+    ),
+    -- A note describes a restriction, and an unspecified restriction must be described
+    CONSTRAINT sales_rights_restriction_check CHECK (
+        (sales_restriction_type IS NOT NULL OR sales_restriction_note IS NULL)
+        AND (sales_restriction_type IS DISTINCT FROM 'unspecified' OR sales_restriction_note IS NOT NULL)
+    )
+);
+SELECT diesel_manage_updated_at('sales_rights');
+
+CREATE INDEX idx_sales_rights_publication_id ON sales_rights (publication_id);
+
+CREATE TABLE sales_rights_history (
+    sales_rights_history_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    sales_rights_id         UUID NOT NULL REFERENCES sales_rights(sales_rights_id) ON DELETE CASCADE,
+    account_id              UUID NOT NULL REFERENCES account(account_id),
+    data                    JSONB NOT NULL,
+    timestamp               TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
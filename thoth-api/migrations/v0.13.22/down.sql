@@ -0,0 +1,32 @@
+ALTER TABLE price DROP CONSTRAINT price_publication_id_currency_code_price_type_uniq;
+
+-- Keep a single price per currency, preferring the undated RRP including tax
+DELETE FROM price
+WHERE price_id NOT IN (
+    SELECT DISTINCT ON (publication_id, currency_code) price_id
+    FROM price
+    ORDER BY publication_id, currency_code,
+        (price_type = 'rrp_including_tax') DESC,
+        (price_effective_from IS NULL) DESC,
+        created_at
+);
+
+ALTER TABLE price ADD CONSTRAINT price_publication_id_currency_code_uniq
+    UNIQUE (publication_id, currency_code);
+
+ALTER TABLE price
+    DROP CONSTRAINT price_exclusion_check,
+    DROP CONSTRAINT price_effective_dates_check,
+    DROP COLUMN price_type,
+    DROP COLUMN price_qualifier,
+    DROP COLUMN countries_included,
+    DROP COLUMN countries_excluded,
+    DROP COLUMN regions_included,
+    DROP COLUMN regions_excluded,
+    DROP COLUMN tax_rate_percent,
+    DROP COLUMN discount_code,
+    DROP COLUMN price_effective_from,
+    DROP COLUMN price_effective_until;
+
+DROP TYPE price_type;
+DROP TYPE price_qualifier;
@@ -0,0 +1,56 @@
+-- Price types (ONIX codelist 58), qualifiers (ONIX codelist 59), territories, tax rates,
+-- discount codes and effective dates, so that a publication can hold several prices per currency.
+
+CREATE TYPE price_type AS ENUM (
+    'rrp_excluding_tax',
+    'rrp_including_tax',
+    'fixed_retail_price_excluding_tax',
+    'fixed_retail_price_including_tax',
+    'suppliers_net_price_excluding_tax',
+    'agency_price_excluding_tax',
+    'agency_price_including_tax'
+);
+
+CREATE TYPE price_qualifier AS ENUM (
+    'consumer_price',
+    'corporate_price',
+    'library_price',
+    'education_price'
+);
+
+-- Existing prices were exported as RRP including tax
+ALTER TABLE price
+    ADD COLUMN price_type            price_type NOT NULL DEFAULT 'rrp_including_tax',
+    ADD COLUMN price_qualifier       price_qualifier,
+    ADD COLUMN countries_included    country_code[] NOT NULL DEFAULT '{}',
+    ADD COLUMN countries_excluded    country_code[] NOT NULL DEFAULT '{}',
+    ADD COLUMN regions_included      TEXT[] NOT NULL DEFAULT '{}' CHECK (array_to_string(regions_included, ' ') ~ '^((WORLD|ECZ|[A-Z]{2}-[A-Z0-9]{1,3})( |$))*$'),
+    ADD COLUMN regions_excluded      TEXT[] NOT NULL DEFAULT '{}' CHECK (array_to_string(regions_excluded, ' ') ~ '^(([A-Z]{2}-[A-Z0-9]{1,3})( |$))*$'),
+    ADD COLUMN tax_rate_percent      double precision CHECK (tax_rate_percent >= 0.0 AND tax_rate_percent <= 100.0),
+    ADD COLUMN discount_code         TEXT CHECK (octet_length(discount_code) >= 1),
+    ADD COLUMN price_effective_from  DATE,
+    ADD COLUMN price_effective_until DATE,
+    -- Exclusions only make sense within an included region
+    ADD CONSTRAINT price_exclusion_check CHECK (
+        cardinality(regions_included) > 0
+        OR cardinality(countries_excluded) + cardinality(regions_excluded) = 0
+    ),
+    ADD CONSTRAINT price_effective_dates_check CHECK (
+        price_effective_from IS NULL
+        OR price_effective_until IS NULL
+        OR price_effective_from <= price_effective_until
+    );
+
+-- A publication may now have several prices in the same currency, as long as they differ
+-- in type, qualifier, territory or effective date
+ALTER TABLE price DROP CONSTRAINT price_publication_id_currency_code_uniq;
+ALTER TABLE price ADD CONSTRAINT price_publication_id_currency_code_price_type_uniq
+    UNIQUE NULLS NOT DISTINCT (
+        publication_id,
+        currency_code,
+        price_type,
+        price_qualifier,
+        countries_included,
+        regions_included,
+        price_effective_from
+    );
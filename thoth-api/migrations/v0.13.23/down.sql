@@ -0,0 +1,16 @@
+ALTER TABLE publication
+    DROP CONSTRAINT publication_wcag_conformance_check,
+    DROP CONSTRAINT publication_physical_no_accessibility,
+    DROP COLUMN accessibility_summary,
+    DROP COLUMN accessibility_certifier,
+    DROP COLUMN wcag_level,
+    DROP COLUMN wcag_version,
+    DROP COLUMN accessibility_standard,
+    DROP COLUMN accessibility_hazards,
+    DROP COLUMN accessibility_features;
+
+DROP TYPE wcag_level;
+DROP TYPE wcag_version;
+DROP TYPE accessibility_standard;
+DROP TYPE accessibility_hazard;
+DROP TYPE accessibility_feature;
@@ -0,0 +1,73 @@
+-- Accessibility metadata of digital publications, expressed as ONIX codelist 196
+-- accessibility details and ONIX codelist 143 e-publication hazards.
+
+CREATE TYPE accessibility_feature AS ENUM (
+    'table_of_contents',
+    'index_navigation',
+    'reading_order',
+    'short_alternative_text',
+    'full_alternative_text',
+    'math_ml',
+    'chem_ml',
+    'print_page_numbers',
+    'synchronised_audio',
+    'text_to_speech_hinting',
+    'high_contrast_display',
+    'high_contrast_audio',
+    'structural_navigation',
+    'aria_roles',
+    'display_transformability'
+);
+
+CREATE TYPE accessibility_hazard AS ENUM (
+    'none',
+    'flashing',
+    'no_flashing_hazard',
+    'unknown_flashing_hazard',
+    'sound',
+    'no_sound_hazard',
+    'unknown_sound_hazard',
+    'motion_simulation',
+    'no_motion_simulation_hazard',
+    'unknown_motion_simulation_hazard'
+);
+
+CREATE TYPE accessibility_standard AS ENUM (
+    'epub_accessibility_1_0_a',
+    'epub_accessibility_1_0_aa',
+    'epub_accessibility_1_1'
+);
+
+CREATE TYPE wcag_version AS ENUM (
+    'wcag_2_0',
+    'wcag_2_1',
+    'wcag_2_2'
+);
+
+CREATE TYPE wcag_level AS ENUM (
+    'a',
+    'aa',
+    'aaa'
+);
+
+ALTER TABLE publication
+    ADD COLUMN accessibility_features accessibility_feature[] NOT NULL DEFAULT '{}',
+    ADD COLUMN accessibility_hazards accessibility_hazard[] NOT NULL DEFAULT '{}',
+    ADD COLUMN accessibility_standard accessibility_standard,
+    ADD COLUMN wcag_version wcag_version,
+    ADD COLUMN wcag_level wcag_level,
+    ADD COLUMN accessibility_certifier TEXT CHECK (octet_length(accessibility_certifier) >= 1),
+    ADD COLUMN accessibility_summary TEXT CHECK (octet_length(accessibility_summary) >= 1),
+    ADD CONSTRAINT publication_physical_no_accessibility CHECK
+        ((cardinality(accessibility_features) = 0
+            AND cardinality(accessibility_hazards) = 0
+            AND accessibility_standard IS NULL
+            AND wcag_version IS NULL AND wcag_level IS NULL
+            AND accessibility_certifier IS NULL
+            AND accessibility_summary IS NULL)
+        OR (publication_type <> 'Paperback' AND publication_type <> 'Hardback')),
+    -- WCAG conformance is stated as a version and level together, and EPUB Accessibility 1.1
+    -- conformance is only meaningful alongside the WCAG conformance it builds upon
+    ADD CONSTRAINT publication_wcag_conformance_check CHECK
+        ((wcag_version IS NULL) = (wcag_level IS NULL)
+        AND (accessibility_standard IS DISTINCT FROM 'epub_accessibility_1_1' OR wcag_version IS NOT NULL));
@@ -0,0 +1,15 @@
+ALTER TABLE publication
+    DROP CONSTRAINT publication_non_physical_no_print_attributes,
+    DROP COLUMN carton_quantity,
+    DROP COLUMN cover_finish,
+    DROP COLUMN page_count,
+    DROP COLUMN trim_category,
+    DROP COLUMN interior_colour,
+    DROP COLUMN paper_type,
+    DROP COLUMN binding_style;
+
+DROP TYPE cover_finish;
+DROP TYPE trim_category;
+DROP TYPE interior_colour;
+DROP TYPE paper_type;
+DROP TYPE binding_style;
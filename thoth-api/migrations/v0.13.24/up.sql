@@ -0,0 +1,64 @@
+-- Print production attributes of physical publications, as requested by
+-- print-on-demand and distribution partners.
+
+CREATE TYPE binding_style AS ENUM (
+    'perfect_bound',
+    'section_sewn',
+    'saddle_stitched',
+    'library_bound',
+    'comb_bound',
+    'wire_o_bound',
+    'spiral_bound',
+    'lay_flat'
+);
+
+CREATE TYPE paper_type AS ENUM (
+    'white',
+    'cream',
+    'groundwood',
+    'coated_matte',
+    'coated_gloss'
+);
+
+CREATE TYPE interior_colour AS ENUM (
+    'black_and_white',
+    'standard_colour',
+    'premium_colour'
+);
+
+CREATE TYPE trim_category AS ENUM (
+    'mass_market',
+    'us_trade',
+    'digest',
+    'a_format',
+    'b_format',
+    'uk_trade',
+    'tall_rack',
+    'custom'
+);
+
+CREATE TYPE cover_finish AS ENUM (
+    'gloss_laminate',
+    'matte_laminate',
+    'soft_touch_laminate',
+    'uncoated',
+    'cloth'
+);
+
+ALTER TABLE publication
+    ADD COLUMN binding_style binding_style,
+    ADD COLUMN paper_type paper_type,
+    ADD COLUMN interior_colour interior_colour,
+    ADD COLUMN trim_category trim_category,
+    ADD COLUMN page_count INTEGER CHECK (page_count > 0),
+    ADD COLUMN cover_finish cover_finish,
+    ADD COLUMN carton_quantity INTEGER CHECK (carton_quantity > 0),
+    ADD CONSTRAINT publication_non_physical_no_print_attributes CHECK
+        ((binding_style IS NULL
+            AND paper_type IS NULL
+            AND interior_colour IS NULL
+            AND trim_category IS NULL
+            AND page_count IS NULL
+            AND cover_finish IS NULL
+            AND carton_quantity IS NULL)
+        OR publication_type = 'Paperback' OR publication_type = 'Hardback');
@@ -0,0 +1,17 @@
+DROP TABLE usage_constraint_history;
+DROP TABLE usage_constraint;
+
+DROP TYPE usage_unit;
+DROP TYPE usage_status;
+DROP TYPE usage_type;
+
+ALTER TABLE publication
+    DROP CONSTRAINT publication_physical_no_digital_attributes,
+    DROP CONSTRAINT publication_technical_protections_none_check,
+    DROP COLUMN layout,
+    DROP COLUMN technical_protections,
+    DROP COLUMN file_size_kb,
+    DROP COLUMN format_version;
+
+DROP TYPE digital_layout;
+DROP TYPE technical_protection;
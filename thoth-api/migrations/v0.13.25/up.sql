@@ -0,0 +1,89 @@
+-- Technical attributes of digital publications and the usage constraints applied to them,
+-- as required by ebook aggregators.
+
+CREATE TYPE technical_protection AS ENUM (
+    'none',
+    'drm',
+    'digital_watermarking',
+    'adobe_drm',
+    'apple_drm',
+    'oma_drm',
+    'readium_lcp',
+    'sony_drm'
+);
+
+CREATE TYPE digital_layout AS ENUM (
+    'reflowable',
+    'fixed_format'
+);
+
+ALTER TABLE publication
+    ADD COLUMN format_version TEXT CHECK (octet_length(format_version) >= 1),
+    ADD COLUMN file_size_kb INTEGER CHECK (file_size_kb > 0),
+    ADD COLUMN technical_protections technical_protection[] NOT NULL DEFAULT '{}',
+    ADD COLUMN layout digital_layout,
+    ADD CONSTRAINT publication_technical_protections_none_check CHECK
+        (NOT ('none' = ANY(technical_protections)) OR cardinality(technical_protections) = 1),
+    ADD CONSTRAINT publication_physical_no_digital_attributes CHECK
+        ((format_version IS NULL
+            AND file_size_kb IS NULL
+            AND cardinality(technical_protections) = 0
+            AND layout IS NULL)
+        OR (publication_type <> 'Paperback' AND publication_type <> 'Hardback'));
+
+-- Usage constraints of a digital publication, expressed with ONIX codelists 145, 146 and 147
+
+CREATE TYPE usage_type AS ENUM (
+    'preview',
+    'print',
+    'copy_paste',
+    'share',
+    'text_to_speech',
+    'lend',
+    'time_limited_licence'
+);
+
+CREATE TYPE usage_status AS ENUM (
+    'permitted_unlimited',
+    'permitted_subject_to_limit',
+    'prohibited'
+);
+
+CREATE TYPE usage_unit AS ENUM (
+    'copies',
+    'characters',
+    'words',
+    'pages',
+    'percentage',
+    'devices',
+    'concurrent_users',
+    'days',
+    'weeks',
+    'months'
+);
+
+CREATE TABLE usage_constraint (
+    usage_constraint_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publication_id      UUID NOT NULL REFERENCES publication(publication_id) ON DELETE CASCADE,
+    usage_type          usage_type NOT NULL,
+    usage_status        usage_status NOT NULL,
+    usage_limit         INTEGER CHECK (usage_limit > 0),
+    usage_unit          usage_unit,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- A limit, with its unit, is given if and only if usage is permitted subject to it
+    CONSTRAINT usage_constraint_limit_check CHECK (
+        ((usage_status = 'permitted_subject_to_limit') = (usage_limit IS NOT NULL))
+        AND ((usage_limit IS NULL) = (usage_unit IS NULL))
+    ),
+    CONSTRAINT usage_constraint_publication_id_usage_type_uniq UNIQUE (publication_id, usage_type)
+);
+SELECT diesel_manage_updated_at('usage_constraint');
+
+CREATE TABLE usage_constraint_history (
+    usage_constraint_history_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    usage_constraint_id         UUID NOT NULL REFERENCES usage_constraint(usage_constraint_id) ON DELETE CASCADE,
+    account_id                  UUID NOT NULL REFERENCES account(account_id),
+    data                        JSONB NOT NULL,
+    timestamp                   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
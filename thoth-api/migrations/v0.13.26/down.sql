@@ -0,0 +1,4 @@
+DROP TABLE prize_history;
+DROP TABLE prize;
+
+DROP TYPE prize_code;
@@ -0,0 +1,37 @@
+-- Prizes and awards won, or shortlisted for, by a work, with achievements
+-- expressed as ONIX codelist 41 prize or award achievement codes.
+
+CREATE TYPE prize_code AS ENUM (
+    'winner',
+    'runner_up',
+    'commended',
+    'short_listed',
+    'long_listed',
+    'joint_winner',
+    'nominated'
+);
+
+CREATE TABLE prize (
+    prize_id        UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id         UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    prize_name      TEXT NOT NULL CHECK (octet_length(prize_name) >= 1),
+    prize_year      INTEGER CHECK (prize_year BETWEEN 1000 AND 9999),
+    prize_country   country_code,
+    prize_code      prize_code NOT NULL,
+    prize_statement TEXT CHECK (octet_length(prize_statement) >= 1),
+    -- Members of the jury or judging panel, distinct from the jury's statement about the work
+    prize_jury      TEXT CHECK (octet_length(prize_jury) >= 1),
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('prize');
+
+CREATE INDEX idx_prize_work_id ON prize (work_id);
+
+CREATE TABLE prize_history (
+    prize_history_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    prize_id            UUID NOT NULL REFERENCES prize(prize_id) ON DELETE CASCADE,
+    account_id          UUID NOT NULL REFERENCES account(account_id),
+    data                JSONB NOT NULL,
+    timestamp           TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
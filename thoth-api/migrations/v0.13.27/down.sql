@@ -0,0 +1,5 @@
+DROP TABLE text_content_history;
+DROP TABLE text_content;
+
+DROP TYPE content_audience;
+DROP TYPE text_content_type;
@@ -0,0 +1,54 @@
+-- Promotional and critical text about a work, such as review quotes and endorsements,
+-- typed using ONIX codelist 153 text types and codelist 154 content audiences.
+
+CREATE TYPE text_content_type AS ENUM (
+    'description',
+    'primary_cover_copy',
+    'review_quote',
+    'previous_edition_review_quote',
+    'previous_work_review_quote',
+    'endorsement',
+    'promotional_headline',
+    'feature',
+    'biographical_note',
+    'publishers_notice',
+    'excerpt'
+);
+
+CREATE TYPE content_audience AS ENUM (
+    'unrestricted',
+    'restricted',
+    'booktrade',
+    'end_customers',
+    'librarians',
+    'teachers',
+    'students',
+    'press',
+    'shopping_comparison_services',
+    'search_engine_index'
+);
+
+CREATE TABLE text_content (
+    text_content_id     UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    text_type           text_content_type NOT NULL,
+    content_audience    content_audience NOT NULL DEFAULT 'unrestricted',
+    content             TEXT NOT NULL CHECK (octet_length(content) >= 1),
+    text_author         TEXT CHECK (octet_length(text_author) >= 1),
+    source_title        TEXT CHECK (octet_length(source_title) >= 1),
+    source_url          TEXT CHECK (source_url ~* '^[^:]*:\/\/(?:[^\/:]*:[^\/@]*@)?(?:[^\/:.]*\.)+([^:\/]+)'),
+    content_date        DATE,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('text_content');
+
+CREATE INDEX idx_text_content_work_id ON text_content (work_id);
+
+CREATE TABLE text_content_history (
+    text_content_history_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    text_content_id         UUID NOT NULL REFERENCES text_content(text_content_id) ON DELETE CASCADE,
+    account_id              UUID NOT NULL REFERENCES account(account_id),
+    data                    JSONB NOT NULL,
+    timestamp               TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,6 @@
+DROP TABLE supporting_resource_history;
+DROP TABLE supporting_resource;
+
+DROP TYPE resource_form;
+DROP TYPE resource_mode;
+DROP TYPE resource_content_type;
@@ -0,0 +1,59 @@
+-- Supporting resources of a work, such as cover images, sample content, contributor photos and
+-- trailers, typed using ONIX codelists 158 (content type), 159 (mode) and 161 (form).
+
+CREATE TYPE resource_content_type AS ENUM (
+    'front_cover',
+    'back_cover',
+    'full_cover',
+    'cover_packshot',
+    'cover_thumbnail',
+    'contributor_picture',
+    'contributor_interview',
+    'sample_content',
+    'reading_group_guide',
+    'teachers_guide',
+    'press_release',
+    'trailer'
+);
+
+CREATE TYPE resource_mode AS ENUM (
+    'application',
+    'audio',
+    'image',
+    'text',
+    'video',
+    'multi_mode'
+);
+
+CREATE TYPE resource_form AS ENUM (
+    'linkable_resource',
+    'downloadable_file',
+    'embeddable_application'
+);
+
+CREATE TABLE supporting_resource (
+    supporting_resource_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id                 UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    resource_content_type   resource_content_type NOT NULL,
+    content_audience        content_audience NOT NULL DEFAULT 'unrestricted',
+    resource_mode           resource_mode NOT NULL,
+    resource_form           resource_form NOT NULL DEFAULT 'downloadable_file',
+    resource_url            TEXT NOT NULL CHECK (resource_url ~* '^[^:]*:\/\/(?:[^\/:]*:[^\/@]*@)?(?:[^\/:.]*\.)+([^:\/]+)'),
+    caption                 TEXT CHECK (octet_length(caption) >= 1),
+    credit                  TEXT CHECK (octet_length(credit) >= 1),
+    width_px                INTEGER CHECK (width_px > 0),
+    height_px               INTEGER CHECK (height_px > 0),
+    created_at              TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at              TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('supporting_resource');
+
+CREATE INDEX idx_supporting_resource_work_id ON supporting_resource (work_id);
+
+CREATE TABLE supporting_resource_history (
+    supporting_resource_history_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    supporting_resource_id          UUID NOT NULL REFERENCES supporting_resource(supporting_resource_id) ON DELETE CASCADE,
+    account_id                      UUID NOT NULL REFERENCES account(account_id),
+    data                            JSONB NOT NULL,
+    timestamp                       TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,4 @@
+DROP TABLE asset_history;
+DROP TABLE asset;
+
+DROP TYPE asset_type;
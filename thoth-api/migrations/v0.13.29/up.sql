@@ -0,0 +1,41 @@
+-- Files uploaded to Thoth's own asset storage: work covers, and publication files which are
+-- made available from a Thoth location.
+
+CREATE TYPE asset_type AS ENUM (
+    'cover',
+    'publication_file'
+);
+
+CREATE TABLE asset (
+    asset_id        UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id         UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    publication_id  UUID REFERENCES publication(publication_id) ON DELETE CASCADE,
+    location_id     UUID REFERENCES location(location_id) ON DELETE SET NULL,
+    asset_type      asset_type NOT NULL,
+    storage_key     TEXT NOT NULL CHECK (octet_length(storage_key) >= 1),
+    asset_url       TEXT NOT NULL CHECK (octet_length(asset_url) >= 1),
+    filename        TEXT NOT NULL CHECK (octet_length(filename) >= 1),
+    mime_type       TEXT NOT NULL CHECK (mime_type ~ '^[a-z]+\/[a-zA-Z0-9.+_-]+$'),
+    -- Size in bytes
+    file_size       INTEGER NOT NULL CHECK (file_size > 0),
+    -- Hex-encoded SHA-256 digest of the file contents
+    checksum        TEXT NOT NULL CHECK (checksum ~ '^[0-9a-f]{64}$'),
+    created_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at      TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT asset_publication_id_check CHECK (
+        (asset_type = 'cover' AND publication_id IS NULL) OR
+        (asset_type = 'publication_file' AND publication_id IS NOT NULL)
+    )
+);
+SELECT diesel_manage_updated_at('asset');
+
+CREATE UNIQUE INDEX asset_storage_key_uniq_idx ON asset (storage_key);
+CREATE INDEX idx_asset_work_id ON asset (work_id);
+
+CREATE TABLE asset_history (
+    asset_history_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    asset_id            UUID NOT NULL REFERENCES asset(asset_id) ON DELETE CASCADE,
+    account_id          UUID NOT NULL REFERENCES account(account_id),
+    data                JSONB NOT NULL,
+    timestamp           TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,10 @@
+DELETE FROM supporting_resource WHERE cover_rendition IS NOT NULL;
+
+DROP INDEX IF EXISTS supporting_resource_cover_rendition_uniq_idx;
+
+ALTER TABLE supporting_resource
+    DROP CONSTRAINT supporting_resource_cover_rendition_check,
+    DROP COLUMN asset_id,
+    DROP COLUMN cover_rendition;
+
+DROP TYPE IF EXISTS cover_rendition;
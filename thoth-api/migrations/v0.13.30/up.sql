@@ -0,0 +1,25 @@
+-- Renditions of uploaded covers generated by the server, such as thumbnails and the sizes
+-- required by retailers, recorded as supporting resources derived from the cover asset.
+
+CREATE TYPE cover_rendition AS ENUM (
+    'thumbnail',
+    'small',
+    'medium',
+    'large',
+    'amazon',
+    'apple_books',
+    'google_play'
+);
+
+ALTER TABLE supporting_resource
+    ADD COLUMN cover_rendition cover_rendition,
+    ADD COLUMN asset_id UUID REFERENCES asset(asset_id) ON DELETE CASCADE,
+    ADD CONSTRAINT supporting_resource_cover_rendition_check CHECK (
+        (cover_rendition IS NULL AND asset_id IS NULL) OR
+        (cover_rendition IS NOT NULL AND asset_id IS NOT NULL
+            AND resource_content_type IN ('front_cover', 'cover_thumbnail'))
+    );
+
+CREATE UNIQUE INDEX supporting_resource_cover_rendition_uniq_idx
+    ON supporting_resource (work_id, cover_rendition)
+    WHERE cover_rendition IS NOT NULL;
@@ -0,0 +1,52 @@
+-- We cannot drop individual enum values - we must drop the type and recreate it
+--
+-- Delete works with about-to-be-dropped types
+DELETE FROM work WHERE work_type = 'journal-article';
+
+-- Drop constraints and indexes, otherwise it won't be able to cast to text
+ALTER TABLE work
+    DROP CONSTRAINT work_non_chapter_has_edition,
+    DROP CONSTRAINT work_chapter_no_edition,
+    DROP CONSTRAINT work_chapter_no_toc,
+    DROP CONSTRAINT work_chapter_no_lccn,
+    DROP CONSTRAINT work_chapter_no_oclc,
+    DROP CONSTRAINT work_non_chapter_no_first_page,
+    DROP CONSTRAINT work_non_chapter_no_last_page,
+    DROP CONSTRAINT work_non_chapter_no_page_interval;
+DROP INDEX IF EXISTS idx_work_type_status_pub_date_desc;
+DROP INDEX IF EXISTS idx_work_books_pub_date_desc;
+
+ALTER TABLE work ALTER COLUMN work_type TYPE text;
+DROP TYPE work_type;
+CREATE TYPE work_type AS ENUM (
+    'book-chapter',
+    'monograph',
+    'edited-book',
+    'textbook',
+    'journal-issue',
+    'book-set'
+);
+ALTER TABLE work ALTER COLUMN work_type TYPE work_type USING work_type::work_type;
+
+ALTER TABLE work
+    ADD CONSTRAINT work_non_chapter_has_edition CHECK
+        (edition IS NOT NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_edition CHECK
+        (edition IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_toc CHECK
+        (toc IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_lccn CHECK
+        (lccn IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_oclc CHECK
+        (oclc IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_first_page CHECK
+        (first_page IS NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_last_page CHECK
+        (last_page IS NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_page_interval CHECK
+        (page_interval IS NULL OR work_type = 'book-chapter');
+CREATE INDEX idx_work_type_status_pub_date_desc
+    ON work (work_type, work_status, publication_date DESC);
+CREATE INDEX idx_work_books_pub_date_desc
+    ON work (publication_date DESC)
+    WHERE work_type IN ('monograph', 'edited-book', 'textbook') AND work_status = 'active';
@@ -0,0 +1 @@
+ALTER TYPE work_type ADD VALUE IF NOT EXISTS 'journal-article';
@@ -0,0 +1,36 @@
+ALTER TABLE issue
+    DROP COLUMN issue_number,
+    DROP COLUMN volume;
+
+-- Journal articles cannot satisfy the restored chapter-only constraints
+DELETE FROM work WHERE work_type = 'journal-article';
+
+ALTER TABLE work
+    DROP CONSTRAINT work_non_article_no_article_number,
+    DROP COLUMN article_number,
+    DROP CONSTRAINT work_non_chapter_has_edition,
+    DROP CONSTRAINT work_chapter_no_edition,
+    DROP CONSTRAINT work_chapter_no_toc,
+    DROP CONSTRAINT work_chapter_no_lccn,
+    DROP CONSTRAINT work_chapter_no_oclc,
+    DROP CONSTRAINT work_non_chapter_no_first_page,
+    DROP CONSTRAINT work_non_chapter_no_last_page,
+    DROP CONSTRAINT work_non_chapter_no_page_interval;
+
+ALTER TABLE work
+    ADD CONSTRAINT work_non_chapter_has_edition CHECK
+        (edition IS NOT NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_edition CHECK
+        (edition IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_toc CHECK
+        (toc IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_lccn CHECK
+        (lccn IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_chapter_no_oclc CHECK
+        (oclc IS NULL OR work_type <> 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_first_page CHECK
+        (first_page IS NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_last_page CHECK
+        (last_page IS NULL OR work_type = 'book-chapter'),
+    ADD CONSTRAINT work_non_chapter_no_page_interval CHECK
+        (page_interval IS NULL OR work_type = 'book-chapter');
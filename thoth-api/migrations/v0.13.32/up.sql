@@ -0,0 +1,38 @@
+-- Journal articles, like book chapters, are components of a larger work: they have page
+-- ranges but no edition, and are linked to the journal issue they appear in via relations.
+ALTER TABLE work
+    DROP CONSTRAINT work_non_chapter_has_edition,
+    DROP CONSTRAINT work_chapter_no_edition,
+    DROP CONSTRAINT work_chapter_no_toc,
+    DROP CONSTRAINT work_chapter_no_lccn,
+    DROP CONSTRAINT work_chapter_no_oclc,
+    DROP CONSTRAINT work_non_chapter_no_first_page,
+    DROP CONSTRAINT work_non_chapter_no_last_page,
+    DROP CONSTRAINT work_non_chapter_no_page_interval;
+
+ALTER TABLE work
+    ADD CONSTRAINT work_non_chapter_has_edition CHECK
+        (edition IS NOT NULL OR work_type IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_chapter_no_edition CHECK
+        (edition IS NULL OR work_type NOT IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_chapter_no_toc CHECK
+        (toc IS NULL OR work_type NOT IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_chapter_no_lccn CHECK
+        (lccn IS NULL OR work_type NOT IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_chapter_no_oclc CHECK
+        (oclc IS NULL OR work_type NOT IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_non_chapter_no_first_page CHECK
+        (first_page IS NULL OR work_type IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_non_chapter_no_last_page CHECK
+        (last_page IS NULL OR work_type IN ('book-chapter', 'journal-article')),
+    ADD CONSTRAINT work_non_chapter_no_page_interval CHECK
+        (page_interval IS NULL OR work_type IN ('book-chapter', 'journal-article')),
+    -- Electronic-only journals commonly identify articles by number rather than by pages
+    ADD COLUMN article_number TEXT CHECK (octet_length(article_number) >= 1),
+    ADD CONSTRAINT work_non_article_no_article_number CHECK
+        (article_number IS NULL OR work_type = 'journal-article');
+
+-- Enumeration of a journal issue as printed, e.g. volume "12", issue "3-4"
+ALTER TABLE issue
+    ADD COLUMN volume TEXT CHECK (octet_length(volume) >= 1),
+    ADD COLUMN issue_number TEXT CHECK (octet_length(issue_number) >= 1);
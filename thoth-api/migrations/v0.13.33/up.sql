@@ -0,0 +1,3 @@
+-- Part designation of an issue as printed, e.g. "1" of a volume issued in parts
+ALTER TABLE issue
+    ADD COLUMN part TEXT CHECK (octet_length(part) >= 1);
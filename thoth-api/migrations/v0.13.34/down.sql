@@ -0,0 +1,12 @@
+DROP TRIGGER set_work_updated_at_with_relations ON series_contribution;
+DROP FUNCTION series_contribution_work_updated_at_with_relations();
+
+DROP TABLE series_contribution_history;
+DROP TABLE series_contribution;
+
+DROP TYPE series_contribution_type;
+
+DROP INDEX idx_series_parent_series_id;
+ALTER TABLE series
+    DROP CONSTRAINT series_not_own_parent,
+    DROP COLUMN parent_series_id;
@@ -0,0 +1,65 @@
+-- Series may be subseries of another series, e.g. a thematic strand of a book series
+ALTER TABLE series
+    ADD COLUMN parent_series_id UUID REFERENCES series(series_id) ON DELETE SET NULL,
+    ADD CONSTRAINT series_not_own_parent CHECK (parent_series_id <> series_id);
+
+CREATE INDEX idx_series_parent_series_id ON series (parent_series_id);
+
+-- People responsible for a series as a whole, rather than for any one of its works,
+-- with roles expressed as ONIX codelist 17 editorial contributor roles.
+
+CREATE TYPE series_contribution_type AS ENUM (
+    'series-editor',
+    'editor-in-chief',
+    'managing-editor',
+    'associate-editor',
+    'editorial-board-member'
+);
+
+CREATE TABLE series_contribution (
+    series_contribution_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    series_id               UUID NOT NULL REFERENCES series(series_id) ON DELETE CASCADE,
+    contributor_id          UUID NOT NULL REFERENCES contributor(contributor_id) ON DELETE CASCADE,
+    contribution_type       series_contribution_type NOT NULL,
+    first_name              TEXT CHECK (octet_length(first_name) >= 1),
+    last_name               TEXT NOT NULL CHECK (octet_length(last_name) >= 1),
+    full_name               TEXT NOT NULL CHECK (octet_length(full_name) >= 1),
+    contribution_ordinal    INTEGER NOT NULL CHECK (contribution_ordinal > 0),
+    created_at              TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at              TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT series_contribution_series_id_contributor_id_contribution_type_uniq
+        UNIQUE (series_id, contributor_id, contribution_type),
+    CONSTRAINT series_contribution_contribution_ordinal_series_id_uniq
+        UNIQUE (series_id, contribution_ordinal)
+);
+SELECT diesel_manage_updated_at('series_contribution');
+
+CREATE INDEX idx_series_contribution_series_id ON series_contribution (series_id);
+CREATE INDEX idx_series_contribution_contributor_id ON series_contribution (contributor_id);
+
+CREATE TABLE series_contribution_history (
+    series_contribution_history_id  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    series_contribution_id          UUID NOT NULL REFERENCES series_contribution(series_contribution_id) ON DELETE CASCADE,
+    account_id                      UUID NOT NULL REFERENCES account(account_id),
+    data                            JSONB NOT NULL,
+    timestamp                       TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Works in a series appear with its contributors in exported records
+CREATE OR REPLACE FUNCTION series_contribution_work_updated_at_with_relations() RETURNS trigger AS $$
+BEGIN
+    IF (
+        NEW IS DISTINCT FROM OLD
+    ) THEN
+        UPDATE work
+        SET updated_at_with_relations = current_timestamp
+        FROM issue
+        WHERE work.work_id = issue.work_id
+            AND (issue.series_id = OLD.series_id OR issue.series_id = NEW.series_id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_work_updated_at_with_relations AFTER INSERT OR UPDATE OR DELETE ON series_contribution
+    FOR EACH ROW EXECUTE PROCEDURE series_contribution_work_updated_at_with_relations();
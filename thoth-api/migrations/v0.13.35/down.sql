@@ -0,0 +1,10 @@
+DROP TRIGGER set_work_updated_at_with_relations ON event_sponsor;
+DROP FUNCTION event_sponsor_work_updated_at_with_relations();
+
+DROP TABLE event_sponsor_history;
+DROP TABLE event_sponsor;
+
+DROP TRIGGER set_work_updated_at_with_relations ON event;
+
+DROP TABLE event_history;
+DROP TABLE event;
@@ -0,0 +1,76 @@
+-- Conferences and other events of which a work, typically an edited book,
+-- publishes the proceedings.
+
+CREATE TABLE event (
+    event_id            UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    event_name          TEXT NOT NULL CHECK (octet_length(event_name) >= 1),
+    event_acronym       TEXT CHECK (octet_length(event_acronym) >= 1),
+    event_number        TEXT CHECK (octet_length(event_number) >= 1),
+    event_location      TEXT CHECK (octet_length(event_location) >= 1),
+    event_country       country_code,
+    event_start_date    DATE,
+    event_end_date      DATE,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT event_event_end_date_check
+        CHECK (event_end_date IS NULL OR (event_start_date IS NOT NULL AND event_end_date >= event_start_date))
+);
+SELECT diesel_manage_updated_at('event');
+
+CREATE INDEX idx_event_work_id ON event (work_id);
+
+CREATE TABLE event_history (
+    event_history_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    event_id            UUID NOT NULL REFERENCES event(event_id) ON DELETE CASCADE,
+    account_id          UUID NOT NULL REFERENCES account(account_id),
+    data                JSONB NOT NULL,
+    timestamp           TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TRIGGER set_work_updated_at_with_relations AFTER INSERT OR UPDATE OR DELETE ON event
+    FOR EACH ROW EXECUTE PROCEDURE work_updated_at_with_relations();
+
+-- Institutions sponsoring an event
+
+CREATE TABLE event_sponsor (
+    event_sponsor_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    event_id            UUID NOT NULL REFERENCES event(event_id) ON DELETE CASCADE,
+    institution_id      UUID NOT NULL REFERENCES institution(institution_id) ON DELETE CASCADE,
+    sponsor_ordinal     INTEGER NOT NULL CHECK (sponsor_ordinal > 0),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CONSTRAINT event_sponsor_event_id_institution_id_uniq
+        UNIQUE (event_id, institution_id),
+    CONSTRAINT event_sponsor_sponsor_ordinal_event_id_uniq
+        UNIQUE (event_id, sponsor_ordinal)
+);
+SELECT diesel_manage_updated_at('event_sponsor');
+
+CREATE INDEX idx_event_sponsor_institution_id ON event_sponsor (institution_id);
+
+CREATE TABLE event_sponsor_history (
+    event_sponsor_history_id    UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    event_sponsor_id            UUID NOT NULL REFERENCES event_sponsor(event_sponsor_id) ON DELETE CASCADE,
+    account_id                  UUID NOT NULL REFERENCES account(account_id),
+    data                        JSONB NOT NULL,
+    timestamp                   TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE OR REPLACE FUNCTION event_sponsor_work_updated_at_with_relations() RETURNS trigger AS $$
+BEGIN
+    IF (
+        NEW IS DISTINCT FROM OLD
+    ) THEN
+        UPDATE work
+        SET updated_at_with_relations = current_timestamp
+        FROM event
+        WHERE work.work_id = event.work_id AND event.event_id = OLD.event_id
+            OR work.work_id = event.work_id AND event.event_id = NEW.event_id;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_work_updated_at_with_relations AFTER INSERT OR UPDATE OR DELETE ON event_sponsor
+    FOR EACH ROW EXECUTE PROCEDURE event_sponsor_work_updated_at_with_relations();
@@ -0,0 +1,8 @@
+-- Restore the licence URLs as they were before normalisation, unless they have been edited since
+UPDATE work
+    SET license = work_license_original.original_license
+    FROM work_license_original
+    WHERE work.work_id = work_license_original.work_id
+        AND work.license = work_license_original.normalised_license;
+
+DROP TABLE work_license_original;
@@ -0,0 +1,28 @@
+-- Keep the licence URLs that are about to be normalised, so that the normalisation can be reverted
+CREATE TABLE work_license_original (
+    work_id             UUID PRIMARY KEY NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    original_license    TEXT NOT NULL,
+    normalised_license  TEXT NOT NULL
+);
+
+-- Normalise Creative Commons licence URLs to the canonical form used by the licence registry:
+-- https scheme, no "www.", no legal code or deed suffix, lowercase and a trailing slash
+WITH normalised AS (
+    SELECT work_id, license, 'https://creativecommons.org/' || lower(regexp_replace(
+        regexp_replace(trim(license), '^https?://(www\.)?creativecommons\.org/', '', 'i'),
+        '/?((legalcode|deed)[^/?#]*)?/?([?#].*)?$',
+        '/',
+        'i'
+    )) AS normalised_license
+    FROM work
+    WHERE trim(license) ~* '^https?://(www\.)?creativecommons\.org/(licenses|publicdomain)/'
+)
+INSERT INTO work_license_original (work_id, original_license, normalised_license)
+    SELECT work_id, license, normalised_license
+    FROM normalised
+    WHERE license <> normalised_license;
+
+UPDATE work
+    SET license = work_license_original.normalised_license
+    FROM work_license_original
+    WHERE work.work_id = work_license_original.work_id;
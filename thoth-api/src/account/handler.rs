@@ -10,7 +10,7 @@ use uuid::Uuid;
 use crate::account::{
     model::{
         Account, AccountAccess, AccountData, DecodedToken, LinkedPublisher, NewAccount,
-        NewPassword, NewPublisherAccount, PublisherAccount, Token,
+        NewPassword, NewPublisherAccount, PublisherAccount, Token, TwoFactorChallenge,
     },
     service::get_account,
     util::{make_hash, make_salt},
@@ -19,13 +19,42 @@ use crate::db::PgPool;
 use thoth_errors::{ThothError, ThothResult};
 
 impl Account {
+    /// Publishers this account can act on. Publishers that require two-factor authentication
+    /// are left out until the account has enabled it.
     pub fn get_permissions(&self, pool: &PgPool) -> ThothResult<Vec<LinkedPublisher>> {
         let publisher_accounts = self.get_publisher_accounts(pool)?;
-        let permissions: Vec<LinkedPublisher> =
-            publisher_accounts.into_iter().map(|p| p.into()).collect();
+        let restricted_ids = if self.totp_enabled {
+            vec![]
+        } else {
+            self.get_two_factor_publisher_ids(pool)?
+        };
+        let permissions: Vec<LinkedPublisher> = publisher_accounts
+            .into_iter()
+            .filter(|p| !restricted_ids.contains(&p.publisher_id))
+            .map(|p| p.into())
+            .collect();
         Ok(permissions)
     }
 
+    /// IDs of linked publishers that require two-factor authentication
+    pub fn get_two_factor_publisher_ids(&self, pool: &PgPool) -> ThothResult<Vec<Uuid>> {
+        use crate::schema::{publisher, publisher_account};
+        let mut conn = pool.get()?;
+
+        publisher_account::table
+            .inner_join(publisher::table)
+            .filter(publisher_account::account_id.eq(self.account_id))
+            .filter(publisher::require_two_factor.eq(true))
+            .select(publisher::publisher_id)
+            .load::<Uuid>(&mut conn)
+            .map_err(Into::into)
+    }
+
+    pub fn requires_two_factor(&self, pool: &PgPool) -> ThothResult<bool> {
+        self.get_two_factor_publisher_ids(pool)
+            .map(|publisher_ids| !publisher_ids.is_empty())
+    }
+
     pub fn get_publisher_accounts(&self, pool: &PgPool) -> ThothResult<Vec<PublisherAccount>> {
         use crate::schema::publisher_account::dsl::*;
         let mut conn = pool.get()?;
@@ -158,6 +187,47 @@ impl Token {
     }
 }
 
+impl TwoFactorChallenge {
+    /// Audience of challenge tokens, which keeps them from being accepted as a `Token`
+    const AUDIENCE: &'static str = "https://thoth.pub/two-factor";
+    const VALIDITY: i64 = 5 * 60;
+
+    pub fn issue(email: &str) -> ThothResult<String> {
+        dotenv().ok();
+        let secret_str = env::var("SECRET_KEY").expect("SECRET_KEY must be set");
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| ThothError::InternalError("Unable to set challenge expiry".into()))?;
+        let claim = TwoFactorChallenge {
+            sub: email.to_string(),
+            exp: now.as_secs() as i64 + Self::VALIDITY,
+            aud: Self::AUDIENCE.to_string(),
+        };
+        encode(
+            &Header::default(),
+            &claim,
+            &EncodingKey::from_secret(secret_str.as_bytes()),
+        )
+        .map_err(|_| ThothError::InternalError("Unable to create challenge".into()))
+    }
+
+    pub fn verify(challenge: &str) -> ThothResult<TwoFactorChallenge> {
+        dotenv().ok();
+        let secret_str = env::var("SECRET_KEY").expect("SECRET_KEY must be set");
+        let mut validation = Validation::default();
+        validation.set_audience(&[Self::AUDIENCE]);
+        validation.set_required_spec_claims(&["exp", "aud"]);
+
+        let data = decode::<TwoFactorChallenge>(
+            challenge,
+            &DecodingKey::from_secret(secret_str.as_bytes()),
+            &validation,
+        )
+        .map_err(|_| ThothError::InvalidToken)?;
+        Ok(data.claims)
+    }
+}
+
 lazy_static::lazy_static! {
     static ref BEARER_REGEXP : Regex = Regex::new(r"^Bearer\s(.*)$").expect("Bearer regexp failed!");
 }
@@ -4,4 +4,6 @@ pub mod model;
 #[cfg(feature = "backend")]
 pub mod service;
 #[cfg(feature = "backend")]
+pub mod totp;
+#[cfg(feature = "backend")]
 pub mod util;
@@ -5,6 +5,10 @@ use crate::model::Timestamp;
 #[cfg(feature = "backend")]
 use crate::schema::account;
 #[cfg(feature = "backend")]
+use crate::schema::account_login_audit;
+#[cfg(feature = "backend")]
+use crate::schema::account_recovery_code;
+#[cfg(feature = "backend")]
 use crate::schema::publisher_account;
 use thoth_errors::ThothError;
 use thoth_errors::ThothResult;
@@ -24,6 +28,11 @@ pub struct Account {
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub token: Option<String>,
+    pub failed_login_attempts: i32,
+    pub locked_until: Option<Timestamp>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled: bool,
+    pub totp_last_used_step: Option<i64>,
 }
 
 #[cfg_attr(feature = "backend", derive(Insertable))]
@@ -48,6 +57,43 @@ pub struct AccountData {
     pub is_bot: bool,
 }
 
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct AccountLoginAudit {
+    pub account_login_audit_id: Uuid,
+    pub account_id: Option<Uuid>,
+    pub email: String,
+    pub ip_address: Option<String>,
+    pub success: bool,
+    pub failure_reason: Option<String>,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable))]
+#[cfg_attr(feature = "backend", diesel(table_name = account_login_audit))]
+pub struct NewAccountLoginAudit {
+    pub account_id: Option<Uuid>,
+    pub email: String,
+    pub ip_address: Option<String>,
+    pub success: bool,
+    pub failure_reason: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct AccountRecoveryCode {
+    pub account_recovery_code_id: Uuid,
+    pub account_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable))]
+#[cfg_attr(feature = "backend", diesel(table_name = account_recovery_code))]
+pub struct NewAccountRecoveryCode {
+    pub account_id: Uuid,
+    pub code_hash: String,
+}
+
 #[cfg_attr(feature = "backend", derive(Queryable))]
 pub struct PublisherAccount {
     pub account_id: Uuid,
@@ -101,6 +147,11 @@ pub struct AccountDetails {
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
     pub resource_access: AccountAccess,
+    #[serde(default)]
+    pub two_factor_enabled: bool,
+    /// Whether the account is linked to a publisher that requires two-factor authentication
+    #[serde(default)]
+    pub two_factor_required: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +163,43 @@ pub struct DecodedToken {
 pub struct LoginCredentials {
     pub email: String,
     pub password: String,
+    /// TOTP or recovery code, only required for accounts with two-factor authentication enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub totp_code: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+pub struct TotpCode {
+    pub code: String,
+}
+
+/// Short-lived proof that an external identity provider has authenticated the account,
+/// to be exchanged together with a second factor for a session
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TwoFactorChallenge {
+    pub sub: String,
+    pub exp: i64,
+    pub aud: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, Default)]
+pub struct TwoFactorLogin {
+    pub challenge: String,
+    pub totp_code: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpEnrolment {
+    /// Base32 encoded shared secret, for manual entry into an authenticator app
+    pub secret: String,
+    pub otpauth_url: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryCodes {
+    pub recovery_codes: Vec<String>,
 }
 
 #[cfg_attr(feature = "backend", derive(AsChangeset), diesel(table_name = account))]
@@ -121,6 +209,13 @@ pub struct NewPassword {
     pub salt: String,
 }
 
+impl Account {
+    pub fn is_locked(&self) -> bool {
+        self.locked_until
+            .is_some_and(|locked_until| locked_until > Timestamp::now())
+    }
+}
+
 impl DecodedToken {
     pub fn get_user_permissions(&self) -> AccountAccess {
         if let Some(jwt) = &self.jwt {
@@ -1,27 +1,269 @@
 use diesel::prelude::*;
+use uuid::Uuid;
 
 use crate::account::{
-    model::{Account, AccountData, AccountDetails, LinkedPublisher, NewAccount, NewPassword},
-    util::verify,
+    model::{
+        Account, AccountData, AccountDetails, LinkedPublisher, NewAccount, NewAccountLoginAudit,
+        NewAccountRecoveryCode, NewPassword, RecoveryCodes, TotpEnrolment,
+    },
+    totp::{
+        base32_encode, decrypt_secret, encrypt_secret, generate_recovery_code, generate_secret,
+        hash_recovery_code, otpauth_url, verify_totp, RECOVERY_CODE_COUNT,
+    },
+    util::{make_salt, verify, PasswordPolicy},
 };
 use crate::db::PgPool;
-use crate::model::publisher::Publisher;
+use crate::model::{publisher::Publisher, Timestamp};
 use thoth_errors::{ThothError, ThothResult};
 
-pub fn login(user_email: &str, user_password: &str, pool: &PgPool) -> ThothResult<Account> {
+/// Number of consecutive failed login attempts that will lock an account
+pub const MAX_FAILED_LOGIN_ATTEMPTS: i32 = 5;
+/// Length of time an account remains locked after too many failed attempts
+pub const LOCKOUT_DURATION_SECONDS: i64 = 15 * 60;
+
+pub fn login(
+    user_email: &str,
+    user_password: &str,
+    totp_code: Option<&str>,
+    ip_address: Option<&str>,
+    pool: &PgPool,
+) -> ThothResult<Account> {
     use crate::schema::account::dsl;
 
     let mut conn = pool.get()?;
-    let account = dsl::account
+    let account = match dsl::account
         .filter(dsl::email.eq(user_email))
         .first::<Account>(&mut conn)
-        .map_err(|_| ThothError::Unauthorised)?;
+    {
+        Ok(account) => account,
+        Err(_) => {
+            record_login_attempt(None, user_email, ip_address, Some("Unknown account"), pool)?;
+            return Err(ThothError::Unauthorised);
+        }
+    };
 
-    if verify(&account, user_password) {
-        Ok(account)
+    if account.is_locked() {
+        record_login_attempt(
+            Some(account.account_id),
+            user_email,
+            ip_address,
+            Some("Account locked"),
+            pool,
+        )?;
+        return Err(ThothError::AccountLocked);
+    }
+
+    let failure_reason = if !verify(&account, user_password) {
+        Some("Invalid password")
+    } else if account.totp_enabled {
+        match totp_code {
+            None => {
+                // The password was correct: prompt for the second factor without
+                // counting this towards the lockout threshold
+                record_login_attempt(
+                    Some(account.account_id),
+                    user_email,
+                    ip_address,
+                    Some("Two-factor code required"),
+                    pool,
+                )?;
+                return Err(ThothError::TwoFactorRequired);
+            }
+            Some(code) if verify_second_factor(&account, code, pool)? => None,
+            Some(_) => Some("Invalid two-factor code"),
+        }
     } else {
-        Err(ThothError::Unauthorised)
+        None
+    };
+
+    complete_login(&account, user_email, failure_reason, ip_address, pool)
+}
+
+/// Reset the failed attempt count after a successful login, or count a failed one towards the
+/// lockout threshold
+fn complete_login(
+    account: &Account,
+    user_email: &str,
+    failure_reason: Option<&str>,
+    ip_address: Option<&str>,
+    pool: &PgPool,
+) -> ThothResult<Account> {
+    use crate::schema::account::dsl;
+
+    let mut conn = pool.get()?;
+    match failure_reason {
+        None => {
+            let account = diesel::update(dsl::account.find(&account.account_id))
+                .set((
+                    dsl::failed_login_attempts.eq(0),
+                    dsl::locked_until.eq(None::<Timestamp>),
+                ))
+                .get_result::<Account>(&mut conn)?;
+            record_login_attempt(Some(account.account_id), user_email, ip_address, None, pool)?;
+            Ok(account)
+        }
+        Some(reason) => {
+            // Increment in the database, so that concurrent attempts all count towards the lockout
+            let failed_login_attempts = diesel::update(dsl::account.find(&account.account_id))
+                .set(dsl::failed_login_attempts.eq(dsl::failed_login_attempts + 1))
+                .returning(dsl::failed_login_attempts)
+                .get_result::<i32>(&mut conn)?;
+            if failed_login_attempts >= MAX_FAILED_LOGIN_ATTEMPTS {
+                diesel::update(dsl::account.find(&account.account_id))
+                    .set((
+                        dsl::failed_login_attempts.eq(0),
+                        dsl::locked_until.eq(Some(
+                            Timestamp::now().plus_seconds(LOCKOUT_DURATION_SECONDS),
+                        )),
+                    ))
+                    .execute(&mut conn)?;
+            }
+            record_login_attempt(
+                Some(account.account_id),
+                user_email,
+                ip_address,
+                Some(reason),
+                pool,
+            )?;
+            Err(ThothError::Unauthorised)
+        }
+    }
+}
+
+/// Log in an account whose identity has been asserted by an external identity provider.
+///
+/// The account is matched by (case-insensitive) email. If none exists and `provision` is set,
+/// a new non-superuser account is created, with a random password that is never disclosed.
+/// Accounts with two-factor authentication enabled must still provide a code, using
+/// [`login_external_second_factor`].
+pub fn login_external(
+    user_email: &str,
+    name: &str,
+    surname: &str,
+    provision: bool,
+    ip_address: Option<&str>,
+    pool: &PgPool,
+) -> ThothResult<Account> {
+    use crate::schema::account::dsl;
+    use diesel::sql_types::Text;
+    define_sql_function!(fn lower(x: Text) -> Text);
+
+    let mut conn = pool.get()?;
+    let account = match dsl::account
+        .filter(lower(dsl::email).eq(user_email.to_lowercase()))
+        .first::<Account>(&mut conn)
+        .optional()?
+    {
+        Some(account) => account,
+        None if provision => {
+            let new_account: NewAccount = AccountData {
+                name: name.to_string(),
+                surname: surname.to_string(),
+                email: user_email.to_string(),
+                password: make_salt(),
+                is_superuser: false,
+                is_bot: false,
+            }
+            .into();
+            diesel::insert_into(dsl::account)
+                .values(&new_account)
+                .get_result::<Account>(&mut conn)?
+        }
+        None => {
+            record_login_attempt(None, user_email, ip_address, Some("Unknown account"), pool)?;
+            return Err(ThothError::Unauthorised);
+        }
+    };
+
+    if account.is_locked() {
+        record_login_attempt(
+            Some(account.account_id),
+            user_email,
+            ip_address,
+            Some("Account locked"),
+            pool,
+        )?;
+        return Err(ThothError::AccountLocked);
+    }
+    if account.totp_enabled {
+        record_login_attempt(
+            Some(account.account_id),
+            user_email,
+            ip_address,
+            Some("Two-factor code required"),
+            pool,
+        )?;
+        return Err(ThothError::TwoFactorRequired);
     }
+    record_login_attempt(Some(account.account_id), user_email, ip_address, None, pool)?;
+    Ok(account)
+}
+
+/// Complete an external login for an account with two-factor authentication enabled,
+/// once the identity provider has asserted the account's email
+pub fn login_external_second_factor(
+    user_email: &str,
+    totp_code: &str,
+    ip_address: Option<&str>,
+    pool: &PgPool,
+) -> ThothResult<Account> {
+    use crate::schema::account::dsl;
+    use diesel::sql_types::Text;
+    define_sql_function!(fn lower(x: Text) -> Text);
+
+    let mut conn = pool.get()?;
+    let account = match dsl::account
+        .filter(lower(dsl::email).eq(user_email.to_lowercase()))
+        .first::<Account>(&mut conn)
+        .optional()?
+    {
+        Some(account) if account.totp_enabled => account,
+        _ => {
+            record_login_attempt(None, user_email, ip_address, Some("Unknown account"), pool)?;
+            return Err(ThothError::Unauthorised);
+        }
+    };
+
+    if account.is_locked() {
+        record_login_attempt(
+            Some(account.account_id),
+            user_email,
+            ip_address,
+            Some("Account locked"),
+            pool,
+        )?;
+        return Err(ThothError::AccountLocked);
+    }
+
+    let failure_reason = if verify_second_factor(&account, totp_code, pool)? {
+        None
+    } else {
+        Some("Invalid two-factor code")
+    };
+    complete_login(&account, user_email, failure_reason, ip_address, pool)
+}
+
+fn record_login_attempt(
+    account_id: Option<Uuid>,
+    email: &str,
+    ip_address: Option<&str>,
+    failure_reason: Option<&str>,
+    pool: &PgPool,
+) -> ThothResult<()> {
+    use crate::schema::account_login_audit::dsl;
+
+    let mut conn = pool.get()?;
+    let audit = NewAccountLoginAudit {
+        account_id,
+        email: email.to_string(),
+        ip_address: ip_address.map(|ip| ip.to_string()),
+        success: failure_reason.is_none(),
+        failure_reason: failure_reason.map(|reason| reason.to_string()),
+    };
+    diesel::insert_into(dsl::account_login_audit)
+        .values(&audit)
+        .execute(&mut conn)?;
+    Ok(())
 }
 
 pub fn get_account(email: &str, pool: &PgPool) -> ThothResult<Account> {
@@ -45,6 +287,7 @@ pub fn get_account_details(email: &str, pool: &PgPool) -> ThothResult<AccountDet
         .map_err(|_| ThothError::Unauthorised)?;
     let linked_publishers: Vec<LinkedPublisher> = account.get_permissions(pool).unwrap_or_default();
     let resource_access = account.get_account_access(linked_publishers);
+    let two_factor_required = account.requires_two_factor(pool)?;
     let account_details = AccountDetails {
         account_id: account.account_id,
         name: account.name,
@@ -54,6 +297,8 @@ pub fn get_account_details(email: &str, pool: &PgPool) -> ThothResult<AccountDet
         created_at: account.created_at,
         updated_at: account.updated_at,
         resource_access,
+        two_factor_enabled: account.totp_enabled,
+        two_factor_required,
     };
     Ok(account_details)
 }
@@ -69,6 +314,7 @@ pub fn register(
 ) -> ThothResult<Account> {
     use crate::schema::account::dsl;
 
+    PasswordPolicy::from_env().validate(&password)?;
     let mut connection = pool.get()?;
     let account: NewAccount = AccountData {
         name,
@@ -109,6 +355,7 @@ pub fn all_publishers(pool: &PgPool) -> ThothResult<Vec<Publisher>> {
 }
 
 pub fn update_password(email: &str, password: &str, pool: &PgPool) -> ThothResult<Account> {
+    PasswordPolicy::from_env().validate(password)?;
     let mut connection = pool.get()?;
 
     let new_password = NewPassword::new(email.to_string(), password.to_string());
@@ -124,3 +371,176 @@ pub fn update_password(email: &str, password: &str, pool: &PgPool) -> ThothResul
         .get_result(&mut connection)
         .map_err(Into::into)
 }
+
+/// Check a TOTP code, falling back to (and consuming) a matching unused recovery code
+pub fn verify_second_factor(account: &Account, code: &str, pool: &PgPool) -> ThothResult<bool> {
+    let encrypted_secret = account.totp_secret.as_deref().ok_or_else(|| {
+        ThothError::TwoFactorError("Two-factor authentication is not enabled".into())
+    })?;
+    let mut connection = pool.get()?;
+    if let Some(step) = verify_totp(
+        &decrypt_secret(encrypted_secret)?,
+        code,
+        account.totp_last_used_step,
+    ) {
+        return record_totp_step(account.account_id, step, &mut connection);
+    }
+
+    use crate::schema::account_recovery_code::dsl;
+    let used = diesel::update(
+        dsl::account_recovery_code
+            .filter(dsl::account_id.eq(account.account_id))
+            .filter(dsl::code_hash.eq(hash_recovery_code(code)))
+            .filter(dsl::used_at.is_null()),
+    )
+    .set(dsl::used_at.eq(Some(Timestamp::now())))
+    .execute(&mut connection)?;
+    Ok(used == 1)
+}
+
+/// Generate and store a new (not yet active) TOTP secret for the account
+pub fn start_totp_enrolment(account: &Account, pool: &PgPool) -> ThothResult<TotpEnrolment> {
+    if account.totp_enabled {
+        return Err(ThothError::TwoFactorError(
+            "Two-factor authentication is already enabled".into(),
+        ));
+    }
+    let secret = generate_secret();
+    let encrypted_secret = encrypt_secret(&secret)?;
+
+    use crate::schema::account::dsl;
+    let mut connection = pool.get()?;
+    diesel::update(dsl::account.find(&account.account_id))
+        .set(dsl::totp_secret.eq(Some(encrypted_secret)))
+        .execute(&mut connection)?;
+    Ok(TotpEnrolment {
+        secret: base32_encode(&secret),
+        otpauth_url: otpauth_url(&account.email, &secret),
+    })
+}
+
+/// Activate two-factor authentication once the account holder has proven they can generate codes
+pub fn confirm_totp_enrolment(
+    account: &Account,
+    code: &str,
+    pool: &PgPool,
+) -> ThothResult<RecoveryCodes> {
+    if account.totp_enabled {
+        return Err(ThothError::TwoFactorError(
+            "Two-factor authentication is already enabled".into(),
+        ));
+    }
+    let encrypted_secret = account.totp_secret.as_deref().ok_or_else(|| {
+        ThothError::TwoFactorError("Two-factor enrolment has not been started".into())
+    })?;
+    let step = verify_totp(
+        &decrypt_secret(encrypted_secret)?,
+        code,
+        account.totp_last_used_step,
+    )
+    .ok_or_else(|| ThothError::TwoFactorError("Invalid two-factor code".into()))?;
+
+    use crate::schema::account::dsl;
+    let mut connection = pool.get()?;
+    connection.transaction(|connection| {
+        if !record_totp_step(account.account_id, step, connection)? {
+            return Err(ThothError::TwoFactorError("Invalid two-factor code".into()));
+        }
+        diesel::update(dsl::account.find(&account.account_id))
+            .set(dsl::totp_enabled.eq(true))
+            .execute(connection)?;
+        replace_recovery_codes(account.account_id, connection)
+    })
+}
+
+pub fn regenerate_recovery_codes(
+    account: &Account,
+    code: &str,
+    pool: &PgPool,
+) -> ThothResult<RecoveryCodes> {
+    if !account.totp_enabled || !verify_second_factor(account, code, pool)? {
+        return Err(ThothError::TwoFactorError("Invalid two-factor code".into()));
+    }
+    let mut connection = pool.get()?;
+    connection.transaction(|connection| replace_recovery_codes(account.account_id, connection))
+}
+
+pub fn disable_totp(account: &Account, code: &str, pool: &PgPool) -> ThothResult<Account> {
+    if !account.totp_enabled || !verify_second_factor(account, code, pool)? {
+        return Err(ThothError::TwoFactorError("Invalid two-factor code".into()));
+    }
+    reset_totp(&account.email, pool)
+}
+
+/// Remove two-factor authentication from an account without requiring a code,
+/// e.g. when the account holder has lost both their device and their recovery codes
+pub fn reset_totp(email: &str, pool: &PgPool) -> ThothResult<Account> {
+    let mut connection = pool.get()?;
+
+    use crate::schema::account::dsl;
+    let account_obj = dsl::account
+        .filter(dsl::email.eq(email))
+        .first::<Account>(&mut connection)
+        .map_err(Into::<ThothError>::into)?;
+
+    connection.transaction(|connection| {
+        use crate::schema::account_recovery_code::dsl as recovery_dsl;
+        diesel::delete(
+            recovery_dsl::account_recovery_code
+                .filter(recovery_dsl::account_id.eq(account_obj.account_id)),
+        )
+        .execute(connection)?;
+        diesel::update(dsl::account.find(&account_obj.account_id))
+            .set((
+                dsl::totp_enabled.eq(false),
+                dsl::totp_secret.eq(None::<String>),
+                dsl::totp_last_used_step.eq(None::<i64>),
+            ))
+            .get_result(connection)
+            .map_err(Into::into)
+    })
+}
+
+/// Record the time step of an accepted TOTP code, returning false if the same or a later
+/// step has already been used, e.g. by a concurrent request replaying the code
+fn record_totp_step(
+    account_id: Uuid,
+    step: i64,
+    connection: &mut PgConnection,
+) -> ThothResult<bool> {
+    use crate::schema::account::dsl;
+    let updated = diesel::update(
+        dsl::account.find(account_id).filter(
+            dsl::totp_last_used_step
+                .is_null()
+                .or(dsl::totp_last_used_step.lt(step)),
+        ),
+    )
+    .set(dsl::totp_last_used_step.eq(Some(step)))
+    .execute(connection)?;
+    Ok(updated == 1)
+}
+
+fn replace_recovery_codes(
+    account_id: Uuid,
+    connection: &mut PgConnection,
+) -> ThothResult<RecoveryCodes> {
+    use crate::schema::account_recovery_code::dsl;
+
+    diesel::delete(dsl::account_recovery_code.filter(dsl::account_id.eq(account_id)))
+        .execute(connection)?;
+    let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+        .map(|_| generate_recovery_code())
+        .collect();
+    let new_codes: Vec<NewAccountRecoveryCode> = recovery_codes
+        .iter()
+        .map(|code| NewAccountRecoveryCode {
+            account_id,
+            code_hash: hash_recovery_code(code),
+        })
+        .collect();
+    diesel::insert_into(dsl::account_recovery_code)
+        .values(&new_codes)
+        .execute(connection)?;
+    Ok(RecoveryCodes { recovery_codes })
+}
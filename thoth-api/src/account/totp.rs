@@ -0,0 +1,281 @@
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Key, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use dotenv::dotenv;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
+use thoth_errors::{ThothError, ThothResult};
+
+const TOTP_ISSUER: &str = "Thoth";
+const TOTP_DIGITS: u32 = 6;
+const TOTP_STEP_SECONDS: u64 = 30;
+/// Number of time steps either side of the current one in which a code is still accepted
+const TOTP_ALLOWED_DRIFT: u64 = 1;
+const SECRET_LENGTH: usize = 20;
+const NONCE_LENGTH: usize = 12;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+pub const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_CHARSET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+const RECOVERY_CODE_GROUP_LENGTH: usize = 5;
+
+pub fn generate_secret() -> Vec<u8> {
+    let mut rng = rand::rng();
+    (0..SECRET_LENGTH).map(|_| rng.random::<u8>()).collect()
+}
+
+/// Encode bytes as unpadded RFC 4648 base32, the format expected by authenticator apps
+pub fn base32_encode(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for byte in data {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            output.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        output.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    output
+}
+
+/// Key URI used to provision authenticator apps, usually rendered as a QR code
+pub fn otpauth_url(email: &str, secret: &[u8]) -> String {
+    format!(
+        "otpauth://totp/{TOTP_ISSUER}:{email}?secret={}&issuer={TOTP_ISSUER}&algorithm=SHA1&digits={TOTP_DIGITS}&period={TOTP_STEP_SECONDS}",
+        base32_encode(secret)
+    )
+}
+
+/// HOTP value (RFC 4226) for the given counter
+fn hotp(secret: &[u8], counter: u64) -> String {
+    let mut mac =
+        <Hmac<Sha1> as Mac>::new_from_slice(secret).expect("HMAC can take a key of any length");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    )
+}
+
+/// TOTP code (RFC 6238) for the given unix time, as an authenticator app would display it
+pub fn totp_code_at(secret: &[u8], unix_time: u64) -> String {
+    hotp(secret, unix_time / TOTP_STEP_SECONDS)
+}
+
+/// Check a TOTP code (RFC 6238) against the given unix time, allowing for clock drift,
+/// and return the time step it matched. Steps up to and including `last_used_step` are
+/// rejected, so that a code cannot be replayed.
+fn verify_totp_at(
+    secret: &[u8],
+    code: &str,
+    unix_time: u64,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let current_step = unix_time / TOTP_STEP_SECONDS;
+    (current_step.saturating_sub(TOTP_ALLOWED_DRIFT)..=current_step + TOTP_ALLOWED_DRIFT)
+        .filter_map(|step| i64::try_from(step).ok().map(|signed| (step, signed)))
+        .filter(|(_, signed)| last_used_step.is_none_or(|last| *signed > last))
+        .find(|(step, _)| hotp(secret, *step) == code)
+        .map(|(_, signed)| signed)
+}
+
+pub fn verify_totp(secret: &[u8], code: &str, last_used_step: Option<i64>) -> Option<i64> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    verify_totp_at(secret, code, now, last_used_step)
+}
+
+fn encryption_key() -> ThothResult<Key<Aes256Gcm>> {
+    dotenv().ok();
+    let key_str = env::var("TOTP_ENCRYPTION_KEY").map_err(|_| {
+        ThothError::InternalError("TOTP_ENCRYPTION_KEY must be set to use 2FA".into())
+    })?;
+    Ok(Key::<Aes256Gcm>::clone_from_slice(&Sha256::digest(
+        key_str.as_bytes(),
+    )))
+}
+
+fn encrypt_with_key(secret: &[u8], key: &Key<Aes256Gcm>) -> ThothResult<String> {
+    let cipher = Aes256Gcm::new(key);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, secret)
+        .map_err(|_| ThothError::InternalError("Unable to encrypt TOTP secret".into()))?;
+    let mut payload = nonce.to_vec();
+    payload.extend(ciphertext);
+    Ok(BASE64.encode(payload))
+}
+
+fn decrypt_with_key(encrypted: &str, key: &Key<Aes256Gcm>) -> ThothResult<Vec<u8>> {
+    let payload = BASE64
+        .decode(encrypted)
+        .map_err(|_| ThothError::InternalError("Invalid TOTP secret encoding".into()))?;
+    if payload.len() <= NONCE_LENGTH {
+        return Err(ThothError::InternalError(
+            "Invalid TOTP secret encoding".into(),
+        ));
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+    Aes256Gcm::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| ThothError::InternalError("Unable to decrypt TOTP secret".into()))
+}
+
+/// Encrypt a TOTP secret for storage using the key in `TOTP_ENCRYPTION_KEY`
+pub fn encrypt_secret(secret: &[u8]) -> ThothResult<String> {
+    encrypt_with_key(secret, &encryption_key()?)
+}
+
+pub fn decrypt_secret(encrypted: &str) -> ThothResult<Vec<u8>> {
+    decrypt_with_key(encrypted, &encryption_key()?)
+}
+
+/// Generate a single-use recovery code of the form `xxxxx-xxxxx`
+pub fn generate_recovery_code() -> String {
+    let mut rng = rand::rng();
+    let mut group = || -> String {
+        (0..RECOVERY_CODE_GROUP_LENGTH)
+            .map(|_| {
+                RECOVERY_CODE_CHARSET[rng.random_range(0..RECOVERY_CODE_CHARSET.len())] as char
+            })
+            .collect()
+    };
+    format!("{}-{}", group(), group())
+}
+
+/// Recovery codes are random and high entropy, so a plain digest is sufficient for storage
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalised: String = code
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect();
+    Sha256::digest(normalised.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Shared secret used in the RFC 6238 test vectors
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn test_base32_encode() {
+        assert_eq!(base32_encode(b""), "");
+        assert_eq!(base32_encode(b"f"), "MY");
+        assert_eq!(base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(
+            base32_encode(RFC_SECRET),
+            "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ"
+        );
+    }
+
+    #[test]
+    fn test_hotp_rfc_vectors() {
+        // RFC 4226, Appendix D
+        assert_eq!(hotp(RFC_SECRET, 0), "755224");
+        assert_eq!(hotp(RFC_SECRET, 1), "287082");
+        assert_eq!(hotp(RFC_SECRET, 9), "520489");
+    }
+
+    #[test]
+    fn test_verify_totp_at() {
+        // RFC 6238, Appendix B (last six digits of the SHA1 values)
+        assert_eq!(verify_totp_at(RFC_SECRET, "287082", 59, None), Some(1));
+        assert_eq!(
+            verify_totp_at(RFC_SECRET, "081804", 1111111109, None),
+            Some(37037036)
+        );
+        assert!(verify_totp_at(RFC_SECRET, " 081804 ", 1111111109, None).is_some());
+        // Codes from adjacent time steps are accepted, further ones are not
+        assert_eq!(
+            verify_totp_at(RFC_SECRET, "081804", 1111111109 + 30, None),
+            Some(37037036)
+        );
+        assert!(verify_totp_at(RFC_SECRET, "081804", 1111111109 + 90, None).is_none());
+        assert!(verify_totp_at(RFC_SECRET, "000000", 1111111109, None).is_none());
+        assert!(verify_totp_at(RFC_SECRET, "81804", 1111111109, None).is_none());
+        assert!(verify_totp_at(RFC_SECRET, "08180a", 1111111109, None).is_none());
+    }
+
+    #[test]
+    fn test_verify_totp_rejects_replay() {
+        let step = verify_totp_at(RFC_SECRET, "081804", 1111111109, None).unwrap();
+        // The same code cannot be used again, neither in its own time step nor later in the drift window
+        assert!(verify_totp_at(RFC_SECRET, "081804", 1111111109, Some(step)).is_none());
+        assert!(verify_totp_at(RFC_SECRET, "081804", 1111111109 + 30, Some(step)).is_none());
+        // Nor can a code from an earlier step once a later one has been accepted
+        assert!(verify_totp_at(RFC_SECRET, "081804", 1111111109, Some(step + 1)).is_none());
+        // The code for the following step is still accepted
+        let next_code = hotp(RFC_SECRET, step as u64 + 1);
+        assert_eq!(
+            verify_totp_at(RFC_SECRET, &next_code, 1111111109, Some(step)),
+            Some(step + 1)
+        );
+    }
+
+    #[test]
+    fn test_otpauth_url() {
+        assert_eq!(
+            otpauth_url("user@thoth.pub", RFC_SECRET),
+            "otpauth://totp/Thoth:user@thoth.pub?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Thoth&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_secret() {
+        let key = Key::<Aes256Gcm>::clone_from_slice(&Sha256::digest(b"test key"));
+        let other_key = Key::<Aes256Gcm>::clone_from_slice(&Sha256::digest(b"other key"));
+        let secret = generate_secret();
+        let encrypted = encrypt_with_key(&secret, &key).unwrap();
+        assert_ne!(encrypted, BASE64.encode(&secret));
+        assert_eq!(decrypt_with_key(&encrypted, &key).unwrap(), secret);
+        assert!(decrypt_with_key(&encrypted, &other_key).is_err());
+        assert!(decrypt_with_key("not base64!", &key).is_err());
+    }
+
+    #[test]
+    fn test_recovery_codes() {
+        let code = generate_recovery_code();
+        assert_eq!(code.len(), RECOVERY_CODE_GROUP_LENGTH * 2 + 1);
+        assert_eq!(code.chars().nth(RECOVERY_CODE_GROUP_LENGTH), Some('-'));
+        assert_eq!(
+            hash_recovery_code("abcde-fghjk"),
+            hash_recovery_code(" ABCDE FGHJK ")
+        );
+        assert_ne!(
+            hash_recovery_code("abcde-fghjk"),
+            hash_recovery_code("abcde-fghjm")
+        );
+    }
+}
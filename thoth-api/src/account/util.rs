@@ -1,7 +1,12 @@
 use argon2rs::argon2i_simple;
+use dotenv::dotenv;
+use std::env;
+use thoth_errors::{ThothError, ThothResult};
 
 use super::model::Account;
 
+const DEFAULT_PASSWORD_MIN_LENGTH: usize = 12;
+
 pub fn make_salt() -> String {
     use rand::Rng;
     const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
@@ -28,3 +33,133 @@ pub fn verify(account: &Account, password: &str) -> bool {
 
     make_hash(password, salt) == hash.as_ref()
 }
+
+/// Rules that a password must satisfy before it can be stored against an account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: DEFAULT_PASSWORD_MIN_LENGTH,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_symbol: false,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Build the policy from the `PASSWORD_*` environment variables,
+    /// falling back to the defaults for any that are unset or unparseable
+    pub fn from_env() -> Self {
+        dotenv().ok();
+        let default = Self::default();
+        Self {
+            min_length: env_or("PASSWORD_MIN_LENGTH", default.min_length),
+            require_lowercase: env_or("PASSWORD_REQUIRE_LOWERCASE", default.require_lowercase),
+            require_uppercase: env_or("PASSWORD_REQUIRE_UPPERCASE", default.require_uppercase),
+            require_digit: env_or("PASSWORD_REQUIRE_DIGIT", default.require_digit),
+            require_symbol: env_or("PASSWORD_REQUIRE_SYMBOL", default.require_symbol),
+        }
+    }
+
+    pub fn validate(&self, password: &str) -> ThothResult<()> {
+        let mut unmet: Vec<String> = vec![];
+        if password.chars().count() < self.min_length {
+            unmet.push(format!("at least {} characters", self.min_length));
+        }
+        if self.require_lowercase && !password.chars().any(char::is_lowercase) {
+            unmet.push("a lowercase letter".to_string());
+        }
+        if self.require_uppercase && !password.chars().any(char::is_uppercase) {
+            unmet.push("an uppercase letter".to_string());
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            unmet.push("a digit".to_string());
+        }
+        if self.require_symbol && password.chars().all(char::is_alphanumeric) {
+            unmet.push("a symbol".to_string());
+        }
+        if unmet.is_empty() {
+            Ok(())
+        } else {
+            Err(ThothError::PasswordPolicyError(format!(
+                "it must contain {}",
+                unmet.join(", ")
+            )))
+        }
+    }
+}
+
+pub(crate) fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_policy_default() {
+        let policy = PasswordPolicy::default();
+        assert_eq!(policy.min_length, DEFAULT_PASSWORD_MIN_LENGTH);
+        assert!(policy.require_lowercase);
+        assert!(policy.require_uppercase);
+        assert!(policy.require_digit);
+        assert!(!policy.require_symbol);
+    }
+
+    #[test]
+    fn test_password_policy_validate() {
+        let policy = PasswordPolicy::default();
+        assert!(policy.validate("CorrectHorse42").is_ok());
+        assert_eq!(
+            policy.validate("Short1"),
+            Err(ThothError::PasswordPolicyError(
+                "it must contain at least 12 characters".to_string()
+            ))
+        );
+        assert_eq!(
+            policy.validate("alllowercaseletters"),
+            Err(ThothError::PasswordPolicyError(
+                "it must contain an uppercase letter, a digit".to_string()
+            ))
+        );
+        assert!(policy.validate("ALLUPPERCASE123").is_err());
+    }
+
+    #[test]
+    fn test_password_policy_validate_symbol() {
+        let policy = PasswordPolicy {
+            require_symbol: true,
+            ..Default::default()
+        };
+        assert!(policy.validate("CorrectHorse42").is_err());
+        assert!(policy.validate("CorrectHorse42!").is_ok());
+    }
+
+    #[test]
+    fn test_password_policy_min_length_counts_characters() {
+        let policy = PasswordPolicy {
+            min_length: 4,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+        };
+        // Four characters, eight bytes
+        assert!(policy.validate("ñöüé").is_ok());
+        assert!(policy.validate("ñöü").is_err());
+    }
+}
@@ -0,0 +1,490 @@
+use dotenv::dotenv;
+use graphql_parser::{
+    query::{self, Definition, OperationDefinition, Selection, SelectionSet},
+    schema,
+};
+use juniper::{InputValue, Variables};
+use std::collections::{HashMap, HashSet};
+use thoth_errors::{ThothError, ThothResult};
+
+use super::persisted_query::query_hash;
+use super::GraphQLRequest;
+use crate::account::model::DecodedToken;
+use crate::account::util::env_or;
+
+const QUERY_ROOT: &str = "QueryRoot";
+const LIMIT_ARGUMENT: &str = "limit";
+
+/// The kind of caller making a request, each of which is subject to different limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallerType {
+    Anonymous,
+    Authenticated,
+    Bot,
+}
+
+impl From<&DecodedToken> for CallerType {
+    fn from(token: &DecodedToken) -> Self {
+        match &token.jwt {
+            None => CallerType::Anonymous,
+            Some(jwt) if jwt.namespace.is_bot => CallerType::Bot,
+            Some(_) => CallerType::Authenticated,
+        }
+    }
+}
+
+/// Upper bounds applied to the requests of a single caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryLimits {
+    /// Maximum nesting of field selections
+    pub max_depth: u32,
+    /// Maximum estimated number of fields resolved, see [`QueryAnalyser`]
+    pub max_complexity: u64,
+    /// Number of requests a caller can make per minute, in bursts of up to the same size
+    pub requests_per_minute: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphqlLimits {
+    pub anonymous: QueryLimits,
+    pub authenticated: QueryLimits,
+    pub bot: QueryLimits,
+}
+
+impl Default for GraphqlLimits {
+    fn default() -> Self {
+        Self {
+            anonymous: QueryLimits {
+                max_depth: 10,
+                max_complexity: 50_000,
+                requests_per_minute: 120,
+            },
+            authenticated: QueryLimits {
+                max_depth: 15,
+                max_complexity: 100_000,
+                requests_per_minute: 300,
+            },
+            bot: QueryLimits {
+                max_depth: 15,
+                max_complexity: 500_000,
+                requests_per_minute: 600,
+            },
+        }
+    }
+}
+
+impl GraphqlLimits {
+    /// Build the limits from the `GRAPHQL_MAX_DEPTH_*`, `GRAPHQL_MAX_COMPLEXITY_*` and
+    /// `GRAPHQL_RATE_LIMIT_*` environment variables (suffixed with `ANONYMOUS`, `AUTHENTICATED`
+    /// or `BOT`), falling back to the defaults for any that are unset or unparseable
+    pub fn from_env() -> Self {
+        dotenv().ok();
+        let default = Self::default();
+        let caller_limits = |suffix: &str, default: QueryLimits| QueryLimits {
+            max_depth: env_or(&format!("GRAPHQL_MAX_DEPTH_{suffix}"), default.max_depth),
+            max_complexity: env_or(
+                &format!("GRAPHQL_MAX_COMPLEXITY_{suffix}"),
+                default.max_complexity,
+            ),
+            requests_per_minute: env_or(
+                &format!("GRAPHQL_RATE_LIMIT_{suffix}"),
+                default.requests_per_minute,
+            ),
+        };
+        Self {
+            anonymous: caller_limits("ANONYMOUS", default.anonymous),
+            authenticated: caller_limits("AUTHENTICATED", default.authenticated),
+            bot: caller_limits("BOT", default.bot),
+        }
+    }
+
+    pub fn for_caller(&self, caller: CallerType) -> &QueryLimits {
+        match caller {
+            CallerType::Anonymous => &self.anonymous,
+            CallerType::Authenticated => &self.authenticated,
+            CallerType::Bot => &self.bot,
+        }
+    }
+}
+
+/// Estimates the cost of a GraphQL request before it is executed.
+///
+/// Complexity approximates the number of fields resolved: every field costs one, and the
+/// selection of a field is multiplied by the number of records it requests, i.e. its `limit`
+/// argument. Top level query fields without one are multiplied by the argument's default.
+/// Nested lists without an explicit `limit` are bounded by the records linked to their parent
+/// rather than by the size of a table, so they are not multiplied, and their nesting is
+/// constrained by the depth limit instead. The same applies to the nested lists of trusted query
+/// documents, which request every record linked to the works they query.
+///
+/// Introspection fields count towards complexity but not depth, since their nesting is bounded
+/// by the schema and tools such as GraphiQL rely on deeply nested introspection queries.
+pub struct QueryAnalyser {
+    /// Default `limit` of each top level query field that accepts one
+    default_limits: HashMap<String, i64>,
+    /// Hashes of the query documents whose nested `limit` arguments are not charged
+    trusted_documents: HashSet<String>,
+}
+
+impl QueryAnalyser {
+    /// Read the default list sizes from the schema definition, as output by `Schema::as_sdl`
+    pub fn new(schema_sdl: &str) -> Self {
+        let mut default_limits = HashMap::new();
+        if let Ok(document) = schema::parse_schema::<&str>(schema_sdl) {
+            for definition in document.definitions {
+                if let schema::Definition::TypeDefinition(schema::TypeDefinition::Object(object)) =
+                    definition
+                {
+                    if object.name != QUERY_ROOT {
+                        continue;
+                    }
+                    for field in object.fields {
+                        let default_limit = field
+                            .arguments
+                            .iter()
+                            .find(|argument| argument.name == LIMIT_ARGUMENT)
+                            .and_then(|argument| argument.default_value.as_ref())
+                            .and_then(|value| match value {
+                                query::Value::Int(number) => number.as_i64(),
+                                _ => None,
+                            });
+                        if let Some(limit) = default_limit {
+                            default_limits.insert(field.name.to_string(), limit);
+                        }
+                    }
+                }
+            }
+        }
+        Self {
+            default_limits,
+            trusted_documents: HashSet::new(),
+        }
+    }
+
+    /// Trust a first-party query document, such as the one used by `thoth-client`, to pass large
+    /// `limit` arguments to nested lists in order to retrieve every record linked to a work.
+    /// Its top level fields are still charged in full.
+    pub fn trust(mut self, document: &str) -> Self {
+        self.trusted_documents.insert(query_hash(document));
+        self
+    }
+
+    /// Reject the request if it exceeds the given depth or complexity limits.
+    ///
+    /// Requests that cannot be parsed are let through, for the executor to report the error.
+    pub fn check(&self, request: &GraphQLRequest, limits: &QueryLimits) -> ThothResult<()> {
+        let document = match query::parse_query::<&str>(&request.query) {
+            Ok(document) => document,
+            Err(_) => return Ok(()),
+        };
+        let mut fragments = HashMap::new();
+        let mut operations = vec![];
+        for definition in &document.definitions {
+            match definition {
+                Definition::Fragment(fragment) => {
+                    fragments.insert(fragment.name, &fragment.selection_set);
+                }
+                Definition::Operation(operation) => {
+                    let (name, selection_set, is_query) = match operation {
+                        OperationDefinition::SelectionSet(set) => (None, set, true),
+                        OperationDefinition::Query(q) => (q.name, &q.selection_set, true),
+                        OperationDefinition::Mutation(m) => (m.name, &m.selection_set, false),
+                        OperationDefinition::Subscription(s) => (s.name, &s.selection_set, false),
+                    };
+                    if request.operation_name.is_none() || request.operation_name.as_deref() == name
+                    {
+                        operations.push((selection_set, is_query));
+                    }
+                }
+            }
+        }
+        let walker = Walker {
+            analyser: self,
+            fragments,
+            variables: request.variables(),
+            limits,
+            nested_limits: !self.trusted_documents.contains(&query_hash(&request.query)),
+        };
+        for (selection_set, is_query) in operations {
+            walker.cost(selection_set, 1, is_query, false, &mut vec![])?;
+        }
+        Ok(())
+    }
+}
+
+struct Walker<'a> {
+    analyser: &'a QueryAnalyser,
+    fragments: HashMap<&'a str, &'a SelectionSet<'a, &'a str>>,
+    variables: Variables,
+    limits: &'a QueryLimits,
+    /// Whether `limit` arguments of nested fields multiply the cost of their selection
+    nested_limits: bool,
+}
+
+impl<'a> Walker<'a> {
+    /// Number of records requested by a field, falling back to the schema's default `limit`
+    /// for top level query fields
+    fn multiplier(&self, field: &query::Field<'a, &'a str>, top_level: bool) -> u64 {
+        let limit = match field
+            .arguments
+            .iter()
+            .find(|(name, _)| *name == LIMIT_ARGUMENT)
+        {
+            Some((_, query::Value::Int(number))) => number.as_i64(),
+            Some((_, query::Value::Variable(variable))) => self
+                .variables
+                .get(*variable)
+                .and_then(InputValue::as_int_value)
+                .map(i64::from),
+            _ => None,
+        }
+        .or_else(|| {
+            top_level
+                .then(|| self.analyser.default_limits.get(field.name).copied())
+                .flatten()
+        });
+        limit.map_or(1, |limit| limit.max(1) as u64)
+    }
+
+    /// Cost of resolving a selection set once, with its fields at the given depth
+    fn cost(
+        &self,
+        selection_set: &'a SelectionSet<'a, &'a str>,
+        depth: u32,
+        is_query: bool,
+        introspection: bool,
+        fragment_path: &mut Vec<&'a str>,
+    ) -> ThothResult<u64> {
+        let mut cost: u64 = 0;
+        for selection in &selection_set.items {
+            let selection_cost = match selection {
+                Selection::Field(field) => {
+                    let introspection = introspection || field.name.starts_with("__");
+                    if !introspection && depth > self.limits.max_depth {
+                        return Err(ThothError::QueryDepthLimitError(self.limits.max_depth));
+                    }
+                    let children = self.cost(
+                        &field.selection_set,
+                        depth + 1,
+                        is_query,
+                        introspection,
+                        fragment_path,
+                    )?;
+                    let multiplier = match (is_query, depth == 1) {
+                        (true, true) => self.multiplier(field, true),
+                        (true, false) if self.nested_limits => self.multiplier(field, false),
+                        _ => 1,
+                    };
+                    children.saturating_mul(multiplier).saturating_add(1)
+                }
+                Selection::FragmentSpread(spread) => {
+                    // Cyclic fragments are invalid, and are reported when the query is validated
+                    if fragment_path.contains(&spread.fragment_name) {
+                        continue;
+                    }
+                    match self.fragments.get(spread.fragment_name) {
+                        Some(fragment) => {
+                            fragment_path.push(spread.fragment_name);
+                            let fragment_cost =
+                                self.cost(fragment, depth, is_query, introspection, fragment_path);
+                            fragment_path.pop();
+                            fragment_cost?
+                        }
+                        None => 0,
+                    }
+                }
+                Selection::InlineFragment(fragment) => self.cost(
+                    &fragment.selection_set,
+                    depth,
+                    is_query,
+                    introspection,
+                    fragment_path,
+                )?,
+            };
+            cost = cost.saturating_add(selection_cost);
+            if cost > self.limits.max_complexity {
+                return Err(ThothError::QueryComplexityLimitError(
+                    self.limits.max_complexity,
+                ));
+            }
+        }
+        Ok(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::model::{AccountAccess, Token};
+    use crate::graphql::model::create_schema;
+
+    const LIMITS: QueryLimits = QueryLimits {
+        max_depth: 4,
+        max_complexity: 1_000,
+        requests_per_minute: 60,
+    };
+
+    fn analyser() -> QueryAnalyser {
+        QueryAnalyser::new(&create_schema().as_sdl())
+    }
+
+    fn request(query: &str, variables: Option<serde_json::Value>) -> GraphQLRequest {
+        serde_json::from_value(serde_json::json!({
+            "query": query,
+            "variables": variables,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_limits_read_from_schema() {
+        let analyser = analyser();
+        assert_eq!(analyser.default_limits.get("works"), Some(&100));
+        assert_eq!(analyser.default_limits.get("work"), None);
+    }
+
+    #[test]
+    fn test_query_within_limits() {
+        let query = "{ works(limit: 10) { workId fullTitle contributions { fullName } } }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_ok());
+    }
+
+    #[test]
+    fn test_query_too_deep() {
+        let query = "{ works(limit: 1) { relations { relatedWork { relations { relatedWork { workId } } } } } }";
+        assert_eq!(
+            analyser().check(&request(query, None), &LIMITS),
+            Err(ThothError::QueryDepthLimitError(4))
+        );
+    }
+
+    #[test]
+    fn test_query_too_complex() {
+        let query = "{ works(limit: 500) { workId fullTitle doi } }";
+        assert_eq!(
+            analyser().check(&request(query, None), &LIMITS),
+            Err(ThothError::QueryComplexityLimitError(1_000))
+        );
+        // The default limit of 100 applies when none is given
+        let query = "{ works { workId fullTitle doi landingPage } }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_ok());
+        let query = "{ works { workId fullTitle title subtitle doi landingPage license place pageCount reference } }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_err());
+    }
+
+    #[test]
+    fn test_nested_limit_multiplies_cost() {
+        // Nested lists are not multiplied by their default limit...
+        let query =
+            "{ work(workId: \"00000000-0000-0000-0000-000000000000\") { references { doi } } }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_ok());
+        // ...but an explicit limit is charged in full
+        let query = "{ work(workId: \"00000000-0000-0000-0000-000000000000\") { references(limit: 99999) { doi } } }";
+        assert_eq!(
+            analyser().check(&request(query, None), &LIMITS),
+            Err(ThothError::QueryComplexityLimitError(1_000))
+        );
+        let query =
+            "query Refs($limit: Int) { works(limit: 10) { references(limit: $limit) { doi } } }";
+        let large = request(query, Some(serde_json::json!({ "limit": 200 })));
+        assert!(analyser().check(&large, &LIMITS).is_err());
+        let small = request(query, Some(serde_json::json!({ "limit": 5 })));
+        assert!(analyser().check(&small, &LIMITS).is_ok());
+    }
+
+    #[test]
+    fn test_limit_variables_and_fragments() {
+        let query = "query Works($limit: Int) { works(limit: $limit) { ...WorkFields } }
+            fragment WorkFields on Work { workId fullTitle doi }";
+        let analyser = analyser();
+        let small = request(query, Some(serde_json::json!({ "limit": 10 })));
+        let large = request(query, Some(serde_json::json!({ "limit": 10000 })));
+        assert!(analyser.check(&small, &LIMITS).is_ok());
+        assert_eq!(
+            analyser.check(&large, &LIMITS),
+            Err(ThothError::QueryComplexityLimitError(1_000))
+        );
+    }
+
+    #[test]
+    fn test_cyclic_fragments_terminate() {
+        let query = "{ works(limit: 1) { ...A } } fragment A on Work { workId ...A }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_ok());
+    }
+
+    #[test]
+    fn test_introspection_is_not_depth_limited() {
+        let query =
+            "{ __schema { types { fields { type { ofType { ofType { ofType { name } } } } } } } }";
+        assert!(analyser().check(&request(query, None), &LIMITS).is_ok());
+    }
+
+    #[test]
+    fn test_client_queries_within_default_anonymous_limits() {
+        let queries = include_str!("../../../thoth-client/assets/queries.graphql");
+        let variables = serde_json::json!({
+            "workId": "00000000-0000-0000-aaaa-000000000001",
+            "limit": 100,
+            "offset": 0,
+            "issuesLimit": 99999,
+            "languagesLimit": 99999,
+            "publicationsLimit": 99999,
+            "subjectsLimit": 99999,
+            "fundingsLimit": 99999,
+            "relationsLimit": 99999,
+            "referencesLimit": 99999,
+        });
+        let client_analyser = analyser().trust(queries);
+        for operation in [
+            "WorkQuery",
+            "WorksQuery",
+            "WorkCountQuery",
+            "WorksLastUpdatedQuery",
+        ] {
+            let mut request = request(queries, Some(variables.clone()));
+            request.operation_name = Some(operation.to_string());
+            assert_eq!(
+                client_analyser.check(&request, &GraphqlLimits::default().anonymous),
+                Ok(()),
+                "{operation}"
+            );
+        }
+        // The same nested limits are charged in full in any other document
+        let mut request = request(queries, Some(variables));
+        request.operation_name = Some("WorkQuery".to_string());
+        assert!(analyser()
+            .check(&request, &GraphqlLimits::default().anonymous)
+            .is_err());
+    }
+
+    #[test]
+    fn test_caller_type() {
+        let token = |is_superuser: bool, is_bot: bool| DecodedToken {
+            jwt: Some(Token {
+                sub: "editor@thoth.pub".to_string(),
+                exp: 0,
+                iat: 0,
+                jti: "".to_string(),
+                namespace: AccountAccess {
+                    is_superuser,
+                    is_bot,
+                    linked_publishers: vec![],
+                },
+            }),
+        };
+        assert_eq!(
+            CallerType::from(&DecodedToken { jwt: None }),
+            CallerType::Anonymous
+        );
+        assert_eq!(
+            CallerType::from(&token(false, false)),
+            CallerType::Authenticated
+        );
+        // Superusers are subject to the same limits as any other authenticated account
+        assert_eq!(
+            CallerType::from(&token(true, false)),
+            CallerType::Authenticated
+        );
+        assert_eq!(CallerType::from(&token(false, true)), CallerType::Bot);
+    }
+}
@@ -1,5 +1,9 @@
 #[cfg(feature = "backend")]
+pub mod limits;
+#[cfg(feature = "backend")]
 pub mod model;
+#[cfg(feature = "backend")]
+pub mod persisted_query;
 pub mod utils;
 
 #[cfg(feature = "backend")]
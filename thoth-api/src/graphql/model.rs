@@ -7,23 +7,40 @@ use uuid::Uuid;
 use crate::account::model::AccountAccess;
 use crate::account::model::DecodedToken;
 use crate::db::PgPool;
+use crate::markup::{validate_markup, validate_markup_change};
 use crate::model::affiliation::*;
+use crate::model::alternative_name::*;
+use crate::model::asset::*;
 use crate::model::contribution::*;
 use crate::model::contributor::*;
+use crate::model::event::*;
+use crate::model::event_sponsor::*;
 use crate::model::funding::*;
+use crate::model::identifier::{FunderId, IdentifierType, Isni, Lcnaf, Ringgold, Viaf, WikidataId};
 use crate::model::imprint::*;
 use crate::model::institution::*;
 use crate::model::issue::*;
 use crate::model::language::*;
+use crate::model::license::is_open_access;
 use crate::model::location::*;
 use crate::model::price::*;
+use crate::model::prize::*;
 use crate::model::publication::*;
+use crate::model::publication_identifier::*;
 use crate::model::publisher::*;
 use crate::model::reference::*;
+use crate::model::sales_rights::*;
 use crate::model::series::*;
+use crate::model::series_contribution::*;
 use crate::model::subject::*;
+use crate::model::supporting_resource::*;
+use crate::model::text_content::*;
+use crate::model::usage_constraint::*;
 use crate::model::work::*;
+use crate::model::work_abstract::*;
+use crate::model::work_identifier::*;
 use crate::model::work_relation::*;
+use crate::model::work_title::*;
 use crate::model::Convert;
 use crate::model::Crud;
 use crate::model::Doi;
@@ -72,6 +89,22 @@ impl Default for ContributionOrderBy {
     }
 }
 
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting series contributions list")]
+pub struct SeriesContributionOrderBy {
+    pub field: SeriesContributionField,
+    pub direction: Direction,
+}
+
+impl Default for SeriesContributionOrderBy {
+    fn default() -> SeriesContributionOrderBy {
+        SeriesContributionOrderBy {
+            field: SeriesContributionField::ContributionOrdinal,
+            direction: Default::default(),
+        }
+    }
+}
+
 #[derive(juniper::GraphQLInputObject)]
 #[graphql(description = "Field and order to use when sorting issues list")]
 pub struct IssueOrderBy {
@@ -104,6 +137,214 @@ impl Default for LanguageOrderBy {
     }
 }
 
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting titles list")]
+pub struct WorkTitleOrderBy {
+    pub field: WorkTitleField,
+    pub direction: Direction,
+}
+
+impl Default for WorkTitleOrderBy {
+    fn default() -> WorkTitleOrderBy {
+        WorkTitleOrderBy {
+            field: WorkTitleField::LanguageCode,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting abstracts list")]
+pub struct WorkAbstractOrderBy {
+    pub field: WorkAbstractField,
+    pub direction: Direction,
+}
+
+impl Default for WorkAbstractOrderBy {
+    fn default() -> WorkAbstractOrderBy {
+        WorkAbstractOrderBy {
+            field: WorkAbstractField::LanguageCode,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting alternative names list")]
+pub struct AlternativeNameOrderBy {
+    pub field: AlternativeNameField,
+    pub direction: Direction,
+}
+
+impl Default for AlternativeNameOrderBy {
+    fn default() -> AlternativeNameOrderBy {
+        AlternativeNameOrderBy {
+            field: AlternativeNameField::NameType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting work identifiers list")]
+pub struct WorkIdentifierOrderBy {
+    pub field: WorkIdentifierField,
+    pub direction: Direction,
+}
+
+impl Default for WorkIdentifierOrderBy {
+    fn default() -> WorkIdentifierOrderBy {
+        WorkIdentifierOrderBy {
+            field: WorkIdentifierField::IdentifierType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting events list")]
+pub struct EventOrderBy {
+    pub field: EventField,
+    pub direction: Direction,
+}
+
+impl Default for EventOrderBy {
+    fn default() -> EventOrderBy {
+        EventOrderBy {
+            field: EventField::EventStartDate,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting event sponsors list")]
+pub struct EventSponsorOrderBy {
+    pub field: EventSponsorField,
+    pub direction: Direction,
+}
+
+impl Default for EventSponsorOrderBy {
+    fn default() -> EventSponsorOrderBy {
+        EventSponsorOrderBy {
+            field: EventSponsorField::SponsorOrdinal,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting prizes list")]
+pub struct PrizeOrderBy {
+    pub field: PrizeField,
+    pub direction: Direction,
+}
+
+impl Default for PrizeOrderBy {
+    fn default() -> PrizeOrderBy {
+        PrizeOrderBy {
+            field: PrizeField::PrizeYear,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting text contents list")]
+pub struct TextContentOrderBy {
+    pub field: TextContentField,
+    pub direction: Direction,
+}
+
+impl Default for TextContentOrderBy {
+    fn default() -> TextContentOrderBy {
+        TextContentOrderBy {
+            field: TextContentField::TextType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting supporting resources list")]
+pub struct SupportingResourceOrderBy {
+    pub field: SupportingResourceField,
+    pub direction: Direction,
+}
+
+impl Default for SupportingResourceOrderBy {
+    fn default() -> SupportingResourceOrderBy {
+        SupportingResourceOrderBy {
+            field: SupportingResourceField::ResourceContentType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting assets list")]
+pub struct AssetOrderBy {
+    pub field: AssetField,
+    pub direction: Direction,
+}
+
+impl Default for AssetOrderBy {
+    fn default() -> AssetOrderBy {
+        AssetOrderBy {
+            field: AssetField::CreatedAt,
+            direction: Direction::Desc,
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting publication identifiers list")]
+pub struct PublicationIdentifierOrderBy {
+    pub field: PublicationIdentifierField,
+    pub direction: Direction,
+}
+
+impl Default for PublicationIdentifierOrderBy {
+    fn default() -> PublicationIdentifierOrderBy {
+        PublicationIdentifierOrderBy {
+            field: PublicationIdentifierField::IdentifierType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting sales rights list")]
+pub struct SalesRightsOrderBy {
+    pub field: SalesRightsField,
+    pub direction: Direction,
+}
+
+impl Default for SalesRightsOrderBy {
+    fn default() -> SalesRightsOrderBy {
+        SalesRightsOrderBy {
+            field: SalesRightsField::SalesRightsType,
+            direction: Default::default(),
+        }
+    }
+}
+
+#[derive(juniper::GraphQLInputObject)]
+#[graphql(description = "Field and order to use when sorting usage constraints list")]
+pub struct UsageConstraintOrderBy {
+    pub field: UsageConstraintField,
+    pub direction: Direction,
+}
+
+impl Default for UsageConstraintOrderBy {
+    fn default() -> UsageConstraintOrderBy {
+        UsageConstraintOrderBy {
+            field: UsageConstraintField::UsageType,
+            direction: Default::default(),
+        }
+    }
+}
+
 #[derive(juniper::GraphQLInputObject)]
 #[graphql(description = "Field and order to use when sorting prices list")]
 pub struct PriceOrderBy {
@@ -743,7 +984,7 @@ impl QueryRoot {
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name, last_name and orcid"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name, last_name, orcid, isni, viaf, wikidata and lcnaf"
         )]
         filter: Option<String>,
         #[graphql(
@@ -781,13 +1022,85 @@ impl QueryRoot {
         context: &Context,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name, last_name and orcid"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name, last_name, orcid, isni, viaf, wikidata and lcnaf"
         )]
         filter: Option<String>,
     ) -> FieldResult<i32> {
         Contributor::count(&context.db, filter, vec![], vec![], vec![], None).map_err(|e| e.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of alternative names")]
+    fn alternative_names(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name and last_name"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = AlternativeNameOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AlternativeNameOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific name types to filter by"
+        )]
+        name_types: Option<Vec<NameType>>,
+    ) -> FieldResult<Vec<AlternativeName>> {
+        AlternativeName::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+            name_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Query a single alternative name using its ID")]
+    fn alternative_name(
+        context: &Context,
+        #[graphql(description = "Thoth alternative name ID to search on")]
+        alternative_name_id: Uuid,
+    ) -> FieldResult<AlternativeName> {
+        AlternativeName::from_id(&context.db, &alternative_name_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the total number of alternative names of contributors")]
+    fn alternative_name_count(
+        context: &Context,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name and last_name"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific name types to filter by"
+        )]
+        name_types: Option<Vec<NameType>>,
+    ) -> FieldResult<i32> {
+        AlternativeName::count(
+            &context.db,
+            filter,
+            vec![],
+            name_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
     #[graphql(description = "Query the full list of contributions")]
     fn contributions(
         context: &Context,
@@ -853,6 +1166,72 @@ impl QueryRoot {
         .map_err(|e| e.into())
     }
 
+    #[graphql(description = "Query the full list of series contributions")]
+    fn series_contributions(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SeriesContributionOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SeriesContributionOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        contribution_types: Option<Vec<SeriesContributionType>>,
+    ) -> FieldResult<Vec<SeriesContribution>> {
+        SeriesContribution::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            contribution_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Query a single series contribution using its ID")]
+    fn series_contribution(
+        context: &Context,
+        #[graphql(description = "Thoth series contribution ID to search on")]
+        series_contribution_id: Uuid,
+    ) -> FieldResult<SeriesContribution> {
+        SeriesContribution::from_id(&context.db, &series_contribution_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the total number of series contributions")]
+    fn series_contribution_count(
+        context: &Context,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        contribution_types: Option<Vec<SeriesContributionType>>,
+    ) -> FieldResult<i32> {
+        SeriesContribution::count(
+            &context.db,
+            None,
+            vec![],
+            contribution_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
     #[graphql(description = "Query the full list of series")]
     fn serieses(
         context: &Context,
@@ -1070,16 +1449,22 @@ impl QueryRoot {
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of locations")]
-    fn locations(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of titles")]
+    fn work_titles(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = LocationOrderBy::default(),
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title and title"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = WorkTitleOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<LocationOrderBy>,
+        order: Option<WorkTitleOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
@@ -1087,64 +1472,75 @@ impl QueryRoot {
         publishers: Option<Vec<Uuid>>,
         #[graphql(
             default = vec![],
-            description = "Specific platforms to filter by"
+            description = "Specific languages to filter by"
         )]
-        location_platforms: Option<Vec<LocationPlatform>>,
-    ) -> FieldResult<Vec<Location>> {
-        Location::all(
+        language_codes: Option<Vec<LanguageCode>>,
+    ) -> FieldResult<Vec<WorkTitle>> {
+        WorkTitle::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            None,
+            filter,
             order.unwrap_or_default(),
             publishers.unwrap_or_default(),
             None,
             None,
-            location_platforms.unwrap_or_default(),
+            language_codes.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single location using its ID")]
-    fn location(
+    #[graphql(description = "Query a single title using its ID")]
+    fn work_title(
         context: &Context,
-        #[graphql(description = "Thoth location ID to search on")] location_id: Uuid,
-    ) -> FieldResult<Location> {
-        Location::from_id(&context.db, &location_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth title ID to search on")] work_title_id: Uuid,
+    ) -> FieldResult<WorkTitle> {
+        WorkTitle::from_id(&context.db, &work_title_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of locations associated to works")]
-    fn location_count(
+    #[graphql(description = "Get the total number of titles associated to works")]
+    fn work_title_count(
         context: &Context,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title and title"
+        )]
+        filter: Option<String>,
         #[graphql(
             default = vec![],
-            description = "Specific platforms to filter by"
+            description = "Specific languages to filter by"
         )]
-        location_platforms: Option<Vec<LocationPlatform>>,
+        language_codes: Option<Vec<LanguageCode>>,
     ) -> FieldResult<i32> {
-        Location::count(
+        WorkTitle::count(
             &context.db,
-            None,
+            filter,
             vec![],
-            location_platforms.unwrap_or_default(),
+            language_codes.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of prices")]
-    fn prices(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of abstracts")]
+    fn work_abstracts(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = PriceOrderBy::default(),
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on content"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = WorkAbstractOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<PriceOrderBy>,
+        order: Option<WorkAbstractOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
@@ -1152,69 +1548,85 @@ impl QueryRoot {
         publishers: Option<Vec<Uuid>>,
         #[graphql(
             default = vec![],
-            description = "Specific currencies to filter by"
+            description = "Specific languages to filter by"
         )]
-        currency_codes: Option<Vec<CurrencyCode>>,
-    ) -> FieldResult<Vec<Price>> {
-        Price::all(
+        language_codes: Option<Vec<LanguageCode>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by"
+        )]
+        abstract_types: Option<Vec<AbstractType>>,
+    ) -> FieldResult<Vec<WorkAbstract>> {
+        WorkAbstract::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            None,
+            filter,
             order.unwrap_or_default(),
             publishers.unwrap_or_default(),
             None,
             None,
-            currency_codes.unwrap_or_default(),
-            vec![],
+            language_codes.unwrap_or_default(),
+            abstract_types.unwrap_or_default(),
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single price using its ID")]
-    fn price(
+    #[graphql(description = "Query a single abstract using its ID")]
+    fn work_abstract(
         context: &Context,
-        #[graphql(description = "Thoth price ID to search on")] price_id: Uuid,
-    ) -> FieldResult<Price> {
-        Price::from_id(&context.db, &price_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth abstract ID to search on")] work_abstract_id: Uuid,
+    ) -> FieldResult<WorkAbstract> {
+        WorkAbstract::from_id(&context.db, &work_abstract_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of prices associated to works")]
-    fn price_count(
+    #[graphql(description = "Get the total number of abstracts associated to works")]
+    fn work_abstract_count(
         context: &Context,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on content"
+        )]
+        filter: Option<String>,
         #[graphql(
             default = vec![],
-            description = "Specific currencies to filter by"
+            description = "Specific languages to filter by"
         )]
-        currency_codes: Option<Vec<CurrencyCode>>,
+        language_codes: Option<Vec<LanguageCode>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by"
+        )]
+        abstract_types: Option<Vec<AbstractType>>,
     ) -> FieldResult<i32> {
-        Price::count(
+        WorkAbstract::count(
             &context.db,
-            None,
-            vec![],
-            currency_codes.unwrap_or_default(),
+            filter,
             vec![],
+            language_codes.unwrap_or_default(),
+            abstract_types.unwrap_or_default(),
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of subjects")]
-    fn subjects(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of work identifiers")]
+    fn work_identifiers(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on identifier_value"
         )]
         filter: Option<String>,
         #[graphql(
-            default = SubjectOrderBy::default(),
+            default = WorkIdentifierOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<SubjectOrderBy>,
+        order: Option<WorkIdentifierOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
@@ -1222,11 +1634,11 @@ impl QueryRoot {
         publishers: Option<Vec<Uuid>>,
         #[graphql(
             default = vec![],
-            description = "Specific types to filter by",
+            description = "Specific identifier types to filter by"
         )]
-        subject_types: Option<Vec<SubjectType>>,
-    ) -> FieldResult<Vec<Subject>> {
-        Subject::all(
+        identifier_types: Option<Vec<IdentifierType>>,
+    ) -> FieldResult<Vec<WorkIdentifier>> {
+        WorkIdentifier::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
@@ -1235,119 +1647,148 @@ impl QueryRoot {
             publishers.unwrap_or_default(),
             None,
             None,
-            subject_types.unwrap_or_default(),
+            identifier_types.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single subject using its ID")]
-    fn subject(
+    #[graphql(description = "Query a single work identifier using its ID")]
+    fn work_identifier(
         context: &Context,
-        #[graphql(description = "Thoth subject ID to search on")] subject_id: Uuid,
-    ) -> FieldResult<Subject> {
-        Subject::from_id(&context.db, &subject_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth work identifier ID to search on")] work_identifier_id: Uuid,
+    ) -> FieldResult<WorkIdentifier> {
+        WorkIdentifier::from_id(&context.db, &work_identifier_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of subjects associated to works")]
-    fn subject_count(
+    #[graphql(description = "Get the total number of identifiers associated to works")]
+    fn work_identifier_count(
         context: &Context,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on identifier_value"
         )]
         filter: Option<String>,
         #[graphql(
             default = vec![],
-            description = "Specific types to filter by",
+            description = "Specific identifier types to filter by"
         )]
-        subject_types: Option<Vec<SubjectType>>,
+        identifier_types: Option<Vec<IdentifierType>>,
     ) -> FieldResult<i32> {
-        Subject::count(
+        WorkIdentifier::count(
             &context.db,
             filter,
             vec![],
-            subject_types.unwrap_or_default(),
+            identifier_types.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of institutions")]
-    fn institutions(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of prizes")]
+    fn prizes(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on institution_name, ror and institution_doi"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on prize_name"
         )]
         filter: Option<String>,
         #[graphql(
-            default = InstitutionOrderBy::default(),
+            default = PrizeOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<InstitutionOrderBy>,
-    ) -> FieldResult<Vec<Institution>> {
-        Institution::all(
+        order: Option<PrizeOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific prize achievement codes to filter by"
+        )]
+        prize_codes: Option<Vec<PrizeCode>>,
+    ) -> FieldResult<Vec<Prize>> {
+        Prize::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
             filter,
             order.unwrap_or_default(),
-            vec![],
+            publishers.unwrap_or_default(),
             None,
             None,
-            vec![],
+            prize_codes.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single institution using its ID")]
-    fn institution(
+    #[graphql(description = "Query a single prize using its ID")]
+    fn prize(
         context: &Context,
-        #[graphql(description = "Thoth institution ID to search on")] institution_id: Uuid,
-    ) -> FieldResult<Institution> {
-        Institution::from_id(&context.db, &institution_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth prize ID to search on")] prize_id: Uuid,
+    ) -> FieldResult<Prize> {
+        Prize::from_id(&context.db, &prize_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of institutions")]
-    fn institution_count(
+    #[graphql(description = "Get the total number of prizes associated to works")]
+    fn prize_count(
         context: &Context,
         #[graphql(
             default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on institution_name, ror and institution_doi"
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on prize_name"
         )]
         filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific prize achievement codes to filter by"
+        )]
+        prize_codes: Option<Vec<PrizeCode>>,
     ) -> FieldResult<i32> {
-        Institution::count(&context.db, filter, vec![], vec![], vec![], None).map_err(|e| e.into())
+        Prize::count(
+            &context.db,
+            filter,
+            vec![],
+            prize_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of fundings")]
-    fn fundings(
+    #[graphql(description = "Query the full list of events")]
+    fn events(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = FundingOrderBy::default(),
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on event_name and event_acronym"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = EventOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<FundingOrderBy>,
+        order: Option<EventOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Funding>> {
-        Funding::all(
+    ) -> FieldResult<Vec<Event>> {
+        Event::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            None,
+            filter,
             order.unwrap_or_default(),
             publishers.unwrap_or_default(),
             None,
@@ -1359,36 +1800,43 @@ impl QueryRoot {
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single funding using its ID")]
-    fn funding(
+    #[graphql(description = "Query a single event using its ID")]
+    fn event(
         context: &Context,
-        #[graphql(description = "Thoth funding ID to search on")] funding_id: Uuid,
-    ) -> FieldResult<Funding> {
-        Funding::from_id(&context.db, &funding_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth event ID to search on")] event_id: Uuid,
+    ) -> FieldResult<Event> {
+        Event::from_id(&context.db, &event_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of funding instances associated to works")]
-    fn funding_count(context: &Context) -> FieldResult<i32> {
-        Funding::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
+    #[graphql(description = "Get the total number of events associated to works")]
+    fn event_count(
+        context: &Context,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on event_name and event_acronym"
+        )]
+        filter: Option<String>,
+    ) -> FieldResult<i32> {
+        Event::count(&context.db, filter, vec![], vec![], vec![], None).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of affiliations")]
-    fn affiliations(
+    #[graphql(description = "Query the full list of event sponsors")]
+    fn event_sponsors(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = AffiliationOrderBy::default(),
+            default = EventSponsorOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<AffiliationOrderBy>,
+        order: Option<EventSponsorOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Affiliation>> {
-        Affiliation::all(
+    ) -> FieldResult<Vec<EventSponsor>> {
+        EventSponsor::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
@@ -1404,1423 +1852,4353 @@ impl QueryRoot {
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single affiliation using its ID")]
-    fn affiliation(
+    #[graphql(description = "Query a single event sponsor using its ID")]
+    fn event_sponsor(
         context: &Context,
-        #[graphql(description = "Thoth affiliation ID to search on")] affiliation_id: Uuid,
-    ) -> FieldResult<Affiliation> {
-        Affiliation::from_id(&context.db, &affiliation_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth event sponsor ID to search on")] event_sponsor_id: Uuid,
+    ) -> FieldResult<EventSponsor> {
+        EventSponsor::from_id(&context.db, &event_sponsor_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of affiliations")]
-    fn affiliation_count(context: &Context) -> FieldResult<i32> {
-        Affiliation::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
+    #[graphql(description = "Get the total number of event sponsors")]
+    fn event_sponsor_count(context: &Context) -> FieldResult<i32> {
+        EventSponsor::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query the full list of references")]
-    fn references(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of text contents")]
+    fn text_contents(
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = ReferenceOrderBy::default(),
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on content, text_author and source_title"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = TextContentOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<ReferenceOrderBy>,
+        order: Option<TextContentOrderBy>,
         #[graphql(
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs"
         )]
         publishers: Option<Vec<Uuid>>,
-    ) -> FieldResult<Vec<Reference>> {
-        Reference::all(
+        #[graphql(
+            default = vec![],
+            description = "Specific text types to filter by"
+        )]
+        text_types: Option<Vec<TextContentType>>,
+    ) -> FieldResult<Vec<TextContent>> {
+        TextContent::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            None,
+            filter,
             order.unwrap_or_default(),
             publishers.unwrap_or_default(),
             None,
             None,
-            vec![],
+            text_types.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Query a single reference using its ID")]
-    fn reference(
+    #[graphql(description = "Query a single text content using its ID")]
+    fn text_content(
         context: &Context,
-        #[graphql(description = "Thoth reference ID to search on")] reference_id: Uuid,
-    ) -> FieldResult<Reference> {
-        Reference::from_id(&context.db, &reference_id).map_err(|e| e.into())
+        #[graphql(description = "Thoth text content ID to search on")] text_content_id: Uuid,
+    ) -> FieldResult<TextContent> {
+        TextContent::from_id(&context.db, &text_content_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the total number of references")]
-    fn reference_count(context: &Context) -> FieldResult<i32> {
-        Reference::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
+    #[graphql(description = "Get the total number of text contents associated to works")]
+    fn text_content_count(
+        context: &Context,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on content, text_author and source_title"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific text types to filter by"
+        )]
+        text_types: Option<Vec<TextContentType>>,
+    ) -> FieldResult<i32> {
+        TextContent::count(
+            &context.db,
+            filter,
+            vec![],
+            text_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
-}
-
-pub struct MutationRoot;
 
-#[juniper::graphql_object(Context = Context)]
-impl MutationRoot {
-    #[graphql(description = "Create a new work with the specified values")]
-    fn create_work(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of supporting resources")]
+    fn supporting_resources(
         context: &Context,
-        #[graphql(description = "Values for work to be created")] data: NewWork,
-    ) -> FieldResult<Work> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
-
-        data.validate()?;
-
-        Work::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on resource_url and caption"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = SupportingResourceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SupportingResourceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific resource content types to filter by"
+        )]
+        resource_content_types: Option<Vec<ResourceContentType>>,
+    ) -> FieldResult<Vec<SupportingResource>> {
+        SupportingResource::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            resource_content_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new publisher with the specified values")]
-    fn create_publisher(
+    #[graphql(description = "Query a single supporting resource using its ID")]
+    fn supporting_resource(
         context: &Context,
-        #[graphql(description = "Values for publisher to be created")] data: NewPublisher,
-    ) -> FieldResult<Publisher> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        // Only superusers can create new publishers - NewPublisher has no ID field
-        if !context.account_access.is_superuser {
-            return Err(ThothError::Unauthorised.into());
-        }
-
-        Publisher::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth supporting resource ID to search on")]
+        supporting_resource_id: Uuid,
+    ) -> FieldResult<SupportingResource> {
+        SupportingResource::from_id(&context.db, &supporting_resource_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new imprint with the specified values")]
-    fn create_imprint(
+    #[graphql(description = "Get the total number of supporting resources associated to works")]
+    fn supporting_resource_count(
         context: &Context,
-        #[graphql(description = "Values for imprint to be created")] data: NewImprint,
-    ) -> FieldResult<Imprint> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context.account_access.can_edit(data.publisher_id)?;
-
-        Imprint::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on resource_url and caption"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific resource content types to filter by"
+        )]
+        resource_content_types: Option<Vec<ResourceContentType>>,
+    ) -> FieldResult<i32> {
+        SupportingResource::count(
+            &context.db,
+            filter,
+            vec![],
+            resource_content_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new contributor with the specified values")]
-    fn create_contributor(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of files uploaded to Thoth's asset storage")]
+    fn assets(
         context: &Context,
-        #[graphql(description = "Values for contributor to be created")] data: NewContributor,
-    ) -> FieldResult<Contributor> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        Contributor::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on filename"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = AssetOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AssetOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific asset types to filter by"
+        )]
+        asset_types: Option<Vec<AssetType>>,
+    ) -> FieldResult<Vec<Asset>> {
+        Asset::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            asset_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new contribution with the specified values")]
-    fn create_contribution(
+    #[graphql(description = "Query a single asset using its ID")]
+    fn asset(
         context: &Context,
-        #[graphql(description = "Values for contribution to be created")] data: NewContribution,
-    ) -> FieldResult<Contribution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        Contribution::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth asset ID to search on")] asset_id: Uuid,
+    ) -> FieldResult<Asset> {
+        Asset::from_id(&context.db, &asset_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new publication with the specified values")]
-    fn create_publication(
+    #[graphql(description = "Get the total number of files uploaded to Thoth's asset storage")]
+    fn asset_count(
         context: &Context,
-        #[graphql(description = "Values for publication to be created")] data: NewPublication,
-    ) -> FieldResult<Publication> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        data.validate(&context.db)?;
-
-        Publication::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on filename"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific asset types to filter by"
+        )]
+        asset_types: Option<Vec<AssetType>>,
+    ) -> FieldResult<i32> {
+        Asset::count(
+            &context.db,
+            filter,
+            vec![],
+            asset_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new series with the specified values")]
-    fn create_series(
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Query the full list of publication identifiers")]
+    fn publication_identifiers(
         context: &Context,
-        #[graphql(description = "Values for series to be created")] data: NewSeries,
-    ) -> FieldResult<Series> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
-
-        Series::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on identifier_value"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = PublicationIdentifierOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PublicationIdentifierOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific identifier types to filter by"
+        )]
+        identifier_types: Option<Vec<IdentifierType>>,
+    ) -> FieldResult<Vec<PublicationIdentifier>> {
+        PublicationIdentifier::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            identifier_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new issue with the specified values")]
-    fn create_issue(
+    #[graphql(description = "Query a single publication identifier using its ID")]
+    fn publication_identifier(
         context: &Context,
-        #[graphql(description = "Values for issue to be created")] data: NewIssue,
-    ) -> FieldResult<Issue> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        data.imprints_match(&context.db)?;
-
-        Issue::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth publication identifier ID to search on")]
+        publication_identifier_id: Uuid,
+    ) -> FieldResult<PublicationIdentifier> {
+        PublicationIdentifier::from_id(&context.db, &publication_identifier_id)
+            .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new language with the specified values")]
-    fn create_language(
+    #[graphql(description = "Get the total number of identifiers associated to publications")]
+    fn publication_identifier_count(
         context: &Context,
-        #[graphql(description = "Values for language to be created")] data: NewLanguage,
-    ) -> FieldResult<Language> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        Language::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on identifier_value"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific identifier types to filter by"
+        )]
+        identifier_types: Option<Vec<IdentifierType>>,
+    ) -> FieldResult<i32> {
+        PublicationIdentifier::count(
+            &context.db,
+            filter,
+            vec![],
+            identifier_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new institution with the specified values")]
-    fn create_institution(
+    #[graphql(description = "Query the full list of sales rights")]
+    fn sales_rights(
         context: &Context,
-        #[graphql(description = "Values for institution to be created")] data: NewInstitution,
-    ) -> FieldResult<Institution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        Institution::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SalesRightsOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SalesRightsOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> FieldResult<Vec<SalesRights>> {
+        SalesRights::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            sales_rights_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new funding with the specified values")]
-    fn create_funding(
+    #[graphql(description = "Query a single sales rights record using its ID")]
+    fn sales_rights_record(
         context: &Context,
-        #[graphql(description = "Values for funding to be created")] data: NewFunding,
-    ) -> FieldResult<Funding> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        Funding::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth sales rights ID to search on")] sales_rights_id: Uuid,
+    ) -> FieldResult<SalesRights> {
+        SalesRights::from_id(&context.db, &sales_rights_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new location with the specified values")]
-    fn create_location(
+    #[graphql(description = "Get the total number of sales rights records")]
+    fn sales_rights_count(
         context: &Context,
-        #[graphql(description = "Values for location to be created")] data: NewLocation,
-    ) -> FieldResult<Location> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        // Only superusers can create new locations where Location Platform is Thoth
-        if !context.account_access.is_superuser && data.location_platform == LocationPlatform::Thoth
-        {
-            return Err(ThothError::ThothLocationError.into());
-        }
-        context
-            .account_access
-            .can_edit(publisher_id_from_publication_id(
-                &context.db,
-                data.publication_id,
-            )?)?;
-
-        if data.canonical {
-            data.canonical_record_complete(&context.db)?;
-        } else {
-            data.can_be_non_canonical(&context.db)?;
-        }
-
-        Location::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> FieldResult<i32> {
+        SalesRights::count(
+            &context.db,
+            None,
+            vec![],
+            sales_rights_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new price with the specified values")]
-    fn create_price(
+    #[graphql(description = "Query the full list of usage constraints")]
+    fn usage_constraints(
         context: &Context,
-        #[graphql(description = "Values for price to be created")] data: NewPrice,
-    ) -> FieldResult<Price> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_publication_id(
-                &context.db,
-                data.publication_id,
-            )?)?;
-
-        if data.unit_price <= 0.0 {
-            // Prices must be non-zero (and non-negative).
-            return Err(ThothError::PriceZeroError.into());
-        }
-
-        Price::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = UsageConstraintOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<UsageConstraintOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of usage to filter by"
+        )]
+        usage_types: Option<Vec<UsageType>>,
+    ) -> FieldResult<Vec<UsageConstraint>> {
+        UsageConstraint::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            usage_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new subject with the specified values")]
-    fn create_subject(
+    #[graphql(description = "Query a single usage constraint using its ID")]
+    fn usage_constraint(
         context: &Context,
-        #[graphql(description = "Values for subject to be created")] data: NewSubject,
-    ) -> FieldResult<Subject> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        check_subject(&data.subject_type, &data.subject_code)?;
-
-        Subject::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth usage constraint ID to search on")]
+        usage_constraint_id: Uuid,
+    ) -> FieldResult<UsageConstraint> {
+        UsageConstraint::from_id(&context.db, &usage_constraint_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new affiliation with the specified values")]
-    fn create_affiliation(
+    #[graphql(description = "Get the total number of usage constraints")]
+    fn usage_constraint_count(
         context: &Context,
-        #[graphql(description = "Values for affiliation to be created")] data: NewAffiliation,
-    ) -> FieldResult<Affiliation> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_contribution_id(
-                &context.db,
-                data.contribution_id,
-            )?)?;
-
-        Affiliation::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(
+            default = vec![],
+            description = "Specific types of usage to filter by"
+        )]
+        usage_types: Option<Vec<UsageType>>,
+    ) -> FieldResult<i32> {
+        UsageConstraint::count(
+            &context.db,
+            None,
+            vec![],
+            usage_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new work relation with the specified values")]
-    fn create_work_relation(
+    #[graphql(description = "Query the full list of locations")]
+    fn locations(
         context: &Context,
-        #[graphql(description = "Values for work relation to be created")] data: NewWorkRelation,
-    ) -> FieldResult<WorkRelation> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        // Work relations may link works from different publishers.
-        // User must have permissions for all relevant publishers.
-        context.account_access.can_edit(publisher_id_from_work_id(
-            &context.db,
-            data.relator_work_id,
-        )?)?;
-        context.account_access.can_edit(publisher_id_from_work_id(
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = LocationOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<LocationOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific platforms to filter by"
+        )]
+        location_platforms: Option<Vec<LocationPlatform>>,
+    ) -> FieldResult<Vec<Location>> {
+        Location::all(
             &context.db,
-            data.related_work_id,
-        )?)?;
-
-        WorkRelation::create(&context.db, &data).map_err(|e| e.into())
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            location_platforms.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Create a new reference with the specified values")]
-    fn create_reference(
+    #[graphql(description = "Query a single location using its ID")]
+    fn location(
         context: &Context,
-        #[graphql(description = "Values for reference to be created")] data: NewReference,
-    ) -> FieldResult<Reference> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context
-            .account_access
-            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-
-        Reference::create(&context.db, &data).map_err(|e| e.into())
+        #[graphql(description = "Thoth location ID to search on")] location_id: Uuid,
+    ) -> FieldResult<Location> {
+        Location::from_id(&context.db, &location_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing work with the specified values")]
-    fn update_work(
+    #[graphql(description = "Get the total number of locations associated to works")]
+    fn location_count(
         context: &Context,
-        #[graphql(description = "Values to apply to existing work")] data: PatchWork,
-    ) -> FieldResult<Work> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let work = Work::from_id(&context.db, &data.work_id).unwrap();
-        context
-            .account_access
-            .can_edit(work.publisher_id(&context.db)?)?;
-
-        if data.imprint_id != work.imprint_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
-            work.can_update_imprint(&context.db)?;
-        }
-
-        if data.work_type == WorkType::BookChapter {
-            work.can_be_chapter(&context.db)?;
-        }
-
-        data.validate()?;
-
-        if work.is_published() && !data.is_published() && !context.account_access.is_superuser {
-            return Err(ThothError::ThothSetWorkStatusError.into());
-        }
-
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        // update the work and, if it succeeds, synchronise its children statuses and pub. date
-        match work.update(&context.db, &data, &account_id) {
-            Ok(w) => {
-                // update chapters if their pub. data, withdrawn_date or work_status doesn't match the parent's
-                for child in work.children(&context.db)? {
-                    if child.publication_date != w.publication_date
-                        || child.work_status != w.work_status
-                        || child.withdrawn_date != w.withdrawn_date
-                    {
-                        let mut data: PatchWork = child.clone().into();
-                        data.publication_date = w.publication_date;
-                        data.withdrawn_date = w.withdrawn_date;
-                        data.work_status = w.work_status;
-                        child.update(&context.db, &data, &account_id)?;
-                    }
-                }
-                Ok(w)
-            }
-            Err(e) => Err(e.into()),
-        }
+        #[graphql(
+            default = vec![],
+            description = "Specific platforms to filter by"
+        )]
+        location_platforms: Option<Vec<LocationPlatform>>,
+    ) -> FieldResult<i32> {
+        Location::count(
+            &context.db,
+            None,
+            vec![],
+            location_platforms.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing publisher with the specified values")]
-    fn update_publisher(
+    #[graphql(description = "Query the full list of prices")]
+    fn prices(
         context: &Context,
-        #[graphql(description = "Values to apply to existing publisher")] data: PatchPublisher,
-    ) -> FieldResult<Publisher> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let publisher = Publisher::from_id(&context.db, &data.publisher_id).unwrap();
-        context.account_access.can_edit(publisher.publisher_id)?;
-
-        if data.publisher_id != publisher.publisher_id {
-            context.account_access.can_edit(data.publisher_id)?;
-        }
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        publisher
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = PriceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PriceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific currencies to filter by"
+        )]
+        currency_codes: Option<Vec<CurrencyCode>>,
+    ) -> FieldResult<Vec<Price>> {
+        Price::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            currency_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing imprint with the specified values")]
-    fn update_imprint(
+    #[graphql(description = "Query a single price using its ID")]
+    fn price(
         context: &Context,
-        #[graphql(description = "Values to apply to existing imprint")] data: PatchImprint,
-    ) -> FieldResult<Imprint> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let imprint = Imprint::from_id(&context.db, &data.imprint_id).unwrap();
-        context.account_access.can_edit(imprint.publisher_id())?;
+        #[graphql(description = "Thoth price ID to search on")] price_id: Uuid,
+    ) -> FieldResult<Price> {
+        Price::from_id(&context.db, &price_id).map_err(|e| e.into())
+    }
 
-        if data.publisher_id != imprint.publisher_id {
-            context.account_access.can_edit(data.publisher_id)?;
-        }
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        imprint
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
-    }
-
-    #[graphql(description = "Update an existing contributor with the specified values")]
-    fn update_contributor(
+    #[graphql(description = "Get the total number of prices associated to works")]
+    fn price_count(
         context: &Context,
-        #[graphql(description = "Values to apply to existing contributor")] data: PatchContributor,
-    ) -> FieldResult<Contributor> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        Contributor::from_id(&context.db, &data.contributor_id)
-            .unwrap()
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(
+            default = vec![],
+            description = "Specific currencies to filter by"
+        )]
+        currency_codes: Option<Vec<CurrencyCode>>,
+    ) -> FieldResult<i32> {
+        Price::count(
+            &context.db,
+            None,
+            vec![],
+            currency_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing contribution with the specified values")]
-    fn update_contribution(
+    #[graphql(description = "Query the full list of subjects")]
+    fn subjects(
         context: &Context,
-        #[graphql(description = "Values to apply to existing contribution")]
-        data: PatchContribution,
-    ) -> FieldResult<Contribution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let contribution = Contribution::from_id(&context.db, &data.contribution_id).unwrap();
-        context
-            .account_access
-            .can_edit(contribution.publisher_id(&context.db)?)?;
-
-        if data.work_id != contribution.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-        }
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        contribution
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = SubjectOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SubjectOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        subject_types: Option<Vec<SubjectType>>,
+    ) -> FieldResult<Vec<Subject>> {
+        Subject::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            subject_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing publication with the specified values")]
-    fn update_publication(
+    #[graphql(description = "Query a single subject using its ID")]
+    fn subject(
         context: &Context,
-        #[graphql(description = "Values to apply to existing publication")] data: PatchPublication,
-    ) -> FieldResult<Publication> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let publication = Publication::from_id(&context.db, &data.publication_id).unwrap();
-        context
-            .account_access
-            .can_edit(publication.publisher_id(&context.db)?)?;
-
-        if data.work_id != publication.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-        }
-
-        data.validate(&context.db)?;
-
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        publication
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(description = "Thoth subject ID to search on")] subject_id: Uuid,
+    ) -> FieldResult<Subject> {
+        Subject::from_id(&context.db, &subject_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing series with the specified values")]
-    fn update_series(
+    #[graphql(description = "Get the total number of subjects associated to works")]
+    fn subject_count(
         context: &Context,
-        #[graphql(description = "Values to apply to existing series")] data: PatchSeries,
-    ) -> FieldResult<Series> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let series = Series::from_id(&context.db, &data.series_id).unwrap();
-        context
-            .account_access
-            .can_edit(series.publisher_id(&context.db)?)?;
-
-        if data.imprint_id != series.imprint_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
-        }
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        series
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        subject_types: Option<Vec<SubjectType>>,
+    ) -> FieldResult<i32> {
+        Subject::count(
+            &context.db,
+            filter,
+            vec![],
+            subject_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing issue with the specified values")]
-    fn update_issue(
+    #[graphql(description = "Query the full list of institutions")]
+    fn institutions(
         context: &Context,
-        #[graphql(description = "Values to apply to existing issue")] data: PatchIssue,
-    ) -> FieldResult<Issue> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let issue = Issue::from_id(&context.db, &data.issue_id).unwrap();
-        context
-            .account_access
-            .can_edit(issue.publisher_id(&context.db)?)?;
-
-        data.imprints_match(&context.db)?;
-
-        if data.work_id != issue.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-        }
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        issue
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on institution_name, ror, institution_doi, crossref_funder_id, isni and ringgold"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = InstitutionOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<InstitutionOrderBy>,
+    ) -> FieldResult<Vec<Institution>> {
+        Institution::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing language with the specified values")]
-    fn update_language(
+    #[graphql(description = "Query a single institution using its ID")]
+    fn institution(
         context: &Context,
-        #[graphql(description = "Values to apply to existing language")] data: PatchLanguage,
-    ) -> FieldResult<Language> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let language = Language::from_id(&context.db, &data.language_id).unwrap();
-        context
-            .account_access
-            .can_edit(language.publisher_id(&context.db)?)?;
-
-        if data.work_id != language.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-        }
-
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        language
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(description = "Thoth institution ID to search on")] institution_id: Uuid,
+    ) -> FieldResult<Institution> {
+        Institution::from_id(&context.db, &institution_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing institution with the specified values")]
-    fn update_institution(
+    #[graphql(description = "Get the total number of institutions")]
+    fn institution_count(
         context: &Context,
-        #[graphql(description = "Values to apply to existing institution")] data: PatchInstitution,
-    ) -> FieldResult<Institution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        Institution::from_id(&context.db, &data.institution_id)
-            .unwrap()
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on institution_name, ror, institution_doi, crossref_funder_id, isni and ringgold"
+        )]
+        filter: Option<String>,
+    ) -> FieldResult<i32> {
+        Institution::count(&context.db, filter, vec![], vec![], vec![], None).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing funding with the specified values")]
-    fn update_funding(
+    #[graphql(description = "Query the full list of fundings")]
+    fn fundings(
         context: &Context,
-        #[graphql(description = "Values to apply to existing funding")] data: PatchFunding,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = FundingOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<FundingOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+    ) -> FieldResult<Vec<Funding>> {
+        Funding::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Query a single funding using its ID")]
+    fn funding(
+        context: &Context,
+        #[graphql(description = "Thoth funding ID to search on")] funding_id: Uuid,
     ) -> FieldResult<Funding> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let funding = Funding::from_id(&context.db, &data.funding_id).unwrap();
-        context
-            .account_access
-            .can_edit(funding.publisher_id(&context.db)?)?;
+        Funding::from_id(&context.db, &funding_id).map_err(|e| e.into())
+    }
 
-        if data.work_id != funding.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
-        }
+    #[graphql(description = "Get the total number of funding instances associated to works")]
+    fn funding_count(context: &Context) -> FieldResult<i32> {
+        Funding::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
+    }
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        funding
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+    #[graphql(description = "Query the full list of affiliations")]
+    fn affiliations(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = AffiliationOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AffiliationOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+    ) -> FieldResult<Vec<Affiliation>> {
+        Affiliation::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing location with the specified values")]
-    fn update_location(
+    #[graphql(description = "Query a single affiliation using its ID")]
+    fn affiliation(
         context: &Context,
-        #[graphql(description = "Values to apply to existing location")] data: PatchLocation,
-    ) -> FieldResult<Location> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let current_location = Location::from_id(&context.db, &data.location_id).unwrap();
-        let has_canonical_thoth_location = Publication::from_id(&context.db, &data.publication_id)?
-            .locations(
-                context,
-                Some(1),
-                None,
-                None,
-                Some(vec![LocationPlatform::Thoth]),
-            )?
-            .first()
-            .is_some_and(|location| location.canonical);
-        // Only superusers can update the canonical location when a Thoth Location Platform canonical location already exists
-        if has_canonical_thoth_location && data.canonical && !context.account_access.is_superuser {
-            return Err(ThothError::ThothUpdateCanonicalError.into());
-        }
+        #[graphql(description = "Thoth affiliation ID to search on")] affiliation_id: Uuid,
+    ) -> FieldResult<Affiliation> {
+        Affiliation::from_id(&context.db, &affiliation_id).map_err(|e| e.into())
+    }
 
-        // Only superusers can edit locations where Location Platform is Thoth
-        if !context.account_access.is_superuser
-            && current_location.location_platform == LocationPlatform::Thoth
-        {
-            return Err(ThothError::ThothLocationError.into());
-        }
-        context
-            .account_access
-            .can_edit(current_location.publisher_id(&context.db)?)?;
+    #[graphql(description = "Get the total number of affiliations")]
+    fn affiliation_count(context: &Context) -> FieldResult<i32> {
+        Affiliation::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
+    }
 
-        if data.publication_id != current_location.publication_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_publication_id(
-                    &context.db,
-                    data.publication_id,
-                )?)?;
-        }
+    #[graphql(description = "Query the full list of references")]
+    fn references(
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = ReferenceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ReferenceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "If set, only shows results connected to publishers with these IDs"
+        )]
+        publishers: Option<Vec<Uuid>>,
+    ) -> FieldResult<Vec<Reference>> {
+        Reference::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            publishers.unwrap_or_default(),
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
 
-        if data.canonical {
-            data.canonical_record_complete(&context.db)?;
-        }
+    #[graphql(description = "Query a single reference using its ID")]
+    fn reference(
+        context: &Context,
+        #[graphql(description = "Thoth reference ID to search on")] reference_id: Uuid,
+    ) -> FieldResult<Reference> {
+        Reference::from_id(&context.db, &reference_id).map_err(|e| e.into())
+    }
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        current_location
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+    #[graphql(description = "Get the total number of references")]
+    fn reference_count(context: &Context) -> FieldResult<i32> {
+        Reference::count(&context.db, None, vec![], vec![], vec![], None).map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Update an existing price with the specified values")]
-    fn update_price(
+pub struct MutationRoot;
+
+#[juniper::graphql_object(Context = Context)]
+impl MutationRoot {
+    #[graphql(description = "Create a new work with the specified values")]
+    fn create_work(
         context: &Context,
-        #[graphql(description = "Values to apply to existing price")] data: PatchPrice,
-    ) -> FieldResult<Price> {
+        #[graphql(description = "Values for work to be created")] data: NewWork,
+    ) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let price = Price::from_id(&context.db, &data.price_id).unwrap();
         context
             .account_access
-            .can_edit(price.publisher_id(&context.db)?)?;
-
-        if data.publication_id != price.publication_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_publication_id(
-                    &context.db,
-                    data.publication_id,
-                )?)?;
-        }
+            .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
 
-        if data.unit_price <= 0.0 {
-            // Prices must be non-zero (and non-negative).
-            return Err(ThothError::PriceZeroError.into());
-        }
+        let data = data.standardise()?;
+        data.validate()?;
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        price
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        Work::create(&context.db, &data).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing subject with the specified values")]
-    fn update_subject(
+    #[graphql(description = "Create a new publisher with the specified values")]
+    fn create_publisher(
         context: &Context,
-        #[graphql(description = "Values to apply to existing subject")] data: PatchSubject,
-    ) -> FieldResult<Subject> {
+        #[graphql(description = "Values for publisher to be created")] data: NewPublisher,
+    ) -> FieldResult<Publisher> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let subject = Subject::from_id(&context.db, &data.subject_id).unwrap();
-        context
-            .account_access
-            .can_edit(subject.publisher_id(&context.db)?)?;
-
-        if data.work_id != subject.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        // Only superusers can create new publishers - NewPublisher has no ID field
+        if !context.account_access.is_superuser {
+            return Err(ThothError::Unauthorised.into());
         }
 
-        check_subject(&data.subject_type, &data.subject_code)?;
-
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        subject
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        Publisher::create(&context.db, &data).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing affiliation with the specified values")]
-    fn update_affiliation(
+    #[graphql(description = "Create a new imprint with the specified values")]
+    fn create_imprint(
         context: &Context,
-        #[graphql(description = "Values to apply to existing affiliation")] data: PatchAffiliation,
-    ) -> FieldResult<Affiliation> {
+        #[graphql(description = "Values for imprint to be created")] data: NewImprint,
+    ) -> FieldResult<Imprint> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let affiliation = Affiliation::from_id(&context.db, &data.affiliation_id).unwrap();
-        context
-            .account_access
-            .can_edit(affiliation.publisher_id(&context.db)?)?;
+        context.account_access.can_edit(data.publisher_id)?;
 
-        if data.contribution_id != affiliation.contribution_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_contribution_id(
-                    &context.db,
-                    data.contribution_id,
-                )?)?;
+        Imprint::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new contributor with the specified values")]
+    fn create_contributor(
+        context: &Context,
+        #[graphql(description = "Values for contributor to be created")] data: NewContributor,
+    ) -> FieldResult<Contributor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let data = data.standardise()?;
+        Contributor::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new alternative name with the specified values")]
+    fn create_alternative_name(
+        context: &Context,
+        #[graphql(description = "Values for alternative name to be created")]
+        data: NewAlternativeName,
+    ) -> FieldResult<AlternativeName> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let data = data.standardise()?;
+        AlternativeName::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new contribution with the specified values")]
+    fn create_contribution(
+        context: &Context,
+        #[graphql(description = "Values for contribution to be created")] data: NewContribution,
+    ) -> FieldResult<Contribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        if let Some(biography) = &data.biography {
+            validate_markup(biography)?;
         }
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        affiliation
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        Contribution::create(&context.db, &data).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing work relation with the specified values")]
-    fn update_work_relation(
+    #[graphql(description = "Create a new publication with the specified values")]
+    fn create_publication(
         context: &Context,
-        #[graphql(description = "Values to apply to existing work relation")]
-        data: PatchWorkRelation,
-    ) -> FieldResult<WorkRelation> {
+        #[graphql(description = "Values for publication to be created")] data: NewPublication,
+    ) -> FieldResult<Publication> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let work_relation = WorkRelation::from_id(&context.db, &data.work_relation_id).unwrap();
-        // Work relations may link works from different publishers.
-        // User must have permissions for all relevant publishers.
-        context.account_access.can_edit(publisher_id_from_work_id(
-            &context.db,
-            work_relation.relator_work_id,
-        )?)?;
-        context.account_access.can_edit(publisher_id_from_work_id(
-            &context.db,
-            work_relation.related_work_id,
-        )?)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
 
-        if data.relator_work_id != work_relation.relator_work_id {
-            context.account_access.can_edit(publisher_id_from_work_id(
+        data.validate(&context.db)?;
+
+        Publication::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new series with the specified values")]
+    fn create_series(
+        context: &Context,
+        #[graphql(description = "Values for series to be created")] data: NewSeries,
+    ) -> FieldResult<Series> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
+
+        data.parent_series_valid(&context.db)?;
+
+        Series::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new series contribution with the specified values")]
+    fn create_series_contribution(
+        context: &Context,
+        #[graphql(description = "Values for series contribution to be created")]
+        data: NewSeriesContribution,
+    ) -> FieldResult<SeriesContribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_series_id(&context.db, data.series_id)?)?;
+
+        SeriesContribution::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new issue with the specified values")]
+    fn create_issue(
+        context: &Context,
+        #[graphql(description = "Values for issue to be created")] data: NewIssue,
+    ) -> FieldResult<Issue> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        data.imprints_match(&context.db)?;
+
+        Issue::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new language with the specified values")]
+    fn create_language(
+        context: &Context,
+        #[graphql(description = "Values for language to be created")] data: NewLanguage,
+    ) -> FieldResult<Language> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        Language::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new title with the specified values")]
+    fn create_work_title(
+        context: &Context,
+        #[graphql(description = "Values for title to be created")] data: NewWorkTitle,
+    ) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        WorkTitle::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new abstract with the specified values")]
+    fn create_work_abstract(
+        context: &Context,
+        #[graphql(description = "Values for abstract to be created")] data: NewWorkAbstract,
+    ) -> FieldResult<WorkAbstract> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        validate_markup(&data.content)?;
+
+        WorkAbstract::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new work identifier with the specified values")]
+    fn create_work_identifier(
+        context: &Context,
+        #[graphql(description = "Values for work identifier to be created")]
+        data: NewWorkIdentifier,
+    ) -> FieldResult<WorkIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        let data = data.standardise()?;
+
+        WorkIdentifier::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new prize with the specified values")]
+    fn create_prize(
+        context: &Context,
+        #[graphql(description = "Values for prize to be created")] data: NewPrize,
+    ) -> FieldResult<Prize> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        Prize::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new event with the specified values")]
+    fn create_event(
+        context: &Context,
+        #[graphql(description = "Values for event to be created")] data: NewEvent,
+    ) -> FieldResult<Event> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        Event::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new event sponsor with the specified values")]
+    fn create_event_sponsor(
+        context: &Context,
+        #[graphql(description = "Values for event sponsor to be created")] data: NewEventSponsor,
+    ) -> FieldResult<EventSponsor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_event_id(&context.db, data.event_id)?)?;
+
+        EventSponsor::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new text content with the specified values")]
+    fn create_text_content(
+        context: &Context,
+        #[graphql(description = "Values for text content to be created")] data: NewTextContent,
+    ) -> FieldResult<TextContent> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        validate_markup(&data.content)?;
+
+        TextContent::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new supporting resource with the specified values")]
+    fn create_supporting_resource(
+        context: &Context,
+        #[graphql(description = "Values for supporting resource to be created")]
+        data: NewSupportingResource,
+    ) -> FieldResult<SupportingResource> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        SupportingResource::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new publication identifier with the specified values")]
+    fn create_publication_identifier(
+        context: &Context,
+        #[graphql(description = "Values for publication identifier to be created")]
+        data: NewPublicationIdentifier,
+    ) -> FieldResult<PublicationIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_publication_id(
                 &context.db,
-                data.relator_work_id,
+                data.publication_id,
             )?)?;
-        }
-        if data.related_work_id != work_relation.related_work_id {
-            context.account_access.can_edit(publisher_id_from_work_id(
+
+        let data = data.standardise()?;
+
+        PublicationIdentifier::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create new sales rights with the specified values")]
+    fn create_sales_rights(
+        context: &Context,
+        #[graphql(description = "Values for sales rights to be created")] data: NewSalesRights,
+    ) -> FieldResult<SalesRights> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_publication_id(
                 &context.db,
-                data.related_work_id,
+                data.publication_id,
             )?)?;
-        }
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        work_relation
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        let data = data.standardise()?;
+
+        SalesRights::create(&context.db, &data).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Update an existing reference with the specified values")]
-    fn update_reference(
+    #[graphql(description = "Create a new usage constraint with the specified values")]
+    fn create_usage_constraint(
         context: &Context,
-        #[graphql(description = "Values to apply to existing reference")] data: PatchReference,
-    ) -> FieldResult<Reference> {
+        #[graphql(description = "Values for usage constraint to be created")]
+        data: NewUsageConstraint,
+    ) -> FieldResult<UsageConstraint> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let reference = Reference::from_id(&context.db, &data.reference_id).unwrap();
         context
             .account_access
-            .can_edit(reference.publisher_id(&context.db)?)?;
+            .can_edit(publisher_id_from_publication_id(
+                &context.db,
+                data.publication_id,
+            )?)?;
 
-        if data.work_id != reference.work_id {
-            context
-                .account_access
-                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        data.validate()?;
+        if Publication::from_id(&context.db, &data.publication_id)?.is_physical() {
+            return Err(ThothError::UsageConstraintPhysicalError.into());
         }
 
-        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-        reference
-            .update(&context.db, &data, &account_id)
-            .map_err(|e| e.into())
+        UsageConstraint::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new institution with the specified values")]
+    fn create_institution(
+        context: &Context,
+        #[graphql(description = "Values for institution to be created")] data: NewInstitution,
+    ) -> FieldResult<Institution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let data = data.standardise()?;
+        Institution::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new funding with the specified values")]
+    fn create_funding(
+        context: &Context,
+        #[graphql(description = "Values for funding to be created")] data: NewFunding,
+    ) -> FieldResult<Funding> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        Funding::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new location with the specified values")]
+    fn create_location(
+        context: &Context,
+        #[graphql(description = "Values for location to be created")] data: NewLocation,
+    ) -> FieldResult<Location> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        // Only superusers can create new locations where Location Platform is Thoth
+        if !context.account_access.is_superuser && data.location_platform == LocationPlatform::Thoth
+        {
+            return Err(ThothError::ThothLocationError.into());
+        }
+        context
+            .account_access
+            .can_edit(publisher_id_from_publication_id(
+                &context.db,
+                data.publication_id,
+            )?)?;
+
+        if data.canonical {
+            data.canonical_record_complete(&context.db)?;
+        } else {
+            data.can_be_non_canonical(&context.db)?;
+        }
+
+        Location::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new price with the specified values")]
+    fn create_price(
+        context: &Context,
+        #[graphql(description = "Values for price to be created")] data: NewPrice,
+    ) -> FieldResult<Price> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_publication_id(
+                &context.db,
+                data.publication_id,
+            )?)?;
+
+        let data = data.standardise()?;
+
+        Price::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new subject with the specified values")]
+    fn create_subject(
+        context: &Context,
+        #[graphql(description = "Values for subject to be created")] data: NewSubject,
+    ) -> FieldResult<Subject> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        check_subject(&data.subject_type, &data.subject_code)?;
+
+        Subject::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new affiliation with the specified values")]
+    fn create_affiliation(
+        context: &Context,
+        #[graphql(description = "Values for affiliation to be created")] data: NewAffiliation,
+    ) -> FieldResult<Affiliation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_contribution_id(
+                &context.db,
+                data.contribution_id,
+            )?)?;
+
+        Affiliation::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new work relation with the specified values")]
+    fn create_work_relation(
+        context: &Context,
+        #[graphql(description = "Values for work relation to be created")] data: NewWorkRelation,
+    ) -> FieldResult<WorkRelation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        // Work relations may link works from different publishers.
+        // User must have permissions for all relevant publishers.
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            data.relator_work_id,
+        )?)?;
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            data.related_work_id,
+        )?)?;
+
+        WorkRelation::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Create a new reference with the specified values")]
+    fn create_reference(
+        context: &Context,
+        #[graphql(description = "Values for reference to be created")] data: NewReference,
+    ) -> FieldResult<Reference> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+
+        Reference::create(&context.db, &data).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing work with the specified values")]
+    fn update_work(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing work")] data: PatchWork,
+    ) -> FieldResult<Work> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work = Work::from_id(&context.db, &data.work_id).unwrap();
+        context
+            .account_access
+            .can_edit(work.publisher_id(&context.db)?)?;
+
+        if data.imprint_id != work.imprint_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
+            work.can_update_imprint(&context.db)?;
+        }
+
+        if data.work_type.is_component() {
+            work.can_be_chapter(&context.db)?;
+        }
+
+        // Licences saved before the controlled vocabulary was introduced are only checked once edited
+        let data = if data.license != work.license {
+            data.standardise()?
+        } else {
+            data
+        };
+        data.validate_update(&work)?;
+
+        if work.is_published() && !data.is_published() && !context.account_access.is_superuser {
+            return Err(ThothError::ThothSetWorkStatusError.into());
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        // update the work and, if it succeeds, synchronise its children statuses and pub. date
+        match work.update(&context.db, &data, &account_id) {
+            Ok(w) => {
+                // update chapters if their pub. data, withdrawn_date or work_status doesn't match the parent's
+                for child in work.children(&context.db)? {
+                    if child.publication_date != w.publication_date
+                        || child.work_status != w.work_status
+                        || child.withdrawn_date != w.withdrawn_date
+                    {
+                        let mut data: PatchWork = child.clone().into();
+                        data.publication_date = w.publication_date;
+                        data.withdrawn_date = w.withdrawn_date;
+                        data.work_status = w.work_status;
+                        child.update(&context.db, &data, &account_id)?;
+                    }
+                }
+                Ok(w)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[graphql(description = "Update an existing publisher with the specified values")]
+    fn update_publisher(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing publisher")] data: PatchPublisher,
+    ) -> FieldResult<Publisher> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publisher = Publisher::from_id(&context.db, &data.publisher_id).unwrap();
+        context.account_access.can_edit(publisher.publisher_id)?;
+
+        if data.publisher_id != publisher.publisher_id {
+            context.account_access.can_edit(data.publisher_id)?;
+        }
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        publisher
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Require (or stop requiring) two-factor authentication for all accounts linked to a publisher. Only available to superusers."
+    )]
+    fn update_publisher_two_factor_requirement(
+        context: &Context,
+        #[graphql(description = "Thoth ID of the publisher to be updated")] publisher_id: Uuid,
+        #[graphql(
+            description = "Whether accounts linked to this publisher must use two-factor authentication"
+        )]
+        require_two_factor: bool,
+    ) -> FieldResult<Publisher> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        if !context.account_access.is_superuser {
+            return Err(ThothError::Unauthorised.into());
+        }
+        let publisher = Publisher::from_id(&context.db, &publisher_id)?;
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        publisher
+            .update_two_factor_requirement(&context.db, require_two_factor, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing imprint with the specified values")]
+    fn update_imprint(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing imprint")] data: PatchImprint,
+    ) -> FieldResult<Imprint> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let imprint = Imprint::from_id(&context.db, &data.imprint_id).unwrap();
+        context.account_access.can_edit(imprint.publisher_id())?;
+
+        if data.publisher_id != imprint.publisher_id {
+            context.account_access.can_edit(data.publisher_id)?;
+        }
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        imprint
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing contributor with the specified values")]
+    fn update_contributor(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing contributor")] data: PatchContributor,
+    ) -> FieldResult<Contributor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let data = data.standardise()?;
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        Contributor::from_id(&context.db, &data.contributor_id)
+            .unwrap()
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing alternative name with the specified values")]
+    fn update_alternative_name(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing alternative name")]
+        data: PatchAlternativeName,
+    ) -> FieldResult<AlternativeName> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let data = data.standardise()?;
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        AlternativeName::from_id(&context.db, &data.alternative_name_id)
+            .unwrap()
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing contribution with the specified values")]
+    fn update_contribution(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing contribution")]
+        data: PatchContribution,
+    ) -> FieldResult<Contribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let contribution = Contribution::from_id(&context.db, &data.contribution_id).unwrap();
+        context
+            .account_access
+            .can_edit(contribution.publisher_id(&context.db)?)?;
+
+        if data.work_id != contribution.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        validate_markup_change(data.biography.as_deref(), contribution.biography.as_deref())?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        contribution
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing publication with the specified values")]
+    fn update_publication(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing publication")] data: PatchPublication,
+    ) -> FieldResult<Publication> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publication = Publication::from_id(&context.db, &data.publication_id).unwrap();
+        context
+            .account_access
+            .can_edit(publication.publisher_id(&context.db)?)?;
+
+        if data.work_id != publication.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        data.validate(&context.db)?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        publication
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing series with the specified values")]
+    fn update_series(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing series")] data: PatchSeries,
+    ) -> FieldResult<Series> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let series = Series::from_id(&context.db, &data.series_id).unwrap();
+        context
+            .account_access
+            .can_edit(series.publisher_id(&context.db)?)?;
+
+        if data.imprint_id != series.imprint_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_imprint_id(&context.db, data.imprint_id)?)?;
+        }
+
+        data.parent_series_valid(&context.db)?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        series
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing series contribution with the specified values")]
+    fn update_series_contribution(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing series contribution")]
+        data: PatchSeriesContribution,
+    ) -> FieldResult<SeriesContribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let series_contribution =
+            SeriesContribution::from_id(&context.db, &data.series_contribution_id).unwrap();
+        context
+            .account_access
+            .can_edit(series_contribution.publisher_id(&context.db)?)?;
+
+        if data.series_id != series_contribution.series_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_series_id(&context.db, data.series_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        series_contribution
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing issue with the specified values")]
+    fn update_issue(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing issue")] data: PatchIssue,
+    ) -> FieldResult<Issue> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let issue = Issue::from_id(&context.db, &data.issue_id).unwrap();
+        context
+            .account_access
+            .can_edit(issue.publisher_id(&context.db)?)?;
+
+        data.imprints_match(&context.db)?;
+
+        if data.work_id != issue.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        issue
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing language with the specified values")]
+    fn update_language(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing language")] data: PatchLanguage,
+    ) -> FieldResult<Language> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let language = Language::from_id(&context.db, &data.language_id).unwrap();
+        context
+            .account_access
+            .can_edit(language.publisher_id(&context.db)?)?;
+
+        if data.work_id != language.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        language
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing title with the specified values")]
+    fn update_work_title(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing title")] data: PatchWorkTitle,
+    ) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_title = WorkTitle::from_id(&context.db, &data.work_title_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_title.publisher_id(&context.db)?)?;
+
+        if data.work_id != work_title.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        work_title
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing abstract with the specified values")]
+    fn update_work_abstract(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing abstract")] data: PatchWorkAbstract,
+    ) -> FieldResult<WorkAbstract> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_abstract = WorkAbstract::from_id(&context.db, &data.work_abstract_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_abstract.publisher_id(&context.db)?)?;
+
+        if data.work_id != work_abstract.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        validate_markup_change(Some(&data.content), Some(&work_abstract.content))?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        work_abstract
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing work identifier with the specified values")]
+    fn update_work_identifier(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing work identifier")]
+        data: PatchWorkIdentifier,
+    ) -> FieldResult<WorkIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_identifier =
+            WorkIdentifier::from_id(&context.db, &data.work_identifier_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_identifier.publisher_id(&context.db)?)?;
+
+        if data.work_id != work_identifier.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let data = data.standardise()?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        work_identifier
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing prize with the specified values")]
+    fn update_prize(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing prize")] data: PatchPrize,
+    ) -> FieldResult<Prize> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let prize = Prize::from_id(&context.db, &data.prize_id).unwrap();
+        context
+            .account_access
+            .can_edit(prize.publisher_id(&context.db)?)?;
+
+        if data.work_id != prize.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        prize
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing event with the specified values")]
+    fn update_event(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing event")] data: PatchEvent,
+    ) -> FieldResult<Event> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let event = Event::from_id(&context.db, &data.event_id).unwrap();
+        context
+            .account_access
+            .can_edit(event.publisher_id(&context.db)?)?;
+
+        if data.work_id != event.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        event
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing event sponsor with the specified values")]
+    fn update_event_sponsor(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing event sponsor")]
+        data: PatchEventSponsor,
+    ) -> FieldResult<EventSponsor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let event_sponsor = EventSponsor::from_id(&context.db, &data.event_sponsor_id).unwrap();
+        context
+            .account_access
+            .can_edit(event_sponsor.publisher_id(&context.db)?)?;
+
+        if data.event_id != event_sponsor.event_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_event_id(&context.db, data.event_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        event_sponsor
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing text content with the specified values")]
+    fn update_text_content(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing text content")] data: PatchTextContent,
+    ) -> FieldResult<TextContent> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let text_content = TextContent::from_id(&context.db, &data.text_content_id).unwrap();
+        context
+            .account_access
+            .can_edit(text_content.publisher_id(&context.db)?)?;
+
+        if data.work_id != text_content.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        validate_markup_change(Some(&data.content), Some(&text_content.content))?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        text_content
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing supporting resource with the specified values")]
+    fn update_supporting_resource(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing supporting resource")]
+        data: PatchSupportingResource,
+    ) -> FieldResult<SupportingResource> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let supporting_resource =
+            SupportingResource::from_id(&context.db, &data.supporting_resource_id).unwrap();
+        context
+            .account_access
+            .can_edit(supporting_resource.publisher_id(&context.db)?)?;
+
+        if data.work_id != supporting_resource.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        supporting_resource
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing publication identifier with the specified values")]
+    fn update_publication_identifier(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing publication identifier")]
+        data: PatchPublicationIdentifier,
+    ) -> FieldResult<PublicationIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publication_identifier =
+            PublicationIdentifier::from_id(&context.db, &data.publication_identifier_id).unwrap();
+        context
+            .account_access
+            .can_edit(publication_identifier.publisher_id(&context.db)?)?;
+
+        if data.publication_id != publication_identifier.publication_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_publication_id(
+                    &context.db,
+                    data.publication_id,
+                )?)?;
+        }
+
+        let data = data.standardise()?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        publication_identifier
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update existing sales rights with the specified values")]
+    fn update_sales_rights(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing sales rights")] data: PatchSalesRights,
+    ) -> FieldResult<SalesRights> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let sales_rights = SalesRights::from_id(&context.db, &data.sales_rights_id).unwrap();
+        context
+            .account_access
+            .can_edit(sales_rights.publisher_id(&context.db)?)?;
+
+        if data.publication_id != sales_rights.publication_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_publication_id(
+                    &context.db,
+                    data.publication_id,
+                )?)?;
+        }
+
+        let data = data.standardise()?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        sales_rights
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing usage constraint with the specified values")]
+    fn update_usage_constraint(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing usage constraint")]
+        data: PatchUsageConstraint,
+    ) -> FieldResult<UsageConstraint> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let usage_constraint =
+            UsageConstraint::from_id(&context.db, &data.usage_constraint_id).unwrap();
+        context
+            .account_access
+            .can_edit(usage_constraint.publisher_id(&context.db)?)?;
+
+        if data.publication_id != usage_constraint.publication_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_publication_id(
+                    &context.db,
+                    data.publication_id,
+                )?)?;
+        }
+
+        data.validate()?;
+        if Publication::from_id(&context.db, &data.publication_id)?.is_physical() {
+            return Err(ThothError::UsageConstraintPhysicalError.into());
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        usage_constraint
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing institution with the specified values")]
+    fn update_institution(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing institution")] data: PatchInstitution,
+    ) -> FieldResult<Institution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        let data = data.standardise()?;
+        Institution::from_id(&context.db, &data.institution_id)
+            .unwrap()
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing funding with the specified values")]
+    fn update_funding(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing funding")] data: PatchFunding,
+    ) -> FieldResult<Funding> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let funding = Funding::from_id(&context.db, &data.funding_id).unwrap();
+        context
+            .account_access
+            .can_edit(funding.publisher_id(&context.db)?)?;
+
+        if data.work_id != funding.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        funding
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing location with the specified values")]
+    fn update_location(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing location")] data: PatchLocation,
+    ) -> FieldResult<Location> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let current_location = Location::from_id(&context.db, &data.location_id).unwrap();
+        let has_canonical_thoth_location = Publication::from_id(&context.db, &data.publication_id)?
+            .locations(
+                context,
+                Some(1),
+                None,
+                None,
+                Some(vec![LocationPlatform::Thoth]),
+            )?
+            .first()
+            .is_some_and(|location| location.canonical);
+        // Only superusers can update the canonical location when a Thoth Location Platform canonical location already exists
+        if has_canonical_thoth_location && data.canonical && !context.account_access.is_superuser {
+            return Err(ThothError::ThothUpdateCanonicalError.into());
+        }
+
+        // Only superusers can edit locations where Location Platform is Thoth
+        if !context.account_access.is_superuser
+            && current_location.location_platform == LocationPlatform::Thoth
+        {
+            return Err(ThothError::ThothLocationError.into());
+        }
+        context
+            .account_access
+            .can_edit(current_location.publisher_id(&context.db)?)?;
+
+        if data.publication_id != current_location.publication_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_publication_id(
+                    &context.db,
+                    data.publication_id,
+                )?)?;
+        }
+
+        if data.canonical {
+            data.canonical_record_complete(&context.db)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        current_location
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing price with the specified values")]
+    fn update_price(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing price")] data: PatchPrice,
+    ) -> FieldResult<Price> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let price = Price::from_id(&context.db, &data.price_id).unwrap();
+        context
+            .account_access
+            .can_edit(price.publisher_id(&context.db)?)?;
+
+        if data.publication_id != price.publication_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_publication_id(
+                    &context.db,
+                    data.publication_id,
+                )?)?;
+        }
+
+        let data = data.standardise()?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        price
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing subject with the specified values")]
+    fn update_subject(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing subject")] data: PatchSubject,
+    ) -> FieldResult<Subject> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let subject = Subject::from_id(&context.db, &data.subject_id).unwrap();
+        context
+            .account_access
+            .can_edit(subject.publisher_id(&context.db)?)?;
+
+        if data.work_id != subject.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        check_subject(&data.subject_type, &data.subject_code)?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        subject
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing affiliation with the specified values")]
+    fn update_affiliation(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing affiliation")] data: PatchAffiliation,
+    ) -> FieldResult<Affiliation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let affiliation = Affiliation::from_id(&context.db, &data.affiliation_id).unwrap();
+        context
+            .account_access
+            .can_edit(affiliation.publisher_id(&context.db)?)?;
+
+        if data.contribution_id != affiliation.contribution_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_contribution_id(
+                    &context.db,
+                    data.contribution_id,
+                )?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        affiliation
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing work relation with the specified values")]
+    fn update_work_relation(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing work relation")]
+        data: PatchWorkRelation,
+    ) -> FieldResult<WorkRelation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_relation = WorkRelation::from_id(&context.db, &data.work_relation_id).unwrap();
+        // Work relations may link works from different publishers.
+        // User must have permissions for all relevant publishers.
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            work_relation.relator_work_id,
+        )?)?;
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            work_relation.related_work_id,
+        )?)?;
+
+        if data.relator_work_id != work_relation.relator_work_id {
+            context.account_access.can_edit(publisher_id_from_work_id(
+                &context.db,
+                data.relator_work_id,
+            )?)?;
+        }
+        if data.related_work_id != work_relation.related_work_id {
+            context.account_access.can_edit(publisher_id_from_work_id(
+                &context.db,
+                data.related_work_id,
+            )?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        work_relation
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Update an existing reference with the specified values")]
+    fn update_reference(
+        context: &Context,
+        #[graphql(description = "Values to apply to existing reference")] data: PatchReference,
+    ) -> FieldResult<Reference> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let reference = Reference::from_id(&context.db, &data.reference_id).unwrap();
+        context
+            .account_access
+            .can_edit(reference.publisher_id(&context.db)?)?;
+
+        if data.work_id != reference.work_id {
+            context
+                .account_access
+                .can_edit(publisher_id_from_work_id(&context.db, data.work_id)?)?;
+        }
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        reference
+            .update(&context.db, &data, &account_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single work using its ID")]
+    fn delete_work(
+        context: &Context,
+        #[graphql(description = "Thoth ID of work to be deleted")] work_id: Uuid,
+    ) -> FieldResult<Work> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work = Work::from_id(&context.db, &work_id).unwrap();
+        context
+            .account_access
+            .can_edit(work.publisher_id(&context.db)?)?;
+
+        if work.is_published() && !context.account_access.is_superuser {
+            return Err(ThothError::ThothDeleteWorkError.into());
+        }
+
+        work.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single publisher using its ID")]
+    fn delete_publisher(
+        context: &Context,
+        #[graphql(description = "Thoth ID of publisher to be deleted")] publisher_id: Uuid,
+    ) -> FieldResult<Publisher> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publisher = Publisher::from_id(&context.db, &publisher_id).unwrap();
+        context.account_access.can_edit(publisher_id)?;
+
+        publisher.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single imprint using its ID")]
+    fn delete_imprint(
+        context: &Context,
+        #[graphql(description = "Thoth ID of imprint to be deleted")] imprint_id: Uuid,
+    ) -> FieldResult<Imprint> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let imprint = Imprint::from_id(&context.db, &imprint_id).unwrap();
+        context.account_access.can_edit(imprint.publisher_id())?;
+
+        imprint.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single contributor using its ID")]
+    fn delete_contributor(
+        context: &Context,
+        #[graphql(description = "Thoth ID of contributor to be deleted")] contributor_id: Uuid,
+    ) -> FieldResult<Contributor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let contributor = Contributor::from_id(&context.db, &contributor_id).unwrap();
+        for linked_publisher_id in contributor.linked_publisher_ids(&context.db)? {
+            context.account_access.can_edit(linked_publisher_id)?;
+        }
+
+        contributor.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single alternative name using its ID")]
+    fn delete_alternative_name(
+        context: &Context,
+        #[graphql(description = "Thoth ID of alternative name to be deleted")]
+        alternative_name_id: Uuid,
+    ) -> FieldResult<AlternativeName> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let alternative_name = AlternativeName::from_id(&context.db, &alternative_name_id).unwrap();
+        let contributor = Contributor::from_id(&context.db, &alternative_name.contributor_id)?;
+        for linked_publisher_id in contributor.linked_publisher_ids(&context.db)? {
+            context.account_access.can_edit(linked_publisher_id)?;
+        }
+
+        alternative_name.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single contribution using its ID")]
+    fn delete_contribution(
+        context: &Context,
+        #[graphql(description = "Thoth ID of contribution to be deleted")] contribution_id: Uuid,
+    ) -> FieldResult<Contribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let contribution = Contribution::from_id(&context.db, &contribution_id).unwrap();
+        context
+            .account_access
+            .can_edit(contribution.publisher_id(&context.db)?)?;
+
+        contribution.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single publication using its ID")]
+    fn delete_publication(
+        context: &Context,
+        #[graphql(description = "Thoth ID of publication to be deleted")] publication_id: Uuid,
+    ) -> FieldResult<Publication> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publication = Publication::from_id(&context.db, &publication_id).unwrap();
+        context
+            .account_access
+            .can_edit(publication.publisher_id(&context.db)?)?;
+
+        publication.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single series contribution using its ID")]
+    fn delete_series_contribution(
+        context: &Context,
+        #[graphql(description = "Thoth ID of series contribution to be deleted")]
+        series_contribution_id: Uuid,
+    ) -> FieldResult<SeriesContribution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let series_contribution =
+            SeriesContribution::from_id(&context.db, &series_contribution_id).unwrap();
+        context
+            .account_access
+            .can_edit(series_contribution.publisher_id(&context.db)?)?;
+
+        series_contribution
+            .delete(&context.db)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single series using its ID")]
+    fn delete_series(
+        context: &Context,
+        #[graphql(description = "Thoth ID of series to be deleted")] series_id: Uuid,
+    ) -> FieldResult<Series> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let series = Series::from_id(&context.db, &series_id).unwrap();
+        context
+            .account_access
+            .can_edit(series.publisher_id(&context.db)?)?;
+
+        series.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single issue using its ID")]
+    fn delete_issue(
+        context: &Context,
+        #[graphql(description = "Thoth ID of issue to be deleted")] issue_id: Uuid,
+    ) -> FieldResult<Issue> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let issue = Issue::from_id(&context.db, &issue_id).unwrap();
+        context
+            .account_access
+            .can_edit(issue.publisher_id(&context.db)?)?;
+
+        issue.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single language using its ID")]
+    fn delete_language(
+        context: &Context,
+        #[graphql(description = "Thoth ID of language to be deleted")] language_id: Uuid,
+    ) -> FieldResult<Language> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let language = Language::from_id(&context.db, &language_id).unwrap();
+        context
+            .account_access
+            .can_edit(language.publisher_id(&context.db)?)?;
+
+        language.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single title using its ID")]
+    fn delete_work_title(
+        context: &Context,
+        #[graphql(description = "Thoth ID of title to be deleted")] work_title_id: Uuid,
+    ) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_title = WorkTitle::from_id(&context.db, &work_title_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_title.publisher_id(&context.db)?)?;
+
+        work_title.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single abstract using its ID")]
+    fn delete_work_abstract(
+        context: &Context,
+        #[graphql(description = "Thoth ID of abstract to be deleted")] work_abstract_id: Uuid,
+    ) -> FieldResult<WorkAbstract> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_abstract = WorkAbstract::from_id(&context.db, &work_abstract_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_abstract.publisher_id(&context.db)?)?;
+
+        work_abstract.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single work identifier using its ID")]
+    fn delete_work_identifier(
+        context: &Context,
+        #[graphql(description = "Thoth ID of work identifier to be deleted")]
+        work_identifier_id: Uuid,
+    ) -> FieldResult<WorkIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_identifier = WorkIdentifier::from_id(&context.db, &work_identifier_id).unwrap();
+        context
+            .account_access
+            .can_edit(work_identifier.publisher_id(&context.db)?)?;
+
+        work_identifier.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single prize using its ID")]
+    fn delete_prize(
+        context: &Context,
+        #[graphql(description = "Thoth ID of prize to be deleted")] prize_id: Uuid,
+    ) -> FieldResult<Prize> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let prize = Prize::from_id(&context.db, &prize_id).unwrap();
+        context
+            .account_access
+            .can_edit(prize.publisher_id(&context.db)?)?;
+
+        prize.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single event using its ID")]
+    fn delete_event(
+        context: &Context,
+        #[graphql(description = "Thoth ID of event to be deleted")] event_id: Uuid,
+    ) -> FieldResult<Event> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let event = Event::from_id(&context.db, &event_id).unwrap();
+        context
+            .account_access
+            .can_edit(event.publisher_id(&context.db)?)?;
+
+        event.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single event sponsor using its ID")]
+    fn delete_event_sponsor(
+        context: &Context,
+        #[graphql(description = "Thoth ID of event sponsor to be deleted")] event_sponsor_id: Uuid,
+    ) -> FieldResult<EventSponsor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let event_sponsor = EventSponsor::from_id(&context.db, &event_sponsor_id).unwrap();
+        context
+            .account_access
+            .can_edit(event_sponsor.publisher_id(&context.db)?)?;
+
+        event_sponsor.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single text content using its ID")]
+    fn delete_text_content(
+        context: &Context,
+        #[graphql(description = "Thoth ID of text content to be deleted")] text_content_id: Uuid,
+    ) -> FieldResult<TextContent> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let text_content = TextContent::from_id(&context.db, &text_content_id).unwrap();
+        context
+            .account_access
+            .can_edit(text_content.publisher_id(&context.db)?)?;
+
+        text_content.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single supporting resource using its ID")]
+    fn delete_supporting_resource(
+        context: &Context,
+        #[graphql(description = "Thoth ID of supporting resource to be deleted")]
+        supporting_resource_id: Uuid,
+    ) -> FieldResult<SupportingResource> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let supporting_resource =
+            SupportingResource::from_id(&context.db, &supporting_resource_id).unwrap();
+        context
+            .account_access
+            .can_edit(supporting_resource.publisher_id(&context.db)?)?;
+
+        supporting_resource
+            .delete(&context.db)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single publication identifier using its ID")]
+    fn delete_publication_identifier(
+        context: &Context,
+        #[graphql(description = "Thoth ID of publication identifier to be deleted")]
+        publication_identifier_id: Uuid,
+    ) -> FieldResult<PublicationIdentifier> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let publication_identifier =
+            PublicationIdentifier::from_id(&context.db, &publication_identifier_id).unwrap();
+        context
+            .account_access
+            .can_edit(publication_identifier.publisher_id(&context.db)?)?;
+
+        publication_identifier
+            .delete(&context.db)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single sales rights record using its ID")]
+    fn delete_sales_rights(
+        context: &Context,
+        #[graphql(description = "Thoth ID of sales rights to be deleted")] sales_rights_id: Uuid,
+    ) -> FieldResult<SalesRights> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let sales_rights = SalesRights::from_id(&context.db, &sales_rights_id).unwrap();
+        context
+            .account_access
+            .can_edit(sales_rights.publisher_id(&context.db)?)?;
+
+        sales_rights.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single usage constraint using its ID")]
+    fn delete_usage_constraint(
+        context: &Context,
+        #[graphql(description = "Thoth ID of usage constraint to be deleted")]
+        usage_constraint_id: Uuid,
+    ) -> FieldResult<UsageConstraint> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let usage_constraint = UsageConstraint::from_id(&context.db, &usage_constraint_id).unwrap();
+        context
+            .account_access
+            .can_edit(usage_constraint.publisher_id(&context.db)?)?;
+
+        usage_constraint.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single institution using its ID")]
+    fn delete_institution(
+        context: &Context,
+        #[graphql(description = "Thoth ID of institution to be deleted")] institution_id: Uuid,
+    ) -> FieldResult<Institution> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let institution = Institution::from_id(&context.db, &institution_id).unwrap();
+        for linked_publisher_id in institution.linked_publisher_ids(&context.db)? {
+            context.account_access.can_edit(linked_publisher_id)?;
+        }
+
+        institution.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single funding using its ID")]
+    fn delete_funding(
+        context: &Context,
+        #[graphql(description = "Thoth ID of funding to be deleted")] funding_id: Uuid,
+    ) -> FieldResult<Funding> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let funding = Funding::from_id(&context.db, &funding_id).unwrap();
+        context
+            .account_access
+            .can_edit(funding.publisher_id(&context.db)?)?;
+
+        funding.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single location using its ID")]
+    fn delete_location(
+        context: &Context,
+        #[graphql(description = "Thoth ID of location to be deleted")] location_id: Uuid,
+    ) -> FieldResult<Location> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let location = Location::from_id(&context.db, &location_id).unwrap();
+        // Only superusers can delete locations where Location Platform is Thoth
+        if !context.account_access.is_superuser
+            && location.location_platform == LocationPlatform::Thoth
+        {
+            return Err(ThothError::ThothLocationError.into());
+        }
+        context
+            .account_access
+            .can_edit(location.publisher_id(&context.db)?)?;
+
+        location.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single price using its ID")]
+    fn delete_price(
+        context: &Context,
+        #[graphql(description = "Thoth ID of price to be deleted")] price_id: Uuid,
+    ) -> FieldResult<Price> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let price = Price::from_id(&context.db, &price_id).unwrap();
+        context
+            .account_access
+            .can_edit(price.publisher_id(&context.db)?)?;
+
+        price.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single subject using its ID")]
+    fn delete_subject(
+        context: &Context,
+        #[graphql(description = "Thoth ID of subject to be deleted")] subject_id: Uuid,
+    ) -> FieldResult<Subject> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let subject = Subject::from_id(&context.db, &subject_id).unwrap();
+        context
+            .account_access
+            .can_edit(subject.publisher_id(&context.db)?)?;
+
+        subject.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single affiliation using its ID")]
+    fn delete_affiliation(
+        context: &Context,
+        #[graphql(description = "Thoth ID of affiliation to be deleted")] affiliation_id: Uuid,
+    ) -> FieldResult<Affiliation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let affiliation = Affiliation::from_id(&context.db, &affiliation_id).unwrap();
+        context
+            .account_access
+            .can_edit(affiliation.publisher_id(&context.db)?)?;
+
+        affiliation.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single work relation using its ID")]
+    fn delete_work_relation(
+        context: &Context,
+        #[graphql(description = "Thoth ID of work relation to be deleted")] work_relation_id: Uuid,
+    ) -> FieldResult<WorkRelation> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let work_relation = WorkRelation::from_id(&context.db, &work_relation_id).unwrap();
+        // Work relations may link works from different publishers.
+        // User must have permissions for all relevant publishers.
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            work_relation.relator_work_id,
+        )?)?;
+        context.account_access.can_edit(publisher_id_from_work_id(
+            &context.db,
+            work_relation.related_work_id,
+        )?)?;
+
+        work_relation.delete(&context.db).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Delete a single reference using its ID")]
+    fn delete_reference(
+        context: &Context,
+        #[graphql(description = "Thoth ID of reference to be deleted")] reference_id: Uuid,
+    ) -> FieldResult<Reference> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let reference = Reference::from_id(&context.db, &reference_id).unwrap();
+        context
+            .account_access
+            .can_edit(reference.publisher_id(&context.db)?)?;
+
+        reference.delete(&context.db).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A written text that can be published")]
+impl Work {
+    #[graphql(description = "Thoth ID of the work")]
+    pub fn work_id(&self) -> &Uuid {
+        &self.work_id
+    }
+
+    #[graphql(description = "Type of the work")]
+    pub fn work_type(&self) -> &WorkType {
+        &self.work_type
+    }
+
+    #[graphql(description = "Publication status of the work")]
+    pub fn work_status(&self) -> &WorkStatus {
+        &self.work_status
+    }
+
+    #[graphql(description = "Concatenation of title and subtitle with punctuation mark")]
+    pub fn full_title(&self) -> &str {
+        self.full_title.as_str()
+    }
+
+    #[graphql(description = "Main title of the work (excluding subtitle)")]
+    pub fn title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    #[graphql(description = "Secondary title of the work (excluding main title)")]
+    pub fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+
+    #[graphql(description = "Internal reference code")]
+    pub fn reference(&self) -> Option<&String> {
+        self.reference.as_ref()
+    }
+
+    #[graphql(description = "Edition number of the work (not applicable to chapters)")]
+    pub fn edition(&self) -> Option<&i32> {
+        self.edition.as_ref()
+    }
+
+    #[graphql(description = "Thoth ID of the work's imprint")]
+    pub fn imprint_id(&self) -> Uuid {
+        self.imprint_id
+    }
+
+    #[graphql(
+        description = "Digital Object Identifier of the work as full URL, using the HTTPS scheme and the doi.org domain (e.g. https://doi.org/10.11647/obp.0001)"
+    )]
+    pub fn doi(&self) -> Option<&Doi> {
+        self.doi.as_ref()
+    }
+
+    #[graphql(description = "Date the work was published")]
+    pub fn publication_date(&self) -> Option<NaiveDate> {
+        self.publication_date
+    }
+
+    #[graphql(
+        description = "Date the work was withdrawn from publication. Only applies to out of print and withdrawn works."
+    )]
+    pub fn withdrawn_date(&self) -> Option<NaiveDate> {
+        self.withdrawn_date
+    }
+
+    #[graphql(description = "Place of publication of the work")]
+    pub fn place(&self) -> Option<&String> {
+        self.place.as_ref()
+    }
+
+    #[graphql(
+        description = "Total number of pages in the work. In most cases, unnumbered pages (e.g. endpapers) should be omitted from this count."
+    )]
+    pub fn page_count(&self) -> Option<&i32> {
+        self.page_count.as_ref()
+    }
+
+    #[graphql(
+        description = "Breakdown of work's page count into front matter, main content, and/or back matter (e.g. 'xi + 140')"
+    )]
+    pub fn page_breakdown(&self) -> Option<&String> {
+        self.page_breakdown.as_ref()
+    }
+
+    #[graphql(description = "Total number of images in the work")]
+    pub fn image_count(&self) -> Option<&i32> {
+        self.image_count.as_ref()
+    }
+
+    #[graphql(description = "Total number of tables in the work")]
+    pub fn table_count(&self) -> Option<&i32> {
+        self.table_count.as_ref()
+    }
+
+    #[graphql(description = "Total number of audio fragments in the work")]
+    pub fn audio_count(&self) -> Option<&i32> {
+        self.audio_count.as_ref()
+    }
+
+    #[graphql(description = "Total number of video fragments in the work")]
+    pub fn video_count(&self) -> Option<&i32> {
+        self.video_count.as_ref()
+    }
+
+    #[graphql(
+        description = "Canonical URL of the license which applies to this work (frequently a Creative Commons license for open-access works). Licenses from the controlled vocabulary may be submitted either as a URL or as an SPDX identifier, and any other license as the URL of a custom open license"
+    )]
+    pub fn license(&self) -> Option<&String> {
+        self.license.as_ref()
+    }
+
+    #[graphql(
+        description = "Whether this work is open access, i.e. it carries either a license from the controlled vocabulary of open licenses or the URL of a custom open license"
+    )]
+    pub fn is_open_access(&self) -> bool {
+        is_open_access(self.license.as_deref())
+    }
+
+    #[graphql(description = "Copyright holder of the work")]
+    pub fn copyright_holder(&self) -> Option<&String> {
+        self.copyright_holder.as_ref()
+    }
+
+    #[graphql(description = "URL of the web page of the work")]
+    pub fn landing_page(&self) -> Option<&String> {
+        self.landing_page.as_ref()
+    }
+
+    #[graphql(
+        description = "Library of Congress Control Number of the work (not applicable to chapters)"
+    )]
+    pub fn lccn(&self) -> Option<&String> {
+        self.lccn.as_ref()
+    }
+
+    #[graphql(
+        description = "OCLC (WorldCat) Control Number of the work (not applicable to chapters)"
+    )]
+    pub fn oclc(&self) -> Option<&String> {
+        self.oclc.as_ref()
+    }
+
+    #[graphql(
+        description = "Short abstract of the work. Where a work has two different versions of the abstract, the truncated version should be entered here. Otherwise, it can be left blank. This field is not output in metadata formats; where relevant, Long Abstract is used instead."
+    )]
+    pub fn short_abstract(&self) -> Option<&String> {
+        self.short_abstract.as_ref()
+    }
+
+    #[graphql(
+        description = "Abstract of the work. Where a work has only one abstract, it should be entered here, and Short Abstract can be left blank. Long Abstract is output in metadata formats, and Short Abstract is not. May contain the following markup: <p>, <br>, <em>, <strong>, <sup>, <sub>, <ul>, <ol> and <li>."
+    )]
+    pub fn long_abstract(&self) -> Option<&String> {
+        self.long_abstract.as_ref()
+    }
+
+    #[graphql(
+        description = "A general-purpose field used to include information that does not have a specific designated field. May contain the following markup: <p>, <br>, <em>, <strong>, <sup>, <sub>, <ul>, <ol> and <li>."
+    )]
+    pub fn general_note(&self) -> Option<&String> {
+        self.general_note.as_ref()
+    }
+
+    #[graphql(
+        description = "Indicates that the work contains a bibliography or other similar information"
+    )]
+    pub fn bibliography_note(&self) -> Option<&String> {
+        self.bibliography_note.as_ref()
+    }
+
+    #[graphql(description = "Table of contents of the work (not applicable to chapters)")]
+    pub fn toc(&self) -> Option<&String> {
+        self.toc.as_ref()
+    }
+
+    #[graphql(description = "URL of the work's cover image")]
+    pub fn cover_url(
+        &self,
+        context: &Context,
+        #[graphql(
+            description = "Size of the rendition to return, for covers uploaded to Thoth. Null if no rendition of this size has been generated"
+        )]
+        size: Option<CoverRendition>,
+    ) -> FieldResult<Option<String>> {
+        match size {
+            Some(size) => {
+                SupportingResource::from_cover_rendition(&context.db, &self.work_id, &size)
+                    .map(|rendition| rendition.map(|r| r.resource_url))
+                    .map_err(|e| e.into())
+            }
+            None => Ok(self.cover_url.clone()),
+        }
+    }
+
+    #[graphql(description = "Caption describing the work's cover image")]
+    pub fn cover_caption(&self) -> Option<&String> {
+        self.cover_caption.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the work record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the work record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(
+        description = "Page number on which the work begins (only applicable to chapters and journal articles)"
+    )]
+    pub fn first_page(&self) -> Option<&String> {
+        self.first_page.as_ref()
+    }
+
+    #[graphql(
+        description = "Page number on which the work ends (only applicable to chapters and journal articles)"
+    )]
+    pub fn last_page(&self) -> Option<&String> {
+        self.last_page.as_ref()
+    }
+
+    #[graphql(
+        description = "Concatenation of first page and last page with dash (only applicable to chapters and journal articles)"
+    )]
+    pub fn page_interval(&self) -> Option<&String> {
+        self.page_interval.as_ref()
+    }
+
+    #[graphql(
+        description = "Number identifying the article within its journal issue, used in place of or alongside page numbers (only applicable to journal articles)"
+    )]
+    pub fn article_number(&self) -> Option<&String> {
+        self.article_number.as_ref()
+    }
+
+    #[graphql(
+        description = "Date and time at which the work record or any of its linked records was last updated"
+    )]
+    pub fn updated_at_with_relations(&self) -> Timestamp {
+        self.updated_at_with_relations
+    }
+
+    #[graphql(description = "Get this work's imprint")]
+    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
+        Imprint::from_id(&context.db, &self.imprint_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get contributions linked to this work")]
+    pub fn contributions(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = ContributionOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ContributionOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        contribution_types: Option<Vec<ContributionType>>,
+    ) -> FieldResult<Vec<Contribution>> {
+        Contribution::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            contribution_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Get languages linked to this work")]
+    pub fn languages(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = LanguageOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<LanguageOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific languages to filter by"
+        )]
+        language_codes: Option<Vec<LanguageCode>>,
+        #[graphql(
+            description = "(deprecated) A specific relation to filter by"
+        )]
+        language_relation: Option<LanguageRelation>,
+        #[graphql(
+            default = vec![],
+            description = "Specific relations to filter by"
+        )]
+        language_relations: Option<Vec<LanguageRelation>>,
+    ) -> FieldResult<Vec<Language>> {
+        let mut relations = language_relations.unwrap_or_default();
+        if let Some(relation) = language_relation {
+            relations.push(relation);
+        }
+        Language::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            language_codes.unwrap_or_default(),
+            relations,
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get titles of this work in each of its languages")]
+    pub fn titles(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkTitleOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkTitleOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific languages to filter by"
+        )]
+        language_codes: Option<Vec<LanguageCode>>,
+    ) -> FieldResult<Vec<WorkTitle>> {
+        WorkTitle::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            language_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Get abstracts of this work in each of its languages")]
+    pub fn abstracts(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkAbstractOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkAbstractOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific languages to filter by"
+        )]
+        language_codes: Option<Vec<LanguageCode>>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by"
+        )]
+        abstract_types: Option<Vec<AbstractType>>,
+    ) -> FieldResult<Vec<WorkAbstract>> {
+        WorkAbstract::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            language_codes.unwrap_or_default(),
+            abstract_types.unwrap_or_default(),
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Get persistent identifiers of this work, in addition to its own identifier fields"
+    )]
+    pub fn identifiers(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkIdentifierOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkIdentifierOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific identifier types to filter by"
+        )]
+        identifier_types: Option<Vec<IdentifierType>>,
+    ) -> FieldResult<Vec<WorkIdentifier>> {
+        WorkIdentifier::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            identifier_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get publications linked to this work")]
+    pub fn publications(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on isbn"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = PublicationOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PublicationOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        publication_types: Option<Vec<PublicationType>>,
+    ) -> FieldResult<Vec<Publication>> {
+        Publication::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            publication_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get subjects linked to this work")]
+    pub fn subjects(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = SubjectOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SubjectOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        subject_types: Option<Vec<SubjectType>>,
+    ) -> FieldResult<Vec<Subject>> {
+        Subject::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            subject_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get fundings linked to this work")]
+    pub fn fundings(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = FundingOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<FundingOrderBy>,
+    ) -> FieldResult<Vec<Funding>> {
+        Funding::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get prizes and awards achieved by this work")]
+    pub fn prizes(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = PrizeOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PrizeOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific prize achievement codes to filter by"
+        )]
+        prize_codes: Option<Vec<PrizeCode>>,
+    ) -> FieldResult<Vec<Prize>> {
+        Prize::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            prize_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Get conferences or other events whose proceedings are published in this work"
+    )]
+    pub fn events(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = EventOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<EventOrderBy>,
+    ) -> FieldResult<Vec<Event>> {
+        Event::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Get review quotes, endorsements and other promotional text about this work"
+    )]
+    pub fn text_contents(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = TextContentOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<TextContentOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific text types to filter by"
+        )]
+        text_types: Option<Vec<TextContentType>>,
+    ) -> FieldResult<Vec<TextContent>> {
+        TextContent::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            text_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Get cover images, sample content and other resources supporting this work"
+    )]
+    pub fn supporting_resources(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SupportingResourceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SupportingResourceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific resource content types to filter by"
+        )]
+        resource_content_types: Option<Vec<ResourceContentType>>,
+    ) -> FieldResult<Vec<SupportingResource>> {
+        SupportingResource::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            resource_content_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get files uploaded to Thoth's asset storage for this work")]
+    pub fn assets(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = AssetOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<AssetOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific asset types to filter by"
+        )]
+        asset_types: Option<Vec<AssetType>>,
+    ) -> FieldResult<Vec<Asset>> {
+        Asset::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            asset_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get issues linked to this work")]
+    pub fn issues(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = IssueOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<IssueOrderBy>,
+    ) -> FieldResult<Vec<Issue>> {
+        Issue::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+    #[graphql(description = "Get other works related to this work")]
+    pub fn relations(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = WorkRelationOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkRelationOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        relation_types: Option<Vec<RelationType>>,
+    ) -> FieldResult<Vec<WorkRelation>> {
+        WorkRelation::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            relation_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
+    #[graphql(description = "Get references cited by this work")]
+    pub fn references(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on doi, unstructured_citation, issn, isbn, journal_title, article_title, series_title, volume_title, author, standard_designator, standards_body_name, and standards_body_acronym"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = ReferenceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ReferenceOrderBy>,
+    ) -> FieldResult<Vec<Reference>> {
+        Reference::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.work_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Delete a single work using its ID")]
-    fn delete_work(
-        context: &Context,
-        #[graphql(description = "Thoth ID of work to be deleted")] work_id: Uuid,
-    ) -> FieldResult<Work> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let work = Work::from_id(&context.db, &work_id).unwrap();
-        context
-            .account_access
-            .can_edit(work.publisher_id(&context.db)?)?;
-
-        if work.is_published() && !context.account_access.is_superuser {
-            return Err(ThothError::ThothDeleteWorkError.into());
-        }
+#[juniper::graphql_object(Context = Context, description = "A manifestation of a written text")]
+impl Publication {
+    #[graphql(description = "Thoth ID of the publication")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
 
-        work.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Format of this publication")]
+    pub fn publication_type(&self) -> &PublicationType {
+        &self.publication_type
     }
 
-    #[graphql(description = "Delete a single publisher using its ID")]
-    fn delete_publisher(
-        context: &Context,
-        #[graphql(description = "Thoth ID of publisher to be deleted")] publisher_id: Uuid,
-    ) -> FieldResult<Publisher> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let publisher = Publisher::from_id(&context.db, &publisher_id).unwrap();
-        context.account_access.can_edit(publisher_id)?;
+    #[graphql(description = "Thoth ID of the work to which this publication belongs")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
 
-        publisher.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "International Standard Book Number of the publication, in ISBN-13 format"
+    )]
+    pub fn isbn(&self) -> Option<&Isbn> {
+        self.isbn.as_ref()
     }
 
-    #[graphql(description = "Delete a single imprint using its ID")]
-    fn delete_imprint(
-        context: &Context,
-        #[graphql(description = "Thoth ID of imprint to be deleted")] imprint_id: Uuid,
-    ) -> FieldResult<Imprint> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let imprint = Imprint::from_id(&context.db, &imprint_id).unwrap();
-        context.account_access.can_edit(imprint.publisher_id())?;
+    #[graphql(description = "Date and time at which the publication record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
 
-        imprint.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Date and time at which the publication record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
     }
 
-    #[graphql(description = "Delete a single contributor using its ID")]
-    fn delete_contributor(
-        context: &Context,
-        #[graphql(description = "Thoth ID of contributor to be deleted")] contributor_id: Uuid,
-    ) -> FieldResult<Contributor> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let contributor = Contributor::from_id(&context.db, &contributor_id).unwrap();
-        for linked_publisher_id in contributor.linked_publisher_ids(&context.db)? {
-            context.account_access.can_edit(linked_publisher_id)?;
+    #[graphql(
+        description = "Width of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+    )]
+    pub fn width(
+        &self,
+        #[graphql(
+            default = LengthUnit::default(),
+            description = "Unit of measurement in which to represent the width (mm, cm or in)",
+        )]
+        units: LengthUnit,
+    ) -> Option<f64> {
+        match units {
+            LengthUnit::Mm => self.width_mm,
+            LengthUnit::Cm => self
+                .width_mm
+                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
+            LengthUnit::In => self.width_in,
         }
-
-        contributor.delete(&context.db).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Delete a single contribution using its ID")]
-    fn delete_contribution(
-        context: &Context,
-        #[graphql(description = "Thoth ID of contribution to be deleted")] contribution_id: Uuid,
-    ) -> FieldResult<Contribution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let contribution = Contribution::from_id(&context.db, &contribution_id).unwrap();
-        context
-            .account_access
-            .can_edit(contribution.publisher_id(&context.db)?)?;
-
-        contribution.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Height of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+    )]
+    pub fn height(
+        &self,
+        #[graphql(
+            default = LengthUnit::default(),
+            description = "Unit of measurement in which to represent the height (mm, cm or in)",
+        )]
+        units: LengthUnit,
+    ) -> Option<f64> {
+        match units {
+            LengthUnit::Mm => self.height_mm,
+            LengthUnit::Cm => self
+                .height_mm
+                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
+            LengthUnit::In => self.height_in,
+        }
     }
 
-    #[graphql(description = "Delete a single publication using its ID")]
-    fn delete_publication(
-        context: &Context,
-        #[graphql(description = "Thoth ID of publication to be deleted")] publication_id: Uuid,
-    ) -> FieldResult<Publication> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let publication = Publication::from_id(&context.db, &publication_id).unwrap();
-        context
-            .account_access
-            .can_edit(publication.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "Depth of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+    )]
+    pub fn depth(
+        &self,
+        #[graphql(
+            default = LengthUnit::default(),
+            description = "Unit of measurement in which to represent the depth (mm, cm or in)",
+        )]
+        units: LengthUnit,
+    ) -> Option<f64> {
+        match units {
+            LengthUnit::Mm => self.depth_mm,
+            LengthUnit::Cm => self
+                .depth_mm
+                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
+            LengthUnit::In => self.depth_in,
+        }
+    }
 
-        publication.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Weight of the physical Publication (in g or oz) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+    )]
+    pub fn weight(
+        &self,
+        #[graphql(
+            default = WeightUnit::default(),
+            description = "Unit of measurement in which to represent the weight (grams or ounces)",
+        )]
+        units: WeightUnit,
+    ) -> Option<f64> {
+        match units {
+            WeightUnit::G => self.weight_g,
+            WeightUnit::Oz => self.weight_oz,
+        }
     }
 
-    #[graphql(description = "Delete a single series using its ID")]
-    fn delete_series(
-        context: &Context,
-        #[graphql(description = "Thoth ID of series to be deleted")] series_id: Uuid,
-    ) -> FieldResult<Series> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let series = Series::from_id(&context.db, &series_id).unwrap();
-        context
-            .account_access
-            .can_edit(series.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "Accessibility features of the digital publication (ONIX codelist 196)"
+    )]
+    pub fn accessibility_features(&self) -> &Vec<AccessibilityFeature> {
+        &self.accessibility_features
+    }
 
-        series.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Hazard warnings for the digital publication, or a statement that there are none"
+    )]
+    pub fn accessibility_hazards(&self) -> &Vec<AccessibilityHazard> {
+        &self.accessibility_hazards
     }
 
-    #[graphql(description = "Delete a single issue using its ID")]
-    fn delete_issue(
-        context: &Context,
-        #[graphql(description = "Thoth ID of issue to be deleted")] issue_id: Uuid,
-    ) -> FieldResult<Issue> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let issue = Issue::from_id(&context.db, &issue_id).unwrap();
-        context
-            .account_access
-            .can_edit(issue.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "EPUB accessibility specification to which the digital publication conforms"
+    )]
+    pub fn accessibility_standard(&self) -> Option<&AccessibilityStandard> {
+        self.accessibility_standard.as_ref()
+    }
 
-        issue.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Version of the Web Content Accessibility Guidelines to which the digital publication conforms"
+    )]
+    pub fn wcag_version(&self) -> Option<&WcagVersion> {
+        self.wcag_version.as_ref()
     }
 
-    #[graphql(description = "Delete a single language using its ID")]
-    fn delete_language(
-        context: &Context,
-        #[graphql(description = "Thoth ID of language to be deleted")] language_id: Uuid,
-    ) -> FieldResult<Language> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let language = Language::from_id(&context.db, &language_id).unwrap();
-        context
-            .account_access
-            .can_edit(language.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "Level of the Web Content Accessibility Guidelines to which the digital publication conforms"
+    )]
+    pub fn wcag_level(&self) -> Option<&WcagLevel> {
+        self.wcag_level.as_ref()
+    }
 
-        language.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Name of the organisation which certified the accessibility conformance of the digital publication"
+    )]
+    pub fn accessibility_certifier(&self) -> Option<&String> {
+        self.accessibility_certifier.as_ref()
     }
 
-    #[graphql(description = "Delete a single institution using its ID")]
-    fn delete_institution(
-        context: &Context,
-        #[graphql(description = "Thoth ID of institution to be deleted")] institution_id: Uuid,
-    ) -> FieldResult<Institution> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let institution = Institution::from_id(&context.db, &institution_id).unwrap();
-        for linked_publisher_id in institution.linked_publisher_ids(&context.db)? {
-            context.account_access.can_edit(linked_publisher_id)?;
-        }
+    #[graphql(
+        description = "Human-readable summary of the accessibility of the digital publication"
+    )]
+    pub fn accessibility_summary(&self) -> Option<&String> {
+        self.accessibility_summary.as_ref()
+    }
 
-        institution.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Binding method of the physical publication")]
+    pub fn binding_style(&self) -> Option<&BindingStyle> {
+        self.binding_style.as_ref()
     }
 
-    #[graphql(description = "Delete a single funding using its ID")]
-    fn delete_funding(
-        context: &Context,
-        #[graphql(description = "Thoth ID of funding to be deleted")] funding_id: Uuid,
-    ) -> FieldResult<Funding> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let funding = Funding::from_id(&context.db, &funding_id).unwrap();
-        context
-            .account_access
-            .can_edit(funding.publisher_id(&context.db)?)?;
+    #[graphql(description = "Interior paper stock of the physical publication")]
+    pub fn paper_type(&self) -> Option<&PaperType> {
+        self.paper_type.as_ref()
+    }
 
-        funding.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Interior printing colour of the physical publication")]
+    pub fn interior_colour(&self) -> Option<&InteriorColour> {
+        self.interior_colour.as_ref()
     }
 
-    #[graphql(description = "Delete a single location using its ID")]
-    fn delete_location(
-        context: &Context,
-        #[graphql(description = "Thoth ID of location to be deleted")] location_id: Uuid,
-    ) -> FieldResult<Location> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let location = Location::from_id(&context.db, &location_id).unwrap();
-        // Only superusers can delete locations where Location Platform is Thoth
-        if !context.account_access.is_superuser
-            && location.location_platform == LocationPlatform::Thoth
-        {
-            return Err(ThothError::ThothLocationError.into());
-        }
-        context
-            .account_access
-            .can_edit(location.publisher_id(&context.db)?)?;
+    #[graphql(description = "Trim size category of the physical publication")]
+    pub fn trim_category(&self) -> Option<&TrimCategory> {
+        self.trim_category.as_ref()
+    }
 
-        location.delete(&context.db).map_err(|e| e.into())
+    #[graphql(
+        description = "Number of pages in the physical publication, where this differs from the work's page count"
+    )]
+    pub fn page_count(&self) -> Option<&i32> {
+        self.page_count.as_ref()
     }
 
-    #[graphql(description = "Delete a single price using its ID")]
-    fn delete_price(
-        context: &Context,
-        #[graphql(description = "Thoth ID of price to be deleted")] price_id: Uuid,
-    ) -> FieldResult<Price> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let price = Price::from_id(&context.db, &price_id).unwrap();
-        context
-            .account_access
-            .can_edit(price.publisher_id(&context.db)?)?;
+    #[graphql(description = "Cover finish of the physical publication")]
+    pub fn cover_finish(&self) -> Option<&CoverFinish> {
+        self.cover_finish.as_ref()
+    }
 
-        price.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Number of copies of the physical publication packed in a carton")]
+    pub fn carton_quantity(&self) -> Option<&i32> {
+        self.carton_quantity.as_ref()
     }
 
-    #[graphql(description = "Delete a single subject using its ID")]
-    fn delete_subject(
-        context: &Context,
-        #[graphql(description = "Thoth ID of subject to be deleted")] subject_id: Uuid,
-    ) -> FieldResult<Subject> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let subject = Subject::from_id(&context.db, &subject_id).unwrap();
-        context
-            .account_access
-            .can_edit(subject.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "Version of the file format of the digital publication, e.g. 3.0 for EPUB 3.0"
+    )]
+    pub fn format_version(&self) -> Option<&String> {
+        self.format_version.as_ref()
+    }
 
-        subject.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Size of the digital publication's file, in kilobytes")]
+    pub fn file_size_kb(&self) -> Option<&i32> {
+        self.file_size_kb.as_ref()
     }
 
-    #[graphql(description = "Delete a single affiliation using its ID")]
-    fn delete_affiliation(
-        context: &Context,
-        #[graphql(description = "Thoth ID of affiliation to be deleted")] affiliation_id: Uuid,
-    ) -> FieldResult<Affiliation> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let affiliation = Affiliation::from_id(&context.db, &affiliation_id).unwrap();
-        context
-            .account_access
-            .can_edit(affiliation.publisher_id(&context.db)?)?;
+    #[graphql(
+        description = "Technical protection applied to the digital publication, such as DRM or watermarking"
+    )]
+    pub fn technical_protections(&self) -> &Vec<TechnicalProtection> {
+        &self.technical_protections
+    }
 
-        affiliation.delete(&context.db).map_err(|e| e.into())
+    #[graphql(description = "Whether the digital publication is reflowable or has a fixed layout")]
+    pub fn layout(&self) -> Option<&DigitalLayout> {
+        self.layout.as_ref()
     }
 
-    #[graphql(description = "Delete a single work relation using its ID")]
-    fn delete_work_relation(
+    #[graphql(description = "Get prices linked to this publication")]
+    pub fn prices(
+        &self,
         context: &Context,
-        #[graphql(description = "Thoth ID of work relation to be deleted")] work_relation_id: Uuid,
-    ) -> FieldResult<WorkRelation> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let work_relation = WorkRelation::from_id(&context.db, &work_relation_id).unwrap();
-        // Work relations may link works from different publishers.
-        // User must have permissions for all relevant publishers.
-        context.account_access.can_edit(publisher_id_from_work_id(
-            &context.db,
-            work_relation.relator_work_id,
-        )?)?;
-        context.account_access.can_edit(publisher_id_from_work_id(
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = PriceOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PriceOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific currencies to filter by"
+        )]
+        currency_codes: Option<Vec<CurrencyCode>>,
+    ) -> FieldResult<Vec<Price>> {
+        Price::all(
             &context.db,
-            work_relation.related_work_id,
-        )?)?;
-
-        work_relation.delete(&context.db).map_err(|e| e.into())
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            currency_codes.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Delete a single reference using its ID")]
-    fn delete_reference(
+    #[graphql(description = "Get locations linked to this publication")]
+    pub fn locations(
+        &self,
         context: &Context,
-        #[graphql(description = "Thoth ID of reference to be deleted")] reference_id: Uuid,
-    ) -> FieldResult<Reference> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let reference = Reference::from_id(&context.db, &reference_id).unwrap();
-        context
-            .account_access
-            .can_edit(reference.publisher_id(&context.db)?)?;
-
-        reference.delete(&context.db).map_err(|e| e.into())
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = LocationOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<LocationOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific platforms to filter by"
+        )]
+        location_platforms: Option<Vec<LocationPlatform>>,
+    ) -> FieldResult<Vec<Location>> {
+        Location::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            location_platforms.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
-}
 
-#[juniper::graphql_object(Context = Context, description = "A written text that can be published")]
-impl Work {
-    #[graphql(description = "Thoth ID of the work")]
-    pub fn work_id(&self) -> &Uuid {
-        &self.work_id
+    #[graphql(
+        description = "Get persistent identifiers of this publication, in addition to its own identifier fields"
+    )]
+    pub fn identifiers(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = PublicationIdentifierOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<PublicationIdentifierOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific identifier types to filter by"
+        )]
+        identifier_types: Option<Vec<IdentifierType>>,
+    ) -> FieldResult<Vec<PublicationIdentifier>> {
+        PublicationIdentifier::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            identifier_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Type of the work")]
-    pub fn work_type(&self) -> &WorkType {
-        &self.work_type
+    #[graphql(
+        description = "Get the territories in which this publication may or may not be sold. Where none are recorded, world rights are assumed"
+    )]
+    pub fn sales_rights(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = SalesRightsOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<SalesRightsOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of sales rights to filter by"
+        )]
+        sales_rights_types: Option<Vec<SalesRightsType>>,
+    ) -> FieldResult<Vec<SalesRights>> {
+        SalesRights::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            sales_rights_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Publication status of the work")]
-    pub fn work_status(&self) -> &WorkStatus {
-        &self.work_status
+    #[graphql(
+        description = "Get the constraints on how this digital publication may be used, such as print or copy limits"
+    )]
+    pub fn usage_constraints(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = UsageConstraintOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<UsageConstraintOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types of usage to filter by"
+        )]
+        usage_types: Option<Vec<UsageType>>,
+    ) -> FieldResult<Vec<UsageConstraint>> {
+        UsageConstraint::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publication_id),
+            None,
+            usage_types.unwrap_or_default(),
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Concatenation of title and subtitle with punctuation mark")]
-    pub fn full_title(&self) -> &str {
-        self.full_title.as_str()
+    #[graphql(description = "Get the work to which this publication belongs")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Main title of the work (excluding subtitle)")]
-    pub fn title(&self) -> &str {
-        self.title.as_str()
+#[juniper::graphql_object(Context = Context, description = "An organisation that produces and distributes written texts.")]
+impl Publisher {
+    #[graphql(description = "Thoth ID of the publisher")]
+    pub fn publisher_id(&self) -> Uuid {
+        self.publisher_id
     }
 
-    #[graphql(description = "Secondary title of the work (excluding main title)")]
-    pub fn subtitle(&self) -> Option<&String> {
-        self.subtitle.as_ref()
+    #[graphql(description = "Name of the publisher")]
+    pub fn publisher_name(&self) -> &String {
+        &self.publisher_name
     }
 
-    #[graphql(description = "Internal reference code")]
-    pub fn reference(&self) -> Option<&String> {
-        self.reference.as_ref()
+    #[graphql(description = "Short name of the publisher, if any (e.g. an abbreviation)")]
+    pub fn publisher_shortname(&self) -> Option<&String> {
+        self.publisher_shortname.as_ref()
     }
 
-    #[graphql(description = "Edition number of the work (not applicable to chapters)")]
-    pub fn edition(&self) -> Option<&i32> {
-        self.edition.as_ref()
+    #[graphql(description = "URL of the publisher's website")]
+    pub fn publisher_url(&self) -> Option<&String> {
+        self.publisher_url.as_ref()
     }
 
-    #[graphql(description = "Thoth ID of the work's imprint")]
-    pub fn imprint_id(&self) -> Uuid {
-        self.imprint_id
+    #[graphql(description = "Date and time at which the publisher record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the publisher record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
     }
 
     #[graphql(
-        description = "Digital Object Identifier of the work as full URL, using the HTTPS scheme and the doi.org domain (e.g. https://doi.org/10.11647/obp.0001)"
+        description = "Whether accounts linked to this publisher must use two-factor authentication in order to edit its records"
     )]
-    pub fn doi(&self) -> Option<&Doi> {
-        self.doi.as_ref()
+    pub fn require_two_factor(&self) -> bool {
+        self.require_two_factor
     }
 
-    #[graphql(description = "Date the work was published")]
-    pub fn publication_date(&self) -> Option<NaiveDate> {
-        self.publication_date
+    #[graphql(description = "Get imprints linked to this publisher")]
+    pub fn imprints(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on imprint_name and imprint_url"
+        )]
+        filter: Option<String>,
+        #[graphql(
+           default = {
+                ImprintOrderBy {
+                    field: ImprintField::ImprintName,
+                    direction: Direction::Asc,
+                }
+            },
+            description = "The order in which to sort the results"
+        )]
+        order: Option<ImprintOrderBy>,
+    ) -> FieldResult<Vec<Imprint>> {
+        Imprint::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.publisher_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
     }
+}
 
-    #[graphql(
-        description = "Date the work was withdrawn from publication. Only applies to out of print and withdrawn works."
-    )]
-    pub fn withdrawn_date(&self) -> Option<NaiveDate> {
-        self.withdrawn_date
+#[juniper::graphql_object(Context = Context, description = "The brand under which a publisher issues works.")]
+impl Imprint {
+    #[graphql(description = "Thoth ID of the imprint")]
+    pub fn imprint_id(&self) -> Uuid {
+        self.imprint_id
     }
 
-    #[graphql(description = "Place of publication of the work")]
-    pub fn place(&self) -> Option<&String> {
-        self.place.as_ref()
+    #[graphql(description = "Thoth ID of the publisher to which this imprint belongs")]
+    pub fn publisher_id(&self) -> Uuid {
+        self.publisher_id
     }
 
-    #[graphql(
-        description = "Total number of pages in the work. In most cases, unnumbered pages (e.g. endpapers) should be omitted from this count."
-    )]
-    pub fn page_count(&self) -> Option<&i32> {
-        self.page_count.as_ref()
+    #[graphql(description = "Name of the imprint")]
+    pub fn imprint_name(&self) -> &String {
+        &self.imprint_name
     }
 
-    #[graphql(
-        description = "Breakdown of work's page count into front matter, main content, and/or back matter (e.g. 'xi + 140')"
-    )]
-    pub fn page_breakdown(&self) -> Option<&String> {
-        self.page_breakdown.as_ref()
+    #[graphql(description = "URL of the imprint's landing page")]
+    pub fn imprint_url(&self) -> Option<&String> {
+        self.imprint_url.as_ref()
     }
 
-    #[graphql(description = "Total number of images in the work")]
-    pub fn image_count(&self) -> Option<&i32> {
-        self.image_count.as_ref()
+    #[graphql(
+        description = "DOI of the imprint's Crossmark policy page, if publisher participates. Crossmark 'gives readers quick and easy access to the
+    current status of an item of content, including any corrections, retractions, or updates'. More: https://www.crossref.org/services/crossmark/"
+    )]
+    pub fn crossmark_doi(&self) -> Option<&Doi> {
+        self.crossmark_doi.as_ref()
     }
 
-    #[graphql(description = "Total number of tables in the work")]
-    pub fn table_count(&self) -> Option<&i32> {
-        self.table_count.as_ref()
+    #[graphql(description = "Date and time at which the imprint record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
     }
 
-    #[graphql(description = "Total number of audio fragments in the work")]
-    pub fn audio_count(&self) -> Option<&i32> {
-        self.audio_count.as_ref()
+    #[graphql(description = "Date and time at which the imprint record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
     }
 
-    #[graphql(description = "Total number of video fragments in the work")]
-    pub fn video_count(&self) -> Option<&i32> {
-        self.video_count.as_ref()
+    #[graphql(description = "Get the publisher to which this imprint belongs")]
+    pub fn publisher(&self, context: &Context) -> FieldResult<Publisher> {
+        Publisher::from_id(&context.db, &self.publisher_id).map_err(|e| e.into())
     }
 
-    #[graphql(
-        description = "URL of the license which applies to this work (frequently a Creative Commons license for open-access works)"
-    )]
-    pub fn license(&self) -> Option<&String> {
-        self.license.as_ref()
+    #[allow(clippy::too_many_arguments)]
+    #[graphql(description = "Get works linked to this imprint")]
+    pub fn works(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = "".to_string(),
+            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title, doi, reference, short_abstract, long_abstract, and landing_page"
+        )]
+        filter: Option<String>,
+        #[graphql(
+            default = WorkOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<WorkOrderBy>,
+        #[graphql(
+            default = vec![],
+            description = "Specific types to filter by",
+        )]
+        work_types: Option<Vec<WorkType>>,
+        #[graphql(description = "(deprecated) A specific status to filter by")] work_status: Option<
+            WorkStatus,
+        >,
+        #[graphql(
+            default = vec![],
+            description = "Specific statuses to filter by"
+        )]
+        work_statuses: Option<Vec<WorkStatus>>,
+        #[graphql(
+            description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
+        )]
+        updated_at_with_relations: Option<TimeExpression>,
+    ) -> FieldResult<Vec<Work>> {
+        let mut statuses = work_statuses.unwrap_or_default();
+        if let Some(status) = work_status {
+            statuses.push(status);
+        }
+        Work::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            filter,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.imprint_id),
+            None,
+            work_types.unwrap_or_default(),
+            statuses,
+            updated_at_with_relations,
+        )
+        .map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Copyright holder of the work")]
-    pub fn copyright_holder(&self) -> Option<&String> {
-        self.copyright_holder.as_ref()
+#[juniper::graphql_object(Context = Context, description = "A person who has been involved in the production of a written text.")]
+impl Contributor {
+    #[graphql(description = "Thoth ID of the contributor")]
+    pub fn contributor_id(&self) -> Uuid {
+        self.contributor_id
     }
 
-    #[graphql(description = "URL of the web page of the work")]
-    pub fn landing_page(&self) -> Option<&String> {
-        self.landing_page.as_ref()
+    #[graphql(description = "Given or first name(s) of the contributor")]
+    pub fn first_name(&self) -> Option<&String> {
+        self.first_name.as_ref()
     }
 
-    #[graphql(
-        description = "Library of Congress Control Number of the work (not applicable to chapters)"
-    )]
-    pub fn lccn(&self) -> Option<&String> {
-        self.lccn.as_ref()
+    #[graphql(description = "Family or surname of the contributor")]
+    pub fn last_name(&self) -> &String {
+        &self.last_name
     }
 
     #[graphql(
-        description = "OCLC (WorldCat) Control Number of the work (not applicable to chapters)"
+        description = "Full, serialized name of the contributor. Serialization is often culturally determined."
     )]
-    pub fn oclc(&self) -> Option<&String> {
-        self.oclc.as_ref()
+    pub fn full_name(&self) -> &String {
+        &self.full_name
     }
 
     #[graphql(
-        description = "Short abstract of the work. Where a work has two different versions of the abstract, the truncated version should be entered here. Otherwise, it can be left blank. This field is not output in metadata formats; where relevant, Long Abstract is used instead."
+        description = "ORCID (Open Researcher and Contributor ID) of the contributor as full URL, using the HTTPS scheme and the orcid.org domain (e.g. https://orcid.org/0000-0002-1825-0097)"
     )]
-    pub fn short_abstract(&self) -> Option<&String> {
-        self.short_abstract.as_ref()
+    pub fn orcid(&self) -> Option<&Orcid> {
+        self.orcid.as_ref()
     }
 
-    #[graphql(
-        description = "Abstract of the work. Where a work has only one abstract, it should be entered here, and Short Abstract can be left blank. Long Abstract is output in metadata formats, and Short Abstract is not."
-    )]
-    pub fn long_abstract(&self) -> Option<&String> {
-        self.long_abstract.as_ref()
+    #[graphql(description = "URL of the contributor's website")]
+    pub fn website(&self) -> Option<&String> {
+        self.website.as_ref()
     }
 
     #[graphql(
-        description = "A general-purpose field used to include information that does not have a specific designated field"
+        description = "ISNI (International Standard Name Identifier) of the contributor, as 16 characters without spaces (e.g. 0000000121032683)"
     )]
-    pub fn general_note(&self) -> Option<&String> {
-        self.general_note.as_ref()
+    pub fn isni(&self) -> Option<&Isni> {
+        self.isni.as_ref()
     }
 
     #[graphql(
-        description = "Indicates that the work contains a bibliography or other similar information"
+        description = "VIAF (Virtual International Authority File) cluster identifier of the contributor (e.g. 44300643)"
     )]
-    pub fn bibliography_note(&self) -> Option<&String> {
-        self.bibliography_note.as_ref()
-    }
-
-    #[graphql(description = "Table of contents of the work (not applicable to chapters)")]
-    pub fn toc(&self) -> Option<&String> {
-        self.toc.as_ref()
+    pub fn viaf(&self) -> Option<&Viaf> {
+        self.viaf.as_ref()
     }
 
-    #[graphql(description = "URL of the work's cover image")]
-    pub fn cover_url(&self) -> Option<&String> {
-        self.cover_url.as_ref()
+    #[graphql(description = "Wikidata item identifier of the contributor (e.g. Q935)")]
+    pub fn wikidata(&self) -> Option<&WikidataId> {
+        self.wikidata.as_ref()
     }
 
-    #[graphql(description = "Caption describing the work's cover image")]
-    pub fn cover_caption(&self) -> Option<&String> {
-        self.cover_caption.as_ref()
+    #[graphql(
+        description = "Library of Congress Name Authority File identifier of the contributor (e.g. n79021164)"
+    )]
+    pub fn lcnaf(&self) -> Option<&Lcnaf> {
+        self.lcnaf.as_ref()
     }
 
-    #[graphql(description = "Date and time at which the work record was created")]
+    #[graphql(description = "Date and time at which the contributor record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the work record was last updated")]
+    #[graphql(description = "Date and time at which the contributor record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Page number on which the work begins (only applicable to chapters)")]
-    pub fn first_page(&self) -> Option<&String> {
-        self.first_page.as_ref()
-    }
-
-    #[graphql(description = "Page number on which the work ends (only applicable to chapters)")]
-    pub fn last_page(&self) -> Option<&String> {
-        self.last_page.as_ref()
-    }
-
-    #[graphql(
-        description = "Concatenation of first page and last page with dash (only applicable to chapters)"
-    )]
-    pub fn page_interval(&self) -> Option<&String> {
-        self.page_interval.as_ref()
-    }
-
-    #[graphql(
-        description = "Date and time at which the work record or any of its linked records was last updated"
-    )]
-    pub fn updated_at_with_relations(&self) -> Timestamp {
-        self.updated_at_with_relations
-    }
-
-    #[graphql(description = "Get this work's imprint")]
-    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
-        Imprint::from_id(&context.db, &self.imprint_id).map_err(|e| e.into())
-    }
-
-    #[graphql(description = "Get contributions linked to this work")]
-    pub fn contributions(
+    #[graphql(description = "Get alternative forms of this contributor's name")]
+    pub fn alternative_names(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = ContributionOrderBy::default(),
+            default = AlternativeNameOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<ContributionOrderBy>,
+        order: Option<AlternativeNameOrderBy>,
         #[graphql(
             default = vec![],
-            description = "Specific types to filter by",
+            description = "Specific name types to filter by"
         )]
-        contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<Vec<Contribution>> {
-        Contribution::all(
+        name_types: Option<Vec<NameType>>,
+    ) -> FieldResult<Vec<AlternativeName>> {
+        AlternativeName::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
             None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
+            Some(self.contributor_id),
             None,
-            contribution_types.unwrap_or_default(),
+            name_types.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    #[graphql(description = "Get languages linked to this work")]
-    pub fn languages(
+    #[graphql(description = "Get contributions linked to this contributor")]
+    pub fn contributions(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = LanguageOrderBy::default(),
+            default = ContributionOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<LanguageOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific languages to filter by"
-        )]
-        language_codes: Option<Vec<LanguageCode>>,
-        #[graphql(
-            description = "(deprecated) A specific relation to filter by"
-        )]
-        language_relation: Option<LanguageRelation>,
+        order: Option<ContributionOrderBy>,
         #[graphql(
             default = vec![],
-            description = "Specific relations to filter by"
+            description = "Specific types to filter by",
         )]
-        language_relations: Option<Vec<LanguageRelation>>,
-    ) -> FieldResult<Vec<Language>> {
-        let mut relations = language_relations.unwrap_or_default();
-        if let Some(relation) = language_relation {
-            relations.push(relation);
-        }
-        Language::all(
+        contribution_types: Option<Vec<ContributionType>>,
+    ) -> FieldResult<Vec<Contribution>> {
+        Contribution::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
             None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
             None,
-            language_codes.unwrap_or_default(),
-            relations,
+            Some(self.contributor_id),
+            contribution_types.unwrap_or_default(),
+            vec![],
             None,
         )
         .map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get publications linked to this work")]
-    pub fn publications(
+    #[graphql(description = "Get series contributions linked to this contributor")]
+    pub fn series_contributions(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on isbn"
-        )]
-        filter: Option<String>,
-        #[graphql(
-            default = PublicationOrderBy::default(),
+            default = SeriesContributionOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<PublicationOrderBy>,
+        order: Option<SeriesContributionOrderBy>,
         #[graphql(
             default = vec![],
             description = "Specific types to filter by",
         )]
-        publication_types: Option<Vec<PublicationType>>,
-    ) -> FieldResult<Vec<Publication>> {
-        Publication::all(
+        contribution_types: Option<Vec<SeriesContributionType>>,
+    ) -> FieldResult<Vec<SeriesContribution>> {
+        SeriesContribution::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            filter,
+            None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
             None,
-            publication_types.unwrap_or_default(),
+            Some(self.contributor_id),
+            contribution_types.unwrap_or_default(),
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Get subjects linked to this work")]
-    pub fn subjects(
+#[juniper::graphql_object(Context = Context, description = "An alternative form of a contributor's name, such as a pseudonym or a transliteration.")]
+impl AlternativeName {
+    #[graphql(description = "Thoth ID of the alternative name")]
+    pub fn alternative_name_id(&self) -> Uuid {
+        self.alternative_name_id
+    }
+
+    #[graphql(description = "Thoth ID of the contributor to whom the alternative name belongs")]
+    pub fn contributor_id(&self) -> Uuid {
+        self.contributor_id
+    }
+
+    #[graphql(description = "Relationship of the alternative name to the contributor's main name")]
+    pub fn name_type(&self) -> &NameType {
+        &self.name_type
+    }
+
+    #[graphql(description = "Given or first name(s) in this form of the name")]
+    pub fn first_name(&self) -> Option<&String> {
+        self.first_name.as_ref()
+    }
+
+    #[graphql(description = "Family or surname in this form of the name")]
+    pub fn last_name(&self) -> &String {
+        &self.last_name
+    }
+
+    #[graphql(description = "Full, serialized form of the name")]
+    pub fn full_name(&self) -> &String {
+        &self.full_name
+    }
+
+    #[graphql(description = "Language in which this form of the name is used")]
+    pub fn language_code(&self) -> Option<&LanguageCode> {
+        self.language_code.as_ref()
+    }
+
+    #[graphql(
+        description = "ISO 15924 code of the script in which this form of the name is written (e.g. Cyrl)"
+    )]
+    pub fn script_code(&self) -> Option<&String> {
+        self.script_code.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the alternative name record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the alternative name record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the contributor to whom the alternative name belongs")]
+    pub fn contributor(&self, context: &Context) -> FieldResult<Contributor> {
+        Contributor::from_id(&context.db, &self.contributor_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A person's involvement in the production of a written text.")]
+impl Contribution {
+    #[graphql(description = "Thoth ID of the contribution")]
+    pub fn contribution_id(&self) -> Uuid {
+        self.contribution_id
+    }
+
+    #[graphql(description = "Thoth ID of the contributor who created the contribution")]
+    pub fn contributor_id(&self) -> Uuid {
+        self.contributor_id
+    }
+
+    #[graphql(description = "Thoth ID of the work in which the contribution appears")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Nature of the contribution")]
+    pub fn contribution_type(&self) -> &ContributionType {
+        &self.contribution_type
+    }
+
+    #[graphql(
+        description = "Whether this is a main contribution to the work (e.g. contributor credited on title page)"
+    )]
+    pub fn main_contribution(&self) -> bool {
+        self.main_contribution
+    }
+
+    #[graphql(
+        description = "Biography of the contributor at the time of contribution. May contain the following markup: <p>, <br>, <em>, <strong>, <sup>, <sub>, <ul>, <ol> and <li>."
+    )]
+    pub fn biography(&self) -> Option<&String> {
+        self.biography.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the contribution record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the contribution record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(
+        description = "Given or first name(s) of the contributor, as credited in this contribution"
+    )]
+    pub fn first_name(&self) -> Option<&String> {
+        self.first_name.as_ref()
+    }
+
+    #[graphql(
+        description = "Family or surname of the contributor, as credited in this contribution"
+    )]
+    pub fn last_name(&self) -> &String {
+        &self.last_name
+    }
+
+    #[graphql(
+        description = "Full, serialized name of the contributor, as credited in this contribution"
+    )]
+    pub fn full_name(&self) -> &String {
+        &self.full_name
+    }
+
+    #[graphql(
+        description = "Number representing this contribution's position in an ordered list of contributions within the work"
+    )]
+    pub fn contribution_ordinal(&self) -> &i32 {
+        &self.contribution_ordinal
+    }
+
+    #[graphql(description = "Get the work in which the contribution appears")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the contributor who created the contribution")]
+    pub fn contributor(&self, context: &Context) -> FieldResult<Contributor> {
+        Contributor::from_id(&context.db, &self.contributor_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get affiliations linked to this contribution")]
+    pub fn affiliations(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on subject_code"
-        )]
-        filter: Option<String>,
-        #[graphql(
-            default = SubjectOrderBy::default(),
+            default = AffiliationOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<SubjectOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific types to filter by",
-        )]
-        subject_types: Option<Vec<SubjectType>>,
-    ) -> FieldResult<Vec<Subject>> {
-        Subject::all(
+        order: Option<AffiliationOrderBy>,
+    ) -> FieldResult<Vec<Affiliation>> {
+        Affiliation::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            filter,
+            None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
             None,
-            subject_types.unwrap_or_default(),
+            Some(self.contribution_id),
+            vec![],
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A periodical of publications about a particular subject.")]
+impl Series {
+    #[graphql(description = "Thoth ID of the series")]
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    #[graphql(description = "Type of the series")]
+    pub fn series_type(&self) -> &SeriesType {
+        &self.series_type
+    }
+
+    #[graphql(description = "Name of the series")]
+    pub fn series_name(&self) -> &String {
+        &self.series_name
+    }
+
+    #[graphql(
+        description = "Print ISSN (International Standard Serial Number) of the series. This represents the print media version."
+    )]
+    pub fn issn_print(&self) -> Option<&String> {
+        self.issn_print.as_ref()
+    }
+
+    #[graphql(
+        description = "Electronic ISSN (International Standard Serial Number) of the series. This represents the online version."
+    )]
+    pub fn issn_digital(&self) -> Option<&String> {
+        self.issn_digital.as_ref()
+    }
+
+    #[graphql(description = "URL of the series' landing page")]
+    pub fn series_url(&self) -> Option<&String> {
+        self.series_url.as_ref()
+    }
+
+    #[graphql(description = "Description of the series")]
+    pub fn series_description(&self) -> Option<&String> {
+        self.series_description.as_ref()
+    }
+
+    #[graphql(description = "URL of the series' call for proposals page")]
+    pub fn series_cfp_url(&self) -> Option<&String> {
+        self.series_cfp_url.as_ref()
+    }
 
-    #[graphql(description = "Get fundings linked to this work")]
-    pub fn fundings(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = FundingOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<FundingOrderBy>,
-    ) -> FieldResult<Vec<Funding>> {
-        Funding::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            Some(self.work_id),
-            None,
-            vec![],
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Thoth ID of the imprint to which this series belongs")]
+    pub fn imprint_id(&self) -> Uuid {
+        self.imprint_id
     }
 
-    #[graphql(description = "Get issues linked to this work")]
+    #[graphql(description = "Date and time at which the series record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the series record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the imprint linked to this series")]
+    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
+        Imprint::from_id(&context.db, &self.imprint_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get issues linked to this series")]
     pub fn issues(
         &self,
         context: &Context,
@@ -2839,73 +6217,83 @@ impl Work {
             None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
             None,
+            Some(self.series_id),
             vec![],
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
-    #[graphql(description = "Get other works related to this work")]
-    pub fn relations(
+
+    #[graphql(description = "Thoth ID of the series of which this series is a subseries")]
+    pub fn parent_series_id(&self) -> Option<Uuid> {
+        self.parent_series_id
+    }
+
+    #[graphql(description = "Get the series of which this series is a subseries")]
+    pub fn parent_series(&self, context: &Context) -> FieldResult<Option<Series>> {
+        self.parent_series_id
+            .map(|parent_series_id| Series::from_id(&context.db, &parent_series_id))
+            .transpose()
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get subseries of this series")]
+    pub fn subseries(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = WorkRelationOrderBy::default(),
+            default = SeriesOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<WorkRelationOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific types to filter by",
-        )]
-        relation_types: Option<Vec<RelationType>>,
-    ) -> FieldResult<Vec<WorkRelation>> {
-        WorkRelation::all(
+        order: Option<SeriesOrderBy>,
+    ) -> FieldResult<Vec<Series>> {
+        Series::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
             None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
+            Some(self.series_id),
             None,
-            relation_types.unwrap_or_default(),
+            vec![],
             vec![],
             None,
         )
         .map_err(|e| e.into())
     }
-    #[graphql(description = "Get references cited by this work")]
-    pub fn references(
+
+    #[graphql(description = "Get contributions to this series as a whole, such as its editors")]
+    pub fn contributions(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on doi, unstructured_citation, issn, isbn, journal_title, article_title, series_title, volume_title, author, standard_designator, standards_body_name, and standards_body_acronym"
+            default = SeriesContributionOrderBy::default(),
+            description = "The order in which to sort the results"
         )]
-        filter: Option<String>,
+        order: Option<SeriesContributionOrderBy>,
         #[graphql(
-            default = ReferenceOrderBy::default(),
-            description = "The order in which to sort the results"
+            default = vec![],
+            description = "Specific types to filter by",
         )]
-        order: Option<ReferenceOrderBy>,
-    ) -> FieldResult<Vec<Reference>> {
-        Reference::all(
+        contribution_types: Option<Vec<SeriesContributionType>>,
+    ) -> FieldResult<Vec<SeriesContribution>> {
+        SeriesContribution::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            filter,
+            None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.work_id),
+            Some(self.series_id),
             None,
-            vec![],
+            contribution_types.unwrap_or_default(),
             vec![],
             None,
         )
@@ -2913,252 +6301,470 @@ impl Work {
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A manifestation of a written text")]
-impl Publication {
-    #[graphql(description = "Thoth ID of the publication")]
-    pub fn publication_id(&self) -> Uuid {
-        self.publication_id
+#[juniper::graphql_object(Context = Context, description = "A person's involvement in the editing of a series as a whole.")]
+impl SeriesContribution {
+    #[graphql(description = "Thoth ID of the series contribution")]
+    pub fn series_contribution_id(&self) -> Uuid {
+        self.series_contribution_id
+    }
+
+    #[graphql(description = "Thoth ID of the series to which the contribution was made")]
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    #[graphql(description = "Thoth ID of the contributor who made the contribution")]
+    pub fn contributor_id(&self) -> Uuid {
+        self.contributor_id
+    }
+
+    #[graphql(description = "Nature of the contribution")]
+    pub fn contribution_type(&self) -> &SeriesContributionType {
+        &self.contribution_type
+    }
+
+    #[graphql(
+        description = "Given or first name(s) of the contributor, as credited in this contribution"
+    )]
+    pub fn first_name(&self) -> Option<&String> {
+        self.first_name.as_ref()
+    }
+
+    #[graphql(
+        description = "Family or surname of the contributor, as credited in this contribution"
+    )]
+    pub fn last_name(&self) -> &String {
+        &self.last_name
+    }
+
+    #[graphql(
+        description = "Full, serialized name of the contributor, as credited in this contribution"
+    )]
+    pub fn full_name(&self) -> &String {
+        &self.full_name
+    }
+
+    #[graphql(
+        description = "Number representing this contribution's position in an ordered list of contributions within the series"
+    )]
+    pub fn contribution_ordinal(&self) -> &i32 {
+        &self.contribution_ordinal
+    }
+
+    #[graphql(description = "Date and time at which the series contribution record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(
+        description = "Date and time at which the series contribution record was last updated"
+    )]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the series to which the contribution was made")]
+    pub fn series(&self, context: &Context) -> FieldResult<Series> {
+        Series::from_id(&context.db, &self.series_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the contributor who made the contribution")]
+    pub fn contributor(&self, context: &Context) -> FieldResult<Contributor> {
+        Contributor::from_id(&context.db, &self.contributor_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A work published as a number in a periodical.")]
+impl Issue {
+    #[graphql(description = "Thoth ID of the issue")]
+    pub fn issue_id(&self) -> Uuid {
+        self.issue_id
+    }
+
+    #[graphql(description = "Thoth ID of the work represented by the issue")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Thoth ID of the series to which the issue belongs")]
+    pub fn series_id(&self) -> Uuid {
+        self.series_id
+    }
+
+    #[graphql(
+        description = "Number representing this issue's position in an ordered list of issues within the series (does not have to correspond to published issue number)"
+    )]
+    pub fn issue_ordinal(&self) -> &i32 {
+        &self.issue_ordinal
+    }
+
+    #[graphql(description = "Volume of the series in which the issue was published, as printed")]
+    pub fn volume(&self) -> Option<&String> {
+        self.volume.as_ref()
+    }
+
+    #[graphql(description = "Number of the issue within its volume, as printed")]
+    pub fn issue_number(&self) -> Option<&String> {
+        self.issue_number.as_ref()
+    }
+
+    #[graphql(description = "Part of the issue within its volume or issue number, as printed")]
+    pub fn part(&self) -> Option<&String> {
+        self.part.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the issue record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the issue record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the series to which the issue belongs")]
+    pub fn series(&self, context: &Context) -> FieldResult<Series> {
+        Series::from_id(&context.db, &self.series_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the work represented by the issue")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "Description of a work's language.")]
+impl Language {
+    #[graphql(description = "Thoth ID of the language")]
+    pub fn language_id(&self) -> Uuid {
+        self.language_id
+    }
+
+    #[graphql(description = "Thoth ID of the work which has this language")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Three-letter ISO 639 code representing the language")]
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    #[graphql(description = "Relation between this language and the original language of the text")]
+    pub fn language_relation(&self) -> &LanguageRelation {
+        &self.language_relation
+    }
+
+    #[graphql(
+        description = "Whether this is a main language of the work (e.g. used for large sections of the text rather than just isolated quotations)"
+    )]
+    pub fn main_language(&self) -> bool {
+        self.main_language
+    }
+
+    #[graphql(description = "Date and time at which the language record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the language record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the work which has this language")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Format of this publication")]
-    pub fn publication_type(&self) -> &PublicationType {
-        &self.publication_type
+#[juniper::graphql_object(Context = Context, description = "Title of a work in a given language.")]
+impl WorkTitle {
+    #[graphql(description = "Thoth ID of the title")]
+    pub fn work_title_id(&self) -> Uuid {
+        self.work_title_id
     }
 
-    #[graphql(description = "Thoth ID of the work to which this publication belongs")]
+    #[graphql(description = "Thoth ID of the work which has this title")]
     pub fn work_id(&self) -> Uuid {
         self.work_id
     }
 
+    #[graphql(description = "Three-letter ISO 639 code representing the language of the title")]
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    #[graphql(description = "Concatenation of title and subtitle with punctuation mark")]
+    pub fn full_title(&self) -> &String {
+        &self.full_title
+    }
+
+    #[graphql(description = "Main title of the work (excluding subtitle)")]
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+
+    #[graphql(description = "Secondary title of the work (excluding main title)")]
+    pub fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+
     #[graphql(
-        description = "International Standard Book Number of the publication, in ISBN-13 format"
+        description = "Whether this is the work's main title, which is also returned by the work's own title fields"
     )]
-    pub fn isbn(&self) -> Option<&Isbn> {
-        self.isbn.as_ref()
+    pub fn canonical(&self) -> bool {
+        self.canonical
     }
 
-    #[graphql(description = "Date and time at which the publication record was created")]
+    #[graphql(description = "Date and time at which the title record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the publication record was last updated")]
+    #[graphql(description = "Date and time at which the title record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(
-        description = "Width of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
-    )]
-    pub fn width(
-        &self,
-        #[graphql(
-            default = LengthUnit::default(),
-            description = "Unit of measurement in which to represent the width (mm, cm or in)",
-        )]
-        units: LengthUnit,
-    ) -> Option<f64> {
-        match units {
-            LengthUnit::Mm => self.width_mm,
-            LengthUnit::Cm => self
-                .width_mm
-                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
-            LengthUnit::In => self.width_in,
-        }
+    #[graphql(description = "Get the work which has this title")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
+}
 
-    #[graphql(
-        description = "Height of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
-    )]
-    pub fn height(
-        &self,
-        #[graphql(
-            default = LengthUnit::default(),
-            description = "Unit of measurement in which to represent the height (mm, cm or in)",
-        )]
-        units: LengthUnit,
-    ) -> Option<f64> {
-        match units {
-            LengthUnit::Mm => self.height_mm,
-            LengthUnit::Cm => self
-                .height_mm
-                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
-            LengthUnit::In => self.height_in,
-        }
+#[juniper::graphql_object(Context = Context, description = "Abstract of a work in a given language.")]
+impl WorkAbstract {
+    #[graphql(description = "Thoth ID of the abstract")]
+    pub fn work_abstract_id(&self) -> Uuid {
+        self.work_abstract_id
+    }
+
+    #[graphql(description = "Thoth ID of the work which has this abstract")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Three-letter ISO 639 code representing the language of the abstract")]
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    #[graphql(description = "Length of the abstract")]
+    pub fn abstract_type(&self) -> &AbstractType {
+        &self.abstract_type
     }
 
     #[graphql(
-        description = "Depth of the physical Publication (in mm, cm or in) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+        description = "Text of the abstract. May contain the following markup: <p>, <br>, <em>, <strong>, <sup>, <sub>, <ul>, <ol> and <li>."
     )]
-    pub fn depth(
-        &self,
-        #[graphql(
-            default = LengthUnit::default(),
-            description = "Unit of measurement in which to represent the depth (mm, cm or in)",
-        )]
-        units: LengthUnit,
-    ) -> Option<f64> {
-        match units {
-            LengthUnit::Mm => self.depth_mm,
-            LengthUnit::Cm => self
-                .depth_mm
-                .map(|w| w.convert_length_from_to(&LengthUnit::Mm, &LengthUnit::Cm)),
-            LengthUnit::In => self.depth_in,
-        }
+    pub fn content(&self) -> &String {
+        &self.content
     }
 
     #[graphql(
-        description = "Weight of the physical Publication (in g or oz) (only applicable to non-Chapter Paperbacks and Hardbacks)"
+        description = "Whether this is the work's main abstract of its type, which is also returned by the work's own abstract fields"
     )]
-    pub fn weight(
-        &self,
-        #[graphql(
-            default = WeightUnit::default(),
-            description = "Unit of measurement in which to represent the weight (grams or ounces)",
-        )]
-        units: WeightUnit,
-    ) -> Option<f64> {
-        match units {
-            WeightUnit::G => self.weight_g,
-            WeightUnit::Oz => self.weight_oz,
-        }
+    pub fn canonical(&self) -> bool {
+        self.canonical
     }
 
-    #[graphql(description = "Get prices linked to this publication")]
-    pub fn prices(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = PriceOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<PriceOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific currencies to filter by"
-        )]
-        currency_codes: Option<Vec<CurrencyCode>>,
-    ) -> FieldResult<Vec<Price>> {
-        Price::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            Some(self.publication_id),
-            None,
-            currency_codes.unwrap_or_default(),
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Date and time at which the abstract record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
     }
 
-    #[graphql(description = "Get locations linked to this publication")]
-    pub fn locations(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = LocationOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<LocationOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific platforms to filter by"
-        )]
-        location_platforms: Option<Vec<LocationPlatform>>,
-    ) -> FieldResult<Vec<Location>> {
-        Location::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            Some(self.publication_id),
-            None,
-            location_platforms.unwrap_or_default(),
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Date and time at which the abstract record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
     }
 
-    #[graphql(description = "Get the work to which this publication belongs")]
+    #[graphql(description = "Get the work which has this abstract")]
     pub fn work(&self, context: &Context) -> FieldResult<Work> {
         Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "An organisation that produces and distributes written texts.")]
-impl Publisher {
-    #[graphql(description = "Thoth ID of the publisher")]
-    pub fn publisher_id(&self) -> Uuid {
-        self.publisher_id
+#[juniper::graphql_object(Context = Context, description = "A persistent identifier of a work.")]
+impl WorkIdentifier {
+    #[graphql(description = "Thoth ID of the work identifier")]
+    pub fn work_identifier_id(&self) -> Uuid {
+        self.work_identifier_id
     }
 
-    #[graphql(description = "Name of the publisher")]
-    pub fn publisher_name(&self) -> &String {
-        &self.publisher_name
+    #[graphql(description = "Thoth ID of the work which has this identifier")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
     }
 
-    #[graphql(description = "Short name of the publisher, if any (e.g. an abbreviation)")]
-    pub fn publisher_shortname(&self) -> Option<&String> {
-        self.publisher_shortname.as_ref()
+    #[graphql(description = "Type of the identifier")]
+    pub fn identifier_type(&self) -> &IdentifierType {
+        &self.identifier_type
     }
 
-    #[graphql(description = "URL of the publisher's website")]
-    pub fn publisher_url(&self) -> Option<&String> {
-        self.publisher_url.as_ref()
+    #[graphql(description = "Value of the identifier, in the standard form of its type")]
+    pub fn identifier_value(&self) -> &String {
+        &self.identifier_value
     }
 
-    #[graphql(description = "Date and time at which the publisher record was created")]
+    #[graphql(description = "Date and time at which the work identifier record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the work identifier record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the work which has this identifier")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A prize or award won, or shortlisted for, by a work.")]
+impl Prize {
+    #[graphql(description = "Thoth ID of the prize")]
+    pub fn prize_id(&self) -> Uuid {
+        self.prize_id
+    }
+
+    #[graphql(description = "Thoth ID of the work which achieved this prize")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Name of the prize or award")]
+    pub fn prize_name(&self) -> &String {
+        &self.prize_name
+    }
+
+    #[graphql(description = "Year in which the prize or award was given")]
+    pub fn prize_year(&self) -> Option<i32> {
+        self.prize_year
+    }
+
+    #[graphql(description = "Country in which the prize or award is given")]
+    pub fn prize_country(&self) -> Option<&CountryCode> {
+        self.prize_country.as_ref()
+    }
+
+    #[graphql(description = "Achievement of the work in relation to the prize or award")]
+    pub fn prize_code(&self) -> &PrizeCode {
+        &self.prize_code
+    }
+
+    #[graphql(description = "Statement from the jury or judging panel about the work")]
+    pub fn prize_statement(&self) -> Option<&String> {
+        self.prize_statement.as_ref()
+    }
+
+    #[graphql(description = "Members of the jury or judging panel that awarded the prize")]
+    pub fn prize_jury(&self) -> Option<&String> {
+        self.prize_jury.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the prize record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the prize record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the work which achieved this prize")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A conference or other event whose proceedings are published in a work.")]
+impl Event {
+    #[graphql(description = "Thoth ID of the event")]
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
+    }
+
+    #[graphql(description = "Thoth ID of the work which publishes the proceedings of this event")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Name of the event")]
+    pub fn event_name(&self) -> &String {
+        &self.event_name
+    }
+
+    #[graphql(description = "Acronym of the event, e.g. ICML")]
+    pub fn event_acronym(&self) -> Option<&String> {
+        self.event_acronym.as_ref()
+    }
+
+    #[graphql(description = "Number of the event within a series of events, e.g. 12")]
+    pub fn event_number(&self) -> Option<&String> {
+        self.event_number.as_ref()
+    }
+
+    #[graphql(description = "Place at which the event was held, e.g. a city or venue")]
+    pub fn event_location(&self) -> Option<&String> {
+        self.event_location.as_ref()
+    }
+
+    #[graphql(description = "Country in which the event was held")]
+    pub fn event_country(&self) -> Option<&CountryCode> {
+        self.event_country.as_ref()
+    }
+
+    #[graphql(description = "Date on which the event started")]
+    pub fn event_start_date(&self) -> Option<NaiveDate> {
+        self.event_start_date
+    }
+
+    #[graphql(description = "Date on which the event ended, if held over more than one day")]
+    pub fn event_end_date(&self) -> Option<NaiveDate> {
+        self.event_end_date
+    }
+
+    #[graphql(description = "Date and time at which the event record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the publisher record was last updated")]
+    #[graphql(description = "Date and time at which the event record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get imprints linked to this publisher")]
-    pub fn imprints(
+    #[graphql(description = "Get the work which publishes the proceedings of this event")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get institutions sponsoring this event")]
+    pub fn sponsors(
         &self,
         context: &Context,
         #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
         #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
         #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on imprint_name and imprint_url"
-        )]
-        filter: Option<String>,
-        #[graphql(
-           default = {
-                ImprintOrderBy {
-                    field: ImprintField::ImprintName,
-                    direction: Direction::Asc,
-                }
-            },
+            default = EventSponsorOrderBy::default(),
             description = "The order in which to sort the results"
         )]
-        order: Option<ImprintOrderBy>,
-    ) -> FieldResult<Vec<Imprint>> {
-        Imprint::all(
+        order: Option<EventSponsorOrderBy>,
+    ) -> FieldResult<Vec<EventSponsor>> {
+        EventSponsor::all(
             &context.db,
             limit.unwrap_or_default(),
             offset.unwrap_or_default(),
-            filter,
+            None,
             order.unwrap_or_default(),
             vec![],
-            Some(self.publisher_id),
             None,
+            Some(self.event_id),
             vec![],
             vec![],
             None,
@@ -3167,480 +6773,457 @@ impl Publisher {
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "The brand under which a publisher issues works.")]
-impl Imprint {
-    #[graphql(description = "Thoth ID of the imprint")]
-    pub fn imprint_id(&self) -> Uuid {
-        self.imprint_id
-    }
-
-    #[graphql(description = "Thoth ID of the publisher to which this imprint belongs")]
-    pub fn publisher_id(&self) -> Uuid {
-        self.publisher_id
+#[juniper::graphql_object(Context = Context, description = "The sponsorship of an event by an institution.")]
+impl EventSponsor {
+    #[graphql(description = "Thoth ID of the event sponsor")]
+    pub fn event_sponsor_id(&self) -> Uuid {
+        self.event_sponsor_id
     }
 
-    #[graphql(description = "Name of the imprint")]
-    pub fn imprint_name(&self) -> &String {
-        &self.imprint_name
+    #[graphql(description = "Thoth ID of the sponsored event")]
+    pub fn event_id(&self) -> Uuid {
+        self.event_id
     }
 
-    #[graphql(description = "URL of the imprint's landing page")]
-    pub fn imprint_url(&self) -> Option<&String> {
-        self.imprint_url.as_ref()
+    #[graphql(description = "Thoth ID of the sponsoring institution")]
+    pub fn institution_id(&self) -> Uuid {
+        self.institution_id
     }
 
     #[graphql(
-        description = "DOI of the imprint's Crossmark policy page, if publisher participates. Crossmark 'gives readers quick and easy access to the
-    current status of an item of content, including any corrections, retractions, or updates'. More: https://www.crossref.org/services/crossmark/"
+        description = "Number representing this sponsor's position in an ordered list of sponsors of the event"
     )]
-    pub fn crossmark_doi(&self) -> Option<&Doi> {
-        self.crossmark_doi.as_ref()
+    pub fn sponsor_ordinal(&self) -> &i32 {
+        &self.sponsor_ordinal
     }
 
-    #[graphql(description = "Date and time at which the imprint record was created")]
+    #[graphql(description = "Date and time at which the event sponsor record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the imprint record was last updated")]
+    #[graphql(description = "Date and time at which the event sponsor record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the publisher to which this imprint belongs")]
-    pub fn publisher(&self, context: &Context) -> FieldResult<Publisher> {
-        Publisher::from_id(&context.db, &self.publisher_id).map_err(|e| e.into())
+    #[graphql(description = "Get the sponsored event")]
+    pub fn event(&self, context: &Context) -> FieldResult<Event> {
+        Event::from_id(&context.db, &self.event_id).map_err(|e| e.into())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    #[graphql(description = "Get works linked to this imprint")]
-    pub fn works(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = "".to_string(),
-            description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title, doi, reference, short_abstract, long_abstract, and landing_page"
-        )]
-        filter: Option<String>,
-        #[graphql(
-            default = WorkOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<WorkOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific types to filter by",
-        )]
-        work_types: Option<Vec<WorkType>>,
-        #[graphql(description = "(deprecated) A specific status to filter by")] work_status: Option<
-            WorkStatus,
-        >,
-        #[graphql(
-            default = vec![],
-            description = "Specific statuses to filter by"
-        )]
-        work_statuses: Option<Vec<WorkStatus>>,
-        #[graphql(
-            description = "Only show results updated either before (less than) or after (greater than) the specified timestamp"
-        )]
-        updated_at_with_relations: Option<TimeExpression>,
-    ) -> FieldResult<Vec<Work>> {
-        let mut statuses = work_statuses.unwrap_or_default();
-        if let Some(status) = work_status {
-            statuses.push(status);
-        }
-        Work::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            filter,
-            order.unwrap_or_default(),
-            vec![],
-            Some(self.imprint_id),
-            None,
-            work_types.unwrap_or_default(),
-            statuses,
-            updated_at_with_relations,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Get the sponsoring institution")]
+    pub fn institution(&self, context: &Context) -> FieldResult<Institution> {
+        Institution::from_id(&context.db, &self.institution_id).map_err(|e| e.into())
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A person who has been involved in the production of a written text.")]
-impl Contributor {
-    #[graphql(description = "Thoth ID of the contributor")]
-    pub fn contributor_id(&self) -> Uuid {
-        self.contributor_id
+#[juniper::graphql_object(Context = Context, description = "A review quote, endorsement or other promotional or critical text about a work.")]
+impl TextContent {
+    #[graphql(description = "Thoth ID of the text content")]
+    pub fn text_content_id(&self) -> Uuid {
+        self.text_content_id
     }
 
-    #[graphql(description = "Given or first name(s) of the contributor")]
-    pub fn first_name(&self) -> Option<&String> {
-        self.first_name.as_ref()
+    #[graphql(description = "Thoth ID of the work which this text is about")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
     }
 
-    #[graphql(description = "Family or surname of the contributor")]
-    pub fn last_name(&self) -> &String {
-        &self.last_name
+    #[graphql(description = "Type of the text")]
+    pub fn text_type(&self) -> &TextContentType {
+        &self.text_type
     }
 
-    #[graphql(
-        description = "Full, serialized name of the contributor. Serialization is often culturally determined."
-    )]
-    pub fn full_name(&self) -> &String {
-        &self.full_name
+    #[graphql(description = "Audience for which the text is intended")]
+    pub fn content_audience(&self) -> &ContentAudience {
+        &self.content_audience
+    }
+
+    #[graphql(description = "The text itself, which may contain a restricted set of HTML markup")]
+    pub fn content(&self) -> &String {
+        &self.content
+    }
+
+    #[graphql(description = "Name of the author of the text, such as a reviewer or endorser")]
+    pub fn text_author(&self) -> Option<&String> {
+        self.text_author.as_ref()
+    }
+
+    #[graphql(description = "Title of the publication or other source in which the text appeared")]
+    pub fn source_title(&self) -> Option<&String> {
+        self.source_title.as_ref()
     }
 
     #[graphql(
-        description = "ORCID (Open Researcher and Contributor ID) of the contributor as full URL, using the HTTPS scheme and the orcid.org domain (e.g. https://orcid.org/0000-0002-1825-0097)"
+        description = "URL of the source in which the text appeared, such as an online review"
     )]
-    pub fn orcid(&self) -> Option<&Orcid> {
-        self.orcid.as_ref()
+    pub fn source_url(&self) -> Option<&String> {
+        self.source_url.as_ref()
     }
 
-    #[graphql(description = "URL of the contributor's website")]
-    pub fn website(&self) -> Option<&String> {
-        self.website.as_ref()
+    #[graphql(description = "Date on which the text was published in its source")]
+    pub fn content_date(&self) -> Option<NaiveDate> {
+        self.content_date
     }
 
-    #[graphql(description = "Date and time at which the contributor record was created")]
+    #[graphql(description = "Date and time at which the text content record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the contributor record was last updated")]
+    #[graphql(description = "Date and time at which the text content record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get contributions linked to this contributor")]
-    pub fn contributions(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = ContributionOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<ContributionOrderBy>,
-        #[graphql(
-            default = vec![],
-            description = "Specific types to filter by",
-        )]
-        contribution_types: Option<Vec<ContributionType>>,
-    ) -> FieldResult<Vec<Contribution>> {
-        Contribution::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            None,
-            Some(self.contributor_id),
-            contribution_types.unwrap_or_default(),
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Get the work which this text is about")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A person's involvement in the production of a written text.")]
-impl Contribution {
-    #[graphql(description = "Thoth ID of the contribution")]
-    pub fn contribution_id(&self) -> Uuid {
-        self.contribution_id
-    }
-
-    #[graphql(description = "Thoth ID of the contributor who created the contribution")]
-    pub fn contributor_id(&self) -> Uuid {
-        self.contributor_id
+#[juniper::graphql_object(Context = Context, description = "A resource supporting the promotion of a work, such as a cover image, sample chapter or author photo.")]
+impl SupportingResource {
+    #[graphql(description = "Thoth ID of the supporting resource")]
+    pub fn supporting_resource_id(&self) -> Uuid {
+        self.supporting_resource_id
     }
 
-    #[graphql(description = "Thoth ID of the work in which the contribution appears")]
+    #[graphql(description = "Thoth ID of the work which this resource supports")]
     pub fn work_id(&self) -> Uuid {
         self.work_id
     }
 
-    #[graphql(description = "Nature of the contribution")]
-    pub fn contribution_type(&self) -> &ContributionType {
-        &self.contribution_type
+    #[graphql(description = "Type of content of the resource")]
+    pub fn resource_content_type(&self) -> &ResourceContentType {
+        &self.resource_content_type
     }
 
-    #[graphql(
-        description = "Whether this is a main contribution to the work (e.g. contributor credited on title page)"
-    )]
-    pub fn main_contribution(&self) -> bool {
-        self.main_contribution
+    #[graphql(description = "Audience for which the resource is intended")]
+    pub fn content_audience(&self) -> &ContentAudience {
+        &self.content_audience
     }
 
-    #[graphql(description = "Biography of the contributor at the time of contribution")]
-    pub fn biography(&self) -> Option<&String> {
-        self.biography.as_ref()
+    #[graphql(description = "Predominant mode of the resource")]
+    pub fn resource_mode(&self) -> &ResourceMode {
+        &self.resource_mode
     }
 
-    #[graphql(description = "Date and time at which the contribution record was created")]
-    pub fn created_at(&self) -> Timestamp {
-        self.created_at
+    #[graphql(description = "Form in which the resource is made available")]
+    pub fn resource_form(&self) -> &ResourceForm {
+        &self.resource_form
     }
 
-    #[graphql(description = "Date and time at which the contribution record was last updated")]
-    pub fn updated_at(&self) -> Timestamp {
-        self.updated_at
+    #[graphql(description = "URL from which the resource can be accessed or downloaded")]
+    pub fn resource_url(&self) -> &String {
+        &self.resource_url
     }
 
-    #[graphql(
-        description = "Given or first name(s) of the contributor, as credited in this contribution"
-    )]
-    pub fn first_name(&self) -> Option<&String> {
-        self.first_name.as_ref()
+    #[graphql(description = "Caption to display alongside the resource")]
+    pub fn caption(&self) -> Option<&String> {
+        self.caption.as_ref()
+    }
+
+    #[graphql(description = "Credit which must be displayed alongside the resource")]
+    pub fn credit(&self) -> Option<&String> {
+        self.credit.as_ref()
+    }
+
+    #[graphql(description = "Width of the resource in pixels, if it is an image or video")]
+    pub fn width_px(&self) -> Option<i32> {
+        self.width_px
+    }
+
+    #[graphql(description = "Height of the resource in pixels, if it is an image or video")]
+    pub fn height_px(&self) -> Option<i32> {
+        self.height_px
+    }
+
+    #[graphql(description = "Date and time at which the supporting resource record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
     }
 
     #[graphql(
-        description = "Family or surname of the contributor, as credited in this contribution"
+        description = "Date and time at which the supporting resource record was last updated"
     )]
-    pub fn last_name(&self) -> &String {
-        &self.last_name
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
     }
 
     #[graphql(
-        description = "Full, serialized name of the contributor, as credited in this contribution"
+        description = "Size of the rendition, if the resource was generated from an uploaded cover"
     )]
-    pub fn full_name(&self) -> &String {
-        &self.full_name
+    pub fn cover_rendition(&self) -> Option<&CoverRendition> {
+        self.cover_rendition.as_ref()
     }
 
     #[graphql(
-        description = "Number representing this contribution's position in an ordered list of contributions within the work"
+        description = "Thoth ID of the uploaded cover from which the resource was generated, if any"
     )]
-    pub fn contribution_ordinal(&self) -> &i32 {
-        &self.contribution_ordinal
+    pub fn asset_id(&self) -> Option<Uuid> {
+        self.asset_id
     }
 
-    #[graphql(description = "Get the work in which the contribution appears")]
+    #[graphql(description = "Get the work which this resource supports")]
     pub fn work(&self, context: &Context) -> FieldResult<Work> {
         Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get the contributor who created the contribution")]
-    pub fn contributor(&self, context: &Context) -> FieldResult<Contributor> {
-        Contributor::from_id(&context.db, &self.contributor_id).map_err(|e| e.into())
-    }
-
-    #[graphql(description = "Get affiliations linked to this contribution")]
-    pub fn affiliations(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = AffiliationOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<AffiliationOrderBy>,
-    ) -> FieldResult<Vec<Affiliation>> {
-        Affiliation::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            None,
-            Some(self.contribution_id),
-            vec![],
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Get the uploaded cover from which the resource was generated")]
+    pub fn asset(&self, context: &Context) -> FieldResult<Option<Asset>> {
+        match self.asset_id {
+            Some(asset_id) => Asset::from_id(&context.db, &asset_id)
+                .map(Some)
+                .map_err(|e| e.into()),
+            None => Ok(None),
+        }
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A periodical of publications about a particular subject.")]
-impl Series {
-    #[graphql(description = "Thoth ID of the series")]
-    pub fn series_id(&self) -> Uuid {
-        self.series_id
+#[juniper::graphql_object(Context = Context, description = "A file uploaded to Thoth's asset storage, such as a cover image or the full text of a publication.")]
+impl Asset {
+    #[graphql(description = "Thoth ID of the asset")]
+    pub fn asset_id(&self) -> Uuid {
+        self.asset_id
     }
 
-    #[graphql(description = "Type of the series")]
-    pub fn series_type(&self) -> &SeriesType {
-        &self.series_type
+    #[graphql(description = "Thoth ID of the work to which the asset belongs")]
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
     }
 
-    #[graphql(description = "Name of the series")]
-    pub fn series_name(&self) -> &String {
-        &self.series_name
+    #[graphql(description = "Thoth ID of the publication whose file this is, if any")]
+    pub fn publication_id(&self) -> Option<Uuid> {
+        self.publication_id
     }
 
     #[graphql(
-        description = "Print ISSN (International Standard Serial Number) of the series. This represents the print media version."
+        description = "Thoth ID of the location from which the file is made available, if any"
     )]
-    pub fn issn_print(&self) -> Option<&String> {
-        self.issn_print.as_ref()
+    pub fn location_id(&self) -> Option<Uuid> {
+        self.location_id
     }
 
-    #[graphql(
-        description = "Electronic ISSN (International Standard Serial Number) of the series. This represents the online version."
-    )]
-    pub fn issn_digital(&self) -> Option<&String> {
-        self.issn_digital.as_ref()
+    #[graphql(description = "Purpose of the file")]
+    pub fn asset_type(&self) -> &AssetType {
+        &self.asset_type
     }
 
-    #[graphql(description = "URL of the series' landing page")]
-    pub fn series_url(&self) -> Option<&String> {
-        self.series_url.as_ref()
+    #[graphql(description = "Public URL from which the file can be retrieved")]
+    pub fn asset_url(&self) -> &String {
+        &self.asset_url
     }
 
-    #[graphql(description = "Description of the series")]
-    pub fn series_description(&self) -> Option<&String> {
-        self.series_description.as_ref()
+    #[graphql(description = "Name of the file as uploaded")]
+    pub fn filename(&self) -> &String {
+        &self.filename
     }
 
-    #[graphql(description = "URL of the series' call for proposals page")]
-    pub fn series_cfp_url(&self) -> Option<&String> {
-        self.series_cfp_url.as_ref()
+    #[graphql(description = "MIME type of the file")]
+    pub fn mime_type(&self) -> &String {
+        &self.mime_type
     }
 
-    #[graphql(description = "Thoth ID of the imprint to which this series belongs")]
-    pub fn imprint_id(&self) -> Uuid {
-        self.imprint_id
+    #[graphql(description = "Size of the file in bytes")]
+    pub fn file_size(&self) -> i32 {
+        self.file_size
     }
 
-    #[graphql(description = "Date and time at which the series record was created")]
+    #[graphql(description = "Hex-encoded SHA-256 checksum of the file contents")]
+    pub fn checksum(&self) -> &String {
+        &self.checksum
+    }
+
+    #[graphql(description = "Date and time at which the file was uploaded")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the series record was last updated")]
+    #[graphql(description = "Date and time at which the asset record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the imprint linked to this series")]
-    pub fn imprint(&self, context: &Context) -> FieldResult<Imprint> {
-        Imprint::from_id(&context.db, &self.imprint_id).map_err(|e| e.into())
+    #[graphql(description = "Get the work to which the asset belongs")]
+    pub fn work(&self, context: &Context) -> FieldResult<Work> {
+        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
     }
 
-    #[graphql(description = "Get issues linked to this series")]
-    pub fn issues(
-        &self,
-        context: &Context,
-        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
-        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
-        #[graphql(
-            default = IssueOrderBy::default(),
-            description = "The order in which to sort the results"
-        )]
-        order: Option<IssueOrderBy>,
-    ) -> FieldResult<Vec<Issue>> {
-        Issue::all(
-            &context.db,
-            limit.unwrap_or_default(),
-            offset.unwrap_or_default(),
-            None,
-            order.unwrap_or_default(),
-            vec![],
-            None,
-            Some(self.series_id),
-            vec![],
-            vec![],
-            None,
-        )
-        .map_err(|e| e.into())
+    #[graphql(description = "Get the publication whose file this is, if any")]
+    pub fn publication(&self, context: &Context) -> FieldResult<Option<Publication>> {
+        self.publication_id
+            .map(|publication_id| Publication::from_id(&context.db, &publication_id))
+            .transpose()
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Get the location from which the file is made available, if any")]
+    pub fn location(&self, context: &Context) -> FieldResult<Option<Location>> {
+        self.location_id
+            .map(|location_id| Location::from_id(&context.db, &location_id))
+            .transpose()
+            .map_err(|e| e.into())
     }
 }
 
-#[juniper::graphql_object(Context = Context, description = "A work published as a number in a periodical.")]
-impl Issue {
-    #[graphql(description = "Thoth ID of the issue")]
-    pub fn issue_id(&self) -> Uuid {
-        self.issue_id
+#[juniper::graphql_object(Context = Context, description = "A persistent identifier of a publication.")]
+impl PublicationIdentifier {
+    #[graphql(description = "Thoth ID of the publication identifier")]
+    pub fn publication_identifier_id(&self) -> Uuid {
+        self.publication_identifier_id
     }
 
-    #[graphql(description = "Thoth ID of the work represented by the issue")]
-    pub fn work_id(&self) -> Uuid {
-        self.work_id
+    #[graphql(description = "Thoth ID of the publication which has this identifier")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
     }
 
-    #[graphql(description = "Thoth ID of the series to which the issue belongs")]
-    pub fn series_id(&self) -> Uuid {
-        self.series_id
+    #[graphql(description = "Type of the identifier")]
+    pub fn identifier_type(&self) -> &IdentifierType {
+        &self.identifier_type
     }
 
-    #[graphql(
-        description = "Number representing this issue's position in an ordered list of issues within the series (does not have to correspond to published issue number)"
-    )]
-    pub fn issue_ordinal(&self) -> &i32 {
-        &self.issue_ordinal
+    #[graphql(description = "Value of the identifier, in the standard form of its type")]
+    pub fn identifier_value(&self) -> &String {
+        &self.identifier_value
     }
 
-    #[graphql(description = "Date and time at which the issue record was created")]
+    #[graphql(description = "Date and time at which the publication identifier record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the issue record was last updated")]
+    #[graphql(
+        description = "Date and time at which the publication identifier record was last updated"
+    )]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the series to which the issue belongs")]
-    pub fn series(&self, context: &Context) -> FieldResult<Series> {
-        Series::from_id(&context.db, &self.series_id).map_err(|e| e.into())
+    #[graphql(description = "Get the publication which has this identifier")]
+    pub fn publication(&self, context: &Context) -> FieldResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id).map_err(|e| e.into())
     }
+}
 
-    #[graphql(description = "Get the work represented by the issue")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+#[juniper::graphql_object(Context = Context, description = "Rights to sell a publication in a territory, made up of included countries and regions less any excluded ones.")]
+impl SalesRights {
+    #[graphql(description = "Thoth ID of the sales rights")]
+    pub fn sales_rights_id(&self) -> Uuid {
+        self.sales_rights_id
     }
-}
 
-#[juniper::graphql_object(Context = Context, description = "Description of a work's language.")]
-impl Language {
-    #[graphql(description = "Thoth ID of the language")]
-    pub fn language_id(&self) -> Uuid {
-        self.language_id
+    #[graphql(description = "Thoth ID of the publication to which the sales rights apply")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
     }
 
-    #[graphql(description = "Thoth ID of the work which has this language")]
-    pub fn work_id(&self) -> Uuid {
-        self.work_id
+    #[graphql(description = "Type of rights held in the territory")]
+    pub fn sales_rights_type(&self) -> &SalesRightsType {
+        &self.sales_rights_type
     }
 
-    #[graphql(description = "Three-letter ISO 639 code representing the language")]
-    pub fn language_code(&self) -> &LanguageCode {
-        &self.language_code
+    #[graphql(description = "Countries included in the territory")]
+    pub fn countries_included(&self) -> &Vec<CountryCode> {
+        &self.countries_included
     }
 
-    #[graphql(description = "Relation between this language and the original language of the text")]
-    pub fn language_relation(&self) -> &LanguageRelation {
-        &self.language_relation
+    #[graphql(description = "Countries excluded from the included regions")]
+    pub fn countries_excluded(&self) -> &Vec<CountryCode> {
+        &self.countries_excluded
     }
 
     #[graphql(
-        description = "Whether this is a main language of the work (e.g. used for large sections of the text rather than just isolated quotations)"
+        description = "ONIX region codes included in the territory: WORLD, ECZ (the Eurozone) or an ISO 3166-2 subdivision such as GB-SCT"
     )]
-    pub fn main_language(&self) -> bool {
-        self.main_language
+    pub fn regions_included(&self) -> &Vec<String> {
+        &self.regions_included
     }
 
-    #[graphql(description = "Date and time at which the language record was created")]
+    #[graphql(description = "ISO 3166-2 subdivisions excluded from the included regions")]
+    pub fn regions_excluded(&self) -> &Vec<String> {
+        &self.regions_excluded
+    }
+
+    #[graphql(
+        description = "Restriction on the outlets through which the publication may be sold in the territory"
+    )]
+    pub fn sales_restriction_type(&self) -> Option<&SalesRestrictionType> {
+        self.sales_restriction_type.as_ref()
+    }
+
+    #[graphql(
+        description = "Description of the sales restriction, such as the name of the exclusive retailer"
+    )]
+    pub fn sales_restriction_note(&self) -> Option<&String> {
+        self.sales_restriction_note.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the sales rights record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
-    #[graphql(description = "Date and time at which the language record was last updated")]
+    #[graphql(description = "Date and time at which the sales rights record was last updated")]
     pub fn updated_at(&self) -> Timestamp {
         self.updated_at
     }
 
-    #[graphql(description = "Get the work which has this language")]
-    pub fn work(&self, context: &Context) -> FieldResult<Work> {
-        Work::from_id(&context.db, &self.work_id).map_err(|e| e.into())
+    #[graphql(description = "Get the publication to which the sales rights apply")]
+    pub fn publication(&self, context: &Context) -> FieldResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id).map_err(|e| e.into())
+    }
+}
+
+#[juniper::graphql_object(Context = Context, description = "A constraint on how a digital publication may be used, such as a limit on printing or copying.")]
+impl UsageConstraint {
+    #[graphql(description = "Thoth ID of the usage constraint")]
+    pub fn usage_constraint_id(&self) -> Uuid {
+        self.usage_constraint_id
+    }
+
+    #[graphql(description = "Thoth ID of the publication to which the usage constraint applies")]
+    pub fn publication_id(&self) -> Uuid {
+        self.publication_id
+    }
+
+    #[graphql(description = "Type of usage being constrained")]
+    pub fn usage_type(&self) -> &UsageType {
+        &self.usage_type
+    }
+
+    #[graphql(
+        description = "Whether the type of usage is permitted, and if so whether it is limited"
+    )]
+    pub fn usage_status(&self) -> &UsageStatus {
+        &self.usage_status
+    }
+
+    #[graphql(description = "Maximum permitted quantity of the usage, in the given unit")]
+    pub fn usage_limit(&self) -> Option<&i32> {
+        self.usage_limit.as_ref()
+    }
+
+    #[graphql(description = "Unit in which the usage limit is expressed")]
+    pub fn usage_unit(&self) -> Option<&UsageUnit> {
+        self.usage_unit.as_ref()
+    }
+
+    #[graphql(description = "Date and time at which the usage constraint record was created")]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    #[graphql(description = "Date and time at which the usage constraint record was last updated")]
+    pub fn updated_at(&self) -> Timestamp {
+        self.updated_at
+    }
+
+    #[graphql(description = "Get the publication to which the usage constraint applies")]
+    pub fn publication(&self, context: &Context) -> FieldResult<Publication> {
+        Publication::from_id(&context.db, &self.publication_id).map_err(|e| e.into())
     }
 }
 
@@ -3718,6 +7301,60 @@ impl Price {
         self.unit_price
     }
 
+    #[graphql(description = "Type of the price, including whether it includes tax")]
+    pub fn price_type(&self) -> &PriceType {
+        &self.price_type
+    }
+
+    #[graphql(description = "Class of buyer to which the price applies, if restricted")]
+    pub fn price_qualifier(&self) -> Option<&PriceQualifier> {
+        self.price_qualifier.as_ref()
+    }
+
+    #[graphql(
+        description = "Countries in which the price applies. If no countries or regions are included, the price applies worldwide"
+    )]
+    pub fn countries_included(&self) -> &Vec<CountryCode> {
+        &self.countries_included
+    }
+
+    #[graphql(description = "Countries excluded from the included regions")]
+    pub fn countries_excluded(&self) -> &Vec<CountryCode> {
+        &self.countries_excluded
+    }
+
+    #[graphql(
+        description = "ONIX region codes (e.g. WORLD, ECZ, GB-SCT) in which the price applies"
+    )]
+    pub fn regions_included(&self) -> &Vec<String> {
+        &self.regions_included
+    }
+
+    #[graphql(description = "ONIX region codes excluded from the included regions")]
+    pub fn regions_excluded(&self) -> &Vec<String> {
+        &self.regions_excluded
+    }
+
+    #[graphql(description = "Rate of sales tax or VAT, as a percentage, included in the price")]
+    pub fn tax_rate_percent(&self) -> Option<f64> {
+        self.tax_rate_percent
+    }
+
+    #[graphql(description = "Publisher's own trade discount code for the price")]
+    pub fn discount_code(&self) -> Option<&String> {
+        self.discount_code.as_ref()
+    }
+
+    #[graphql(description = "Date from which the price is effective")]
+    pub fn price_effective_from(&self) -> Option<NaiveDate> {
+        self.price_effective_from
+    }
+
+    #[graphql(description = "Date until which the price is effective")]
+    pub fn price_effective_until(&self) -> Option<NaiveDate> {
+        self.price_effective_until
+    }
+
     #[graphql(description = "Date and time at which the price record was created")]
     pub fn created_at(&self) -> Timestamp {
         self.created_at
@@ -3808,8 +7445,77 @@ impl Institution {
     #[graphql(
         description = "Research Organisation Registry identifier of the organisation as full URL, using the HTTPS scheme and the ror.org domain (e.g. https://ror.org/051z6e826)"
     )]
-    pub fn ror(&self) -> Option<&Ror> {
-        self.ror.as_ref()
+    pub fn ror(
+        &self,
+        context: &Context,
+        #[graphql(
+            default = false,
+            description = "If no identifier is recorded, look it up in the imported institution identifier mapping"
+        )]
+        resolve: Option<bool>,
+    ) -> FieldResult<Option<Ror>> {
+        if self.ror.is_some() || !resolve.unwrap_or_default() {
+            return Ok(self.ror.clone());
+        }
+        self.resolved_identifiers(&context.db)
+            .map(|identifiers| identifiers.ror)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(
+        description = "Crossref Funder Registry identifier of the organisation, without its 10.13039 DOI prefix (e.g. 501100000780)"
+    )]
+    pub fn crossref_funder_id(
+        &self,
+        context: &Context,
+        #[graphql(
+            default = false,
+            description = "If no identifier is recorded, derive one from a Funder Registry institution DOI or look it up in the imported institution identifier mapping"
+        )]
+        resolve: Option<bool>,
+    ) -> FieldResult<Option<FunderId>> {
+        if self.crossref_funder_id.is_some() || !resolve.unwrap_or_default() {
+            return Ok(self.crossref_funder_id.clone());
+        }
+        self.resolved_identifiers(&context.db)
+            .map(|identifiers| identifiers.crossref_funder_id)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "International Standard Name Identifier of the organisation")]
+    pub fn isni(
+        &self,
+        context: &Context,
+        #[graphql(
+            default = false,
+            description = "If no identifier is recorded, look it up in the imported institution identifier mapping"
+        )]
+        resolve: Option<bool>,
+    ) -> FieldResult<Option<Isni>> {
+        if self.isni.is_some() || !resolve.unwrap_or_default() {
+            return Ok(self.isni.clone());
+        }
+        self.resolved_identifiers(&context.db)
+            .map(|identifiers| identifiers.isni)
+            .map_err(|e| e.into())
+    }
+
+    #[graphql(description = "Ringgold identifier of the organisation")]
+    pub fn ringgold(
+        &self,
+        context: &Context,
+        #[graphql(
+            default = false,
+            description = "If no identifier is recorded, look it up in the imported institution identifier mapping"
+        )]
+        resolve: Option<bool>,
+    ) -> FieldResult<Option<Ringgold>> {
+        if self.ringgold.is_some() || !resolve.unwrap_or_default() {
+            return Ok(self.ringgold.clone());
+        }
+        self.resolved_identifiers(&context.db)
+            .map(|identifiers| identifiers.ringgold)
+            .map_err(|e| e.into())
     }
 
     #[graphql(description = "Date and time at which the institution record was created")]
@@ -3877,6 +7583,34 @@ impl Institution {
         )
         .map_err(|e| e.into())
     }
+
+    #[graphql(description = "Get event sponsorships linked to this institution")]
+    pub fn event_sponsors(
+        &self,
+        context: &Context,
+        #[graphql(default = 100, description = "The number of items to return")] limit: Option<i32>,
+        #[graphql(default = 0, description = "The number of items to skip")] offset: Option<i32>,
+        #[graphql(
+            default = EventSponsorOrderBy::default(),
+            description = "The order in which to sort the results"
+        )]
+        order: Option<EventSponsorOrderBy>,
+    ) -> FieldResult<Vec<EventSponsor>> {
+        EventSponsor::all(
+            &context.db,
+            limit.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            None,
+            order.unwrap_or_default(),
+            vec![],
+            Some(self.institution_id),
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .map_err(|e| e.into())
+    }
 }
 
 #[juniper::graphql_object(Context = Context, description = "A grant awarded for the publication of a work by an institution.")]
@@ -4210,6 +7944,14 @@ fn publisher_id_from_publication_id(
     Publication::from_id(db, &publication_id)?.publisher_id(db)
 }
 
+fn publisher_id_from_series_id(db: &crate::db::PgPool, series_id: Uuid) -> ThothResult<Uuid> {
+    Series::from_id(db, &series_id)?.publisher_id(db)
+}
+
+fn publisher_id_from_event_id(db: &crate::db::PgPool, event_id: Uuid) -> ThothResult<Uuid> {
+    Event::from_id(db, &event_id)?.publisher_id(db)
+}
+
 fn publisher_id_from_contribution_id(
     db: &crate::db::PgPool,
     contribution_id: Uuid,
@@ -0,0 +1,159 @@
+use diesel::prelude::*;
+use juniper::InputValue;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use super::GraphQLRequest;
+use crate::db::PgPool;
+use crate::model::Timestamp;
+use crate::schema::persisted_query;
+
+/// The only version of the automatic persisted query protocol
+pub const PERSISTED_QUERY_VERSION: i32 = 1;
+
+#[derive(Debug, Clone, Queryable)]
+pub struct PersistedQuery {
+    pub persisted_query_id: Uuid,
+    pub query_hash: String,
+    pub query: String,
+    pub created_at: Timestamp,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = persisted_query)]
+pub struct NewPersistedQuery {
+    pub query_hash: String,
+    pub query: String,
+}
+
+/// Identifier of a query document: the hex encoded SHA-256 digest of its exact contents
+pub fn query_hash(query: &str) -> String {
+    Sha256::digest(query.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Register a query document, returning the existing record if it had already been registered
+pub fn register_persisted_query(query: &str, pool: &PgPool) -> ThothResult<PersistedQuery> {
+    use crate::schema::persisted_query::dsl;
+
+    let hash = query_hash(query);
+    let mut connection = pool.get()?;
+    diesel::insert_into(dsl::persisted_query)
+        .values(NewPersistedQuery {
+            query_hash: hash.clone(),
+            query: query.to_string(),
+        })
+        .on_conflict(dsl::query_hash)
+        .do_nothing()
+        .execute(&mut connection)?;
+    dsl::persisted_query
+        .filter(dsl::query_hash.eq(hash))
+        .first::<PersistedQuery>(&mut connection)
+        .map_err(Into::into)
+}
+
+/// Look up the document of a registered query by its hash
+pub fn get_persisted_query(hash: &str, pool: &PgPool) -> ThothResult<Option<String>> {
+    use crate::schema::persisted_query::dsl;
+
+    let mut connection = pool.get()?;
+    dsl::persisted_query
+        .select(dsl::query)
+        .filter(dsl::query_hash.eq(hash.to_lowercase()))
+        .first::<String>(&mut connection)
+        .optional()
+        .map_err(Into::into)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQueryExtension {
+    pub version: i32,
+    pub sha256_hash: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestExtensions {
+    pub persisted_query: Option<PersistedQueryExtension>,
+}
+
+/// Body of a GraphQL request in which the query document may be replaced by a reference to a
+/// persisted query, following the automatic persisted query protocol
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQueryRequest {
+    pub query: Option<String>,
+    pub operation_name: Option<String>,
+    pub variables: Option<InputValue>,
+    #[serde(default)]
+    pub extensions: Option<RequestExtensions>,
+}
+
+impl PersistedQueryRequest {
+    /// The hash of the referenced query, checking that the protocol version is supported
+    pub fn persisted_query_hash(&self) -> ThothResult<Option<&str>> {
+        let extension = self
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.persisted_query.as_ref());
+        match extension {
+            Some(extension) if extension.version != PERSISTED_QUERY_VERSION => {
+                Err(ThothError::PersistedQueryVersionError)
+            }
+            Some(extension) => Ok(Some(&extension.sha256_hash)),
+            None => Ok(None),
+        }
+    }
+
+    /// Build the request to execute once the query document has been resolved
+    pub fn into_graphql_request(self, query: String) -> GraphQLRequest {
+        GraphQLRequest::new(query, self.operation_name, self.variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_hash() {
+        assert_eq!(
+            query_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_persisted_query_request() {
+        let request: PersistedQueryRequest = serde_json::from_value(serde_json::json!({
+            "operationName": "Works",
+            "variables": { "limit": 10 },
+            "extensions": { "persistedQuery": { "version": 1, "sha256Hash": "abc" } },
+        }))
+        .unwrap();
+        assert_eq!(request.query, None);
+        assert_eq!(request.persisted_query_hash(), Ok(Some("abc")));
+        let graphql_request = request.into_graphql_request("query Works { workCount }".into());
+        assert_eq!(graphql_request.operation_name.as_deref(), Some("Works"));
+
+        let request: PersistedQueryRequest = serde_json::from_value(
+            serde_json::json!({ "query": "{ workCount }", "extensions": null }),
+        )
+        .unwrap();
+        assert_eq!(request.persisted_query_hash(), Ok(None));
+
+        let request: PersistedQueryRequest = serde_json::from_value(serde_json::json!({
+            "extensions": { "persistedQuery": { "version": 2, "sha256Hash": "abc" } },
+        }))
+        .unwrap();
+        assert_eq!(
+            request.persisted_query_hash(),
+            Err(ThothError::PersistedQueryVersionError)
+        );
+    }
+}
@@ -18,6 +18,7 @@ pub mod account;
 #[cfg(feature = "backend")]
 pub mod db;
 pub mod graphql;
+pub mod markup;
 #[macro_use]
 pub mod model;
 #[cfg(feature = "backend")]
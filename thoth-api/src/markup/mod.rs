@@ -0,0 +1,545 @@
+use std::str::FromStr;
+use thoth_errors::{ThothError, ThothResult};
+
+/// Elements supported in rich text fields (abstracts, biographies and notes): a subset of HTML
+/// which can be converted to both XHTML and JATS.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MarkupTag {
+    Paragraph,
+    Break,
+    Italic,
+    Bold,
+    Superscript,
+    Subscript,
+    UnorderedList,
+    OrderedList,
+    ListItem,
+}
+
+impl MarkupTag {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "p" => Some(MarkupTag::Paragraph),
+            "br" => Some(MarkupTag::Break),
+            "em" | "i" => Some(MarkupTag::Italic),
+            "strong" | "b" => Some(MarkupTag::Bold),
+            "sup" => Some(MarkupTag::Superscript),
+            "sub" => Some(MarkupTag::Subscript),
+            "ul" => Some(MarkupTag::UnorderedList),
+            "ol" => Some(MarkupTag::OrderedList),
+            "li" => Some(MarkupTag::ListItem),
+            _ => None,
+        }
+    }
+
+    pub fn is_block(&self) -> bool {
+        matches!(
+            self,
+            MarkupTag::Paragraph | MarkupTag::UnorderedList | MarkupTag::OrderedList
+        )
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, MarkupTag::UnorderedList | MarkupTag::OrderedList)
+    }
+
+    /// Name of the equivalent XHTML element
+    pub fn xhtml_name(&self) -> &'static str {
+        match self {
+            MarkupTag::Paragraph => "p",
+            MarkupTag::Break => "br",
+            MarkupTag::Italic => "em",
+            MarkupTag::Bold => "strong",
+            MarkupTag::Superscript => "sup",
+            MarkupTag::Subscript => "sub",
+            MarkupTag::UnorderedList => "ul",
+            MarkupTag::OrderedList => "ol",
+            MarkupTag::ListItem => "li",
+        }
+    }
+
+    /// Name of the equivalent JATS element, if any. JATS paragraphs cannot contain line breaks.
+    pub fn jats_name(&self) -> Option<&'static str> {
+        match self {
+            MarkupTag::Paragraph => Some("jats:p"),
+            MarkupTag::Break => None,
+            MarkupTag::Italic => Some("jats:italic"),
+            MarkupTag::Bold => Some("jats:bold"),
+            MarkupTag::Superscript => Some("jats:sup"),
+            MarkupTag::Subscript => Some("jats:sub"),
+            MarkupTag::UnorderedList | MarkupTag::OrderedList => Some("jats:list"),
+            MarkupTag::ListItem => Some("jats:list-item"),
+        }
+    }
+
+    /// Value of the JATS `list-type` attribute for lists
+    pub fn jats_list_type(&self) -> Option<&'static str> {
+        match self {
+            MarkupTag::UnorderedList => Some("bullet"),
+            MarkupTag::OrderedList => Some("order"),
+            _ => None,
+        }
+    }
+
+    fn can_contain(parent: Option<MarkupTag>, child: MarkupTag) -> bool {
+        match parent {
+            None => child != MarkupTag::ListItem,
+            Some(MarkupTag::UnorderedList) | Some(MarkupTag::OrderedList) => {
+                child == MarkupTag::ListItem
+            }
+            Some(MarkupTag::ListItem) => child != MarkupTag::ListItem,
+            Some(_) => !child.is_block() && child != MarkupTag::ListItem,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupNode {
+    Text(String),
+    Element(MarkupTag, Vec<MarkupNode>),
+}
+
+/// Parsed rich text, as a list of block elements (paragraphs and lists).
+///
+/// Text outside of block elements is split into paragraphs at each new line, so that unformatted
+/// values are read the same way they always have been.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markup {
+    blocks: Vec<MarkupNode>,
+    formatted: bool,
+}
+
+impl Markup {
+    pub fn blocks(&self) -> &[MarkupNode] {
+        &self.blocks
+    }
+
+    /// Whether the value contains any markup, as opposed to just plain text
+    pub fn is_formatted(&self) -> bool {
+        self.formatted
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(plain_text)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl FromStr for Markup {
+    type Err = ThothError;
+
+    fn from_str(content: &str) -> ThothResult<Self> {
+        let mut root: Vec<MarkupNode> = vec![];
+        let mut stack: Vec<OpenElement> = vec![];
+        let mut text = String::new();
+        let mut formatted = false;
+        let mut rest = content;
+        while let Some(c) = rest.chars().next() {
+            if c == '<' {
+                if let Some((tag, name, closing, length)) = parse_tag(rest)? {
+                    formatted = true;
+                    push_text(&mut text, &mut stack, &mut root)?;
+                    let parent = stack
+                        .last()
+                        .map(|(parent, parent_name, _)| (*parent, *parent_name));
+                    if closing {
+                        match stack.pop() {
+                            Some((open, _, children)) if open == tag => {
+                                current_children(&mut stack, &mut root)
+                                    .push(MarkupNode::Element(tag, children));
+                            }
+                            _ => {
+                                return Err(ThothError::InvalidMarkup(format!(
+                                    "</{name}> does not close an open tag"
+                                )))
+                            }
+                        }
+                    } else if !MarkupTag::can_contain(parent.map(|(parent, _)| parent), tag) {
+                        return Err(ThothError::InvalidMarkup(match parent {
+                            Some((_, parent_name)) => {
+                                format!("<{name}> cannot be placed inside <{parent_name}>")
+                            }
+                            None => format!("<{name}> must be placed inside a list"),
+                        }));
+                    } else if tag == MarkupTag::Break {
+                        current_children(&mut stack, &mut root)
+                            .push(MarkupNode::Element(tag, vec![]));
+                    } else {
+                        stack.push((tag, name, vec![]));
+                    }
+                    rest = &rest[length..];
+                    continue;
+                }
+            } else if c == '&' {
+                if let Some((decoded, length)) = parse_entity(rest) {
+                    text.push(decoded);
+                    rest = &rest[length..];
+                    continue;
+                }
+            }
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        push_text(&mut text, &mut stack, &mut root)?;
+        if let Some((_, name, _)) = stack.last() {
+            return Err(ThothError::InvalidMarkup(format!("<{name}> is not closed")));
+        }
+        Ok(Markup {
+            blocks: into_blocks(root),
+            formatted,
+        })
+    }
+}
+
+/// Check that a rich text value only uses supported markup
+pub fn validate_markup(content: &str) -> ThothResult<()> {
+    Markup::from_str(content).map(|_| ())
+}
+
+/// Check a rich text value being saved in place of a previous one. Values saved before markup
+/// was validated are only checked once edited, so that records holding them can still be updated.
+pub fn validate_markup_change(content: Option<&str>, previous: Option<&str>) -> ThothResult<()> {
+    match content {
+        Some(content) if Some(content) != previous => validate_markup(content),
+        _ => Ok(()),
+    }
+}
+
+/// Plain text version of a rich text value. Values which are not valid markup are returned as is.
+pub fn to_plain_text(content: &str) -> String {
+    match Markup::from_str(content) {
+        Ok(markup) if markup.is_formatted() => markup.to_plain_text(),
+        _ => content.to_string(),
+    }
+}
+
+// An element which has been opened but not yet closed, with the tag name as written
+type OpenElement<'a> = (MarkupTag, &'a str, Vec<MarkupNode>);
+
+fn current_children<'a>(
+    stack: &'a mut [OpenElement],
+    root: &'a mut Vec<MarkupNode>,
+) -> &'a mut Vec<MarkupNode> {
+    match stack.last_mut() {
+        Some((_, _, children)) => children,
+        None => root,
+    }
+}
+
+fn push_text(
+    text: &mut String,
+    stack: &mut [OpenElement],
+    root: &mut Vec<MarkupNode>,
+) -> ThothResult<()> {
+    if text.is_empty() {
+        return Ok(());
+    }
+    let text = std::mem::take(text);
+    match stack.last() {
+        // Lists may only contain list items, whitespace between them is ignored
+        Some((parent, name, _)) if parent.is_list() => {
+            if !text.trim().is_empty() {
+                return Err(ThothError::InvalidMarkup(format!(
+                    "text in <{name}> must be placed inside <li>"
+                )));
+            }
+        }
+        _ => current_children(stack, root).push(MarkupNode::Text(text)),
+    }
+    Ok(())
+}
+
+// Returns the tag, its name as written, whether it is a closing tag, and its length, or None if
+// the '<' at the start of the input does not open a tag
+fn parse_tag(input: &str) -> ThothResult<Option<(MarkupTag, &str, bool, usize)>> {
+    let inner = &input[1..];
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    if !inner.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Ok(None);
+    }
+    let name_length = inner
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(inner.len());
+    let name = &inner[..name_length];
+    let tag = MarkupTag::from_name(name)
+        .ok_or_else(|| ThothError::InvalidMarkup(format!("<{name}> is not a supported tag")))?;
+    let after_name = &inner[name_length..];
+    let remainder = after_name.trim_start();
+    let remainder = if closing {
+        remainder
+    } else {
+        remainder.strip_prefix('/').unwrap_or(remainder)
+    };
+    if !remainder.starts_with('>') {
+        return Err(ThothError::InvalidMarkup(format!(
+            "<{name}> must not have attributes and must end with '>'"
+        )));
+    }
+    if closing && tag == MarkupTag::Break {
+        return Err(ThothError::InvalidMarkup(format!(
+            "<{name}> must not be closed"
+        )));
+    }
+    let length = input.len() - remainder.len() + 1;
+    Ok(Some((tag, name, closing, length)))
+}
+
+// Returns the decoded character and the length of the entity, or None if the '&' at the start of
+// the input does not begin a character reference
+fn parse_entity(input: &str) -> Option<(char, usize)> {
+    let end = input.find(';').filter(|end| *end <= 10)?;
+    let name = &input[1..end];
+    let decoded = match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)?
+        }
+    };
+    Some((decoded, end + 1))
+}
+
+fn into_blocks(nodes: Vec<MarkupNode>) -> Vec<MarkupNode> {
+    let mut blocks = vec![];
+    let mut paragraph = vec![];
+    for node in nodes {
+        match node {
+            MarkupNode::Element(tag, _) if tag.is_block() => {
+                push_paragraph(&mut paragraph, &mut blocks);
+                blocks.push(node);
+            }
+            MarkupNode::Text(text) => {
+                let mut lines = text.split('\n');
+                if let Some(first) = lines.next() {
+                    paragraph.push(MarkupNode::Text(first.to_string()));
+                }
+                for line in lines {
+                    push_paragraph(&mut paragraph, &mut blocks);
+                    paragraph.push(MarkupNode::Text(line.to_string()));
+                }
+            }
+            _ => paragraph.push(node),
+        }
+    }
+    push_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn push_paragraph(paragraph: &mut Vec<MarkupNode>, blocks: &mut Vec<MarkupNode>) {
+    let mut nodes = std::mem::take(paragraph);
+    if let Some(MarkupNode::Text(text)) = nodes.first_mut() {
+        *text = text.trim_start().to_string();
+    }
+    if let Some(MarkupNode::Text(text)) = nodes.last_mut() {
+        *text = text.trim_end().to_string();
+    }
+    nodes.retain(|node| !matches!(node, MarkupNode::Text(text) if text.is_empty()));
+    if !nodes.is_empty() {
+        blocks.push(MarkupNode::Element(MarkupTag::Paragraph, nodes));
+    }
+}
+
+fn plain_text(node: &MarkupNode) -> String {
+    match node {
+        MarkupNode::Text(text) => text.to_string(),
+        MarkupNode::Element(MarkupTag::Break, _) => "\n".to_string(),
+        MarkupNode::Element(tag, items) if tag.is_list() => items
+            .iter()
+            .map(plain_text)
+            .collect::<Vec<String>>()
+            .join("\n"),
+        MarkupNode::Element(_, children) => {
+            let mut text = String::new();
+            let mut after_block = false;
+            for child in children {
+                let is_block = matches!(child, MarkupNode::Element(tag, _) if tag.is_block());
+                if !text.is_empty() && (is_block || after_block) {
+                    text.push('\n');
+                }
+                text.push_str(&plain_text(child));
+                after_block = is_block;
+            }
+            text
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(nodes: Vec<MarkupNode>) -> MarkupNode {
+        MarkupNode::Element(MarkupTag::Paragraph, nodes)
+    }
+
+    fn text(value: &str) -> MarkupNode {
+        MarkupNode::Text(value.to_string())
+    }
+
+    #[test]
+    fn test_validate_markup_change() {
+        // Legacy values which are not valid markup can be kept as they are...
+        assert!(validate_markup_change(Some("a<b"), Some("a<b")).is_ok());
+        assert!(validate_markup_change(None, Some("<a href>")).is_ok());
+        // ...but not introduced
+        assert!(validate_markup_change(Some("a<b"), None).is_err());
+        assert!(validate_markup_change(Some("<a href>"), Some("a<b")).is_err());
+        assert!(validate_markup_change(Some("<p>Valid</p>"), Some("a<b")).is_ok());
+    }
+
+    #[test]
+    fn test_markup_plain_text_paragraphs() {
+        let markup = Markup::from_str("First paragraph.\r\n\nSecond & last.").unwrap();
+        assert!(!markup.is_formatted());
+        assert_eq!(
+            markup.blocks(),
+            &[
+                paragraph(vec![text("First paragraph.")]),
+                paragraph(vec![text("Second & last.")])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markup_inline_elements() {
+        let markup = Markup::from_str(
+            "<p>A study of <i>Hamlet</i> and H<sub>2</sub>O&nbsp;&amp; E=mc<sup>2</sup></p>",
+        )
+        .unwrap();
+        assert!(markup.is_formatted());
+        assert_eq!(
+            markup.blocks(),
+            &[paragraph(vec![
+                text("A study of "),
+                MarkupNode::Element(MarkupTag::Italic, vec![text("Hamlet")]),
+                text(" and H"),
+                MarkupNode::Element(MarkupTag::Subscript, vec![text("2")]),
+                text("O\u{a0}& E=mc"),
+                MarkupNode::Element(MarkupTag::Superscript, vec![text("2")]),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_markup_top_level_inline_elements() {
+        let markup = Markup::from_str("On <em>Walden</em>\nA second<br/>paragraph").unwrap();
+        assert_eq!(
+            markup.blocks(),
+            &[
+                paragraph(vec![
+                    text("On "),
+                    MarkupNode::Element(MarkupTag::Italic, vec![text("Walden")]),
+                ]),
+                paragraph(vec![
+                    text("A second"),
+                    MarkupNode::Element(MarkupTag::Break, vec![]),
+                    text("paragraph"),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markup_lists() {
+        let markup = Markup::from_str(
+            "<p>Contents:</p>\n<ol>\n  <li>One</li>\n  <li><b>Two</b></li>\n</ol>",
+        )
+        .unwrap();
+        assert_eq!(
+            markup.blocks(),
+            &[
+                paragraph(vec![text("Contents:")]),
+                MarkupNode::Element(
+                    MarkupTag::OrderedList,
+                    vec![
+                        MarkupNode::Element(MarkupTag::ListItem, vec![text("One")]),
+                        MarkupNode::Element(
+                            MarkupTag::ListItem,
+                            vec![MarkupNode::Element(MarkupTag::Bold, vec![text("Two")])]
+                        ),
+                    ]
+                ),
+            ]
+        );
+        assert_eq!(markup.to_plain_text(), "Contents:\nOne\nTwo");
+    }
+
+    #[test]
+    fn test_markup_literal_characters() {
+        let markup = Markup::from_str("1 < 2 & 3 > 2; AT&T").unwrap();
+        assert!(!markup.is_formatted());
+        assert_eq!(markup.to_plain_text(), "1 < 2 & 3 > 2; AT&T");
+    }
+
+    #[test]
+    fn test_markup_invalid() {
+        assert_eq!(
+            Markup::from_str("<span>Text</span>"),
+            Err(ThothError::InvalidMarkup(
+                "<span> is not a supported tag".to_string()
+            ))
+        );
+        assert_eq!(
+            Markup::from_str("<p class=\"lead\">Text</p>"),
+            Err(ThothError::InvalidMarkup(
+                "<p> must not have attributes and must end with '>'".to_string()
+            ))
+        );
+        assert_eq!(
+            Markup::from_str("<i>Text</b>"),
+            Err(ThothError::InvalidMarkup(
+                "</b> does not close an open tag".to_string()
+            ))
+        );
+        assert_eq!(
+            Markup::from_str("<p>Text"),
+            Err(ThothError::InvalidMarkup("<p> is not closed".to_string()))
+        );
+        assert_eq!(
+            Markup::from_str("<em><p>Text</p></em>"),
+            Err(ThothError::InvalidMarkup(
+                "<p> cannot be placed inside <em>".to_string()
+            ))
+        );
+        assert_eq!(
+            Markup::from_str("<li>Text</li>"),
+            Err(ThothError::InvalidMarkup(
+                "<li> must be placed inside a list".to_string()
+            ))
+        );
+        assert_eq!(
+            Markup::from_str("<ul>Text</ul>"),
+            Err(ThothError::InvalidMarkup(
+                "text in <ul> must be placed inside <li>".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_to_plain_text() {
+        assert_eq!(
+            to_plain_text("<p>A study of <i>Hamlet</i>.</p><p>Line<br>break</p>"),
+            "A study of Hamlet.\nLine\nbreak"
+        );
+        // Unformatted and invalid values are left untouched
+        assert_eq!(
+            to_plain_text("Plain\ttext &amp; more"),
+            "Plain\ttext &amp; more"
+        );
+        assert_eq!(to_plain_text("<div>Text</div>"), "<div>Text</div>");
+    }
+}
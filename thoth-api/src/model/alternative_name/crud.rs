@@ -0,0 +1,194 @@
+use super::{
+    AlternativeName, AlternativeNameField, AlternativeNameHistory, NameType, NewAlternativeName,
+    NewAlternativeNameHistory, PatchAlternativeName,
+};
+use crate::graphql::model::AlternativeNameOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{alternative_name, alternative_name_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+impl Crud for AlternativeName {
+    type NewEntity = NewAlternativeName;
+    type PatchEntity = PatchAlternativeName;
+    type OrderByEntity = AlternativeNameOrderBy;
+    type FilterParameter1 = NameType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.alternative_name_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        _: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        name_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<AlternativeName>> {
+        use crate::schema::alternative_name::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::alternative_name.into_boxed();
+
+        query = match order.field {
+            AlternativeNameField::AlternativeNameId => match order.direction {
+                Direction::Asc => query.order(dsl::alternative_name_id.asc()),
+                Direction::Desc => query.order(dsl::alternative_name_id.desc()),
+            },
+            AlternativeNameField::ContributorId => match order.direction {
+                Direction::Asc => query.order(dsl::contributor_id.asc()),
+                Direction::Desc => query.order(dsl::contributor_id.desc()),
+            },
+            AlternativeNameField::NameType => match order.direction {
+                Direction::Asc => query.order(dsl::name_type.asc()),
+                Direction::Desc => query.order(dsl::name_type.desc()),
+            },
+            AlternativeNameField::FirstName => match order.direction {
+                Direction::Asc => query.order(dsl::first_name.asc()),
+                Direction::Desc => query.order(dsl::first_name.desc()),
+            },
+            AlternativeNameField::LastName => match order.direction {
+                Direction::Asc => query.order(dsl::last_name.asc()),
+                Direction::Desc => query.order(dsl::last_name.desc()),
+            },
+            AlternativeNameField::FullName => match order.direction {
+                Direction::Asc => query.order(dsl::full_name.asc()),
+                Direction::Desc => query.order(dsl::full_name.desc()),
+            },
+            AlternativeNameField::LanguageCode => match order.direction {
+                Direction::Asc => query.order(dsl::language_code.asc()),
+                Direction::Desc => query.order(dsl::language_code.desc()),
+            },
+            AlternativeNameField::ScriptCode => match order.direction {
+                Direction::Asc => query.order(dsl::script_code.asc()),
+                Direction::Desc => query.order(dsl::script_code.desc()),
+            },
+            AlternativeNameField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            AlternativeNameField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::contributor_id.eq(pid));
+        }
+        if !name_types.is_empty() {
+            query = query.filter(dsl::name_type.eq_any(name_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::full_name
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::last_name.ilike(format!("%{filter}%"))),
+            );
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<AlternativeName>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        name_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::alternative_name::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::alternative_name.into_boxed();
+        if !name_types.is_empty() {
+            query = query.filter(dsl::name_type.eq_any(name_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::full_name
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::last_name.ilike(format!("%{filter}%"))),
+            );
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, _db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        Err(ThothError::InternalError(
+            "Method publisher_id() is not supported for Alternative Name objects".to_string(),
+        ))
+    }
+
+    crud_methods!(
+        alternative_name::table,
+        alternative_name::dsl::alternative_name
+    );
+}
+
+impl HistoryEntry for AlternativeName {
+    type NewHistoryEntity = NewAlternativeNameHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            alternative_name_id: self.alternative_name_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewAlternativeNameHistory {
+    type MainEntity = AlternativeNameHistory;
+
+    db_insert!(alternative_name_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alternative_name_pk() {
+        let alternative_name: AlternativeName = Default::default();
+        assert_eq!(alternative_name.pk(), alternative_name.alternative_name_id);
+    }
+
+    #[test]
+    fn test_new_alternative_name_history_from_alternative_name() {
+        let alternative_name: AlternativeName = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_alternative_name_history = alternative_name.new_history_entry(&account_id);
+        assert_eq!(
+            new_alternative_name_history.alternative_name_id,
+            alternative_name.alternative_name_id
+        );
+        assert_eq!(new_alternative_name_history.account_id, account_id);
+        assert_eq!(
+            new_alternative_name_history.data,
+            serde_json::Value::String(serde_json::to_string(&alternative_name).unwrap())
+        );
+    }
+}
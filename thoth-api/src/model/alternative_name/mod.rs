@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+use crate::model::language::LanguageCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::alternative_name;
+#[cfg(feature = "backend")]
+use crate::schema::alternative_name_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Relationship of an alternative name to a contributor's main name"),
+    ExistingTypePath = "crate::schema::sql_types::NameType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NameType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Variant form of the name, with no more specific relationship")
+    )]
+    #[default]
+    Variant,
+    #[cfg_attr(feature = "backend", graphql(description = "Pseudonym or pen name"))]
+    Pseudonym,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Name previously used by the contributor")
+    )]
+    Earlier,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Real name, where the main name is a pseudonym")
+    )]
+    Real,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Main name transliterated into another script")
+    )]
+    Transliterated,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Name subsequently used by the contributor")
+    )]
+    Later,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting alternative names list")
+)]
+pub enum AlternativeNameField {
+    AlternativeNameId,
+    ContributorId,
+    NameType,
+    FirstName,
+    LastName,
+    FullName,
+    LanguageCode,
+    ScriptCode,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlternativeName {
+    pub alternative_name_id: Uuid,
+    pub contributor_id: Uuid,
+    pub name_type: NameType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub language_code: Option<LanguageCode>,
+    pub script_code: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new alternative name of a contributor"),
+    diesel(table_name = alternative_name)
+)]
+pub struct NewAlternativeName {
+    pub contributor_id: Uuid,
+    pub name_type: NameType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub language_code: Option<LanguageCode>,
+    pub script_code: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing alternative name of a contributor"),
+    diesel(table_name = alternative_name, treat_none_as_null = true)
+)]
+pub struct PatchAlternativeName {
+    pub alternative_name_id: Uuid,
+    pub contributor_id: Uuid,
+    pub name_type: NameType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub language_code: Option<LanguageCode>,
+    pub script_code: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct AlternativeNameHistory {
+    pub alternative_name_history_id: Uuid,
+    pub alternative_name_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = alternative_name_history)
+)]
+pub struct NewAlternativeNameHistory {
+    pub alternative_name_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+/// Convert a script code to the ISO 15924 form (e.g. `Cyrl`), rejecting anything
+/// that is not four letters
+pub fn standardise_script_code(input: &str) -> ThothResult<String> {
+    let input = input.trim();
+    if input.len() != 4 || !input.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(ThothError::ScriptCodeParseError(input.to_string()));
+    }
+    Ok(format!(
+        "{}{}",
+        input[..1].to_ascii_uppercase(),
+        input[1..].to_ascii_lowercase()
+    ))
+}
+
+impl NewAlternativeName {
+    /// Validate the script code, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.script_code = self
+            .script_code
+            .as_deref()
+            .map(standardise_script_code)
+            .transpose()?;
+        Ok(self)
+    }
+}
+
+impl PatchAlternativeName {
+    /// Validate the script code, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.script_code = self
+            .script_code
+            .as_deref()
+            .map(standardise_script_code)
+            .transpose()?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_type_default() {
+        let name_type: NameType = Default::default();
+        assert_eq!(name_type, NameType::Variant);
+    }
+
+    #[test]
+    fn test_standardise_script_code() {
+        assert_eq!(standardise_script_code("Cyrl").unwrap(), "Cyrl");
+        assert_eq!(standardise_script_code(" hans ").unwrap(), "Hans");
+        assert_eq!(standardise_script_code("LATN").unwrap(), "Latn");
+        assert!(standardise_script_code("Cyr").is_err());
+        assert!(standardise_script_code("C1rl").is_err());
+        assert!(standardise_script_code("").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
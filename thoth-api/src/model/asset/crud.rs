@@ -0,0 +1,340 @@
+use super::{
+    Asset, AssetField, AssetHistory, AssetType, ImageInfo, NewAsset, NewAssetHistory, PatchAsset,
+};
+use crate::graphql::model::AssetOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::location::{Location, LocationPlatform, NewLocation, PatchLocation};
+use crate::model::publication::Publication;
+use crate::model::supporting_resource::SupportingResource;
+use crate::model::work::{PatchWork, Work};
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{asset, asset_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    Connection, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use sha2::{Digest, Sha256};
+use thoth_errors::{ThothError, ThothResult};
+use uuid::Uuid;
+
+impl Crud for Asset {
+    type NewEntity = NewAsset;
+    type PatchEntity = PatchAsset;
+    type OrderByEntity = AssetOrderBy;
+    type FilterParameter1 = AssetType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.asset_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        parent_id_2: Option<Uuid>,
+        asset_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<Asset>> {
+        use crate::schema::asset::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::asset
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::asset::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            AssetField::AssetId => match order.direction {
+                Direction::Asc => query.order(dsl::asset_id.asc()),
+                Direction::Desc => query.order(dsl::asset_id.desc()),
+            },
+            AssetField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            AssetField::PublicationId => match order.direction {
+                Direction::Asc => query.order(dsl::publication_id.asc()),
+                Direction::Desc => query.order(dsl::publication_id.desc()),
+            },
+            AssetField::AssetType => match order.direction {
+                Direction::Asc => query.order(dsl::asset_type.asc()),
+                Direction::Desc => query.order(dsl::asset_type.desc()),
+            },
+            AssetField::Filename => match order.direction {
+                Direction::Asc => query.order(dsl::filename.asc()),
+                Direction::Desc => query.order(dsl::filename.desc()),
+            },
+            AssetField::MimeType => match order.direction {
+                Direction::Asc => query.order(dsl::mime_type.asc()),
+                Direction::Desc => query.order(dsl::mime_type.desc()),
+            },
+            AssetField::FileSize => match order.direction {
+                Direction::Asc => query.order(dsl::file_size.asc()),
+                Direction::Desc => query.order(dsl::file_size.desc()),
+            },
+            AssetField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            AssetField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if let Some(pid) = parent_id_2 {
+            query = query.filter(dsl::publication_id.eq(pid));
+        }
+        if !asset_types.is_empty() {
+            query = query.filter(dsl::asset_type.eq_any(asset_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::filename.ilike(format!("%{filter}%")));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<Asset>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        asset_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::asset::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::asset.into_boxed();
+        if !asset_types.is_empty() {
+            query = query.filter(dsl::asset_type.eq_any(asset_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::filename.ilike(format!("%{filter}%")));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(asset::table, asset::dsl::asset);
+}
+
+impl HistoryEntry for Asset {
+    type NewHistoryEntity = NewAssetHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            asset_id: self.asset_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewAssetHistory {
+    type MainEntity = AssetHistory;
+
+    db_insert!(asset_history::table);
+}
+
+/// Change to make to a publication's Thoth location when one of its files is uploaded
+enum ThothLocation {
+    Existing(Location, PatchLocation),
+    New(NewLocation),
+}
+
+impl NewAsset {
+    /// Describe an uploaded file, computing its size and checksum and determining its MIME type
+    /// from its contents.
+    ///
+    /// Publication files are attached to the work of their publication.
+    pub fn from_upload(
+        db: &crate::db::PgPool,
+        asset_type: AssetType,
+        parent_id: Uuid,
+        filename: String,
+        contents: &[u8],
+    ) -> ThothResult<Self> {
+        let mime_type = asset_type.sniff_mime_type(contents)?.to_string();
+        let (work_id, publication_id) = match asset_type {
+            AssetType::Cover => {
+                ImageInfo::from_bytes(contents)?.validate_cover()?;
+                (Work::from_id(db, &parent_id)?.work_id, None)
+            }
+            AssetType::PublicationFile => {
+                let publication = Publication::from_id(db, &parent_id)?;
+                (publication.work_id, Some(publication.publication_id))
+            }
+        };
+        let file_size = i32::try_from(contents.len())
+            .map_err(|_| ThothError::InvalidAssetError("File is too large.".to_string()))?;
+        let mut new_asset = NewAsset {
+            work_id,
+            publication_id,
+            location_id: None,
+            asset_type,
+            storage_key: Default::default(),
+            asset_url: Default::default(),
+            filename,
+            mime_type,
+            file_size,
+            checksum: format!("{:x}", Sha256::digest(contents)),
+        };
+        new_asset.validate()?;
+        new_asset.storage_key = new_asset.storage_key();
+        Ok(new_asset)
+    }
+
+    /// Check that the asset can be recorded, before its file is stored
+    pub fn can_be_recorded(&self, db: &crate::db::PgPool) -> ThothResult<()> {
+        self.validate()?;
+        if self.asset_type == AssetType::PublicationFile {
+            self.thoth_location(db)?;
+        }
+        Ok(())
+    }
+
+    /// Record a stored asset, and point the work's cover or the publication's Thoth location to it.
+    ///
+    /// All changes are made in a single transaction. Storage keys are derived from the file
+    /// contents, so uploading a file that has already been recorded points to the existing asset
+    /// again instead of recording a duplicate.
+    pub fn record(mut self, db: &crate::db::PgPool, account_id: &Uuid) -> ThothResult<Asset> {
+        use crate::schema::{location, work};
+
+        let work = Work::from_id(db, &self.work_id)?;
+        let thoth_location = match self.asset_type {
+            AssetType::Cover => None,
+            AssetType::PublicationFile => Some(self.thoth_location(db)?),
+        };
+        let mut connection = db.get()?;
+        connection.transaction(|connection| {
+            match thoth_location {
+                None => {
+                    let mut patch = PatchWork::from(work.clone());
+                    patch.cover_url = Some(self.asset_url.clone());
+                    diesel::update(work::table.find(work.work_id))
+                        .set(&patch)
+                        .execute(connection)?;
+                    work.new_history_entry(account_id).insert(connection)?;
+                    // Renditions of the previous cover are out of date
+                    SupportingResource::delete_cover_renditions(connection, &self.work_id)?;
+                }
+                Some(ThothLocation::Existing(location, patch)) => {
+                    diesel::update(location::table.find(location.location_id))
+                        .set(&patch)
+                        .execute(connection)?;
+                    location.new_history_entry(account_id).insert(connection)?;
+                    self.location_id = Some(location.location_id);
+                }
+                Some(ThothLocation::New(new_location)) => {
+                    let location = diesel::insert_into(location::table)
+                        .values(&new_location)
+                        .get_result::<Location>(connection)?;
+                    self.location_id = Some(location.location_id);
+                }
+            }
+            let existing = asset::table
+                .filter(asset::storage_key.eq(&self.storage_key))
+                .first::<Asset>(connection)
+                .optional()?;
+            if let Some(asset) = existing {
+                return Ok(asset);
+            }
+            let asset = diesel::insert_into(asset::table)
+                .values(&self)
+                .get_result::<Asset>(connection)?;
+            asset.new_history_entry(account_id).insert(connection)?;
+            Ok(asset)
+        })
+    }
+
+    /// A publication may only have one location per platform: if it already has a Thoth location
+    /// its full text URL is replaced, otherwise one is created, which is canonical unless another
+    /// location already is.
+    fn thoth_location(&self, db: &crate::db::PgPool) -> ThothResult<ThothLocation> {
+        use crate::schema::location::dsl;
+        let publication_id = self.publication_id.ok_or_else(|| {
+            ThothError::InvalidAssetError("Publication files must belong to a publication.".into())
+        })?;
+        let existing = dsl::location
+            .filter(dsl::publication_id.eq(publication_id))
+            .filter(dsl::location_platform.eq(LocationPlatform::Thoth))
+            .first::<Location>(&mut db.get()?)
+            .optional()?;
+        match existing {
+            Some(location) => {
+                let mut patch = PatchLocation::from(location.clone());
+                patch.full_text_url = Some(self.asset_url.clone());
+                if patch.canonical {
+                    patch.canonical_record_complete(db)?;
+                }
+                Ok(ThothLocation::Existing(location, patch))
+            }
+            None => {
+                let mut new_location = NewLocation {
+                    publication_id,
+                    landing_page: Work::from_id(db, &self.work_id)?.landing_page,
+                    full_text_url: Some(self.asset_url.clone()),
+                    location_platform: LocationPlatform::Thoth,
+                    canonical: false,
+                };
+                if new_location.can_be_non_canonical(db).is_err() {
+                    new_location.canonical = true;
+                    new_location.canonical_record_complete(db)?;
+                }
+                Ok(ThothLocation::New(new_location))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_pk() {
+        let asset: Asset = Default::default();
+        assert_eq!(asset.pk(), asset.asset_id);
+    }
+
+    #[test]
+    fn test_new_asset_history_from_asset() {
+        let asset: Asset = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_asset_history = asset.new_history_entry(&account_id);
+        assert_eq!(new_asset_history.asset_id, asset.asset_id);
+        assert_eq!(new_asset_history.account_id, account_id);
+        assert_eq!(
+            new_asset_history.data,
+            serde_json::Value::String(serde_json::to_string(&asset).unwrap())
+        );
+    }
+}
@@ -0,0 +1,632 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::supporting_resource::CoverRendition;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::asset;
+#[cfg(feature = "backend")]
+use crate::schema::asset_history;
+use thoth_errors::{ThothError, ThothResult};
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Purpose of a file uploaded to Thoth's asset storage"),
+    ExistingTypePath = "crate::schema::sql_types::AssetType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AssetType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Cover image of a work, used as the work's cover URL")
+    )]
+    #[default]
+    Cover,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Full text file of a publication, made available from its Thoth location"
+        )
+    )]
+    PublicationFile,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting assets list")
+)]
+pub enum AssetField {
+    AssetId,
+    WorkId,
+    PublicationId,
+    AssetType,
+    Filename,
+    MimeType,
+    FileSize,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Asset {
+    pub asset_id: Uuid,
+    pub work_id: Uuid,
+    pub publication_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub asset_type: AssetType,
+    pub storage_key: String,
+    pub asset_url: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: i32,
+    pub checksum: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable), diesel(table_name = asset))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewAsset {
+    pub work_id: Uuid,
+    pub publication_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub asset_type: AssetType,
+    pub storage_key: String,
+    pub asset_url: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: i32,
+    pub checksum: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(AsChangeset),
+    diesel(table_name = asset, treat_none_as_null = true)
+)]
+pub struct PatchAsset {
+    pub asset_id: Uuid,
+    pub work_id: Uuid,
+    pub publication_id: Option<Uuid>,
+    pub location_id: Option<Uuid>,
+    pub asset_type: AssetType,
+    pub storage_key: String,
+    pub asset_url: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub file_size: i32,
+    pub checksum: String,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct AssetHistory {
+    pub asset_history_id: Uuid,
+    pub asset_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable), diesel(table_name = asset_history))]
+pub struct NewAssetHistory {
+    pub asset_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+/// Covers narrower or shorter than this are rejected by most retailers
+pub const MIN_COVER_WIDTH: u32 = 1400;
+pub const MIN_COVER_HEIGHT: u32 = 1400;
+/// Covers may range from square to twice as tall as they are wide
+pub const MIN_COVER_ASPECT_RATIO: f64 = 1.0;
+pub const MAX_COVER_ASPECT_RATIO: f64 = 2.0;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const JPEG_SIGNATURE: &[u8] = &[0xFF, 0xD8];
+const PDF_SIGNATURE: &[u8] = b"%PDF-";
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+/// An EPUB's first entry must be an uncompressed `mimetype` file holding its MIME type
+const EPUB_MIMETYPE_ENTRY: &[u8] = b"mimetypeapplication/epub+zip";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColourSpace {
+    Rgb,
+    Greyscale,
+    Cmyk,
+}
+
+/// Dimensions and colour space of a JPEG or PNG image, read from its headers
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub width: u32,
+    pub height: u32,
+    pub colour_space: ColourSpace,
+}
+
+impl ImageInfo {
+    pub fn from_bytes(contents: &[u8]) -> ThothResult<Self> {
+        let info = if contents.starts_with(PNG_SIGNATURE) {
+            Self::from_png(contents)
+        } else if contents.starts_with(JPEG_SIGNATURE) {
+            Self::from_jpeg(contents)
+        } else {
+            return Err(ThothError::InvalidAssetError(
+                "Covers must be JPEG or PNG images.".to_string(),
+            ));
+        };
+        info.ok_or_else(|| {
+            ThothError::InvalidAssetError("Could not read the image's dimensions.".to_string())
+        })
+    }
+
+    /// The IHDR chunk, which must come first, holds the dimensions followed by the bit depth
+    /// and colour type.
+    fn from_png(contents: &[u8]) -> Option<Self> {
+        let ihdr = contents.get(PNG_SIGNATURE.len()..PNG_SIGNATURE.len() + 18)?;
+        if &ihdr[4..8] != b"IHDR" {
+            return None;
+        }
+        let colour_space = match ihdr[17] {
+            // Greyscale, with or without alpha
+            0 | 4 => ColourSpace::Greyscale,
+            // Truecolour, with or without alpha, and indexed colour
+            2 | 3 | 6 => ColourSpace::Rgb,
+            _ => return None,
+        };
+        Some(ImageInfo {
+            width: u32::from_be_bytes(ihdr[8..12].try_into().ok()?),
+            height: u32::from_be_bytes(ihdr[12..16].try_into().ok()?),
+            colour_space,
+        })
+    }
+
+    /// Walk the segments until the start of frame, which holds the dimensions and the number
+    /// of colour components.
+    fn from_jpeg(contents: &[u8]) -> Option<Self> {
+        let mut position = JPEG_SIGNATURE.len();
+        loop {
+            if *contents.get(position)? != 0xFF {
+                return None;
+            }
+            let marker = *contents.get(position + 1)?;
+            position += 2;
+            match marker {
+                // Fill byte
+                0xFF => position -= 1,
+                // Markers without a payload
+                0x01 | 0xD0..=0xD7 => {}
+                // Start of frame, other than the huffman and arithmetic coding tables
+                0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+                    let frame = contents.get(position + 2..position + 8)?;
+                    let colour_space = match frame[5] {
+                        1 => ColourSpace::Greyscale,
+                        3 => ColourSpace::Rgb,
+                        4 => ColourSpace::Cmyk,
+                        _ => return None,
+                    };
+                    return Some(ImageInfo {
+                        width: u16::from_be_bytes([frame[3], frame[4]]).into(),
+                        height: u16::from_be_bytes([frame[1], frame[2]]).into(),
+                        colour_space,
+                    });
+                }
+                _ => {
+                    let length = u16::from_be_bytes([
+                        *contents.get(position)?,
+                        *contents.get(position + 1)?,
+                    ]);
+                    position += usize::from(length);
+                }
+            }
+        }
+    }
+
+    /// Check that the image meets the requirements of retailers for cover images
+    pub fn validate_cover(&self) -> ThothResult<()> {
+        if self.colour_space != ColourSpace::Rgb {
+            return Err(ThothError::InvalidAssetError(
+                "Covers must use the RGB colour space.".to_string(),
+            ));
+        }
+        if self.width < MIN_COVER_WIDTH || self.height < MIN_COVER_HEIGHT {
+            return Err(ThothError::InvalidAssetError(format!(
+                "Covers must be at least {MIN_COVER_WIDTH} pixels wide and {MIN_COVER_HEIGHT} pixels high."
+            )));
+        }
+        let aspect_ratio = f64::from(self.height) / f64::from(self.width);
+        if !(MIN_COVER_ASPECT_RATIO..=MAX_COVER_ASPECT_RATIO).contains(&aspect_ratio) {
+            return Err(ThothError::InvalidAssetError(
+                "Covers must be between one and two times as tall as they are wide.".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl AssetType {
+    /// Directory under which assets of this type are stored
+    fn storage_prefix(&self) -> &'static str {
+        match self {
+            AssetType::Cover => "covers",
+            AssetType::PublicationFile => "publications",
+        }
+    }
+
+    /// MIME types accepted for assets of this type, with the extension they are stored under.
+    /// Formats that browsers may run as active content, such as SVG or HTML, are never accepted.
+    fn formats(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            AssetType::Cover => &[("image/jpeg", "jpg"), ("image/png", "png")],
+            AssetType::PublicationFile => {
+                &[("application/pdf", "pdf"), ("application/epub+zip", "epub")]
+            }
+        }
+    }
+
+    /// Extension under which files of an accepted MIME type are stored
+    fn extension(&self, mime_type: &str) -> Option<&'static str> {
+        self.formats()
+            .iter()
+            .find(|(accepted, _)| *accepted == mime_type)
+            .map(|(_, extension)| *extension)
+    }
+
+    /// Determine the MIME type of a file from its contents, regardless of its name or the type
+    /// declared by the client, rejecting any format not accepted for assets of this type
+    pub fn sniff_mime_type(&self, contents: &[u8]) -> ThothResult<&'static str> {
+        let mime_type = if contents.starts_with(PNG_SIGNATURE) {
+            Some("image/png")
+        } else if contents.starts_with(JPEG_SIGNATURE) {
+            Some("image/jpeg")
+        } else if contents.starts_with(PDF_SIGNATURE) {
+            Some("application/pdf")
+        } else if contents.starts_with(ZIP_SIGNATURE)
+            && contents.get(30..30 + EPUB_MIMETYPE_ENTRY.len()) == Some(EPUB_MIMETYPE_ENTRY)
+        {
+            Some("application/epub+zip")
+        } else {
+            None
+        };
+        mime_type
+            .filter(|mime_type| self.extension(mime_type).is_some())
+            .ok_or_else(|| {
+                ThothError::InvalidAssetError(
+                    match self {
+                        AssetType::Cover => "Covers must be JPEG or PNG images.",
+                        AssetType::PublicationFile => {
+                            "Publication files must be PDF or EPUB files."
+                        }
+                    }
+                    .to_string(),
+                )
+            })
+    }
+}
+
+impl Asset {
+    /// Key under which a rendition of a cover is stored, next to the original:
+    /// `covers/<work ID>/<checksum>/<rendition>.jpg`
+    pub fn rendition_storage_key(&self, rendition: &CoverRendition) -> String {
+        format!(
+            "{}/{}/{}/{}.jpg",
+            self.asset_type.storage_prefix(),
+            self.work_id,
+            self.checksum,
+            rendition.name()
+        )
+    }
+}
+
+impl NewAsset {
+    /// Key under which the file is stored: `<prefix>/<work or publication ID>/<checksum>.<ext>`.
+    ///
+    /// Keys are derived from the file contents, so uploading the same file twice is detected.
+    /// The extension is that of the validated MIME type, never the one the file was uploaded with.
+    pub fn storage_key(&self) -> String {
+        let parent_id = self.publication_id.unwrap_or(self.work_id);
+        match self.asset_type.extension(&self.mime_type) {
+            Some(ext) => format!(
+                "{}/{}/{}.{}",
+                self.asset_type.storage_prefix(),
+                parent_id,
+                self.checksum,
+                ext
+            ),
+            None => format!(
+                "{}/{}/{}",
+                self.asset_type.storage_prefix(),
+                parent_id,
+                self.checksum
+            ),
+        }
+    }
+
+    pub fn validate(&self) -> ThothResult<()> {
+        if self.asset_type.extension(&self.mime_type).is_none() {
+            return Err(ThothError::InvalidAssetError(format!(
+                "Files of type {} cannot be uploaded as a {}.",
+                self.mime_type,
+                self.asset_type.to_string().to_lowercase()
+            )));
+        }
+        if self.file_size < 1 {
+            return Err(ThothError::InvalidAssetError("File is empty.".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const CHECKSUM: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    fn test_cover() -> NewAsset {
+        NewAsset {
+            work_id: Uuid::parse_str("00000000-0000-0000-aaaa-000000000001").unwrap(),
+            asset_type: AssetType::Cover,
+            filename: "Cover.JPG".to_string(),
+            mime_type: "image/jpeg".to_string(),
+            file_size: 1024,
+            checksum: CHECKSUM.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_assettype_default() {
+        let asset_type: AssetType = Default::default();
+        assert_eq!(asset_type, AssetType::Cover);
+    }
+
+    #[test]
+    fn test_assettype_display() {
+        assert_eq!(format!("{}", AssetType::Cover), "Cover");
+        assert_eq!(
+            format!("{}", AssetType::PublicationFile),
+            "Publication File"
+        );
+    }
+
+    #[test]
+    fn test_assettype_fromstr() {
+        assert_eq!(
+            AssetType::from_str("Publication File").unwrap(),
+            AssetType::PublicationFile
+        );
+        assert!(AssetType::from_str("File").is_err());
+    }
+
+    #[test]
+    fn test_storage_key() {
+        let cover = test_cover();
+        assert_eq!(
+            cover.storage_key(),
+            format!("covers/00000000-0000-0000-aaaa-000000000001/{CHECKSUM}.jpg")
+        );
+        let publication_file = NewAsset {
+            publication_id: Some(Uuid::parse_str("00000000-0000-0000-bbbb-000000000002").unwrap()),
+            asset_type: AssetType::PublicationFile,
+            filename: "book".to_string(),
+            mime_type: "application/pdf".to_string(),
+            ..test_cover()
+        };
+        assert_eq!(
+            publication_file.storage_key(),
+            format!("publications/00000000-0000-0000-bbbb-000000000002/{CHECKSUM}.pdf")
+        );
+        // The extension follows the MIME type, not the name the file was uploaded with
+        let renamed = NewAsset {
+            filename: "cover.html".to_string(),
+            mime_type: "image/png".to_string(),
+            ..test_cover()
+        };
+        assert_eq!(
+            renamed.storage_key(),
+            format!("covers/00000000-0000-0000-aaaa-000000000001/{CHECKSUM}.png")
+        );
+    }
+
+    #[test]
+    fn test_sniff_mime_type() {
+        let mut epub = ZIP_SIGNATURE.to_vec();
+        epub.extend_from_slice(&[0; 26]);
+        epub.extend_from_slice(EPUB_MIMETYPE_ENTRY);
+        assert_eq!(
+            AssetType::Cover.sniff_mime_type(&test_png(1600, 2400, 6)),
+            Ok("image/png")
+        );
+        assert_eq!(
+            AssetType::Cover.sniff_mime_type(&test_jpeg(1500, 2250, 3)),
+            Ok("image/jpeg")
+        );
+        assert_eq!(
+            AssetType::PublicationFile.sniff_mime_type(b"%PDF-1.7\n"),
+            Ok("application/pdf")
+        );
+        assert_eq!(
+            AssetType::PublicationFile.sniff_mime_type(&epub),
+            Ok("application/epub+zip")
+        );
+        // Each asset type only accepts its own formats
+        assert!(AssetType::Cover.sniff_mime_type(b"%PDF-1.7\n").is_err());
+        assert!(AssetType::PublicationFile
+            .sniff_mime_type(&test_png(1600, 2400, 6))
+            .is_err());
+        // Active content is rejected, whatever it claims to be
+        assert!(AssetType::Cover
+            .sniff_mime_type(b"<svg xmlns=\"http://www.w3.org/2000/svg\"><script/></svg>")
+            .is_err());
+        assert!(AssetType::PublicationFile
+            .sniff_mime_type(b"<!DOCTYPE html><script>alert(1)</script>")
+            .is_err());
+        assert!(AssetType::PublicationFile
+            .sniff_mime_type(&ZIP_SIGNATURE.repeat(20))
+            .is_err());
+    }
+
+    fn test_png(width: u32, height: u32, colour_type: u8) -> Vec<u8> {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend_from_slice(&13u32.to_be_bytes());
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&width.to_be_bytes());
+        png.extend_from_slice(&height.to_be_bytes());
+        png.extend_from_slice(&[8, colour_type, 0, 0, 0]);
+        png
+    }
+
+    fn test_jpeg(width: u16, height: u16, components: u8) -> Vec<u8> {
+        let mut jpeg = JPEG_SIGNATURE.to_vec();
+        // APP0 segment, to be skipped
+        jpeg.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x10]);
+        jpeg.extend_from_slice(b"JFIF\0\x01\x01\0\0\x01\0\x01\0\0");
+        // Baseline start of frame
+        jpeg.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x11, 0x08]);
+        jpeg.extend_from_slice(&height.to_be_bytes());
+        jpeg.extend_from_slice(&width.to_be_bytes());
+        jpeg.push(components);
+        jpeg
+    }
+
+    #[test]
+    fn test_image_info_from_bytes() {
+        assert_eq!(
+            ImageInfo::from_bytes(&test_png(1600, 2400, 6)).unwrap(),
+            ImageInfo {
+                width: 1600,
+                height: 2400,
+                colour_space: ColourSpace::Rgb
+            }
+        );
+        assert_eq!(
+            ImageInfo::from_bytes(&test_png(1600, 2400, 0))
+                .unwrap()
+                .colour_space,
+            ColourSpace::Greyscale
+        );
+        assert_eq!(
+            ImageInfo::from_bytes(&test_jpeg(1500, 2250, 3)).unwrap(),
+            ImageInfo {
+                width: 1500,
+                height: 2250,
+                colour_space: ColourSpace::Rgb
+            }
+        );
+        assert_eq!(
+            ImageInfo::from_bytes(&test_jpeg(1500, 2250, 4))
+                .unwrap()
+                .colour_space,
+            ColourSpace::Cmyk
+        );
+        assert!(ImageInfo::from_bytes(b"GIF89a").is_err());
+        assert!(ImageInfo::from_bytes(&test_jpeg(1500, 2250, 3)[..20]).is_err());
+    }
+
+    #[test]
+    fn test_image_info_validate_cover() {
+        let cover = ImageInfo {
+            width: 1600,
+            height: 2400,
+            colour_space: ColourSpace::Rgb,
+        };
+        assert!(cover.validate_cover().is_ok());
+        assert!(ImageInfo {
+            colour_space: ColourSpace::Cmyk,
+            ..cover
+        }
+        .validate_cover()
+        .is_err());
+        assert!(ImageInfo {
+            width: 1000,
+            height: 1500,
+            ..cover
+        }
+        .validate_cover()
+        .is_err());
+        assert!(ImageInfo {
+            width: 2400,
+            height: 1600,
+            ..cover
+        }
+        .validate_cover()
+        .is_err());
+        assert!(ImageInfo {
+            width: 1400,
+            height: 2900,
+            ..cover
+        }
+        .validate_cover()
+        .is_err());
+        assert!(ImageInfo {
+            width: 1400,
+            height: 1400,
+            ..cover
+        }
+        .validate_cover()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rendition_storage_key() {
+        let asset = Asset {
+            work_id: Uuid::parse_str("00000000-0000-0000-aaaa-000000000001").unwrap(),
+            checksum: CHECKSUM.to_string(),
+            storage_key: format!("covers/00000000-0000-0000-aaaa-000000000001/{CHECKSUM}.png"),
+            ..Default::default()
+        };
+        assert_eq!(
+            asset.rendition_storage_key(&CoverRendition::AppleBooks),
+            format!("covers/00000000-0000-0000-aaaa-000000000001/{CHECKSUM}/apple_books.jpg")
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(test_cover().validate().is_ok());
+        let not_an_image = NewAsset {
+            mime_type: "application/pdf".to_string(),
+            ..test_cover()
+        };
+        assert!(not_an_image.validate().is_err());
+        let empty = NewAsset {
+            file_size: 0,
+            ..test_cover()
+        };
+        assert!(empty.validate().is_err());
+        let publication_file = NewAsset {
+            asset_type: AssetType::PublicationFile,
+            mime_type: "application/epub+zip".to_string(),
+            ..test_cover()
+        };
+        assert!(publication_file.validate().is_ok());
+        for mime_type in ["image/svg+xml", "text/html", "application/xhtml+xml"] {
+            assert!(NewAsset {
+                mime_type: mime_type.to_string(),
+                ..test_cover()
+            }
+            .validate()
+            .is_err());
+            assert!(NewAsset {
+                mime_type: mime_type.to_string(),
+                ..publication_file.clone()
+            }
+            .validate()
+            .is_err());
+        }
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
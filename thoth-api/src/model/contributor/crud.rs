@@ -80,7 +80,11 @@ impl Crud for Contributor {
                 full_name
                     .ilike(format!("%{filter}%"))
                     .or(last_name.ilike(format!("%{filter}%")))
-                    .or(orcid.ilike(format!("%{filter}%"))),
+                    .or(orcid.ilike(format!("%{filter}%")))
+                    .or(isni.ilike(format!("%{filter}%")))
+                    .or(viaf.ilike(format!("%{filter}%")))
+                    .or(wikidata.ilike(format!("%{filter}%")))
+                    .or(lcnaf.ilike(format!("%{filter}%"))),
             );
         }
         query
@@ -106,7 +110,11 @@ impl Crud for Contributor {
                 full_name
                     .ilike(format!("%{filter}%"))
                     .or(last_name.ilike(format!("%{filter}%")))
-                    .or(orcid.ilike(format!("%{filter}%"))),
+                    .or(orcid.ilike(format!("%{filter}%")))
+                    .or(isni.ilike(format!("%{filter}%")))
+                    .or(viaf.ilike(format!("%{filter}%")))
+                    .or(wikidata.ilike(format!("%{filter}%")))
+                    .or(lcnaf.ilike(format!("%{filter}%"))),
             );
         }
 
@@ -3,9 +3,12 @@ use serde::Serialize;
 use std::fmt;
 use strum::Display;
 use strum::EnumString;
+use thoth_errors::ThothResult;
 use uuid::Uuid;
 
 use crate::graphql::utils::Direction;
+use crate::model::alternative_name::AlternativeName;
+use crate::model::identifier::{standardise_identifier, Isni, Lcnaf, Viaf, WikidataId};
 use crate::model::Orcid;
 use crate::model::Timestamp;
 #[cfg(feature = "backend")]
@@ -47,6 +50,28 @@ pub struct Contributor {
     pub website: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub isni: Option<Isni>,
+    pub viaf: Option<Viaf>,
+    pub wikidata: Option<WikidataId>,
+    pub lcnaf: Option<Lcnaf>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributorWithAlternativeNames {
+    pub contributor_id: Uuid,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub orcid: Option<Orcid>,
+    pub website: Option<String>,
+    pub isni: Option<Isni>,
+    pub viaf: Option<Viaf>,
+    pub wikidata: Option<WikidataId>,
+    pub lcnaf: Option<Lcnaf>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub alternative_names: Option<Vec<AlternativeName>>,
 }
 
 #[cfg_attr(
@@ -61,6 +86,10 @@ pub struct NewContributor {
     pub full_name: String,
     pub orcid: Option<Orcid>,
     pub website: Option<String>,
+    pub isni: Option<Isni>,
+    pub viaf: Option<Viaf>,
+    pub wikidata: Option<WikidataId>,
+    pub lcnaf: Option<Lcnaf>,
 }
 
 #[cfg_attr(
@@ -76,6 +105,10 @@ pub struct PatchContributor {
     pub full_name: String,
     pub orcid: Option<Orcid>,
     pub website: Option<String>,
+    pub isni: Option<Isni>,
+    pub viaf: Option<Viaf>,
+    pub wikidata: Option<WikidataId>,
+    pub lcnaf: Option<Lcnaf>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -109,6 +142,28 @@ pub struct ContributorOrderBy {
     pub direction: Direction,
 }
 
+impl NewContributor {
+    /// Validate the contributor's authority identifiers, replacing them with their standardised forms
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.isni = standardise_identifier(self.isni)?;
+        self.viaf = standardise_identifier(self.viaf)?;
+        self.wikidata = standardise_identifier(self.wikidata)?;
+        self.lcnaf = standardise_identifier(self.lcnaf)?;
+        Ok(self)
+    }
+}
+
+impl PatchContributor {
+    /// Validate the contributor's authority identifiers, replacing them with their standardised forms
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.isni = standardise_identifier(self.isni)?;
+        self.viaf = standardise_identifier(self.viaf)?;
+        self.wikidata = standardise_identifier(self.wikidata)?;
+        self.lcnaf = standardise_identifier(self.lcnaf)?;
+        Ok(self)
+    }
+}
+
 impl fmt::Display for Contributor {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(orcid) = &self.orcid {
@@ -169,5 +224,33 @@ fn test_contributorfield_fromstr() {
     assert!(ContributorField::from_str("Institution").is_err());
 }
 
+#[test]
+fn test_new_contributor_standardise() {
+    // Identifiers arrive from the API exactly as they were entered
+    let raw = |value: &str| serde_json::Value::String(value.to_string());
+    let new_contributor = |isni: &str| NewContributor {
+        first_name: Some("Isaac".to_string()),
+        last_name: "Newton".to_string(),
+        full_name: "Isaac Newton".to_string(),
+        orcid: None,
+        website: None,
+        isni: serde_json::from_value(raw(isni)).unwrap(),
+        viaf: serde_json::from_value(raw("https://viaf.org/viaf/44300643/")).unwrap(),
+        wikidata: serde_json::from_value(raw("q935")).unwrap(),
+        lcnaf: serde_json::from_value(raw("n 79021164")).unwrap(),
+    };
+    let contributor = new_contributor("0000 0001 2103 2683")
+        .standardise()
+        .unwrap();
+    assert_eq!(contributor.isni.unwrap().to_string(), "0000000121032683");
+    assert_eq!(contributor.viaf.unwrap().to_string(), "44300643");
+    assert_eq!(contributor.wikidata.unwrap().to_string(), "Q935");
+    assert_eq!(contributor.lcnaf.unwrap().to_string(), "n79021164");
+    // Wrong ISNI check character
+    assert!(new_contributor("0000 0001 2103 2684")
+        .standardise()
+        .is_err());
+}
+
 #[cfg(feature = "backend")]
 pub mod crud;
@@ -0,0 +1,187 @@
+use super::{Event, EventField, EventHistory, NewEvent, NewEventHistory, PatchEvent};
+use crate::graphql::model::EventOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{event, event_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for Event {
+    type NewEntity = NewEvent;
+    type PatchEntity = PatchEvent;
+    type OrderByEntity = EventOrderBy;
+    type FilterParameter1 = ();
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.event_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        _: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<Event>> {
+        use crate::schema::event::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::event
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::event::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            EventField::EventId => match order.direction {
+                Direction::Asc => query.order(dsl::event_id.asc()),
+                Direction::Desc => query.order(dsl::event_id.desc()),
+            },
+            EventField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            EventField::EventName => match order.direction {
+                Direction::Asc => query.order(dsl::event_name.asc()),
+                Direction::Desc => query.order(dsl::event_name.desc()),
+            },
+            EventField::EventAcronym => match order.direction {
+                Direction::Asc => query.order(dsl::event_acronym.asc()),
+                Direction::Desc => query.order(dsl::event_acronym.desc()),
+            },
+            EventField::EventNumber => match order.direction {
+                Direction::Asc => query.order(dsl::event_number.asc()),
+                Direction::Desc => query.order(dsl::event_number.desc()),
+            },
+            EventField::EventLocation => match order.direction {
+                Direction::Asc => query.order(dsl::event_location.asc()),
+                Direction::Desc => query.order(dsl::event_location.desc()),
+            },
+            EventField::EventCountry => match order.direction {
+                Direction::Asc => query.order(dsl::event_country.asc()),
+                Direction::Desc => query.order(dsl::event_country.desc()),
+            },
+            EventField::EventStartDate => match order.direction {
+                Direction::Asc => query.order(dsl::event_start_date.asc()),
+                Direction::Desc => query.order(dsl::event_start_date.desc()),
+            },
+            EventField::EventEndDate => match order.direction {
+                Direction::Asc => query.order(dsl::event_end_date.asc()),
+                Direction::Desc => query.order(dsl::event_end_date.desc()),
+            },
+            EventField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            EventField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::event_name
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::event_acronym.ilike(format!("%{filter}%"))),
+            );
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<Event>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        _: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::event::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::event.into_boxed();
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::event_name
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::event_acronym.ilike(format!("%{filter}%"))),
+            );
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(event::table, event::dsl::event);
+}
+
+impl HistoryEntry for Event {
+    type NewHistoryEntity = NewEventHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            event_id: self.event_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewEventHistory {
+    type MainEntity = EventHistory;
+
+    db_insert!(event_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_pk() {
+        let event: Event = Default::default();
+        assert_eq!(event.pk(), event.event_id);
+    }
+
+    #[test]
+    fn test_new_event_history_from_event() {
+        let event: Event = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_event_history = event.new_history_entry(&account_id);
+        assert_eq!(new_event_history.event_id, event.event_id);
+        assert_eq!(new_event_history.account_id, account_id);
+        assert_eq!(
+            new_event_history.data,
+            serde_json::Value::String(serde_json::to_string(&event).unwrap())
+        );
+    }
+}
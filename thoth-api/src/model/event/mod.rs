@@ -0,0 +1,116 @@
+use chrono::naive::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::event_sponsor::EventSponsorWithInstitution;
+use crate::model::institution::CountryCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::event;
+#[cfg(feature = "backend")]
+use crate::schema::event_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting events list")
+)]
+pub enum EventField {
+    EventId,
+    WorkId,
+    EventName,
+    EventAcronym,
+    EventNumber,
+    EventLocation,
+    EventCountry,
+    EventStartDate,
+    EventEndDate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    pub event_id: Uuid,
+    pub work_id: Uuid,
+    pub event_name: String,
+    pub event_acronym: Option<String>,
+    pub event_number: Option<String>,
+    pub event_location: Option<String>,
+    pub event_country: Option<CountryCode>,
+    pub event_start_date: Option<NaiveDate>,
+    pub event_end_date: Option<NaiveDate>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventWithSponsors {
+    pub event_id: Uuid,
+    pub work_id: Uuid,
+    pub event_name: String,
+    pub event_acronym: Option<String>,
+    pub event_number: Option<String>,
+    pub event_location: Option<String>,
+    pub event_country: Option<CountryCode>,
+    pub event_start_date: Option<NaiveDate>,
+    pub event_end_date: Option<NaiveDate>,
+    pub sponsors: Option<Vec<EventSponsorWithInstitution>>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new conference or other event whose proceedings are published in a work"),
+    diesel(table_name = event)
+)]
+pub struct NewEvent {
+    pub work_id: Uuid,
+    pub event_name: String,
+    pub event_acronym: Option<String>,
+    pub event_number: Option<String>,
+    pub event_location: Option<String>,
+    pub event_country: Option<CountryCode>,
+    pub event_start_date: Option<NaiveDate>,
+    pub event_end_date: Option<NaiveDate>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing conference or other event whose proceedings are published in a work"),
+    diesel(table_name = event, treat_none_as_null = true)
+)]
+pub struct PatchEvent {
+    pub event_id: Uuid,
+    pub work_id: Uuid,
+    pub event_name: String,
+    pub event_acronym: Option<String>,
+    pub event_number: Option<String>,
+    pub event_location: Option<String>,
+    pub event_country: Option<CountryCode>,
+    pub event_start_date: Option<NaiveDate>,
+    pub event_end_date: Option<NaiveDate>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct EventHistory {
+    pub event_history_id: Uuid,
+    pub event_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable), diesel(table_name = event_history))]
+pub struct NewEventHistory {
+    pub event_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
@@ -0,0 +1,163 @@
+use super::{
+    EventSponsor, EventSponsorField, EventSponsorHistory, NewEventSponsor, NewEventSponsorHistory,
+    PatchEventSponsor,
+};
+use crate::graphql::model::EventSponsorOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{event_sponsor, event_sponsor_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for EventSponsor {
+    type NewEntity = NewEventSponsor;
+    type PatchEntity = PatchEventSponsor;
+    type OrderByEntity = EventSponsorOrderBy;
+    type FilterParameter1 = ();
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.event_sponsor_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        parent_id_2: Option<Uuid>,
+        _: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<EventSponsor>> {
+        use crate::schema::event_sponsor::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::event_sponsor
+                .inner_join(crate::schema::event::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::event_sponsor::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            EventSponsorField::EventSponsorId => match order.direction {
+                Direction::Asc => query.order(dsl::event_sponsor_id.asc()),
+                Direction::Desc => query.order(dsl::event_sponsor_id.desc()),
+            },
+            EventSponsorField::EventId => match order.direction {
+                Direction::Asc => query.order(dsl::event_id.asc()),
+                Direction::Desc => query.order(dsl::event_id.desc()),
+            },
+            EventSponsorField::InstitutionId => match order.direction {
+                Direction::Asc => query.order(dsl::institution_id.asc()),
+                Direction::Desc => query.order(dsl::institution_id.desc()),
+            },
+            EventSponsorField::SponsorOrdinal => match order.direction {
+                Direction::Asc => query.order(dsl::sponsor_ordinal.asc()),
+                Direction::Desc => query.order(dsl::sponsor_ordinal.desc()),
+            },
+            EventSponsorField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            EventSponsorField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::institution_id.eq(pid));
+        }
+        if let Some(pid) = parent_id_2 {
+            query = query.filter(dsl::event_id.eq(pid));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<EventSponsor>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        _: Vec<Uuid>,
+        _: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::event_sponsor::dsl;
+        let mut connection = db.get()?;
+
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        dsl::event_sponsor
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::event::Event::from_id(db, &self.event_id)?.publisher_id(db)
+    }
+
+    crud_methods!(event_sponsor::table, event_sponsor::dsl::event_sponsor);
+}
+
+impl HistoryEntry for EventSponsor {
+    type NewHistoryEntity = NewEventSponsorHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            event_sponsor_id: self.event_sponsor_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewEventSponsorHistory {
+    type MainEntity = EventSponsorHistory;
+
+    db_insert!(event_sponsor_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_sponsor_pk() {
+        let event_sponsor: EventSponsor = Default::default();
+        assert_eq!(event_sponsor.pk(), event_sponsor.event_sponsor_id);
+    }
+
+    #[test]
+    fn test_new_event_sponsor_history_from_event_sponsor() {
+        let event_sponsor: EventSponsor = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_event_sponsor_history = event_sponsor.new_history_entry(&account_id);
+        assert_eq!(
+            new_event_sponsor_history.event_sponsor_id,
+            event_sponsor.event_sponsor_id
+        );
+        assert_eq!(new_event_sponsor_history.account_id, account_id);
+        assert_eq!(
+            new_event_sponsor_history.data,
+            serde_json::Value::String(serde_json::to_string(&event_sponsor).unwrap())
+        );
+    }
+}
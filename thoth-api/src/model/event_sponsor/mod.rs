@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::institution::Institution;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::event_sponsor;
+#[cfg(feature = "backend")]
+use crate::schema::event_sponsor_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting event sponsors list")
+)]
+pub enum EventSponsorField {
+    EventSponsorId,
+    EventId,
+    InstitutionId,
+    SponsorOrdinal,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSponsor {
+    pub event_sponsor_id: Uuid,
+    pub event_id: Uuid,
+    pub institution_id: Uuid,
+    pub sponsor_ordinal: i32,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventSponsorWithInstitution {
+    pub event_sponsor_id: Uuid,
+    pub event_id: Uuid,
+    pub institution_id: Uuid,
+    pub sponsor_ordinal: i32,
+    pub institution: Institution,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new sponsorship of an event by an institution"),
+    diesel(table_name = event_sponsor)
+)]
+pub struct NewEventSponsor {
+    pub event_id: Uuid,
+    pub institution_id: Uuid,
+    pub sponsor_ordinal: i32,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing sponsorship of an event by an institution"),
+    diesel(table_name = event_sponsor, treat_none_as_null = true)
+)]
+pub struct PatchEventSponsor {
+    pub event_sponsor_id: Uuid,
+    pub event_id: Uuid,
+    pub institution_id: Uuid,
+    pub sponsor_ordinal: i32,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct EventSponsorHistory {
+    pub event_sponsor_history_id: Uuid,
+    pub event_sponsor_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = event_sponsor_history)
+)]
+pub struct NewEventSponsorHistory {
+    pub event_sponsor_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
@@ -0,0 +1,910 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use strum::Display;
+use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
+
+use crate::model::{IdentifierWithDomain, UrlIdentifier};
+
+pub const HANDLE_DOMAIN: &str = "https://hdl.handle.net/";
+pub const ARK_DOMAIN: &str = "https://n2t.net/";
+pub const WIKIDATA_DOMAIN: &str = "https://www.wikidata.org/wiki/";
+pub const ISNI_DOMAIN: &str = "https://isni.org/isni/";
+pub const VIAF_DOMAIN: &str = "https://viaf.org/viaf/";
+pub const LCNAF_DOMAIN: &str = "https://id.loc.gov/authorities/names/";
+pub const FUNDER_ID_DOMAIN: &str = "https://doi.org/10.13039/";
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of a persistent identifier assigned to a work or publication"),
+    ExistingTypePath = "crate::schema::sql_types::IdentifierType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IdentifierType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Handle System identifier, e.g. 20.500.12345/678")
+    )]
+    #[default]
+    Handle,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Archival Resource Key, e.g. ark:/12345/x54xz321")
+    )]
+    #[strum(serialize = "ARK")]
+    Ark,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "National Bibliography Number, e.g. urn:nbn:de:101:1-2019")
+    )]
+    #[strum(serialize = "URN:NBN")]
+    UrnNbn,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "International Standard Text Code (works only)")
+    )]
+    #[strum(serialize = "ISTC")]
+    Istc,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Wikidata item identifier, e.g. Q42 (works only)")
+    )]
+    Wikidata,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Publisher's stock keeping unit (publications only)")
+    )]
+    #[strum(serialize = "SKU")]
+    Sku,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Global Trade Item Number (GTIN-8, GTIN-12, GTIN-13 or GTIN-14), for non-book products (publications only)"
+        )
+    )]
+    #[strum(serialize = "GTIN")]
+    Gtin,
+}
+
+impl IdentifierType {
+    pub fn applies_to_works(&self) -> bool {
+        !matches!(self, IdentifierType::Sku | IdentifierType::Gtin)
+    }
+
+    pub fn applies_to_publications(&self) -> bool {
+        !matches!(self, IdentifierType::Istc | IdentifierType::Wikidata)
+    }
+
+    /// Validate an identifier of this type, returning it in its standardised form
+    pub fn standardise(&self, input: &str) -> ThothResult<String> {
+        let input = input.trim();
+        match self {
+            IdentifierType::Handle => Handle::from_str(input).map(|i| i.to_string()),
+            IdentifierType::Ark => Ark::from_str(input).map(|i| i.to_string()),
+            IdentifierType::UrnNbn => UrnNbn::from_str(input).map(|i| i.to_string()),
+            IdentifierType::Istc => Istc::from_str(input).map(|i| i.to_string()),
+            IdentifierType::Wikidata => WikidataId::from_str(input).map(|i| i.to_string()),
+            IdentifierType::Sku => {
+                if input.is_empty() {
+                    Err(ThothError::IdentifierEmptyError(self.to_string()))
+                } else {
+                    Ok(input.to_string())
+                }
+            }
+            IdentifierType::Gtin => Gtin::from_str(input).map(|i| i.to_string()),
+        }
+    }
+
+    /// Standardise an identifier to be assigned to a work
+    pub fn standardise_for_work(&self, input: &str) -> ThothResult<String> {
+        if !self.applies_to_works() {
+            return Err(ThothError::IdentifierTypeNotAllowed(
+                self.to_string(),
+                "works".to_string(),
+            ));
+        }
+        self.standardise(input)
+    }
+
+    /// Standardise an identifier to be assigned to a publication
+    pub fn standardise_for_publication(&self, input: &str) -> ThothResult<String> {
+        if !self.applies_to_publications() {
+            return Err(ThothError::IdentifierTypeNotAllowed(
+                self.to_string(),
+                "publications".to_string(),
+            ));
+        }
+        self.standardise(input)
+    }
+}
+
+/// Handle System identifier, stored without its resolver
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Handle(String);
+
+/// Archival Resource Key, stored as `ark:/NAAN/Name` without its resolver
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Ark(String);
+
+/// National Bibliography Number, stored as a lowercase-prefixed URN
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UrnNbn(String);
+
+/// International Standard Text Code, with its parts separated by hyphens
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Istc(String);
+
+/// Wikidata item identifier (QID)
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"Wikidata item identifier. Expressed as `^Q[1-9]\d*$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WikidataId(String);
+
+/// International Standard Name Identifier, stored as 16 characters without spaces
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"ISNI (International Standard Name Identifier). Expressed as `^\d{15}[\dX]$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Isni(String);
+
+/// Virtual International Authority File cluster identifier
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"VIAF (Virtual International Authority File) identifier. Expressed as `^[1-9]\d{1,21}$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Viaf(String);
+
+/// Library of Congress Name Authority File identifier, in normalised LCCN form
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"Library of Congress Name Authority File identifier. Expressed as `^n[bors]?\d{8}(\d{2})?$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Lcnaf(String);
+
+/// Crossref Funder Registry identifier, stored as the suffix of its 10.13039 DOI
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"Crossref Funder Registry identifier, i.e. the suffix of a 10.13039 DOI. Expressed as `^[1-9]\d{8,11}$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunderId(String);
+
+/// Ringgold institutional identifier
+#[cfg_attr(
+    feature = "backend",
+    derive(DieselNewType, juniper::GraphQLScalar),
+    graphql(
+        transparent,
+        description = r#"Ringgold institutional identifier. Expressed as `^[1-9]\d{0,6}$`"#
+    )
+)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Ringgold(String);
+
+/// Global Trade Item Number, digits only
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Gtin(String);
+
+impl fmt::Display for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Ark {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for UrnNbn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Istc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for WikidataId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Gtin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Isni {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Viaf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Lcnaf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for FunderId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl fmt::Display for Ringgold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl FromStr for Handle {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Handle> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[http[s]://]hdl.handle.net/|hdl:]PREFIX/SUFFIX"
+            // where the prefix is a series of dot-separated numbers, and captures "PREFIX/SUFFIX"
+            r"^(?i:(?:https?://)?hdl\.handle\.net/|hdl:)?(\d+(?:\.\d+)*/\S+)$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("Handle".to_string()))
+        } else if let Some(identifier) = RE.captures(input).and_then(|m| m.get(1)) {
+            Ok(Handle(identifier.as_str().to_string()))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "Handle".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Ark {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Ark> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[http[s]://RESOLVER/]ark:[/]NAAN/NAME", where the
+            // Name Assigning Authority Number is five digits or betanumeric characters,
+            // and captures the NAAN and the name
+            r"^(?:https?://[^/\s]+/)?(?i:ark:)/?([0-9bcdfghjkmnpqrstvwxz]{5})/(\S+)$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("ARK".to_string()))
+        } else if let Some(matches) = RE.captures(input) {
+            Ok(Ark(format!("ark:/{}/{}", &matches[1], &matches[2])))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "ARK".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for UrnNbn {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<UrnNbn> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "urn:nbn:CC[:|-]NSS", where CC is a two-letter country
+            // code, and captures the country code and the namespace-specific string
+            r"^(?i:urn:nbn:)([a-zA-Z]{2})([:-]\S+)$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("URN:NBN".to_string()))
+        } else if let Some(matches) = RE.captures(input) {
+            Ok(UrnNbn(format!(
+                "urn:nbn:{}{}",
+                matches[1].to_lowercase(),
+                &matches[2]
+            )))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "URN:NBN".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Istc {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Istc> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[ISTC ]XXX-YYYY-XXXXXXXX-C" with optional hyphens or
+            // spaces, where X are hexadecimal digits, YYYY is the year of registration and C is
+            // the check digit, and captures each element
+            r"^(?i:ISTC:?\s*)?([0-9a-fA-F]{3})[-\s]?(\d{4})[-\s]?([0-9a-fA-F]{8})[-\s]?([0-9a-fA-F])$").unwrap();
+        }
+        let parse_error =
+            || ThothError::IdentifierParseError(input.to_string(), "ISTC".to_string());
+        if input.is_empty() {
+            return Err(ThothError::IdentifierEmptyError("ISTC".to_string()));
+        }
+        let matches = RE.captures(input).ok_or_else(parse_error)?;
+        let digits = format!("{}{}{}", &matches[1], &matches[2], &matches[3]).to_uppercase();
+        // ISO 21047 check digit: weighted sum of the hexadecimal digits, modulo 16
+        let check_digit = digits
+            .chars()
+            .zip([11, 9, 3, 1].iter().cycle())
+            .map(|(c, weight)| c.to_digit(16).unwrap() * weight)
+            .sum::<u32>()
+            % 16;
+        if format!("{check_digit:X}") != matches[4].to_uppercase() {
+            return Err(parse_error());
+        }
+        Ok(Istc(format!(
+            "{}-{}-{}-{:X}",
+            &digits[..3],
+            &digits[3..7],
+            &digits[7..],
+            check_digit
+        )))
+    }
+}
+
+impl FromStr for WikidataId {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<WikidataId> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[http[s]://][www.]wikidata.org/wiki|entity/]QNNN"
+            // and captures the item identifier
+            r"^(?i:(?:https?://)?(?:www\.)?wikidata\.org/(?:wiki|entity)/)?([qQ][1-9]\d*)$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("Wikidata".to_string()))
+        } else if let Some(identifier) = RE.captures(input).and_then(|m| m.get(1)) {
+            Ok(WikidataId(identifier.as_str().to_uppercase()))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "Wikidata".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Gtin {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Gtin> {
+        if input.is_empty() {
+            return Err(ThothError::IdentifierEmptyError("GTIN".to_string()));
+        }
+        let parse_error =
+            || ThothError::IdentifierParseError(input.to_string(), "GTIN".to_string());
+        let digits: String = input.chars().filter(|c| *c != '-' && *c != ' ').collect();
+        if !matches!(digits.len(), 8 | 12 | 13 | 14) || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(parse_error());
+        }
+        // GS1 check digit: digits are weighted 3 and 1 alternately from the right
+        let sum: u32 = digits
+            .chars()
+            .rev()
+            .skip(1)
+            .zip([3, 1].iter().cycle())
+            .map(|(c, weight)| c.to_digit(10).unwrap() * weight)
+            .sum();
+        let check_digit = (10 - sum % 10) % 10;
+        if digits.ends_with(char::from_digit(check_digit, 10).unwrap()) {
+            Ok(Gtin(digits))
+        } else {
+            Err(parse_error())
+        }
+    }
+}
+
+impl FromStr for Isni {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Isni> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[http[s]://][www.]isni.org/isni/|ISNI ]XXXX XXXX XXXX XXXX"
+            // with optional spaces or hyphens between groups, and captures each group
+            r"^(?i:(?:https?://)?(?:www\.)?isni\.org/isni/|ISNI:?\s*)?(\d{4})[\s-]?(\d{4})[\s-]?(\d{4})[\s-]?(\d{3}[\dxX])$").unwrap();
+        }
+        let parse_error =
+            || ThothError::IdentifierParseError(input.to_string(), "ISNI".to_string());
+        if input.is_empty() {
+            return Err(ThothError::IdentifierEmptyError("ISNI".to_string()));
+        }
+        let matches = RE.captures(input).ok_or_else(parse_error)?;
+        let digits = format!(
+            "{}{}{}{}",
+            &matches[1], &matches[2], &matches[3], &matches[4]
+        )
+        .to_uppercase();
+        if digits.ends_with(iso7064_mod_11_2(&digits[..15])) {
+            Ok(Isni(digits))
+        } else {
+            Err(parse_error())
+        }
+    }
+}
+
+impl FromStr for Viaf {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Viaf> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[http[s]://][www.]viaf.org/viaf/]NNN[/]"
+            // and captures the numeric cluster identifier
+            r"^(?i:(?:https?://)?(?:www\.)?viaf\.org/viaf/)?([1-9]\d{1,21})/?$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("VIAF".to_string()))
+        } else if let Some(identifier) = RE.captures(input).and_then(|m| m.get(1)) {
+            Ok(Viaf(identifier.as_str().to_string()))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "VIAF".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Lcnaf {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Lcnaf> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[http[s]://]id.loc.gov/authorities/names/]PP NNNNNNNN[.html]"
+            // where PP is a name authority prefix (n, nb, no, nr or ns) and the serial number has
+            // eight or ten digits, and captures the prefix and the serial number
+            r"^(?i:(?:https?://)?id\.loc\.gov/authorities/names/)?(n[bors]?)\s*(\d{8}|\d{10})(?i:\.html)?$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("LCNAF".to_string()))
+        } else if let Some(matches) = RE.captures(input) {
+            Ok(Lcnaf(format!("{}{}", &matches[1], &matches[2])))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "LCNAF".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for FunderId {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<FunderId> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[[[http[s]://][www.][dx.]doi.org/]10.13039/]NNNNNNNNN"
+            // and captures the numeric funder identifier
+            r"^(?:(?i:(?:https?://)?(?:www\.)?(?:dx\.)?doi\.org/)?10\.13039/)?([1-9]\d{8,11})$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("Funder ID".to_string()))
+        } else if let Some(identifier) = RE.captures(input.trim()).and_then(|m| m.get(1)) {
+            Ok(FunderId(identifier.as_str().to_string()))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "Funder ID".to_string(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Ringgold {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> ThothResult<Ringgold> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+        lazy_static! {
+            static ref RE: Regex = Regex::new(
+            // Matches strings of format "[Ringgold[:] ]NNNNNN" and captures the numeric identifier
+            r"^(?i:Ringgold:?\s*)?([1-9]\d{0,6})$").unwrap();
+        }
+        if input.is_empty() {
+            Err(ThothError::IdentifierEmptyError("Ringgold".to_string()))
+        } else if let Some(identifier) = RE.captures(input.trim()).and_then(|m| m.get(1)) {
+            Ok(Ringgold(identifier.as_str().to_string()))
+        } else {
+            Err(ThothError::IdentifierParseError(
+                input.to_string(),
+                "Ringgold".to_string(),
+            ))
+        }
+    }
+}
+
+/// Validate an optional identifier, replacing it with its standardised form
+pub fn standardise_identifier<T>(identifier: Option<T>) -> ThothResult<Option<T>>
+where
+    T: FromStr<Err = ThothError> + fmt::Display,
+{
+    identifier
+        .map(|i| T::from_str(i.to_string().trim()))
+        .transpose()
+}
+
+/// ISO 7064 MOD 11-2 check character, as used by ISNI and ORCID
+fn iso7064_mod_11_2(digits: &str) -> char {
+    let total = digits
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .fold(0, |total, digit| (total + digit) * 2);
+    match (12 - total % 11) % 11 {
+        10 => 'X',
+        remainder => char::from_digit(remainder, 10).unwrap(),
+    }
+}
+
+impl UrlIdentifier for Handle {
+    fn domain(&self) -> &'static str {
+        HANDLE_DOMAIN
+    }
+}
+
+impl UrlIdentifier for Ark {
+    fn domain(&self) -> &'static str {
+        ARK_DOMAIN
+    }
+}
+
+impl UrlIdentifier for WikidataId {
+    fn domain(&self) -> &'static str {
+        WIKIDATA_DOMAIN
+    }
+}
+
+impl UrlIdentifier for Isni {
+    fn domain(&self) -> &'static str {
+        ISNI_DOMAIN
+    }
+}
+
+impl UrlIdentifier for Viaf {
+    fn domain(&self) -> &'static str {
+        VIAF_DOMAIN
+    }
+}
+
+impl UrlIdentifier for Lcnaf {
+    fn domain(&self) -> &'static str {
+        LCNAF_DOMAIN
+    }
+}
+
+impl UrlIdentifier for FunderId {
+    fn domain(&self) -> &'static str {
+        FUNDER_ID_DOMAIN
+    }
+}
+
+impl IdentifierWithDomain for Handle {}
+impl IdentifierWithDomain for Ark {}
+impl IdentifierWithDomain for WikidataId {}
+impl IdentifierWithDomain for Isni {}
+impl IdentifierWithDomain for Viaf {}
+impl IdentifierWithDomain for Lcnaf {}
+impl IdentifierWithDomain for FunderId {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle_fromstr() {
+        let standardised = Handle("20.500.12345/678".to_string());
+        assert_eq!(Handle::from_str("20.500.12345/678").unwrap(), standardised);
+        assert_eq!(
+            Handle::from_str("https://hdl.handle.net/20.500.12345/678").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            Handle::from_str("hdl:20.500.12345/678").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://hdl.handle.net/20.500.12345/678"
+        );
+        assert!(Handle::from_str("").is_err());
+        assert!(Handle::from_str("20.500.12345").is_err());
+        assert!(Handle::from_str("https://example.org/20.500.12345/678").is_err());
+    }
+
+    #[test]
+    fn test_ark_fromstr() {
+        let standardised = Ark("ark:/12345/x54xz321".to_string());
+        assert_eq!(Ark::from_str("ark:/12345/x54xz321").unwrap(), standardised);
+        assert_eq!(Ark::from_str("ark:12345/x54xz321").unwrap(), standardised);
+        assert_eq!(
+            Ark::from_str("https://n2t.net/ark:/12345/x54xz321").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://n2t.net/ark:/12345/x54xz321"
+        );
+        assert!(Ark::from_str("12345/x54xz321").is_err());
+        assert!(Ark::from_str("ark:/123/x54xz321").is_err());
+    }
+
+    #[test]
+    fn test_urn_nbn_fromstr() {
+        assert_eq!(
+            UrnNbn::from_str("URN:NBN:DE:101:1-2019").unwrap(),
+            UrnNbn("urn:nbn:de:101:1-2019".to_string())
+        );
+        assert_eq!(
+            UrnNbn::from_str("urn:nbn:fi-fe2019").unwrap(),
+            UrnNbn("urn:nbn:fi-fe2019".to_string())
+        );
+        assert!(UrnNbn::from_str("urn:isbn:9783161484100").is_err());
+        assert!(UrnNbn::from_str("urn:nbn:d:101").is_err());
+    }
+
+    #[test]
+    fn test_istc_fromstr() {
+        let standardised = Istc("0A9-2002-12B4A105-7".to_string());
+        assert_eq!(Istc::from_str("0A9-2002-12B4A105-7").unwrap(), standardised);
+        assert_eq!(Istc::from_str("0a9200212b4a1057").unwrap(), standardised);
+        assert_eq!(
+            Istc::from_str("ISTC 0A9 2002 12B4A105 7").unwrap(),
+            standardised
+        );
+        // Wrong check digit
+        assert!(Istc::from_str("0A9-2002-12B4A105-5").is_err());
+        assert!(Istc::from_str("0A9-2002-12B4A10-7").is_err());
+    }
+
+    #[test]
+    fn test_wikidata_id_fromstr() {
+        let standardised = WikidataId("Q42".to_string());
+        assert_eq!(WikidataId::from_str("Q42").unwrap(), standardised);
+        assert_eq!(WikidataId::from_str("q42").unwrap(), standardised);
+        assert_eq!(
+            WikidataId::from_str("https://www.wikidata.org/wiki/Q42").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            WikidataId::from_str("http://www.wikidata.org/entity/Q42").unwrap(),
+            standardised
+        );
+        assert!(WikidataId::from_str("Q042").is_err());
+        assert!(WikidataId::from_str("P31").is_err());
+    }
+
+    #[test]
+    fn test_gtin_fromstr() {
+        assert_eq!(
+            Gtin::from_str("4006381333931").unwrap(),
+            Gtin("4006381333931".to_string())
+        );
+        assert_eq!(
+            Gtin::from_str("400-6381-33393-1").unwrap(),
+            Gtin("4006381333931".to_string())
+        );
+        assert!(Gtin::from_str("036000291452").is_ok());
+        assert!(Gtin::from_str("96385074").is_ok());
+        assert!(Gtin::from_str("10614141000415").is_ok());
+        // Wrong check digit
+        assert!(Gtin::from_str("4006381333932").is_err());
+        assert!(Gtin::from_str("400638133393").is_err());
+        assert!(Gtin::from_str("40063813339AB").is_err());
+    }
+
+    #[test]
+    fn test_isni_fromstr() {
+        let standardised = Isni("0000000121032683".to_string());
+        assert_eq!(Isni::from_str("0000000121032683").unwrap(), standardised);
+        assert_eq!(Isni::from_str("0000 0001 2103 2683").unwrap(), standardised);
+        assert_eq!(
+            Isni::from_str("ISNI 0000 0001 2103 2683").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            Isni::from_str("https://isni.org/isni/0000000121032683").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://isni.org/isni/0000000121032683"
+        );
+        assert_eq!(
+            Isni::from_str("0000 0001 2146 438x").unwrap(),
+            Isni("000000012146438X".to_string())
+        );
+        // Wrong check character
+        assert!(Isni::from_str("0000000121032684").is_err());
+        assert!(Isni::from_str("000000012103268").is_err());
+        assert!(Isni::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_iso7064_mod_11_2() {
+        // Shared with ORCID
+        assert_eq!(iso7064_mod_11_2("000000021825009"), '7');
+        assert_eq!(iso7064_mod_11_2("000000012146438"), 'X');
+    }
+
+    #[test]
+    fn test_viaf_fromstr() {
+        let standardised = Viaf("102333412".to_string());
+        assert_eq!(Viaf::from_str("102333412").unwrap(), standardised);
+        assert_eq!(
+            Viaf::from_str("https://viaf.org/viaf/102333412/").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://viaf.org/viaf/102333412"
+        );
+        assert!(Viaf::from_str("0102333412").is_err());
+        assert!(Viaf::from_str("viaf102333412").is_err());
+    }
+
+    #[test]
+    fn test_lcnaf_fromstr() {
+        let standardised = Lcnaf("n79021164".to_string());
+        assert_eq!(Lcnaf::from_str("n79021164").unwrap(), standardised);
+        assert_eq!(Lcnaf::from_str("n 79021164").unwrap(), standardised);
+        assert_eq!(
+            Lcnaf::from_str("http://id.loc.gov/authorities/names/n79021164.html").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://id.loc.gov/authorities/names/n79021164"
+        );
+        assert!(Lcnaf::from_str("nr2001012345").is_ok());
+        assert!(Lcnaf::from_str("sh85034652").is_err());
+        assert!(Lcnaf::from_str("n790211").is_err());
+    }
+
+    #[test]
+    fn test_funder_id_fromstr() {
+        let standardised = FunderId("501100000780".to_string());
+        assert_eq!(FunderId::from_str("501100000780").unwrap(), standardised);
+        assert_eq!(
+            FunderId::from_str("10.13039/501100000780").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            FunderId::from_str("https://doi.org/10.13039/501100000780").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            FunderId::from_str("http://dx.doi.org/10.13039/501100000780").unwrap(),
+            standardised
+        );
+        assert_eq!(
+            standardised.with_domain(),
+            "https://doi.org/10.13039/501100000780"
+        );
+        assert!(FunderId::from_str("100000001").is_ok());
+        assert!(FunderId::from_str("10.12345/501100000780").is_err());
+        assert!(FunderId::from_str("10000000").is_err());
+        assert!(FunderId::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_ringgold_fromstr() {
+        let standardised = Ringgold("6396".to_string());
+        assert_eq!(Ringgold::from_str("6396").unwrap(), standardised);
+        assert_eq!(Ringgold::from_str("Ringgold: 6396").unwrap(), standardised);
+        assert_eq!(Ringgold::from_str("ringgold 6396").unwrap(), standardised);
+        assert!(Ringgold::from_str("06396").is_err());
+        assert!(Ringgold::from_str("12345678").is_err());
+        assert!(Ringgold::from_str("").is_err());
+    }
+
+    #[test]
+    fn test_identifier_type_standardise() {
+        assert_eq!(
+            IdentifierType::Wikidata
+                .standardise_for_work(" https://www.wikidata.org/wiki/Q42 ")
+                .unwrap(),
+            "Q42"
+        );
+        assert_eq!(
+            IdentifierType::Sku
+                .standardise_for_publication("PB-0001")
+                .unwrap(),
+            "PB-0001"
+        );
+        assert!(IdentifierType::Sku
+            .standardise_for_publication(" ")
+            .is_err());
+        assert!(IdentifierType::Sku.standardise_for_work("PB-0001").is_err());
+        assert!(IdentifierType::Istc
+            .standardise_for_publication("0A9-2002-12B4A105-7")
+            .is_err());
+        assert!(IdentifierType::Handle
+            .standardise_for_publication("20.500.12345/678")
+            .is_ok());
+    }
+
+    #[test]
+    fn test_identifier_type_display() {
+        assert_eq!(format!("{}", IdentifierType::Handle), "Handle");
+        assert_eq!(format!("{}", IdentifierType::Ark), "ARK");
+        assert_eq!(format!("{}", IdentifierType::UrnNbn), "URN:NBN");
+        assert_eq!(format!("{}", IdentifierType::Istc), "ISTC");
+        assert_eq!(format!("{}", IdentifierType::Wikidata), "Wikidata");
+        assert_eq!(format!("{}", IdentifierType::Sku), "SKU");
+        assert_eq!(format!("{}", IdentifierType::Gtin), "GTIN");
+    }
+}
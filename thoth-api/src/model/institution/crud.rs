@@ -1,13 +1,15 @@
 use super::{
-    Institution, InstitutionField, InstitutionHistory, InstitutionOrderBy, NewInstitution,
-    NewInstitutionHistory, PatchInstitution,
+    Institution, InstitutionField, InstitutionHistory, InstitutionIdentifierMapping,
+    InstitutionOrderBy, NewInstitution, NewInstitutionHistory, NewInstitutionIdentifierMapping,
+    PatchInstitution,
 };
 use crate::graphql::utils::Direction;
 use crate::model::{Crud, DbInsert, HistoryEntry};
-use crate::schema::{institution, institution_history};
+use crate::schema::{institution, institution_history, institution_identifier_mapping};
 use crate::{crud_methods, db_insert};
 use diesel::{
-    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+    BoolExpressionMethods, Connection, ExpressionMethods, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
 };
 use thoth_errors::{ThothError, ThothResult};
 use uuid::Uuid;
@@ -76,7 +78,10 @@ impl Crud for Institution {
                 institution_name
                     .ilike(format!("%{filter}%"))
                     .or(ror.ilike(format!("%{filter}%")))
-                    .or(institution_doi.ilike(format!("%{filter}%"))),
+                    .or(institution_doi.ilike(format!("%{filter}%")))
+                    .or(crossref_funder_id.ilike(format!("%{filter}%")))
+                    .or(isni.ilike(format!("%{filter}%")))
+                    .or(ringgold.ilike(format!("%{filter}%"))),
             );
         }
         query
@@ -102,7 +107,10 @@ impl Crud for Institution {
                 institution_name
                     .ilike(format!("%{filter}%"))
                     .or(ror.ilike(format!("%{filter}%")))
-                    .or(institution_doi.ilike(format!("%{filter}%"))),
+                    .or(institution_doi.ilike(format!("%{filter}%")))
+                    .or(crossref_funder_id.ilike(format!("%{filter}%")))
+                    .or(isni.ilike(format!("%{filter}%")))
+                    .or(ringgold.ilike(format!("%{filter}%"))),
             );
         }
 
@@ -148,6 +156,75 @@ impl Institution {
     pub fn linked_publisher_ids(&self, db: &crate::db::PgPool) -> ThothResult<Vec<Uuid>> {
         institution_linked_publisher_ids(self.institution_id, db)
     }
+
+    /// Identifiers recorded against this institution, completed with any identifiers that the
+    /// imported mapping associates with them. Recorded values always take precedence.
+    pub fn resolved_identifiers(
+        &self,
+        db: &crate::db::PgPool,
+    ) -> ThothResult<NewInstitutionIdentifierMapping> {
+        use crate::schema::institution_identifier_mapping::dsl;
+        let mut resolved = NewInstitutionIdentifierMapping {
+            ror: self.ror.clone(),
+            crossref_funder_id: self
+                .crossref_funder_id
+                .clone()
+                .or_else(|| self.funder_id_from_doi()),
+            isni: self.isni.clone(),
+            ringgold: self.ringgold.clone(),
+        };
+        if resolved == NewInstitutionIdentifierMapping::default() {
+            return Ok(resolved);
+        }
+        let mut query = dsl::institution_identifier_mapping.into_boxed();
+        if let Some(ror) = &resolved.ror {
+            query = query.or_filter(dsl::ror.eq(ror.clone()));
+        }
+        if let Some(funder_id) = &resolved.crossref_funder_id {
+            query = query.or_filter(dsl::crossref_funder_id.eq(funder_id.clone()));
+        }
+        if let Some(isni) = &resolved.isni {
+            query = query.or_filter(dsl::isni.eq(isni.clone()));
+        }
+        if let Some(ringgold) = &resolved.ringgold {
+            query = query.or_filter(dsl::ringgold.eq(ringgold.clone()));
+        }
+        let mut connection = db.get()?;
+        let mappings = query
+            .order(dsl::created_at.asc())
+            .load::<InstitutionIdentifierMapping>(&mut connection)?;
+        for mapping in mappings {
+            resolved.ror = resolved.ror.or(mapping.ror);
+            resolved.crossref_funder_id =
+                resolved.crossref_funder_id.or(mapping.crossref_funder_id);
+            resolved.isni = resolved.isni.or(mapping.isni);
+            resolved.ringgold = resolved.ringgold.or(mapping.ringgold);
+        }
+        Ok(resolved)
+    }
+}
+
+impl InstitutionIdentifierMapping {
+    /// Maximum number of rows per insert, keeping within the PostgreSQL bind parameter limit
+    const INSERT_BATCH_SIZE: usize = 10_000;
+
+    /// Replace the whole identifier mapping with the given entries, returning the number imported
+    pub fn replace_all(
+        db: &crate::db::PgPool,
+        mappings: &[NewInstitutionIdentifierMapping],
+    ) -> ThothResult<usize> {
+        let mut connection = db.get()?;
+        connection.transaction(|connection| {
+            diesel::delete(institution_identifier_mapping::table).execute(connection)?;
+            let mut imported = 0;
+            for batch in mappings.chunks(Self::INSERT_BATCH_SIZE) {
+                imported += diesel::insert_into(institution_identifier_mapping::table)
+                    .values(batch)
+                    .execute(connection)?;
+            }
+            Ok(imported)
+        })
+    }
 }
 
 fn institution_linked_publisher_ids(
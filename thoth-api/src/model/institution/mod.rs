@@ -1,11 +1,14 @@
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt;
+use std::str::FromStr;
 use strum::Display;
 use strum::EnumString;
+use thoth_errors::{ThothError, ThothResult};
 use uuid::Uuid;
 
 use crate::graphql::utils::Direction;
+use crate::model::identifier::{standardise_identifier, FunderId, Isni, Ringgold};
 use crate::model::Doi;
 use crate::model::Ror;
 use crate::model::Timestamp;
@@ -13,6 +16,11 @@ use crate::model::Timestamp;
 use crate::schema::institution;
 #[cfg(feature = "backend")]
 use crate::schema::institution_history;
+#[cfg(feature = "backend")]
+use crate::schema::institution_identifier_mapping;
+
+/// DOI prefix under which the Crossref Funder Registry assigns its identifiers
+pub const FUNDER_REGISTRY_DOI_PREFIX: &str = "10.13039/";
 
 #[cfg_attr(
     feature = "backend",
@@ -48,6 +56,9 @@ pub struct Institution {
     pub updated_at: Timestamp,
     pub ror: Option<Ror>,
     pub country_code: Option<CountryCode>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
 }
 
 #[cfg_attr(
@@ -61,6 +72,9 @@ pub struct NewInstitution {
     pub institution_doi: Option<Doi>,
     pub ror: Option<Ror>,
     pub country_code: Option<CountryCode>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
 }
 
 #[cfg_attr(
@@ -75,6 +89,9 @@ pub struct PatchInstitution {
     pub institution_doi: Option<Doi>,
     pub ror: Option<Ror>,
     pub country_code: Option<CountryCode>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
 }
 
 #[cfg_attr(
@@ -865,6 +882,288 @@ pub enum CountryCode {
     Zwe,
 }
 
+impl CountryCode {
+    /// Two-letter ISO 3166-1 code of the country, as required by ONIX
+    pub fn alpha_2(&self) -> &'static str {
+        match self {
+            CountryCode::Afg => "AF",
+            CountryCode::Ala => "AX",
+            CountryCode::Alb => "AL",
+            CountryCode::Dza => "DZ",
+            CountryCode::Asm => "AS",
+            CountryCode::And => "AD",
+            CountryCode::Ago => "AO",
+            CountryCode::Aia => "AI",
+            CountryCode::Ata => "AQ",
+            CountryCode::Atg => "AG",
+            CountryCode::Arg => "AR",
+            CountryCode::Arm => "AM",
+            CountryCode::Abw => "AW",
+            CountryCode::Aus => "AU",
+            CountryCode::Aut => "AT",
+            CountryCode::Aze => "AZ",
+            CountryCode::Bhs => "BS",
+            CountryCode::Bhr => "BH",
+            CountryCode::Bgd => "BD",
+            CountryCode::Brb => "BB",
+            CountryCode::Blr => "BY",
+            CountryCode::Bel => "BE",
+            CountryCode::Blz => "BZ",
+            CountryCode::Ben => "BJ",
+            CountryCode::Bmu => "BM",
+            CountryCode::Btn => "BT",
+            CountryCode::Bol => "BO",
+            CountryCode::Bes => "BQ",
+            CountryCode::Bih => "BA",
+            CountryCode::Bwa => "BW",
+            CountryCode::Bvt => "BV",
+            CountryCode::Bra => "BR",
+            CountryCode::Iot => "IO",
+            CountryCode::Brn => "BN",
+            CountryCode::Bgr => "BG",
+            CountryCode::Bfa => "BF",
+            CountryCode::Bdi => "BI",
+            CountryCode::Cpv => "CV",
+            CountryCode::Khm => "KH",
+            CountryCode::Cmr => "CM",
+            CountryCode::Can => "CA",
+            CountryCode::Cym => "KY",
+            CountryCode::Caf => "CF",
+            CountryCode::Tcd => "TD",
+            CountryCode::Chl => "CL",
+            CountryCode::Chn => "CN",
+            CountryCode::Cxr => "CX",
+            CountryCode::Cck => "CC",
+            CountryCode::Col => "CO",
+            CountryCode::Com => "KM",
+            CountryCode::Cok => "CK",
+            CountryCode::Cri => "CR",
+            CountryCode::Civ => "CI",
+            CountryCode::Hrv => "HR",
+            CountryCode::Cub => "CU",
+            CountryCode::Cuw => "CW",
+            CountryCode::Cyp => "CY",
+            CountryCode::Cze => "CZ",
+            CountryCode::Cod => "CD",
+            CountryCode::Dnk => "DK",
+            CountryCode::Dji => "DJ",
+            CountryCode::Dma => "DM",
+            CountryCode::Dom => "DO",
+            CountryCode::Ecu => "EC",
+            CountryCode::Egy => "EG",
+            CountryCode::Slv => "SV",
+            CountryCode::Gnq => "GQ",
+            CountryCode::Eri => "ER",
+            CountryCode::Est => "EE",
+            CountryCode::Swz => "SZ",
+            CountryCode::Eth => "ET",
+            CountryCode::Flk => "FK",
+            CountryCode::Fro => "FO",
+            CountryCode::Fji => "FJ",
+            CountryCode::Fin => "FI",
+            CountryCode::Fra => "FR",
+            CountryCode::Guf => "GF",
+            CountryCode::Pyf => "PF",
+            CountryCode::Atf => "TF",
+            CountryCode::Gab => "GA",
+            CountryCode::Gmb => "GM",
+            CountryCode::Geo => "GE",
+            CountryCode::Deu => "DE",
+            CountryCode::Gha => "GH",
+            CountryCode::Gib => "GI",
+            CountryCode::Grc => "GR",
+            CountryCode::Grl => "GL",
+            CountryCode::Grd => "GD",
+            CountryCode::Glp => "GP",
+            CountryCode::Gum => "GU",
+            CountryCode::Gtm => "GT",
+            CountryCode::Ggy => "GG",
+            CountryCode::Gin => "GN",
+            CountryCode::Gnb => "GW",
+            CountryCode::Guy => "GY",
+            CountryCode::Hti => "HT",
+            CountryCode::Hmd => "HM",
+            CountryCode::Hnd => "HN",
+            CountryCode::Hkg => "HK",
+            CountryCode::Hun => "HU",
+            CountryCode::Isl => "IS",
+            CountryCode::Ind => "IN",
+            CountryCode::Idn => "ID",
+            CountryCode::Irn => "IR",
+            CountryCode::Irq => "IQ",
+            CountryCode::Irl => "IE",
+            CountryCode::Imn => "IM",
+            CountryCode::Isr => "IL",
+            CountryCode::Ita => "IT",
+            CountryCode::Jam => "JM",
+            CountryCode::Jpn => "JP",
+            CountryCode::Jey => "JE",
+            CountryCode::Jor => "JO",
+            CountryCode::Kaz => "KZ",
+            CountryCode::Ken => "KE",
+            CountryCode::Kir => "KI",
+            CountryCode::Kwt => "KW",
+            CountryCode::Kgz => "KG",
+            CountryCode::Lao => "LA",
+            CountryCode::Lva => "LV",
+            CountryCode::Lbn => "LB",
+            CountryCode::Lso => "LS",
+            CountryCode::Lbr => "LR",
+            CountryCode::Lby => "LY",
+            CountryCode::Lie => "LI",
+            CountryCode::Ltu => "LT",
+            CountryCode::Lux => "LU",
+            CountryCode::Mac => "MO",
+            CountryCode::Mdg => "MG",
+            CountryCode::Mwi => "MW",
+            CountryCode::Mys => "MY",
+            CountryCode::Mdv => "MV",
+            CountryCode::Mli => "ML",
+            CountryCode::Mlt => "MT",
+            CountryCode::Mhl => "MH",
+            CountryCode::Mtq => "MQ",
+            CountryCode::Mrt => "MR",
+            CountryCode::Mus => "MU",
+            CountryCode::Myt => "YT",
+            CountryCode::Mex => "MX",
+            CountryCode::Fsm => "FM",
+            CountryCode::Mda => "MD",
+            CountryCode::Mco => "MC",
+            CountryCode::Mng => "MN",
+            CountryCode::Mne => "ME",
+            CountryCode::Msr => "MS",
+            CountryCode::Mar => "MA",
+            CountryCode::Moz => "MZ",
+            CountryCode::Mmr => "MM",
+            CountryCode::Nam => "NA",
+            CountryCode::Nru => "NR",
+            CountryCode::Npl => "NP",
+            CountryCode::Nld => "NL",
+            CountryCode::Ncl => "NC",
+            CountryCode::Nzl => "NZ",
+            CountryCode::Nic => "NI",
+            CountryCode::Ner => "NE",
+            CountryCode::Nga => "NG",
+            CountryCode::Niu => "NU",
+            CountryCode::Nfk => "NF",
+            CountryCode::Prk => "KP",
+            CountryCode::Mkd => "MK",
+            CountryCode::Mnp => "MP",
+            CountryCode::Nor => "NO",
+            CountryCode::Omn => "OM",
+            CountryCode::Pak => "PK",
+            CountryCode::Plw => "PW",
+            CountryCode::Pse => "PS",
+            CountryCode::Pan => "PA",
+            CountryCode::Png => "PG",
+            CountryCode::Pry => "PY",
+            CountryCode::Per => "PE",
+            CountryCode::Phl => "PH",
+            CountryCode::Pcn => "PN",
+            CountryCode::Pol => "PL",
+            CountryCode::Prt => "PT",
+            CountryCode::Pri => "PR",
+            CountryCode::Qat => "QA",
+            CountryCode::Cog => "CG",
+            CountryCode::Reu => "RE",
+            CountryCode::Rou => "RO",
+            CountryCode::Rus => "RU",
+            CountryCode::Rwa => "RW",
+            CountryCode::Blm => "BL",
+            CountryCode::Shn => "SH",
+            CountryCode::Kna => "KN",
+            CountryCode::Lca => "LC",
+            CountryCode::Maf => "MF",
+            CountryCode::Spm => "PM",
+            CountryCode::Vct => "VC",
+            CountryCode::Wsm => "WS",
+            CountryCode::Smr => "SM",
+            CountryCode::Stp => "ST",
+            CountryCode::Sau => "SA",
+            CountryCode::Sen => "SN",
+            CountryCode::Srb => "RS",
+            CountryCode::Syc => "SC",
+            CountryCode::Sle => "SL",
+            CountryCode::Sgp => "SG",
+            CountryCode::Sxm => "SX",
+            CountryCode::Svk => "SK",
+            CountryCode::Svn => "SI",
+            CountryCode::Slb => "SB",
+            CountryCode::Som => "SO",
+            CountryCode::Zaf => "ZA",
+            CountryCode::Sgs => "GS",
+            CountryCode::Kor => "KR",
+            CountryCode::Ssd => "SS",
+            CountryCode::Esp => "ES",
+            CountryCode::Lka => "LK",
+            CountryCode::Sdn => "SD",
+            CountryCode::Sur => "SR",
+            CountryCode::Sjm => "SJ",
+            CountryCode::Swe => "SE",
+            CountryCode::Che => "CH",
+            CountryCode::Syr => "SY",
+            CountryCode::Twn => "TW",
+            CountryCode::Tjk => "TJ",
+            CountryCode::Tza => "TZ",
+            CountryCode::Tha => "TH",
+            CountryCode::Tls => "TL",
+            CountryCode::Tgo => "TG",
+            CountryCode::Tkl => "TK",
+            CountryCode::Ton => "TO",
+            CountryCode::Tto => "TT",
+            CountryCode::Tun => "TN",
+            CountryCode::Tur => "TR",
+            CountryCode::Tkm => "TM",
+            CountryCode::Tca => "TC",
+            CountryCode::Tuv => "TV",
+            CountryCode::Uga => "UG",
+            CountryCode::Ukr => "UA",
+            CountryCode::Are => "AE",
+            CountryCode::Gbr => "GB",
+            CountryCode::Umi => "UM",
+            CountryCode::Usa => "US",
+            CountryCode::Ury => "UY",
+            CountryCode::Uzb => "UZ",
+            CountryCode::Vut => "VU",
+            CountryCode::Vat => "VA",
+            CountryCode::Ven => "VE",
+            CountryCode::Vnm => "VN",
+            CountryCode::Vgb => "VG",
+            CountryCode::Vir => "VI",
+            CountryCode::Wlf => "WF",
+            CountryCode::Esh => "EH",
+            CountryCode::Yem => "YE",
+            CountryCode::Zmb => "ZM",
+            CountryCode::Zwe => "ZW",
+        }
+    }
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionIdentifierMapping {
+    pub institution_identifier_mapping_id: Uuid,
+    pub ror: Option<Ror>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
+    pub created_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = institution_identifier_mapping)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NewInstitutionIdentifierMapping {
+    pub ror: Option<Ror>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
+}
+
 #[cfg_attr(feature = "backend", derive(Queryable))]
 pub struct InstitutionHistory {
     pub institution_history_id: Uuid,
@@ -896,6 +1195,101 @@ pub struct InstitutionOrderBy {
     pub direction: Direction,
 }
 
+impl Institution {
+    /// The Crossref Funder Registry identifier implied by a 10.13039 institution DOI
+    pub fn funder_id_from_doi(&self) -> Option<FunderId> {
+        self.institution_doi
+            .as_ref()
+            .map(|doi| doi.to_string())
+            .filter(|doi| doi.starts_with(FUNDER_REGISTRY_DOI_PREFIX))
+            .and_then(|doi| FunderId::from_str(&doi).ok())
+    }
+}
+
+impl NewInstitution {
+    /// Validate the institution's identifiers, replacing them with their standardised forms
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.crossref_funder_id = standardise_identifier(self.crossref_funder_id)?;
+        self.isni = standardise_identifier(self.isni)?;
+        self.ringgold = standardise_identifier(self.ringgold)?;
+        Ok(self)
+    }
+}
+
+impl PatchInstitution {
+    /// Validate the institution's identifiers, replacing them with their standardised forms
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.crossref_funder_id = standardise_identifier(self.crossref_funder_id)?;
+        self.isni = standardise_identifier(self.isni)?;
+        self.ringgold = standardise_identifier(self.ringgold)?;
+        Ok(self)
+    }
+}
+
+impl NewInstitutionIdentifierMapping {
+    const COLUMNS: [&'static str; 4] = ["ror", "crossref_funder_id", "isni", "ringgold"];
+
+    /// Parse a comma-separated mapping file with the header `ror,crossref_funder_id,isni,ringgold`.
+    ///
+    /// Each subsequent line maps identifiers of the same institution; empty values are allowed,
+    /// but a line must relate at least two identifiers. Blank lines are ignored.
+    pub fn parse_mapping_file(contents: &str) -> ThothResult<Vec<Self>> {
+        let mut lines = contents.lines().enumerate();
+        let header = lines
+            .next()
+            .map(|(_, header)| header.trim().trim_start_matches('\u{feff}'))
+            .unwrap_or_default();
+        let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+        if columns != Self::COLUMNS {
+            return Err(ThothError::IdentifierMappingError(
+                1,
+                format!("expected header \"{}\"", Self::COLUMNS.join(",")),
+            ));
+        }
+        lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                Self::parse_line(line).map_err(|e| ThothError::IdentifierMappingError(index + 1, e))
+            })
+            .collect()
+    }
+
+    fn parse_line(line: &str) -> Result<Self, String> {
+        fn parse<T: FromStr<Err = ThothError>>(value: Option<&str>) -> Result<Option<T>, String> {
+            value
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(T::from_str)
+                .transpose()
+                .map_err(|e| e.to_string())
+        }
+        let values: Vec<&str> = line.split(',').collect();
+        if values.len() != Self::COLUMNS.len() {
+            return Err(format!(
+                "expected {} values, found {}",
+                Self::COLUMNS.len(),
+                values.len()
+            ));
+        }
+        let mapping = NewInstitutionIdentifierMapping {
+            ror: parse(values.first().copied())?,
+            crossref_funder_id: parse(values.get(1).copied())?,
+            isni: parse(values.get(2).copied())?,
+            ringgold: parse(values.get(3).copied())?,
+        };
+        let identifiers = [
+            mapping.ror.is_some(),
+            mapping.crossref_funder_id.is_some(),
+            mapping.isni.is_some(),
+            mapping.ringgold.is_some(),
+        ];
+        if identifiers.iter().filter(|present| **present).count() < 2 {
+            return Err("at least two identifiers are required".to_string());
+        }
+        Ok(mapping)
+    }
+}
+
 impl fmt::Display for Institution {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(ror) = &self.ror {
@@ -964,6 +1358,108 @@ fn test_institutionfield_fromstr() {
     assert!(InstitutionField::from_str("Fundings").is_err());
 }
 
+#[test]
+fn test_institution_funder_id_from_doi() {
+    let mut institution = Institution {
+        institution_doi: Some(Doi::from_str("https://doi.org/10.13039/501100000780").unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(
+        institution.funder_id_from_doi(),
+        Some(FunderId::from_str("501100000780").unwrap())
+    );
+    institution.institution_doi = Some(Doi::from_str("10.12345/501100000780").unwrap());
+    assert_eq!(institution.funder_id_from_doi(), None);
+    institution.institution_doi = None;
+    assert_eq!(institution.funder_id_from_doi(), None);
+}
+
+#[test]
+fn test_new_institution_standardise() {
+    // Identifiers arrive from the API exactly as they were entered
+    let raw = |value: &str| serde_json::Value::String(value.to_string());
+    let new_institution = |ringgold: &str| NewInstitution {
+        institution_name: "European Commission".to_string(),
+        institution_doi: None,
+        ror: None,
+        country_code: None,
+        crossref_funder_id: serde_json::from_value(raw("10.13039/501100000780")).unwrap(),
+        isni: serde_json::from_value(raw("0000 0001 2146 438X")).unwrap(),
+        ringgold: serde_json::from_value(raw(ringgold)).unwrap(),
+    };
+    let institution = new_institution("Ringgold: 6396").standardise().unwrap();
+    assert_eq!(
+        institution.crossref_funder_id.unwrap().to_string(),
+        "501100000780"
+    );
+    assert_eq!(institution.isni.unwrap().to_string(), "000000012146438X");
+    assert_eq!(institution.ringgold.unwrap().to_string(), "6396");
+    assert!(new_institution("06396").standardise().is_err());
+}
+
+#[test]
+fn test_parse_mapping_file() {
+    let contents = "ror,crossref_funder_id,isni,ringgold
+https://ror.org/00k4n6c32,501100000780,0000 0001 2146 438X,
+,100000001,,6396
+
+https://ror.org/021nxhr62,100000001,,
+";
+    let mappings = NewInstitutionIdentifierMapping::parse_mapping_file(contents).unwrap();
+    assert_eq!(mappings.len(), 3);
+    assert_eq!(
+        mappings[0],
+        NewInstitutionIdentifierMapping {
+            ror: Some(Ror::from_str("https://ror.org/00k4n6c32").unwrap()),
+            crossref_funder_id: Some(FunderId::from_str("501100000780").unwrap()),
+            isni: Some(Isni::from_str("000000012146438X").unwrap()),
+            ringgold: None,
+        }
+    );
+    assert_eq!(
+        mappings[1].ringgold,
+        Some(Ringgold::from_str("6396").unwrap())
+    );
+    assert!(mappings[2].isni.is_none());
+}
+
+#[test]
+fn test_parse_mapping_file_errors() {
+    let line_error =
+        |contents: &str| match NewInstitutionIdentifierMapping::parse_mapping_file(contents)
+            .unwrap_err()
+        {
+            ThothError::IdentifierMappingError(line, _) => line,
+            e => panic!("unexpected error {e}"),
+        };
+    assert_eq!(line_error("ror,isni\n"), 1);
+    assert_eq!(line_error(""), 1);
+    // Invalid ISNI check character
+    assert_eq!(
+        line_error("ror,crossref_funder_id,isni,ringgold\n,100000001,0000000121626731,\n"),
+        2
+    );
+    // Only one identifier
+    assert_eq!(
+        line_error("ror,crossref_funder_id,isni,ringgold\n,100000001,,6396\n\n,100000001,,\n"),
+        4
+    );
+    // Wrong number of values
+    assert_eq!(
+        line_error("ror,crossref_funder_id,isni,ringgold\n,100000001,6396\n"),
+        2
+    );
+}
+
+#[test]
+fn test_countrycode_alpha_2() {
+    assert_eq!(CountryCode::Afg.alpha_2(), "AF");
+    assert_eq!(CountryCode::Ala.alpha_2(), "AX");
+    assert_eq!(CountryCode::Gbr.alpha_2(), "GB");
+    assert_eq!(CountryCode::Mda.alpha_2(), "MD");
+    assert_eq!(CountryCode::Zwe.alpha_2(), "ZW");
+}
+
 #[test]
 fn test_countrycode_display() {
     assert_eq!(format!("{}", CountryCode::Afg), "Afghanistan");
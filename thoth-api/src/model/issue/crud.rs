@@ -65,6 +65,18 @@ impl Crud for Issue {
                 Direction::Asc => query.order(updated_at.asc()),
                 Direction::Desc => query.order(updated_at.desc()),
             },
+            IssueField::Volume => match order.direction {
+                Direction::Asc => query.order(volume.asc()),
+                Direction::Desc => query.order(volume.desc()),
+            },
+            IssueField::IssueNumber => match order.direction {
+                Direction::Asc => query.order(issue_number.asc()),
+                Direction::Desc => query.order(issue_number.desc()),
+            },
+            IssueField::Part => match order.direction {
+                Direction::Asc => query.order(part.asc()),
+                Direction::Desc => query.order(part.desc()),
+            },
         };
         if !publishers.is_empty() {
             query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
@@ -20,6 +20,9 @@ pub enum IssueField {
     IssueOrdinal,
     CreatedAt,
     UpdatedAt,
+    Volume,
+    IssueNumber,
+    Part,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -32,6 +35,9 @@ pub struct Issue {
     pub issue_ordinal: i32,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+    pub part: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -41,6 +47,9 @@ pub struct IssueWithSeries {
     pub work_id: Uuid,
     pub series_id: Uuid,
     pub issue_ordinal: i32,
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+    pub part: Option<String>,
     pub series: SeriesWithImprint,
 }
 
@@ -54,6 +63,9 @@ pub struct NewIssue {
     pub series_id: Uuid,
     pub work_id: Uuid,
     pub issue_ordinal: i32,
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+    pub part: Option<String>,
 }
 
 #[cfg_attr(
@@ -67,6 +79,9 @@ pub struct PatchIssue {
     pub series_id: Uuid,
     pub work_id: Uuid,
     pub issue_ordinal: i32,
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+    pub part: Option<String>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -92,6 +107,9 @@ impl Default for IssueWithSeries {
             work_id: Default::default(),
             series_id: Default::default(),
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: Default::default(),
         }
     }
@@ -1181,6 +1181,199 @@ impl Default for Language {
     }
 }
 
+impl LanguageCode {
+    /// Two-letter ISO 639-1 code of the language, for those languages which have one
+    pub fn iso_639_1(&self) -> Option<&'static str> {
+        match self {
+            LanguageCode::Aar => Some("aa"),
+            LanguageCode::Abk => Some("ab"),
+            LanguageCode::Afr => Some("af"),
+            LanguageCode::Aka => Some("ak"),
+            LanguageCode::Alb => Some("sq"),
+            LanguageCode::Amh => Some("am"),
+            LanguageCode::Ara => Some("ar"),
+            LanguageCode::Arg => Some("an"),
+            LanguageCode::Arm => Some("hy"),
+            LanguageCode::Asm => Some("as"),
+            LanguageCode::Ava => Some("av"),
+            LanguageCode::Ave => Some("ae"),
+            LanguageCode::Aym => Some("ay"),
+            LanguageCode::Aze => Some("az"),
+            LanguageCode::Bak => Some("ba"),
+            LanguageCode::Bam => Some("bm"),
+            LanguageCode::Baq => Some("eu"),
+            LanguageCode::Bel => Some("be"),
+            LanguageCode::Ben => Some("bn"),
+            LanguageCode::Bih => Some("bh"),
+            LanguageCode::Bis => Some("bi"),
+            LanguageCode::Bos => Some("bs"),
+            LanguageCode::Bre => Some("br"),
+            LanguageCode::Bul => Some("bg"),
+            LanguageCode::Bur => Some("my"),
+            LanguageCode::Cat => Some("ca"),
+            LanguageCode::Cha => Some("ch"),
+            LanguageCode::Che => Some("ce"),
+            LanguageCode::Chi => Some("zh"),
+            LanguageCode::Chu => Some("cu"),
+            LanguageCode::Chv => Some("cv"),
+            LanguageCode::Cor => Some("kw"),
+            LanguageCode::Cos => Some("co"),
+            LanguageCode::Cre => Some("cr"),
+            LanguageCode::Cze => Some("cs"),
+            LanguageCode::Dan => Some("da"),
+            LanguageCode::Div => Some("dv"),
+            LanguageCode::Dut => Some("nl"),
+            LanguageCode::Dzo => Some("dz"),
+            LanguageCode::Eng => Some("en"),
+            LanguageCode::Epo => Some("eo"),
+            LanguageCode::Est => Some("et"),
+            LanguageCode::Ewe => Some("ee"),
+            LanguageCode::Fao => Some("fo"),
+            LanguageCode::Fij => Some("fj"),
+            LanguageCode::Fin => Some("fi"),
+            LanguageCode::Fre => Some("fr"),
+            LanguageCode::Fry => Some("fy"),
+            LanguageCode::Ful => Some("ff"),
+            LanguageCode::Geo => Some("ka"),
+            LanguageCode::Ger => Some("de"),
+            LanguageCode::Gla => Some("gd"),
+            LanguageCode::Gle => Some("ga"),
+            LanguageCode::Glg => Some("gl"),
+            LanguageCode::Glv => Some("gv"),
+            LanguageCode::Gre => Some("el"),
+            LanguageCode::Grn => Some("gn"),
+            LanguageCode::Guj => Some("gu"),
+            LanguageCode::Hat => Some("ht"),
+            LanguageCode::Hau => Some("ha"),
+            LanguageCode::Heb => Some("he"),
+            LanguageCode::Her => Some("hz"),
+            LanguageCode::Hin => Some("hi"),
+            LanguageCode::Hmo => Some("ho"),
+            LanguageCode::Hrv => Some("hr"),
+            LanguageCode::Hun => Some("hu"),
+            LanguageCode::Ibo => Some("ig"),
+            LanguageCode::Ice => Some("is"),
+            LanguageCode::Ido => Some("io"),
+            LanguageCode::Iii => Some("ii"),
+            LanguageCode::Iku => Some("iu"),
+            LanguageCode::Ile => Some("ie"),
+            LanguageCode::Ina => Some("ia"),
+            LanguageCode::Ind => Some("id"),
+            LanguageCode::Ipk => Some("ik"),
+            LanguageCode::Ita => Some("it"),
+            LanguageCode::Jav => Some("jv"),
+            LanguageCode::Jpn => Some("ja"),
+            LanguageCode::Kal => Some("kl"),
+            LanguageCode::Kan => Some("kn"),
+            LanguageCode::Kas => Some("ks"),
+            LanguageCode::Kau => Some("kr"),
+            LanguageCode::Kaz => Some("kk"),
+            LanguageCode::Khm => Some("km"),
+            LanguageCode::Kik => Some("ki"),
+            LanguageCode::Kin => Some("rw"),
+            LanguageCode::Kir => Some("ky"),
+            LanguageCode::Kom => Some("kv"),
+            LanguageCode::Kon => Some("kg"),
+            LanguageCode::Kor => Some("ko"),
+            LanguageCode::Kua => Some("kj"),
+            LanguageCode::Kur => Some("ku"),
+            LanguageCode::Lao => Some("lo"),
+            LanguageCode::Lat => Some("la"),
+            LanguageCode::Lav => Some("lv"),
+            LanguageCode::Lim => Some("li"),
+            LanguageCode::Lin => Some("ln"),
+            LanguageCode::Lit => Some("lt"),
+            LanguageCode::Ltz => Some("lb"),
+            LanguageCode::Lub => Some("lu"),
+            LanguageCode::Lug => Some("lg"),
+            LanguageCode::Mac => Some("mk"),
+            LanguageCode::Mah => Some("mh"),
+            LanguageCode::Mal => Some("ml"),
+            LanguageCode::Mao => Some("mi"),
+            LanguageCode::Mar => Some("mr"),
+            LanguageCode::May => Some("ms"),
+            LanguageCode::Mlg => Some("mg"),
+            LanguageCode::Mlt => Some("mt"),
+            LanguageCode::Mon => Some("mn"),
+            LanguageCode::Nau => Some("na"),
+            LanguageCode::Nav => Some("nv"),
+            LanguageCode::Nbl => Some("nr"),
+            LanguageCode::Nde => Some("nd"),
+            LanguageCode::Ndo => Some("ng"),
+            LanguageCode::Nep => Some("ne"),
+            LanguageCode::Nno => Some("nn"),
+            LanguageCode::Nob => Some("nb"),
+            LanguageCode::Nor => Some("no"),
+            LanguageCode::Nya => Some("ny"),
+            LanguageCode::Oci => Some("oc"),
+            LanguageCode::Oji => Some("oj"),
+            LanguageCode::Ori => Some("or"),
+            LanguageCode::Orm => Some("om"),
+            LanguageCode::Oss => Some("os"),
+            LanguageCode::Pan => Some("pa"),
+            LanguageCode::Per => Some("fa"),
+            LanguageCode::Pli => Some("pi"),
+            LanguageCode::Pol => Some("pl"),
+            LanguageCode::Por => Some("pt"),
+            LanguageCode::Pus => Some("ps"),
+            LanguageCode::Que => Some("qu"),
+            LanguageCode::Roh => Some("rm"),
+            LanguageCode::Rum => Some("ro"),
+            LanguageCode::Run => Some("rn"),
+            LanguageCode::Rus => Some("ru"),
+            LanguageCode::Sag => Some("sg"),
+            LanguageCode::San => Some("sa"),
+            LanguageCode::Sin => Some("si"),
+            LanguageCode::Slo => Some("sk"),
+            LanguageCode::Slv => Some("sl"),
+            LanguageCode::Sme => Some("se"),
+            LanguageCode::Smo => Some("sm"),
+            LanguageCode::Sna => Some("sn"),
+            LanguageCode::Snd => Some("sd"),
+            LanguageCode::Som => Some("so"),
+            LanguageCode::Sot => Some("st"),
+            LanguageCode::Spa => Some("es"),
+            LanguageCode::Srd => Some("sc"),
+            LanguageCode::Srp => Some("sr"),
+            LanguageCode::Ssw => Some("ss"),
+            LanguageCode::Sun => Some("su"),
+            LanguageCode::Swa => Some("sw"),
+            LanguageCode::Swe => Some("sv"),
+            LanguageCode::Tah => Some("ty"),
+            LanguageCode::Tam => Some("ta"),
+            LanguageCode::Tat => Some("tt"),
+            LanguageCode::Tel => Some("te"),
+            LanguageCode::Tgk => Some("tg"),
+            LanguageCode::Tgl => Some("tl"),
+            LanguageCode::Tha => Some("th"),
+            LanguageCode::Tib => Some("bo"),
+            LanguageCode::Tir => Some("ti"),
+            LanguageCode::Ton => Some("to"),
+            LanguageCode::Tsn => Some("tn"),
+            LanguageCode::Tso => Some("ts"),
+            LanguageCode::Tuk => Some("tk"),
+            LanguageCode::Tur => Some("tr"),
+            LanguageCode::Twi => Some("tw"),
+            LanguageCode::Uig => Some("ug"),
+            LanguageCode::Ukr => Some("uk"),
+            LanguageCode::Urd => Some("ur"),
+            LanguageCode::Uzb => Some("uz"),
+            LanguageCode::Ven => Some("ve"),
+            LanguageCode::Vie => Some("vi"),
+            LanguageCode::Vol => Some("vo"),
+            LanguageCode::Wel => Some("cy"),
+            LanguageCode::Wln => Some("wa"),
+            LanguageCode::Wol => Some("wo"),
+            LanguageCode::Xho => Some("xh"),
+            LanguageCode::Yid => Some("yi"),
+            LanguageCode::Yor => Some("yo"),
+            LanguageCode::Zha => Some("za"),
+            LanguageCode::Zul => Some("zu"),
+            _ => None,
+        }
+    }
+}
+
 #[test]
 fn test_languagecode_default() {
     let langcode: LanguageCode = Default::default();
@@ -1716,6 +1909,16 @@ fn test_languagerelation_fromstr() {
     assert!(LanguageRelation::from_str("Invented").is_err());
 }
 
+#[test]
+fn test_languagecode_iso_639_1() {
+    assert_eq!(LanguageCode::Eng.iso_639_1(), Some("en"));
+    assert_eq!(LanguageCode::Fre.iso_639_1(), Some("fr"));
+    assert_eq!(LanguageCode::Ger.iso_639_1(), Some("de"));
+    assert_eq!(LanguageCode::Chi.iso_639_1(), Some("zh"));
+    assert_eq!(LanguageCode::Ace.iso_639_1(), None);
+    assert_eq!(LanguageCode::Zxx.iso_639_1(), None);
+}
+
 #[test]
 fn test_languagecode_fromstr() {
     use std::str::FromStr;
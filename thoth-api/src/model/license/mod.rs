@@ -0,0 +1,651 @@
+use phf::phf_map;
+use phf::phf_set;
+use std::str::FromStr;
+use thoth_errors::{ThothError, ThothResult};
+
+pub const CC_DOMAIN: &str = "https://creativecommons.org/";
+
+/// Licence versions published by Creative Commons. Version 2.1 only exists as jurisdiction ports.
+const CC_VERSIONS: [&str; 6] = ["1.0", "2.0", "2.1", "2.5", "3.0", "4.0"];
+
+/// Jurisdictions to which Creative Commons licences (up to version 3.0) have been ported
+static CC_PORTS: phf::Map<&'static str, &'static str> = phf_map! {
+    "am" => "Armenia",
+    "ar" => "Argentina",
+    "at" => "Austria",
+    "au" => "Australia",
+    "az" => "Azerbaijan",
+    "be" => "Belgium",
+    "bg" => "Bulgaria",
+    "br" => "Brazil",
+    "ca" => "Canada",
+    "ch" => "Switzerland",
+    "cl" => "Chile",
+    "cn" => "China Mainland",
+    "co" => "Colombia",
+    "cr" => "Costa Rica",
+    "cz" => "Czech Republic",
+    "de" => "Germany",
+    "dk" => "Denmark",
+    "ec" => "Ecuador",
+    "ee" => "Estonia",
+    "eg" => "Egypt",
+    "es" => "Spain",
+    "fi" => "Finland",
+    "fr" => "France",
+    "ge" => "Georgia",
+    "gr" => "Greece",
+    "gt" => "Guatemala",
+    "hk" => "Hong Kong",
+    "hr" => "Croatia",
+    "hu" => "Hungary",
+    "ie" => "Ireland",
+    "igo" => "IGO",
+    "il" => "Israel",
+    "in" => "India",
+    "it" => "Italy",
+    "jp" => "Japan",
+    "kr" => "Korea",
+    "lu" => "Luxembourg",
+    "mk" => "Macedonia",
+    "mt" => "Malta",
+    "mx" => "Mexico",
+    "my" => "Malaysia",
+    "nl" => "Netherlands",
+    "no" => "Norway",
+    "nz" => "New Zealand",
+    "pe" => "Peru",
+    "ph" => "Philippines",
+    "pl" => "Poland",
+    "pr" => "Puerto Rico",
+    "pt" => "Portugal",
+    "ro" => "Romania",
+    "rs" => "Serbia",
+    "scotland" => "Scotland",
+    "se" => "Sweden",
+    "sg" => "Singapore",
+    "si" => "Slovenia",
+    "th" => "Thailand",
+    "tw" => "Taiwan",
+    "ug" => "Uganda",
+    "uk" => "England and Wales",
+    "us" => "United States",
+    "ve" => "Venezuela",
+    "vn" => "Vietnam",
+    "za" => "South Africa",
+};
+
+/// Ported Creative Commons licences which have their own SPDX identifier
+static SPDX_CC_PORTS: phf::Set<&'static str> = phf_set! {
+    "CC-BY-2.5-AU",
+    "CC-BY-3.0-AT",
+    "CC-BY-3.0-AU",
+    "CC-BY-3.0-DE",
+    "CC-BY-3.0-IGO",
+    "CC-BY-3.0-NL",
+    "CC-BY-3.0-US",
+    "CC-BY-NC-3.0-DE",
+    "CC-BY-NC-ND-3.0-DE",
+    "CC-BY-NC-ND-3.0-IGO",
+    "CC-BY-NC-SA-2.0-DE",
+    "CC-BY-NC-SA-2.0-FR",
+    "CC-BY-NC-SA-2.0-UK",
+    "CC-BY-NC-SA-3.0-DE",
+    "CC-BY-NC-SA-3.0-IGO",
+    "CC-BY-ND-3.0-DE",
+    "CC-BY-SA-2.0-UK",
+    "CC-BY-SA-2.1-JP",
+    "CC-BY-SA-3.0-AT",
+    "CC-BY-SA-3.0-DE",
+    "CC-BY-SA-3.0-IGO",
+};
+
+#[derive(Debug, PartialEq, Eq)]
+/// An open licence which is not published by Creative Commons
+pub struct RegisteredLicense {
+    pub spdx_id: &'static str,
+    pub name: &'static str,
+    pub url: &'static str,
+}
+
+static OTHER_LICENSES: [RegisteredLicense; 3] = [
+    RegisteredLicense {
+        spdx_id: "OGL-UK-3.0",
+        name: "Open Government Licence v3.0",
+        url: "https://www.nationalarchives.gov.uk/doc/open-government-licence/version/3/",
+    },
+    RegisteredLicense {
+        spdx_id: "MIT",
+        name: "MIT License",
+        url: "https://opensource.org/licenses/MIT",
+    },
+    RegisteredLicense {
+        spdx_id: "Apache-2.0",
+        name: "Apache License 2.0",
+        url: "https://www.apache.org/licenses/LICENSE-2.0",
+    },
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CcRights {
+    By,
+    BySa,
+    ByNd,
+    ByNc,
+    ByNcSa,
+    ByNcNd,
+}
+
+impl CcRights {
+    fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "by" => Some(CcRights::By),
+            "by-sa" => Some(CcRights::BySa),
+            "by-nd" => Some(CcRights::ByNd),
+            "by-nc" => Some(CcRights::ByNc),
+            "by-nc-sa" => Some(CcRights::ByNcSa),
+            // Version 1.0 of the licence used a different ordering in its URL
+            "by-nc-nd" | "by-nd-nc" => Some(CcRights::ByNcNd),
+            _ => None,
+        }
+    }
+
+    /// Licence elements as used in URLs, e.g. `by-nc-sa`
+    pub fn code(&self) -> &'static str {
+        match self {
+            CcRights::By => "by",
+            CcRights::BySa => "by-sa",
+            CcRights::ByNd => "by-nd",
+            CcRights::ByNc => "by-nc",
+            CcRights::ByNcSa => "by-nc-sa",
+            CcRights::ByNcNd => "by-nc-nd",
+        }
+    }
+
+    pub fn full_text(&self) -> &'static str {
+        match self {
+            CcRights::By => "Attribution",
+            CcRights::BySa => "Attribution-ShareAlike",
+            CcRights::ByNd => "Attribution-NoDerivatives",
+            CcRights::ByNc => "Attribution-NonCommercial",
+            CcRights::ByNcSa => "Attribution-NonCommercial-ShareAlike",
+            CcRights::ByNcNd => "Attribution-NonCommercial-NoDerivatives",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A licence accepted for works: either one from the controlled vocabulary,
+/// or a custom open licence identified only by its URL
+pub enum License {
+    CreativeCommons {
+        rights: CcRights,
+        version: &'static str,
+        port: Option<&'static str>,
+    },
+    Cc0,
+    PublicDomainMark,
+    Other(&'static RegisteredLicense),
+    Custom(String),
+}
+
+impl License {
+    fn creative_commons(rights: &str, version: &str, port: Option<&str>) -> Option<Self> {
+        let rights = CcRights::from_code(rights)?;
+        let version = *CC_VERSIONS.iter().find(|v| **v == version)?;
+        let port = match port {
+            // International licences have not been ported
+            Some(_) if version == "4.0" => return None,
+            Some(port) => Some(*CC_PORTS.get_key(port)?),
+            None if version == "2.1" => return None,
+            None => None,
+        };
+        Some(License::CreativeCommons {
+            rights,
+            version,
+            port,
+        })
+    }
+
+    fn from_url(input: &str) -> Option<Self> {
+        let lowercase = input.to_lowercase();
+        let without_scheme = lowercase
+            .strip_prefix("https://")
+            .or_else(|| lowercase.strip_prefix("http://"))
+            .unwrap_or(&lowercase);
+        let without_www = without_scheme
+            .strip_prefix("www.")
+            .unwrap_or(without_scheme);
+        let path = without_www.split(['?', '#']).next().unwrap_or_default();
+        let mut segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        // Links to the legal code or a translated deed identify the same licence
+        if segments
+            .last()
+            .is_some_and(|s| s.starts_with("legalcode") || s.starts_with("deed"))
+        {
+            segments.pop();
+        }
+        match segments.as_slice() {
+            ["creativecommons.org", "licenses", rights, version] => {
+                License::creative_commons(rights, version, None)
+            }
+            ["creativecommons.org", "licenses", rights, version, port] => {
+                License::creative_commons(rights, version, Some(port))
+            }
+            ["creativecommons.org", "publicdomain", "zero", "1.0"] => Some(License::Cc0),
+            ["creativecommons.org", "publicdomain", "mark", "1.0"] => {
+                Some(License::PublicDomainMark)
+            }
+            // Malformed Creative Commons URLs are rejected rather than kept as custom licences
+            ["creativecommons.org", ..] => None,
+            _ => OTHER_LICENSES
+                .iter()
+                .find(|l| {
+                    l.url
+                        .to_lowercase()
+                        .trim_start_matches("https://")
+                        .trim_start_matches("www.")
+                        .trim_end_matches('/')
+                        == path.trim_end_matches('/')
+                })
+                .map(License::Other)
+                // Any other web address is taken to be a custom open licence
+                .or_else(|| {
+                    (without_scheme != lowercase).then(|| License::Custom(input.to_string()))
+                }),
+        }
+    }
+
+    fn from_spdx_id(input: &str) -> Option<Self> {
+        if input.eq_ignore_ascii_case("CC0-1.0") {
+            return Some(License::Cc0);
+        }
+        if let Some(license) = OTHER_LICENSES
+            .iter()
+            .find(|l| l.spdx_id.eq_ignore_ascii_case(input))
+        {
+            return Some(License::Other(license));
+        }
+        // Creative Commons identifiers take the form `CC-BY-NC-SA-3.0[-PORT]`
+        let lowercase = input.to_lowercase();
+        let elements = lowercase.strip_prefix("cc-")?;
+        let version_start = elements.find(|c: char| c.is_ascii_digit())?;
+        let rights = elements[..version_start].strip_suffix('-')?;
+        let (version, port) = match elements[version_start..].split_once('-') {
+            Some((version, port)) => (version, Some(port)),
+            None => (&elements[version_start..], None),
+        };
+        License::creative_commons(rights, version, port)
+    }
+
+    /// Whether the licence is part of the controlled vocabulary, rather than a custom licence
+    pub fn is_registered(&self) -> bool {
+        !matches!(self, License::Custom(_))
+    }
+
+    /// Canonical URL of the licence, e.g. `https://creativecommons.org/licenses/by/4.0/`
+    pub fn url(&self) -> String {
+        match self {
+            License::CreativeCommons {
+                rights,
+                version,
+                port,
+            } => {
+                let rights = match (rights, *version) {
+                    (CcRights::ByNcNd, "1.0") => "by-nd-nc",
+                    _ => rights.code(),
+                };
+                match port {
+                    Some(port) => format!("{CC_DOMAIN}licenses/{rights}/{version}/{port}/"),
+                    None => format!("{CC_DOMAIN}licenses/{rights}/{version}/"),
+                }
+            }
+            License::Cc0 => format!("{CC_DOMAIN}publicdomain/zero/1.0/"),
+            License::PublicDomainMark => format!("{CC_DOMAIN}publicdomain/mark/1.0/"),
+            License::Other(license) => license.url.to_string(),
+            License::Custom(url) => url.to_string(),
+        }
+    }
+
+    /// SPDX identifier of the licence, if SPDX defines one
+    pub fn spdx_id(&self) -> Option<String> {
+        match self {
+            License::CreativeCommons {
+                rights,
+                version,
+                port,
+            } => {
+                let id = format!("CC-{}-{}", rights.code().to_uppercase(), version);
+                match port {
+                    Some(port) => {
+                        let id = format!("{}-{}", id, port.to_uppercase());
+                        SPDX_CC_PORTS.contains(id.as_str()).then_some(id)
+                    }
+                    None => Some(id),
+                }
+            }
+            License::Cc0 => Some("CC0-1.0".to_string()),
+            License::PublicDomainMark => None,
+            License::Other(license) => Some(license.spdx_id.to_string()),
+            License::Custom(_) => None,
+        }
+    }
+
+    /// Abbreviated name of the licence, e.g. `CC BY-NC 3.0 DE`
+    pub fn short_name(&self) -> String {
+        match self {
+            License::CreativeCommons {
+                rights,
+                version,
+                port,
+            } => {
+                let short = format!("CC {} {}", rights.code().to_uppercase(), version);
+                match port {
+                    Some(port) => format!("{} {}", short, port.to_uppercase()),
+                    None => short,
+                }
+            }
+            License::Cc0 => "CC0 1.0".to_string(),
+            License::PublicDomainMark => "PDM 1.0".to_string(),
+            License::Other(license) => license.spdx_id.to_string(),
+            License::Custom(_) => "Custom".to_string(),
+        }
+    }
+
+    /// Full name of the licence, e.g.
+    /// `Creative Commons Attribution 4.0 International license (CC BY 4.0).`
+    pub fn description(&self) -> String {
+        match self {
+            License::CreativeCommons {
+                rights,
+                version,
+                port,
+            } => {
+                let nomenclature = match (port, *version) {
+                    (Some(port), _) => CC_PORTS.get(port).copied().unwrap_or_default(),
+                    (None, "4.0") => "International",
+                    (None, "3.0") => "Unported",
+                    (None, _) => "Generic",
+                };
+                format!(
+                    "Creative Commons {} {} {} license ({}).",
+                    rights.full_text(),
+                    version,
+                    nomenclature,
+                    self.short_name()
+                )
+            }
+            License::Cc0 => format!(
+                "Creative Commons CC0 1.0 Universal Public Domain Dedication ({}).",
+                self.short_name()
+            ),
+            License::PublicDomainMark => {
+                format!("Public Domain Mark 1.0 ({}).", self.short_name())
+            }
+            License::Other(license) => format!("{} ({}).", license.name, license.spdx_id),
+            License::Custom(_) => "custom license.".to_string(),
+        }
+    }
+}
+
+impl FromStr for License {
+    type Err = ThothError;
+
+    /// Parse a licence from its URL (in any of its common variants) or from its SPDX identifier
+    fn from_str(input: &str) -> ThothResult<License> {
+        let trimmed = input.trim();
+        let license = if trimmed.contains('/') {
+            License::from_url(trimmed)
+        } else {
+            License::from_spdx_id(trimmed)
+        };
+        license.ok_or_else(|| ThothError::LicenseParseError(input.to_string()))
+    }
+}
+
+/// Convert a licence URL or SPDX identifier to the canonical URL of the licence,
+/// rejecting anything that is neither in the controlled vocabulary nor a web address
+pub fn standardise_license(input: &str) -> ThothResult<String> {
+    License::from_str(input).map(|license| license.url())
+}
+
+/// Works are considered open access when they carry a licence, whether from the controlled
+/// vocabulary or a custom one
+pub fn is_open_access(license: Option<&str>) -> bool {
+    license.is_some_and(|l| License::from_str(l).is_ok())
+}
+
+#[cfg(feature = "backend")]
+/// Works whose licence is not in the controlled vocabulary, with the licence saved against them
+pub fn unregistered_licenses(db: &crate::db::PgPool) -> ThothResult<Vec<(uuid::Uuid, String)>> {
+    use crate::schema::work::dsl::{license, work, work_id};
+    use diesel::prelude::*;
+    let mut connection = db.get()?;
+    let licenses = work
+        .select((work_id, license.assume_not_null()))
+        .filter(license.is_not_null())
+        .order(work_id)
+        .load::<(uuid::Uuid, String)>(&mut connection)?;
+    Ok(licenses
+        .into_iter()
+        .filter(|(_, l)| !License::from_str(l).is_ok_and(|l| l.is_registered()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_license_from_url() {
+        let cc_by = License::CreativeCommons {
+            rights: CcRights::By,
+            version: "4.0",
+            port: None,
+        };
+        assert_eq!(
+            License::from_str("https://creativecommons.org/licenses/by/4.0/").unwrap(),
+            cc_by
+        );
+        assert_eq!(
+            License::from_str("http://www.creativecommons.org/licenses/by/4.0").unwrap(),
+            cc_by
+        );
+        assert_eq!(
+            License::from_str("https://creativecommons.org/licenses/by/4.0/legalcode").unwrap(),
+            cc_by
+        );
+        assert_eq!(
+            License::from_str("https://creativecommons.org/licenses/BY/4.0/deed.en").unwrap(),
+            cc_by
+        );
+        assert_eq!(
+            License::from_str("creativecommons.org/licenses/by-nc-nd/3.0/de/").unwrap(),
+            License::CreativeCommons {
+                rights: CcRights::ByNcNd,
+                version: "3.0",
+                port: Some("de"),
+            }
+        );
+        assert_eq!(
+            License::from_str("https://creativecommons.org/licenses/by-nd-nc/1.0/").unwrap(),
+            License::CreativeCommons {
+                rights: CcRights::ByNcNd,
+                version: "1.0",
+                port: None,
+            }
+        );
+        assert_eq!(
+            License::from_str("https://creativecommons.org/publicdomain/zero/1.0/legalcode")
+                .unwrap(),
+            License::Cc0
+        );
+        assert_eq!(
+            License::from_str("https://creativecommons.org/publicdomain/mark/1.0/").unwrap(),
+            License::PublicDomainMark
+        );
+        assert_eq!(
+            License::from_str(
+                "http://www.nationalarchives.gov.uk/doc/open-government-licence/version/3"
+            )
+            .unwrap(),
+            License::Other(&OTHER_LICENSES[0])
+        );
+        assert!(License::from_str("").is_err());
+        assert!(License::from_str("https://creativecommons.org/licenses/by/").is_err());
+        assert!(License::from_str("https://creativecommons.org/licenses/by/5.0/").is_err());
+        assert!(
+            License::from_str("https://creativecommons.org/licenses/attribution/4.0/").is_err()
+        );
+        assert!(License::from_str("https://creativecommons.org/licenses/by/4.0/de/").is_err());
+        assert!(License::from_str("https://creativecommons.org/licenses/by/3.0/xx/").is_err());
+        assert!(License::from_str("https://creativecommons.org/licenses/by/2.1/").is_err());
+        assert!(License::from_str("creativecommons.org/licenses/by/5.0/").is_err());
+        assert_eq!(
+            License::from_str(" https://example.org/licenses/by/4.0/ ").unwrap(),
+            License::Custom("https://example.org/licenses/by/4.0/".to_string())
+        );
+        assert!(
+            License::from_str("https://creativecommons.org/licenses/by/4.0/")
+                .is_ok_and(|l| l.is_registered())
+        );
+        assert!(License::from_str("https://example.org/licenses/by/4.0/")
+            .is_ok_and(|l| !l.is_registered()));
+    }
+
+    #[test]
+    fn test_license_from_spdx_id() {
+        assert_eq!(
+            License::from_str("CC-BY-SA-4.0").unwrap(),
+            License::CreativeCommons {
+                rights: CcRights::BySa,
+                version: "4.0",
+                port: None,
+            }
+        );
+        assert_eq!(
+            License::from_str("cc-by-nc-sa-2.0-uk").unwrap(),
+            License::CreativeCommons {
+                rights: CcRights::ByNcSa,
+                version: "2.0",
+                port: Some("uk"),
+            }
+        );
+        assert_eq!(License::from_str("CC0-1.0").unwrap(), License::Cc0);
+        assert_eq!(
+            License::from_str("OGL-UK-3.0").unwrap(),
+            License::Other(&OTHER_LICENSES[0])
+        );
+        assert!(License::from_str("CC-BY").is_err());
+        assert!(License::from_str("CC-BY-4.0-DE").is_err());
+        assert!(License::from_str("GPL-3.0-only").is_err());
+    }
+
+    #[test]
+    fn test_license_url() {
+        assert_eq!(
+            standardise_license("http://creativecommons.org/licenses/by-nc/4.0/legalcode").unwrap(),
+            "https://creativecommons.org/licenses/by-nc/4.0/"
+        );
+        assert_eq!(
+            standardise_license("CC-BY-3.0-IGO").unwrap(),
+            "https://creativecommons.org/licenses/by/3.0/igo/"
+        );
+        assert_eq!(
+            standardise_license("https://creativecommons.org/licenses/by-nd-nc/1.0").unwrap(),
+            "https://creativecommons.org/licenses/by-nd-nc/1.0/"
+        );
+        assert_eq!(
+            standardise_license("cc0-1.0").unwrap(),
+            "https://creativecommons.org/publicdomain/zero/1.0/"
+        );
+        assert_eq!(
+            standardise_license("apache-2.0").unwrap(),
+            "https://www.apache.org/licenses/LICENSE-2.0"
+        );
+        assert_eq!(
+            standardise_license("https://www.book.com/license").unwrap(),
+            "https://www.book.com/license"
+        );
+        assert!(standardise_license("All rights reserved").is_err());
+        assert!(standardise_license("All rights reserved/Other").is_err());
+    }
+
+    #[test]
+    fn test_license_spdx_id() {
+        let spdx_id = |input: &str| License::from_str(input).unwrap().spdx_id();
+        assert_eq!(
+            spdx_id("https://creativecommons.org/licenses/by-nc-nd/4.0/"),
+            Some("CC-BY-NC-ND-4.0".to_string())
+        );
+        assert_eq!(
+            spdx_id("https://creativecommons.org/licenses/by-nd-nc/1.0/"),
+            Some("CC-BY-NC-ND-1.0".to_string())
+        );
+        assert_eq!(
+            spdx_id("https://creativecommons.org/licenses/by/3.0/de/"),
+            Some("CC-BY-3.0-DE".to_string())
+        );
+        // SPDX does not define identifiers for every ported licence
+        assert_eq!(
+            spdx_id("https://creativecommons.org/licenses/by/3.0/es/"),
+            None
+        );
+        assert_eq!(
+            spdx_id("https://creativecommons.org/publicdomain/zero/1.0/"),
+            Some("CC0-1.0".to_string())
+        );
+        assert_eq!(
+            spdx_id("https://creativecommons.org/publicdomain/mark/1.0/"),
+            None
+        );
+        assert_eq!(
+            spdx_id("https://opensource.org/licenses/MIT"),
+            Some("MIT".to_string())
+        );
+    }
+
+    #[test]
+    fn test_license_description() {
+        let description = |input: &str| License::from_str(input).unwrap().description();
+        assert_eq!(
+            description("CC-BY-4.0"),
+            "Creative Commons Attribution 4.0 International license (CC BY 4.0)."
+        );
+        assert_eq!(
+            description("CC-BY-NC-SA-3.0"),
+            "Creative Commons Attribution-NonCommercial-ShareAlike 3.0 Unported license (CC BY-NC-SA 3.0)."
+        );
+        assert_eq!(
+            description("CC-BY-ND-2.5"),
+            "Creative Commons Attribution-NoDerivatives 2.5 Generic license (CC BY-ND 2.5)."
+        );
+        assert_eq!(
+            description("CC-BY-3.0-DE"),
+            "Creative Commons Attribution 3.0 Germany license (CC BY 3.0 DE)."
+        );
+        assert_eq!(
+            description("CC0-1.0"),
+            "Creative Commons CC0 1.0 Universal Public Domain Dedication (CC0 1.0)."
+        );
+        assert_eq!(
+            description("OGL-UK-3.0"),
+            "Open Government Licence v3.0 (OGL-UK-3.0)."
+        );
+        assert_eq!(
+            description("https://www.book.com/license"),
+            "custom license."
+        );
+    }
+
+    #[test]
+    fn test_is_open_access() {
+        assert!(is_open_access(Some(
+            "https://creativecommons.org/licenses/by/4.0/"
+        )));
+        assert!(is_open_access(Some("CC0-1.0")));
+        assert!(is_open_access(Some("https://example.org/our-licence")));
+        assert!(!is_open_access(Some("GPL-3.0-only")));
+        assert!(!is_open_access(None));
+    }
+}
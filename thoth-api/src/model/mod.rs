@@ -107,6 +107,14 @@ pub struct Ror(String);
 pub struct Timestamp(DateTime<Utc>);
 
 impl Timestamp {
+    pub fn now() -> Self {
+        Timestamp(Utc::now())
+    }
+
+    pub fn plus_seconds(&self, seconds: i64) -> Self {
+        Timestamp(self.0 + chrono::Duration::seconds(seconds))
+    }
+
     pub fn to_rfc3339(&self) -> String {
         self.0.to_rfc3339()
     }
@@ -1063,6 +1071,13 @@ mod tests {
         assert!(timestamp.is_err());
     }
 
+    #[test]
+    fn test_timestamp_plus_seconds() {
+        let stamp: Timestamp = Default::default();
+        assert_eq!(stamp.plus_seconds(90).to_string(), "1970-01-01 00:01:30");
+        assert!(Timestamp::now().plus_seconds(60) > Timestamp::now());
+    }
+
     #[test]
     fn test_timestamp_to_rfc3339() {
         let timestamp = Timestamp(Utc.with_ymd_and_hms(1999, 12, 31, 23, 59, 0).unwrap());
@@ -1081,19 +1096,35 @@ mod tests {
 }
 
 pub mod affiliation;
+pub mod alternative_name;
+pub mod asset;
 pub mod contribution;
 pub mod contributor;
+pub mod event;
+pub mod event_sponsor;
 pub mod funding;
+pub mod identifier;
 pub mod imprint;
 pub mod institution;
 pub mod issue;
 pub mod language;
+pub mod license;
 pub mod location;
 pub mod price;
+pub mod prize;
 pub mod publication;
+pub mod publication_identifier;
 pub mod publisher;
 pub mod reference;
+pub mod sales_rights;
 pub mod series;
+pub mod series_contribution;
 pub mod subject;
+pub mod supporting_resource;
+pub mod text_content;
+pub mod usage_constraint;
 pub mod work;
+pub mod work_abstract;
+pub mod work_identifier;
 pub mod work_relation;
+pub mod work_title;
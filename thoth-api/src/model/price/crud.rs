@@ -60,6 +60,18 @@ impl Crud for Price {
                 Direction::Asc => query.order(unit_price.asc()),
                 Direction::Desc => query.order(unit_price.desc()),
             },
+            PriceField::PriceType => match order.direction {
+                Direction::Asc => query.order(price_type.asc()),
+                Direction::Desc => query.order(price_type.desc()),
+            },
+            PriceField::PriceEffectiveFrom => match order.direction {
+                Direction::Asc => query.order(price_effective_from.asc()),
+                Direction::Desc => query.order(price_effective_from.desc()),
+            },
+            PriceField::PriceEffectiveUntil => match order.direction {
+                Direction::Asc => query.order(price_effective_until.asc()),
+                Direction::Desc => query.order(price_effective_until.desc()),
+            },
             PriceField::CreatedAt => match order.direction {
                 Direction::Asc => query.order(created_at.asc()),
                 Direction::Desc => query.order(created_at.desc()),
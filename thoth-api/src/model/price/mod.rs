@@ -1,13 +1,102 @@
+use chrono::naive::NaiveDate;
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use strum::EnumString;
 use uuid::Uuid;
 
+use crate::model::institution::CountryCode;
+use crate::model::sales_rights::{dedup_countries, standardise_regions, validate_exclusions};
 use crate::model::Timestamp;
 #[cfg(feature = "backend")]
 use crate::schema::price;
 #[cfg(feature = "backend")]
 use crate::schema::price_history;
+use thoth_errors::{ThothError, ThothResult};
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of a price, including its tax status (ONIX codelist 58)"),
+    ExistingTypePath = "crate::schema::sql_types::PriceType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum PriceType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Recommended retail price, excluding any sales tax or VAT")
+    )]
+    #[strum(serialize = "RRP Excluding Tax")]
+    RrpExcludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Recommended retail price, including any sales tax or VAT")
+    )]
+    #[strum(serialize = "RRP Including Tax")]
+    #[default]
+    RrpIncludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Fixed retail price set by law or agreement, excluding tax")
+    )]
+    FixedRetailPriceExcludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Fixed retail price set by law or agreement, including tax")
+    )]
+    FixedRetailPriceIncludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Net price charged by the supplier to a reseller, excluding tax")
+    )]
+    SuppliersNetPriceExcludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Retail price set by the publisher under an agency model, excluding tax"
+        )
+    )]
+    AgencyPriceExcludingTax,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Retail price set by the publisher under an agency model, including tax"
+        )
+    )]
+    AgencyPriceIncludingTax,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Class of buyer to which a price applies (ONIX codelist 59)"),
+    ExistingTypePath = "crate::schema::sql_types::PriceQualifier"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum PriceQualifier {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Price for individual consumers")
+    )]
+    ConsumerPrice,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Price for corporate customers")
+    )]
+    CorporatePrice,
+    #[cfg_attr(feature = "backend", graphql(description = "Price for libraries"))]
+    LibraryPrice,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Price for schools, colleges and universities")
+    )]
+    EducationPrice,
+}
 
 #[cfg_attr(
     feature = "backend",
@@ -19,6 +108,9 @@ pub enum PriceField {
     PublicationId,
     CurrencyCode,
     UnitPrice,
+    PriceType,
+    PriceEffectiveFrom,
+    PriceEffectiveUntil,
     CreatedAt,
     UpdatedAt,
 }
@@ -33,6 +125,16 @@ pub struct Price {
     pub unit_price: f64,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub price_type: PriceType,
+    pub price_qualifier: Option<PriceQualifier>,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub tax_rate_percent: Option<f64>,
+    pub discount_code: Option<String>,
+    pub price_effective_from: Option<NaiveDate>,
+    pub price_effective_until: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -45,6 +147,16 @@ pub struct NewPrice {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    pub price_type: PriceType,
+    pub price_qualifier: Option<PriceQualifier>,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub tax_rate_percent: Option<f64>,
+    pub discount_code: Option<String>,
+    pub price_effective_from: Option<NaiveDate>,
+    pub price_effective_until: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -58,6 +170,16 @@ pub struct PatchPrice {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    pub price_type: PriceType,
+    pub price_qualifier: Option<PriceQualifier>,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub tax_rate_percent: Option<f64>,
+    pub discount_code: Option<String>,
+    pub price_effective_from: Option<NaiveDate>,
+    pub price_effective_until: Option<NaiveDate>,
 }
 
 #[cfg_attr(
@@ -839,6 +961,111 @@ pub struct NewPriceHistory {
     pub data: serde_json::Value,
 }
 
+macro_rules! standardise_price {
+    ($t:ty) => {
+        impl $t {
+            /// Validate the amount, effective dates and territory, removing duplicate countries
+            /// and standardising region codes. An empty territory means the price applies worldwide.
+            pub fn standardise(mut self) -> ThothResult<Self> {
+                if self.unit_price <= 0.0 {
+                    // Prices must be non-zero (and non-negative).
+                    return Err(ThothError::PriceZeroError);
+                }
+                if let (Some(from), Some(until)) =
+                    (self.price_effective_from, self.price_effective_until)
+                {
+                    if from > until {
+                        return Err(ThothError::PriceEffectiveDatesError);
+                    }
+                }
+                self.countries_included = dedup_countries(self.countries_included);
+                self.countries_excluded = dedup_countries(self.countries_excluded);
+                self.regions_included = standardise_regions(self.regions_included)?;
+                self.regions_excluded = standardise_regions(self.regions_excluded)?;
+                validate_exclusions(
+                    &self.countries_excluded,
+                    &self.regions_included,
+                    &self.regions_excluded,
+                )?;
+                self.discount_code = self
+                    .discount_code
+                    .map(|code| code.trim().to_string())
+                    .filter(|code| !code.is_empty());
+                Ok(self)
+            }
+        }
+    };
+}
+
+standardise_price!(NewPrice);
+standardise_price!(PatchPrice);
+
+#[test]
+fn test_pricetype_default() {
+    let pricetype: PriceType = Default::default();
+    assert_eq!(pricetype, PriceType::RrpIncludingTax);
+}
+
+#[test]
+fn test_pricetype_display() {
+    assert_eq!(
+        format!("{}", PriceType::RrpExcludingTax),
+        "RRP Excluding Tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::RrpIncludingTax),
+        "RRP Including Tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::AgencyPriceIncludingTax),
+        "Agency Price Including Tax"
+    );
+    assert_eq!(format!("{}", PriceQualifier::LibraryPrice), "Library Price");
+}
+
+#[test]
+fn test_new_price_standardise() {
+    let new_price = |unit_price: f64| NewPrice {
+        publication_id: Default::default(),
+        currency_code: CurrencyCode::Gbp,
+        unit_price,
+        price_type: Default::default(),
+        price_qualifier: None,
+        countries_included: vec![CountryCode::Gbr, CountryCode::Gbr],
+        countries_excluded: vec![],
+        regions_included: vec![],
+        regions_excluded: vec![],
+        tax_rate_percent: None,
+        discount_code: Some("  ".to_string()),
+        price_effective_from: NaiveDate::from_ymd_opt(2025, 1, 1),
+        price_effective_until: NaiveDate::from_ymd_opt(2025, 12, 31),
+    };
+    let price = new_price(9.99).standardise().unwrap();
+    assert_eq!(price.countries_included, vec![CountryCode::Gbr]);
+    assert_eq!(price.discount_code, None);
+
+    assert_eq!(
+        new_price(0.0).standardise().err(),
+        Some(ThothError::PriceZeroError)
+    );
+    let mut reversed = new_price(9.99);
+    reversed.price_effective_until = NaiveDate::from_ymd_opt(2024, 12, 31);
+    assert_eq!(
+        reversed.standardise().err(),
+        Some(ThothError::PriceEffectiveDatesError)
+    );
+    let mut excluded = new_price(9.99);
+    excluded.countries_excluded = vec![CountryCode::Irl];
+    assert_eq!(
+        excluded.standardise().err(),
+        Some(ThothError::TerritoryExclusionError)
+    );
+    let mut world = new_price(9.99);
+    world.regions_included = vec!["world".to_string()];
+    world.countries_excluded = vec![CountryCode::Irl];
+    assert_eq!(world.standardise().unwrap().regions_included, vec!["WORLD"]);
+}
+
 #[test]
 fn test_currencycode_default() {
     let currencycode: CurrencyCode = Default::default();
@@ -0,0 +1,171 @@
+use super::{NewPrize, NewPrizeHistory, PatchPrize, Prize, PrizeCode, PrizeField, PrizeHistory};
+use crate::graphql::model::PrizeOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{prize, prize_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for Prize {
+    type NewEntity = NewPrize;
+    type PatchEntity = PatchPrize;
+    type OrderByEntity = PrizeOrderBy;
+    type FilterParameter1 = PrizeCode;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.prize_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        prize_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<Prize>> {
+        use crate::schema::prize::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::prize
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::prize::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            PrizeField::PrizeId => match order.direction {
+                Direction::Asc => query.order(dsl::prize_id.asc()),
+                Direction::Desc => query.order(dsl::prize_id.desc()),
+            },
+            PrizeField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            PrizeField::PrizeName => match order.direction {
+                Direction::Asc => query.order(dsl::prize_name.asc()),
+                Direction::Desc => query.order(dsl::prize_name.desc()),
+            },
+            PrizeField::PrizeYear => match order.direction {
+                Direction::Asc => query.order(dsl::prize_year.asc()),
+                Direction::Desc => query.order(dsl::prize_year.desc()),
+            },
+            PrizeField::PrizeCountry => match order.direction {
+                Direction::Asc => query.order(dsl::prize_country.asc()),
+                Direction::Desc => query.order(dsl::prize_country.desc()),
+            },
+            PrizeField::PrizeCode => match order.direction {
+                Direction::Asc => query.order(dsl::prize_code.asc()),
+                Direction::Desc => query.order(dsl::prize_code.desc()),
+            },
+            PrizeField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            PrizeField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !prize_codes.is_empty() {
+            query = query.filter(dsl::prize_code.eq_any(prize_codes));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::prize_name.ilike(format!("%{filter}%")));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<Prize>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        prize_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::prize::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::prize.into_boxed();
+        if !prize_codes.is_empty() {
+            query = query.filter(dsl::prize_code.eq_any(prize_codes));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::prize_name.ilike(format!("%{filter}%")));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(prize::table, prize::dsl::prize);
+}
+
+impl HistoryEntry for Prize {
+    type NewHistoryEntity = NewPrizeHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            prize_id: self.prize_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewPrizeHistory {
+    type MainEntity = PrizeHistory;
+
+    db_insert!(prize_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prize_pk() {
+        let prize: Prize = Default::default();
+        assert_eq!(prize.pk(), prize.prize_id);
+    }
+
+    #[test]
+    fn test_new_prize_history_from_prize() {
+        let prize: Prize = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_prize_history = prize.new_history_entry(&account_id);
+        assert_eq!(new_prize_history.prize_id, prize.prize_id);
+        assert_eq!(new_prize_history.account_id, account_id);
+        assert_eq!(
+            new_prize_history.data,
+            serde_json::Value::String(serde_json::to_string(&prize).unwrap())
+        );
+    }
+}
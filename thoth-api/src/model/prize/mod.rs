@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::institution::CountryCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::prize;
+#[cfg(feature = "backend")]
+use crate::schema::prize_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Achievement of a work in relation to a prize or award (ONIX codelist 41)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::PrizeCode"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum PrizeCode {
+    #[cfg_attr(feature = "backend", graphql(description = "Winner"))]
+    #[default]
+    Winner,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Named as being in second place")
+    )]
+    #[strum(serialize = "Runner-up")]
+    RunnerUp,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Cited as being worthy of special attention at the final stage of the judging process, but not named specifically as winner or runner-up"
+        )
+    )]
+    Commended,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Title named by the judging process to be one of the final list of candidates"
+        )
+    )]
+    #[strum(serialize = "Shortlisted")]
+    ShortListed,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Title named by the judging process to be one of the preliminary list of candidates"
+        )
+    )]
+    #[strum(serialize = "Longlisted")]
+    LongListed,
+    #[cfg_attr(feature = "backend", graphql(description = "Or co-winner"))]
+    JointWinner,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Selected by judging panel or an official nominating process for final consideration for a prize"
+        )
+    )]
+    Nominated,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting prizes list")
+)]
+pub enum PrizeField {
+    PrizeId,
+    WorkId,
+    PrizeName,
+    PrizeYear,
+    PrizeCountry,
+    PrizeCode,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Prize {
+    pub prize_id: Uuid,
+    pub work_id: Uuid,
+    pub prize_name: String,
+    pub prize_year: Option<i32>,
+    pub prize_country: Option<CountryCode>,
+    pub prize_code: PrizeCode,
+    pub prize_statement: Option<String>,
+    pub prize_jury: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new prize or award achieved by a work"),
+    diesel(table_name = prize)
+)]
+pub struct NewPrize {
+    pub work_id: Uuid,
+    pub prize_name: String,
+    pub prize_year: Option<i32>,
+    pub prize_country: Option<CountryCode>,
+    pub prize_code: PrizeCode,
+    pub prize_statement: Option<String>,
+    pub prize_jury: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing prize or award achieved by a work"),
+    diesel(table_name = prize, treat_none_as_null = true)
+)]
+pub struct PatchPrize {
+    pub prize_id: Uuid,
+    pub work_id: Uuid,
+    pub prize_name: String,
+    pub prize_year: Option<i32>,
+    pub prize_country: Option<CountryCode>,
+    pub prize_code: PrizeCode,
+    pub prize_statement: Option<String>,
+    pub prize_jury: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct PrizeHistory {
+    pub prize_history_id: Uuid,
+    pub prize_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable), diesel(table_name = prize_history))]
+pub struct NewPrizeHistory {
+    pub prize_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_prize_code_default() {
+        let prize_code: PrizeCode = Default::default();
+        assert_eq!(prize_code, PrizeCode::Winner);
+    }
+
+    #[test]
+    fn test_prize_code_display() {
+        assert_eq!(format!("{}", PrizeCode::Winner), "Winner");
+        assert_eq!(format!("{}", PrizeCode::RunnerUp), "Runner-up");
+        assert_eq!(format!("{}", PrizeCode::ShortListed), "Shortlisted");
+        assert_eq!(format!("{}", PrizeCode::LongListed), "Longlisted");
+        assert_eq!(format!("{}", PrizeCode::JointWinner), "Joint Winner");
+    }
+
+    #[test]
+    fn test_prize_code_fromstr() {
+        assert_eq!(
+            PrizeCode::from_str("Commended").unwrap(),
+            PrizeCode::Commended
+        );
+        assert_eq!(
+            PrizeCode::from_str("Shortlisted").unwrap(),
+            PrizeCode::ShortListed
+        );
+        assert_eq!(
+            PrizeCode::from_str("Nominated").unwrap(),
+            PrizeCode::Nominated
+        );
+        assert!(PrizeCode::from_str("Shortlist").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
@@ -7,7 +7,10 @@ use uuid::Uuid;
 use crate::graphql::utils::Direction;
 use crate::model::location::Location;
 use crate::model::price::Price;
-use crate::model::work::WorkWithRelations;
+use crate::model::publication_identifier::PublicationIdentifier;
+use crate::model::sales_rights::SalesRights;
+use crate::model::usage_constraint::UsageConstraint;
+use crate::model::work::{WorkType, WorkWithRelations};
 use crate::model::Isbn;
 use crate::model::Timestamp;
 #[cfg(feature = "backend")]
@@ -108,6 +111,474 @@ pub enum PublicationType {
     Wav,
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Accessibility feature of a digital publication (ONIX codelist 196)"),
+    ExistingTypePath = "crate::schema::sql_types::AccessibilityFeature"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AccessibilityFeature {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Table of contents navigation")
+    )]
+    TableOfContents,
+    #[cfg_attr(feature = "backend", graphql(description = "Index navigation"))]
+    IndexNavigation,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Single logical reading order")
+    )]
+    ReadingOrder,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Short alternative textual descriptions of images")
+    )]
+    ShortAlternativeText,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Full alternative textual descriptions of images")
+    )]
+    FullAlternativeText,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "math_ml",
+        graphql(description = "Accessible mathematical content as MathML")
+    )]
+    #[strum(serialize = "MathML")]
+    MathMl,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "chem_ml",
+        graphql(description = "Accessible chemistry content as ChemML")
+    )]
+    #[strum(serialize = "ChemML")]
+    ChemMl,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Print-equivalent page numbering")
+    )]
+    PrintPageNumbers,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Synchronised pre-recorded audio")
+    )]
+    SynchronisedAudio,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Text-to-speech hinting provided")
+    )]
+    TextToSpeechHinting,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "High contrast between text and background colour")
+    )]
+    HighContrastDisplay,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "High contrast between foreground and background audio")
+    )]
+    HighContrastAudio,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Next/previous structural navigation")
+    )]
+    StructuralNavigation,
+    #[cfg_attr(feature = "backend", graphql(description = "ARIA roles provided"))]
+    #[strum(serialize = "ARIA Roles")]
+    AriaRoles,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "All textual content can be modified by the reader")
+    )]
+    DisplayTransformability,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Hazard warning for a digital publication (ONIX codelist 143)"),
+    ExistingTypePath = "crate::schema::sql_types::AccessibilityHazard"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AccessibilityHazard {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "No known hazards or warnings")
+    )]
+    None,
+    #[cfg_attr(feature = "backend", graphql(description = "Flashing hazard"))]
+    Flashing,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "No flashing hazard warning necessary")
+    )]
+    NoFlashingHazard,
+    #[cfg_attr(feature = "backend", graphql(description = "Unknown flashing hazard"))]
+    UnknownFlashingHazard,
+    #[cfg_attr(feature = "backend", graphql(description = "Sound hazard"))]
+    Sound,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "No sound hazard warning necessary")
+    )]
+    NoSoundHazard,
+    #[cfg_attr(feature = "backend", graphql(description = "Unknown sound hazard"))]
+    UnknownSoundHazard,
+    #[cfg_attr(feature = "backend", graphql(description = "Motion simulation hazard"))]
+    MotionSimulation,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "No motion simulation hazard warning necessary")
+    )]
+    NoMotionSimulationHazard,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Unknown motion simulation hazard")
+    )]
+    UnknownMotionSimulationHazard,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "EPUB accessibility specification to which a publication conforms"),
+    ExistingTypePath = "crate::schema::sql_types::AccessibilityStandard"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccessibilityStandard {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "epub_accessibility_1_0_a",
+        graphql(
+            name = "EPUB_1_0_A",
+            description = "EPUB Accessibility Specification 1.0, WCAG 2.0 level A"
+        )
+    )]
+    #[serde(rename = "EPUB_1_0_A")]
+    #[strum(serialize = "EPUB Accessibility 1.0 A")]
+    Epub10A,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "epub_accessibility_1_0_aa",
+        graphql(
+            name = "EPUB_1_0_AA",
+            description = "EPUB Accessibility Specification 1.0, WCAG 2.0 level AA"
+        )
+    )]
+    #[serde(rename = "EPUB_1_0_AA")]
+    #[strum(serialize = "EPUB Accessibility 1.0 AA")]
+    Epub10Aa,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "epub_accessibility_1_1",
+        graphql(
+            name = "EPUB_1_1",
+            description = "EPUB Accessibility Specification 1.1, at the WCAG version and level given separately"
+        )
+    )]
+    #[serde(rename = "EPUB_1_1")]
+    #[strum(serialize = "EPUB Accessibility 1.1")]
+    Epub11,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Version of the Web Content Accessibility Guidelines"),
+    ExistingTypePath = "crate::schema::sql_types::WcagVersion"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WcagVersion {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "wcag_2_0",
+        graphql(name = "WCAG_2_0", description = "WCAG 2.0")
+    )]
+    #[serde(rename = "WCAG_2_0")]
+    #[strum(serialize = "WCAG 2.0")]
+    Wcag20,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "wcag_2_1",
+        graphql(name = "WCAG_2_1", description = "WCAG 2.1")
+    )]
+    #[serde(rename = "WCAG_2_1")]
+    #[strum(serialize = "WCAG 2.1")]
+    Wcag21,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "wcag_2_2",
+        graphql(name = "WCAG_2_2", description = "WCAG 2.2")
+    )]
+    #[serde(rename = "WCAG_2_2")]
+    #[strum(serialize = "WCAG 2.2")]
+    Wcag22,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Web Content Accessibility Guidelines conformance level"),
+    ExistingTypePath = "crate::schema::sql_types::WcagLevel"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum WcagLevel {
+    #[cfg_attr(feature = "backend", graphql(description = "Level A"))]
+    A,
+    #[cfg_attr(feature = "backend", graphql(description = "Level AA"))]
+    Aa,
+    #[cfg_attr(feature = "backend", graphql(description = "Level AAA"))]
+    Aaa,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Binding method of a physical publication"),
+    ExistingTypePath = "crate::schema::sql_types::BindingStyle"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum BindingStyle {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Unsewn, adhesive (perfect) binding")
+    )]
+    PerfectBound,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Sewn binding, with signatures sewn together")
+    )]
+    SectionSewn,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Saddle-stitched or saddle-sewn binding")
+    )]
+    SaddleStitched,
+    #[cfg_attr(feature = "backend", graphql(description = "Library binding"))]
+    LibraryBound,
+    #[cfg_attr(feature = "backend", graphql(description = "Plastic comb binding"))]
+    CombBound,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "wire_o_bound",
+        graphql(name = "WIRE_O_BOUND", description = "Wire-O binding")
+    )]
+    #[serde(rename = "WIRE_O_BOUND")]
+    #[strum(serialize = "Wire-O Bound")]
+    WireOBound,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Coiled wire or spiral binding")
+    )]
+    SpiralBound,
+    #[cfg_attr(feature = "backend", graphql(description = "Lay-flat binding"))]
+    LayFlat,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Interior paper stock of a physical publication"),
+    ExistingTypePath = "crate::schema::sql_types::PaperType"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum PaperType {
+    #[cfg_attr(feature = "backend", graphql(description = "Uncoated white paper"))]
+    White,
+    #[cfg_attr(feature = "backend", graphql(description = "Uncoated cream paper"))]
+    Cream,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Groundwood (mechanical) paper")
+    )]
+    Groundwood,
+    #[cfg_attr(feature = "backend", graphql(description = "Coated matte paper"))]
+    CoatedMatte,
+    #[cfg_attr(feature = "backend", graphql(description = "Coated gloss paper"))]
+    CoatedGloss,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Interior printing colour of a physical publication"),
+    ExistingTypePath = "crate::schema::sql_types::InteriorColour"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum InteriorColour {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Black and white (monochrome) interior")
+    )]
+    BlackAndWhite,
+    #[cfg_attr(feature = "backend", graphql(description = "Standard colour interior"))]
+    StandardColour,
+    #[cfg_attr(feature = "backend", graphql(description = "Premium colour interior"))]
+    PremiumColour,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Trim size category of a physical publication"),
+    ExistingTypePath = "crate::schema::sql_types::TrimCategory"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum TrimCategory {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Mass market (rack) paperback")
+    )]
+    MassMarket,
+    #[cfg_attr(feature = "backend", graphql(description = "US trade paperback"))]
+    #[strum(serialize = "US Trade")]
+    UsTrade,
+    #[cfg_attr(feature = "backend", graphql(description = "Digest format paperback"))]
+    Digest,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "a_format",
+        graphql(name = "A_FORMAT", description = "A-format paperback")
+    )]
+    #[serde(rename = "A_FORMAT")]
+    #[strum(serialize = "A-Format")]
+    AFormat,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "b_format",
+        graphql(name = "B_FORMAT", description = "B-format paperback")
+    )]
+    #[serde(rename = "B_FORMAT")]
+    #[strum(serialize = "B-Format")]
+    BFormat,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "UK trade paperback (C-format)")
+    )]
+    #[strum(serialize = "UK Trade")]
+    UkTrade,
+    #[cfg_attr(feature = "backend", graphql(description = "Tall rack paperback"))]
+    TallRack,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Custom trim size, given by the publication's dimensions")
+    )]
+    Custom,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Cover finish of a physical publication"),
+    ExistingTypePath = "crate::schema::sql_types::CoverFinish"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum CoverFinish {
+    #[cfg_attr(feature = "backend", graphql(description = "Gloss laminated cover"))]
+    GlossLaminate,
+    #[cfg_attr(feature = "backend", graphql(description = "Matte laminated cover"))]
+    MatteLaminate,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Soft-touch laminated cover")
+    )]
+    SoftTouchLaminate,
+    #[cfg_attr(feature = "backend", graphql(description = "Uncoated cover"))]
+    Uncoated,
+    #[cfg_attr(feature = "backend", graphql(description = "Cloth over boards"))]
+    Cloth,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Technical protection applied to a digital publication (ONIX codelist 144)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::TechnicalProtection"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum TechnicalProtection {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Has no technical protection")
+    )]
+    None,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Has DRM protection, of an unspecified kind")
+    )]
+    #[strum(serialize = "DRM")]
+    Drm,
+    #[cfg_attr(feature = "backend", graphql(description = "Has a digital watermark"))]
+    DigitalWatermarking,
+    #[cfg_attr(feature = "backend", graphql(description = "Has Adobe DRM protection"))]
+    #[strum(serialize = "Adobe DRM")]
+    AdobeDrm,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Has Apple FairPlay DRM protection")
+    )]
+    #[strum(serialize = "Apple DRM")]
+    AppleDrm,
+    #[cfg_attr(feature = "backend", graphql(description = "Has OMA DRM protection"))]
+    #[strum(serialize = "OMA DRM")]
+    OmaDrm,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Has Readium Licensed Content Protection")
+    )]
+    #[strum(serialize = "Readium LCP")]
+    ReadiumLcp,
+    #[cfg_attr(feature = "backend", graphql(description = "Has Sony DRM protection"))]
+    #[strum(serialize = "Sony DRM")]
+    SonyDrm,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Page layout of a digital publication"),
+    ExistingTypePath = "crate::schema::sql_types::DigitalLayout"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum DigitalLayout {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Text reflows to fit the reading system's display")
+    )]
+    Reflowable,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Fixed page layout, as in print")
+    )]
+    FixedFormat,
+}
+
 #[cfg_attr(
     feature = "backend",
     derive(juniper::GraphQLEnum),
@@ -155,6 +626,24 @@ pub struct Publication {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub accessibility_features: Vec<AccessibilityFeature>,
+    pub accessibility_hazards: Vec<AccessibilityHazard>,
+    pub accessibility_standard: Option<AccessibilityStandard>,
+    pub wcag_version: Option<WcagVersion>,
+    pub wcag_level: Option<WcagLevel>,
+    pub accessibility_certifier: Option<String>,
+    pub accessibility_summary: Option<String>,
+    pub binding_style: Option<BindingStyle>,
+    pub paper_type: Option<PaperType>,
+    pub interior_colour: Option<InteriorColour>,
+    pub trim_category: Option<TrimCategory>,
+    pub page_count: Option<i32>,
+    pub cover_finish: Option<CoverFinish>,
+    pub carton_quantity: Option<i32>,
+    pub format_version: Option<String>,
+    pub file_size_kb: Option<i32>,
+    pub technical_protections: Vec<TechnicalProtection>,
+    pub layout: Option<DigitalLayout>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -173,8 +662,29 @@ pub struct PublicationWithRelations {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub accessibility_features: Vec<AccessibilityFeature>,
+    pub accessibility_hazards: Vec<AccessibilityHazard>,
+    pub accessibility_standard: Option<AccessibilityStandard>,
+    pub wcag_version: Option<WcagVersion>,
+    pub wcag_level: Option<WcagLevel>,
+    pub accessibility_certifier: Option<String>,
+    pub accessibility_summary: Option<String>,
+    pub binding_style: Option<BindingStyle>,
+    pub paper_type: Option<PaperType>,
+    pub interior_colour: Option<InteriorColour>,
+    pub trim_category: Option<TrimCategory>,
+    pub page_count: Option<i32>,
+    pub cover_finish: Option<CoverFinish>,
+    pub carton_quantity: Option<i32>,
+    pub format_version: Option<String>,
+    pub file_size_kb: Option<i32>,
+    pub technical_protections: Vec<TechnicalProtection>,
+    pub layout: Option<DigitalLayout>,
     pub prices: Option<Vec<Price>>,
     pub locations: Option<Vec<Location>>,
+    pub identifiers: Option<Vec<PublicationIdentifier>>,
+    pub sales_rights: Option<Vec<SalesRights>>,
+    pub usage_constraints: Option<Vec<UsageConstraint>>,
     pub work: WorkWithRelations,
 }
 
@@ -196,6 +706,24 @@ pub struct NewPublication {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub accessibility_features: Vec<AccessibilityFeature>,
+    pub accessibility_hazards: Vec<AccessibilityHazard>,
+    pub accessibility_standard: Option<AccessibilityStandard>,
+    pub wcag_version: Option<WcagVersion>,
+    pub wcag_level: Option<WcagLevel>,
+    pub accessibility_certifier: Option<String>,
+    pub accessibility_summary: Option<String>,
+    pub binding_style: Option<BindingStyle>,
+    pub paper_type: Option<PaperType>,
+    pub interior_colour: Option<InteriorColour>,
+    pub trim_category: Option<TrimCategory>,
+    pub page_count: Option<i32>,
+    pub cover_finish: Option<CoverFinish>,
+    pub carton_quantity: Option<i32>,
+    pub format_version: Option<String>,
+    pub file_size_kb: Option<i32>,
+    pub technical_protections: Vec<TechnicalProtection>,
+    pub layout: Option<DigitalLayout>,
 }
 
 #[cfg_attr(
@@ -217,6 +745,24 @@ pub struct PatchPublication {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub accessibility_features: Vec<AccessibilityFeature>,
+    pub accessibility_hazards: Vec<AccessibilityHazard>,
+    pub accessibility_standard: Option<AccessibilityStandard>,
+    pub wcag_version: Option<WcagVersion>,
+    pub wcag_level: Option<WcagLevel>,
+    pub accessibility_certifier: Option<String>,
+    pub accessibility_summary: Option<String>,
+    pub binding_style: Option<BindingStyle>,
+    pub paper_type: Option<PaperType>,
+    pub interior_colour: Option<InteriorColour>,
+    pub trim_category: Option<TrimCategory>,
+    pub page_count: Option<i32>,
+    pub cover_finish: Option<CoverFinish>,
+    pub carton_quantity: Option<i32>,
+    pub format_version: Option<String>,
+    pub file_size_kb: Option<i32>,
+    pub technical_protections: Vec<TechnicalProtection>,
+    pub layout: Option<DigitalLayout>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -262,6 +808,24 @@ pub trait PublicationProperties {
     fn weight_oz(&self) -> &Option<f64>;
     fn isbn(&self) -> &Option<Isbn>;
     fn work_id(&self) -> &Uuid;
+    fn accessibility_features(&self) -> &[AccessibilityFeature];
+    fn accessibility_hazards(&self) -> &[AccessibilityHazard];
+    fn accessibility_standard(&self) -> &Option<AccessibilityStandard>;
+    fn wcag_version(&self) -> &Option<WcagVersion>;
+    fn wcag_level(&self) -> &Option<WcagLevel>;
+    fn accessibility_certifier(&self) -> &Option<String>;
+    fn accessibility_summary(&self) -> &Option<String>;
+    fn binding_style(&self) -> &Option<BindingStyle>;
+    fn paper_type(&self) -> &Option<PaperType>;
+    fn interior_colour(&self) -> &Option<InteriorColour>;
+    fn trim_category(&self) -> &Option<TrimCategory>;
+    fn page_count(&self) -> &Option<i32>;
+    fn cover_finish(&self) -> &Option<CoverFinish>;
+    fn carton_quantity(&self) -> &Option<i32>;
+    fn format_version(&self) -> &Option<String>;
+    fn file_size_kb(&self) -> &Option<i32>;
+    fn technical_protections(&self) -> &[TechnicalProtection];
+    fn layout(&self) -> &Option<DigitalLayout>;
 
     fn is_physical(&self) -> bool {
         matches!(
@@ -289,6 +853,97 @@ pub trait PublicationProperties {
         .any(Option::is_some)
     }
 
+    fn has_accessibility(&self) -> bool {
+        !self.accessibility_features().is_empty()
+            || !self.accessibility_hazards().is_empty()
+            || self.accessibility_standard().is_some()
+            || self.wcag_version().is_some()
+            || self.wcag_level().is_some()
+            || self.accessibility_certifier().is_some()
+            || self.accessibility_summary().is_some()
+    }
+
+    fn has_print_attributes(&self) -> bool {
+        self.binding_style().is_some()
+            || self.paper_type().is_some()
+            || self.interior_colour().is_some()
+            || self.trim_category().is_some()
+            || self.page_count().is_some()
+            || self.cover_finish().is_some()
+            || self.carton_quantity().is_some()
+    }
+
+    fn validate_print_constraints(&self) -> ThothResult<()> {
+        if self.is_digital() && self.has_print_attributes() {
+            return Err(ThothError::PrintAttributesDigitalError);
+        }
+        if self.page_count().is_some_and(|count| count < 1) {
+            return Err(ThothError::PublicationPageCountError);
+        }
+        if self.carton_quantity().is_some_and(|quantity| quantity < 1) {
+            return Err(ThothError::CartonQuantityError);
+        }
+        Ok(())
+    }
+
+    fn has_digital_attributes(&self) -> bool {
+        self.format_version().is_some()
+            || self.file_size_kb().is_some()
+            || !self.technical_protections().is_empty()
+            || self.layout().is_some()
+    }
+
+    fn validate_digital_constraints(&self) -> ThothResult<()> {
+        if self.is_physical() && self.has_digital_attributes() {
+            return Err(ThothError::DigitalAttributesPhysicalError);
+        }
+        if self.file_size_kb().is_some_and(|size| size < 1) {
+            return Err(ThothError::FileSizeError);
+        }
+        let protections = self.technical_protections();
+        if protections.contains(&TechnicalProtection::None) && protections.len() > 1 {
+            return Err(ThothError::TechnicalProtectionsError);
+        }
+        Ok(())
+    }
+
+    fn validate_accessibility_constraints(&self) -> ThothResult<()> {
+        if self.is_physical() && self.has_accessibility() {
+            return Err(ThothError::AccessibilityPhysicalError);
+        }
+        if self.wcag_version().is_some() != self.wcag_level().is_some()
+            || (*self.accessibility_standard() == Some(AccessibilityStandard::Epub11)
+                && self.wcag_version().is_none())
+        {
+            return Err(ThothError::WcagConformanceError);
+        }
+        // Each kind of hazard may only be stated once, and "none" rules out any other statement
+        let hazards = self.accessibility_hazards();
+        let conflicting = [
+            [
+                AccessibilityHazard::Flashing,
+                AccessibilityHazard::NoFlashingHazard,
+                AccessibilityHazard::UnknownFlashingHazard,
+            ],
+            [
+                AccessibilityHazard::Sound,
+                AccessibilityHazard::NoSoundHazard,
+                AccessibilityHazard::UnknownSoundHazard,
+            ],
+            [
+                AccessibilityHazard::MotionSimulation,
+                AccessibilityHazard::NoMotionSimulationHazard,
+                AccessibilityHazard::UnknownMotionSimulationHazard,
+            ],
+        ]
+        .iter()
+        .any(|kind| hazards.iter().filter(|h| kind.contains(h)).count() > 1);
+        if conflicting || (hazards.contains(&AccessibilityHazard::None) && hazards.len() > 1) {
+            return Err(ThothError::AccessibilityHazardsError);
+        }
+        Ok(())
+    }
+
     fn validate_dimensions_constraints(&self) -> ThothResult<()> {
         use ThothError::*;
 
@@ -311,19 +966,22 @@ pub trait PublicationProperties {
     }
 
     #[cfg(feature = "backend")]
-    fn is_chapter(&self, db: &crate::db::PgPool) -> ThothResult<bool> {
-        use crate::model::work::WorkType;
+    fn work_type(&self, db: &crate::db::PgPool) -> ThothResult<WorkType> {
         use diesel::prelude::*;
         let mut connection = db.get()?;
-        let work_type = crate::schema::work::table
+        crate::schema::work::table
             .select(crate::schema::work::work_type)
             .filter(crate::schema::work::work_id.eq(self.work_id()))
-            .first::<WorkType>(&mut connection)?;
-        Ok(work_type == WorkType::BookChapter)
+            .first::<WorkType>(&mut connection)
+            .map_err(Into::into)
     }
 
-    #[cfg(feature = "backend")]
-    fn validate_chapter_constraints(&self) -> ThothResult<()> {
+    /// Chapters and journal articles are parts of a larger work, so their publications
+    /// carry neither ISBNs nor physical dimensions
+    fn validate_chapter_constraints(&self, work_type: &WorkType) -> ThothResult<()> {
+        if !work_type.is_component() {
+            return Ok(());
+        }
         match (self.isbn().is_some(), self.has_dimension()) {
             (true, _) => Err(ThothError::ChapterIsbnError),
             (_, true) => Err(ThothError::ChapterDimensionError),
@@ -333,9 +991,10 @@ pub trait PublicationProperties {
 
     #[cfg(feature = "backend")]
     fn validate(&self, db: &crate::db::PgPool) -> ThothResult<()> {
-        if self.is_chapter(db)? {
-            self.validate_chapter_constraints()?;
-        }
+        self.validate_chapter_constraints(&self.work_type(db)?)?;
+        self.validate_accessibility_constraints()?;
+        self.validate_print_constraints()?;
+        self.validate_digital_constraints()?;
         self.validate_dimensions_constraints()
     }
 }
@@ -376,6 +1035,60 @@ macro_rules! publication_properties {
             fn work_id(&self) -> &Uuid {
                 &self.work_id
             }
+            fn accessibility_features(&self) -> &[AccessibilityFeature] {
+                &self.accessibility_features
+            }
+            fn accessibility_hazards(&self) -> &[AccessibilityHazard] {
+                &self.accessibility_hazards
+            }
+            fn accessibility_standard(&self) -> &Option<AccessibilityStandard> {
+                &self.accessibility_standard
+            }
+            fn wcag_version(&self) -> &Option<WcagVersion> {
+                &self.wcag_version
+            }
+            fn wcag_level(&self) -> &Option<WcagLevel> {
+                &self.wcag_level
+            }
+            fn accessibility_certifier(&self) -> &Option<String> {
+                &self.accessibility_certifier
+            }
+            fn accessibility_summary(&self) -> &Option<String> {
+                &self.accessibility_summary
+            }
+            fn binding_style(&self) -> &Option<BindingStyle> {
+                &self.binding_style
+            }
+            fn paper_type(&self) -> &Option<PaperType> {
+                &self.paper_type
+            }
+            fn interior_colour(&self) -> &Option<InteriorColour> {
+                &self.interior_colour
+            }
+            fn trim_category(&self) -> &Option<TrimCategory> {
+                &self.trim_category
+            }
+            fn page_count(&self) -> &Option<i32> {
+                &self.page_count
+            }
+            fn cover_finish(&self) -> &Option<CoverFinish> {
+                &self.cover_finish
+            }
+            fn carton_quantity(&self) -> &Option<i32> {
+                &self.carton_quantity
+            }
+            fn format_version(&self) -> &Option<String> {
+                &self.format_version
+            }
+            fn file_size_kb(&self) -> &Option<i32> {
+                &self.file_size_kb
+            }
+            fn technical_protections(&self) -> &[TechnicalProtection] {
+                &self.technical_protections
+            }
+            fn layout(&self) -> &Option<DigitalLayout> {
+                &self.layout
+            }
         }
     };
 }
@@ -549,6 +1262,34 @@ mod tests {
         assert!(publication.validate_dimensions_constraints().is_ok());
     }
 
+    #[test]
+    fn test_publicationproperties_chapter() {
+        use std::str::FromStr;
+        let mut publication: Publication = Publication {
+            publication_type: PublicationType::Paperback,
+            isbn: Some(Isbn::from_str("978-3-16-148410-0").unwrap()),
+            ..Default::default()
+        };
+        assert!(publication
+            .validate_chapter_constraints(&WorkType::Monograph)
+            .is_ok());
+        for work_type in [WorkType::BookChapter, WorkType::JournalArticle] {
+            publication.isbn = Some(Isbn::from_str("978-3-16-148410-0").unwrap());
+            publication.width_mm = None;
+            assert_eq!(
+                publication.validate_chapter_constraints(&work_type),
+                Err(ThothError::ChapterIsbnError)
+            );
+            publication.isbn = None;
+            assert!(publication.validate_chapter_constraints(&work_type).is_ok());
+            publication.width_mm = Some(100.0);
+            assert_eq!(
+                publication.validate_chapter_constraints(&work_type),
+                Err(ThothError::ChapterDimensionError)
+            );
+        }
+    }
+
     #[test]
     fn test_publicationtype_default() {
         let pubtype: PublicationType = Default::default();
@@ -595,6 +1336,183 @@ mod tests {
         assert_eq!(format!("{}", PublicationField::WeightOz), "WeightOz");
     }
 
+    #[test]
+    fn test_publicationproperties_accessibility() {
+        let mut publication: Publication = Publication {
+            publication_type: PublicationType::Paperback,
+            accessibility_summary: Some("Fully accessible".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::AccessibilityPhysicalError)
+        );
+        publication.publication_type = PublicationType::Epub;
+        assert!(publication.validate_accessibility_constraints().is_ok());
+        publication.accessibility_standard = Some(AccessibilityStandard::Epub11);
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::WcagConformanceError)
+        );
+        publication.wcag_version = Some(WcagVersion::Wcag21);
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::WcagConformanceError)
+        );
+        publication.wcag_level = Some(WcagLevel::Aa);
+        assert!(publication.validate_accessibility_constraints().is_ok());
+        publication.accessibility_standard = Some(AccessibilityStandard::Epub10Aa);
+        publication.wcag_version = None;
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::WcagConformanceError)
+        );
+        publication.wcag_level = None;
+        assert!(publication.validate_accessibility_constraints().is_ok());
+    }
+
+    #[test]
+    fn test_publicationproperties_accessibility_hazards() {
+        let mut publication: Publication = Publication {
+            publication_type: PublicationType::Pdf,
+            accessibility_hazards: vec![
+                AccessibilityHazard::NoFlashingHazard,
+                AccessibilityHazard::NoSoundHazard,
+                AccessibilityHazard::UnknownMotionSimulationHazard,
+            ],
+            ..Default::default()
+        };
+        assert!(publication.validate_accessibility_constraints().is_ok());
+        publication
+            .accessibility_hazards
+            .push(AccessibilityHazard::Sound);
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::AccessibilityHazardsError)
+        );
+        publication.accessibility_hazards = vec![AccessibilityHazard::None];
+        assert!(publication.validate_accessibility_constraints().is_ok());
+        publication
+            .accessibility_hazards
+            .push(AccessibilityHazard::Flashing);
+        assert_eq!(
+            publication.validate_accessibility_constraints(),
+            Err(ThothError::AccessibilityHazardsError)
+        );
+    }
+
+    #[test]
+    fn test_accessibility_enums_display() {
+        assert_eq!(
+            format!("{}", AccessibilityFeature::TableOfContents),
+            "Table Of Contents"
+        );
+        assert_eq!(format!("{}", AccessibilityFeature::MathMl), "MathML");
+        assert_eq!(format!("{}", AccessibilityFeature::AriaRoles), "ARIA Roles");
+        assert_eq!(
+            format!("{}", AccessibilityHazard::NoFlashingHazard),
+            "No Flashing Hazard"
+        );
+        assert_eq!(
+            format!("{}", AccessibilityStandard::Epub11),
+            "EPUB Accessibility 1.1"
+        );
+        assert_eq!(format!("{}", WcagVersion::Wcag22), "WCAG 2.2");
+        assert_eq!(format!("{}", WcagLevel::Aa), "AA");
+    }
+
+    #[test]
+    fn test_publicationproperties_print_attributes() {
+        let mut publication: Publication = Publication {
+            publication_type: PublicationType::Pdf,
+            binding_style: Some(BindingStyle::PerfectBound),
+            ..Default::default()
+        };
+        assert!(publication.has_print_attributes());
+        assert_eq!(
+            publication.validate_print_constraints(),
+            Err(ThothError::PrintAttributesDigitalError)
+        );
+        publication.publication_type = PublicationType::Paperback;
+        assert!(publication.validate_print_constraints().is_ok());
+        publication.page_count = Some(0);
+        assert_eq!(
+            publication.validate_print_constraints(),
+            Err(ThothError::PublicationPageCountError)
+        );
+        publication.page_count = Some(352);
+        publication.carton_quantity = Some(0);
+        assert_eq!(
+            publication.validate_print_constraints(),
+            Err(ThothError::CartonQuantityError)
+        );
+        publication.carton_quantity = Some(24);
+        assert!(publication.validate_print_constraints().is_ok());
+    }
+
+    #[test]
+    fn test_print_enums_display() {
+        assert_eq!(format!("{}", BindingStyle::PerfectBound), "Perfect Bound");
+        assert_eq!(format!("{}", BindingStyle::WireOBound), "Wire-O Bound");
+        assert_eq!(format!("{}", PaperType::CoatedMatte), "Coated Matte");
+        assert_eq!(
+            format!("{}", InteriorColour::BlackAndWhite),
+            "Black And White"
+        );
+        assert_eq!(format!("{}", TrimCategory::UsTrade), "US Trade");
+        assert_eq!(format!("{}", TrimCategory::AFormat), "A-Format");
+        assert_eq!(
+            format!("{}", CoverFinish::SoftTouchLaminate),
+            "Soft Touch Laminate"
+        );
+    }
+
+    #[test]
+    fn test_publicationproperties_digital_attributes() {
+        let mut publication: Publication = Publication {
+            publication_type: PublicationType::Hardback,
+            layout: Some(DigitalLayout::Reflowable),
+            ..Default::default()
+        };
+        assert!(publication.has_digital_attributes());
+        assert_eq!(
+            publication.validate_digital_constraints(),
+            Err(ThothError::DigitalAttributesPhysicalError)
+        );
+        publication.publication_type = PublicationType::Epub;
+        assert!(publication.validate_digital_constraints().is_ok());
+        publication.file_size_kb = Some(0);
+        assert_eq!(
+            publication.validate_digital_constraints(),
+            Err(ThothError::FileSizeError)
+        );
+        publication.file_size_kb = Some(2450);
+        publication.technical_protections = vec![
+            TechnicalProtection::None,
+            TechnicalProtection::DigitalWatermarking,
+        ];
+        assert_eq!(
+            publication.validate_digital_constraints(),
+            Err(ThothError::TechnicalProtectionsError)
+        );
+        publication.technical_protections = vec![TechnicalProtection::None];
+        assert!(publication.validate_digital_constraints().is_ok());
+    }
+
+    #[test]
+    fn test_digital_enums_display() {
+        assert_eq!(format!("{}", TechnicalProtection::None), "None");
+        assert_eq!(
+            format!("{}", TechnicalProtection::DigitalWatermarking),
+            "Digital Watermarking"
+        );
+        assert_eq!(
+            format!("{}", TechnicalProtection::ReadiumLcp),
+            "Readium LCP"
+        );
+        assert_eq!(format!("{}", DigitalLayout::FixedFormat), "Fixed Format");
+    }
+
     #[test]
     fn test_publicationtype_fromstr() {
         use std::str::FromStr;
@@ -0,0 +1,180 @@
+use super::{
+    NewPublicationIdentifier, NewPublicationIdentifierHistory, PatchPublicationIdentifier,
+    PublicationIdentifier, PublicationIdentifierField, PublicationIdentifierHistory,
+};
+use crate::graphql::model::PublicationIdentifierOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::identifier::IdentifierType;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{publication_identifier, publication_identifier_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for PublicationIdentifier {
+    type NewEntity = NewPublicationIdentifier;
+    type PatchEntity = PatchPublicationIdentifier;
+    type OrderByEntity = PublicationIdentifierOrderBy;
+    type FilterParameter1 = IdentifierType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.publication_identifier_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        identifier_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<PublicationIdentifier>> {
+        use crate::schema::publication_identifier::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::publication_identifier
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::publication_identifier::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            PublicationIdentifierField::PublicationIdentifierId => match order.direction {
+                Direction::Asc => query.order(dsl::publication_identifier_id.asc()),
+                Direction::Desc => query.order(dsl::publication_identifier_id.desc()),
+            },
+            PublicationIdentifierField::PublicationId => match order.direction {
+                Direction::Asc => query.order(dsl::publication_id.asc()),
+                Direction::Desc => query.order(dsl::publication_id.desc()),
+            },
+            PublicationIdentifierField::IdentifierType => match order.direction {
+                Direction::Asc => query.order(dsl::identifier_type.asc()),
+                Direction::Desc => query.order(dsl::identifier_type.desc()),
+            },
+            PublicationIdentifierField::IdentifierValue => match order.direction {
+                Direction::Asc => query.order(dsl::identifier_value.asc()),
+                Direction::Desc => query.order(dsl::identifier_value.desc()),
+            },
+            PublicationIdentifierField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            PublicationIdentifierField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::publication_id.eq(pid));
+        }
+        if !identifier_types.is_empty() {
+            query = query.filter(dsl::identifier_type.eq_any(identifier_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::identifier_value.ilike(format!("%{filter}%")));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<PublicationIdentifier>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        identifier_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::publication_identifier::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::publication_identifier.into_boxed();
+        if !identifier_types.is_empty() {
+            query = query.filter(dsl::identifier_type.eq_any(identifier_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::identifier_value.ilike(format!("%{filter}%")));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::publication::Publication::from_id(db, &self.publication_id)?.publisher_id(db)
+    }
+
+    crud_methods!(
+        publication_identifier::table,
+        publication_identifier::dsl::publication_identifier
+    );
+}
+
+impl HistoryEntry for PublicationIdentifier {
+    type NewHistoryEntity = NewPublicationIdentifierHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            publication_identifier_id: self.publication_identifier_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewPublicationIdentifierHistory {
+    type MainEntity = PublicationIdentifierHistory;
+
+    db_insert!(publication_identifier_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publication_identifier_pk() {
+        let publication_identifier: PublicationIdentifier = Default::default();
+        assert_eq!(
+            publication_identifier.pk(),
+            publication_identifier.publication_identifier_id
+        );
+    }
+
+    #[test]
+    fn test_new_publication_identifier_history_from_publication_identifier() {
+        let publication_identifier: PublicationIdentifier = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_publication_identifier_history =
+            publication_identifier.new_history_entry(&account_id);
+        assert_eq!(
+            new_publication_identifier_history.publication_identifier_id,
+            publication_identifier.publication_identifier_id
+        );
+        assert_eq!(new_publication_identifier_history.account_id, account_id);
+        assert_eq!(
+            new_publication_identifier_history.data,
+            serde_json::Value::String(serde_json::to_string(&publication_identifier).unwrap())
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::identifier::IdentifierType;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::publication_identifier;
+#[cfg(feature = "backend")]
+use crate::schema::publication_identifier_history;
+use thoth_errors::ThothResult;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting publication identifiers list")
+)]
+pub enum PublicationIdentifierField {
+    PublicationIdentifierId,
+    PublicationId,
+    IdentifierType,
+    IdentifierValue,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicationIdentifier {
+    pub publication_identifier_id: Uuid,
+    pub publication_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new persistent identifier of a publication"),
+    diesel(table_name = publication_identifier)
+)]
+pub struct NewPublicationIdentifier {
+    pub publication_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing persistent identifier of a publication"),
+    diesel(table_name = publication_identifier, treat_none_as_null = true)
+)]
+pub struct PatchPublicationIdentifier {
+    pub publication_identifier_id: Uuid,
+    pub publication_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct PublicationIdentifierHistory {
+    pub publication_identifier_history_id: Uuid,
+    pub publication_identifier_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = publication_identifier_history)
+)]
+pub struct NewPublicationIdentifierHistory {
+    pub publication_identifier_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl NewPublicationIdentifier {
+    /// Validate the identifier against its type, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.identifier_value = self
+            .identifier_type
+            .standardise_for_publication(&self.identifier_value)?;
+        Ok(self)
+    }
+}
+
+impl PatchPublicationIdentifier {
+    /// Validate the identifier against its type, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.identifier_value = self
+            .identifier_type
+            .standardise_for_publication(&self.identifier_value)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_publication_identifier_standardise() {
+        let new_publication_identifier = NewPublicationIdentifier {
+            publication_id: Default::default(),
+            identifier_type: IdentifierType::Gtin,
+            identifier_value: "400-6381-33393-1".to_string(),
+        };
+        assert_eq!(
+            new_publication_identifier
+                .standardise()
+                .unwrap()
+                .identifier_value,
+            "4006381333931"
+        );
+
+        let new_publication_identifier = NewPublicationIdentifier {
+            publication_id: Default::default(),
+            identifier_type: IdentifierType::Wikidata,
+            identifier_value: "Q42".to_string(),
+        };
+        assert!(new_publication_identifier.standardise().is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
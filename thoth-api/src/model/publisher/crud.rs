@@ -12,6 +12,32 @@ use diesel::{
 use thoth_errors::ThothResult;
 use uuid::Uuid;
 
+impl Publisher {
+    /// Kept apart from `PatchPublisher` as only superusers may change this setting.
+    /// Records a history entry, as with any other update.
+    pub fn update_two_factor_requirement(
+        &self,
+        db: &crate::db::PgPool,
+        require_two_factor: bool,
+        account_id: &Uuid,
+    ) -> ThothResult<Self> {
+        use diesel::Connection;
+
+        let mut connection = db.get()?;
+        connection.transaction(|connection| {
+            diesel::update(publisher::dsl::publisher.find(&self.pk()))
+                .set(publisher::dsl::require_two_factor.eq(require_two_factor))
+                .get_result(connection)
+                .map_err(Into::into)
+                .and_then(|p| {
+                    self.new_history_entry(account_id)
+                        .insert(connection)
+                        .map(|_| p)
+                })
+        })
+    }
+}
+
 impl Crud for Publisher {
     type NewEntity = NewPublisher;
     type PatchEntity = PatchPublisher;
@@ -42,6 +42,8 @@ pub struct Publisher {
     pub publisher_url: Option<String>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    #[serde(default)]
+    pub require_two_factor: bool,
 }
 
 #[cfg_attr(
@@ -0,0 +1,162 @@
+use super::{
+    NewSalesRights, NewSalesRightsHistory, PatchSalesRights, SalesRights, SalesRightsField,
+    SalesRightsHistory, SalesRightsType,
+};
+use crate::graphql::model::SalesRightsOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{sales_rights, sales_rights_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for SalesRights {
+    type NewEntity = NewSalesRights;
+    type PatchEntity = PatchSalesRights;
+    type OrderByEntity = SalesRightsOrderBy;
+    type FilterParameter1 = SalesRightsType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.sales_rights_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        sales_rights_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<SalesRights>> {
+        use crate::schema::sales_rights::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::sales_rights
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::sales_rights::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            SalesRightsField::SalesRightsId => match order.direction {
+                Direction::Asc => query.order(dsl::sales_rights_id.asc()),
+                Direction::Desc => query.order(dsl::sales_rights_id.desc()),
+            },
+            SalesRightsField::PublicationId => match order.direction {
+                Direction::Asc => query.order(dsl::publication_id.asc()),
+                Direction::Desc => query.order(dsl::publication_id.desc()),
+            },
+            SalesRightsField::SalesRightsType => match order.direction {
+                Direction::Asc => query.order(dsl::sales_rights_type.asc()),
+                Direction::Desc => query.order(dsl::sales_rights_type.desc()),
+            },
+            SalesRightsField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            SalesRightsField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::publication_id.eq(pid));
+        }
+        if !sales_rights_types.is_empty() {
+            query = query.filter(dsl::sales_rights_type.eq_any(sales_rights_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<SalesRights>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        _: Vec<Uuid>,
+        sales_rights_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::sales_rights::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::sales_rights.into_boxed();
+        if !sales_rights_types.is_empty() {
+            query = query.filter(dsl::sales_rights_type.eq_any(sales_rights_types));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::publication::Publication::from_id(db, &self.publication_id)?.publisher_id(db)
+    }
+
+    crud_methods!(sales_rights::table, sales_rights::dsl::sales_rights);
+}
+
+impl HistoryEntry for SalesRights {
+    type NewHistoryEntity = NewSalesRightsHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            sales_rights_id: self.sales_rights_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewSalesRightsHistory {
+    type MainEntity = SalesRightsHistory;
+
+    db_insert!(sales_rights_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sales_rights_pk() {
+        let sales_rights: SalesRights = Default::default();
+        assert_eq!(sales_rights.pk(), sales_rights.sales_rights_id);
+    }
+
+    #[test]
+    fn test_new_sales_rights_history_from_sales_rights() {
+        let sales_rights: SalesRights = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_sales_rights_history = sales_rights.new_history_entry(&account_id);
+        assert_eq!(
+            new_sales_rights_history.sales_rights_id,
+            sales_rights.sales_rights_id
+        );
+        assert_eq!(new_sales_rights_history.account_id, account_id);
+        assert_eq!(
+            new_sales_rights_history.data,
+            serde_json::Value::String(serde_json::to_string(&sales_rights).unwrap())
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::institution::CountryCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::sales_rights;
+#[cfg(feature = "backend")]
+use crate::schema::sales_rights_history;
+use thoth_errors::{ThothError, ThothResult};
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of rights held in a territory (ONIX codelist 46)"),
+    ExistingTypePath = "crate::schema::sql_types::SalesRightsType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum SalesRightsType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "For sale with exclusive rights in the specified territory")
+    )]
+    #[default]
+    ForSaleWithExclusiveRights,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "For sale with non-exclusive rights in the specified territory")
+    )]
+    ForSaleWithNonExclusiveRights,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Not for sale in the specified territory")
+    )]
+    NotForSale,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Restriction on the outlets through which a publication may be sold (ONIX codelist 71)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::SalesRestrictionType"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum SalesRestrictionType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Restriction described in the sales restriction note")
+    )]
+    Unspecified,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "For internal publisher use only: do not list")
+    )]
+    InternalPublisherUseOnly,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "For sale only through the designated retailer")
+    )]
+    RetailerExclusive,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "For sale only through the designated retailer, under its own brand"
+        )
+    )]
+    RetailerOwnBrand,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting sales rights list")
+)]
+pub enum SalesRightsField {
+    SalesRightsId,
+    PublicationId,
+    SalesRightsType,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRights {
+    pub sales_rights_id: Uuid,
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub sales_restriction_type: Option<SalesRestrictionType>,
+    pub sales_restriction_note: Option<String>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define new sales rights of a publication"),
+    diesel(table_name = sales_rights)
+)]
+pub struct NewSalesRights {
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub sales_restriction_type: Option<SalesRestrictionType>,
+    pub sales_restriction_note: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update existing sales rights of a publication"),
+    diesel(table_name = sales_rights, treat_none_as_null = true)
+)]
+pub struct PatchSalesRights {
+    pub sales_rights_id: Uuid,
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub sales_restriction_type: Option<SalesRestrictionType>,
+    pub sales_restriction_note: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct SalesRightsHistory {
+    pub sales_rights_history_id: Uuid,
+    pub sales_rights_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = sales_rights_history)
+)]
+pub struct NewSalesRightsHistory {
+    pub sales_rights_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+/// Convert a region code to the upper case form used by ONIX codelist 49, rejecting anything
+/// other than `WORLD`, `ECZ` or an ISO 3166-2 subdivision such as `GB-SCT`
+pub fn standardise_region_code(input: &str) -> ThothResult<String> {
+    let region = input.trim().to_ascii_uppercase();
+    let is_subdivision = match region.split_once('-') {
+        Some((country, subdivision)) => {
+            country.len() == 2
+                && country.chars().all(|c| c.is_ascii_uppercase())
+                && (1..=3).contains(&subdivision.len())
+                && subdivision.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        None => false,
+    };
+    if is_subdivision || region == "WORLD" || region == "ECZ" {
+        Ok(region)
+    } else {
+        Err(ThothError::RegionCodeParseError(input.trim().to_string()))
+    }
+}
+
+pub(crate) fn standardise_regions(regions: Vec<String>) -> ThothResult<Vec<String>> {
+    let mut standardised: Vec<String> = Vec::with_capacity(regions.len());
+    for region in regions.iter().map(|r| standardise_region_code(r)) {
+        let region = region?;
+        if !standardised.contains(&region) {
+            standardised.push(region);
+        }
+    }
+    Ok(standardised)
+}
+
+pub(crate) fn dedup_countries(countries: Vec<CountryCode>) -> Vec<CountryCode> {
+    let mut deduped: Vec<CountryCode> = Vec::with_capacity(countries.len());
+    for country in countries {
+        if !deduped.contains(&country) {
+            deduped.push(country);
+        }
+    }
+    deduped
+}
+
+/// Check that countries and regions are only excluded from an included region
+pub(crate) fn validate_exclusions(
+    countries_excluded: &[CountryCode],
+    regions_included: &[String],
+    regions_excluded: &[String],
+) -> ThothResult<()> {
+    if regions_included.is_empty()
+        && !(countries_excluded.is_empty() && regions_excluded.is_empty())
+    {
+        return Err(ThothError::TerritoryExclusionError);
+    }
+    if regions_excluded.iter().any(|r| r == "WORLD" || r == "ECZ") {
+        return Err(ThothError::TerritoryExclusionError);
+    }
+    Ok(())
+}
+
+/// Validate the territory of a set of sales rights: it must include at least one country or
+/// region, and exclusions are only meaningful within an included region
+fn validate_territory(
+    countries_included: &[CountryCode],
+    countries_excluded: &[CountryCode],
+    regions_included: &[String],
+    regions_excluded: &[String],
+) -> ThothResult<()> {
+    if countries_included.is_empty() && regions_included.is_empty() {
+        return Err(ThothError::SalesRightsTerritoryError);
+    }
+    validate_exclusions(countries_excluded, regions_included, regions_excluded)
+}
+
+/// Validate a sales restriction: a note must describe a restriction, and an unspecified
+/// restriction is only meaningful with a note
+fn validate_restriction(
+    sales_restriction_type: Option<SalesRestrictionType>,
+    sales_restriction_note: Option<&str>,
+) -> ThothResult<()> {
+    match (sales_restriction_type, sales_restriction_note) {
+        (None, Some(_)) | (Some(SalesRestrictionType::Unspecified), None) => {
+            Err(ThothError::SalesRestrictionNoteError)
+        }
+        _ => Ok(()),
+    }
+}
+
+macro_rules! standardise_sales_rights {
+    ($t:ty) => {
+        impl $t {
+            /// Validate the territory and sales restriction, removing duplicates and
+            /// standardising region codes
+            pub fn standardise(mut self) -> ThothResult<Self> {
+                self.countries_included = dedup_countries(self.countries_included);
+                self.countries_excluded = dedup_countries(self.countries_excluded);
+                self.regions_included = standardise_regions(self.regions_included)?;
+                self.regions_excluded = standardise_regions(self.regions_excluded)?;
+                validate_territory(
+                    &self.countries_included,
+                    &self.countries_excluded,
+                    &self.regions_included,
+                    &self.regions_excluded,
+                )?;
+                self.sales_restriction_note = self
+                    .sales_restriction_note
+                    .map(|note| note.trim().to_string())
+                    .filter(|note| !note.is_empty());
+                validate_restriction(
+                    self.sales_restriction_type,
+                    self.sales_restriction_note.as_deref(),
+                )?;
+                Ok(self)
+            }
+        }
+    };
+}
+
+standardise_sales_rights!(NewSalesRights);
+standardise_sales_rights!(PatchSalesRights);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_sales_rights(
+        countries_included: Vec<CountryCode>,
+        countries_excluded: Vec<CountryCode>,
+        regions_included: Vec<&str>,
+    ) -> NewSalesRights {
+        NewSalesRights {
+            publication_id: Default::default(),
+            sales_rights_type: Default::default(),
+            countries_included,
+            countries_excluded,
+            regions_included: regions_included.into_iter().map(String::from).collect(),
+            regions_excluded: vec![],
+            sales_restriction_type: None,
+            sales_restriction_note: None,
+        }
+    }
+
+    #[test]
+    fn test_sales_rights_type_default() {
+        let sales_rights_type: SalesRightsType = Default::default();
+        assert_eq!(
+            sales_rights_type,
+            SalesRightsType::ForSaleWithExclusiveRights
+        );
+    }
+
+    #[test]
+    fn test_sales_rights_type_display() {
+        assert_eq!(
+            format!("{}", SalesRightsType::ForSaleWithNonExclusiveRights),
+            "For Sale With Non Exclusive Rights"
+        );
+        assert_eq!(format!("{}", SalesRightsType::NotForSale), "Not For Sale");
+    }
+
+    #[test]
+    fn test_standardise_region_code() {
+        assert_eq!(standardise_region_code("WORLD").unwrap(), "WORLD");
+        assert_eq!(standardise_region_code(" ecz ").unwrap(), "ECZ");
+        assert_eq!(standardise_region_code("gb-sct").unwrap(), "GB-SCT");
+        assert_eq!(standardise_region_code("ES-CN").unwrap(), "ES-CN");
+        assert!(standardise_region_code("GBR").is_err());
+        assert!(standardise_region_code("GB-").is_err());
+        assert!(standardise_region_code("GB-ABCD").is_err());
+        assert!(standardise_region_code("").is_err());
+    }
+
+    #[test]
+    fn test_new_sales_rights_standardise() {
+        let sales_rights = new_sales_rights(
+            vec![],
+            vec![CountryCode::Usa, CountryCode::Can, CountryCode::Usa],
+            vec!["world", "WORLD"],
+        )
+        .standardise()
+        .unwrap();
+        assert_eq!(sales_rights.regions_included, vec!["WORLD"]);
+        assert_eq!(
+            sales_rights.countries_excluded,
+            vec![CountryCode::Usa, CountryCode::Can]
+        );
+
+        assert!(new_sales_rights(vec![CountryCode::Gbr], vec![], vec![])
+            .standardise()
+            .is_ok());
+        assert_eq!(
+            new_sales_rights(vec![], vec![], vec![]).standardise().err(),
+            Some(ThothError::SalesRightsTerritoryError)
+        );
+        assert_eq!(
+            new_sales_rights(vec![CountryCode::Gbr], vec![CountryCode::Irl], vec![])
+                .standardise()
+                .err(),
+            Some(ThothError::TerritoryExclusionError)
+        );
+        assert_eq!(
+            new_sales_rights(vec![], vec![], vec!["Europe"])
+                .standardise()
+                .err(),
+            Some(ThothError::RegionCodeParseError("Europe".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_new_sales_rights_restriction() {
+        let with_restriction = |restriction_type, note: Option<&str>| NewSalesRights {
+            sales_restriction_type: restriction_type,
+            sales_restriction_note: note.map(String::from),
+            ..new_sales_rights(vec![CountryCode::Gbr], vec![], vec![])
+        };
+
+        let sales_rights = with_restriction(
+            Some(SalesRestrictionType::RetailerExclusive),
+            Some("  Exclusive to Bookshop Ltd  "),
+        )
+        .standardise()
+        .unwrap();
+        assert_eq!(
+            sales_rights.sales_restriction_note,
+            Some("Exclusive to Bookshop Ltd".to_string())
+        );
+        assert!(
+            with_restriction(Some(SalesRestrictionType::RetailerOwnBrand), None)
+                .standardise()
+                .is_ok()
+        );
+        assert_eq!(
+            with_restriction(None, Some("Exclusive to Bookshop Ltd"))
+                .standardise()
+                .err(),
+            Some(ThothError::SalesRestrictionNoteError)
+        );
+        assert_eq!(
+            with_restriction(Some(SalesRestrictionType::Unspecified), Some("   "))
+                .standardise()
+                .err(),
+            Some(ThothError::SalesRestrictionNoteError)
+        );
+        assert!(with_restriction(None, Some(" ")).standardise().is_ok());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
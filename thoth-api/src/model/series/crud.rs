@@ -9,7 +9,7 @@ use crate::{crud_methods, db_insert};
 use diesel::{
     BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
 };
-use thoth_errors::ThothResult;
+use thoth_errors::{ThothError, ThothResult};
 use uuid::Uuid;
 
 impl Crud for Series {
@@ -31,7 +31,7 @@ impl Crud for Series {
         filter: Option<String>,
         order: Self::OrderByEntity,
         publishers: Vec<Uuid>,
-        _: Option<Uuid>,
+        parent_id_1: Option<Uuid>,
         _: Option<Uuid>,
         series_types: Vec<Self::FilterParameter1>,
         _: Vec<Self::FilterParameter2>,
@@ -77,6 +77,10 @@ impl Crud for Series {
                 Direction::Asc => query.order(series_cfp_url.asc()),
                 Direction::Desc => query.order(series_cfp_url.desc()),
             },
+            SeriesField::ParentSeriesId => match order.direction {
+                Direction::Asc => query.order(parent_series_id.asc()),
+                Direction::Desc => query.order(parent_series_id.desc()),
+            },
             SeriesField::CreatedAt => match order.direction {
                 Direction::Asc => query.order(created_at.asc()),
                 Direction::Desc => query.order(created_at.desc()),
@@ -89,6 +93,9 @@ impl Crud for Series {
         if !publishers.is_empty() {
             query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
         }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(parent_series_id.eq(pid));
+        }
         if !series_types.is_empty() {
             query = query.filter(series_type.eq_any(series_types));
         }
@@ -176,6 +183,63 @@ impl DbInsert for NewSeriesHistory {
     db_insert!(series_history::table);
 }
 
+impl NewSeries {
+    pub fn parent_series_valid(&self, db: &crate::db::PgPool) -> ThothResult<()> {
+        series_parent_valid(None, self.imprint_id, self.parent_series_id, db)
+    }
+}
+
+impl PatchSeries {
+    pub fn parent_series_valid(&self, db: &crate::db::PgPool) -> ThothResult<()> {
+        series_parent_valid(
+            Some(self.series_id),
+            self.imprint_id,
+            self.parent_series_id,
+            db,
+        )
+    }
+}
+
+fn series_parent_valid(
+    series_id: Option<Uuid>,
+    imprint_id: Uuid,
+    parent_series_id: Option<Uuid>,
+    db: &crate::db::PgPool,
+) -> ThothResult<()> {
+    let Some(parent_series_id) = parent_series_id else {
+        return Ok(());
+    };
+    let parent = Series::from_id(db, &parent_series_id)?;
+    let has_subseries = match series_id {
+        Some(series_id) => {
+            let mut connection = db.get()?;
+            diesel::select(diesel::dsl::exists(
+                series::table.filter(series::parent_series_id.eq(series_id)),
+            ))
+            .get_result::<bool>(&mut connection)?
+        }
+        None => false,
+    };
+    check_series_parent(series_id, imprint_id, &parent, has_subseries)
+}
+
+/// Subseries are exported beneath a single parent series, so a series can either be
+/// a subseries or have subseries of its own, but not both
+fn check_series_parent(
+    series_id: Option<Uuid>,
+    imprint_id: Uuid,
+    parent: &Series,
+    has_subseries: bool,
+) -> ThothResult<()> {
+    if parent.imprint_id != imprint_id {
+        return Err(ThothError::SeriesImprintsError);
+    }
+    if Some(parent.series_id) == series_id || parent.parent_series_id.is_some() || has_subseries {
+        return Err(ThothError::SeriesHierarchyError);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +262,46 @@ mod tests {
             serde_json::Value::String(serde_json::to_string(&series).unwrap())
         );
     }
+
+    #[test]
+    fn test_check_series_parent() {
+        let imprint_id = Uuid::new_v4();
+        let series_id = Uuid::new_v4();
+        let parent = Series {
+            series_id: Uuid::new_v4(),
+            imprint_id,
+            ..Default::default()
+        };
+        assert!(check_series_parent(None, imprint_id, &parent, false).is_ok());
+        assert!(check_series_parent(Some(series_id), imprint_id, &parent, false).is_ok());
+        assert_eq!(
+            check_series_parent(Some(series_id), Uuid::new_v4(), &parent, false),
+            Err(ThothError::SeriesImprintsError)
+        );
+        // A series cannot be its own parent
+        assert_eq!(
+            check_series_parent(Some(parent.series_id), imprint_id, &parent, false),
+            Err(ThothError::SeriesHierarchyError)
+        );
+        // A series with subseries cannot become a subseries itself
+        assert_eq!(
+            check_series_parent(Some(series_id), imprint_id, &parent, true),
+            Err(ThothError::SeriesHierarchyError)
+        );
+        // Nor can a subseries become the parent of another series
+        let subseries = Series {
+            series_id: Uuid::new_v4(),
+            imprint_id,
+            parent_series_id: Some(parent.series_id),
+            ..Default::default()
+        };
+        assert_eq!(
+            check_series_parent(None, imprint_id, &subseries, false),
+            Err(ThothError::SeriesHierarchyError)
+        );
+        assert_eq!(
+            check_series_parent(Some(series_id), imprint_id, &subseries, false),
+            Err(ThothError::SeriesHierarchyError)
+        );
+    }
 }
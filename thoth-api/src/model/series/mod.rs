@@ -65,6 +65,7 @@ pub enum SeriesField {
     SeriesDescription,
     #[strum(serialize = "SeriesCFPURL")]
     SeriesCfpUrl,
+    ParentSeriesId,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -82,6 +83,7 @@ pub struct Series {
     pub updated_at: Timestamp,
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
+    pub parent_series_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -95,6 +97,7 @@ pub struct SeriesWithImprint {
     pub series_url: Option<String>,
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
+    pub parent_series_id: Option<Uuid>,
     pub updated_at: Timestamp,
     pub imprint: ImprintWithPublisher,
 }
@@ -114,6 +117,7 @@ pub struct NewSeries {
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
     pub imprint_id: Uuid,
+    pub parent_series_id: Option<Uuid>,
 }
 
 #[cfg_attr(
@@ -132,6 +136,7 @@ pub struct PatchSeries {
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
     pub imprint_id: Uuid,
+    pub parent_series_id: Option<Uuid>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -212,6 +217,7 @@ fn test_seriesfield_display() {
         "SeriesDescription"
     );
     assert_eq!(format!("{}", SeriesField::SeriesCfpUrl), "SeriesCFPURL");
+    assert_eq!(format!("{}", SeriesField::ParentSeriesId), "ParentSeriesId");
     assert_eq!(format!("{}", SeriesField::CreatedAt), "CreatedAt");
     assert_eq!(format!("{}", SeriesField::UpdatedAt), "UpdatedAt");
 }
@@ -0,0 +1,189 @@
+use super::{
+    NewSeriesContribution, NewSeriesContributionHistory, PatchSeriesContribution,
+    SeriesContribution, SeriesContributionField, SeriesContributionHistory, SeriesContributionType,
+};
+use crate::graphql::model::SeriesContributionOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{series_contribution, series_contribution_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for SeriesContribution {
+    type NewEntity = NewSeriesContribution;
+    type PatchEntity = PatchSeriesContribution;
+    type OrderByEntity = SeriesContributionOrderBy;
+    type FilterParameter1 = SeriesContributionType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.series_contribution_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        parent_id_2: Option<Uuid>,
+        contribution_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<SeriesContribution>> {
+        use crate::schema::series_contribution::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::series_contribution
+            .inner_join(crate::schema::series::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::series_contribution::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            SeriesContributionField::SeriesContributionId => match order.direction {
+                Direction::Asc => query.order(dsl::series_contribution_id.asc()),
+                Direction::Desc => query.order(dsl::series_contribution_id.desc()),
+            },
+            SeriesContributionField::SeriesId => match order.direction {
+                Direction::Asc => query.order(dsl::series_id.asc()),
+                Direction::Desc => query.order(dsl::series_id.desc()),
+            },
+            SeriesContributionField::ContributorId => match order.direction {
+                Direction::Asc => query.order(dsl::contributor_id.asc()),
+                Direction::Desc => query.order(dsl::contributor_id.desc()),
+            },
+            SeriesContributionField::ContributionType => match order.direction {
+                Direction::Asc => query.order(dsl::contribution_type.asc()),
+                Direction::Desc => query.order(dsl::contribution_type.desc()),
+            },
+            SeriesContributionField::FirstName => match order.direction {
+                Direction::Asc => query.order(dsl::first_name.asc()),
+                Direction::Desc => query.order(dsl::first_name.desc()),
+            },
+            SeriesContributionField::LastName => match order.direction {
+                Direction::Asc => query.order(dsl::last_name.asc()),
+                Direction::Desc => query.order(dsl::last_name.desc()),
+            },
+            SeriesContributionField::FullName => match order.direction {
+                Direction::Asc => query.order(dsl::full_name.asc()),
+                Direction::Desc => query.order(dsl::full_name.desc()),
+            },
+            SeriesContributionField::ContributionOrdinal => match order.direction {
+                Direction::Asc => query.order(dsl::contribution_ordinal.asc()),
+                Direction::Desc => query.order(dsl::contribution_ordinal.desc()),
+            },
+            SeriesContributionField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            SeriesContributionField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::series_id.eq(pid));
+        }
+        if let Some(pid) = parent_id_2 {
+            query = query.filter(dsl::contributor_id.eq(pid));
+        }
+        if !contribution_types.is_empty() {
+            query = query.filter(dsl::contribution_type.eq_any(contribution_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<SeriesContribution>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        _: Vec<Uuid>,
+        contribution_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::series_contribution::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::series_contribution.into_boxed();
+        if !contribution_types.is_empty() {
+            query = query.filter(dsl::contribution_type.eq_any(contribution_types));
+        }
+
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::series::Series::from_id(db, &self.series_id)?.publisher_id(db)
+    }
+
+    crud_methods!(
+        series_contribution::table,
+        series_contribution::dsl::series_contribution
+    );
+}
+
+impl HistoryEntry for SeriesContribution {
+    type NewHistoryEntity = NewSeriesContributionHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            series_contribution_id: self.series_contribution_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewSeriesContributionHistory {
+    type MainEntity = SeriesContributionHistory;
+
+    db_insert!(series_contribution_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_contribution_pk() {
+        let series_contribution: SeriesContribution = Default::default();
+        assert_eq!(
+            series_contribution.pk(),
+            series_contribution.series_contribution_id
+        );
+    }
+
+    #[test]
+    fn test_new_series_contribution_history_from_series_contribution() {
+        let series_contribution: SeriesContribution = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_series_contribution_history = series_contribution.new_history_entry(&account_id);
+        assert_eq!(
+            new_series_contribution_history.series_contribution_id,
+            series_contribution.series_contribution_id
+        );
+        assert_eq!(new_series_contribution_history.account_id, account_id);
+        assert_eq!(
+            new_series_contribution_history.data,
+            serde_json::Value::String(serde_json::to_string(&series_contribution).unwrap())
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::series_contribution;
+#[cfg(feature = "backend")]
+use crate::schema::series_contribution_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Role describing the type of contribution to a series as a whole"),
+    ExistingTypePath = "crate::schema::sql_types::SeriesContributionType"
+)]
+#[derive(
+    Debug, Clone, Default, Copy, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum SeriesContributionType {
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "series-editor",
+        graphql(description = "Editor of the series")
+    )]
+    #[default]
+    SeriesEditor,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "editor-in-chief",
+        graphql(description = "Editor with overall responsibility for the series")
+    )]
+    EditorInChief,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "managing-editor",
+        graphql(description = "Editor responsible for the day-to-day running of the series")
+    )]
+    ManagingEditor,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "associate-editor",
+        graphql(description = "Editor assisting the editor or editor-in-chief of the series")
+    )]
+    AssociateEditor,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "editorial-board-member",
+        graphql(description = "Member of the editorial board of the series")
+    )]
+    EditorialBoardMember,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting series contributions list")
+)]
+pub enum SeriesContributionField {
+    SeriesContributionId,
+    SeriesId,
+    ContributorId,
+    ContributionType,
+    FirstName,
+    LastName,
+    FullName,
+    ContributionOrdinal,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesContribution {
+    pub series_contribution_id: Uuid,
+    pub series_id: Uuid,
+    pub contributor_id: Uuid,
+    pub contribution_type: SeriesContributionType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub contribution_ordinal: i32,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new individual involvement in the editing of a series"),
+    diesel(table_name = series_contribution)
+)]
+pub struct NewSeriesContribution {
+    pub series_id: Uuid,
+    pub contributor_id: Uuid,
+    pub contribution_type: SeriesContributionType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub contribution_ordinal: i32,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an individual involvement in the editing of a series"),
+    diesel(table_name = series_contribution, treat_none_as_null = true)
+)]
+pub struct PatchSeriesContribution {
+    pub series_contribution_id: Uuid,
+    pub series_id: Uuid,
+    pub contributor_id: Uuid,
+    pub contribution_type: SeriesContributionType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub contribution_ordinal: i32,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct SeriesContributionHistory {
+    pub series_contribution_history_id: Uuid,
+    pub series_contribution_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = series_contribution_history)
+)]
+pub struct NewSeriesContributionHistory {
+    pub series_contribution_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl Default for SeriesContribution {
+    fn default() -> SeriesContribution {
+        SeriesContribution {
+            series_contribution_id: Default::default(),
+            series_id: Default::default(),
+            contributor_id: Default::default(),
+            contribution_type: Default::default(),
+            first_name: Default::default(),
+            last_name: Default::default(),
+            full_name: Default::default(),
+            contribution_ordinal: 1,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_seriescontributiontype_default() {
+        let contribution_type: SeriesContributionType = Default::default();
+        assert_eq!(contribution_type, SeriesContributionType::SeriesEditor);
+    }
+
+    #[test]
+    fn test_seriescontributiontype_display() {
+        assert_eq!(
+            format!("{}", SeriesContributionType::SeriesEditor),
+            "Series Editor"
+        );
+        assert_eq!(
+            format!("{}", SeriesContributionType::EditorInChief),
+            "Editor In Chief"
+        );
+        assert_eq!(
+            format!("{}", SeriesContributionType::ManagingEditor),
+            "Managing Editor"
+        );
+        assert_eq!(
+            format!("{}", SeriesContributionType::AssociateEditor),
+            "Associate Editor"
+        );
+        assert_eq!(
+            format!("{}", SeriesContributionType::EditorialBoardMember),
+            "Editorial Board Member"
+        );
+    }
+
+    #[test]
+    fn test_seriescontributiontype_fromstr() {
+        assert_eq!(
+            SeriesContributionType::from_str("Series Editor").unwrap(),
+            SeriesContributionType::SeriesEditor
+        );
+        assert_eq!(
+            SeriesContributionType::from_str("Editorial Board Member").unwrap(),
+            SeriesContributionType::EditorialBoardMember
+        );
+        assert!(SeriesContributionType::from_str("Author").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
@@ -0,0 +1,240 @@
+use super::{
+    CoverRendition, NewCoverRendition, NewSupportingResource, NewSupportingResourceHistory,
+    PatchSupportingResource, ResourceContentType, SupportingResource, SupportingResourceField,
+    SupportingResourceHistory,
+};
+use crate::graphql::model::SupportingResourceOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{supporting_resource, supporting_resource_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl,
+    RunQueryDsl,
+};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for SupportingResource {
+    type NewEntity = NewSupportingResource;
+    type PatchEntity = PatchSupportingResource;
+    type OrderByEntity = SupportingResourceOrderBy;
+    type FilterParameter1 = ResourceContentType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.supporting_resource_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        resource_content_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<SupportingResource>> {
+        use crate::schema::supporting_resource::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::supporting_resource
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::supporting_resource::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            SupportingResourceField::SupportingResourceId => match order.direction {
+                Direction::Asc => query.order(dsl::supporting_resource_id.asc()),
+                Direction::Desc => query.order(dsl::supporting_resource_id.desc()),
+            },
+            SupportingResourceField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            SupportingResourceField::ResourceContentType => match order.direction {
+                Direction::Asc => query.order(dsl::resource_content_type.asc()),
+                Direction::Desc => query.order(dsl::resource_content_type.desc()),
+            },
+            SupportingResourceField::ContentAudience => match order.direction {
+                Direction::Asc => query.order(dsl::content_audience.asc()),
+                Direction::Desc => query.order(dsl::content_audience.desc()),
+            },
+            SupportingResourceField::ResourceMode => match order.direction {
+                Direction::Asc => query.order(dsl::resource_mode.asc()),
+                Direction::Desc => query.order(dsl::resource_mode.desc()),
+            },
+            SupportingResourceField::ResourceForm => match order.direction {
+                Direction::Asc => query.order(dsl::resource_form.asc()),
+                Direction::Desc => query.order(dsl::resource_form.desc()),
+            },
+            SupportingResourceField::ResourceUrl => match order.direction {
+                Direction::Asc => query.order(dsl::resource_url.asc()),
+                Direction::Desc => query.order(dsl::resource_url.desc()),
+            },
+            SupportingResourceField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            SupportingResourceField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !resource_content_types.is_empty() {
+            query = query.filter(dsl::resource_content_type.eq_any(resource_content_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::resource_url
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::caption.ilike(format!("%{filter}%"))),
+            );
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<SupportingResource>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        resource_content_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::supporting_resource::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::supporting_resource.into_boxed();
+        if !resource_content_types.is_empty() {
+            query = query.filter(dsl::resource_content_type.eq_any(resource_content_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::resource_url
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::caption.ilike(format!("%{filter}%"))),
+            );
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(
+        supporting_resource::table,
+        supporting_resource::dsl::supporting_resource
+    );
+}
+
+impl HistoryEntry for SupportingResource {
+    type NewHistoryEntity = NewSupportingResourceHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            supporting_resource_id: self.supporting_resource_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewSupportingResourceHistory {
+    type MainEntity = SupportingResourceHistory;
+
+    db_insert!(supporting_resource_history::table);
+}
+
+impl SupportingResource {
+    /// Find the given rendition of a work's uploaded cover, if one has been generated
+    pub fn from_cover_rendition(
+        db: &crate::db::PgPool,
+        work_id: &Uuid,
+        cover_rendition: &CoverRendition,
+    ) -> ThothResult<Option<SupportingResource>> {
+        use crate::schema::supporting_resource::dsl;
+        dsl::supporting_resource
+            .filter(dsl::work_id.eq(work_id))
+            .filter(dsl::cover_rendition.eq(cover_rendition))
+            .first::<SupportingResource>(&mut db.get()?)
+            .optional()
+            .map_err(Into::into)
+    }
+
+    /// Remove the renditions generated from a work's previous cover
+    pub fn delete_cover_renditions(
+        connection: &mut diesel::PgConnection,
+        work_id: &Uuid,
+    ) -> ThothResult<usize> {
+        use crate::schema::supporting_resource::dsl;
+        diesel::delete(
+            dsl::supporting_resource
+                .filter(dsl::work_id.eq(work_id))
+                .filter(dsl::cover_rendition.is_not_null()),
+        )
+        .execute(connection)
+        .map_err(Into::into)
+    }
+}
+
+impl NewCoverRendition {
+    pub fn create(&self, db: &crate::db::PgPool) -> ThothResult<SupportingResource> {
+        diesel::insert_into(supporting_resource::table)
+            .values(self)
+            .get_result::<SupportingResource>(&mut db.get()?)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supporting_resource_pk() {
+        let supporting_resource: SupportingResource = Default::default();
+        assert_eq!(
+            supporting_resource.pk(),
+            supporting_resource.supporting_resource_id
+        );
+    }
+
+    #[test]
+    fn test_new_supporting_resource_history_from_supporting_resource() {
+        let supporting_resource: SupportingResource = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_supporting_resource_history = supporting_resource.new_history_entry(&account_id);
+        assert_eq!(
+            new_supporting_resource_history.supporting_resource_id,
+            supporting_resource.supporting_resource_id
+        );
+        assert_eq!(new_supporting_resource_history.account_id, account_id);
+        assert_eq!(
+            new_supporting_resource_history.data,
+            serde_json::Value::String(serde_json::to_string(&supporting_resource).unwrap())
+        );
+    }
+}
@@ -0,0 +1,536 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::asset::Asset;
+use crate::model::text_content::ContentAudience;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::supporting_resource;
+#[cfg(feature = "backend")]
+use crate::schema::supporting_resource_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Type of resource supporting the promotion of a work (ONIX codelist 158)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::ResourceContentType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum ResourceContentType {
+    #[cfg_attr(feature = "backend", graphql(description = "2D front cover image"))]
+    #[default]
+    FrontCover,
+    #[cfg_attr(feature = "backend", graphql(description = "2D back cover image"))]
+    BackCover,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Image of the full cover, including front, back and spine")
+    )]
+    FullCover,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Picture of the product, usually in 3D")
+    )]
+    CoverPackshot,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Low-resolution version of the front cover image")
+    )]
+    CoverThumbnail,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Photograph or portrait of a contributor")
+    )]
+    ContributorPicture,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Interview with a contributor")
+    )]
+    ContributorInterview,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Extract from the work, such as a sample chapter")
+    )]
+    SampleContent,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Questions and suggestions for discussion of the work by reading groups"
+        )
+    )]
+    ReadingGroupGuide,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Guide for teachers using the work in the classroom")
+    )]
+    #[strum(serialize = "Teacher's Guide")]
+    TeachersGuide,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Press release about the work")
+    )]
+    PressRelease,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Promotional video for the work")
+    )]
+    Trailer,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Predominant mode of a supporting resource (ONIX codelist 159)"),
+    ExistingTypePath = "crate::schema::sql_types::ResourceMode"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum ResourceMode {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "An executable package, program or widget")
+    )]
+    Application,
+    #[cfg_attr(feature = "backend", graphql(description = "Audio recording"))]
+    Audio,
+    #[cfg_attr(feature = "backend", graphql(description = "Still image"))]
+    #[default]
+    Image,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Readable text, with or without associated images")
+    )]
+    Text,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Moving images, with or without sound")
+    )]
+    Video,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "A website or other supporting resource combining several modes")
+    )]
+    #[strum(serialize = "Multi-mode")]
+    MultiMode,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Form in which a supporting resource is made available (ONIX codelist 161)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::ResourceForm"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum ResourceForm {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "A resource that may be accessed by a hyperlink, such as a web page"
+        )
+    )]
+    LinkableResource,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "A file that may be downloaded on demand")
+    )]
+    #[default]
+    DownloadableFile,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "An application which is supplied in a form which can be embedded into a third-party webpage"
+        )
+    )]
+    EmbeddableApplication,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Size of a cover image generated from an uploaded cover, either for general use or to the specification of a retailer"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::CoverRendition"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum CoverRendition {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Thumbnail, at most 200 pixels wide")
+    )]
+    #[default]
+    Thumbnail,
+    #[cfg_attr(feature = "backend", graphql(description = "At most 400 pixels wide"))]
+    Small,
+    #[cfg_attr(feature = "backend", graphql(description = "At most 800 pixels wide"))]
+    Medium,
+    #[cfg_attr(feature = "backend", graphql(description = "At most 1600 pixels wide"))]
+    Large,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Amazon's preferred size, within 1600 by 2560 pixels")
+    )]
+    Amazon,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Apple Books' preferred size, within 1600 by 2400 pixels")
+    )]
+    AppleBooks,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Google Play Books' preferred size, within 1280 by 2048 pixels")
+    )]
+    GooglePlay,
+}
+
+pub const COVER_RENDITIONS: [CoverRendition; 7] = [
+    CoverRendition::Thumbnail,
+    CoverRendition::Small,
+    CoverRendition::Medium,
+    CoverRendition::Large,
+    CoverRendition::Amazon,
+    CoverRendition::AppleBooks,
+    CoverRendition::GooglePlay,
+];
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting supporting resources list")
+)]
+pub enum SupportingResourceField {
+    SupportingResourceId,
+    WorkId,
+    ResourceContentType,
+    ContentAudience,
+    ResourceMode,
+    ResourceForm,
+    ResourceUrl,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportingResource {
+    pub supporting_resource_id: Uuid,
+    pub work_id: Uuid,
+    pub resource_content_type: ResourceContentType,
+    pub content_audience: ContentAudience,
+    pub resource_mode: ResourceMode,
+    pub resource_form: ResourceForm,
+    pub resource_url: String,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub width_px: Option<i32>,
+    pub height_px: Option<i32>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub cover_rendition: Option<CoverRendition>,
+    pub asset_id: Option<Uuid>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new resource supporting a work"),
+    diesel(table_name = supporting_resource)
+)]
+pub struct NewSupportingResource {
+    pub work_id: Uuid,
+    pub resource_content_type: ResourceContentType,
+    pub content_audience: ContentAudience,
+    pub resource_mode: ResourceMode,
+    pub resource_form: ResourceForm,
+    pub resource_url: String,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub width_px: Option<i32>,
+    pub height_px: Option<i32>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing resource supporting a work"),
+    diesel(table_name = supporting_resource, treat_none_as_null = true)
+)]
+pub struct PatchSupportingResource {
+    pub supporting_resource_id: Uuid,
+    pub work_id: Uuid,
+    pub resource_content_type: ResourceContentType,
+    pub content_audience: ContentAudience,
+    pub resource_mode: ResourceMode,
+    pub resource_form: ResourceForm,
+    pub resource_url: String,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub width_px: Option<i32>,
+    pub height_px: Option<i32>,
+}
+
+/// Rendition of an uploaded cover, generated by the server and recorded as a supporting resource
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = supporting_resource)
+)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewCoverRendition {
+    pub work_id: Uuid,
+    pub asset_id: Uuid,
+    pub cover_rendition: CoverRendition,
+    pub resource_content_type: ResourceContentType,
+    pub resource_mode: ResourceMode,
+    pub resource_form: ResourceForm,
+    pub resource_url: String,
+    pub width_px: i32,
+    pub height_px: i32,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct SupportingResourceHistory {
+    pub supporting_resource_history_id: Uuid,
+    pub supporting_resource_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = supporting_resource_history)
+)]
+pub struct NewSupportingResourceHistory {
+    pub supporting_resource_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl CoverRendition {
+    /// Name used in the rendition's storage key
+    pub fn name(&self) -> &'static str {
+        match self {
+            CoverRendition::Thumbnail => "thumbnail",
+            CoverRendition::Small => "small",
+            CoverRendition::Medium => "medium",
+            CoverRendition::Large => "large",
+            CoverRendition::Amazon => "amazon",
+            CoverRendition::AppleBooks => "apple_books",
+            CoverRendition::GooglePlay => "google_play",
+        }
+    }
+
+    /// Width and height, in pixels, the rendition must fit within. Smaller covers are not enlarged.
+    pub fn max_dimensions(&self) -> (u32, u32) {
+        match self {
+            CoverRendition::Thumbnail => (200, 400),
+            CoverRendition::Small => (400, 800),
+            CoverRendition::Medium => (800, 1600),
+            CoverRendition::Large => (1600, 3200),
+            CoverRendition::Amazon => (1600, 2560),
+            CoverRendition::AppleBooks => (1600, 2400),
+            CoverRendition::GooglePlay => (1280, 2048),
+        }
+    }
+
+    pub fn resource_content_type(&self) -> ResourceContentType {
+        match self {
+            CoverRendition::Thumbnail => ResourceContentType::CoverThumbnail,
+            _ => ResourceContentType::FrontCover,
+        }
+    }
+}
+
+impl NewCoverRendition {
+    pub fn new(
+        asset: &Asset,
+        cover_rendition: CoverRendition,
+        resource_url: String,
+        width_px: i32,
+        height_px: i32,
+    ) -> Self {
+        NewCoverRendition {
+            work_id: asset.work_id,
+            asset_id: asset.asset_id,
+            cover_rendition,
+            resource_content_type: cover_rendition.resource_content_type(),
+            resource_mode: ResourceMode::Image,
+            resource_form: ResourceForm::DownloadableFile,
+            resource_url,
+            width_px,
+            height_px,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_resource_content_type_default() {
+        let content_type: ResourceContentType = Default::default();
+        assert_eq!(content_type, ResourceContentType::FrontCover);
+    }
+
+    #[test]
+    fn test_resource_mode_default() {
+        let resource_mode: ResourceMode = Default::default();
+        assert_eq!(resource_mode, ResourceMode::Image);
+    }
+
+    #[test]
+    fn test_resource_form_default() {
+        let resource_form: ResourceForm = Default::default();
+        assert_eq!(resource_form, ResourceForm::DownloadableFile);
+    }
+
+    #[test]
+    fn test_resource_content_type_display() {
+        assert_eq!(
+            format!("{}", ResourceContentType::FrontCover),
+            "Front Cover"
+        );
+        assert_eq!(
+            format!("{}", ResourceContentType::ContributorPicture),
+            "Contributor Picture"
+        );
+        assert_eq!(
+            format!("{}", ResourceContentType::TeachersGuide),
+            "Teacher's Guide"
+        );
+    }
+
+    #[test]
+    fn test_resource_mode_display() {
+        assert_eq!(format!("{}", ResourceMode::Image), "Image");
+        assert_eq!(format!("{}", ResourceMode::MultiMode), "Multi-mode");
+    }
+
+    #[test]
+    fn test_resource_form_display() {
+        assert_eq!(
+            format!("{}", ResourceForm::LinkableResource),
+            "Linkable Resource"
+        );
+        assert_eq!(
+            format!("{}", ResourceForm::DownloadableFile),
+            "Downloadable File"
+        );
+    }
+
+    #[test]
+    fn test_resource_content_type_fromstr() {
+        assert_eq!(
+            ResourceContentType::from_str("Sample Content").unwrap(),
+            ResourceContentType::SampleContent
+        );
+        assert_eq!(
+            ResourceContentType::from_str("Back Cover").unwrap(),
+            ResourceContentType::BackCover
+        );
+        assert!(ResourceContentType::from_str("Cover").is_err());
+    }
+
+    #[test]
+    fn test_resource_mode_fromstr() {
+        assert_eq!(
+            ResourceMode::from_str("Video").unwrap(),
+            ResourceMode::Video
+        );
+        assert_eq!(
+            ResourceMode::from_str("Multi-mode").unwrap(),
+            ResourceMode::MultiMode
+        );
+        assert!(ResourceMode::from_str("Picture").is_err());
+    }
+
+    #[test]
+    fn test_cover_rendition_display() {
+        assert_eq!(format!("{}", CoverRendition::Thumbnail), "Thumbnail");
+        assert_eq!(format!("{}", CoverRendition::AppleBooks), "Apple Books");
+    }
+
+    #[test]
+    fn test_cover_rendition_fromstr() {
+        assert_eq!(
+            CoverRendition::from_str("Google Play").unwrap(),
+            CoverRendition::GooglePlay
+        );
+        assert!(CoverRendition::from_str("Huge").is_err());
+    }
+
+    #[test]
+    fn test_cover_rendition_resource_content_type() {
+        assert_eq!(
+            CoverRendition::Thumbnail.resource_content_type(),
+            ResourceContentType::CoverThumbnail
+        );
+        for rendition in &COVER_RENDITIONS[1..] {
+            assert_eq!(
+                rendition.resource_content_type(),
+                ResourceContentType::FrontCover
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_cover_rendition() {
+        let asset = Asset {
+            asset_id: Uuid::parse_str("00000000-0000-0000-aaaa-000000000001").unwrap(),
+            work_id: Uuid::parse_str("00000000-0000-0000-bbbb-000000000002").unwrap(),
+            ..Default::default()
+        };
+        let rendition = NewCoverRendition::new(
+            &asset,
+            CoverRendition::Thumbnail,
+            "https://example.com/thumbnail.jpg".to_string(),
+            200,
+            300,
+        );
+        assert_eq!(rendition.work_id, asset.work_id);
+        assert_eq!(rendition.asset_id, asset.asset_id);
+        assert_eq!(
+            rendition.resource_content_type,
+            ResourceContentType::CoverThumbnail
+        );
+        assert_eq!(rendition.resource_mode, ResourceMode::Image);
+    }
+
+    #[test]
+    fn test_resource_form_fromstr() {
+        assert_eq!(
+            ResourceForm::from_str("Embeddable Application").unwrap(),
+            ResourceForm::EmbeddableApplication
+        );
+        assert!(ResourceForm::from_str("Download").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
@@ -0,0 +1,193 @@
+use super::{
+    NewTextContent, NewTextContentHistory, PatchTextContent, TextContent, TextContentField,
+    TextContentHistory, TextContentType,
+};
+use crate::graphql::model::TextContentOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{text_content, text_content_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for TextContent {
+    type NewEntity = NewTextContent;
+    type PatchEntity = PatchTextContent;
+    type OrderByEntity = TextContentOrderBy;
+    type FilterParameter1 = TextContentType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.text_content_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        text_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<TextContent>> {
+        use crate::schema::text_content::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::text_content
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::text_content::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            TextContentField::TextContentId => match order.direction {
+                Direction::Asc => query.order(dsl::text_content_id.asc()),
+                Direction::Desc => query.order(dsl::text_content_id.desc()),
+            },
+            TextContentField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            TextContentField::TextType => match order.direction {
+                Direction::Asc => query.order(dsl::text_type.asc()),
+                Direction::Desc => query.order(dsl::text_type.desc()),
+            },
+            TextContentField::ContentAudience => match order.direction {
+                Direction::Asc => query.order(dsl::content_audience.asc()),
+                Direction::Desc => query.order(dsl::content_audience.desc()),
+            },
+            TextContentField::TextAuthor => match order.direction {
+                Direction::Asc => query.order(dsl::text_author.asc()),
+                Direction::Desc => query.order(dsl::text_author.desc()),
+            },
+            TextContentField::SourceTitle => match order.direction {
+                Direction::Asc => query.order(dsl::source_title.asc()),
+                Direction::Desc => query.order(dsl::source_title.desc()),
+            },
+            TextContentField::ContentDate => match order.direction {
+                Direction::Asc => query.order(dsl::content_date.asc()),
+                Direction::Desc => query.order(dsl::content_date.desc()),
+            },
+            TextContentField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            TextContentField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !text_types.is_empty() {
+            query = query.filter(dsl::text_type.eq_any(text_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::content
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::text_author.ilike(format!("%{filter}%")))
+                    .or(dsl::source_title.ilike(format!("%{filter}%"))),
+            );
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<TextContent>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        text_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::text_content::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::text_content.into_boxed();
+        if !text_types.is_empty() {
+            query = query.filter(dsl::text_type.eq_any(text_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::content
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::text_author.ilike(format!("%{filter}%")))
+                    .or(dsl::source_title.ilike(format!("%{filter}%"))),
+            );
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(text_content::table, text_content::dsl::text_content);
+}
+
+impl HistoryEntry for TextContent {
+    type NewHistoryEntity = NewTextContentHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            text_content_id: self.text_content_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewTextContentHistory {
+    type MainEntity = TextContentHistory;
+
+    db_insert!(text_content_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_content_pk() {
+        let text_content: TextContent = Default::default();
+        assert_eq!(text_content.pk(), text_content.text_content_id);
+    }
+
+    #[test]
+    fn test_new_text_content_history_from_text_content() {
+        let text_content: TextContent = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_text_content_history = text_content.new_history_entry(&account_id);
+        assert_eq!(
+            new_text_content_history.text_content_id,
+            text_content.text_content_id
+        );
+        assert_eq!(new_text_content_history.account_id, account_id);
+        assert_eq!(
+            new_text_content_history.data,
+            serde_json::Value::String(serde_json::to_string(&text_content).unwrap())
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use chrono::naive::NaiveDate;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::text_content;
+#[cfg(feature = "backend")]
+use crate::schema::text_content_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of promotional or critical text about a work (ONIX codelist 153)"),
+    ExistingTypePath = "crate::schema::sql_types::TextContentType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum TextContentType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Description of the work written for a specific audience, in addition to its abstracts"
+        )
+    )]
+    Description,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Text as it appears on the back cover or jacket of the work")
+    )]
+    PrimaryCoverCopy,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Quote from a review of the work")
+    )]
+    #[default]
+    ReviewQuote,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Quote from a review of a previous edition of the work")
+    )]
+    #[strum(serialize = "Review Quote (Previous Edition)")]
+    PreviousEditionReviewQuote,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Quote from a review of a previous work by the same author(s)")
+    )]
+    #[strum(serialize = "Review Quote (Previous Work)")]
+    PreviousWorkReviewQuote,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Quote usually provided by a celebrity or another author to promote the work, not taken from a review"
+        )
+    )]
+    Endorsement,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Promotional phrase intended to headline a description of the work")
+    )]
+    PromotionalHeadline,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Text describing a feature of the work to which the publisher wishes to draw attention"
+        )
+    )]
+    Feature,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Note about the author(s) of the work as a whole, or a statement from them about it"
+        )
+    )]
+    BiographicalNote,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Statement included by a publisher in fulfilment of contractual obligations, such as a disclaimer or sponsor statement"
+        )
+    )]
+    #[strum(serialize = "Publisher's Notice")]
+    PublishersNotice,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Short excerpt from the main text of the work")
+    )]
+    Excerpt,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(
+        description = "Audience for which a text about a work is intended (ONIX codelist 154)"
+    ),
+    ExistingTypePath = "crate::schema::sql_types::ContentAudience"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum ContentAudience {
+    #[cfg_attr(feature = "backend", graphql(description = "Any audience"))]
+    #[default]
+    Unrestricted,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(
+            description = "Distribution by agreement between the parties to the ONIX exchange only"
+        )
+    )]
+    Restricted,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "For internal use by booksellers, distributors and similar")
+    )]
+    Booktrade,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Suitable for presentation to end customers")
+    )]
+    EndCustomers,
+    #[cfg_attr(feature = "backend", graphql(description = "Intended for librarians"))]
+    Librarians,
+    #[cfg_attr(feature = "backend", graphql(description = "Intended for teachers"))]
+    Teachers,
+    #[cfg_attr(feature = "backend", graphql(description = "Intended for students"))]
+    Students,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Intended for the press or the media")
+    )]
+    Press,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Intended for use by price comparison services")
+    )]
+    ShoppingComparisonServices,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Not for display, but may be indexed by search engines")
+    )]
+    SearchEngineIndex,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting text contents list")
+)]
+pub enum TextContentField {
+    TextContentId,
+    WorkId,
+    TextType,
+    ContentAudience,
+    TextAuthor,
+    SourceTitle,
+    ContentDate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextContent {
+    pub text_content_id: Uuid,
+    pub work_id: Uuid,
+    pub text_type: TextContentType,
+    pub content_audience: ContentAudience,
+    pub content: String,
+    pub text_author: Option<String>,
+    pub source_title: Option<String>,
+    pub source_url: Option<String>,
+    pub content_date: Option<NaiveDate>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new promotional or critical text about a work"),
+    diesel(table_name = text_content)
+)]
+pub struct NewTextContent {
+    pub work_id: Uuid,
+    pub text_type: TextContentType,
+    pub content_audience: ContentAudience,
+    pub content: String,
+    pub text_author: Option<String>,
+    pub source_title: Option<String>,
+    pub source_url: Option<String>,
+    pub content_date: Option<NaiveDate>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing promotional or critical text about a work"),
+    diesel(table_name = text_content, treat_none_as_null = true)
+)]
+pub struct PatchTextContent {
+    pub text_content_id: Uuid,
+    pub work_id: Uuid,
+    pub text_type: TextContentType,
+    pub content_audience: ContentAudience,
+    pub content: String,
+    pub text_author: Option<String>,
+    pub source_title: Option<String>,
+    pub source_url: Option<String>,
+    pub content_date: Option<NaiveDate>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct TextContentHistory {
+    pub text_content_history_id: Uuid,
+    pub text_content_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = text_content_history)
+)]
+pub struct NewTextContentHistory {
+    pub text_content_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_text_content_type_default() {
+        let text_type: TextContentType = Default::default();
+        assert_eq!(text_type, TextContentType::ReviewQuote);
+    }
+
+    #[test]
+    fn test_content_audience_default() {
+        let content_audience: ContentAudience = Default::default();
+        assert_eq!(content_audience, ContentAudience::Unrestricted);
+    }
+
+    #[test]
+    fn test_text_content_type_display() {
+        assert_eq!(format!("{}", TextContentType::ReviewQuote), "Review Quote");
+        assert_eq!(
+            format!("{}", TextContentType::PreviousEditionReviewQuote),
+            "Review Quote (Previous Edition)"
+        );
+        assert_eq!(format!("{}", TextContentType::Endorsement), "Endorsement");
+        assert_eq!(
+            format!("{}", TextContentType::PublishersNotice),
+            "Publisher's Notice"
+        );
+        assert_eq!(
+            format!("{}", TextContentType::PrimaryCoverCopy),
+            "Primary Cover Copy"
+        );
+    }
+
+    #[test]
+    fn test_content_audience_display() {
+        assert_eq!(
+            format!("{}", ContentAudience::EndCustomers),
+            "End Customers"
+        );
+        assert_eq!(format!("{}", ContentAudience::Librarians), "Librarians");
+        assert_eq!(
+            format!("{}", ContentAudience::SearchEngineIndex),
+            "Search Engine Index"
+        );
+    }
+
+    #[test]
+    fn test_text_content_type_fromstr() {
+        assert_eq!(
+            TextContentType::from_str("Review Quote (Previous Work)").unwrap(),
+            TextContentType::PreviousWorkReviewQuote
+        );
+        assert_eq!(
+            TextContentType::from_str("Biographical Note").unwrap(),
+            TextContentType::BiographicalNote
+        );
+        assert!(TextContentType::from_str("Review").is_err());
+    }
+
+    #[test]
+    fn test_content_audience_fromstr() {
+        assert_eq!(
+            ContentAudience::from_str("Teachers").unwrap(),
+            ContentAudience::Teachers
+        );
+        assert!(ContentAudience::from_str("Everyone").is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
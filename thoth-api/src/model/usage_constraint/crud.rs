@@ -0,0 +1,169 @@
+use super::{
+    NewUsageConstraint, NewUsageConstraintHistory, PatchUsageConstraint, UsageConstraint,
+    UsageConstraintField, UsageConstraintHistory, UsageType,
+};
+use crate::graphql::model::UsageConstraintOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{usage_constraint, usage_constraint_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for UsageConstraint {
+    type NewEntity = NewUsageConstraint;
+    type PatchEntity = PatchUsageConstraint;
+    type OrderByEntity = UsageConstraintOrderBy;
+    type FilterParameter1 = UsageType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.usage_constraint_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        _: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        usage_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<UsageConstraint>> {
+        use crate::schema::usage_constraint::dsl;
+        let mut connection = db.get()?;
+        let mut query =
+            dsl::usage_constraint
+                .inner_join(crate::schema::publication::table.inner_join(
+                    crate::schema::work::table.inner_join(crate::schema::imprint::table),
+                ))
+                .select(crate::schema::usage_constraint::all_columns)
+                .into_boxed();
+
+        query = match order.field {
+            UsageConstraintField::UsageConstraintId => match order.direction {
+                Direction::Asc => query.order(dsl::usage_constraint_id.asc()),
+                Direction::Desc => query.order(dsl::usage_constraint_id.desc()),
+            },
+            UsageConstraintField::PublicationId => match order.direction {
+                Direction::Asc => query.order(dsl::publication_id.asc()),
+                Direction::Desc => query.order(dsl::publication_id.desc()),
+            },
+            UsageConstraintField::UsageType => match order.direction {
+                Direction::Asc => query.order(dsl::usage_type.asc()),
+                Direction::Desc => query.order(dsl::usage_type.desc()),
+            },
+            UsageConstraintField::UsageStatus => match order.direction {
+                Direction::Asc => query.order(dsl::usage_status.asc()),
+                Direction::Desc => query.order(dsl::usage_status.desc()),
+            },
+            UsageConstraintField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            UsageConstraintField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::publication_id.eq(pid));
+        }
+        if !usage_types.is_empty() {
+            query = query.filter(dsl::usage_type.eq_any(usage_types));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<UsageConstraint>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        _: Option<String>,
+        _: Vec<Uuid>,
+        usage_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::usage_constraint::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::usage_constraint.into_boxed();
+        if !usage_types.is_empty() {
+            query = query.filter(dsl::usage_type.eq_any(usage_types));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::publication::Publication::from_id(db, &self.publication_id)?.publisher_id(db)
+    }
+
+    crud_methods!(
+        usage_constraint::table,
+        usage_constraint::dsl::usage_constraint
+    );
+}
+
+impl HistoryEntry for UsageConstraint {
+    type NewHistoryEntity = NewUsageConstraintHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            usage_constraint_id: self.usage_constraint_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewUsageConstraintHistory {
+    type MainEntity = UsageConstraintHistory;
+
+    db_insert!(usage_constraint_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_constraint_pk() {
+        let usage_constraint: UsageConstraint = Default::default();
+        assert_eq!(usage_constraint.pk(), usage_constraint.usage_constraint_id);
+    }
+
+    #[test]
+    fn test_new_usage_constraint_history_from_usage_constraint() {
+        let usage_constraint: UsageConstraint = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_usage_constraint_history = usage_constraint.new_history_entry(&account_id);
+        assert_eq!(
+            new_usage_constraint_history.usage_constraint_id,
+            usage_constraint.usage_constraint_id
+        );
+        assert_eq!(new_usage_constraint_history.account_id, account_id);
+        assert_eq!(
+            new_usage_constraint_history.data,
+            serde_json::Value::String(serde_json::to_string(&usage_constraint).unwrap())
+        );
+    }
+}
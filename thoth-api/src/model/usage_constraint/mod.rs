@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::usage_constraint;
+#[cfg(feature = "backend")]
+use crate::schema::usage_constraint_history;
+use thoth_errors::{ThothError, ThothResult};
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Type of usage of a digital publication (ONIX codelist 145)"),
+    ExistingTypePath = "crate::schema::sql_types::UsageType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum UsageType {
+    #[cfg_attr(feature = "backend", graphql(description = "Preview before purchase"))]
+    #[default]
+    Preview,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Print paper copy of extract")
+    )]
+    Print,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Make digital copy of extract")
+    )]
+    #[strum(serialize = "Copy/Paste")]
+    CopyPaste,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Share the product across multiple concurrent devices")
+    )]
+    Share,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Enable text to be read aloud by a text-to-speech function")
+    )]
+    #[strum(serialize = "Text-to-Speech")]
+    TextToSpeech,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Lend the product to another reader")
+    )]
+    Lend,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "The product is licensed for a limited period only")
+    )]
+    TimeLimitedLicence,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Whether a type of usage is permitted (ONIX codelist 146)"),
+    ExistingTypePath = "crate::schema::sql_types::UsageStatus"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum UsageStatus {
+    #[cfg_attr(feature = "backend", graphql(description = "Permitted without limit"))]
+    PermittedUnlimited,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Permitted subject to the given limit")
+    )]
+    PermittedSubjectToLimit,
+    #[cfg_attr(feature = "backend", graphql(description = "Prohibited"))]
+    #[default]
+    Prohibited,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Unit in which a usage limit is expressed (ONIX codelist 147)"),
+    ExistingTypePath = "crate::schema::sql_types::UsageUnit"
+)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, EnumString, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum UsageUnit {
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of copies"))]
+    Copies,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Maximum number of characters")
+    )]
+    Characters,
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of words"))]
+    Words,
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of pages"))]
+    Pages,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Maximum percentage of the total content")
+    )]
+    Percentage,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Maximum number of devices")
+    )]
+    Devices,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Maximum number of concurrent users")
+    )]
+    ConcurrentUsers,
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of days"))]
+    Days,
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of weeks"))]
+    Weeks,
+    #[cfg_attr(feature = "backend", graphql(description = "Maximum number of months"))]
+    Months,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting usage constraints list")
+)]
+pub enum UsageConstraintField {
+    UsageConstraintId,
+    PublicationId,
+    UsageType,
+    UsageStatus,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageConstraint {
+    pub usage_constraint_id: Uuid,
+    pub publication_id: Uuid,
+    pub usage_type: UsageType,
+    pub usage_status: UsageStatus,
+    pub usage_limit: Option<i32>,
+    pub usage_unit: Option<UsageUnit>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new usage constraint of a digital publication"),
+    diesel(table_name = usage_constraint)
+)]
+pub struct NewUsageConstraint {
+    pub publication_id: Uuid,
+    pub usage_type: UsageType,
+    pub usage_status: UsageStatus,
+    pub usage_limit: Option<i32>,
+    pub usage_unit: Option<UsageUnit>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing usage constraint of a digital publication"),
+    diesel(table_name = usage_constraint, treat_none_as_null = true)
+)]
+pub struct PatchUsageConstraint {
+    pub usage_constraint_id: Uuid,
+    pub publication_id: Uuid,
+    pub usage_type: UsageType,
+    pub usage_status: UsageStatus,
+    pub usage_limit: Option<i32>,
+    pub usage_unit: Option<UsageUnit>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct UsageConstraintHistory {
+    pub usage_constraint_history_id: Uuid,
+    pub usage_constraint_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = usage_constraint_history)
+)]
+pub struct NewUsageConstraintHistory {
+    pub usage_constraint_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+/// Check that a positive limit, with its unit, is given if and only if usage is permitted
+/// subject to a limit
+fn validate_usage_limit(
+    usage_status: &UsageStatus,
+    usage_limit: &Option<i32>,
+    usage_unit: &Option<UsageUnit>,
+) -> ThothResult<()> {
+    let limited = *usage_status == UsageStatus::PermittedSubjectToLimit;
+    if usage_limit.is_some() != usage_unit.is_some()
+        || usage_limit.is_some() != limited
+        || usage_limit.is_some_and(|limit| limit < 1)
+    {
+        return Err(ThothError::UsageLimitError);
+    }
+    Ok(())
+}
+
+macro_rules! validate_usage_constraint {
+    ($t:ty) => {
+        impl $t {
+            /// Validate the usage limit against the usage status
+            pub fn validate(&self) -> ThothResult<()> {
+                validate_usage_limit(&self.usage_status, &self.usage_limit, &self.usage_unit)
+            }
+        }
+    };
+}
+
+validate_usage_constraint!(NewUsageConstraint);
+validate_usage_constraint!(PatchUsageConstraint);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_type_default() {
+        let usage_type: UsageType = Default::default();
+        assert_eq!(usage_type, UsageType::Preview);
+    }
+
+    #[test]
+    fn test_usage_status_default() {
+        let usage_status: UsageStatus = Default::default();
+        assert_eq!(usage_status, UsageStatus::Prohibited);
+    }
+
+    #[test]
+    fn test_usage_enums_display() {
+        assert_eq!(format!("{}", UsageType::CopyPaste), "Copy/Paste");
+        assert_eq!(format!("{}", UsageType::TextToSpeech), "Text-to-Speech");
+        assert_eq!(
+            format!("{}", UsageType::TimeLimitedLicence),
+            "Time Limited Licence"
+        );
+        assert_eq!(
+            format!("{}", UsageStatus::PermittedSubjectToLimit),
+            "Permitted Subject To Limit"
+        );
+        assert_eq!(
+            format!("{}", UsageUnit::ConcurrentUsers),
+            "Concurrent Users"
+        );
+    }
+
+    #[test]
+    fn test_usage_constraint_validate() {
+        let mut usage_constraint = NewUsageConstraint {
+            publication_id: Default::default(),
+            usage_type: UsageType::Print,
+            usage_status: UsageStatus::PermittedSubjectToLimit,
+            usage_limit: Some(10),
+            usage_unit: Some(UsageUnit::Percentage),
+        };
+        assert!(usage_constraint.validate().is_ok());
+        usage_constraint.usage_unit = None;
+        assert_eq!(
+            usage_constraint.validate(),
+            Err(ThothError::UsageLimitError)
+        );
+        usage_constraint.usage_limit = None;
+        assert_eq!(
+            usage_constraint.validate(),
+            Err(ThothError::UsageLimitError)
+        );
+        usage_constraint.usage_status = UsageStatus::Prohibited;
+        assert!(usage_constraint.validate().is_ok());
+        usage_constraint.usage_limit = Some(10);
+        usage_constraint.usage_unit = Some(UsageUnit::Pages);
+        assert_eq!(
+            usage_constraint.validate(),
+            Err(ThothError::UsageLimitError)
+        );
+        usage_constraint.usage_status = UsageStatus::PermittedSubjectToLimit;
+        usage_constraint.usage_limit = Some(0);
+        assert_eq!(
+            usage_constraint.validate(),
+            Err(ThothError::UsageLimitError)
+        );
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
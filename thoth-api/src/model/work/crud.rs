@@ -280,6 +280,10 @@ impl Crud for Work {
                 Direction::Asc => query.order(dsl::updated_at_with_relations.asc()),
                 Direction::Desc => query.order(dsl::updated_at_with_relations.desc()),
             },
+            WorkField::ArticleNumber => match order.direction {
+                Direction::Asc => query.order(dsl::article_number.asc()),
+                Direction::Desc => query.order(dsl::article_number.desc()),
+            },
         };
         if !publishers.is_empty() {
             query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
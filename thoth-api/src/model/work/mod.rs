@@ -1,13 +1,22 @@
 use crate::graphql::utils::Direction;
+use crate::markup::{validate_markup, validate_markup_change};
 use crate::model::contribution::Contribution;
+use crate::model::event::EventWithSponsors;
 use crate::model::funding::FundingWithInstitution;
 use crate::model::imprint::ImprintWithPublisher;
 use crate::model::issue::IssueWithSeries;
 use crate::model::language::Language;
+use crate::model::license::standardise_license;
+use crate::model::prize::Prize;
 use crate::model::publication::Publication;
 use crate::model::reference::Reference;
 use crate::model::subject::Subject;
+use crate::model::supporting_resource::SupportingResource;
+use crate::model::text_content::TextContent;
+use crate::model::work_abstract::WorkAbstract;
+use crate::model::work_identifier::WorkIdentifier;
 use crate::model::work_relation::WorkRelationWithRelatedWork;
+use crate::model::work_title::WorkTitle;
 use crate::model::Doi;
 use crate::model::Timestamp;
 #[cfg(feature = "backend")]
@@ -71,6 +80,20 @@ pub enum WorkType {
         graphql(description = "Group of volumes published together forming a single work")
     )]
     BookSet,
+    #[cfg_attr(
+        feature = "backend",
+        db_rename = "journal-article",
+        graphql(description = "Article published as part of a journal issue")
+    )]
+    JournalArticle,
+}
+
+impl WorkType {
+    /// Whether works of this type are part of a larger work, like chapters of a book or
+    /// articles of a journal issue: they have page ranges but no edition
+    pub fn is_component(&self) -> bool {
+        matches!(self, WorkType::BookChapter | WorkType::JournalArticle)
+    }
 }
 
 #[cfg_attr(
@@ -187,6 +210,7 @@ pub enum WorkField {
     LastPage,
     PageInterval,
     UpdatedAtWithRelations,
+    ArticleNumber,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -230,6 +254,7 @@ pub struct Work {
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
     pub updated_at_with_relations: Timestamp,
+    pub article_number: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -269,10 +294,18 @@ pub struct WorkWithRelations {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    pub article_number: Option<String>,
     pub contributions: Option<Vec<Contribution>>,
     pub publications: Option<Vec<Publication>>,
     pub languages: Option<Vec<Language>>,
+    pub titles: Option<Vec<WorkTitle>>,
+    pub abstracts: Option<Vec<WorkAbstract>>,
+    pub identifiers: Option<Vec<WorkIdentifier>>,
     pub fundings: Option<Vec<FundingWithInstitution>>,
+    pub prizes: Option<Vec<Prize>>,
+    pub events: Option<Vec<EventWithSponsors>>,
+    pub text_contents: Option<Vec<TextContent>>,
+    pub supporting_resources: Option<Vec<SupportingResource>>,
     pub subjects: Option<Vec<Subject>>,
     pub issues: Option<Vec<IssueWithSeries>>,
     pub imprint: ImprintWithPublisher,
@@ -320,6 +353,7 @@ pub struct NewWork {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    pub article_number: Option<String>,
 }
 
 #[cfg_attr(
@@ -363,6 +397,7 @@ pub struct PatchWork {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    pub article_number: Option<String>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -392,6 +427,24 @@ pub struct WorkOrderBy {
     pub direction: Direction,
 }
 
+/// Join a title and its subtitle, unless the title already ends in punctuation
+pub fn compile_fulltitle(title: &str, subtitle: Option<&str>) -> String {
+    subtitle.map_or_else(
+        || title.to_string(),
+        |subtitle| {
+            if title.ends_with('?')
+                || title.ends_with('!')
+                || title.ends_with(':')
+                || title.ends_with('.')
+            {
+                format!("{} {}", title, subtitle)
+            } else {
+                format!("{}: {}", title, subtitle)
+            }
+        },
+    )
+}
+
 pub trait WorkProperties {
     fn title(&self) -> &str;
     fn subtitle(&self) -> Option<&str>;
@@ -400,23 +453,13 @@ pub trait WorkProperties {
     fn withdrawn_date(&self) -> &Option<NaiveDate>;
     fn first_page(&self) -> Option<&str>;
     fn last_page(&self) -> Option<&str>;
+    fn short_abstract(&self) -> Option<&str>;
+    fn long_abstract(&self) -> Option<&str>;
+    fn general_note(&self) -> Option<&str>;
+    fn bibliography_note(&self) -> Option<&str>;
 
     fn compile_fulltitle(&self) -> String {
-        self.subtitle().map_or_else(
-            || self.title().to_string(),
-            |subtitle| {
-                let title = self.title();
-                if title.ends_with('?')
-                    || title.ends_with('!')
-                    || title.ends_with(':')
-                    || title.ends_with('.')
-                {
-                    format!("{} {}", title, subtitle)
-                } else {
-                    format!("{}: {}", title, subtitle)
-                }
-            },
-        )
+        compile_fulltitle(self.title(), self.subtitle())
     }
 
     fn compile_page_interval(&self) -> Option<String> {
@@ -444,6 +487,14 @@ pub trait WorkProperties {
     }
 
     fn validate(&self) -> ThothResult<()> {
+        self.validate_dates()?;
+        self.markup_fields()
+            .into_iter()
+            .flatten()
+            .try_for_each(validate_markup)
+    }
+
+    fn validate_dates(&self) -> ThothResult<()> {
         match (
             self.is_published(),
             self.publication_date(),
@@ -459,6 +510,26 @@ pub trait WorkProperties {
             _ => Ok(()),
         }
     }
+
+    /// Validate the changes made to an existing work. Rich text saved before markup was
+    /// validated is only checked once edited.
+    fn validate_update(&self, previous: &Work) -> ThothResult<()> {
+        self.validate_dates()?;
+        self.markup_fields()
+            .into_iter()
+            .zip(previous.markup_fields())
+            .try_for_each(|(content, previous)| validate_markup_change(content, previous))
+    }
+
+    /// Rich text fields of the work
+    fn markup_fields(&self) -> [Option<&str>; 4] {
+        [
+            self.short_abstract(),
+            self.long_abstract(),
+            self.general_note(),
+            self.bibliography_note(),
+        ]
+    }
 }
 
 macro_rules! work_properties {
@@ -485,6 +556,18 @@ macro_rules! work_properties {
             fn last_page(&self) -> Option<&str> {
                 self.last_page.as_deref()
             }
+            fn short_abstract(&self) -> Option<&str> {
+                self.short_abstract.as_deref()
+            }
+            fn long_abstract(&self) -> Option<&str> {
+                self.long_abstract.as_deref()
+            }
+            fn general_note(&self) -> Option<&str> {
+                self.general_note.as_deref()
+            }
+            fn bibliography_note(&self) -> Option<&str> {
+                self.bibliography_note.as_deref()
+            }
         }
     };
 }
@@ -507,6 +590,30 @@ impl WorkWithRelations {
     }
 }
 
+impl NewWork {
+    /// Validate the licence, replacing it with the canonical URL of the licence
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.license = self
+            .license
+            .as_deref()
+            .map(standardise_license)
+            .transpose()?;
+        Ok(self)
+    }
+}
+
+impl PatchWork {
+    /// Validate the licence, replacing it with the canonical URL of the licence
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.license = self
+            .license
+            .as_deref()
+            .map(standardise_license)
+            .transpose()?;
+        Ok(self)
+    }
+}
+
 impl From<Work> for PatchWork {
     fn from(w: Work) -> Self {
         Self {
@@ -544,6 +651,7 @@ impl From<Work> for PatchWork {
             first_page: w.first_page,
             last_page: w.last_page,
             page_interval: w.page_interval,
+            article_number: w.article_number,
         }
     }
 }
@@ -601,6 +709,7 @@ mod tests {
             last_page: None,
             page_interval: None,
             updated_at_with_relations: Default::default(),
+            article_number: None,
         }
     }
 
@@ -630,6 +739,7 @@ mod tests {
         assert_eq!(format!("{}", WorkType::Textbook), "Textbook");
         assert_eq!(format!("{}", WorkType::JournalIssue), "Journal Issue");
         assert_eq!(format!("{}", WorkType::BookSet), "Book Set");
+        assert_eq!(format!("{}", WorkType::JournalArticle), "Journal Article");
     }
 
     #[test]
@@ -689,6 +799,18 @@ mod tests {
             format!("{}", WorkField::UpdatedAtWithRelations),
             "UpdatedAtWithRelations"
         );
+        assert_eq!(format!("{}", WorkField::ArticleNumber), "ArticleNumber");
+    }
+
+    #[test]
+    fn test_worktype_is_component() {
+        assert!(WorkType::BookChapter.is_component());
+        assert!(WorkType::JournalArticle.is_component());
+        assert!(!WorkType::Monograph.is_component());
+        assert!(!WorkType::EditedBook.is_component());
+        assert!(!WorkType::Textbook.is_component());
+        assert!(!WorkType::JournalIssue.is_component());
+        assert!(!WorkType::BookSet.is_component());
     }
 
     #[test]
@@ -712,6 +834,10 @@ mod tests {
             WorkType::JournalIssue
         );
         assert_eq!(WorkType::from_str("Book Set").unwrap(), WorkType::BookSet);
+        assert_eq!(
+            WorkType::from_str("Journal Article").unwrap(),
+            WorkType::JournalArticle
+        );
 
         assert!(WorkType::from_str("Book Section").is_err());
         assert!(WorkType::from_str("Manuscript").is_err());
@@ -860,6 +986,10 @@ mod tests {
             WorkField::from_str("UpdatedAtWithRelations").unwrap(),
             WorkField::UpdatedAtWithRelations
         );
+        assert_eq!(
+            WorkField::from_str("ArticleNumber").unwrap(),
+            WorkField::ArticleNumber
+        );
         assert!(WorkField::from_str("WorkID").is_err());
         assert!(WorkField::from_str("Contributors").is_err());
         assert!(WorkField::from_str("Publisher").is_err());
@@ -911,7 +1041,8 @@ mod tests {
             cover_caption,
             first_page,
             last_page,
-            page_interval
+            page_interval,
+            article_number
         );
     }
 
@@ -1061,6 +1192,42 @@ mod tests {
 
         assert_eq!(work.validate(), Ok(()));
     }
+
+    #[test]
+    fn test_validate_fails_with_invalid_markup() {
+        let mut work = test_work();
+        work.long_abstract = Some("<p>A study of <i>Hamlet</i></p>".to_string());
+        assert_eq!(work.validate(), Ok(()));
+
+        work.general_note = Some("<span>Note</span>".to_string());
+        assert_eq!(
+            work.validate(),
+            Err(ThothError::InvalidMarkup(
+                "<span> is not a supported tag".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_validate_update_ignores_unchanged_legacy_markup() {
+        let mut previous = test_work();
+        previous.long_abstract = Some("Results for a<b".to_string());
+        previous.general_note = Some("See <a href>".to_string());
+
+        // Unrelated fields can be edited without fixing the legacy values first
+        let mut work = test_work();
+        work.long_abstract = previous.long_abstract.clone();
+        work.general_note = previous.general_note.clone();
+        work.place = Some("Cambridge".to_string());
+        assert!(work.validate().is_err());
+        assert_eq!(work.validate_update(&previous), Ok(()));
+
+        // Edited values must be valid
+        work.general_note = Some("See <a href> again".to_string());
+        assert!(work.validate_update(&previous).is_err());
+        work.general_note = Some("<p>See <i>Hamlet</i></p>".to_string());
+        assert_eq!(work.validate_update(&previous), Ok(()));
+    }
 }
 
 #[cfg(feature = "backend")]
@@ -0,0 +1,180 @@
+use super::{
+    AbstractType, NewWorkAbstract, NewWorkAbstractHistory, PatchWorkAbstract, WorkAbstract,
+    WorkAbstractField, WorkAbstractHistory,
+};
+use crate::graphql::model::WorkAbstractOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::language::LanguageCode;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{work_abstract, work_abstract_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for WorkAbstract {
+    type NewEntity = NewWorkAbstract;
+    type PatchEntity = PatchWorkAbstract;
+    type OrderByEntity = WorkAbstractOrderBy;
+    type FilterParameter1 = LanguageCode;
+    type FilterParameter2 = AbstractType;
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.work_abstract_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        language_codes: Vec<Self::FilterParameter1>,
+        abstract_types: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<WorkAbstract>> {
+        use crate::schema::work_abstract::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_abstract
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::work_abstract::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            WorkAbstractField::WorkAbstractId => match order.direction {
+                Direction::Asc => query.order(dsl::work_abstract_id.asc()),
+                Direction::Desc => query.order(dsl::work_abstract_id.desc()),
+            },
+            WorkAbstractField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            WorkAbstractField::LanguageCode => match order.direction {
+                Direction::Asc => query.order(dsl::language_code.asc()),
+                Direction::Desc => query.order(dsl::language_code.desc()),
+            },
+            WorkAbstractField::AbstractType => match order.direction {
+                Direction::Asc => query.order(dsl::abstract_type.asc()),
+                Direction::Desc => query.order(dsl::abstract_type.desc()),
+            },
+            WorkAbstractField::Canonical => match order.direction {
+                Direction::Asc => query.order(dsl::canonical.asc()),
+                Direction::Desc => query.order(dsl::canonical.desc()),
+            },
+            WorkAbstractField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            WorkAbstractField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !language_codes.is_empty() {
+            query = query.filter(dsl::language_code.eq_any(language_codes));
+        }
+        if !abstract_types.is_empty() {
+            query = query.filter(dsl::abstract_type.eq_any(abstract_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::content.ilike(format!("%{filter}%")));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<WorkAbstract>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        language_codes: Vec<Self::FilterParameter1>,
+        abstract_types: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::work_abstract::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_abstract.into_boxed();
+        if !language_codes.is_empty() {
+            query = query.filter(dsl::language_code.eq_any(language_codes));
+        }
+        if !abstract_types.is_empty() {
+            query = query.filter(dsl::abstract_type.eq_any(abstract_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::content.ilike(format!("%{filter}%")));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(work_abstract::table, work_abstract::dsl::work_abstract);
+}
+
+impl HistoryEntry for WorkAbstract {
+    type NewHistoryEntity = NewWorkAbstractHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            work_abstract_id: self.work_abstract_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewWorkAbstractHistory {
+    type MainEntity = WorkAbstractHistory;
+
+    db_insert!(work_abstract_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_abstract_pk() {
+        let work_abstract: WorkAbstract = Default::default();
+        assert_eq!(work_abstract.pk(), work_abstract.work_abstract_id);
+    }
+
+    #[test]
+    fn test_new_work_abstract_history_from_work_abstract() {
+        let work_abstract: WorkAbstract = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_work_abstract_history = work_abstract.new_history_entry(&account_id);
+        assert_eq!(
+            new_work_abstract_history.work_abstract_id,
+            work_abstract.work_abstract_id
+        );
+        assert_eq!(new_work_abstract_history.account_id, account_id);
+        assert_eq!(
+            new_work_abstract_history.data,
+            serde_json::Value::String(serde_json::to_string(&work_abstract).unwrap())
+        );
+    }
+}
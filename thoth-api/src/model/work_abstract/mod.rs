@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+use strum::EnumString;
+use uuid::Uuid;
+
+use crate::model::language::LanguageCode;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::work_abstract;
+#[cfg(feature = "backend")]
+use crate::schema::work_abstract_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(DbEnum, juniper::GraphQLEnum),
+    graphql(description = "Length of an abstract of a work"),
+    ExistingTypePath = "crate::schema::sql_types::AbstractType"
+)]
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize, EnumString, Display,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "title_case")]
+pub enum AbstractType {
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Brief summary of the work, usually a single paragraph")
+    )]
+    Short,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Full description of the work")
+    )]
+    #[default]
+    Long,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting abstracts list")
+)]
+pub enum WorkAbstractField {
+    WorkAbstractId,
+    WorkId,
+    LanguageCode,
+    AbstractType,
+    Canonical,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkAbstract {
+    pub work_abstract_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub content: String,
+    pub canonical: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new abstract of a work in a given language"),
+    diesel(table_name = work_abstract)
+)]
+pub struct NewWorkAbstract {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub content: String,
+    pub canonical: bool,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing abstract of a work in a given language"),
+    diesel(table_name = work_abstract, treat_none_as_null = true)
+)]
+pub struct PatchWorkAbstract {
+    pub work_abstract_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub content: String,
+    pub canonical: bool,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct WorkAbstractHistory {
+    pub work_abstract_history_id: Uuid,
+    pub work_abstract_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = work_abstract_history)
+)]
+pub struct NewWorkAbstractHistory {
+    pub work_abstract_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[test]
+fn test_abstracttype_default() {
+    let abstract_type: AbstractType = Default::default();
+    assert_eq!(abstract_type, AbstractType::Long);
+}
+
+#[test]
+fn test_abstracttype_display() {
+    assert_eq!(format!("{}", AbstractType::Short), "Short");
+    assert_eq!(format!("{}", AbstractType::Long), "Long");
+}
+
+#[test]
+fn test_abstracttype_fromstr() {
+    use std::str::FromStr;
+    assert_eq!(
+        AbstractType::from_str("Short").unwrap(),
+        AbstractType::Short
+    );
+    assert_eq!(AbstractType::from_str("Long").unwrap(), AbstractType::Long);
+    assert!(AbstractType::from_str("Medium").is_err());
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
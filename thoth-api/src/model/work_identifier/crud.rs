@@ -0,0 +1,173 @@
+use super::{
+    NewWorkIdentifier, NewWorkIdentifierHistory, PatchWorkIdentifier, WorkIdentifier,
+    WorkIdentifierField, WorkIdentifierHistory,
+};
+use crate::graphql::model::WorkIdentifierOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::identifier::IdentifierType;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{work_identifier, work_identifier_history};
+use crate::{crud_methods, db_insert};
+use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for WorkIdentifier {
+    type NewEntity = NewWorkIdentifier;
+    type PatchEntity = PatchWorkIdentifier;
+    type OrderByEntity = WorkIdentifierOrderBy;
+    type FilterParameter1 = IdentifierType;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.work_identifier_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        identifier_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<WorkIdentifier>> {
+        use crate::schema::work_identifier::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_identifier
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::work_identifier::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            WorkIdentifierField::WorkIdentifierId => match order.direction {
+                Direction::Asc => query.order(dsl::work_identifier_id.asc()),
+                Direction::Desc => query.order(dsl::work_identifier_id.desc()),
+            },
+            WorkIdentifierField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            WorkIdentifierField::IdentifierType => match order.direction {
+                Direction::Asc => query.order(dsl::identifier_type.asc()),
+                Direction::Desc => query.order(dsl::identifier_type.desc()),
+            },
+            WorkIdentifierField::IdentifierValue => match order.direction {
+                Direction::Asc => query.order(dsl::identifier_value.asc()),
+                Direction::Desc => query.order(dsl::identifier_value.desc()),
+            },
+            WorkIdentifierField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            WorkIdentifierField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !identifier_types.is_empty() {
+            query = query.filter(dsl::identifier_type.eq_any(identifier_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::identifier_value.ilike(format!("%{filter}%")));
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<WorkIdentifier>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        identifier_types: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::work_identifier::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_identifier.into_boxed();
+        if !identifier_types.is_empty() {
+            query = query.filter(dsl::identifier_type.eq_any(identifier_types));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(dsl::identifier_value.ilike(format!("%{filter}%")));
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(
+        work_identifier::table,
+        work_identifier::dsl::work_identifier
+    );
+}
+
+impl HistoryEntry for WorkIdentifier {
+    type NewHistoryEntity = NewWorkIdentifierHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            work_identifier_id: self.work_identifier_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewWorkIdentifierHistory {
+    type MainEntity = WorkIdentifierHistory;
+
+    db_insert!(work_identifier_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_identifier_pk() {
+        let work_identifier: WorkIdentifier = Default::default();
+        assert_eq!(work_identifier.pk(), work_identifier.work_identifier_id);
+    }
+
+    #[test]
+    fn test_new_work_identifier_history_from_work_identifier() {
+        let work_identifier: WorkIdentifier = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_work_identifier_history = work_identifier.new_history_entry(&account_id);
+        assert_eq!(
+            new_work_identifier_history.work_identifier_id,
+            work_identifier.work_identifier_id
+        );
+        assert_eq!(new_work_identifier_history.account_id, account_id);
+        assert_eq!(
+            new_work_identifier_history.data,
+            serde_json::Value::String(serde_json::to_string(&work_identifier).unwrap())
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::identifier::IdentifierType;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::work_identifier;
+#[cfg(feature = "backend")]
+use crate::schema::work_identifier_history;
+use thoth_errors::ThothResult;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting work identifiers list")
+)]
+pub enum WorkIdentifierField {
+    WorkIdentifierId,
+    WorkId,
+    IdentifierType,
+    IdentifierValue,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkIdentifier {
+    pub work_identifier_id: Uuid,
+    pub work_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new persistent identifier of a work"),
+    diesel(table_name = work_identifier)
+)]
+pub struct NewWorkIdentifier {
+    pub work_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing persistent identifier of a work"),
+    diesel(table_name = work_identifier, treat_none_as_null = true)
+)]
+pub struct PatchWorkIdentifier {
+    pub work_identifier_id: Uuid,
+    pub work_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct WorkIdentifierHistory {
+    pub work_identifier_history_id: Uuid,
+    pub work_identifier_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = work_identifier_history)
+)]
+pub struct NewWorkIdentifierHistory {
+    pub work_identifier_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl NewWorkIdentifier {
+    /// Validate the identifier against its type, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.identifier_value = self
+            .identifier_type
+            .standardise_for_work(&self.identifier_value)?;
+        Ok(self)
+    }
+}
+
+impl PatchWorkIdentifier {
+    /// Validate the identifier against its type, replacing it with its standardised form
+    pub fn standardise(mut self) -> ThothResult<Self> {
+        self.identifier_value = self
+            .identifier_type
+            .standardise_for_work(&self.identifier_value)?;
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_work_identifier_standardise() {
+        let new_work_identifier = NewWorkIdentifier {
+            work_id: Default::default(),
+            identifier_type: IdentifierType::Istc,
+            identifier_value: "0a9200212b4a1057".to_string(),
+        };
+        assert_eq!(
+            new_work_identifier.standardise().unwrap().identifier_value,
+            "0A9-2002-12B4A105-7"
+        );
+
+        let new_work_identifier = NewWorkIdentifier {
+            work_id: Default::default(),
+            identifier_type: IdentifierType::Gtin,
+            identifier_value: "4006381333931".to_string(),
+        };
+        assert!(new_work_identifier.standardise().is_err());
+    }
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
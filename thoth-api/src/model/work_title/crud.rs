@@ -0,0 +1,191 @@
+use super::{
+    NewWorkTitle, NewWorkTitleHistory, PatchWorkTitle, WorkTitle, WorkTitleField, WorkTitleHistory,
+};
+use crate::graphql::model::WorkTitleOrderBy;
+use crate::graphql::utils::Direction;
+use crate::model::language::LanguageCode;
+use crate::model::{Crud, DbInsert, HistoryEntry};
+use crate::schema::{work_title, work_title_history};
+use crate::{crud_methods, db_insert};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl,
+};
+use thoth_errors::ThothResult;
+use uuid::Uuid;
+
+impl Crud for WorkTitle {
+    type NewEntity = NewWorkTitle;
+    type PatchEntity = PatchWorkTitle;
+    type OrderByEntity = WorkTitleOrderBy;
+    type FilterParameter1 = LanguageCode;
+    type FilterParameter2 = ();
+    type FilterParameter3 = ();
+
+    fn pk(&self) -> Uuid {
+        self.work_title_id
+    }
+
+    fn all(
+        db: &crate::db::PgPool,
+        limit: i32,
+        offset: i32,
+        filter: Option<String>,
+        order: Self::OrderByEntity,
+        publishers: Vec<Uuid>,
+        parent_id_1: Option<Uuid>,
+        _: Option<Uuid>,
+        language_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<Vec<WorkTitle>> {
+        use crate::schema::work_title::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_title
+            .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+            .select(crate::schema::work_title::all_columns)
+            .into_boxed();
+
+        query = match order.field {
+            WorkTitleField::WorkTitleId => match order.direction {
+                Direction::Asc => query.order(dsl::work_title_id.asc()),
+                Direction::Desc => query.order(dsl::work_title_id.desc()),
+            },
+            WorkTitleField::WorkId => match order.direction {
+                Direction::Asc => query.order(dsl::work_id.asc()),
+                Direction::Desc => query.order(dsl::work_id.desc()),
+            },
+            WorkTitleField::LanguageCode => match order.direction {
+                Direction::Asc => query.order(dsl::language_code.asc()),
+                Direction::Desc => query.order(dsl::language_code.desc()),
+            },
+            WorkTitleField::FullTitle => match order.direction {
+                Direction::Asc => query.order(dsl::full_title.asc()),
+                Direction::Desc => query.order(dsl::full_title.desc()),
+            },
+            WorkTitleField::Title => match order.direction {
+                Direction::Asc => query.order(dsl::title.asc()),
+                Direction::Desc => query.order(dsl::title.desc()),
+            },
+            WorkTitleField::Subtitle => match order.direction {
+                Direction::Asc => query.order(dsl::subtitle.asc()),
+                Direction::Desc => query.order(dsl::subtitle.desc()),
+            },
+            WorkTitleField::Canonical => match order.direction {
+                Direction::Asc => query.order(dsl::canonical.asc()),
+                Direction::Desc => query.order(dsl::canonical.desc()),
+            },
+            WorkTitleField::CreatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::created_at.asc()),
+                Direction::Desc => query.order(dsl::created_at.desc()),
+            },
+            WorkTitleField::UpdatedAt => match order.direction {
+                Direction::Asc => query.order(dsl::updated_at.asc()),
+                Direction::Desc => query.order(dsl::updated_at.desc()),
+            },
+        };
+        if !publishers.is_empty() {
+            query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+        }
+        if let Some(pid) = parent_id_1 {
+            query = query.filter(dsl::work_id.eq(pid));
+        }
+        if !language_codes.is_empty() {
+            query = query.filter(dsl::language_code.eq_any(language_codes));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::full_title
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::title.ilike(format!("%{filter}%"))),
+            );
+        }
+        query
+            .limit(limit.into())
+            .offset(offset.into())
+            .load::<WorkTitle>(&mut connection)
+            .map_err(Into::into)
+    }
+
+    fn count(
+        db: &crate::db::PgPool,
+        filter: Option<String>,
+        _: Vec<Uuid>,
+        language_codes: Vec<Self::FilterParameter1>,
+        _: Vec<Self::FilterParameter2>,
+        _: Option<Self::FilterParameter3>,
+    ) -> ThothResult<i32> {
+        use crate::schema::work_title::dsl;
+        let mut connection = db.get()?;
+        let mut query = dsl::work_title.into_boxed();
+        if !language_codes.is_empty() {
+            query = query.filter(dsl::language_code.eq_any(language_codes));
+        }
+        if let Some(filter) = filter {
+            query = query.filter(
+                dsl::full_title
+                    .ilike(format!("%{filter}%"))
+                    .or(dsl::title.ilike(format!("%{filter}%"))),
+            );
+        }
+        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
+        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
+        // is converting i64 to string and then parsing it as i32. This should work until we reach
+        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
+        query
+            .count()
+            .get_result::<i64>(&mut connection)
+            .map(|t| t.to_string().parse::<i32>().unwrap())
+            .map_err(Into::into)
+    }
+
+    fn publisher_id(&self, db: &crate::db::PgPool) -> ThothResult<Uuid> {
+        crate::model::work::Work::from_id(db, &self.work_id)?.publisher_id(db)
+    }
+
+    crud_methods!(work_title::table, work_title::dsl::work_title);
+}
+
+impl HistoryEntry for WorkTitle {
+    type NewHistoryEntity = NewWorkTitleHistory;
+
+    fn new_history_entry(&self, account_id: &Uuid) -> Self::NewHistoryEntity {
+        Self::NewHistoryEntity {
+            work_title_id: self.work_title_id,
+            account_id: *account_id,
+            data: serde_json::Value::String(serde_json::to_string(&self).unwrap()),
+        }
+    }
+}
+
+impl DbInsert for NewWorkTitleHistory {
+    type MainEntity = WorkTitleHistory;
+
+    db_insert!(work_title_history::table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_title_pk() {
+        let work_title: WorkTitle = Default::default();
+        assert_eq!(work_title.pk(), work_title.work_title_id);
+    }
+
+    #[test]
+    fn test_new_work_title_history_from_work_title() {
+        let work_title: WorkTitle = Default::default();
+        let account_id: Uuid = Default::default();
+        let new_work_title_history = work_title.new_history_entry(&account_id);
+        assert_eq!(
+            new_work_title_history.work_title_id,
+            work_title.work_title_id
+        );
+        assert_eq!(new_work_title_history.account_id, account_id);
+        assert_eq!(
+            new_work_title_history.data,
+            serde_json::Value::String(serde_json::to_string(&work_title).unwrap())
+        );
+    }
+}
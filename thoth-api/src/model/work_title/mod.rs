@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::model::language::LanguageCode;
+use crate::model::work::compile_fulltitle;
+use crate::model::Timestamp;
+#[cfg(feature = "backend")]
+use crate::schema::work_title;
+#[cfg(feature = "backend")]
+use crate::schema::work_title_history;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Field to use when sorting titles list")
+)]
+pub enum WorkTitleField {
+    WorkTitleId,
+    WorkId,
+    LanguageCode,
+    FullTitle,
+    Title,
+    Subtitle,
+    Canonical,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkTitle {
+    pub work_title_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub full_title: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub canonical: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl WorkTitle {
+    pub fn compile_fulltitle(&self) -> String {
+        compile_fulltitle(&self.title, self.subtitle.as_deref())
+    }
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    graphql(description = "Set of values required to define a new title of a work in a given language"),
+    diesel(table_name = work_title)
+)]
+pub struct NewWorkTitle {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub full_title: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub canonical: bool,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    graphql(description = "Set of values required to update an existing title of a work in a given language"),
+    diesel(table_name = work_title, treat_none_as_null = true)
+)]
+pub struct PatchWorkTitle {
+    pub work_title_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub full_title: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub canonical: bool,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct WorkTitleHistory {
+    pub work_title_history_id: Uuid,
+    pub work_title_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: Timestamp,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    diesel(table_name = work_title_history)
+)]
+pub struct NewWorkTitleHistory {
+    pub work_title_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[cfg(feature = "backend")]
+pub mod crud;
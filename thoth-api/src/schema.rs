@@ -1,4 +1,8 @@
 pub mod sql_types {
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "abstract_type"))]
+    pub struct AbstractType;
+
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "contribution_type"))]
     pub struct ContributionType;
@@ -46,6 +50,126 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "relation_type"))]
     pub struct RelationType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "identifier_type"))]
+    pub struct IdentifierType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "name_type"))]
+    pub struct NameType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "sales_rights_type"))]
+    pub struct SalesRightsType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "sales_restriction_type"))]
+    pub struct SalesRestrictionType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "price_type"))]
+    pub struct PriceType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "price_qualifier"))]
+    pub struct PriceQualifier;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "accessibility_feature"))]
+    pub struct AccessibilityFeature;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "accessibility_hazard"))]
+    pub struct AccessibilityHazard;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "accessibility_standard"))]
+    pub struct AccessibilityStandard;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "wcag_version"))]
+    pub struct WcagVersion;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "wcag_level"))]
+    pub struct WcagLevel;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "binding_style"))]
+    pub struct BindingStyle;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "paper_type"))]
+    pub struct PaperType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "interior_colour"))]
+    pub struct InteriorColour;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "trim_category"))]
+    pub struct TrimCategory;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "cover_finish"))]
+    pub struct CoverFinish;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "technical_protection"))]
+    pub struct TechnicalProtection;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "digital_layout"))]
+    pub struct DigitalLayout;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "usage_type"))]
+    pub struct UsageType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "usage_status"))]
+    pub struct UsageStatus;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "usage_unit"))]
+    pub struct UsageUnit;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "prize_code"))]
+    pub struct PrizeCode;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "text_content_type"))]
+    pub struct TextContentType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "content_audience"))]
+    pub struct ContentAudience;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "resource_content_type"))]
+    pub struct ResourceContentType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "resource_mode"))]
+    pub struct ResourceMode;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "resource_form"))]
+    pub struct ResourceForm;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "asset_type"))]
+    pub struct AssetType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "cover_rendition"))]
+    pub struct CoverRendition;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "series_contribution_type"))]
+    pub struct SeriesContributionType;
 }
 
 table! {
@@ -64,6 +188,101 @@ table! {
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
         token -> Nullable<Text>,
+        failed_login_attempts -> Int4,
+        locked_until -> Nullable<Timestamptz>,
+        totp_secret -> Nullable<Text>,
+        totp_enabled -> Bool,
+        totp_last_used_step -> Nullable<Int8>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    account_login_audit (account_login_audit_id) {
+        account_login_audit_id -> Uuid,
+        account_id -> Nullable<Uuid>,
+        email -> Text,
+        ip_address -> Nullable<Text>,
+        success -> Bool,
+        failure_reason -> Nullable<Text>,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    account_recovery_code (account_recovery_code_id) {
+        account_recovery_code_id -> Uuid,
+        account_id -> Uuid,
+        code_hash -> Text,
+        used_at -> Nullable<Timestamptz>,
+        created_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::NameType;
+    use super::sql_types::LanguageCode;
+
+    alternative_name (alternative_name_id) {
+        alternative_name_id -> Uuid,
+        contributor_id -> Uuid,
+        name_type -> NameType,
+        first_name -> Nullable<Text>,
+        last_name -> Text,
+        full_name -> Text,
+        language_code -> Nullable<LanguageCode>,
+        script_code -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    alternative_name_history (alternative_name_history_id) {
+        alternative_name_history_id -> Uuid,
+        alternative_name_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AssetType;
+
+    asset (asset_id) {
+        asset_id -> Uuid,
+        work_id -> Uuid,
+        publication_id -> Nullable<Uuid>,
+        location_id -> Nullable<Uuid>,
+        asset_type -> AssetType,
+        storage_key -> Text,
+        asset_url -> Text,
+        filename -> Text,
+        mime_type -> Text,
+        file_size -> Int4,
+        checksum -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    asset_history (asset_history_id) {
+        asset_history_id -> Uuid,
+        asset_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
     }
 }
 
@@ -137,6 +356,10 @@ table! {
         website -> Nullable<Text>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        isni -> Nullable<Text>,
+        viaf -> Nullable<Text>,
+        wikidata -> Nullable<Text>,
+        lcnaf -> Nullable<Text>,
     }
 }
 
@@ -152,6 +375,62 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CountryCode;
+
+    event (event_id) {
+        event_id -> Uuid,
+        work_id -> Uuid,
+        event_name -> Text,
+        event_acronym -> Nullable<Text>,
+        event_number -> Nullable<Text>,
+        event_location -> Nullable<Text>,
+        event_country -> Nullable<CountryCode>,
+        event_start_date -> Nullable<Date>,
+        event_end_date -> Nullable<Date>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    event_history (event_history_id) {
+        event_history_id -> Uuid,
+        event_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    event_sponsor (event_sponsor_id) {
+        event_sponsor_id -> Uuid,
+        event_id -> Uuid,
+        institution_id -> Uuid,
+        sponsor_ordinal -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    event_sponsor_history (event_sponsor_history_id) {
+        event_sponsor_history_id -> Uuid,
+        event_sponsor_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -219,6 +498,22 @@ use diesel::sql_types::*;
         updated_at -> Timestamptz,
         ror -> Nullable<Text>,
         country_code -> Nullable<CountryCode>,
+        crossref_funder_id -> Nullable<Text>,
+        isni -> Nullable<Text>,
+        ringgold -> Nullable<Text>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    institution_identifier_mapping (institution_identifier_mapping_id) {
+        institution_identifier_mapping_id -> Uuid,
+        ror -> Nullable<Text>,
+        crossref_funder_id -> Nullable<Text>,
+        isni -> Nullable<Text>,
+        ringgold -> Nullable<Text>,
+        created_at -> Timestamptz,
     }
 }
 
@@ -244,6 +539,9 @@ table! {
         issue_ordinal -> Int4,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        volume -> Nullable<Text>,
+        issue_number -> Nullable<Text>,
+        part -> Nullable<Text>,
     }
 }
 
@@ -317,7 +615,21 @@ table! {
 
 table! {
     use diesel::sql_types::*;
+
+    persisted_query (persisted_query_id) {
+        persisted_query_id -> Uuid,
+        query_hash -> Text,
+        query -> Text,
+        created_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CountryCode;
     use super::sql_types::CurrencyCode;
+    use super::sql_types::PriceQualifier;
+    use super::sql_types::PriceType;
 
     price (price_id) {
         price_id -> Uuid,
@@ -326,6 +638,16 @@ table! {
         unit_price -> Float8,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        price_type -> PriceType,
+        price_qualifier -> Nullable<PriceQualifier>,
+        countries_included -> Array<CountryCode>,
+        countries_excluded -> Array<CountryCode>,
+        regions_included -> Array<Text>,
+        regions_excluded -> Array<Text>,
+        tax_rate_percent -> Nullable<Float8>,
+        discount_code -> Nullable<Text>,
+        price_effective_from -> Nullable<Date>,
+        price_effective_until -> Nullable<Date>,
     }
 }
 
@@ -343,7 +665,50 @@ table! {
 
 table! {
     use diesel::sql_types::*;
+    use super::sql_types::CountryCode;
+    use super::sql_types::PrizeCode;
+
+    prize (prize_id) {
+        prize_id -> Uuid,
+        work_id -> Uuid,
+        prize_name -> Text,
+        prize_year -> Nullable<Int4>,
+        prize_country -> Nullable<CountryCode>,
+        prize_code -> PrizeCode,
+        prize_statement -> Nullable<Text>,
+        prize_jury -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    prize_history (prize_history_id) {
+        prize_history_id -> Uuid,
+        prize_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AccessibilityFeature;
+    use super::sql_types::AccessibilityHazard;
+    use super::sql_types::AccessibilityStandard;
+    use super::sql_types::BindingStyle;
+    use super::sql_types::CoverFinish;
+    use super::sql_types::DigitalLayout;
+    use super::sql_types::InteriorColour;
+    use super::sql_types::PaperType;
     use super::sql_types::PublicationType;
+    use super::sql_types::TechnicalProtection;
+    use super::sql_types::TrimCategory;
+    use super::sql_types::WcagLevel;
+    use super::sql_types::WcagVersion;
 
     publication (publication_id) {
         publication_id -> Uuid,
@@ -360,6 +725,24 @@ table! {
         depth_in -> Nullable<Float8>,
         weight_g -> Nullable<Float8>,
         weight_oz -> Nullable<Float8>,
+        accessibility_features -> Array<AccessibilityFeature>,
+        accessibility_hazards -> Array<AccessibilityHazard>,
+        accessibility_standard -> Nullable<AccessibilityStandard>,
+        wcag_version -> Nullable<WcagVersion>,
+        wcag_level -> Nullable<WcagLevel>,
+        accessibility_certifier -> Nullable<Text>,
+        accessibility_summary -> Nullable<Text>,
+        binding_style -> Nullable<BindingStyle>,
+        paper_type -> Nullable<PaperType>,
+        interior_colour -> Nullable<InteriorColour>,
+        trim_category -> Nullable<TrimCategory>,
+        page_count -> Nullable<Int4>,
+        cover_finish -> Nullable<CoverFinish>,
+        carton_quantity -> Nullable<Int4>,
+        format_version -> Nullable<Text>,
+        file_size_kb -> Nullable<Int4>,
+        technical_protections -> Array<TechnicalProtection>,
+        layout -> Nullable<DigitalLayout>,
     }
 }
 
@@ -375,6 +758,32 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IdentifierType;
+
+    publication_identifier (publication_identifier_id) {
+        publication_identifier_id -> Uuid,
+        publication_id -> Uuid,
+        identifier_type -> IdentifierType,
+        identifier_value -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    publication_identifier_history (publication_identifier_history_id) {
+        publication_identifier_history_id -> Uuid,
+        publication_identifier_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
 
@@ -385,6 +794,7 @@ table! {
         publisher_url -> Nullable<Text>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        require_two_factor -> Bool,
     }
 }
 
@@ -456,6 +866,39 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SalesRightsType;
+    use super::sql_types::SalesRestrictionType;
+    use super::sql_types::CountryCode;
+
+    sales_rights (sales_rights_id) {
+        sales_rights_id -> Uuid,
+        publication_id -> Uuid,
+        sales_rights_type -> SalesRightsType,
+        countries_included -> Array<CountryCode>,
+        countries_excluded -> Array<CountryCode>,
+        regions_included -> Array<Text>,
+        regions_excluded -> Array<Text>,
+        sales_restriction_type -> Nullable<SalesRestrictionType>,
+        sales_restriction_note -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    sales_rights_history (sales_rights_history_id) {
+        sales_rights_history_id -> Uuid,
+        sales_rights_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::SeriesType;
@@ -472,6 +915,37 @@ table! {
         updated_at -> Timestamptz,
         series_description -> Nullable<Text>,
         series_cfp_url -> Nullable<Text>,
+        parent_series_id -> Nullable<Uuid>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::SeriesContributionType;
+
+    series_contribution (series_contribution_id) {
+        series_contribution_id -> Uuid,
+        series_id -> Uuid,
+        contributor_id -> Uuid,
+        contribution_type -> SeriesContributionType,
+        first_name -> Nullable<Text>,
+        last_name -> Text,
+        full_name -> Text,
+        contribution_ordinal -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    series_contribution_history (series_contribution_history_id) {
+        series_contribution_history_id -> Uuid,
+        series_contribution_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
     }
 }
 
@@ -514,6 +988,107 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ContentAudience;
+    use super::sql_types::CoverRendition;
+    use super::sql_types::ResourceContentType;
+    use super::sql_types::ResourceForm;
+    use super::sql_types::ResourceMode;
+
+    supporting_resource (supporting_resource_id) {
+        supporting_resource_id -> Uuid,
+        work_id -> Uuid,
+        resource_content_type -> ResourceContentType,
+        content_audience -> ContentAudience,
+        resource_mode -> ResourceMode,
+        resource_form -> ResourceForm,
+        resource_url -> Text,
+        caption -> Nullable<Text>,
+        credit -> Nullable<Text>,
+        width_px -> Nullable<Int4>,
+        height_px -> Nullable<Int4>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        cover_rendition -> Nullable<CoverRendition>,
+        asset_id -> Nullable<Uuid>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    supporting_resource_history (supporting_resource_history_id) {
+        supporting_resource_history_id -> Uuid,
+        supporting_resource_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ContentAudience;
+    use super::sql_types::TextContentType;
+
+    text_content (text_content_id) {
+        text_content_id -> Uuid,
+        work_id -> Uuid,
+        text_type -> TextContentType,
+        content_audience -> ContentAudience,
+        content -> Text,
+        text_author -> Nullable<Text>,
+        source_title -> Nullable<Text>,
+        source_url -> Nullable<Text>,
+        content_date -> Nullable<Date>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    text_content_history (text_content_history_id) {
+        text_content_history_id -> Uuid,
+        text_content_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::UsageStatus;
+    use super::sql_types::UsageType;
+    use super::sql_types::UsageUnit;
+
+    usage_constraint (usage_constraint_id) {
+        usage_constraint_id -> Uuid,
+        publication_id -> Uuid,
+        usage_type -> UsageType,
+        usage_status -> UsageStatus,
+        usage_limit -> Nullable<Int4>,
+        usage_unit -> Nullable<UsageUnit>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    usage_constraint_history (usage_constraint_history_id) {
+        usage_constraint_history_id -> Uuid,
+        usage_constraint_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::WorkType;
@@ -557,6 +1132,62 @@ table! {
         last_page -> Nullable<Text>,
         page_interval -> Nullable<Text>,
         updated_at_with_relations -> Timestamptz,
+        article_number -> Nullable<Text>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::LanguageCode;
+    use super::sql_types::AbstractType;
+
+    work_abstract (work_abstract_id) {
+        work_abstract_id -> Uuid,
+        work_id -> Uuid,
+        language_code -> LanguageCode,
+        abstract_type -> AbstractType,
+        content -> Text,
+        canonical -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_abstract_history (work_abstract_history_id) {
+        work_abstract_history_id -> Uuid,
+        work_abstract_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::IdentifierType;
+
+    work_identifier (work_identifier_id) {
+        work_identifier_id -> Uuid,
+        work_id -> Uuid,
+        identifier_type -> IdentifierType,
+        identifier_value -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_identifier_history (work_identifier_history_id) {
+        work_identifier_history_id -> Uuid,
+        work_identifier_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
     }
 }
 
@@ -572,6 +1203,16 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+
+    work_license_original (work_id) {
+        work_id -> Uuid,
+        original_license -> Text,
+        normalised_license -> Text,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use super::sql_types::RelationType;
@@ -599,16 +1240,62 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use super::sql_types::LanguageCode;
+
+    work_title (work_title_id) {
+        work_title_id -> Uuid,
+        work_id -> Uuid,
+        language_code -> LanguageCode,
+        full_title -> Text,
+        title -> Text,
+        subtitle -> Nullable<Text>,
+        canonical -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_title_history (work_title_history_id) {
+        work_title_history_id -> Uuid,
+        work_title_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamptz,
+    }
+}
+
+joinable!(account_login_audit -> account (account_id));
+joinable!(account_recovery_code -> account (account_id));
+joinable!(alternative_name -> contributor (contributor_id));
+joinable!(alternative_name_history -> account (account_id));
+joinable!(alternative_name_history -> alternative_name (alternative_name_id));
 joinable!(affiliation -> contribution (contribution_id));
 joinable!(affiliation -> institution (institution_id));
 joinable!(affiliation_history -> account (account_id));
 joinable!(affiliation_history -> affiliation (affiliation_id));
+joinable!(asset -> location (location_id));
+joinable!(asset -> publication (publication_id));
+joinable!(asset -> work (work_id));
+joinable!(asset_history -> account (account_id));
+joinable!(asset_history -> asset (asset_id));
 joinable!(contribution -> contributor (contributor_id));
 joinable!(contribution -> work (work_id));
 joinable!(contribution_history -> account (account_id));
 joinable!(contribution_history -> contribution (contribution_id));
 joinable!(contributor_history -> account (account_id));
 joinable!(contributor_history -> contributor (contributor_id));
+joinable!(event -> work (work_id));
+joinable!(event_history -> account (account_id));
+joinable!(event_history -> event (event_id));
+joinable!(event_sponsor -> event (event_id));
+joinable!(event_sponsor -> institution (institution_id));
+joinable!(event_sponsor_history -> account (account_id));
+joinable!(event_sponsor_history -> event_sponsor (event_sponsor_id));
 joinable!(funding -> institution (institution_id));
 joinable!(funding -> work (work_id));
 joinable!(funding_history -> account (account_id));
@@ -631,9 +1318,15 @@ joinable!(location_history -> location (location_id));
 joinable!(price -> publication (publication_id));
 joinable!(price_history -> account (account_id));
 joinable!(price_history -> price (price_id));
+joinable!(prize -> work (work_id));
+joinable!(prize_history -> account (account_id));
+joinable!(prize_history -> prize (prize_id));
 joinable!(publication -> work (work_id));
 joinable!(publication_history -> account (account_id));
 joinable!(publication_history -> publication (publication_id));
+joinable!(publication_identifier -> publication (publication_id));
+joinable!(publication_identifier_history -> account (account_id));
+joinable!(publication_identifier_history -> publication_identifier (publication_identifier_id));
 joinable!(publisher_account -> account (account_id));
 joinable!(publisher_account -> publisher (publisher_id));
 joinable!(publisher_history -> account (account_id));
@@ -641,54 +1334,114 @@ joinable!(publisher_history -> publisher (publisher_id));
 joinable!(reference -> work (work_id));
 joinable!(reference_history -> account (account_id));
 joinable!(reference_history -> reference (reference_id));
+joinable!(sales_rights -> publication (publication_id));
+joinable!(sales_rights_history -> account (account_id));
+joinable!(sales_rights_history -> sales_rights (sales_rights_id));
 joinable!(series -> imprint (imprint_id));
+joinable!(series_contribution -> contributor (contributor_id));
+joinable!(series_contribution -> series (series_id));
+joinable!(series_contribution_history -> account (account_id));
+joinable!(series_contribution_history -> series_contribution (series_contribution_id));
 joinable!(series_history -> account (account_id));
 joinable!(series_history -> series (series_id));
 joinable!(subject -> work (work_id));
 joinable!(subject_history -> account (account_id));
 joinable!(subject_history -> subject (subject_id));
+joinable!(supporting_resource -> asset (asset_id));
+joinable!(supporting_resource -> work (work_id));
+joinable!(supporting_resource_history -> account (account_id));
+joinable!(supporting_resource_history -> supporting_resource (supporting_resource_id));
+joinable!(text_content -> work (work_id));
+joinable!(text_content_history -> account (account_id));
+joinable!(text_content_history -> text_content (text_content_id));
+joinable!(usage_constraint -> publication (publication_id));
+joinable!(usage_constraint_history -> account (account_id));
+joinable!(usage_constraint_history -> usage_constraint (usage_constraint_id));
 joinable!(work -> imprint (imprint_id));
+joinable!(work_abstract -> work (work_id));
+joinable!(work_abstract_history -> account (account_id));
+joinable!(work_abstract_history -> work_abstract (work_abstract_id));
 joinable!(work_history -> account (account_id));
 joinable!(work_history -> work (work_id));
+joinable!(work_identifier -> work (work_id));
+joinable!(work_identifier_history -> account (account_id));
+joinable!(work_identifier_history -> work_identifier (work_identifier_id));
+joinable!(work_license_original -> work (work_id));
 joinable!(work_relation -> work (relator_work_id));
 joinable!(work_relation_history -> account (account_id));
 joinable!(work_relation_history -> work_relation (work_relation_id));
+joinable!(work_title -> work (work_id));
+joinable!(work_title_history -> account (account_id));
+joinable!(work_title_history -> work_title (work_title_id));
 
 allow_tables_to_appear_in_same_query!(
     account,
+    account_login_audit,
+    account_recovery_code,
     affiliation,
     affiliation_history,
+    alternative_name,
+    alternative_name_history,
+    asset,
+    asset_history,
     contribution,
     contribution_history,
     contributor,
     contributor_history,
+    event,
+    event_history,
+    event_sponsor,
+    event_sponsor_history,
     funding,
     funding_history,
     imprint,
     imprint_history,
     institution,
     institution_history,
+    institution_identifier_mapping,
     issue,
     issue_history,
     language,
     language_history,
     location,
     location_history,
+    persisted_query,
     price,
     price_history,
+    prize,
+    prize_history,
     publication,
     publication_history,
+    publication_identifier,
+    publication_identifier_history,
     publisher,
     publisher_account,
     publisher_history,
     reference,
     reference_history,
+    sales_rights,
+    sales_rights_history,
     series,
+    series_contribution,
+    series_contribution_history,
     series_history,
     subject,
     subject_history,
+    supporting_resource,
+    supporting_resource_history,
+    text_content,
+    text_content_history,
+    usage_constraint,
+    usage_constraint_history,
     work,
+    work_abstract,
+    work_abstract_history,
     work_history,
+    work_identifier,
+    work_identifier_history,
+    work_license_original,
     work_relation,
     work_relation_history,
+    work_title,
+    work_title_history,
 );
@@ -0,0 +1,460 @@
+use std::str::FromStr;
+use thoth_api::model::alternative_name::AlternativeName;
+use thoth_api::model::alternative_name::NameType;
+use thoth_api::model::language::LanguageCode;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormNameTypeSelect;
+use crate::component::utils::FormOptionalLanguageCodeSelect;
+use crate::component::utils::FormTextInput;
+use crate::models::alternative_name::create_alternative_name_mutation::CreateAlternativeNameRequest;
+use crate::models::alternative_name::create_alternative_name_mutation::CreateAlternativeNameRequestBody;
+use crate::models::alternative_name::create_alternative_name_mutation::PushActionCreateAlternativeName;
+use crate::models::alternative_name::create_alternative_name_mutation::PushCreateAlternativeName;
+use crate::models::alternative_name::create_alternative_name_mutation::Variables;
+use crate::models::alternative_name::delete_alternative_name_mutation::DeleteAlternativeNameRequest;
+use crate::models::alternative_name::delete_alternative_name_mutation::DeleteAlternativeNameRequestBody;
+use crate::models::alternative_name::delete_alternative_name_mutation::PushActionDeleteAlternativeName;
+use crate::models::alternative_name::delete_alternative_name_mutation::PushDeleteAlternativeName;
+use crate::models::alternative_name::delete_alternative_name_mutation::Variables as DeleteVariables;
+use crate::models::alternative_name::name_types_query::FetchActionNameTypes;
+use crate::models::alternative_name::name_types_query::FetchNameTypes;
+use crate::models::alternative_name::NameTypeValues;
+use crate::models::language::language_codes_query::FetchActionLanguageCodes;
+use crate::models::language::language_codes_query::FetchLanguageCodes;
+use crate::models::language::LanguageCodeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_ALTERNATIVE_NAMES;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct AlternativeNamesFormComponent {
+    data: AlternativeNamesFormData,
+    new_name: AlternativeName,
+    show_add_form: bool,
+    fetch_language_codes: FetchLanguageCodes,
+    fetch_name_types: FetchNameTypes,
+    push_name: PushCreateAlternativeName,
+    delete_name: PushDeleteAlternativeName,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct AlternativeNamesFormData {
+    language_codes: Vec<LanguageCodeValues>,
+    name_types: Vec<NameTypeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetLanguageCodesFetchState(FetchActionLanguageCodes),
+    GetLanguageCodes,
+    SetNameTypesFetchState(FetchActionNameTypes),
+    GetNameTypes,
+    SetNamePushState(PushActionCreateAlternativeName),
+    CreateName,
+    SetNameDeleteState(PushActionDeleteAlternativeName),
+    DeleteName(Uuid),
+    ChangeNameType(NameType),
+    ChangeFirstName(String),
+    ChangeLastName(String),
+    ChangeFullName(String),
+    ChangeLanguageCode(String),
+    ChangeScriptCode(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub alternative_names: Option<Vec<AlternativeName>>,
+    pub contributor_id: Uuid,
+    pub update_alternative_names: Callback<Option<Vec<AlternativeName>>>,
+}
+
+impl Component for AlternativeNamesFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: AlternativeNamesFormData = Default::default();
+        let show_add_form = false;
+        let new_name: AlternativeName = Default::default();
+        let fetch_language_codes = Default::default();
+        let fetch_name_types = Default::default();
+        let push_name = Default::default();
+        let delete_name = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetLanguageCodes);
+        ctx.link().send_message(Msg::GetNameTypes);
+
+        AlternativeNamesFormComponent {
+            data,
+            new_name,
+            show_add_form,
+            fetch_language_codes,
+            fetch_name_types,
+            push_name,
+            delete_name,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetLanguageCodesFetchState(fetch_state) => {
+                self.fetch_language_codes.apply(fetch_state);
+                self.data.language_codes = match self.fetch_language_codes.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.language_codes.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetLanguageCodes => {
+                ctx.link().send_future(
+                    self.fetch_language_codes
+                        .fetch(Msg::SetLanguageCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetLanguageCodesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetNameTypesFetchState(fetch_state) => {
+                self.fetch_name_types.apply(fetch_state);
+                self.data.name_types = match self.fetch_name_types.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.name_types.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetNameTypes => {
+                ctx.link()
+                    .send_future(self.fetch_name_types.fetch(Msg::SetNameTypesFetchState));
+                ctx.link()
+                    .send_message(Msg::SetNameTypesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetNamePushState(fetch_state) => {
+                self.push_name.apply(fetch_state);
+                match self.push_name.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_alternative_name {
+                        Some(n) => {
+                            let alternative_name = n.clone();
+                            let mut alternative_names: Vec<AlternativeName> =
+                                ctx.props().alternative_names.clone().unwrap_or_default();
+                            alternative_names.push(alternative_name);
+                            ctx.props()
+                                .update_alternative_names
+                                .emit(Some(alternative_names));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateName => {
+                let body = CreateAlternativeNameRequestBody {
+                    variables: Variables {
+                        contributor_id: ctx.props().contributor_id,
+                        name_type: self.new_name.name_type,
+                        first_name: self.new_name.first_name.clone(),
+                        last_name: self.new_name.last_name.clone(),
+                        full_name: self.new_name.full_name.clone(),
+                        language_code: self.new_name.language_code,
+                        script_code: self.new_name.script_code.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = CreateAlternativeNameRequest { body };
+                self.push_name = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_name.fetch(Msg::SetNamePushState));
+                ctx.link()
+                    .send_message(Msg::SetNamePushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetNameDeleteState(fetch_state) => {
+                self.delete_name.apply(fetch_state);
+                match self.delete_name.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_alternative_name {
+                        Some(alternative_name) => {
+                            let to_keep: Vec<AlternativeName> = ctx
+                                .props()
+                                .alternative_names
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|n| {
+                                    n.alternative_name_id != alternative_name.alternative_name_id
+                                })
+                                .collect();
+                            ctx.props().update_alternative_names.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteName(alternative_name_id) => {
+                let body = DeleteAlternativeNameRequestBody {
+                    variables: DeleteVariables {
+                        alternative_name_id,
+                    },
+                    ..Default::default()
+                };
+                let request = DeleteAlternativeNameRequest { body };
+                self.delete_name = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_name.fetch(Msg::SetNameDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetNameDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeNameType(name_type) => self.new_name.name_type.neq_assign(name_type),
+            Msg::ChangeFirstName(value) => {
+                self.new_name.first_name.neq_assign(value.to_opt_string())
+            }
+            Msg::ChangeLastName(last_name) => self
+                .new_name
+                .last_name
+                .neq_assign(last_name.trim().to_owned()),
+            Msg::ChangeFullName(full_name) => self
+                .new_name
+                .full_name
+                .neq_assign(full_name.trim().to_owned()),
+            Msg::ChangeLanguageCode(value) => self
+                .new_name
+                .language_code
+                .neq_assign(LanguageCode::from_str(&value).ok()),
+            Msg::ChangeScriptCode(value) => {
+                self.new_name.script_code.neq_assign(value.to_opt_string())
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let alternative_names = ctx.props().alternative_names.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Alternative Names" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Alternative Name" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Alternative Name" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="alternative-names-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateName
+                            }) }
+                            >
+                                <FormNameTypeSelect
+                                    label = "Name Type"
+                                    value={ self.new_name.name_type }
+                                    data={ self.data.name_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeNameType(NameType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Given Name"
+                                    value={ self.new_name.first_name.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFirstName(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Family Name"
+                                    value={ self.new_name.last_name.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLastName(e.to_value())) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Full Name"
+                                    value={ self.new_name.full_name.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFullName(e.to_value())) }
+                                    required = true
+                                />
+                                <FormOptionalLanguageCodeSelect
+                                    label = "Language"
+                                    value={ self.new_name.language_code }
+                                    data={ self.data.language_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeLanguageCode(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Script (ISO 15924, e.g. Cyrl)"
+                                    value={ self.new_name.script_code.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeScriptCode(e.to_value())) }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="alternative-names-form"
+                            >
+                                { "Add Alternative Name" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !alternative_names.is_empty() {
+                        html!{{for alternative_names.iter().map(|n| self.render_alternative_name(ctx, n))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_ALTERNATIVE_NAMES }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl AlternativeNamesFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_alternative_name(&self, ctx: &Context<Self>, n: &AlternativeName) -> Html {
+        let alternative_name_id = n.alternative_name_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-user" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Name Type" }</label>
+                        <div class="control is-expanded">
+                            {&n.name_type}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Full Name" }</label>
+                        <div class="control is-expanded">
+                            {&n.full_name}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Language" }</label>
+                        <div class="control is-expanded">
+                            {n.language_code.map(|l| l.to_string()).unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Script" }</label>
+                        <div class="control is-expanded">
+                            {n.script_code.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteName(alternative_name_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
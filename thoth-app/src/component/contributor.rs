@@ -1,6 +1,10 @@
 use thoth_api::account::model::AccountDetails;
+use thoth_api::model::alternative_name::AlternativeName;
 use thoth_api::model::contribution::ContributionWithWork;
-use thoth_api::model::contributor::Contributor;
+use thoth_api::model::contributor::ContributorWithAlternativeNames;
+use thoth_api::model::identifier::{
+    Isni, Lcnaf, Viaf, WikidataId, ISNI_DOMAIN, LCNAF_DOMAIN, VIAF_DOMAIN, WIKIDATA_DOMAIN,
+};
 use thoth_api::model::{Orcid, ORCID_DOMAIN};
 use thoth_errors::ThothError;
 use uuid::Uuid;
@@ -23,6 +27,7 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::alternative_names_form::AlternativeNamesFormComponent;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
 use crate::component::utils::FormTextInput;
 use crate::component::utils::FormTextInputExtended;
@@ -48,14 +53,26 @@ use crate::models::EditRoute;
 use crate::route::AdminRoute;
 use crate::string::SAVE_BUTTON;
 
+use super::accept_identifier;
+use super::check_identifier;
+use super::identifier_to_string;
 use super::ToElementValue;
 use super::ToOption;
 
 pub struct ContributorComponent {
-    contributor: Contributor,
+    contributor: ContributorWithAlternativeNames,
     // Track the user-entered ORCID string, which may not be validly formatted
     orcid: String,
     orcid_warning: String,
+    // Likewise for the authority file identifiers
+    isni: String,
+    isni_warning: String,
+    viaf: String,
+    viaf_warning: String,
+    wikidata: String,
+    wikidata_warning: String,
+    lcnaf: String,
+    lcnaf_warning: String,
     fetch_contributor: FetchContributor,
     push_contributor: PushUpdateContributor,
     delete_contributor: PushDeleteContributor,
@@ -77,6 +94,11 @@ pub enum Msg {
     ChangeFullName(String),
     ChangeOrcid(String),
     ChangeWebsite(String),
+    ChangeIsni(String),
+    ChangeViaf(String),
+    ChangeWikidata(String),
+    ChangeLcnaf(String),
+    UpdateAlternativeNames(Option<Vec<AlternativeName>>),
 }
 
 #[derive(PartialEq, Eq, Properties)]
@@ -101,7 +123,7 @@ impl Component for ContributorComponent {
         let push_contributor = Default::default();
         let delete_contributor = Default::default();
         let notification_bus = NotificationBus::dispatcher();
-        let contributor: Contributor = Default::default();
+        let contributor: ContributorWithAlternativeNames = Default::default();
         let orcid = Default::default();
         let orcid_warning = Default::default();
         let mut _contributor_activity_checker =
@@ -117,6 +139,14 @@ impl Component for ContributorComponent {
             contributor,
             orcid,
             orcid_warning,
+            isni: Default::default(),
+            isni_warning: Default::default(),
+            viaf: Default::default(),
+            viaf_warning: Default::default(),
+            wikidata: Default::default(),
+            wikidata_warning: Default::default(),
+            lcnaf: Default::default(),
+            lcnaf_warning: Default::default(),
             fetch_contributor,
             push_contributor,
             delete_contributor,
@@ -150,13 +180,8 @@ impl Component for ContributorComponent {
                             Some(c) => c.to_owned(),
                             None => Default::default(),
                         };
-                        // Initialise user-entered ORCID variable to match ORCID in database
-                        self.orcid = self
-                            .contributor
-                            .orcid
-                            .clone()
-                            .unwrap_or_default()
-                            .to_string();
+                        // Initialise user-entered identifier variables to match those in database
+                        self.reset_identifiers();
                         true
                     }
                     FetchState::Failed(_, _err) => false,
@@ -176,16 +201,11 @@ impl Component for ContributorComponent {
                     FetchState::Fetching(_) => false,
                     FetchState::Fetched(body) => match &body.data.update_contributor {
                         Some(c) => {
-                            // Save was successful: update user-entered ORCID variable to match ORCID in database
-                            self.orcid = self
-                                .contributor
-                                .orcid
-                                .clone()
-                                .unwrap_or_default()
-                                .to_string();
-                            self.orcid_warning.clear();
+                            let message = format!("Saved {}", c.full_name);
+                            // Save was successful: update user-entered identifier variables to match those in database
+                            self.reset_identifiers();
                             self.notification_bus.send(Request::NotificationBusMsg((
-                                format!("Saved {}", c.full_name),
+                                message,
                                 NotificationStatus::Success,
                             )));
                             true
@@ -216,6 +236,10 @@ impl Component for ContributorComponent {
                 } else if let Ok(result) = self.orcid.parse::<Orcid>() {
                     self.contributor.orcid.neq_assign(Some(result));
                 }
+                accept_identifier(&self.isni, &mut self.contributor.isni);
+                accept_identifier(&self.viaf, &mut self.contributor.viaf);
+                accept_identifier(&self.wikidata, &mut self.contributor.wikidata);
+                accept_identifier(&self.lcnaf, &mut self.contributor.lcnaf);
                 let body = UpdateContributorRequestBody {
                     variables: UpdateVariables {
                         contributor_id: self.contributor.contributor_id,
@@ -224,6 +248,10 @@ impl Component for ContributorComponent {
                         full_name: self.contributor.full_name.clone(),
                         orcid: self.contributor.orcid.clone(),
                         website: self.contributor.website.clone(),
+                        isni: self.contributor.isni.clone(),
+                        viaf: self.contributor.viaf.clone(),
+                        wikidata: self.contributor.wikidata.clone(),
+                        lcnaf: self.contributor.lcnaf.clone(),
                     },
                     ..Default::default()
                 };
@@ -317,6 +345,24 @@ impl Component for ContributorComponent {
                 }
             }
             Msg::ChangeWebsite(value) => self.contributor.website.neq_assign(value.to_opt_string()),
+            Msg::ChangeIsni(value) => {
+                check_identifier::<Isni>(value, &mut self.isni, &mut self.isni_warning)
+            }
+            Msg::ChangeViaf(value) => {
+                check_identifier::<Viaf>(value, &mut self.viaf, &mut self.viaf_warning)
+            }
+            Msg::ChangeWikidata(value) => check_identifier::<WikidataId>(
+                value,
+                &mut self.wikidata,
+                &mut self.wikidata_warning,
+            ),
+            Msg::ChangeLcnaf(value) => {
+                check_identifier::<Lcnaf>(value, &mut self.lcnaf, &mut self.lcnaf_warning)
+            }
+            Msg::UpdateAlternativeNames(alternative_names) => self
+                .contributor
+                .alternative_names
+                .neq_assign(alternative_names),
         }
     }
 
@@ -418,6 +464,34 @@ impl Component for ContributorComponent {
                                 value={ self.contributor.website.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeWebsite(e.to_value())) }
                             />
+                            <FormTextInputExtended
+                                label = "ISNI"
+                                statictext={ ISNI_DOMAIN }
+                                value={ self.isni.clone() }
+                                tooltip={ self.isni_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIsni(e.to_value())) }
+                            />
+                            <FormTextInputExtended
+                                label = "VIAF"
+                                statictext={ VIAF_DOMAIN }
+                                value={ self.viaf.clone() }
+                                tooltip={ self.viaf_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeViaf(e.to_value())) }
+                            />
+                            <FormTextInputExtended
+                                label = "Wikidata"
+                                statictext={ WIKIDATA_DOMAIN }
+                                value={ self.wikidata.clone() }
+                                tooltip={ self.wikidata_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeWikidata(e.to_value())) }
+                            />
+                            <FormTextInputExtended
+                                label = "LC Name Authority"
+                                statictext={ LCNAF_DOMAIN }
+                                value={ self.lcnaf.clone() }
+                                tooltip={ self.lcnaf_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLcnaf(e.to_value())) }
+                            />
 
                             <div class="field">
                                 <div class="control">
@@ -427,6 +501,14 @@ impl Component for ContributorComponent {
                                 </div>
                             </div>
                         </form>
+
+                        <hr/>
+
+                        <AlternativeNamesFormComponent
+                            alternative_names={ self.contributor.alternative_names.clone() }
+                            contributor_id={ self.contributor.contributor_id }
+                            update_alternative_names={ ctx.link().callback(Msg::UpdateAlternativeNames) }
+                        />
                     </>
                 }
             }
@@ -436,3 +518,23 @@ impl Component for ContributorComponent {
         }
     }
 }
+
+impl ContributorComponent {
+    fn reset_identifiers(&mut self) {
+        self.orcid = self
+            .contributor
+            .orcid
+            .clone()
+            .unwrap_or_default()
+            .to_string();
+        self.orcid_warning.clear();
+        self.isni = identifier_to_string(&self.contributor.isni);
+        self.isni_warning.clear();
+        self.viaf = identifier_to_string(&self.contributor.viaf);
+        self.viaf_warning.clear();
+        self.wikidata = identifier_to_string(&self.contributor.wikidata);
+        self.wikidata_warning.clear();
+        self.lcnaf = identifier_to_string(&self.contributor.lcnaf);
+        self.lcnaf_warning.clear();
+    }
+}
@@ -0,0 +1,611 @@
+use chrono::NaiveDate;
+use std::str::FromStr;
+use thoth_api::model::event::EventWithSponsors;
+use thoth_api::model::event_sponsor::EventSponsorWithInstitution;
+use thoth_api::model::institution::CountryCode;
+use thoth_api::model::institution::Institution;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::institution_select::InstitutionSelectComponent;
+use crate::component::utils::FormCountryCodeSelect;
+use crate::component::utils::FormDateInput;
+use crate::component::utils::FormTextInput;
+use crate::models::event::create_event_mutation::CreateEventRequest;
+use crate::models::event::create_event_mutation::CreateEventRequestBody;
+use crate::models::event::create_event_mutation::PushActionCreateEvent;
+use crate::models::event::create_event_mutation::PushCreateEvent;
+use crate::models::event::create_event_mutation::Variables;
+use crate::models::event::delete_event_mutation::DeleteEventRequest;
+use crate::models::event::delete_event_mutation::DeleteEventRequestBody;
+use crate::models::event::delete_event_mutation::PushActionDeleteEvent;
+use crate::models::event::delete_event_mutation::PushDeleteEvent;
+use crate::models::event::delete_event_mutation::Variables as DeleteVariables;
+use crate::models::event_sponsor::create_event_sponsor_mutation::CreateEventSponsorRequest;
+use crate::models::event_sponsor::create_event_sponsor_mutation::CreateEventSponsorRequestBody;
+use crate::models::event_sponsor::create_event_sponsor_mutation::PushActionCreateEventSponsor;
+use crate::models::event_sponsor::create_event_sponsor_mutation::PushCreateEventSponsor;
+use crate::models::event_sponsor::create_event_sponsor_mutation::Variables as CreateSponsorVariables;
+use crate::models::event_sponsor::delete_event_sponsor_mutation::DeleteEventSponsorRequest;
+use crate::models::event_sponsor::delete_event_sponsor_mutation::DeleteEventSponsorRequestBody;
+use crate::models::event_sponsor::delete_event_sponsor_mutation::PushActionDeleteEventSponsor;
+use crate::models::event_sponsor::delete_event_sponsor_mutation::PushDeleteEventSponsor;
+use crate::models::event_sponsor::delete_event_sponsor_mutation::Variables as DeleteSponsorVariables;
+use crate::models::institution::country_codes_query::FetchActionCountryCodes;
+use crate::models::institution::country_codes_query::FetchCountryCodes;
+use crate::models::institution::CountryCodeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_EVENTS;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct EventsFormComponent {
+    data: EventsFormData,
+    new_event: EventWithSponsors,
+    show_add_form: bool,
+    fetch_country_codes: FetchCountryCodes,
+    push_event: PushCreateEvent,
+    delete_event: PushDeleteEvent,
+    push_sponsor: PushCreateEventSponsor,
+    delete_sponsor: PushDeleteEventSponsor,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct EventsFormData {
+    country_codes: Vec<CountryCodeValues>,
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetCountryCodesFetchState(FetchActionCountryCodes),
+    GetCountryCodes,
+    SetEventPushState(PushActionCreateEvent),
+    CreateEvent,
+    SetEventDeleteState(PushActionDeleteEvent),
+    DeleteEvent(Uuid),
+    SetSponsorPushState(PushActionCreateEventSponsor),
+    AddSponsor(Uuid, Institution),
+    SetSponsorDeleteState(PushActionDeleteEventSponsor),
+    DeleteSponsor(Uuid),
+    ChangeEventName(String),
+    ChangeEventAcronym(String),
+    ChangeEventNumber(String),
+    ChangeEventLocation(String),
+    ChangeEventCountry(String),
+    ChangeEventStartDate(String),
+    ChangeEventEndDate(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub events: Option<Vec<EventWithSponsors>>,
+    pub work_id: Uuid,
+    pub update_events: Callback<Option<Vec<EventWithSponsors>>>,
+}
+
+impl Component for EventsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: EventsFormData = Default::default();
+        let show_add_form = false;
+        let new_event: EventWithSponsors = Default::default();
+        let fetch_country_codes = Default::default();
+        let push_event = Default::default();
+        let delete_event = Default::default();
+        let push_sponsor = Default::default();
+        let delete_sponsor = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetCountryCodes);
+
+        EventsFormComponent {
+            data,
+            new_event,
+            show_add_form,
+            fetch_country_codes,
+            push_event,
+            delete_event,
+            push_sponsor,
+            delete_sponsor,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetCountryCodesFetchState(fetch_state) => {
+                self.fetch_country_codes.apply(fetch_state);
+                self.data.country_codes = match self.fetch_country_codes.as_ref().state() {
+                    FetchState::Fetched(body) => body.data.country_codes.enum_values.clone(),
+                    _ => vec![],
+                };
+                true
+            }
+            Msg::GetCountryCodes => {
+                ctx.link().send_future(
+                    self.fetch_country_codes
+                        .fetch(Msg::SetCountryCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetCountryCodesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetEventPushState(fetch_state) => {
+                self.push_event.apply(fetch_state);
+                match self.push_event.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_event {
+                        Some(e) => {
+                            let event = e.clone();
+                            let mut events: Vec<EventWithSponsors> =
+                                ctx.props().events.clone().unwrap_or_default();
+                            events.push(event);
+                            ctx.props().update_events.emit(Some(events));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateEvent => {
+                let body = CreateEventRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        event_name: self.new_event.event_name.clone(),
+                        event_acronym: self.new_event.event_acronym.clone(),
+                        event_number: self.new_event.event_number.clone(),
+                        event_location: self.new_event.event_location.clone(),
+                        event_country: self.new_event.event_country,
+                        event_start_date: self.new_event.event_start_date,
+                        event_end_date: self.new_event.event_end_date,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateEventRequest { body };
+                self.push_event = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_event.fetch(Msg::SetEventPushState));
+                ctx.link()
+                    .send_message(Msg::SetEventPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetEventDeleteState(fetch_state) => {
+                self.delete_event.apply(fetch_state);
+                match self.delete_event.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_event {
+                        Some(event) => {
+                            let to_keep: Vec<EventWithSponsors> = ctx
+                                .props()
+                                .events
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|e| e.event_id != event.event_id)
+                                .collect();
+                            ctx.props().update_events.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteEvent(event_id) => {
+                let body = DeleteEventRequestBody {
+                    variables: DeleteVariables { event_id },
+                    ..Default::default()
+                };
+                let request = DeleteEventRequest { body };
+                self.delete_event = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_event.fetch(Msg::SetEventDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetEventDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSponsorPushState(fetch_state) => {
+                self.push_sponsor.apply(fetch_state);
+                match self.push_sponsor.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_event_sponsor {
+                        Some(s) => {
+                            let sponsor = s.clone();
+                            let mut events: Vec<EventWithSponsors> =
+                                ctx.props().events.clone().unwrap_or_default();
+                            if let Some(event) =
+                                events.iter_mut().find(|e| e.event_id == sponsor.event_id)
+                            {
+                                event.sponsors.get_or_insert_with(Vec::new).push(sponsor);
+                            }
+                            ctx.props().update_events.emit(Some(events));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::AddSponsor(event_id, institution) => {
+                let sponsors: Vec<EventSponsorWithInstitution> = ctx
+                    .props()
+                    .events
+                    .clone()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|e| e.event_id == event_id)
+                    .and_then(|e| e.sponsors)
+                    .unwrap_or_default();
+                let sponsor_ordinal = sponsors
+                    .iter()
+                    .map(|s| s.sponsor_ordinal)
+                    .max()
+                    .unwrap_or_default()
+                    + 1;
+                let body = CreateEventSponsorRequestBody {
+                    variables: CreateSponsorVariables {
+                        event_id,
+                        institution_id: institution.institution_id,
+                        sponsor_ordinal,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateEventSponsorRequest { body };
+                self.push_sponsor = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_sponsor.fetch(Msg::SetSponsorPushState));
+                ctx.link()
+                    .send_message(Msg::SetSponsorPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSponsorDeleteState(fetch_state) => {
+                self.delete_sponsor.apply(fetch_state);
+                match self.delete_sponsor.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_event_sponsor {
+                        Some(sponsor) => {
+                            let mut events: Vec<EventWithSponsors> =
+                                ctx.props().events.clone().unwrap_or_default();
+                            if let Some(event) =
+                                events.iter_mut().find(|e| e.event_id == sponsor.event_id)
+                            {
+                                if let Some(sponsors) = event.sponsors.as_mut() {
+                                    sponsors
+                                        .retain(|s| s.event_sponsor_id != sponsor.event_sponsor_id);
+                                }
+                            }
+                            ctx.props().update_events.emit(Some(events));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteSponsor(event_sponsor_id) => {
+                let body = DeleteEventSponsorRequestBody {
+                    variables: DeleteSponsorVariables { event_sponsor_id },
+                    ..Default::default()
+                };
+                let request = DeleteEventSponsorRequest { body };
+                self.delete_sponsor = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_sponsor.fetch(Msg::SetSponsorDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetSponsorDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeEventName(value) => self
+                .new_event
+                .event_name
+                .neq_assign(value.trim().to_owned()),
+            Msg::ChangeEventAcronym(value) => self
+                .new_event
+                .event_acronym
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeEventNumber(value) => self
+                .new_event
+                .event_number
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeEventLocation(value) => self
+                .new_event
+                .event_location
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeEventCountry(value) => self
+                .new_event
+                .event_country
+                .neq_assign(CountryCode::from_str(&value).ok()),
+            Msg::ChangeEventStartDate(value) => self
+                .new_event
+                .event_start_date
+                .neq_assign(NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()),
+            Msg::ChangeEventEndDate(value) => self
+                .new_event
+                .event_end_date
+                .neq_assign(NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let events = ctx.props().events.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Events" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Event" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Event" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="events-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateEvent
+                            }) }
+                            >
+                                <FormTextInput
+                                    label = "Event Name"
+                                    value={ self.new_event.event_name.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventName(e.to_value())) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Acronym"
+                                    value={ self.new_event.event_acronym.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventAcronym(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Number"
+                                    value={ self.new_event.event_number.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventNumber(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Location"
+                                    value={ self.new_event.event_location.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventLocation(e.to_value())) }
+                                />
+                                <FormCountryCodeSelect
+                                    label = "Country"
+                                    value={ self.new_event.event_country }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeEventCountry(e.to_value())) }
+                                />
+                                <FormDateInput
+                                    label = "Start Date"
+                                    value={ self.new_event.event_start_date.to_value() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventStartDate(e.to_value())) }
+                                />
+                                <FormDateInput
+                                    label = "End Date"
+                                    value={ self.new_event.event_end_date.to_value() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEventEndDate(e.to_value())) }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="events-form"
+                            >
+                                { "Add Event" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !events.is_empty() {
+                        html!{{for events.iter().map(|e| self.render_event(ctx, e))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_EVENTS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl EventsFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_event(&self, ctx: &Context<Self>, e: &EventWithSponsors) -> Html {
+        let event_id = e.event_id;
+        let mut sponsors = e.sponsors.clone().unwrap_or_default();
+        sponsors.sort_by_key(|s| s.sponsor_ordinal);
+        let dates = [e.event_start_date, e.event_end_date]
+            .iter()
+            .flatten()
+            .map(|d| d.to_string())
+            .collect::<Vec<String>>()
+            .join(" – ");
+        let institution_select_callback = ctx
+            .link()
+            .callback(move |institution: Institution| Msg::AddSponsor(event_id, institution));
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-users" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field">
+                        <label class="label">{ "Event Name" }</label>
+                        <div class="control is-expanded">
+                            {&e.event_name}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Acronym" }</label>
+                        <div class="control is-expanded">
+                            {e.event_acronym.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 12em;">
+                        <label class="label">{ "Dates" }</label>
+                        <div class="control is-expanded">
+                            {dates}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Country" }</label>
+                        <div class="control is-expanded">
+                            {e.event_country.map(|c| c.to_string()).unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Sponsors" }</label>
+                        <div class="control is-expanded">
+                            <div class="tags">
+                                {for sponsors.iter().map(|s| self.render_sponsor(ctx, s))}
+                            </div>
+                            <InstitutionSelectComponent callback={institution_select_callback} />
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteEvent(event_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn render_sponsor(&self, ctx: &Context<Self>, s: &EventSponsorWithInstitution) -> Html {
+        let event_sponsor_id = s.event_sponsor_id;
+        html! {
+            <span class="tag is-medium">
+                {&s.institution.institution_name}
+                <button
+                    class="delete is-small"
+                    onclick={ ctx.link().callback(move |_| Msg::DeleteSponsor(event_sponsor_id)) }
+                ></button>
+            </span>
+        }
+    }
+}
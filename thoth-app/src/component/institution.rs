@@ -1,5 +1,6 @@
 use std::str::FromStr;
 use thoth_api::account::model::AccountDetails;
+use thoth_api::model::identifier::{FunderId, Isni, Ringgold, FUNDER_ID_DOMAIN, ISNI_DOMAIN};
 use thoth_api::model::institution::CountryCode;
 use thoth_api::model::institution::Institution;
 use thoth_api::model::work::WorkWithRelations;
@@ -53,6 +54,9 @@ use crate::models::EditRoute;
 use crate::route::AdminRoute;
 use crate::string::SAVE_BUTTON;
 
+use super::accept_identifier;
+use super::check_identifier;
+use super::identifier_to_string;
 use super::ToElementValue;
 
 pub struct InstitutionComponent {
@@ -64,6 +68,13 @@ pub struct InstitutionComponent {
     // Track the user-entered ROR string, which may not be validly formatted
     ror: String,
     ror_warning: String,
+    // Likewise for the other institution identifiers
+    crossref_funder_id: String,
+    crossref_funder_id_warning: String,
+    isni: String,
+    isni_warning: String,
+    ringgold: String,
+    ringgold_warning: String,
     fetch_institution: FetchInstitution,
     push_institution: PushUpdateInstitution,
     delete_institution: PushDeleteInstitution,
@@ -92,6 +103,9 @@ pub enum Msg {
     ChangeInstitutionName(String),
     ChangeInstitutionDoi(String),
     ChangeRor(String),
+    ChangeCrossrefFunderId(String),
+    ChangeIsni(String),
+    ChangeRinggold(String),
     ChangeCountryCode(String),
 }
 
@@ -142,6 +156,12 @@ impl Component for InstitutionComponent {
             institution_doi_warning,
             ror,
             ror_warning,
+            crossref_funder_id: Default::default(),
+            crossref_funder_id_warning: Default::default(),
+            isni: Default::default(),
+            isni_warning: Default::default(),
+            ringgold: Default::default(),
+            ringgold_warning: Default::default(),
             fetch_institution,
             push_institution,
             delete_institution,
@@ -218,6 +238,7 @@ impl Component for InstitutionComponent {
                             .to_string();
                         // Initialise user-entered ROR variable to match ROR in database
                         self.ror = self.institution.ror.clone().unwrap_or_default().to_string();
+                        self.reset_identifiers();
                         true
                     }
                     FetchState::Failed(_, _err) => false,
@@ -237,6 +258,7 @@ impl Component for InstitutionComponent {
                     FetchState::Fetching(_) => false,
                     FetchState::Fetched(body) => match &body.data.update_institution {
                         Some(i) => {
+                            let message = format!("Saved {}", i.institution_name);
                             // Save was successful: update user-entered DOI variable to match DOI in database
                             self.institution_doi = self
                                 .institution
@@ -248,8 +270,9 @@ impl Component for InstitutionComponent {
                             // Save was successful: update user-entered ROR variable to match ROR in database
                             self.ror = self.institution.ror.clone().unwrap_or_default().to_string();
                             self.ror_warning.clear();
+                            self.reset_identifiers();
                             self.notification_bus.send(Request::NotificationBusMsg((
-                                format!("Saved {}", i.institution_name),
+                                message,
                                 NotificationStatus::Success,
                             )));
                             true
@@ -288,6 +311,12 @@ impl Component for InstitutionComponent {
                 } else if let Ok(result) = self.ror.parse::<Ror>() {
                     self.institution.ror.neq_assign(Some(result));
                 }
+                accept_identifier(
+                    &self.crossref_funder_id,
+                    &mut self.institution.crossref_funder_id,
+                );
+                accept_identifier(&self.isni, &mut self.institution.isni);
+                accept_identifier(&self.ringgold, &mut self.institution.ringgold);
                 let body = UpdateInstitutionRequestBody {
                     variables: UpdateVariables {
                         institution_id: self.institution.institution_id,
@@ -295,6 +324,9 @@ impl Component for InstitutionComponent {
                         institution_doi: self.institution.institution_doi.clone(),
                         ror: self.institution.ror.clone(),
                         country_code: self.institution.country_code,
+                        crossref_funder_id: self.institution.crossref_funder_id.clone(),
+                        isni: self.institution.isni.clone(),
+                        ringgold: self.institution.ringgold.clone(),
                     },
                     ..Default::default()
                 };
@@ -400,6 +432,17 @@ impl Component for InstitutionComponent {
                     false
                 }
             }
+            Msg::ChangeCrossrefFunderId(value) => check_identifier::<FunderId>(
+                value,
+                &mut self.crossref_funder_id,
+                &mut self.crossref_funder_id_warning,
+            ),
+            Msg::ChangeIsni(value) => {
+                check_identifier::<Isni>(value, &mut self.isni, &mut self.isni_warning)
+            }
+            Msg::ChangeRinggold(value) => {
+                check_identifier::<Ringgold>(value, &mut self.ringgold, &mut self.ringgold_warning)
+            }
             Msg::ChangeCountryCode(value) => self
                 .institution
                 .country_code
@@ -474,6 +517,26 @@ impl Component for InstitutionComponent {
                                 tooltip={ self.ror_warning.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRor(e.to_value())) }
                             />
+                            <FormTextInputExtended
+                                label = "Crossref Funder ID"
+                                statictext={ FUNDER_ID_DOMAIN }
+                                value={ self.crossref_funder_id.clone() }
+                                tooltip={ self.crossref_funder_id_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCrossrefFunderId(e.to_value())) }
+                            />
+                            <FormTextInputExtended
+                                label = "ISNI"
+                                statictext={ ISNI_DOMAIN }
+                                value={ self.isni.clone() }
+                                tooltip={ self.isni_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIsni(e.to_value())) }
+                            />
+                            <FormTextInputExtended
+                                label = "Ringgold ID"
+                                value={ self.ringgold.clone() }
+                                tooltip={ self.ringgold_warning.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRinggold(e.to_value())) }
+                            />
                             <FormCountryCodeSelect
                                 label = "Country"
                                 value={ self.institution.country_code }
@@ -500,6 +563,15 @@ impl Component for InstitutionComponent {
 }
 
 impl InstitutionComponent {
+    fn reset_identifiers(&mut self) {
+        self.crossref_funder_id = identifier_to_string(&self.institution.crossref_funder_id);
+        self.crossref_funder_id_warning.clear();
+        self.isni = identifier_to_string(&self.institution.isni);
+        self.isni_warning.clear();
+        self.ringgold = identifier_to_string(&self.institution.ringgold);
+        self.ringgold_warning.clear();
+    }
+
     fn render_associated_works(&self, w: &[WorkWithRelations], explanatory_text: &str) -> Html {
         {
             if !w.is_empty() {
@@ -18,6 +18,7 @@ use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::utils::FormNumberInput;
+use crate::component::utils::FormTextInput;
 use crate::models::issue::create_issue_mutation::CreateIssueRequest;
 use crate::models::issue::create_issue_mutation::CreateIssueRequestBody;
 use crate::models::issue::create_issue_mutation::PushActionCreateIssue;
@@ -40,6 +41,7 @@ use crate::string::REMOVE_BUTTON;
 use crate::DEFAULT_DEBOUNCING_TIMEOUT;
 
 use super::ToElementValue;
+use super::ToOption;
 
 pub struct IssuesFormComponent {
     data: IssuesFormData,
@@ -76,6 +78,9 @@ pub enum Msg {
     SearchQueryChanged(String),
     SearchSeries,
     ChangeOrdinal(String),
+    ChangeVolume(String),
+    ChangeIssueNumber(String),
+    ChangePart(String),
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -194,6 +199,9 @@ impl Component for IssuesFormComponent {
                         work_id: ctx.props().work_id,
                         series_id: self.new_issue.series_id,
                         issue_ordinal: self.new_issue.issue_ordinal,
+                        volume: self.new_issue.volume.clone(),
+                        issue_number: self.new_issue.issue_number.clone(),
+                        part: self.new_issue.part.clone(),
                     },
                     ..Default::default()
                 };
@@ -298,6 +306,20 @@ impl Component for IssuesFormComponent {
                 self.new_issue.issue_ordinal.neq_assign(ordinal);
                 false // otherwise we re-render the component and reset the value
             }
+            Msg::ChangeVolume(value) => {
+                self.new_issue.volume.neq_assign(value.to_opt_string());
+                false
+            }
+            Msg::ChangeIssueNumber(value) => {
+                self.new_issue
+                    .issue_number
+                    .neq_assign(value.to_opt_string());
+                false
+            }
+            Msg::ChangePart(value) => {
+                self.new_issue.part.neq_assign(value.to_opt_string());
+                false
+            }
         }
     }
 
@@ -403,6 +425,21 @@ impl Component for IssuesFormComponent {
                                     required = true
                                     min={ "1".to_string() }
                                 />
+                                <FormTextInput
+                                    label="Volume"
+                                    value={ self.new_issue.volume.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeVolume(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label="Issue Number"
+                                    value={ self.new_issue.issue_number.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIssueNumber(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label="Part"
+                                    value={ self.new_issue.part.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePart(e.to_value())) }
+                                />
                             </form>
                         </section>
                         <footer class="modal-card-foot">
@@ -496,6 +533,27 @@ impl IssuesFormComponent {
                         </div>
                     </div>
 
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Volume" }</label>
+                        <div class="control is-expanded">
+                            {&i.volume.as_ref().unwrap_or(&String::default())}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Issue Number" }</label>
+                        <div class="control is-expanded">
+                            {&i.issue_number.as_ref().unwrap_or(&String::default())}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Part" }</label>
+                        <div class="control is-expanded">
+                            {&i.part.as_ref().unwrap_or(&String::default())}
+                        </div>
+                    </div>
+
                     <div class="field">
                         <label class="label"></label>
                         <div class="control is-expanded">
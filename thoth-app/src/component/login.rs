@@ -1,9 +1,11 @@
 use thoth_api::account::model::AccountDetails;
 use thoth_api::account::model::LoginCredentials;
+use thoth_api::account::model::TwoFactorLogin;
 use yew::html;
 use yew::prelude::*;
 use yew_agent::Dispatched;
 use yew_router::history::History;
+use yew_router::history::Location;
 use yew_router::prelude::RouterScopeExt;
 use yewtil::NeqAssign;
 
@@ -12,13 +14,23 @@ use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::route::AdminRoute;
+use crate::route::AppRoute;
 use crate::service::account::AccountError;
 use crate::service::account::AccountService;
+use crate::string::ACCOUNT_LOCKED_ERROR;
 use crate::string::AUTHENTICATION_ERROR;
 use crate::string::INPUT_EMAIL;
 use crate::string::INPUT_PASSWORD;
+use crate::string::INPUT_TWO_FACTOR_CODE;
+use crate::string::RATE_LIMIT_ERROR;
 use crate::string::RESPONSE_ERROR;
+use crate::string::SSO_ERROR;
 use crate::string::TEXT_LOGIN;
+use crate::string::TEXT_LOGIN_SSO;
+use crate::string::TWO_FACTOR_REQUIRED;
+
+use crate::SSO_ENABLED;
+use crate::THOTH_GRAPHQL_API;
 
 use super::ToElementValue;
 
@@ -26,6 +38,10 @@ pub struct LoginComponent {
     request: LoginCredentials,
     account_service: AccountService,
     notification_bus: NotificationDispatcher,
+    // Set once the server asks for a second factor
+    two_factor_step: bool,
+    // Set when single sign-on must be completed with a second factor
+    sso_challenge: Option<String>,
 }
 
 #[derive(PartialEq, Properties)]
@@ -37,9 +53,11 @@ pub struct Props {
 pub enum Msg {
     RedirectToAdmin,
     Request,
+    SingleSignOn(String),
     Response(Result<AccountDetails, AccountError>),
     ChangeEmail(String),
     ChangePassword(String),
+    ChangeTotpCode(String),
 }
 
 impl Component for LoginComponent {
@@ -51,6 +69,8 @@ impl Component for LoginComponent {
             request: Default::default(),
             account_service: AccountService::new(),
             notification_bus: NotificationBus::dispatcher(),
+            two_factor_step: false,
+            sso_challenge: None,
         }
     }
 
@@ -58,6 +78,13 @@ impl Component for LoginComponent {
         // if user is logged in there's no point in seeing the login page
         if first_render && ctx.props().current_user.is_some() {
             ctx.link().send_message(Msg::RedirectToAdmin);
+        } else if first_render {
+            // the API redirects back here after single sign-on, passing the outcome in the fragment
+            let hash = ctx.link().location().unwrap().hash();
+            if let Some(fragment) = hash.strip_prefix('#') {
+                ctx.link()
+                    .send_message(Msg::SingleSignOn(fragment.to_string()));
+            }
         }
     }
 
@@ -76,9 +103,46 @@ impl Component for LoginComponent {
             }
             Msg::Request => {
                 let mut service = self.account_service.clone();
-                let request = self.request.clone();
-                ctx.link()
-                    .send_future(async move { Msg::Response(service.login(request).await) });
+                match self.sso_challenge.clone() {
+                    Some(challenge) => {
+                        let request = TwoFactorLogin {
+                            challenge,
+                            totp_code: self.request.totp_code.clone().unwrap_or_default(),
+                        };
+                        ctx.link().send_future(async move {
+                            Msg::Response(service.login_two_factor(request).await)
+                        });
+                    }
+                    None => {
+                        let request = self.request.clone();
+                        ctx.link().send_future(async move {
+                            Msg::Response(service.login(request).await)
+                        });
+                    }
+                }
+                true
+            }
+            Msg::SingleSignOn(fragment) => {
+                // remove the token from the address bar and browser history
+                ctx.link().history().unwrap().replace(AppRoute::Login);
+                if let Some(token) = fragment.strip_prefix("token=") {
+                    self.account_service.set_token(token.to_string());
+                    let mut service = self.account_service.clone();
+                    ctx.link()
+                        .send_future(async move { Msg::Response(service.account_details().await) });
+                } else if let Some(challenge) = fragment.strip_prefix("two_factor=") {
+                    self.sso_challenge = Some(challenge.to_string());
+                    self.two_factor_step = true;
+                    self.notification_bus.send(Request::NotificationBusMsg((
+                        TWO_FACTOR_REQUIRED.into(),
+                        NotificationStatus::Warning,
+                    )));
+                } else {
+                    self.notification_bus.send(Request::NotificationBusMsg((
+                        SSO_ERROR.into(),
+                        NotificationStatus::Warning,
+                    )));
+                }
                 true
             }
             Msg::Response(Ok(account_details)) => {
@@ -96,17 +160,43 @@ impl Component for LoginComponent {
                             NotificationStatus::Warning,
                         )));
                     }
+                    AccountError::AccountLocked => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ACCOUNT_LOCKED_ERROR.into(),
+                            NotificationStatus::Warning,
+                        )));
+                    }
                     AccountError::ResponseError => {
                         self.notification_bus.send(Request::NotificationBusMsg((
                             RESPONSE_ERROR.into(),
                             NotificationStatus::Danger,
                         )));
                     }
+                    AccountError::TooManyRequests => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            RATE_LIMIT_ERROR.into(),
+                            NotificationStatus::Warning,
+                        )));
+                    }
+                    AccountError::TwoFactorRequired => {
+                        self.two_factor_step = true;
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            TWO_FACTOR_REQUIRED.into(),
+                            NotificationStatus::Warning,
+                        )));
+                    }
                 };
                 true
             }
             Msg::ChangeEmail(email) => self.request.email.neq_assign(email),
             Msg::ChangePassword(password) => self.request.password.neq_assign(password),
+            Msg::ChangeTotpCode(code) => {
+                let code = match code.trim().is_empty() {
+                    true => None,
+                    false => Some(code.trim().to_string()),
+                };
+                self.request.totp_code.neq_assign(code)
+            }
         }
     }
 
@@ -115,34 +205,68 @@ impl Component for LoginComponent {
             <div class="columns is-mobile is-centered">
                 <div class="column is-3">
                     <div class="box">
-                        <div class="field">
-                            <p class="control has-icons-left has-icons-right">
-                                <input
-                                    class="input"
-                                    type="email"
-                                    value={ self.request.email.clone() }
-                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEmail(e.to_value())) }
-                                    placeholder={ INPUT_EMAIL }
-                                />
-                                <span class="icon is-small is-left">
-                                    <i class="fas fa-envelope"></i>
-                                </span>
-                            </p>
-                        </div>
-                        <div class="field">
-                            <p class="control has-icons-left">
-                                <input
-                                    class="input"
-                                    type="password"
-                                    value={ self.request.password.clone() }
-                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePassword(e.to_value())) }
-                                    placeholder={ INPUT_PASSWORD }
-                                />
-                                <span class="icon is-small is-left">
-                                    <i class="fas fa-lock"></i>
-                                </span>
-                            </p>
-                        </div>
+                        {
+                            // single sign-on has already established who is logging in
+                            if self.sso_challenge.is_none() {
+                                html! {
+                                    <>
+                                        <div class="field">
+                                            <p class="control has-icons-left has-icons-right">
+                                                <input
+                                                    class="input"
+                                                    type="email"
+                                                    value={ self.request.email.clone() }
+                                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEmail(e.to_value())) }
+                                                    placeholder={ INPUT_EMAIL }
+                                                />
+                                                <span class="icon is-small is-left">
+                                                    <i class="fas fa-envelope"></i>
+                                                </span>
+                                            </p>
+                                        </div>
+                                        <div class="field">
+                                            <p class="control has-icons-left">
+                                                <input
+                                                    class="input"
+                                                    type="password"
+                                                    value={ self.request.password.clone() }
+                                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePassword(e.to_value())) }
+                                                    placeholder={ INPUT_PASSWORD }
+                                                />
+                                                <span class="icon is-small is-left">
+                                                    <i class="fas fa-lock"></i>
+                                                </span>
+                                            </p>
+                                        </div>
+                                    </>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        {
+                            if self.two_factor_step {
+                                html! {
+                                    <div class="field">
+                                        <p class="control has-icons-left">
+                                            <input
+                                                class="input"
+                                                type="text"
+                                                autocomplete="one-time-code"
+                                                value={ self.request.totp_code.clone().unwrap_or_default() }
+                                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTotpCode(e.to_value())) }
+                                                placeholder={ INPUT_TWO_FACTOR_CODE }
+                                            />
+                                            <span class="icon is-small is-left">
+                                                <i class="fas fa-key"></i>
+                                            </span>
+                                        </p>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                         <div class="field">
                             <p class="control">
                                 <button
@@ -153,6 +277,24 @@ impl Component for LoginComponent {
                                 </button>
                             </p>
                         </div>
+                        {
+                            if SSO_ENABLED {
+                                html! {
+                                    <div class="field">
+                                        <p class="control">
+                                            <a
+                                                class="button is-fullwidth"
+                                                href={ format!("{THOTH_GRAPHQL_API}/account/oidc/login") }
+                                            >
+                                                { TEXT_LOGIN_SSO }
+                                            </a>
+                                        </p>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                     </div>
                 </div>
             </div>
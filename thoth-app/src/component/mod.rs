@@ -1,5 +1,9 @@
 #![allow(clippy::let_unit_value)]
 
+use std::str::FromStr;
+use thoth_errors::ThothError;
+use yewtil::NeqAssign;
+
 #[macro_export]
 macro_rules! pagination_helpers {
     ($component:ident, $pagination_text:ident, $search_text:ident) => {
@@ -437,6 +441,30 @@ impl ToElementValue for yew::Event {
     }
 }
 
+pub trait ToElementValues {
+    fn to_values(self) -> Vec<String>;
+}
+
+impl ToElementValues for yew::Event {
+    fn to_values(self) -> Vec<String> {
+        use wasm_bindgen::JsCast;
+        use web_sys::{HtmlOptionElement, HtmlSelectElement};
+        let target = self.target().expect("Failed to get Event target");
+        if target.has_type::<HtmlSelectElement>() {
+            let options = target
+                .unchecked_into::<HtmlSelectElement>()
+                .selected_options();
+            (0..options.length())
+                .filter_map(|i| options.item(i))
+                .map(|option| option.unchecked_into::<HtmlOptionElement>().value())
+                .collect()
+        } else {
+            // We currently only expect to encounter multiple Select elements from Events
+            unimplemented!()
+        }
+    }
+}
+
 impl ToElementValue for Option<chrono::NaiveDate> {
     fn to_value(self) -> String {
         match self {
@@ -446,8 +474,47 @@ impl ToElementValue for Option<chrono::NaiveDate> {
     }
 }
 
+pub fn identifier_to_string<T: ToString>(identifier: &Option<T>) -> String {
+    identifier
+        .as_ref()
+        .map(|i| i.to_string())
+        .unwrap_or_default()
+}
+
+/// Record a user-entered identifier string, displaying a warning if it is not correctly formatted.
+/// The edited object itself is not updated yet, as the user may later overwrite a new valid value
+/// with an invalid one.
+pub fn check_identifier<T>(value: String, input: &mut String, warning: &mut String) -> bool
+where
+    T: FromStr<Err = ThothError> + ToString,
+{
+    if input.neq_assign(value.trim().to_owned()) {
+        warning.clear();
+        match input.parse::<T>() {
+            // If no identifier was provided, no warning is required.
+            Err(ThothError::IdentifierEmptyError(_)) => {}
+            Err(e) => *warning = e.to_string(),
+            Ok(value) => *input = value.to_string(),
+        }
+        true
+    } else {
+        false
+    }
+}
+
+/// Only update the identifier with the current user-entered string if it is validly
+/// formatted - otherwise keep the database version. If none was provided, clear it.
+pub fn accept_identifier<T: FromStr + PartialEq>(input: &str, identifier: &mut Option<T>) {
+    if input.is_empty() {
+        identifier.neq_assign(None);
+    } else if let Ok(result) = input.parse::<T>() {
+        identifier.neq_assign(Some(result));
+    }
+}
+
 pub mod admin;
 pub mod affiliations_form;
+pub mod alternative_names_form;
 pub mod books;
 pub mod chapters;
 pub mod contributions_form;
@@ -456,6 +523,7 @@ pub mod contributor_select;
 pub mod contributors;
 pub mod dashboard;
 pub mod delete_dialogue;
+pub mod events_form;
 pub mod fundings_form;
 pub mod imprint;
 pub mod imprints;
@@ -477,7 +545,9 @@ pub mod new_series;
 pub mod new_work;
 pub mod notification;
 pub mod prices_form;
+pub mod prizes_form;
 pub mod publication;
+pub mod publication_identifiers_form;
 pub mod publication_modal;
 pub mod publications;
 pub mod publications_form;
@@ -487,10 +557,17 @@ pub mod reference_modal;
 pub mod references_form;
 pub mod related_works_form;
 pub mod root;
+pub mod sales_rights_form;
 pub mod series;
 pub mod serieses;
 pub mod subjects_form;
+pub mod supporting_resources_form;
+pub mod text_contents_form;
+pub mod usage_constraints_form;
 pub mod utils;
 pub mod work;
+pub mod work_abstracts_form;
+pub mod work_identifiers_form;
 pub mod work_status_modal;
+pub mod work_titles_form;
 pub mod works;
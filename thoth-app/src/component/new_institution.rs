@@ -1,4 +1,5 @@
 use std::str::FromStr;
+use thoth_api::model::identifier::{FunderId, Isni, Ringgold, FUNDER_ID_DOMAIN, ISNI_DOMAIN};
 use thoth_api::model::institution::CountryCode;
 use thoth_api::model::institution::Institution;
 use thoth_api::model::{Doi, Ror, DOI_DOMAIN, ROR_DOMAIN};
@@ -31,6 +32,8 @@ use crate::models::institution::CountryCodeValues;
 use crate::models::EditRoute;
 use crate::string::SAVE_BUTTON;
 
+use super::accept_identifier;
+use super::check_identifier;
 use super::ToElementValue;
 
 pub struct NewInstitutionComponent {
@@ -42,6 +45,13 @@ pub struct NewInstitutionComponent {
     // Track the user-entered ROR string, which may not be validly formatted
     ror: String,
     ror_warning: String,
+    // Likewise for the other institution identifiers
+    crossref_funder_id: String,
+    crossref_funder_id_warning: String,
+    isni: String,
+    isni_warning: String,
+    ringgold: String,
+    ringgold_warning: String,
     push_institution: PushCreateInstitution,
     data: InstitutionFormData,
     notification_bus: NotificationDispatcher,
@@ -60,6 +70,9 @@ pub enum Msg {
     ChangeInstitutionName(String),
     ChangeInstitutionDoi(String),
     ChangeRor(String),
+    ChangeCrossrefFunderId(String),
+    ChangeIsni(String),
+    ChangeRinggold(String),
     ChangeCountryCode(String),
 }
 
@@ -87,6 +100,12 @@ impl Component for NewInstitutionComponent {
             institution_doi_warning,
             ror,
             ror_warning,
+            crossref_funder_id: Default::default(),
+            crossref_funder_id_warning: Default::default(),
+            isni: Default::default(),
+            isni_warning: Default::default(),
+            ringgold: Default::default(),
+            ringgold_warning: Default::default(),
             push_institution,
             data,
             notification_bus,
@@ -162,12 +181,21 @@ impl Component for NewInstitutionComponent {
                 } else if let Ok(result) = self.ror.parse::<Ror>() {
                     self.institution.ror.neq_assign(Some(result));
                 }
+                accept_identifier(
+                    &self.crossref_funder_id,
+                    &mut self.institution.crossref_funder_id,
+                );
+                accept_identifier(&self.isni, &mut self.institution.isni);
+                accept_identifier(&self.ringgold, &mut self.institution.ringgold);
                 let body = CreateInstitutionRequestBody {
                     variables: Variables {
                         institution_name: self.institution.institution_name.clone(),
                         institution_doi: self.institution.institution_doi.clone(),
                         ror: self.institution.ror.clone(),
                         country_code: self.institution.country_code,
+                        crossref_funder_id: self.institution.crossref_funder_id.clone(),
+                        isni: self.institution.isni.clone(),
+                        ringgold: self.institution.ringgold.clone(),
                     },
                     ..Default::default()
                 };
@@ -225,6 +253,17 @@ impl Component for NewInstitutionComponent {
                     false
                 }
             }
+            Msg::ChangeCrossrefFunderId(value) => check_identifier::<FunderId>(
+                value,
+                &mut self.crossref_funder_id,
+                &mut self.crossref_funder_id_warning,
+            ),
+            Msg::ChangeIsni(value) => {
+                check_identifier::<Isni>(value, &mut self.isni, &mut self.isni_warning)
+            }
+            Msg::ChangeRinggold(value) => {
+                check_identifier::<Ringgold>(value, &mut self.ringgold, &mut self.ringgold_warning)
+            }
             Msg::ChangeCountryCode(value) => self
                 .institution
                 .country_code
@@ -269,6 +308,26 @@ impl Component for NewInstitutionComponent {
                         tooltip={ self.ror_warning.clone() }
                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRor(e.to_value())) }
                     />
+                    <FormTextInputExtended
+                        label = "Crossref Funder ID"
+                        statictext={ FUNDER_ID_DOMAIN }
+                        value={ self.crossref_funder_id.clone() }
+                        tooltip={ self.crossref_funder_id_warning.clone() }
+                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCrossrefFunderId(e.to_value())) }
+                    />
+                    <FormTextInputExtended
+                        label = "ISNI"
+                        statictext={ ISNI_DOMAIN }
+                        value={ self.isni.clone() }
+                        tooltip={ self.isni_warning.clone() }
+                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIsni(e.to_value())) }
+                    />
+                    <FormTextInputExtended
+                        label = "Ringgold ID"
+                        value={ self.ringgold.clone() }
+                        tooltip={ self.ringgold_warning.clone() }
+                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRinggold(e.to_value())) }
+                    />
                     <FormCountryCodeSelect
                         label = "Country"
                         value={ self.institution.country_code }
@@ -198,6 +198,7 @@ impl Component for NewSeriesComponent {
                         series_description: self.series.series_description.clone(),
                         series_cfp_url: self.series.series_cfp_url.clone(),
                         imprint_id: self.series.imprint_id,
+                        parent_series_id: self.series.parent_series_id,
                     },
                     ..Default::default()
                 };
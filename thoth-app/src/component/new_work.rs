@@ -105,6 +105,7 @@ pub enum Msg {
     ChangePageBreakdown(String),
     ChangeFirstPage(String),
     ChangeLastPage(String),
+    ChangeArticleNumber(String),
     ChangeImageCount(String),
     ChangeTableCount(String),
     ChangeAudioCount(String),
@@ -282,7 +283,7 @@ impl Component for NewWorkComponent {
                 }
                 // Clear any fields which are not applicable to the currently selected work type or work status.
                 // (Do not clear them before the save point as the user may change the type again.)
-                if self.work.work_type == WorkType::BookChapter {
+                if self.work.work_type.is_component() {
                     self.work.edition = None;
                     self.work.toc = None;
                     self.work.lccn = None;
@@ -292,6 +293,9 @@ impl Component for NewWorkComponent {
                     self.work.last_page = None;
                     self.work.page_interval = None;
                 }
+                if self.work.work_type != WorkType::JournalArticle {
+                    self.work.article_number = None;
+                }
                 if self.work.work_status != WorkStatus::Withdrawn
                     && self.work.work_status != WorkStatus::Superseded
                 {
@@ -332,6 +336,7 @@ impl Component for NewWorkComponent {
                         first_page: self.work.first_page.clone(),
                         last_page: self.work.last_page.clone(),
                         page_interval: self.work.page_interval.clone(),
+                        article_number: self.work.article_number.clone(),
                     },
                     ..Default::default()
                 };
@@ -414,6 +419,9 @@ impl Component for NewWorkComponent {
                     false
                 }
             }
+            Msg::ChangeArticleNumber(value) => {
+                self.work.article_number.neq_assign(value.to_opt_string())
+            }
             Msg::ChangeImageCount(value) => self.work.image_count.neq_assign(value.to_opt_int()),
             Msg::ChangeTableCount(value) => self.work.table_count.neq_assign(value.to_opt_int()),
             Msg::ChangeAudioCount(value) => self.work.audio_count.neq_assign(value.to_opt_int()),
@@ -464,7 +472,8 @@ impl Component for NewWorkComponent {
         });
         // Grey out chapter-specific or "book"-specific fields
         // based on currently selected work type.
-        let is_chapter = self.work.work_type == WorkType::BookChapter;
+        let is_component = self.work.work_type.is_component();
+        let is_article = self.work.work_type == WorkType::JournalArticle;
         let is_not_withdrawn_or_superseded = self.work.work_status != WorkStatus::Withdrawn
             && self.work.work_status != WorkStatus::Superseded;
         let is_active_withdrawn_or_superseded = self.work.work_status == WorkStatus::Active
@@ -530,7 +539,7 @@ impl Component for NewWorkComponent {
                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEdition(e.to_value())) }
                         required = true
                         min={ "1".to_string() }
-                        deactivated={ is_chapter }
+                        deactivated={ is_component }
                     />
                     <FormDateInput
                         label = "Publication Date"
@@ -591,13 +600,13 @@ impl Component for NewWorkComponent {
                                 label = "LCCN"
                                 value={ self.work.lccn.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLccn(e.to_value())) }
-                                deactivated={ is_chapter }
+                                deactivated={ is_component }
                             />
                             <FormTextInput
                                 label = "OCLC Number"
                                 value={ self.work.oclc.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeOclc(e.to_value())) }
-                                deactivated={ is_chapter }
+                                deactivated={ is_component }
                             />
                             <FormTextInput
                                 label = "Internal Reference"
@@ -622,13 +631,19 @@ impl Component for NewWorkComponent {
                                 label = "First Page"
                                 value={ self.work.first_page.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFirstPage(e.to_value())) }
-                                deactivated={ !is_chapter }
+                                deactivated={ !is_component }
                             />
                             <FormTextInput
                                 label = "Last Page"
                                 value={ self.work.last_page.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLastPage(e.to_value())) }
-                                deactivated={ !is_chapter }
+                                deactivated={ !is_component }
+                            />
+                            <FormTextInput
+                                label = "Article Number"
+                                value={ self.work.article_number.clone() }
+                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeArticleNumber(e.to_value())) }
+                                deactivated={ !is_article }
                             />
                         </div>
                     </div>
@@ -695,7 +710,7 @@ impl Component for NewWorkComponent {
                         label = "Table of Content"
                         value={ self.work.toc.clone() }
                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeToc(e.to_value())) }
-                        deactivated={ is_chapter }
+                        deactivated={ is_component }
                     />
 
                     <div class="field">
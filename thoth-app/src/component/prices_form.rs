@@ -1,6 +1,9 @@
 use std::str::FromStr;
+use thoth_api::model::institution::CountryCode;
 use thoth_api::model::price::CurrencyCode;
 use thoth_api::model::price::Price;
+use thoth_api::model::price::PriceQualifier;
+use thoth_api::model::price::PriceType;
 use thoth_errors::ThothError;
 use uuid::Uuid;
 use yew::html;
@@ -15,8 +18,16 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::utils::FormCountryCodeMultiSelect;
 use crate::component::utils::FormCurrencyCodeSelect;
+use crate::component::utils::FormDateInput;
 use crate::component::utils::FormFloatInput;
+use crate::component::utils::FormOptionalPriceQualifierSelect;
+use crate::component::utils::FormPriceTypeSelect;
+use crate::component::utils::FormTextInput;
+use crate::models::institution::country_codes_query::FetchActionCountryCodes;
+use crate::models::institution::country_codes_query::FetchCountryCodes;
+use crate::models::institution::CountryCodeValues;
 use crate::models::price::create_price_mutation::CreatePriceRequest;
 use crate::models::price::create_price_mutation::CreatePriceRequestBody;
 use crate::models::price::create_price_mutation::PushActionCreatePrice;
@@ -29,18 +40,32 @@ use crate::models::price::delete_price_mutation::DeletePriceRequestBody;
 use crate::models::price::delete_price_mutation::PushActionDeletePrice;
 use crate::models::price::delete_price_mutation::PushDeletePrice;
 use crate::models::price::delete_price_mutation::Variables as DeleteVariables;
+use crate::models::price::price_qualifiers_query::FetchActionPriceQualifiers;
+use crate::models::price::price_qualifiers_query::FetchPriceQualifiers;
+use crate::models::price::price_types_query::FetchActionPriceTypes;
+use crate::models::price::price_types_query::FetchPriceTypes;
 use crate::models::price::CurrencyCodeValues;
+use crate::models::price::PriceQualifierValues;
+use crate::models::price::PriceTypeValues;
 use crate::string::CANCEL_BUTTON;
 use crate::string::EMPTY_PRICES;
 use crate::string::REMOVE_BUTTON;
 
 use super::ToElementValue;
+use super::ToElementValues;
+use super::ToOption;
 
 pub struct PricesFormComponent {
     data: PricesFormData,
     new_price: Price,
+    // Region codes are entered as space-separated lists and only split on submission
+    regions_included: String,
+    regions_excluded: String,
     show_add_form: bool,
     fetch_currency_codes: FetchCurrencyCodes,
+    fetch_price_types: FetchPriceTypes,
+    fetch_price_qualifiers: FetchPriceQualifiers,
+    fetch_country_codes: FetchCountryCodes,
     push_price: PushCreatePrice,
     delete_price: PushDeletePrice,
     notification_bus: NotificationDispatcher,
@@ -49,18 +74,37 @@ pub struct PricesFormComponent {
 #[derive(Default)]
 struct PricesFormData {
     currency_codes: Vec<CurrencyCodeValues>,
+    price_types: Vec<PriceTypeValues>,
+    price_qualifiers: Vec<PriceQualifierValues>,
+    country_codes: Vec<CountryCodeValues>,
 }
 
 pub enum Msg {
     ToggleAddFormDisplay(bool),
     SetCurrencyCodesFetchState(FetchActionCurrencyCodes),
     GetCurrencyCodes,
+    SetPriceTypesFetchState(FetchActionPriceTypes),
+    GetPriceTypes,
+    SetPriceQualifiersFetchState(FetchActionPriceQualifiers),
+    GetPriceQualifiers,
+    SetCountryCodesFetchState(FetchActionCountryCodes),
+    GetCountryCodes,
     SetPricePushState(PushActionCreatePrice),
     CreatePrice,
     SetPriceDeleteState(PushActionDeletePrice),
     DeletePrice(Uuid),
     ChangeCurrencyCode(CurrencyCode),
     ChangeUnitPrice(String),
+    ChangePriceType(PriceType),
+    ChangePriceQualifier(String),
+    ChangeCountriesIncluded(Vec<String>),
+    ChangeCountriesExcluded(Vec<String>),
+    ChangeRegionsIncluded(String),
+    ChangeRegionsExcluded(String),
+    ChangeTaxRatePercent(String),
+    ChangeDiscountCode(String),
+    ChangePriceEffectiveFrom(String),
+    ChangePriceEffectiveUntil(String),
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -70,6 +114,50 @@ pub struct Props {
     pub update_prices: Callback<Option<Vec<Price>>>,
 }
 
+fn parse_countries(values: Vec<String>) -> Vec<CountryCode> {
+    values
+        .iter()
+        .filter_map(|value| CountryCode::from_str(value).ok())
+        .collect()
+}
+
+fn split_regions(regions: &str) -> Vec<String> {
+    regions.split_whitespace().map(str::to_string).collect()
+}
+
+fn display_territory(p: &Price) -> String {
+    let included: Vec<String> = p
+        .countries_included
+        .iter()
+        .map(|c| c.to_string())
+        .chain(p.regions_included.iter().cloned())
+        .collect();
+    let excluded: Vec<String> = p
+        .countries_excluded
+        .iter()
+        .map(|c| c.to_string())
+        .chain(p.regions_excluded.iter().cloned())
+        .collect();
+    match (included.is_empty(), excluded.is_empty()) {
+        (true, _) => "World".to_string(),
+        (false, true) => included.join(", "),
+        (false, false) => format!(
+            "{} (excluding {})",
+            included.join(", "),
+            excluded.join(", ")
+        ),
+    }
+}
+
+fn display_effective_dates(p: &Price) -> String {
+    match (p.price_effective_from, p.price_effective_until) {
+        (None, None) => String::new(),
+        (Some(from), None) => format!("From {from}"),
+        (None, Some(until)) => format!("Until {until}"),
+        (Some(from), Some(until)) => format!("{from} to {until}"),
+    }
+}
+
 impl Component for PricesFormComponent {
     type Message = Msg;
     type Properties = Props;
@@ -79,17 +167,28 @@ impl Component for PricesFormComponent {
         let show_add_form = false;
         let new_price: Price = Default::default();
         let fetch_currency_codes = Default::default();
+        let fetch_price_types = Default::default();
+        let fetch_price_qualifiers = Default::default();
+        let fetch_country_codes = Default::default();
         let push_price = Default::default();
         let delete_price = Default::default();
         let notification_bus = NotificationBus::dispatcher();
 
         ctx.link().send_message(Msg::GetCurrencyCodes);
+        ctx.link().send_message(Msg::GetPriceTypes);
+        ctx.link().send_message(Msg::GetPriceQualifiers);
+        ctx.link().send_message(Msg::GetCountryCodes);
 
         PricesFormComponent {
             data,
             new_price,
+            regions_included: Default::default(),
+            regions_excluded: Default::default(),
             show_add_form,
             fetch_currency_codes,
+            fetch_price_types,
+            fetch_price_qualifiers,
+            fetch_country_codes,
             push_price,
             delete_price,
             notification_bus,
@@ -121,6 +220,61 @@ impl Component for PricesFormComponent {
                     .send_message(Msg::SetCurrencyCodesFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetPriceTypesFetchState(fetch_state) => {
+                self.fetch_price_types.apply(fetch_state);
+                self.data.price_types = match self.fetch_price_types.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.price_types.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetPriceTypes => {
+                ctx.link()
+                    .send_future(self.fetch_price_types.fetch(Msg::SetPriceTypesFetchState));
+                ctx.link()
+                    .send_message(Msg::SetPriceTypesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetPriceQualifiersFetchState(fetch_state) => {
+                self.fetch_price_qualifiers.apply(fetch_state);
+                self.data.price_qualifiers = match self.fetch_price_qualifiers.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.price_qualifiers.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetPriceQualifiers => {
+                ctx.link().send_future(
+                    self.fetch_price_qualifiers
+                        .fetch(Msg::SetPriceQualifiersFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetPriceQualifiersFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetCountryCodesFetchState(fetch_state) => {
+                self.fetch_country_codes.apply(fetch_state);
+                self.data.country_codes = match self.fetch_country_codes.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.country_codes.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetCountryCodes => {
+                ctx.link().send_future(
+                    self.fetch_country_codes
+                        .fetch(Msg::SetCountryCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetCountryCodesFetchState(FetchAction::Fetching));
+                false
+            }
             Msg::SetPricePushState(fetch_state) => {
                 self.push_price.apply(fetch_state);
                 match self.push_price.as_ref().state() {
@@ -161,6 +315,16 @@ impl Component for PricesFormComponent {
                         publication_id: ctx.props().publication_id,
                         currency_code: self.new_price.currency_code,
                         unit_price: self.new_price.unit_price,
+                        price_type: self.new_price.price_type,
+                        price_qualifier: self.new_price.price_qualifier,
+                        countries_included: self.new_price.countries_included.clone(),
+                        countries_excluded: self.new_price.countries_excluded.clone(),
+                        regions_included: split_regions(&self.regions_included),
+                        regions_excluded: split_regions(&self.regions_excluded),
+                        tax_rate_percent: self.new_price.tax_rate_percent,
+                        discount_code: self.new_price.discount_code.clone(),
+                        price_effective_from: self.new_price.price_effective_from,
+                        price_effective_until: self.new_price.price_effective_until,
                     },
                     ..Default::default()
                 };
@@ -225,6 +389,38 @@ impl Component for PricesFormComponent {
                 let unit_price: f64 = val.parse().unwrap_or(0.00);
                 self.new_price.unit_price.neq_assign(unit_price)
             }
+            Msg::ChangePriceType(price_type) => self.new_price.price_type.neq_assign(price_type),
+            Msg::ChangePriceQualifier(value) => self
+                .new_price
+                .price_qualifier
+                .neq_assign(PriceQualifier::from_str(&value).ok()),
+            Msg::ChangeCountriesIncluded(values) => self
+                .new_price
+                .countries_included
+                .neq_assign(parse_countries(values)),
+            Msg::ChangeCountriesExcluded(values) => self
+                .new_price
+                .countries_excluded
+                .neq_assign(parse_countries(values)),
+            Msg::ChangeRegionsIncluded(value) => self.regions_included.neq_assign(value),
+            Msg::ChangeRegionsExcluded(value) => self.regions_excluded.neq_assign(value),
+            Msg::ChangeTaxRatePercent(value) => {
+                // Zero is a meaningful tax rate, so only an empty input clears it
+                let tax_rate_percent: Option<f64> = value.trim().parse().ok();
+                self.new_price.tax_rate_percent.neq_assign(tax_rate_percent)
+            }
+            Msg::ChangeDiscountCode(value) => self
+                .new_price
+                .discount_code
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangePriceEffectiveFrom(value) => self
+                .new_price
+                .price_effective_from
+                .neq_assign(value.to_opt_date()),
+            Msg::ChangePriceEffectiveUntil(value) => self
+                .new_price
+                .price_effective_until
+                .neq_assign(value.to_opt_date()),
         }
     }
 
@@ -285,6 +481,64 @@ impl Component for PricesFormComponent {
                                     step={ "0.01".to_string() }
                                     min={ "0.01".to_string() }
                                 />
+                                <FormPriceTypeSelect
+                                    label = "Price Type"
+                                    value={ self.new_price.price_type }
+                                    data={ self.data.price_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangePriceType(PriceType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormOptionalPriceQualifierSelect
+                                    label = "Buyer"
+                                    value={ self.new_price.price_qualifier }
+                                    data={ self.data.price_qualifiers.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangePriceQualifier(e.to_value())) }
+                                />
+                                <FormCountryCodeMultiSelect
+                                    label = "Countries Included"
+                                    value={ self.new_price.countries_included.clone() }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeCountriesIncluded(e.to_values())) }
+                                />
+                                <FormTextInput
+                                    label = "Regions Included (e.g. WORLD, ECZ, GB-SCT)"
+                                    value={ self.regions_included.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRegionsIncluded(e.to_value())) }
+                                />
+                                <FormCountryCodeMultiSelect
+                                    label = "Countries Excluded"
+                                    value={ self.new_price.countries_excluded.clone() }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeCountriesExcluded(e.to_values())) }
+                                />
+                                <FormTextInput
+                                    label = "Regions Excluded (e.g. GB-NIR)"
+                                    value={ self.regions_excluded.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRegionsExcluded(e.to_value())) }
+                                />
+                                <FormFloatInput
+                                    label = "Tax Rate (%)"
+                                    value={ self.new_price.tax_rate_percent }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTaxRatePercent(e.to_value())) }
+                                    step={ "0.01".to_string() }
+                                />
+                                <FormTextInput
+                                    label = "Discount Code"
+                                    value={ self.new_price.discount_code.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeDiscountCode(e.to_value())) }
+                                />
+                                <FormDateInput
+                                    label = "Effective From"
+                                    value={ self.new_price.price_effective_from.to_value() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePriceEffectiveFrom(e.to_value())) }
+                                />
+                                <FormDateInput
+                                    label = "Effective Until"
+                                    value={ self.new_price.price_effective_until.to_value() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePriceEffectiveUntil(e.to_value())) }
+                                />
                             </form>
                         </section>
                         <footer class="modal-card-foot">
@@ -350,6 +604,32 @@ impl PricesFormComponent {
                         </div>
                     </div>
 
+                    <div class="field">
+                        <label class="label">{ "Type" }</label>
+                        <div class="control is-expanded">
+                            {&p.price_type}
+                            {
+                                p.price_qualifier
+                                    .map(|q| format!(" ({q})"))
+                                    .unwrap_or_default()
+                            }
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Territory" }</label>
+                        <div class="control is-expanded">
+                            {display_territory(p)}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Effective" }</label>
+                        <div class="control is-expanded">
+                            {display_effective_dates(p)}
+                        </div>
+                    </div>
+
                     <div class="field">
                         <label class="label"></label>
                         <div class="control is-expanded">
@@ -0,0 +1,431 @@
+use std::str::FromStr;
+use thoth_api::model::institution::CountryCode;
+use thoth_api::model::prize::Prize;
+use thoth_api::model::prize::PrizeCode;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormCountryCodeSelect;
+use crate::component::utils::FormNumberInput;
+use crate::component::utils::FormPrizeCodeSelect;
+use crate::component::utils::FormTextInput;
+use crate::component::utils::FormTextarea;
+use crate::models::institution::CountryCodeValues;
+use crate::models::prize::create_prize_mutation::CreatePrizeRequest;
+use crate::models::prize::create_prize_mutation::CreatePrizeRequestBody;
+use crate::models::prize::create_prize_mutation::PushActionCreatePrize;
+use crate::models::prize::create_prize_mutation::PushCreatePrize;
+use crate::models::prize::create_prize_mutation::Variables;
+use crate::models::prize::delete_prize_mutation::DeletePrizeRequest;
+use crate::models::prize::delete_prize_mutation::DeletePrizeRequestBody;
+use crate::models::prize::delete_prize_mutation::PushActionDeletePrize;
+use crate::models::prize::delete_prize_mutation::PushDeletePrize;
+use crate::models::prize::delete_prize_mutation::Variables as DeleteVariables;
+use crate::models::prize::prize_values_query::FetchActionPrizeValues;
+use crate::models::prize::prize_values_query::FetchPrizeValues;
+use crate::models::prize::PrizeCodeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_PRIZES;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct PrizesFormComponent {
+    data: PrizesFormData,
+    new_prize: Prize,
+    show_add_form: bool,
+    fetch_prize_values: FetchPrizeValues,
+    push_prize: PushCreatePrize,
+    delete_prize: PushDeletePrize,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct PrizesFormData {
+    prize_codes: Vec<PrizeCodeValues>,
+    country_codes: Vec<CountryCodeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetPrizeValuesFetchState(FetchActionPrizeValues),
+    GetPrizeValues,
+    SetPrizePushState(PushActionCreatePrize),
+    CreatePrize,
+    SetPrizeDeleteState(PushActionDeletePrize),
+    DeletePrize(Uuid),
+    ChangePrizeName(String),
+    ChangePrizeYear(String),
+    ChangePrizeCountry(String),
+    ChangePrizeCode(PrizeCode),
+    ChangePrizeStatement(String),
+    ChangePrizeJury(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub prizes: Option<Vec<Prize>>,
+    pub work_id: Uuid,
+    pub update_prizes: Callback<Option<Vec<Prize>>>,
+}
+
+impl Component for PrizesFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: PrizesFormData = Default::default();
+        let show_add_form = false;
+        let new_prize: Prize = Default::default();
+        let fetch_prize_values = Default::default();
+        let push_prize = Default::default();
+        let delete_prize = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetPrizeValues);
+
+        PrizesFormComponent {
+            data,
+            new_prize,
+            show_add_form,
+            fetch_prize_values,
+            push_prize,
+            delete_prize,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetPrizeValuesFetchState(fetch_state) => {
+                self.fetch_prize_values.apply(fetch_state);
+                match self.fetch_prize_values.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        self.data.prize_codes = body.data.prize_codes.enum_values.clone();
+                        self.data.country_codes = body.data.country_codes.enum_values.clone();
+                    }
+                    _ => {
+                        self.data.prize_codes = vec![];
+                        self.data.country_codes = vec![];
+                    }
+                }
+                true
+            }
+            Msg::GetPrizeValues => {
+                ctx.link()
+                    .send_future(self.fetch_prize_values.fetch(Msg::SetPrizeValuesFetchState));
+                ctx.link()
+                    .send_message(Msg::SetPrizeValuesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetPrizePushState(fetch_state) => {
+                self.push_prize.apply(fetch_state);
+                match self.push_prize.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_prize {
+                        Some(p) => {
+                            let prize = p.clone();
+                            let mut prizes: Vec<Prize> =
+                                ctx.props().prizes.clone().unwrap_or_default();
+                            prizes.push(prize);
+                            ctx.props().update_prizes.emit(Some(prizes));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreatePrize => {
+                let body = CreatePrizeRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        prize_name: self.new_prize.prize_name.clone(),
+                        prize_year: self.new_prize.prize_year,
+                        prize_country: self.new_prize.prize_country,
+                        prize_code: self.new_prize.prize_code,
+                        prize_statement: self.new_prize.prize_statement.clone(),
+                        prize_jury: self.new_prize.prize_jury.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = CreatePrizeRequest { body };
+                self.push_prize = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_prize.fetch(Msg::SetPrizePushState));
+                ctx.link()
+                    .send_message(Msg::SetPrizePushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetPrizeDeleteState(fetch_state) => {
+                self.delete_prize.apply(fetch_state);
+                match self.delete_prize.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_prize {
+                        Some(prize) => {
+                            let to_keep: Vec<Prize> = ctx
+                                .props()
+                                .prizes
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|p| p.prize_id != prize.prize_id)
+                                .collect();
+                            ctx.props().update_prizes.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeletePrize(prize_id) => {
+                let body = DeletePrizeRequestBody {
+                    variables: DeleteVariables { prize_id },
+                    ..Default::default()
+                };
+                let request = DeletePrizeRequest { body };
+                self.delete_prize = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_prize.fetch(Msg::SetPrizeDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetPrizeDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangePrizeName(value) => self
+                .new_prize
+                .prize_name
+                .neq_assign(value.trim().to_owned()),
+            Msg::ChangePrizeYear(value) => self.new_prize.prize_year.neq_assign(value.to_opt_int()),
+            Msg::ChangePrizeCountry(value) => self
+                .new_prize
+                .prize_country
+                .neq_assign(CountryCode::from_str(&value).ok()),
+            Msg::ChangePrizeCode(prize_code) => self.new_prize.prize_code.neq_assign(prize_code),
+            Msg::ChangePrizeStatement(value) => self
+                .new_prize
+                .prize_statement
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangePrizeJury(value) => {
+                self.new_prize.prize_jury.neq_assign(value.to_opt_string())
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let prizes = ctx.props().prizes.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Prizes" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Prize" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Prize" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="prizes-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreatePrize
+                            }) }
+                            >
+                                <FormTextInput
+                                    label = "Prize Name"
+                                    value={ self.new_prize.prize_name.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePrizeName(e.to_value())) }
+                                    required = true
+                                />
+                                <FormPrizeCodeSelect
+                                    label = "Achievement"
+                                    value={ self.new_prize.prize_code }
+                                    data={ self.data.prize_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangePrizeCode(PrizeCode::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormNumberInput
+                                    label = "Year"
+                                    value={ self.new_prize.prize_year }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePrizeYear(e.to_value())) }
+                                    min={ "1000".to_string() }
+                                />
+                                <FormCountryCodeSelect
+                                    label = "Country"
+                                    value={ self.new_prize.prize_country }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangePrizeCountry(e.to_value())) }
+                                />
+                                <FormTextarea
+                                    label = "Jury Statement"
+                                    value={ self.new_prize.prize_statement.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePrizeStatement(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Jury Members"
+                                    value={ self.new_prize.prize_jury.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePrizeJury(e.to_value())) }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="prizes-form"
+                            >
+                                { "Add Prize" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !prizes.is_empty() {
+                        html!{{for prizes.iter().map(|p| self.render_prize(ctx, p))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_PRIZES }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl PrizesFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_prize(&self, ctx: &Context<Self>, p: &Prize) -> Html {
+        let prize_id = p.prize_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-award" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field">
+                        <label class="label">{ "Prize Name" }</label>
+                        <div class="control is-expanded">
+                            {&p.prize_name}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Achievement" }</label>
+                        <div class="control is-expanded">
+                            {&p.prize_code}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 6em;">
+                        <label class="label">{ "Year" }</label>
+                        <div class="control is-expanded">
+                            {p.prize_year.map(|y| y.to_string()).unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Country" }</label>
+                        <div class="control is-expanded">
+                            {p.prize_country.map(|c| c.to_string()).unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeletePrize(prize_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -3,7 +3,9 @@ use thoth_api::model::price::Price;
 use thoth_api::model::publication::Publication;
 use thoth_api::model::publication::PublicationProperties;
 use thoth_api::model::publication::PublicationWithRelations;
-use thoth_api::model::work::WorkType;
+use thoth_api::model::publication_identifier::PublicationIdentifier;
+use thoth_api::model::sales_rights::SalesRights;
+use thoth_api::model::usage_constraint::UsageConstraint;
 use thoth_errors::ThothError;
 use uuid::Uuid;
 use yew::html;
@@ -23,7 +25,10 @@ use crate::agent::notification_bus::Request;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
 use crate::component::locations_form::LocationsFormComponent;
 use crate::component::prices_form::PricesFormComponent;
+use crate::component::publication_identifiers_form::PublicationIdentifiersFormComponent;
 use crate::component::publication_modal::PublicationModalComponent;
+use crate::component::sales_rights_form::SalesRightsFormComponent;
+use crate::component::usage_constraints_form::UsageConstraintsFormComponent;
 use crate::component::utils::Loader;
 use crate::models::publication::delete_publication_mutation::DeletePublicationRequest;
 use crate::models::publication::delete_publication_mutation::DeletePublicationRequestBody;
@@ -59,6 +64,9 @@ pub enum Msg {
     DeletePublication,
     UpdateLocations,
     UpdatePrices(Option<Vec<Price>>),
+    UpdateIdentifiers(Option<Vec<PublicationIdentifier>>),
+    UpdateSalesRights(Option<Vec<SalesRights>>),
+    UpdateUsageConstraints(Option<Vec<UsageConstraint>>),
 }
 
 #[derive(PartialEq, Eq, Properties)]
@@ -115,6 +123,24 @@ impl Component for PublicationComponent {
                         depth_in: self.publication.depth_in,
                         weight_g: self.publication.weight_g,
                         weight_oz: self.publication.weight_oz,
+                        accessibility_features: self.publication.accessibility_features.clone(),
+                        accessibility_hazards: self.publication.accessibility_hazards.clone(),
+                        accessibility_standard: self.publication.accessibility_standard,
+                        wcag_version: self.publication.wcag_version,
+                        wcag_level: self.publication.wcag_level,
+                        accessibility_certifier: self.publication.accessibility_certifier.clone(),
+                        accessibility_summary: self.publication.accessibility_summary.clone(),
+                        binding_style: self.publication.binding_style,
+                        paper_type: self.publication.paper_type,
+                        interior_colour: self.publication.interior_colour,
+                        trim_category: self.publication.trim_category,
+                        page_count: self.publication.page_count,
+                        cover_finish: self.publication.cover_finish,
+                        carton_quantity: self.publication.carton_quantity,
+                        format_version: self.publication.format_version.clone(),
+                        file_size_kb: self.publication.file_size_kb,
+                        technical_protections: self.publication.technical_protections.clone(),
+                        layout: self.publication.layout,
                     }),
                     false => None,
                 };
@@ -152,6 +178,24 @@ impl Component for PublicationComponent {
                     self.publication.depth_in = p.depth_in;
                     self.publication.weight_g = p.weight_g;
                     self.publication.weight_oz = p.weight_oz;
+                    self.publication.accessibility_features = p.accessibility_features;
+                    self.publication.accessibility_hazards = p.accessibility_hazards;
+                    self.publication.accessibility_standard = p.accessibility_standard;
+                    self.publication.wcag_version = p.wcag_version;
+                    self.publication.wcag_level = p.wcag_level;
+                    self.publication.accessibility_certifier = p.accessibility_certifier;
+                    self.publication.accessibility_summary = p.accessibility_summary;
+                    self.publication.binding_style = p.binding_style;
+                    self.publication.paper_type = p.paper_type;
+                    self.publication.interior_colour = p.interior_colour;
+                    self.publication.trim_category = p.trim_category;
+                    self.publication.page_count = p.page_count;
+                    self.publication.cover_finish = p.cover_finish;
+                    self.publication.carton_quantity = p.carton_quantity;
+                    self.publication.format_version = p.format_version;
+                    self.publication.file_size_kb = p.file_size_kb;
+                    self.publication.technical_protections = p.technical_protections;
+                    self.publication.layout = p.layout;
                 } else {
                     // This should not be possible: the updated publication returned from the
                     // database does not match the locally-stored publication data.
@@ -271,6 +315,16 @@ impl Component for PublicationComponent {
                 true
             }
             Msg::UpdatePrices(prices) => self.publication.prices.neq_assign(prices),
+            Msg::UpdateIdentifiers(identifiers) => {
+                self.publication.identifiers.neq_assign(identifiers)
+            }
+            Msg::UpdateSalesRights(sales_rights) => {
+                self.publication.sales_rights.neq_assign(sales_rights)
+            }
+            Msg::UpdateUsageConstraints(usage_constraints) => self
+                .publication
+                .usage_constraints
+                .neq_assign(usage_constraints),
         }
     }
 
@@ -337,8 +391,8 @@ impl Component for PublicationComponent {
                         </form>
 
                         {
-                            // Dimensions are only applicable to physical (Paperback/Hardback) non-Chapter publications.
-                            if self.publication.is_physical() && self.publication.work.work_type != WorkType::BookChapter {
+                            // Dimensions and print attributes are only applicable to physical (Paperback/Hardback) publications of whole works.
+                            if self.publication.is_physical() && !self.publication.work.work_type.is_component() {
                                 html! {
                                     <>
                                         <div class="field is-horizontal">
@@ -400,6 +454,59 @@ impl Component for PublicationComponent {
                                                 </div>
                                             </div>
                                         </div>
+
+                                        <div class="field is-horizontal">
+                                            <div class="field" style="width: 12em;">
+                                                <label class="label">{ "Binding" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.binding_style.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 12em;">
+                                                <label class="label">{ "Trim" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.trim_category.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 12em;">
+                                                <label class="label">{ "Cover Finish" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.cover_finish.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+                                        </div>
+
+                                        <div class="field is-horizontal">
+                                            <div class="field" style="width: 12em;">
+                                                <label class="label">{ "Paper" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.paper_type.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 12em;">
+                                                <label class="label">{ "Interior Colour" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.interior_colour.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "Page Count" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.page_count.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "Carton Quantity" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.carton_quantity.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+                                        </div>
                                     </>
                                 }
                             } else {
@@ -407,6 +514,109 @@ impl Component for PublicationComponent {
                             }
                         }
 
+                        {
+                            // File attributes are only applicable to digital publications.
+                            if self.publication.has_digital_attributes() {
+                                html! {
+                                    <form>
+                                        <div class="field is-horizontal">
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "Format Version" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.format_version.clone().unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "File Size (KB)" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.file_size_kb.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "Layout" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.layout.as_ref().map(|l| l.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field">
+                                                <label class="label">{ "Technical Protection" }</label>
+                                                <div class="control is-expanded">
+                                                    {self.publication.technical_protections.iter().map(|t| t.to_string()).collect::<Vec<String>>().join(", ")}
+                                                </div>
+                                            </div>
+                                        </div>
+                                    </form>
+                                }
+                            } else {
+                                html!{}
+                            }
+                        }
+
+                        {
+                            // Accessibility metadata is only applicable to digital publications.
+                            if self.publication.has_accessibility() {
+                                html! {
+                                    <form>
+                                        <div class="field">
+                                            <label class="label">{ "Accessibility Features" }</label>
+                                            <div class="control is-expanded">
+                                                {self.publication.accessibility_features.iter().map(|f| f.to_string()).collect::<Vec<String>>().join(", ")}
+                                            </div>
+                                        </div>
+
+                                        <div class="field">
+                                            <label class="label">{ "Accessibility Hazards" }</label>
+                                            <div class="control is-expanded">
+                                                {self.publication.accessibility_hazards.iter().map(|h| h.to_string()).collect::<Vec<String>>().join(", ")}
+                                            </div>
+                                        </div>
+
+                                        <div class="field is-horizontal">
+                                            <div class="field" style="width: 16em;">
+                                                <label class="label">{ "Conformance" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.accessibility_standard.as_ref().map(|s| s.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "WCAG Version" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.wcag_version.as_ref().map(|v| v.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+
+                                            <div class="field" style="width: 8em;">
+                                                <label class="label">{ "WCAG Level" }</label>
+                                                <div class="control is-expanded">
+                                                    {&self.publication.wcag_level.as_ref().map(|l| l.to_string()).unwrap_or_default()}
+                                                </div>
+                                            </div>
+                                        </div>
+
+                                        <div class="field">
+                                            <label class="label">{ "Accessibility Certifier" }</label>
+                                            <div class="control is-expanded">
+                                                {&self.publication.accessibility_certifier.clone().unwrap_or_default()}
+                                            </div>
+                                        </div>
+
+                                        <div class="field">
+                                            <label class="label">{ "Accessibility Summary" }</label>
+                                            <div class="control is-expanded">
+                                                {&self.publication.accessibility_summary.clone().unwrap_or_default()}
+                                            </div>
+                                        </div>
+                                    </form>
+                                }
+                            } else {
+                                html!{}
+                            }
+                        }
+
                         <hr/>
 
                         <article class="message is-info">
@@ -427,6 +637,33 @@ impl Component for PublicationComponent {
                             publication_id={ self.publication.publication_id }
                             update_prices={ ctx.link().callback(Msg::UpdatePrices) }
                         />
+
+                        <PublicationIdentifiersFormComponent
+                            identifiers={ self.publication.identifiers.clone() }
+                            publication_id={ self.publication.publication_id }
+                            update_identifiers={ ctx.link().callback(Msg::UpdateIdentifiers) }
+                        />
+
+                        <SalesRightsFormComponent
+                            sales_rights={ self.publication.sales_rights.clone() }
+                            publication_id={ self.publication.publication_id }
+                            update_sales_rights={ ctx.link().callback(Msg::UpdateSalesRights) }
+                        />
+
+                        {
+                            // Usage constraints are only applicable to digital publications.
+                            if self.publication.is_digital() {
+                                html! {
+                                    <UsageConstraintsFormComponent
+                                        usage_constraints={ self.publication.usage_constraints.clone() }
+                                        publication_id={ self.publication.publication_id }
+                                        update_usage_constraints={ ctx.link().callback(Msg::UpdateUsageConstraints) }
+                                    />
+                                }
+                            } else {
+                                html!{}
+                            }
+                        }
                     </>
                 }
             }
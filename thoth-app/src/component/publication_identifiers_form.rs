@@ -0,0 +1,382 @@
+use std::str::FromStr;
+use thoth_api::model::identifier::IdentifierType;
+use thoth_api::model::publication_identifier::PublicationIdentifier;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormIdentifierTypeSelect;
+use crate::component::utils::FormTextInput;
+use crate::models::identifier::identifier_types_query::FetchActionIdentifierTypes;
+use crate::models::identifier::identifier_types_query::FetchIdentifierTypes;
+use crate::models::identifier::IdentifierTypeValues;
+use crate::models::publication_identifier::create_publication_identifier_mutation::CreatePublicationIdentifierRequest;
+use crate::models::publication_identifier::create_publication_identifier_mutation::CreatePublicationIdentifierRequestBody;
+use crate::models::publication_identifier::create_publication_identifier_mutation::PushActionCreatePublicationIdentifier;
+use crate::models::publication_identifier::create_publication_identifier_mutation::PushCreatePublicationIdentifier;
+use crate::models::publication_identifier::create_publication_identifier_mutation::Variables;
+use crate::models::publication_identifier::delete_publication_identifier_mutation::DeletePublicationIdentifierRequest;
+use crate::models::publication_identifier::delete_publication_identifier_mutation::DeletePublicationIdentifierRequestBody;
+use crate::models::publication_identifier::delete_publication_identifier_mutation::PushActionDeletePublicationIdentifier;
+use crate::models::publication_identifier::delete_publication_identifier_mutation::PushDeletePublicationIdentifier;
+use crate::models::publication_identifier::delete_publication_identifier_mutation::Variables as DeleteVariables;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_PUBLICATION_IDENTIFIERS;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+
+pub struct PublicationIdentifiersFormComponent {
+    data: PublicationIdentifiersFormData,
+    new_identifier: PublicationIdentifier,
+    show_add_form: bool,
+    fetch_identifier_types: FetchIdentifierTypes,
+    push_identifier: PushCreatePublicationIdentifier,
+    delete_identifier: PushDeletePublicationIdentifier,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct PublicationIdentifiersFormData {
+    identifier_types: Vec<IdentifierTypeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetIdentifierTypesFetchState(FetchActionIdentifierTypes),
+    GetIdentifierTypes,
+    SetIdentifierPushState(PushActionCreatePublicationIdentifier),
+    CreateIdentifier,
+    SetIdentifierDeleteState(PushActionDeletePublicationIdentifier),
+    DeleteIdentifier(Uuid),
+    ChangeIdentifierType(IdentifierType),
+    ChangeIdentifierValue(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub identifiers: Option<Vec<PublicationIdentifier>>,
+    pub publication_id: Uuid,
+    pub update_identifiers: Callback<Option<Vec<PublicationIdentifier>>>,
+}
+
+impl Component for PublicationIdentifiersFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: PublicationIdentifiersFormData = Default::default();
+        let show_add_form = false;
+        let new_identifier: PublicationIdentifier = Default::default();
+        let fetch_identifier_types = Default::default();
+        let push_identifier = Default::default();
+        let delete_identifier = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetIdentifierTypes);
+
+        PublicationIdentifiersFormComponent {
+            data,
+            new_identifier,
+            show_add_form,
+            fetch_identifier_types,
+            push_identifier,
+            delete_identifier,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetIdentifierTypesFetchState(fetch_state) => {
+                self.fetch_identifier_types.apply(fetch_state);
+                self.data.identifier_types = match self.fetch_identifier_types.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    // Only offer the identifier types that can be assigned to a publication
+                    FetchState::Fetched(body) => body
+                        .data
+                        .identifier_types
+                        .enum_values
+                        .iter()
+                        .filter(|i| i.name.applies_to_publications())
+                        .cloned()
+                        .collect(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetIdentifierTypes => {
+                ctx.link().send_future(
+                    self.fetch_identifier_types
+                        .fetch(Msg::SetIdentifierTypesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetIdentifierTypesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetIdentifierPushState(fetch_state) => {
+                self.push_identifier.apply(fetch_state);
+                match self.push_identifier.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_publication_identifier {
+                        Some(i) => {
+                            let identifier = i.clone();
+                            let mut identifiers: Vec<PublicationIdentifier> =
+                                ctx.props().identifiers.clone().unwrap_or_default();
+                            identifiers.push(identifier);
+                            ctx.props().update_identifiers.emit(Some(identifiers));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateIdentifier => {
+                let body = CreatePublicationIdentifierRequestBody {
+                    variables: Variables {
+                        publication_id: ctx.props().publication_id,
+                        identifier_type: self.new_identifier.identifier_type,
+                        identifier_value: self.new_identifier.identifier_value.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = CreatePublicationIdentifierRequest { body };
+                self.push_identifier = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_identifier.fetch(Msg::SetIdentifierPushState));
+                ctx.link()
+                    .send_message(Msg::SetIdentifierPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetIdentifierDeleteState(fetch_state) => {
+                self.delete_identifier.apply(fetch_state);
+                match self.delete_identifier.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_publication_identifier {
+                        Some(identifier) => {
+                            let to_keep: Vec<PublicationIdentifier> = ctx
+                                .props()
+                                .identifiers
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|i| {
+                                    i.publication_identifier_id
+                                        != identifier.publication_identifier_id
+                                })
+                                .collect();
+                            ctx.props().update_identifiers.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteIdentifier(publication_identifier_id) => {
+                let body = DeletePublicationIdentifierRequestBody {
+                    variables: DeleteVariables {
+                        publication_identifier_id,
+                    },
+                    ..Default::default()
+                };
+                let request = DeletePublicationIdentifierRequest { body };
+                self.delete_identifier = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_identifier.fetch(Msg::SetIdentifierDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetIdentifierDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeIdentifierType(identifier_type) => self
+                .new_identifier
+                .identifier_type
+                .neq_assign(identifier_type),
+            Msg::ChangeIdentifierValue(value) => self
+                .new_identifier
+                .identifier_value
+                .neq_assign(value.trim().to_owned()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let identifiers = ctx.props().identifiers.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Identifiers" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Identifier" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Identifier" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="publication-identifiers-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateIdentifier
+                            }) }
+                            >
+                                <FormIdentifierTypeSelect
+                                    label = "Identifier Type"
+                                    value={ self.new_identifier.identifier_type }
+                                    data={ self.data.identifier_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeIdentifierType(IdentifierType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Identifier"
+                                    value={ self.new_identifier.identifier_value.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIdentifierValue(e.to_value())) }
+                                    required = true
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="publication-identifiers-form"
+                            >
+                                { "Add Identifier" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !identifiers.is_empty() {
+                        html!{{for identifiers.iter().map(|i| self.render_identifier(ctx, i))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_PUBLICATION_IDENTIFIERS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl PublicationIdentifiersFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_identifier(&self, ctx: &Context<Self>, i: &PublicationIdentifier) -> Html {
+        let publication_identifier_id = i.publication_identifier_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-fingerprint" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Identifier Type" }</label>
+                        <div class="control is-expanded">
+                            {&i.identifier_type}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Identifier" }</label>
+                        <div class="control is-expanded">
+                            {&i.identifier_value}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteIdentifier(publication_identifier_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,7 +1,16 @@
 use std::str::FromStr;
+use thoth_api::model::publication::AccessibilityStandard;
+use thoth_api::model::publication::BindingStyle;
+use thoth_api::model::publication::CoverFinish;
+use thoth_api::model::publication::DigitalLayout;
+use thoth_api::model::publication::InteriorColour;
+use thoth_api::model::publication::PaperType;
 use thoth_api::model::publication::Publication;
 use thoth_api::model::publication::PublicationProperties;
 use thoth_api::model::publication::PublicationType;
+use thoth_api::model::publication::TrimCategory;
+use thoth_api::model::publication::WcagLevel;
+use thoth_api::model::publication::WcagVersion;
 use thoth_api::model::work::WorkType;
 use thoth_api::model::{Convert, Isbn, LengthUnit, WeightUnit};
 use thoth_errors::ThothError;
@@ -18,14 +27,35 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::utils::FormAccessibilityFeatureMultiSelect;
+use crate::component::utils::FormAccessibilityHazardMultiSelect;
 use crate::component::utils::FormFloatInput;
+use crate::component::utils::FormNumberInput;
+use crate::component::utils::FormOptionalAccessibilityStandardSelect;
+use crate::component::utils::FormOptionalBindingStyleSelect;
+use crate::component::utils::FormOptionalCoverFinishSelect;
+use crate::component::utils::FormOptionalDigitalLayoutSelect;
+use crate::component::utils::FormOptionalInteriorColourSelect;
+use crate::component::utils::FormOptionalPaperTypeSelect;
+use crate::component::utils::FormOptionalTrimCategorySelect;
+use crate::component::utils::FormOptionalWcagLevelSelect;
+use crate::component::utils::FormOptionalWcagVersionSelect;
 use crate::component::utils::FormPublicationTypeSelect;
+use crate::component::utils::FormTechnicalProtectionMultiSelect;
+use crate::component::utils::FormTextInput;
 use crate::component::utils::FormTextInputExtended;
+use crate::component::utils::FormTextarea;
+use crate::models::publication::accessibility_values_query::FetchAccessibilityValues;
+use crate::models::publication::accessibility_values_query::FetchActionAccessibilityValues;
 use crate::models::publication::create_publication_mutation::CreatePublicationRequest;
 use crate::models::publication::create_publication_mutation::CreatePublicationRequestBody;
 use crate::models::publication::create_publication_mutation::PushActionCreatePublication;
 use crate::models::publication::create_publication_mutation::PushCreatePublication;
 use crate::models::publication::create_publication_mutation::Variables;
+use crate::models::publication::digital_attribute_values_query::FetchActionDigitalAttributeValues;
+use crate::models::publication::digital_attribute_values_query::FetchDigitalAttributeValues;
+use crate::models::publication::print_attribute_values_query::FetchActionPrintAttributeValues;
+use crate::models::publication::print_attribute_values_query::FetchPrintAttributeValues;
 use crate::models::publication::publication_types_query::FetchActionPublicationTypes;
 use crate::models::publication::publication_types_query::FetchPublicationTypes;
 use crate::models::publication::update_publication_mutation::PushActionUpdatePublication;
@@ -33,10 +63,23 @@ use crate::models::publication::update_publication_mutation::PushUpdatePublicati
 use crate::models::publication::update_publication_mutation::UpdatePublicationRequest;
 use crate::models::publication::update_publication_mutation::UpdatePublicationRequestBody;
 use crate::models::publication::update_publication_mutation::Variables as UpdateVariables;
+use crate::models::publication::AccessibilityFeatureValues;
+use crate::models::publication::AccessibilityHazardValues;
+use crate::models::publication::AccessibilityStandardValues;
+use crate::models::publication::BindingStyleValues;
+use crate::models::publication::CoverFinishValues;
+use crate::models::publication::DigitalLayoutValues;
+use crate::models::publication::InteriorColourValues;
+use crate::models::publication::PaperTypeValues;
 use crate::models::publication::PublicationTypeValues;
+use crate::models::publication::TechnicalProtectionValues;
+use crate::models::publication::TrimCategoryValues;
+use crate::models::publication::WcagLevelValues;
+use crate::models::publication::WcagVersionValues;
 use crate::string::CANCEL_BUTTON;
 
 use super::ToElementValue;
+use super::ToElementValues;
 use super::ToOption;
 
 pub struct PublicationModalComponent {
@@ -48,6 +91,9 @@ pub struct PublicationModalComponent {
     in_edit_mode: bool,
     convert_dimensions: bool,
     fetch_publication_types: FetchPublicationTypes,
+    fetch_accessibility_values: FetchAccessibilityValues,
+    fetch_print_attribute_values: FetchPrintAttributeValues,
+    fetch_digital_attribute_values: FetchDigitalAttributeValues,
     create_publication: PushCreatePublication,
     update_publication: PushUpdatePublication,
     notification_bus: NotificationDispatcher,
@@ -58,6 +104,18 @@ pub struct PublicationModalComponent {
 #[derive(Default)]
 struct PublicationModalData {
     publication_types: Vec<PublicationTypeValues>,
+    accessibility_features: Vec<AccessibilityFeatureValues>,
+    accessibility_hazards: Vec<AccessibilityHazardValues>,
+    accessibility_standards: Vec<AccessibilityStandardValues>,
+    wcag_versions: Vec<WcagVersionValues>,
+    wcag_levels: Vec<WcagLevelValues>,
+    binding_styles: Vec<BindingStyleValues>,
+    paper_types: Vec<PaperTypeValues>,
+    interior_colours: Vec<InteriorColourValues>,
+    trim_categories: Vec<TrimCategoryValues>,
+    cover_finishes: Vec<CoverFinishValues>,
+    technical_protections: Vec<TechnicalProtectionValues>,
+    layouts: Vec<DigitalLayoutValues>,
 }
 
 pub enum Msg {
@@ -66,6 +124,12 @@ pub enum Msg {
     ToggleDimensionConversion,
     SetPublicationTypesFetchState(FetchActionPublicationTypes),
     GetPublicationTypes,
+    SetAccessibilityValuesFetchState(FetchActionAccessibilityValues),
+    GetAccessibilityValues,
+    SetPrintAttributeValuesFetchState(FetchActionPrintAttributeValues),
+    GetPrintAttributeValues,
+    SetDigitalAttributeValuesFetchState(FetchActionDigitalAttributeValues),
+    GetDigitalAttributeValues,
     SetPublicationCreateState(PushActionCreatePublication),
     CreatePublication,
     SetPublicationUpdateState(PushActionUpdatePublication),
@@ -80,6 +144,24 @@ pub enum Msg {
     ChangeDepthIn(String),
     ChangeWeightG(String),
     ChangeWeightOz(String),
+    ChangeAccessibilityFeatures(Vec<String>),
+    ChangeAccessibilityHazards(Vec<String>),
+    ChangeAccessibilityStandard(String),
+    ChangeWcagVersion(String),
+    ChangeWcagLevel(String),
+    ChangeAccessibilityCertifier(String),
+    ChangeAccessibilitySummary(String),
+    ChangeBindingStyle(String),
+    ChangePaperType(String),
+    ChangeInteriorColour(String),
+    ChangeTrimCategory(String),
+    ChangePageCount(String),
+    ChangeCoverFinish(String),
+    ChangeCartonQuantity(String),
+    ChangeFormatVersion(String),
+    ChangeFileSizeKb(String),
+    ChangeTechnicalProtections(Vec<String>),
+    ChangeLayout(String),
 }
 
 #[derive(Clone, Properties, PartialEq)]
@@ -110,6 +192,9 @@ impl Component for PublicationModalComponent {
         let show_modal_form = ctx.props().show_modal_form;
 
         ctx.link().send_message(Msg::GetPublicationTypes);
+        ctx.link().send_message(Msg::GetAccessibilityValues);
+        ctx.link().send_message(Msg::GetPrintAttributeValues);
+        ctx.link().send_message(Msg::GetDigitalAttributeValues);
 
         PublicationModalComponent {
             data,
@@ -119,6 +204,9 @@ impl Component for PublicationModalComponent {
             in_edit_mode,
             convert_dimensions,
             fetch_publication_types: Default::default(),
+            fetch_accessibility_values: Default::default(),
+            fetch_print_attribute_values: Default::default(),
+            fetch_digital_attribute_values: Default::default(),
             create_publication,
             update_publication,
             notification_bus,
@@ -143,7 +231,7 @@ impl Component for PublicationModalComponent {
                     } else {
                         // Previous values will be retained if creating new publication, which may be
                         // useful for batch-adding, but this is less likely for ISBNs (and they also
-                        // cannot be added for publications whose work type is Book Chapter).
+                        // cannot be added for publications of chapters or articles).
                         self.publication.isbn = None;
                     }
                     // Ensure ISBN variable value is kept in sync with publication object.
@@ -182,6 +270,79 @@ impl Component for PublicationModalComponent {
                     .send_message(Msg::SetPublicationTypesFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetAccessibilityValuesFetchState(fetch_state) => {
+                self.fetch_accessibility_values.apply(fetch_state);
+                if let FetchState::Fetched(body) = self.fetch_accessibility_values.as_ref().state()
+                {
+                    let values = &body.data;
+                    self.data.accessibility_features =
+                        values.accessibility_features.enum_values.clone();
+                    self.data.accessibility_hazards =
+                        values.accessibility_hazards.enum_values.clone();
+                    self.data.accessibility_standards =
+                        values.accessibility_standards.enum_values.clone();
+                    self.data.wcag_versions = values.wcag_versions.enum_values.clone();
+                    self.data.wcag_levels = values.wcag_levels.enum_values.clone();
+                }
+                true
+            }
+            Msg::GetAccessibilityValues => {
+                ctx.link().send_future(
+                    self.fetch_accessibility_values
+                        .fetch(Msg::SetAccessibilityValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetAccessibilityValuesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetPrintAttributeValuesFetchState(fetch_state) => {
+                self.fetch_print_attribute_values.apply(fetch_state);
+                if let FetchState::Fetched(body) =
+                    self.fetch_print_attribute_values.as_ref().state()
+                {
+                    let values = &body.data;
+                    self.data.binding_styles = values.binding_styles.enum_values.clone();
+                    self.data.paper_types = values.paper_types.enum_values.clone();
+                    self.data.interior_colours = values.interior_colours.enum_values.clone();
+                    self.data.trim_categories = values.trim_categories.enum_values.clone();
+                    self.data.cover_finishes = values.cover_finishes.enum_values.clone();
+                }
+                true
+            }
+            Msg::GetPrintAttributeValues => {
+                ctx.link().send_future(
+                    self.fetch_print_attribute_values
+                        .fetch(Msg::SetPrintAttributeValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetPrintAttributeValuesFetchState(
+                        FetchAction::Fetching,
+                    ));
+                false
+            }
+            Msg::SetDigitalAttributeValuesFetchState(fetch_state) => {
+                self.fetch_digital_attribute_values.apply(fetch_state);
+                if let FetchState::Fetched(body) =
+                    self.fetch_digital_attribute_values.as_ref().state()
+                {
+                    let values = &body.data;
+                    self.data.technical_protections =
+                        values.technical_protections.enum_values.clone();
+                    self.data.layouts = values.layouts.enum_values.clone();
+                }
+                true
+            }
+            Msg::GetDigitalAttributeValues => {
+                ctx.link().send_future(
+                    self.fetch_digital_attribute_values
+                        .fetch(Msg::SetDigitalAttributeValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetDigitalAttributeValuesFetchState(
+                        FetchAction::Fetching,
+                    ));
+                false
+            }
             Msg::SetPublicationCreateState(fetch_state) => {
                 self.create_publication.apply(fetch_state);
                 match self.create_publication.as_ref().state() {
@@ -229,6 +390,24 @@ impl Component for PublicationModalComponent {
                         depth_in: self.publication.depth_in,
                         weight_g: self.publication.weight_g,
                         weight_oz: self.publication.weight_oz,
+                        accessibility_features: self.publication.accessibility_features.clone(),
+                        accessibility_hazards: self.publication.accessibility_hazards.clone(),
+                        accessibility_standard: self.publication.accessibility_standard,
+                        wcag_version: self.publication.wcag_version,
+                        wcag_level: self.publication.wcag_level,
+                        accessibility_certifier: self.publication.accessibility_certifier.clone(),
+                        accessibility_summary: self.publication.accessibility_summary.clone(),
+                        binding_style: self.publication.binding_style,
+                        paper_type: self.publication.paper_type,
+                        interior_colour: self.publication.interior_colour,
+                        trim_category: self.publication.trim_category,
+                        page_count: self.publication.page_count,
+                        cover_finish: self.publication.cover_finish,
+                        carton_quantity: self.publication.carton_quantity,
+                        format_version: self.publication.format_version.clone(),
+                        file_size_kb: self.publication.file_size_kb,
+                        technical_protections: self.publication.technical_protections.clone(),
+                        layout: self.publication.layout,
                     },
                     ..Default::default()
                 };
@@ -290,6 +469,24 @@ impl Component for PublicationModalComponent {
                         depth_in: self.publication.depth_in,
                         weight_g: self.publication.weight_g,
                         weight_oz: self.publication.weight_oz,
+                        accessibility_features: self.publication.accessibility_features.clone(),
+                        accessibility_hazards: self.publication.accessibility_hazards.clone(),
+                        accessibility_standard: self.publication.accessibility_standard,
+                        wcag_version: self.publication.wcag_version,
+                        wcag_level: self.publication.wcag_level,
+                        accessibility_certifier: self.publication.accessibility_certifier.clone(),
+                        accessibility_summary: self.publication.accessibility_summary.clone(),
+                        binding_style: self.publication.binding_style,
+                        paper_type: self.publication.paper_type,
+                        interior_colour: self.publication.interior_colour,
+                        trim_category: self.publication.trim_category,
+                        page_count: self.publication.page_count,
+                        cover_finish: self.publication.cover_finish,
+                        carton_quantity: self.publication.carton_quantity,
+                        format_version: self.publication.format_version.clone(),
+                        file_size_kb: self.publication.file_size_kb,
+                        technical_protections: self.publication.technical_protections.clone(),
+                        layout: self.publication.layout,
                     },
                     ..Default::default()
                 };
@@ -431,6 +628,76 @@ impl Component for PublicationModalComponent {
                 }
                 changed_value
             }
+            Msg::ChangeAccessibilityFeatures(values) => self
+                .publication
+                .accessibility_features
+                .neq_assign(parse_values(values)),
+            Msg::ChangeAccessibilityHazards(values) => self
+                .publication
+                .accessibility_hazards
+                .neq_assign(parse_values(values)),
+            Msg::ChangeAccessibilityStandard(value) => self
+                .publication
+                .accessibility_standard
+                .neq_assign(AccessibilityStandard::from_str(&value).ok()),
+            Msg::ChangeWcagVersion(value) => self
+                .publication
+                .wcag_version
+                .neq_assign(WcagVersion::from_str(&value).ok()),
+            Msg::ChangeWcagLevel(value) => self
+                .publication
+                .wcag_level
+                .neq_assign(WcagLevel::from_str(&value).ok()),
+            Msg::ChangeAccessibilityCertifier(value) => self
+                .publication
+                .accessibility_certifier
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeAccessibilitySummary(value) => self
+                .publication
+                .accessibility_summary
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeBindingStyle(value) => self
+                .publication
+                .binding_style
+                .neq_assign(BindingStyle::from_str(&value).ok()),
+            Msg::ChangePaperType(value) => self
+                .publication
+                .paper_type
+                .neq_assign(PaperType::from_str(&value).ok()),
+            Msg::ChangeInteriorColour(value) => self
+                .publication
+                .interior_colour
+                .neq_assign(InteriorColour::from_str(&value).ok()),
+            Msg::ChangeTrimCategory(value) => self
+                .publication
+                .trim_category
+                .neq_assign(TrimCategory::from_str(&value).ok()),
+            Msg::ChangeCoverFinish(value) => self
+                .publication
+                .cover_finish
+                .neq_assign(CoverFinish::from_str(&value).ok()),
+            Msg::ChangePageCount(value) => {
+                self.publication.page_count.neq_assign(value.to_opt_int())
+            }
+            Msg::ChangeCartonQuantity(value) => self
+                .publication
+                .carton_quantity
+                .neq_assign(value.to_opt_int()),
+            Msg::ChangeFormatVersion(value) => self
+                .publication
+                .format_version
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeFileSizeKb(value) => {
+                self.publication.file_size_kb.neq_assign(value.to_opt_int())
+            }
+            Msg::ChangeTechnicalProtections(values) => self
+                .publication
+                .technical_protections
+                .neq_assign(parse_values(values)),
+            Msg::ChangeLayout(value) => self
+                .publication
+                .layout
+                .neq_assign(DigitalLayout::from_str(&value).ok()),
         }
     }
 
@@ -477,12 +744,12 @@ impl Component for PublicationModalComponent {
                                 value={ self.isbn.clone() }
                                 tooltip={ self.isbn_warning.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeIsbn(e.to_value())) }
-                                // ISBNs cannot be added for publications whose work type is Book Chapter.
-                                deactivated={ ctx.props().work_type == WorkType::BookChapter }
+                                // ISBNs cannot be added for publications of chapters or articles.
+                                deactivated={ ctx.props().work_type.is_component() }
                             />
                             {
-                                // Dimensions can only be added for physical (Paperback/Hardback) non-Chapter publications.
-                                if self.publication.is_physical() && ctx.props().work_type != WorkType::BookChapter {
+                                // Dimensions and print attributes can only be added for physical (Paperback/Hardback) publications of whole works.
+                                if self.publication.is_physical() && !ctx.props().work_type.is_component() {
                                     html! {
                                         <>
                                             <label class="checkbox">
@@ -557,6 +824,139 @@ impl Component for PublicationModalComponent {
                                                     />
                                                 </div>
                                             </div>
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormOptionalBindingStyleSelect
+                                                        label = "Binding"
+                                                        value={ self.publication.binding_style }
+                                                        data={ self.data.binding_styles.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeBindingStyle(e.to_value())) }
+                                                    />
+                                                    <FormOptionalTrimCategorySelect
+                                                        label = "Trim"
+                                                        value={ self.publication.trim_category }
+                                                        data={ self.data.trim_categories.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeTrimCategory(e.to_value())) }
+                                                    />
+                                                    <FormOptionalCoverFinishSelect
+                                                        label = "Cover Finish"
+                                                        value={ self.publication.cover_finish }
+                                                        data={ self.data.cover_finishes.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeCoverFinish(e.to_value())) }
+                                                    />
+                                                </div>
+                                            </div>
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormOptionalPaperTypeSelect
+                                                        label = "Paper"
+                                                        value={ self.publication.paper_type }
+                                                        data={ self.data.paper_types.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangePaperType(e.to_value())) }
+                                                    />
+                                                    <FormOptionalInteriorColourSelect
+                                                        label = "Interior Colour"
+                                                        value={ self.publication.interior_colour }
+                                                        data={ self.data.interior_colours.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeInteriorColour(e.to_value())) }
+                                                    />
+                                                </div>
+                                            </div>
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormNumberInput
+                                                        label = "Page Count"
+                                                        value={ self.publication.page_count }
+                                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangePageCount(e.to_value())) }
+                                                    />
+                                                    <FormNumberInput
+                                                        label = "Carton Quantity"
+                                                        value={ self.publication.carton_quantity }
+                                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCartonQuantity(e.to_value())) }
+                                                    />
+                                                </div>
+                                            </div>
+                                        </>
+                                    }
+                                } else {
+                                    html!{}
+                                }
+                            }
+                            {
+                                // File attributes and accessibility metadata can only be added for digital publications.
+                                if self.publication.is_digital() {
+                                    html! {
+                                        <>
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormTextInput
+                                                        label = "Format Version"
+                                                        value={ self.publication.format_version.clone() }
+                                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFormatVersion(e.to_value())) }
+                                                    />
+                                                    <FormNumberInput
+                                                        label = "File Size (KB)"
+                                                        value={ self.publication.file_size_kb }
+                                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFileSizeKb(e.to_value())) }
+                                                    />
+                                                    <FormOptionalDigitalLayoutSelect
+                                                        label = "Layout"
+                                                        value={ self.publication.layout }
+                                                        data={ self.data.layouts.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeLayout(e.to_value())) }
+                                                    />
+                                                </div>
+                                            </div>
+                                            <FormTechnicalProtectionMultiSelect
+                                                label = "Technical Protection"
+                                                value={ self.publication.technical_protections.clone() }
+                                                data={ self.data.technical_protections.clone() }
+                                                onchange={ ctx.link().callback(|e: Event| Msg::ChangeTechnicalProtections(e.to_values())) }
+                                            />
+                                            <FormAccessibilityFeatureMultiSelect
+                                                label = "Accessibility Features"
+                                                value={ self.publication.accessibility_features.clone() }
+                                                data={ self.data.accessibility_features.clone() }
+                                                onchange={ ctx.link().callback(|e: Event| Msg::ChangeAccessibilityFeatures(e.to_values())) }
+                                            />
+                                            <FormAccessibilityHazardMultiSelect
+                                                label = "Accessibility Hazards"
+                                                value={ self.publication.accessibility_hazards.clone() }
+                                                data={ self.data.accessibility_hazards.clone() }
+                                                onchange={ ctx.link().callback(|e: Event| Msg::ChangeAccessibilityHazards(e.to_values())) }
+                                            />
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormOptionalAccessibilityStandardSelect
+                                                        label = "Conformance"
+                                                        value={ self.publication.accessibility_standard }
+                                                        data={ self.data.accessibility_standards.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeAccessibilityStandard(e.to_value())) }
+                                                    />
+                                                    <FormOptionalWcagVersionSelect
+                                                        label = "WCAG Version"
+                                                        value={ self.publication.wcag_version }
+                                                        data={ self.data.wcag_versions.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeWcagVersion(e.to_value())) }
+                                                    />
+                                                    <FormOptionalWcagLevelSelect
+                                                        label = "WCAG Level"
+                                                        value={ self.publication.wcag_level }
+                                                        data={ self.data.wcag_levels.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeWcagLevel(e.to_value())) }
+                                                    />
+                                                </div>
+                                            </div>
+                                            <FormTextInput
+                                                label = "Accessibility Certifier"
+                                                value={ self.publication.accessibility_certifier.clone() }
+                                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeAccessibilityCertifier(e.to_value())) }
+                                            />
+                                            <FormTextarea
+                                                label = "Accessibility Summary"
+                                                value={ self.publication.accessibility_summary.clone() }
+                                                oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeAccessibilitySummary(e.to_value())) }
+                                            />
                                         </>
                                     }
                                 } else {
@@ -632,7 +1032,7 @@ impl PublicationModalComponent {
         }
         // Clear any fields which are not applicable to the currently selected work/publication type.
         // (Do not clear them before the save point as the user may change the type again.)
-        if self.publication.is_digital() || ctx.props().work_type == WorkType::BookChapter {
+        if self.publication.is_digital() || ctx.props().work_type.is_component() {
             self.publication.width_mm = None;
             self.publication.width_in = None;
             self.publication.height_mm = None;
@@ -641,6 +1041,33 @@ impl PublicationModalComponent {
             self.publication.depth_in = None;
             self.publication.weight_g = None;
             self.publication.weight_oz = None;
+            self.publication.binding_style = None;
+            self.publication.paper_type = None;
+            self.publication.interior_colour = None;
+            self.publication.trim_category = None;
+            self.publication.page_count = None;
+            self.publication.cover_finish = None;
+            self.publication.carton_quantity = None;
+        }
+        if self.publication.is_physical() {
+            self.publication.accessibility_features = vec![];
+            self.publication.accessibility_hazards = vec![];
+            self.publication.accessibility_standard = None;
+            self.publication.wcag_version = None;
+            self.publication.wcag_level = None;
+            self.publication.accessibility_certifier = None;
+            self.publication.accessibility_summary = None;
+            self.publication.format_version = None;
+            self.publication.file_size_kb = None;
+            self.publication.technical_protections = vec![];
+            self.publication.layout = None;
         }
     }
 }
+
+fn parse_values<T: FromStr>(values: Vec<String>) -> Vec<T> {
+    values
+        .iter()
+        .filter_map(|value| T::from_str(value).ok())
+        .collect()
+}
@@ -240,8 +240,8 @@ impl PublicationsFormComponent {
                     </div>
 
                     {
-                        // Dimensions are only applicable to physical (Paperback/Hardback) non-Chapter publications.
-                        if p.is_physical() && ctx.props().work_type != WorkType::BookChapter {
+                        // Dimensions are only applicable to physical (Paperback/Hardback) publications of whole works.
+                        if p.is_physical() && !ctx.props().work_type.is_component() {
                             html! {
                                 <>
                                     <div class="field is-vertical">
@@ -0,0 +1,505 @@
+use std::str::FromStr;
+use thoth_api::model::institution::CountryCode;
+use thoth_api::model::sales_rights::SalesRestrictionType;
+use thoth_api::model::sales_rights::SalesRights;
+use thoth_api::model::sales_rights::SalesRightsType;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormCountryCodeMultiSelect;
+use crate::component::utils::FormOptionalSalesRestrictionTypeSelect;
+use crate::component::utils::FormSalesRightsTypeSelect;
+use crate::component::utils::FormTextInput;
+use crate::models::institution::country_codes_query::FetchActionCountryCodes;
+use crate::models::institution::country_codes_query::FetchCountryCodes;
+use crate::models::institution::CountryCodeValues;
+use crate::models::sales_rights::create_sales_rights_mutation::CreateSalesRightsRequest;
+use crate::models::sales_rights::create_sales_rights_mutation::CreateSalesRightsRequestBody;
+use crate::models::sales_rights::create_sales_rights_mutation::PushActionCreateSalesRights;
+use crate::models::sales_rights::create_sales_rights_mutation::PushCreateSalesRights;
+use crate::models::sales_rights::create_sales_rights_mutation::Variables;
+use crate::models::sales_rights::delete_sales_rights_mutation::DeleteSalesRightsRequest;
+use crate::models::sales_rights::delete_sales_rights_mutation::DeleteSalesRightsRequestBody;
+use crate::models::sales_rights::delete_sales_rights_mutation::PushActionDeleteSalesRights;
+use crate::models::sales_rights::delete_sales_rights_mutation::PushDeleteSalesRights;
+use crate::models::sales_rights::delete_sales_rights_mutation::Variables as DeleteVariables;
+use crate::models::sales_rights::sales_rights_types_query::FetchActionSalesRightsTypes;
+use crate::models::sales_rights::sales_rights_types_query::FetchSalesRightsTypes;
+use crate::models::sales_rights::SalesRestrictionTypeValues;
+use crate::models::sales_rights::SalesRightsTypeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_SALES_RIGHTS;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToElementValues;
+use super::ToOption;
+
+pub struct SalesRightsFormComponent {
+    data: SalesRightsFormData,
+    new_sales_rights: SalesRights,
+    // Region codes are entered as space-separated lists and only split on submission
+    regions_included: String,
+    regions_excluded: String,
+    show_add_form: bool,
+    fetch_sales_rights_types: FetchSalesRightsTypes,
+    fetch_country_codes: FetchCountryCodes,
+    push_sales_rights: PushCreateSalesRights,
+    delete_sales_rights: PushDeleteSalesRights,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct SalesRightsFormData {
+    sales_rights_types: Vec<SalesRightsTypeValues>,
+    sales_restriction_types: Vec<SalesRestrictionTypeValues>,
+    country_codes: Vec<CountryCodeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetSalesRightsTypesFetchState(FetchActionSalesRightsTypes),
+    GetSalesRightsTypes,
+    SetCountryCodesFetchState(FetchActionCountryCodes),
+    GetCountryCodes,
+    SetSalesRightsPushState(PushActionCreateSalesRights),
+    CreateSalesRights,
+    SetSalesRightsDeleteState(PushActionDeleteSalesRights),
+    DeleteSalesRights(Uuid),
+    ChangeSalesRightsType(SalesRightsType),
+    ChangeCountriesIncluded(Vec<String>),
+    ChangeCountriesExcluded(Vec<String>),
+    ChangeRegionsIncluded(String),
+    ChangeRegionsExcluded(String),
+    ChangeSalesRestrictionType(String),
+    ChangeSalesRestrictionNote(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub sales_rights: Option<Vec<SalesRights>>,
+    pub publication_id: Uuid,
+    pub update_sales_rights: Callback<Option<Vec<SalesRights>>>,
+}
+
+fn parse_countries(values: Vec<String>) -> Vec<CountryCode> {
+    values
+        .iter()
+        .filter_map(|value| CountryCode::from_str(value).ok())
+        .collect()
+}
+
+fn split_regions(regions: &str) -> Vec<String> {
+    regions.split_whitespace().map(str::to_string).collect()
+}
+
+fn display_countries(countries: &[CountryCode]) -> String {
+    countries
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+impl Component for SalesRightsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: SalesRightsFormData = Default::default();
+        let show_add_form = false;
+        let new_sales_rights: SalesRights = Default::default();
+        let fetch_sales_rights_types = Default::default();
+        let fetch_country_codes = Default::default();
+        let push_sales_rights = Default::default();
+        let delete_sales_rights = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetSalesRightsTypes);
+        ctx.link().send_message(Msg::GetCountryCodes);
+
+        SalesRightsFormComponent {
+            data,
+            new_sales_rights,
+            regions_included: Default::default(),
+            regions_excluded: Default::default(),
+            show_add_form,
+            fetch_sales_rights_types,
+            fetch_country_codes,
+            push_sales_rights,
+            delete_sales_rights,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetSalesRightsTypesFetchState(fetch_state) => {
+                self.fetch_sales_rights_types.apply(fetch_state);
+                if let FetchState::Fetched(body) = self.fetch_sales_rights_types.as_ref().state() {
+                    let values = &body.data;
+                    self.data.sales_rights_types = values.sales_rights_types.enum_values.clone();
+                    self.data.sales_restriction_types =
+                        values.sales_restriction_types.enum_values.clone();
+                }
+                true
+            }
+            Msg::GetSalesRightsTypes => {
+                ctx.link().send_future(
+                    self.fetch_sales_rights_types
+                        .fetch(Msg::SetSalesRightsTypesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetSalesRightsTypesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetCountryCodesFetchState(fetch_state) => {
+                self.fetch_country_codes.apply(fetch_state);
+                self.data.country_codes = match self.fetch_country_codes.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.country_codes.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetCountryCodes => {
+                ctx.link().send_future(
+                    self.fetch_country_codes
+                        .fetch(Msg::SetCountryCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetCountryCodesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSalesRightsPushState(fetch_state) => {
+                self.push_sales_rights.apply(fetch_state);
+                match self.push_sales_rights.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_sales_rights {
+                        Some(s) => {
+                            let mut sales_rights: Vec<SalesRights> =
+                                ctx.props().sales_rights.clone().unwrap_or_default();
+                            sales_rights.push(s.clone());
+                            ctx.props().update_sales_rights.emit(Some(sales_rights));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateSalesRights => {
+                let body = CreateSalesRightsRequestBody {
+                    variables: Variables {
+                        publication_id: ctx.props().publication_id,
+                        sales_rights_type: self.new_sales_rights.sales_rights_type,
+                        countries_included: self.new_sales_rights.countries_included.clone(),
+                        countries_excluded: self.new_sales_rights.countries_excluded.clone(),
+                        regions_included: split_regions(&self.regions_included),
+                        regions_excluded: split_regions(&self.regions_excluded),
+                        sales_restriction_type: self.new_sales_rights.sales_restriction_type,
+                        sales_restriction_note: self
+                            .new_sales_rights
+                            .sales_restriction_note
+                            .clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = CreateSalesRightsRequest { body };
+                self.push_sales_rights = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_sales_rights.fetch(Msg::SetSalesRightsPushState));
+                ctx.link()
+                    .send_message(Msg::SetSalesRightsPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSalesRightsDeleteState(fetch_state) => {
+                self.delete_sales_rights.apply(fetch_state);
+                match self.delete_sales_rights.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_sales_rights {
+                        Some(sales_rights) => {
+                            let to_keep: Vec<SalesRights> = ctx
+                                .props()
+                                .sales_rights
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|s| s.sales_rights_id != sales_rights.sales_rights_id)
+                                .collect();
+                            ctx.props().update_sales_rights.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteSalesRights(sales_rights_id) => {
+                let body = DeleteSalesRightsRequestBody {
+                    variables: DeleteVariables { sales_rights_id },
+                    ..Default::default()
+                };
+                let request = DeleteSalesRightsRequest { body };
+                self.delete_sales_rights = Fetch::new(request);
+                ctx.link().send_future(
+                    self.delete_sales_rights
+                        .fetch(Msg::SetSalesRightsDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetSalesRightsDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeSalesRightsType(sales_rights_type) => self
+                .new_sales_rights
+                .sales_rights_type
+                .neq_assign(sales_rights_type),
+            Msg::ChangeCountriesIncluded(values) => self
+                .new_sales_rights
+                .countries_included
+                .neq_assign(parse_countries(values)),
+            Msg::ChangeCountriesExcluded(values) => self
+                .new_sales_rights
+                .countries_excluded
+                .neq_assign(parse_countries(values)),
+            Msg::ChangeRegionsIncluded(value) => self.regions_included.neq_assign(value),
+            Msg::ChangeRegionsExcluded(value) => self.regions_excluded.neq_assign(value),
+            Msg::ChangeSalesRestrictionType(value) => self
+                .new_sales_rights
+                .sales_restriction_type
+                .neq_assign(SalesRestrictionType::from_str(&value).ok()),
+            Msg::ChangeSalesRestrictionNote(value) => self
+                .new_sales_rights
+                .sales_restriction_note
+                .neq_assign(value.to_opt_string()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let sales_rights = ctx.props().sales_rights.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Sales Rights" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Sales Rights" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Sales Rights" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="sales-rights-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateSalesRights
+                            }) }
+                            >
+                                <FormSalesRightsTypeSelect
+                                    label = "Sales Rights Type"
+                                    value={ self.new_sales_rights.sales_rights_type }
+                                    data={ self.data.sales_rights_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeSalesRightsType(SalesRightsType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormCountryCodeMultiSelect
+                                    label = "Countries Included"
+                                    value={ self.new_sales_rights.countries_included.clone() }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeCountriesIncluded(e.to_values())) }
+                                />
+                                <FormTextInput
+                                    label = "Regions Included (e.g. WORLD, ECZ, GB-SCT)"
+                                    value={ self.regions_included.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRegionsIncluded(e.to_value())) }
+                                />
+                                <FormCountryCodeMultiSelect
+                                    label = "Countries Excluded"
+                                    value={ self.new_sales_rights.countries_excluded.clone() }
+                                    data={ self.data.country_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeCountriesExcluded(e.to_values())) }
+                                />
+                                <FormTextInput
+                                    label = "Regions Excluded (e.g. GB-NIR)"
+                                    value={ self.regions_excluded.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeRegionsExcluded(e.to_value())) }
+                                />
+                                <FormOptionalSalesRestrictionTypeSelect
+                                    label = "Sales Restriction"
+                                    value={ self.new_sales_rights.sales_restriction_type }
+                                    data={ self.data.sales_restriction_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event| Msg::ChangeSalesRestrictionType(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Sales Restriction Note (e.g. the name of the exclusive retailer)"
+                                    value={ self.new_sales_rights.sales_restriction_note.clone().unwrap_or_default() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSalesRestrictionNote(e.to_value())) }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="sales-rights-form"
+                            >
+                                { "Add Sales Rights" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !sales_rights.is_empty() {
+                        html!{{for sales_rights.iter().map(|s| self.render_sales_rights(ctx, s))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_SALES_RIGHTS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl SalesRightsFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_sales_rights(&self, ctx: &Context<Self>, s: &SalesRights) -> Html {
+        let sales_rights_id = s.sales_rights_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-globe" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Sales Rights Type" }</label>
+                        <div class="control is-expanded">
+                            {&s.sales_rights_type}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Included" }</label>
+                        <div class="control is-expanded">
+                            {display_countries(&s.countries_included)}
+                            {" "}
+                            {s.regions_included.join(" ")}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Excluded" }</label>
+                        <div class="control is-expanded">
+                            {display_countries(&s.countries_excluded)}
+                            {" "}
+                            {s.regions_excluded.join(" ")}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Sales Restriction" }</label>
+                        <div class="control is-expanded">
+                            {&s.sales_restriction_type.as_ref().map(|r| r.to_string()).unwrap_or_default()}
+                            {" "}
+                            {&s.sales_restriction_note.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteSalesRights(sales_rights_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
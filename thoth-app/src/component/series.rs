@@ -22,6 +22,7 @@ use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
 use crate::component::utils::FormImprintSelect;
+use crate::component::utils::FormParentSeriesSelect;
 use crate::component::utils::FormSeriesTypeSelect;
 use crate::component::utils::FormTextInput;
 use crate::component::utils::FormTextarea;
@@ -44,6 +45,11 @@ use crate::models::series::series_query::SeriesRequestBody;
 use crate::models::series::series_query::Variables;
 use crate::models::series::series_types_query::FetchActionSeriesTypes;
 use crate::models::series::series_types_query::FetchSeriesTypes;
+use crate::models::series::serieses_query::FetchActionSerieses;
+use crate::models::series::serieses_query::FetchSerieses;
+use crate::models::series::serieses_query::SeriesesRequest;
+use crate::models::series::serieses_query::SeriesesRequestBody;
+use crate::models::series::serieses_query::Variables as SeriesesVariables;
 use crate::models::series::update_series_mutation::PushActionUpdateSeries;
 use crate::models::series::update_series_mutation::PushUpdateSeries;
 use crate::models::series::update_series_mutation::UpdateSeriesRequest;
@@ -62,6 +68,7 @@ pub struct SeriesComponent {
     push_series: PushUpdateSeries,
     data: SeriesFormData,
     fetch_imprints: FetchImprints,
+    fetch_serieses: FetchSerieses,
     fetch_series_types: FetchSeriesTypes,
     delete_series: PushDeleteSeries,
     notification_bus: NotificationDispatcher,
@@ -72,6 +79,7 @@ pub struct SeriesComponent {
 #[derive(Default)]
 struct SeriesFormData {
     imprints: Vec<ImprintWithPublisher>,
+    serieses: Vec<SeriesWithImprint>,
     series_types: Vec<SeriesTypeValues>,
 }
 
@@ -79,6 +87,8 @@ struct SeriesFormData {
 pub enum Msg {
     SetImprintsFetchState(FetchActionImprints),
     GetImprints,
+    SetSeriesesFetchState(FetchActionSerieses),
+    GetSerieses,
     SetSeriesTypesFetchState(FetchActionSeriesTypes),
     GetSeriesTypes,
     SetSeriesFetchState(FetchActionSeries),
@@ -89,6 +99,7 @@ pub enum Msg {
     DeleteSeries,
     ChangeSeriesType(SeriesType),
     ChangeImprint(Uuid),
+    ChangeParentSeries(Option<Uuid>),
     ChangeSeriesName(String),
     ChangeIssnPrint(String),
     ChangeIssnDigital(String),
@@ -115,11 +126,13 @@ impl Component for SeriesComponent {
         let series: SeriesWithImprint = Default::default();
         let data: SeriesFormData = Default::default();
         let fetch_imprints: FetchImprints = Default::default();
+        let fetch_serieses: FetchSerieses = Default::default();
         let fetch_series_types: FetchSeriesTypes = Default::default();
         let resource_access = ctx.props().current_user.resource_access.clone();
 
         ctx.link().send_message(Msg::GetSeries);
         ctx.link().send_message(Msg::GetImprints);
+        ctx.link().send_message(Msg::GetSerieses);
         ctx.link().send_message(Msg::GetSeriesTypes);
 
         SeriesComponent {
@@ -128,6 +141,7 @@ impl Component for SeriesComponent {
             push_series,
             data,
             fetch_imprints,
+            fetch_serieses,
             fetch_series_types,
             delete_series,
             notification_bus,
@@ -165,6 +179,34 @@ impl Component for SeriesComponent {
                     .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetSeriesesFetchState(fetch_state) => {
+                self.fetch_serieses.apply(fetch_state);
+                self.data.serieses = match self.fetch_serieses.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.serieses.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetSerieses => {
+                let body = SeriesesRequestBody {
+                    variables: SeriesesVariables {
+                        limit: Some(100),
+                        publishers: ctx.props().current_user.resource_access.restricted_to(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                let request = SeriesesRequest { body };
+                self.fetch_serieses = Fetch::new(request);
+
+                ctx.link()
+                    .send_future(self.fetch_serieses.fetch(Msg::SetSeriesesFetchState));
+                ctx.link()
+                    .send_message(Msg::SetSeriesesFetchState(FetchAction::Fetching));
+                false
+            }
             Msg::SetSeriesTypesFetchState(fetch_state) => {
                 self.fetch_series_types.apply(fetch_state);
                 self.data.series_types = match self.fetch_series_types.as_ref().state() {
@@ -265,6 +307,7 @@ impl Component for SeriesComponent {
                         series_description: self.series.series_description.clone(),
                         series_cfp_url: self.series.series_cfp_url.clone(),
                         imprint_id: self.series.imprint.imprint_id,
+                        parent_series_id: self.series.parent_series_id,
                     },
                     ..Default::default()
                 };
@@ -324,6 +367,9 @@ impl Component for SeriesComponent {
             }
             Msg::ChangeSeriesType(series_type) => self.series.series_type.neq_assign(series_type),
             Msg::ChangeImprint(imprint_id) => self.series.imprint.imprint_id.neq_assign(imprint_id),
+            Msg::ChangeParentSeries(parent_series_id) => {
+                self.series.parent_series_id.neq_assign(parent_series_id)
+            }
             Msg::ChangeSeriesName(series_name) => self
                 .series
                 .series_name
@@ -353,6 +399,7 @@ impl Component for SeriesComponent {
             .neq_assign(ctx.props().current_user.resource_access.clone());
         if updated_permissions {
             ctx.link().send_message(Msg::GetImprints);
+            ctx.link().send_message(Msg::GetSerieses);
         }
         false
     }
@@ -366,6 +413,18 @@ impl Component for SeriesComponent {
                     event.prevent_default();
                     Msg::UpdateSeries
                 });
+                // Only other top-level series of the same imprint can be selected as parent series
+                let parent_serieses: Vec<SeriesWithImprint> = self
+                    .data
+                    .serieses
+                    .iter()
+                    .filter(|s| {
+                        s.series_id != self.series.series_id
+                            && s.parent_series_id.is_none()
+                            && s.imprint.imprint_id == self.series.imprint.imprint_id
+                    })
+                    .cloned()
+                    .collect();
                 html! {
                     <>
                         <nav class="level">
@@ -402,6 +461,14 @@ impl Component for SeriesComponent {
                                 ) }
                                 required = true
                             />
+                            <FormParentSeriesSelect
+                                label = "Parent Series"
+                                value={ self.series.parent_series_id }
+                                data={ parent_serieses }
+                                onchange={ ctx.link().callback(|e: Event|
+                                    Msg::ChangeParentSeries(Uuid::parse_str(&e.to_value()).ok())
+                                ) }
+                            />
                             <FormTextInput
                                 label = "Series Name"
                                 value={ self.series.series_name.clone() }
@@ -0,0 +1,517 @@
+use std::str::FromStr;
+use thoth_api::model::supporting_resource::ResourceContentType;
+use thoth_api::model::supporting_resource::ResourceForm;
+use thoth_api::model::supporting_resource::ResourceMode;
+use thoth_api::model::supporting_resource::SupportingResource;
+use thoth_api::model::text_content::ContentAudience;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormContentAudienceSelect;
+use crate::component::utils::FormNumberInput;
+use crate::component::utils::FormResourceContentTypeSelect;
+use crate::component::utils::FormResourceFormSelect;
+use crate::component::utils::FormResourceModeSelect;
+use crate::component::utils::FormTextInput;
+use crate::component::utils::FormUrlInput;
+use crate::models::supporting_resource::create_supporting_resource_mutation::CreateSupportingResourceRequest;
+use crate::models::supporting_resource::create_supporting_resource_mutation::CreateSupportingResourceRequestBody;
+use crate::models::supporting_resource::create_supporting_resource_mutation::PushActionCreateSupportingResource;
+use crate::models::supporting_resource::create_supporting_resource_mutation::PushCreateSupportingResource;
+use crate::models::supporting_resource::create_supporting_resource_mutation::Variables;
+use crate::models::supporting_resource::delete_supporting_resource_mutation::DeleteSupportingResourceRequest;
+use crate::models::supporting_resource::delete_supporting_resource_mutation::DeleteSupportingResourceRequestBody;
+use crate::models::supporting_resource::delete_supporting_resource_mutation::PushActionDeleteSupportingResource;
+use crate::models::supporting_resource::delete_supporting_resource_mutation::PushDeleteSupportingResource;
+use crate::models::supporting_resource::delete_supporting_resource_mutation::Variables as DeleteVariables;
+use crate::models::supporting_resource::supporting_resource_values_query::FetchActionSupportingResourceValues;
+use crate::models::supporting_resource::supporting_resource_values_query::FetchSupportingResourceValues;
+use crate::models::supporting_resource::ResourceContentTypeValues;
+use crate::models::supporting_resource::ResourceFormValues;
+use crate::models::supporting_resource::ResourceModeValues;
+use crate::models::text_content::ContentAudienceValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_SUPPORTING_RESOURCES;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct SupportingResourcesFormComponent {
+    data: SupportingResourcesFormData,
+    new_supporting_resource: SupportingResource,
+    show_add_form: bool,
+    fetch_supporting_resource_values: FetchSupportingResourceValues,
+    push_supporting_resource: PushCreateSupportingResource,
+    delete_supporting_resource: PushDeleteSupportingResource,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct SupportingResourcesFormData {
+    resource_content_types: Vec<ResourceContentTypeValues>,
+    content_audiences: Vec<ContentAudienceValues>,
+    resource_modes: Vec<ResourceModeValues>,
+    resource_forms: Vec<ResourceFormValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetSupportingResourceValuesFetchState(FetchActionSupportingResourceValues),
+    GetSupportingResourceValues,
+    SetSupportingResourcePushState(PushActionCreateSupportingResource),
+    CreateSupportingResource,
+    SetSupportingResourceDeleteState(PushActionDeleteSupportingResource),
+    DeleteSupportingResource(Uuid),
+    ChangeResourceContentType(ResourceContentType),
+    ChangeContentAudience(ContentAudience),
+    ChangeResourceMode(ResourceMode),
+    ChangeResourceForm(ResourceForm),
+    ChangeResourceUrl(String),
+    ChangeCaption(String),
+    ChangeCredit(String),
+    ChangeWidthPx(String),
+    ChangeHeightPx(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub supporting_resources: Option<Vec<SupportingResource>>,
+    pub work_id: Uuid,
+    pub update_supporting_resources: Callback<Option<Vec<SupportingResource>>>,
+}
+
+impl Component for SupportingResourcesFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: SupportingResourcesFormData = Default::default();
+        let show_add_form = false;
+        let new_supporting_resource: SupportingResource = Default::default();
+        let fetch_supporting_resource_values = Default::default();
+        let push_supporting_resource = Default::default();
+        let delete_supporting_resource = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetSupportingResourceValues);
+
+        SupportingResourcesFormComponent {
+            data,
+            new_supporting_resource,
+            show_add_form,
+            fetch_supporting_resource_values,
+            push_supporting_resource,
+            delete_supporting_resource,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetSupportingResourceValuesFetchState(fetch_state) => {
+                self.fetch_supporting_resource_values.apply(fetch_state);
+                match self.fetch_supporting_resource_values.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        self.data.resource_content_types =
+                            body.data.resource_content_types.enum_values.clone();
+                        self.data.content_audiences =
+                            body.data.content_audiences.enum_values.clone();
+                        self.data.resource_modes = body.data.resource_modes.enum_values.clone();
+                        self.data.resource_forms = body.data.resource_forms.enum_values.clone();
+                    }
+                    _ => {
+                        self.data.resource_content_types = vec![];
+                        self.data.content_audiences = vec![];
+                        self.data.resource_modes = vec![];
+                        self.data.resource_forms = vec![];
+                    }
+                }
+                true
+            }
+            Msg::GetSupportingResourceValues => {
+                ctx.link().send_future(
+                    self.fetch_supporting_resource_values
+                        .fetch(Msg::SetSupportingResourceValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetSupportingResourceValuesFetchState(
+                        FetchAction::Fetching,
+                    ));
+                false
+            }
+            Msg::SetSupportingResourcePushState(fetch_state) => {
+                self.push_supporting_resource.apply(fetch_state);
+                match self.push_supporting_resource.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_supporting_resource {
+                        Some(r) => {
+                            let supporting_resource = r.clone();
+                            let mut supporting_resources: Vec<SupportingResource> =
+                                ctx.props().supporting_resources.clone().unwrap_or_default();
+                            supporting_resources.push(supporting_resource);
+                            ctx.props()
+                                .update_supporting_resources
+                                .emit(Some(supporting_resources));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateSupportingResource => {
+                let body = CreateSupportingResourceRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        resource_content_type: self.new_supporting_resource.resource_content_type,
+                        content_audience: self.new_supporting_resource.content_audience,
+                        resource_mode: self.new_supporting_resource.resource_mode,
+                        resource_form: self.new_supporting_resource.resource_form,
+                        resource_url: self.new_supporting_resource.resource_url.clone(),
+                        caption: self.new_supporting_resource.caption.clone(),
+                        credit: self.new_supporting_resource.credit.clone(),
+                        width_px: self.new_supporting_resource.width_px,
+                        height_px: self.new_supporting_resource.height_px,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateSupportingResourceRequest { body };
+                self.push_supporting_resource = Fetch::new(request);
+                ctx.link().send_future(
+                    self.push_supporting_resource
+                        .fetch(Msg::SetSupportingResourcePushState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetSupportingResourcePushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetSupportingResourceDeleteState(fetch_state) => {
+                self.delete_supporting_resource.apply(fetch_state);
+                match self.delete_supporting_resource.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_supporting_resource {
+                        Some(supporting_resource) => {
+                            let to_keep: Vec<SupportingResource> = ctx
+                                .props()
+                                .supporting_resources
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|r| {
+                                    r.supporting_resource_id
+                                        != supporting_resource.supporting_resource_id
+                                })
+                                .collect();
+                            ctx.props().update_supporting_resources.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteSupportingResource(supporting_resource_id) => {
+                let body = DeleteSupportingResourceRequestBody {
+                    variables: DeleteVariables {
+                        supporting_resource_id,
+                    },
+                    ..Default::default()
+                };
+                let request = DeleteSupportingResourceRequest { body };
+                self.delete_supporting_resource = Fetch::new(request);
+                ctx.link().send_future(
+                    self.delete_supporting_resource
+                        .fetch(Msg::SetSupportingResourceDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetSupportingResourceDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeResourceContentType(resource_content_type) => self
+                .new_supporting_resource
+                .resource_content_type
+                .neq_assign(resource_content_type),
+            Msg::ChangeContentAudience(content_audience) => self
+                .new_supporting_resource
+                .content_audience
+                .neq_assign(content_audience),
+            Msg::ChangeResourceMode(resource_mode) => self
+                .new_supporting_resource
+                .resource_mode
+                .neq_assign(resource_mode),
+            Msg::ChangeResourceForm(resource_form) => self
+                .new_supporting_resource
+                .resource_form
+                .neq_assign(resource_form),
+            Msg::ChangeResourceUrl(value) => self
+                .new_supporting_resource
+                .resource_url
+                .neq_assign(value.trim().to_owned()),
+            Msg::ChangeCaption(value) => self
+                .new_supporting_resource
+                .caption
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeCredit(value) => self
+                .new_supporting_resource
+                .credit
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeWidthPx(value) => self
+                .new_supporting_resource
+                .width_px
+                .neq_assign(value.to_opt_int()),
+            Msg::ChangeHeightPx(value) => self
+                .new_supporting_resource
+                .height_px
+                .neq_assign(value.to_opt_int()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let supporting_resources = ctx.props().supporting_resources.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Supporting Resources" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Resource" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Resource" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="supporting-resources-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateSupportingResource
+                            }) }
+                            >
+                                <FormResourceContentTypeSelect
+                                    label = "Content Type"
+                                    value={ self.new_supporting_resource.resource_content_type }
+                                    data={ self.data.resource_content_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeResourceContentType(ResourceContentType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormContentAudienceSelect
+                                    label = "Audience"
+                                    value={ self.new_supporting_resource.content_audience }
+                                    data={ self.data.content_audiences.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeContentAudience(ContentAudience::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormResourceModeSelect
+                                    label = "Mode"
+                                    value={ self.new_supporting_resource.resource_mode }
+                                    data={ self.data.resource_modes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeResourceMode(ResourceMode::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormResourceFormSelect
+                                    label = "Form"
+                                    value={ self.new_supporting_resource.resource_form }
+                                    data={ self.data.resource_forms.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeResourceForm(ResourceForm::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormUrlInput
+                                    label = "URL"
+                                    value={ self.new_supporting_resource.resource_url.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeResourceUrl(e.to_value())) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Caption"
+                                    value={ self.new_supporting_resource.caption.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCaption(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Credit"
+                                    value={ self.new_supporting_resource.credit.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeCredit(e.to_value())) }
+                                />
+                                <FormNumberInput
+                                    label = "Width (px)"
+                                    value={ self.new_supporting_resource.width_px }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeWidthPx(e.to_value())) }
+                                    min={ "1".to_string() }
+                                />
+                                <FormNumberInput
+                                    label = "Height (px)"
+                                    value={ self.new_supporting_resource.height_px }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeHeightPx(e.to_value())) }
+                                    min={ "1".to_string() }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="supporting-resources-form"
+                            >
+                                { "Add Resource" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !supporting_resources.is_empty() {
+                        html!{{for supporting_resources.iter().map(|r| self.render_supporting_resource(ctx, r))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_SUPPORTING_RESOURCES }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl SupportingResourcesFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_supporting_resource(&self, ctx: &Context<Self>, r: &SupportingResource) -> Html {
+        let supporting_resource_id = r.supporting_resource_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-photo-video" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 10em;">
+                        <label class="label">{ "Content Type" }</label>
+                        <div class="control is-expanded">
+                            {&r.resource_content_type}
+                            {
+                                r.cover_rendition
+                                    .map(|rendition| format!(" ({rendition})"))
+                                    .unwrap_or_default()
+                            }
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Mode" }</label>
+                        <div class="control is-expanded">
+                            {&r.resource_mode}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "URL" }</label>
+                        <div class="control is-expanded">
+                            {&r.resource_url}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Caption" }</label>
+                        <div class="control is-expanded">
+                            {r.caption.clone().unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteSupportingResource(supporting_resource_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
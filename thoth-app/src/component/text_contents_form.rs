@@ -0,0 +1,461 @@
+use chrono::NaiveDate;
+use std::str::FromStr;
+use thoth_api::model::text_content::ContentAudience;
+use thoth_api::model::text_content::TextContent;
+use thoth_api::model::text_content::TextContentType;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormContentAudienceSelect;
+use crate::component::utils::FormDateInput;
+use crate::component::utils::FormTextContentTypeSelect;
+use crate::component::utils::FormTextInput;
+use crate::component::utils::FormTextarea;
+use crate::component::utils::FormUrlInput;
+use crate::models::text_content::create_text_content_mutation::CreateTextContentRequest;
+use crate::models::text_content::create_text_content_mutation::CreateTextContentRequestBody;
+use crate::models::text_content::create_text_content_mutation::PushActionCreateTextContent;
+use crate::models::text_content::create_text_content_mutation::PushCreateTextContent;
+use crate::models::text_content::create_text_content_mutation::Variables;
+use crate::models::text_content::delete_text_content_mutation::DeleteTextContentRequest;
+use crate::models::text_content::delete_text_content_mutation::DeleteTextContentRequestBody;
+use crate::models::text_content::delete_text_content_mutation::PushActionDeleteTextContent;
+use crate::models::text_content::delete_text_content_mutation::PushDeleteTextContent;
+use crate::models::text_content::delete_text_content_mutation::Variables as DeleteVariables;
+use crate::models::text_content::text_content_values_query::FetchActionTextContentValues;
+use crate::models::text_content::text_content_values_query::FetchTextContentValues;
+use crate::models::text_content::ContentAudienceValues;
+use crate::models::text_content::TextContentTypeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_TEXT_CONTENTS;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct TextContentsFormComponent {
+    data: TextContentsFormData,
+    new_text_content: TextContent,
+    show_add_form: bool,
+    fetch_text_content_values: FetchTextContentValues,
+    push_text_content: PushCreateTextContent,
+    delete_text_content: PushDeleteTextContent,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct TextContentsFormData {
+    text_types: Vec<TextContentTypeValues>,
+    content_audiences: Vec<ContentAudienceValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetTextContentValuesFetchState(FetchActionTextContentValues),
+    GetTextContentValues,
+    SetTextContentPushState(PushActionCreateTextContent),
+    CreateTextContent,
+    SetTextContentDeleteState(PushActionDeleteTextContent),
+    DeleteTextContent(Uuid),
+    ChangeTextType(TextContentType),
+    ChangeContentAudience(ContentAudience),
+    ChangeContent(String),
+    ChangeTextAuthor(String),
+    ChangeSourceTitle(String),
+    ChangeSourceUrl(String),
+    ChangeContentDate(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub text_contents: Option<Vec<TextContent>>,
+    pub work_id: Uuid,
+    pub update_text_contents: Callback<Option<Vec<TextContent>>>,
+}
+
+impl Component for TextContentsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: TextContentsFormData = Default::default();
+        let show_add_form = false;
+        let new_text_content: TextContent = Default::default();
+        let fetch_text_content_values = Default::default();
+        let push_text_content = Default::default();
+        let delete_text_content = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetTextContentValues);
+
+        TextContentsFormComponent {
+            data,
+            new_text_content,
+            show_add_form,
+            fetch_text_content_values,
+            push_text_content,
+            delete_text_content,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetTextContentValuesFetchState(fetch_state) => {
+                self.fetch_text_content_values.apply(fetch_state);
+                match self.fetch_text_content_values.as_ref().state() {
+                    FetchState::Fetched(body) => {
+                        self.data.text_types = body.data.text_types.enum_values.clone();
+                        self.data.content_audiences =
+                            body.data.content_audiences.enum_values.clone();
+                    }
+                    _ => {
+                        self.data.text_types = vec![];
+                        self.data.content_audiences = vec![];
+                    }
+                }
+                true
+            }
+            Msg::GetTextContentValues => {
+                ctx.link().send_future(
+                    self.fetch_text_content_values
+                        .fetch(Msg::SetTextContentValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetTextContentValuesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetTextContentPushState(fetch_state) => {
+                self.push_text_content.apply(fetch_state);
+                match self.push_text_content.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_text_content {
+                        Some(t) => {
+                            let text_content = t.clone();
+                            let mut text_contents: Vec<TextContent> =
+                                ctx.props().text_contents.clone().unwrap_or_default();
+                            text_contents.push(text_content);
+                            ctx.props().update_text_contents.emit(Some(text_contents));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateTextContent => {
+                let body = CreateTextContentRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        text_type: self.new_text_content.text_type,
+                        content_audience: self.new_text_content.content_audience,
+                        content: self.new_text_content.content.clone(),
+                        text_author: self.new_text_content.text_author.clone(),
+                        source_title: self.new_text_content.source_title.clone(),
+                        source_url: self.new_text_content.source_url.clone(),
+                        content_date: self.new_text_content.content_date,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateTextContentRequest { body };
+                self.push_text_content = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_text_content.fetch(Msg::SetTextContentPushState));
+                ctx.link()
+                    .send_message(Msg::SetTextContentPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetTextContentDeleteState(fetch_state) => {
+                self.delete_text_content.apply(fetch_state);
+                match self.delete_text_content.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_text_content {
+                        Some(text_content) => {
+                            let to_keep: Vec<TextContent> = ctx
+                                .props()
+                                .text_contents
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|t| t.text_content_id != text_content.text_content_id)
+                                .collect();
+                            ctx.props().update_text_contents.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteTextContent(text_content_id) => {
+                let body = DeleteTextContentRequestBody {
+                    variables: DeleteVariables { text_content_id },
+                    ..Default::default()
+                };
+                let request = DeleteTextContentRequest { body };
+                self.delete_text_content = Fetch::new(request);
+                ctx.link().send_future(
+                    self.delete_text_content
+                        .fetch(Msg::SetTextContentDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetTextContentDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeTextType(text_type) => self.new_text_content.text_type.neq_assign(text_type),
+            Msg::ChangeContentAudience(content_audience) => self
+                .new_text_content
+                .content_audience
+                .neq_assign(content_audience),
+            Msg::ChangeContent(value) => self
+                .new_text_content
+                .content
+                .neq_assign(value.trim().to_owned()),
+            Msg::ChangeTextAuthor(value) => self
+                .new_text_content
+                .text_author
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeSourceTitle(value) => self
+                .new_text_content
+                .source_title
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeSourceUrl(value) => self
+                .new_text_content
+                .source_url
+                .neq_assign(value.to_opt_string()),
+            Msg::ChangeContentDate(value) => self
+                .new_text_content
+                .content_date
+                .neq_assign(NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let text_contents = ctx.props().text_contents.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Reviews and Endorsements" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Text" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Text" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="text-contents-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateTextContent
+                            }) }
+                            >
+                                <FormTextContentTypeSelect
+                                    label = "Text Type"
+                                    value={ self.new_text_content.text_type }
+                                    data={ self.data.text_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeTextType(TextContentType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormContentAudienceSelect
+                                    label = "Audience"
+                                    value={ self.new_text_content.content_audience }
+                                    data={ self.data.content_audiences.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeContentAudience(ContentAudience::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormTextarea
+                                    label = "Text"
+                                    value={ self.new_text_content.content.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeContent(e.to_value())) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Author"
+                                    value={ self.new_text_content.text_author.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTextAuthor(e.to_value())) }
+                                />
+                                <FormTextInput
+                                    label = "Source Title"
+                                    value={ self.new_text_content.source_title.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSourceTitle(e.to_value())) }
+                                />
+                                <FormUrlInput
+                                    label = "Source URL"
+                                    value={ self.new_text_content.source_url.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSourceUrl(e.to_value())) }
+                                />
+                                <FormDateInput
+                                    label = "Date"
+                                    value={ self.new_text_content.content_date.to_value() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeContentDate(e.to_value())) }
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="text-contents-form"
+                            >
+                                { "Add Text" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !text_contents.is_empty() {
+                        html!{{for text_contents.iter().map(|t| self.render_text_content(ctx, t))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_TEXT_CONTENTS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl TextContentsFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_text_content(&self, ctx: &Context<Self>, t: &TextContent) -> Html {
+        let text_content_id = t.text_content_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-quote-left" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 10em;">
+                        <label class="label">{ "Text Type" }</label>
+                        <div class="control is-expanded">
+                            {&t.text_type}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Audience" }</label>
+                        <div class="control is-expanded">
+                            {&t.content_audience}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Text" }</label>
+                        <div class="control is-expanded">
+                            {&t.content}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Source" }</label>
+                        <div class="control is-expanded">
+                            {
+                                [t.text_author.as_deref(), t.source_title.as_deref()]
+                                    .into_iter()
+                                    .flatten()
+                                    .collect::<Vec<&str>>()
+                                    .join(", ")
+                            }
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteTextContent(text_content_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use std::str::FromStr;
+use thoth_api::model::usage_constraint::UsageConstraint;
+use thoth_api::model::usage_constraint::UsageStatus;
+use thoth_api::model::usage_constraint::UsageType;
+use thoth_api::model::usage_constraint::UsageUnit;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormNumberInput;
+use crate::component::utils::FormOptionalUsageUnitSelect;
+use crate::component::utils::FormUsageStatusSelect;
+use crate::component::utils::FormUsageTypeSelect;
+use crate::models::usage_constraint::create_usage_constraint_mutation::CreateUsageConstraintRequest;
+use crate::models::usage_constraint::create_usage_constraint_mutation::CreateUsageConstraintRequestBody;
+use crate::models::usage_constraint::create_usage_constraint_mutation::PushActionCreateUsageConstraint;
+use crate::models::usage_constraint::create_usage_constraint_mutation::PushCreateUsageConstraint;
+use crate::models::usage_constraint::create_usage_constraint_mutation::Variables;
+use crate::models::usage_constraint::delete_usage_constraint_mutation::DeleteUsageConstraintRequest;
+use crate::models::usage_constraint::delete_usage_constraint_mutation::DeleteUsageConstraintRequestBody;
+use crate::models::usage_constraint::delete_usage_constraint_mutation::PushActionDeleteUsageConstraint;
+use crate::models::usage_constraint::delete_usage_constraint_mutation::PushDeleteUsageConstraint;
+use crate::models::usage_constraint::delete_usage_constraint_mutation::Variables as DeleteVariables;
+use crate::models::usage_constraint::usage_constraint_values_query::FetchActionUsageConstraintValues;
+use crate::models::usage_constraint::usage_constraint_values_query::FetchUsageConstraintValues;
+use crate::models::usage_constraint::UsageStatusValues;
+use crate::models::usage_constraint::UsageTypeValues;
+use crate::models::usage_constraint::UsageUnitValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_USAGE_CONSTRAINTS;
+use crate::string::REMOVE_BUTTON;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct UsageConstraintsFormComponent {
+    data: UsageConstraintsFormData,
+    new_usage_constraint: UsageConstraint,
+    show_add_form: bool,
+    fetch_usage_constraint_values: FetchUsageConstraintValues,
+    push_usage_constraint: PushCreateUsageConstraint,
+    delete_usage_constraint: PushDeleteUsageConstraint,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct UsageConstraintsFormData {
+    usage_types: Vec<UsageTypeValues>,
+    usage_statuses: Vec<UsageStatusValues>,
+    usage_units: Vec<UsageUnitValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetUsageConstraintValuesFetchState(FetchActionUsageConstraintValues),
+    GetUsageConstraintValues,
+    SetUsageConstraintPushState(PushActionCreateUsageConstraint),
+    CreateUsageConstraint,
+    SetUsageConstraintDeleteState(PushActionDeleteUsageConstraint),
+    DeleteUsageConstraint(Uuid),
+    ChangeUsageType(UsageType),
+    ChangeUsageStatus(UsageStatus),
+    ChangeUsageLimit(String),
+    ChangeUsageUnit(String),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub usage_constraints: Option<Vec<UsageConstraint>>,
+    pub publication_id: Uuid,
+    pub update_usage_constraints: Callback<Option<Vec<UsageConstraint>>>,
+}
+
+impl Component for UsageConstraintsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: UsageConstraintsFormData = Default::default();
+        let show_add_form = false;
+        let new_usage_constraint: UsageConstraint = Default::default();
+        let fetch_usage_constraint_values = Default::default();
+        let push_usage_constraint = Default::default();
+        let delete_usage_constraint = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetUsageConstraintValues);
+
+        UsageConstraintsFormComponent {
+            data,
+            new_usage_constraint,
+            show_add_form,
+            fetch_usage_constraint_values,
+            push_usage_constraint,
+            delete_usage_constraint,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetUsageConstraintValuesFetchState(fetch_state) => {
+                self.fetch_usage_constraint_values.apply(fetch_state);
+                if let FetchState::Fetched(body) =
+                    self.fetch_usage_constraint_values.as_ref().state()
+                {
+                    let values = &body.data;
+                    self.data.usage_types = values.usage_types.enum_values.clone();
+                    self.data.usage_statuses = values.usage_statuses.enum_values.clone();
+                    self.data.usage_units = values.usage_units.enum_values.clone();
+                }
+                true
+            }
+            Msg::GetUsageConstraintValues => {
+                ctx.link().send_future(
+                    self.fetch_usage_constraint_values
+                        .fetch(Msg::SetUsageConstraintValuesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetUsageConstraintValuesFetchState(
+                        FetchAction::Fetching,
+                    ));
+                false
+            }
+            Msg::SetUsageConstraintPushState(fetch_state) => {
+                self.push_usage_constraint.apply(fetch_state);
+                match self.push_usage_constraint.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_usage_constraint {
+                        Some(u) => {
+                            let mut usage_constraints: Vec<UsageConstraint> =
+                                ctx.props().usage_constraints.clone().unwrap_or_default();
+                            usage_constraints.push(u.clone());
+                            ctx.props()
+                                .update_usage_constraints
+                                .emit(Some(usage_constraints));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateUsageConstraint => {
+                // A limit is only meaningful for usages permitted subject to a limit
+                if self.new_usage_constraint.usage_status != UsageStatus::PermittedSubjectToLimit {
+                    self.new_usage_constraint.usage_limit = None;
+                    self.new_usage_constraint.usage_unit = None;
+                }
+                let body = CreateUsageConstraintRequestBody {
+                    variables: Variables {
+                        publication_id: ctx.props().publication_id,
+                        usage_type: self.new_usage_constraint.usage_type,
+                        usage_status: self.new_usage_constraint.usage_status,
+                        usage_limit: self.new_usage_constraint.usage_limit,
+                        usage_unit: self.new_usage_constraint.usage_unit,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateUsageConstraintRequest { body };
+                self.push_usage_constraint = Fetch::new(request);
+                ctx.link().send_future(
+                    self.push_usage_constraint
+                        .fetch(Msg::SetUsageConstraintPushState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetUsageConstraintPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetUsageConstraintDeleteState(fetch_state) => {
+                self.delete_usage_constraint.apply(fetch_state);
+                match self.delete_usage_constraint.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_usage_constraint {
+                        Some(usage_constraint) => {
+                            let to_keep: Vec<UsageConstraint> = ctx
+                                .props()
+                                .usage_constraints
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|u| {
+                                    u.usage_constraint_id != usage_constraint.usage_constraint_id
+                                })
+                                .collect();
+                            ctx.props().update_usage_constraints.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteUsageConstraint(usage_constraint_id) => {
+                let body = DeleteUsageConstraintRequestBody {
+                    variables: DeleteVariables {
+                        usage_constraint_id,
+                    },
+                    ..Default::default()
+                };
+                let request = DeleteUsageConstraintRequest { body };
+                self.delete_usage_constraint = Fetch::new(request);
+                ctx.link().send_future(
+                    self.delete_usage_constraint
+                        .fetch(Msg::SetUsageConstraintDeleteState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetUsageConstraintDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeUsageType(usage_type) => {
+                self.new_usage_constraint.usage_type.neq_assign(usage_type)
+            }
+            Msg::ChangeUsageStatus(usage_status) => self
+                .new_usage_constraint
+                .usage_status
+                .neq_assign(usage_status),
+            Msg::ChangeUsageLimit(value) => self
+                .new_usage_constraint
+                .usage_limit
+                .neq_assign(value.to_opt_int()),
+            Msg::ChangeUsageUnit(value) => self
+                .new_usage_constraint
+                .usage_unit
+                .neq_assign(UsageUnit::from_str(&value).ok()),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let usage_constraints = ctx.props().usage_constraints.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Usage Constraints" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Usage Constraint" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Usage Constraint" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="usage-constraints-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateUsageConstraint
+                            }) }
+                            >
+                                <FormUsageTypeSelect
+                                    label = "Usage Type"
+                                    value={ self.new_usage_constraint.usage_type }
+                                    data={ self.data.usage_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeUsageType(UsageType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormUsageStatusSelect
+                                    label = "Usage Status"
+                                    value={ self.new_usage_constraint.usage_status }
+                                    data={ self.data.usage_statuses.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeUsageStatus(UsageStatus::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                {
+                                    // Limits can only be given for usages permitted subject to a limit.
+                                    if self.new_usage_constraint.usage_status == UsageStatus::PermittedSubjectToLimit {
+                                        html! {
+                                            <div class="field is-horizontal">
+                                                <div class="field-body">
+                                                    <FormNumberInput
+                                                        label = "Usage Limit"
+                                                        value={ self.new_usage_constraint.usage_limit }
+                                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeUsageLimit(e.to_value())) }
+                                                        required = true
+                                                        min={ "1".to_string() }
+                                                    />
+                                                    <FormOptionalUsageUnitSelect
+                                                        label = "Usage Unit"
+                                                        value={ self.new_usage_constraint.usage_unit }
+                                                        data={ self.data.usage_units.clone() }
+                                                        onchange={ ctx.link().callback(|e: Event| Msg::ChangeUsageUnit(e.to_value())) }
+                                                        required = true
+                                                    />
+                                                </div>
+                                            </div>
+                                        }
+                                    } else {
+                                        html!{}
+                                    }
+                                }
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="usage-constraints-form"
+                            >
+                                { "Add Usage Constraint" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !usage_constraints.is_empty() {
+                        html!{{for usage_constraints.iter().map(|u| self.render_usage_constraint(ctx, u))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_USAGE_CONSTRAINTS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl UsageConstraintsFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_usage_constraint(&self, ctx: &Context<Self>, u: &UsageConstraint) -> Html {
+        let usage_constraint_id = u.usage_constraint_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-lock" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Usage Type" }</label>
+                        <div class="control is-expanded">
+                            {&u.usage_type}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Usage Status" }</label>
+                        <div class="control is-expanded">
+                            {&u.usage_status}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Limit" }</label>
+                        <div class="control is-expanded">
+                            {&u.usage_limit.as_ref().map(|l| l.to_string()).unwrap_or_default()}
+                            {" "}
+                            {&u.usage_unit.as_ref().map(|l| l.to_string()).unwrap_or_default()}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteUsageConstraint(usage_constraint_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -1,17 +1,46 @@
+use thoth_api::model::alternative_name::NameType;
 use thoth_api::model::contribution::ContributionType;
 use thoth_api::model::contributor::Contributor;
+use thoth_api::model::identifier::IdentifierType;
 use thoth_api::model::imprint::ImprintWithPublisher;
 use thoth_api::model::institution::CountryCode;
 use thoth_api::model::language::LanguageCode;
 use thoth_api::model::language::LanguageRelation;
 use thoth_api::model::location::LocationPlatform;
 use thoth_api::model::price::CurrencyCode;
+use thoth_api::model::price::PriceQualifier;
+use thoth_api::model::price::PriceType;
+use thoth_api::model::prize::PrizeCode;
+use thoth_api::model::publication::AccessibilityFeature;
+use thoth_api::model::publication::AccessibilityHazard;
+use thoth_api::model::publication::AccessibilityStandard;
+use thoth_api::model::publication::BindingStyle;
+use thoth_api::model::publication::CoverFinish;
+use thoth_api::model::publication::DigitalLayout;
+use thoth_api::model::publication::InteriorColour;
+use thoth_api::model::publication::PaperType;
 use thoth_api::model::publication::PublicationType;
+use thoth_api::model::publication::TechnicalProtection;
+use thoth_api::model::publication::TrimCategory;
+use thoth_api::model::publication::WcagLevel;
+use thoth_api::model::publication::WcagVersion;
 use thoth_api::model::publisher::Publisher;
+use thoth_api::model::sales_rights::SalesRestrictionType;
+use thoth_api::model::sales_rights::SalesRightsType;
 use thoth_api::model::series::SeriesType;
+use thoth_api::model::series::SeriesWithImprint;
 use thoth_api::model::subject::SubjectType;
+use thoth_api::model::supporting_resource::ResourceContentType;
+use thoth_api::model::supporting_resource::ResourceForm;
+use thoth_api::model::supporting_resource::ResourceMode;
+use thoth_api::model::text_content::ContentAudience;
+use thoth_api::model::text_content::TextContentType;
+use thoth_api::model::usage_constraint::UsageStatus;
+use thoth_api::model::usage_constraint::UsageType;
+use thoth_api::model::usage_constraint::UsageUnit;
 use thoth_api::model::work::WorkStatus;
 use thoth_api::model::work::WorkType;
+use thoth_api::model::work_abstract::AbstractType;
 use thoth_api::model::work_relation::RelationType;
 use uuid::Uuid;
 use yew::function_component;
@@ -24,17 +53,45 @@ use yew::InputEvent;
 use yew::MouseEvent;
 use yew::Properties;
 
+use crate::models::alternative_name::NameTypeValues;
 use crate::models::contribution::ContributionTypeValues;
+use crate::models::identifier::IdentifierTypeValues;
 use crate::models::institution::CountryCodeValues;
 use crate::models::language::LanguageCodeValues;
 use crate::models::language::LanguageRelationValues;
 use crate::models::location::LocationPlatformValues;
 use crate::models::price::CurrencyCodeValues;
+use crate::models::price::PriceQualifierValues;
+use crate::models::price::PriceTypeValues;
+use crate::models::prize::PrizeCodeValues;
+use crate::models::publication::AccessibilityFeatureValues;
+use crate::models::publication::AccessibilityHazardValues;
+use crate::models::publication::AccessibilityStandardValues;
+use crate::models::publication::BindingStyleValues;
+use crate::models::publication::CoverFinishValues;
+use crate::models::publication::DigitalLayoutValues;
+use crate::models::publication::InteriorColourValues;
+use crate::models::publication::PaperTypeValues;
 use crate::models::publication::PublicationTypeValues;
+use crate::models::publication::TechnicalProtectionValues;
+use crate::models::publication::TrimCategoryValues;
+use crate::models::publication::WcagLevelValues;
+use crate::models::publication::WcagVersionValues;
+use crate::models::sales_rights::SalesRestrictionTypeValues;
+use crate::models::sales_rights::SalesRightsTypeValues;
 use crate::models::series::SeriesTypeValues;
 use crate::models::subject::SubjectTypeValues;
+use crate::models::supporting_resource::ResourceContentTypeValues;
+use crate::models::supporting_resource::ResourceFormValues;
+use crate::models::supporting_resource::ResourceModeValues;
+use crate::models::text_content::ContentAudienceValues;
+use crate::models::text_content::TextContentTypeValues;
+use crate::models::usage_constraint::UsageStatusValues;
+use crate::models::usage_constraint::UsageTypeValues;
+use crate::models::usage_constraint::UsageUnitValues;
 use crate::models::work::WorkStatusValues;
 use crate::models::work::WorkTypeValues;
+use crate::models::work_abstract::AbstractTypeValues;
 use crate::models::work_relation::RelationTypeValues;
 use crate::string::NO;
 use crate::string::RELOAD_BUTTON;
@@ -257,6 +314,16 @@ pub struct FormLanguageCodeSelectProps {
     pub required: bool,
 }
 
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalLanguageCodeSelectProps {
+    pub label: String,
+    pub data: Vec<LanguageCodeValues>,
+    pub value: Option<LanguageCode>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
 #[derive(PartialEq, Properties)]
 pub struct FormLanguageRelationSelectProps {
     pub label: String,
@@ -267,6 +334,56 @@ pub struct FormLanguageRelationSelectProps {
     pub required: bool,
 }
 
+#[derive(PartialEq, Properties)]
+pub struct FormAbstractTypeSelectProps {
+    pub label: String,
+    pub data: Vec<AbstractTypeValues>,
+    pub value: AbstractType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormIdentifierTypeSelectProps {
+    pub label: String,
+    pub data: Vec<IdentifierTypeValues>,
+    pub value: IdentifierType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormSalesRightsTypeSelectProps {
+    pub label: String,
+    pub data: Vec<SalesRightsTypeValues>,
+    pub value: SalesRightsType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalSalesRestrictionTypeSelectProps {
+    pub label: String,
+    pub data: Vec<SalesRestrictionTypeValues>,
+    pub value: Option<SalesRestrictionType>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormNameTypeSelectProps {
+    pub label: String,
+    pub data: Vec<NameTypeValues>,
+    pub value: NameType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
 #[derive(PartialEq, Properties)]
 pub struct FormCurrencyCodeSelectProps {
     pub label: String,
@@ -277,6 +394,26 @@ pub struct FormCurrencyCodeSelectProps {
     pub required: bool,
 }
 
+#[derive(PartialEq, Properties)]
+pub struct FormPriceTypeSelectProps {
+    pub label: String,
+    pub data: Vec<PriceTypeValues>,
+    pub value: PriceType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalPriceQualifierSelectProps {
+    pub label: String,
+    pub data: Vec<PriceQualifierValues>,
+    pub value: Option<PriceQualifier>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
 #[derive(PartialEq, Properties)]
 pub struct FormLocationPlatformSelectProps {
     pub label: String,
@@ -297,6 +434,226 @@ pub struct FormCountryCodeSelectProps {
     pub required: bool,
 }
 
+#[derive(PartialEq, Properties)]
+pub struct FormCountryCodeMultiSelectProps {
+    pub label: String,
+    pub data: Vec<CountryCodeValues>,
+    pub value: Vec<CountryCode>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormAccessibilityFeatureMultiSelectProps {
+    pub label: String,
+    pub data: Vec<AccessibilityFeatureValues>,
+    pub value: Vec<AccessibilityFeature>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormAccessibilityHazardMultiSelectProps {
+    pub label: String,
+    pub data: Vec<AccessibilityHazardValues>,
+    pub value: Vec<AccessibilityHazard>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalAccessibilityStandardSelectProps {
+    pub label: String,
+    pub data: Vec<AccessibilityStandardValues>,
+    pub value: Option<AccessibilityStandard>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalWcagVersionSelectProps {
+    pub label: String,
+    pub data: Vec<WcagVersionValues>,
+    pub value: Option<WcagVersion>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalWcagLevelSelectProps {
+    pub label: String,
+    pub data: Vec<WcagLevelValues>,
+    pub value: Option<WcagLevel>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalBindingStyleSelectProps {
+    pub label: String,
+    pub data: Vec<BindingStyleValues>,
+    pub value: Option<BindingStyle>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalPaperTypeSelectProps {
+    pub label: String,
+    pub data: Vec<PaperTypeValues>,
+    pub value: Option<PaperType>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalInteriorColourSelectProps {
+    pub label: String,
+    pub data: Vec<InteriorColourValues>,
+    pub value: Option<InteriorColour>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalTrimCategorySelectProps {
+    pub label: String,
+    pub data: Vec<TrimCategoryValues>,
+    pub value: Option<TrimCategory>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalCoverFinishSelectProps {
+    pub label: String,
+    pub data: Vec<CoverFinishValues>,
+    pub value: Option<CoverFinish>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormTechnicalProtectionMultiSelectProps {
+    pub label: String,
+    pub data: Vec<TechnicalProtectionValues>,
+    pub value: Vec<TechnicalProtection>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalDigitalLayoutSelectProps {
+    pub label: String,
+    pub data: Vec<DigitalLayoutValues>,
+    pub value: Option<DigitalLayout>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormUsageTypeSelectProps {
+    pub label: String,
+    pub data: Vec<UsageTypeValues>,
+    pub value: UsageType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormUsageStatusSelectProps {
+    pub label: String,
+    pub data: Vec<UsageStatusValues>,
+    pub value: UsageStatus,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormOptionalUsageUnitSelectProps {
+    pub label: String,
+    pub data: Vec<UsageUnitValues>,
+    pub value: Option<UsageUnit>,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormPrizeCodeSelectProps {
+    pub label: String,
+    pub data: Vec<PrizeCodeValues>,
+    pub value: PrizeCode,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormTextContentTypeSelectProps {
+    pub label: String,
+    pub data: Vec<TextContentTypeValues>,
+    pub value: TextContentType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormContentAudienceSelectProps {
+    pub label: String,
+    pub data: Vec<ContentAudienceValues>,
+    pub value: ContentAudience,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormResourceContentTypeSelectProps {
+    pub label: String,
+    pub data: Vec<ResourceContentTypeValues>,
+    pub value: ResourceContentType,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormResourceModeSelectProps {
+    pub label: String,
+    pub data: Vec<ResourceModeValues>,
+    pub value: ResourceMode,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct FormResourceFormSelectProps {
+    pub label: String,
+    pub data: Vec<ResourceFormValues>,
+    pub value: ResourceForm,
+    pub onchange: Callback<Event>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
 #[derive(PartialEq, Properties)]
 pub struct FormRelationTypeSelectProps {
     pub label: String,
@@ -328,6 +685,14 @@ pub struct FormImprintSelectProps {
     pub required: bool,
 }
 
+#[derive(PartialEq, Properties)]
+pub struct FormParentSeriesSelectProps {
+    pub label: String,
+    pub data: Vec<SeriesWithImprint>,
+    pub value: Option<Uuid>,
+    pub onchange: Callback<Event>,
+}
+
 #[derive(PartialEq, Properties)]
 pub struct FormPublisherSelectProps {
     pub label: String,
@@ -671,8 +1036,8 @@ pub fn form_language_code_select(props: &FormLanguageCodeSelectProps) -> VNode {
     }
 }
 
-#[function_component(FormLanguageRelationSelect)]
-pub fn form_language_relation_select(props: &FormLanguageRelationSelectProps) -> VNode {
+#[function_component(FormOptionalLanguageCodeSelect)]
+pub fn form_optional_language_code_select(props: &FormOptionalLanguageCodeSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -682,7 +1047,8 @@ pub fn form_language_relation_select(props: &FormLanguageRelationSelectProps) ->
                     required={ props.required }
                     onchange={ &props.onchange }
                 >
-                    { for props.data.iter().map(|l| props.render_languagerelation(l)) }
+                    <option value="">{"Select Language"}</option>
+                    { for props.data.iter().map(|l| props.render_languagecode(l)) }
                 </select>
                 </div>
             </div>
@@ -690,8 +1056,8 @@ pub fn form_language_relation_select(props: &FormLanguageRelationSelectProps) ->
     }
 }
 
-#[function_component(FormCurrencyCodeSelect)]
-pub fn form_currency_code_select(props: &FormCurrencyCodeSelectProps) -> VNode {
+#[function_component(FormLanguageRelationSelect)]
+pub fn form_language_relation_select(props: &FormLanguageRelationSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -701,7 +1067,7 @@ pub fn form_currency_code_select(props: &FormCurrencyCodeSelectProps) -> VNode {
                     required={ props.required }
                     onchange={ &props.onchange }
                 >
-                    { for props.data.iter().map(|c| props.render_currencycode(c)) }
+                    { for props.data.iter().map(|l| props.render_languagerelation(l)) }
                 </select>
                 </div>
             </div>
@@ -709,8 +1075,8 @@ pub fn form_currency_code_select(props: &FormCurrencyCodeSelectProps) -> VNode {
     }
 }
 
-#[function_component(FormLocationPlatformSelect)]
-pub fn form_location_platform_select(props: &FormLocationPlatformSelectProps) -> VNode {
+#[function_component(FormAbstractTypeSelect)]
+pub fn form_abstract_type_select(props: &FormAbstractTypeSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -720,7 +1086,7 @@ pub fn form_location_platform_select(props: &FormLocationPlatformSelectProps) ->
                     required={ props.required }
                     onchange={ &props.onchange }
                 >
-                    { for props.data.iter().map(|l| props.render_locationplatform(l)) }
+                    { for props.data.iter().map(|a| props.render_abstracttype(a)) }
                 </select>
                 </div>
             </div>
@@ -728,8 +1094,8 @@ pub fn form_location_platform_select(props: &FormLocationPlatformSelectProps) ->
     }
 }
 
-#[function_component(FormCountryCodeSelect)]
-pub fn form_country_code_select(props: &FormCountryCodeSelectProps) -> VNode {
+#[function_component(FormIdentifierTypeSelect)]
+pub fn form_identifier_type_select(props: &FormIdentifierTypeSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -739,8 +1105,7 @@ pub fn form_country_code_select(props: &FormCountryCodeSelectProps) -> VNode {
                     required={ props.required }
                     onchange={ &props.onchange }
                 >
-                    <option value="">{"Select Country"}</option>
-                    { for props.data.iter().map(|c| props.render_countrycode(c)) }
+                    { for props.data.iter().map(|i| props.render_identifiertype(i)) }
                 </select>
                 </div>
             </div>
@@ -748,8 +1113,8 @@ pub fn form_country_code_select(props: &FormCountryCodeSelectProps) -> VNode {
     }
 }
 
-#[function_component(FormRelationTypeSelect)]
-pub fn form_relation_type_select(props: &FormRelationTypeSelectProps) -> VNode {
+#[function_component(FormSalesRightsTypeSelect)]
+pub fn form_sales_rights_type_select(props: &FormSalesRightsTypeSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -759,7 +1124,7 @@ pub fn form_relation_type_select(props: &FormRelationTypeSelectProps) -> VNode {
                     required={ props.required }
                     onchange={ &props.onchange }
                 >
-                    { for props.data.iter().map(|r| props.render_relationtype(r)) }
+                    { for props.data.iter().map(|s| props.render_salesrightstype(s)) }
                 </select>
                 </div>
             </div>
@@ -767,8 +1132,10 @@ pub fn form_relation_type_select(props: &FormRelationTypeSelectProps) -> VNode {
     }
 }
 
-#[function_component(FormBooleanSelect)]
-pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
+#[function_component(FormOptionalSalesRestrictionTypeSelect)]
+pub fn form_optional_sales_restriction_type_select(
+    props: &FormOptionalSalesRestrictionTypeSelectProps,
+) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
@@ -777,14 +1144,9 @@ pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
                 <select
                     required={ props.required }
                     onchange={ &props.onchange }
-                    onblur={ props.onblur.clone() }
                 >
-                    <option value={ true.to_string() } selected={ props.value }>
-                        { YES }
-                    </option>
-                    <option value={ false.to_string() } selected={ !props.value }>
-                        { NO }
-                    </option>
+                    <option value="">{"No Restriction"}</option>
+                    { for props.data.iter().map(|s| props.render_salesrestrictiontype(s)) }
                 </select>
                 </div>
             </div>
@@ -792,19 +1154,644 @@ pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
     }
 }
 
-#[function_component(FormImprintSelect)]
-pub fn form_imprint_select(props: &FormImprintSelectProps) -> VNode {
+#[function_component(FormNameTypeSelect)]
+pub fn form_name_type_select(props: &FormNameTypeSelectProps) -> VNode {
     html! {
         <div class="field">
             <label class="label">{ &props.label }</label>
             <div class="control is-expanded">
-                <div class="select is-fullwidth">
-                <select required={ props.required } onchange={ &props.onchange }>
-                    <option value="">{"Select Imprint"}</option>
-                    { for props.data.iter().map(|i| props.render_imprint(i)) }
-                </select>
-                </div>
-            </div>
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|n| props.render_nametype(n)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormCurrencyCodeSelect)]
+pub fn form_currency_code_select(props: &FormCurrencyCodeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|c| props.render_currencycode(c)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormPriceTypeSelect)]
+pub fn form_price_type_select(props: &FormPriceTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|p| props.render_pricetype(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalPriceQualifierSelect)]
+pub fn form_optional_price_qualifier_select(
+    props: &FormOptionalPriceQualifierSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"Any Buyer"}</option>
+                    { for props.data.iter().map(|p| props.render_pricequalifier(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormLocationPlatformSelect)]
+pub fn form_location_platform_select(props: &FormLocationPlatformSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|l| props.render_locationplatform(l)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormCountryCodeSelect)]
+pub fn form_country_code_select(props: &FormCountryCodeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"Select Country"}</option>
+                    { for props.data.iter().map(|c| props.render_countrycode(c)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormCountryCodeMultiSelect)]
+pub fn form_country_code_multi_select(props: &FormCountryCodeMultiSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-multiple">
+                <select
+                    multiple=true
+                    size="6"
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|c| props.render_countrycode(c)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormAccessibilityFeatureMultiSelect)]
+pub fn form_accessibility_feature_multi_select(
+    props: &FormAccessibilityFeatureMultiSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-multiple">
+                <select
+                    multiple=true
+                    size="6"
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|a| props.render_accessibilityfeature(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormAccessibilityHazardMultiSelect)]
+pub fn form_accessibility_hazard_multi_select(
+    props: &FormAccessibilityHazardMultiSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-multiple">
+                <select
+                    multiple=true
+                    size="6"
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|a| props.render_accessibilityhazard(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalAccessibilityStandardSelect)]
+pub fn form_optional_accessibility_standard_select(
+    props: &FormOptionalAccessibilityStandardSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Conformance Claim"}</option>
+                    { for props.data.iter().map(|a| props.render_accessibilitystandard(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalWcagVersionSelect)]
+pub fn form_optional_wcag_version_select(props: &FormOptionalWcagVersionSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No WCAG Version"}</option>
+                    { for props.data.iter().map(|a| props.render_wcagversion(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalWcagLevelSelect)]
+pub fn form_optional_wcag_level_select(props: &FormOptionalWcagLevelSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No WCAG Level"}</option>
+                    { for props.data.iter().map(|a| props.render_wcaglevel(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalBindingStyleSelect)]
+pub fn form_optional_binding_style_select(props: &FormOptionalBindingStyleSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Binding Specified"}</option>
+                    { for props.data.iter().map(|p| props.render_bindingstyle(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalPaperTypeSelect)]
+pub fn form_optional_paper_type_select(props: &FormOptionalPaperTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Paper Specified"}</option>
+                    { for props.data.iter().map(|p| props.render_papertype(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalInteriorColourSelect)]
+pub fn form_optional_interior_colour_select(
+    props: &FormOptionalInteriorColourSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Colour Specified"}</option>
+                    { for props.data.iter().map(|p| props.render_interiorcolour(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalTrimCategorySelect)]
+pub fn form_optional_trim_category_select(props: &FormOptionalTrimCategorySelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Trim Specified"}</option>
+                    { for props.data.iter().map(|p| props.render_trimcategory(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalCoverFinishSelect)]
+pub fn form_optional_cover_finish_select(props: &FormOptionalCoverFinishSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Finish Specified"}</option>
+                    { for props.data.iter().map(|p| props.render_coverfinish(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormTechnicalProtectionMultiSelect)]
+pub fn form_technical_protection_multi_select(
+    props: &FormTechnicalProtectionMultiSelectProps,
+) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-multiple">
+                <select
+                    multiple=true
+                    size="4"
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|t| props.render_technicalprotection(t)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalDigitalLayoutSelect)]
+pub fn form_optional_digital_layout_select(props: &FormOptionalDigitalLayoutSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Layout Specified"}</option>
+                    { for props.data.iter().map(|l| props.render_digitallayout(l)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormUsageTypeSelect)]
+pub fn form_usage_type_select(props: &FormUsageTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|u| props.render_usagetype(u)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormUsageStatusSelect)]
+pub fn form_usage_status_select(props: &FormUsageStatusSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|u| props.render_usagestatus(u)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormOptionalUsageUnitSelect)]
+pub fn form_optional_usage_unit_select(props: &FormOptionalUsageUnitSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    <option value="">{"No Unit Specified"}</option>
+                    { for props.data.iter().map(|u| props.render_usageunit(u)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormPrizeCodeSelect)]
+pub fn form_prize_code_select(props: &FormPrizeCodeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|p| props.render_prizecode(p)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormTextContentTypeSelect)]
+pub fn form_text_content_type_select(props: &FormTextContentTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|t| props.render_textcontenttype(t)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormContentAudienceSelect)]
+pub fn form_content_audience_select(props: &FormContentAudienceSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|a| props.render_contentaudience(a)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormResourceContentTypeSelect)]
+pub fn form_resource_content_type_select(props: &FormResourceContentTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|t| props.render_resourcecontenttype(t)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormResourceModeSelect)]
+pub fn form_resource_mode_select(props: &FormResourceModeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|m| props.render_resourcemode(m)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormResourceFormSelect)]
+pub fn form_resource_form_select(props: &FormResourceFormSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|f| props.render_resourceform(f)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormRelationTypeSelect)]
+pub fn form_relation_type_select(props: &FormRelationTypeSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                >
+                    { for props.data.iter().map(|r| props.render_relationtype(r)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormBooleanSelect)]
+pub fn form_boolean_select(props: &FormBooleanSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select">
+                <select
+                    required={ props.required }
+                    onchange={ &props.onchange }
+                    onblur={ props.onblur.clone() }
+                >
+                    <option value={ true.to_string() } selected={ props.value }>
+                        { YES }
+                    </option>
+                    <option value={ false.to_string() } selected={ !props.value }>
+                        { NO }
+                    </option>
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormImprintSelect)]
+pub fn form_imprint_select(props: &FormImprintSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select required={ props.required } onchange={ &props.onchange }>
+                    <option value="">{"Select Imprint"}</option>
+                    { for props.data.iter().map(|i| props.render_imprint(i)) }
+                </select>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[function_component(FormParentSeriesSelect)]
+pub fn form_parent_series_select(props: &FormParentSeriesSelectProps) -> VNode {
+    html! {
+        <div class="field">
+            <label class="label">{ &props.label }</label>
+            <div class="control is-expanded">
+                <div class="select is-fullwidth">
+                <select onchange={ &props.onchange }>
+                    <option value="" selected={props.value.is_none()}>{"No parent series"}</option>
+                    { for props.data.iter().map(|s| props.render_series(s)) }
+                </select>
+                </div>
+            </div>
         </div>
     }
 }
@@ -915,6 +1902,22 @@ impl FormLanguageCodeSelectProps {
     }
 }
 
+impl FormOptionalLanguageCodeSelectProps {
+    fn render_languagecode(&self, l: &LanguageCodeValues) -> VNode {
+        if Some(l.name) == self.value {
+            html! {
+                <option value={l.name.to_string()} selected={ true }>
+                    {&l.name}
+                </option>
+            }
+        } else {
+            html! {
+                <option value={l.name.to_string()}>{&l.name}</option>
+            }
+        }
+    }
+}
+
 impl FormLanguageRelationSelectProps {
     fn render_languagerelation(&self, l: &LanguageRelationValues) -> VNode {
         html! {
@@ -925,6 +1928,56 @@ impl FormLanguageRelationSelectProps {
     }
 }
 
+impl FormAbstractTypeSelectProps {
+    fn render_abstracttype(&self, a: &AbstractTypeValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={a.name == self.value}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormIdentifierTypeSelectProps {
+    fn render_identifiertype(&self, i: &IdentifierTypeValues) -> VNode {
+        html! {
+            <option value={i.name.to_string()} selected={i.name == self.value}>
+                {&i.name}
+            </option>
+        }
+    }
+}
+
+impl FormSalesRightsTypeSelectProps {
+    fn render_salesrightstype(&self, s: &SalesRightsTypeValues) -> VNode {
+        html! {
+            <option value={s.name.to_string()} selected={s.name == self.value}>
+                {&s.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalSalesRestrictionTypeSelectProps {
+    fn render_salesrestrictiontype(&self, s: &SalesRestrictionTypeValues) -> VNode {
+        html! {
+            <option value={s.name.to_string()} selected={Some(s.name) == self.value}>
+                {&s.name}
+            </option>
+        }
+    }
+}
+
+impl FormNameTypeSelectProps {
+    fn render_nametype(&self, n: &NameTypeValues) -> VNode {
+        html! {
+            <option value={n.name.to_string()} selected={n.name == self.value}>
+                {&n.name}
+            </option>
+        }
+    }
+}
+
 impl FormCurrencyCodeSelectProps {
     fn render_currencycode(&self, c: &CurrencyCodeValues) -> VNode {
         html! {
@@ -935,6 +1988,26 @@ impl FormCurrencyCodeSelectProps {
     }
 }
 
+impl FormPriceTypeSelectProps {
+    fn render_pricetype(&self, p: &PriceTypeValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={p.name == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalPriceQualifierSelectProps {
+    fn render_pricequalifier(&self, p: &PriceQualifierValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
 impl FormLocationPlatformSelectProps {
     fn render_locationplatform(&self, l: &LocationPlatformValues) -> VNode {
         html! {
@@ -961,6 +2034,226 @@ impl FormCountryCodeSelectProps {
     }
 }
 
+impl FormCountryCodeMultiSelectProps {
+    fn render_countrycode(&self, c: &CountryCodeValues) -> VNode {
+        html! {
+            <option value={c.name.to_string()} selected={self.value.contains(&c.name)}>
+                {&c.name}
+            </option>
+        }
+    }
+}
+
+impl FormAccessibilityFeatureMultiSelectProps {
+    fn render_accessibilityfeature(&self, a: &AccessibilityFeatureValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={self.value.contains(&a.name)}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormAccessibilityHazardMultiSelectProps {
+    fn render_accessibilityhazard(&self, a: &AccessibilityHazardValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={self.value.contains(&a.name)}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalAccessibilityStandardSelectProps {
+    fn render_accessibilitystandard(&self, a: &AccessibilityStandardValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={Some(a.name) == self.value}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalWcagVersionSelectProps {
+    fn render_wcagversion(&self, a: &WcagVersionValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={Some(a.name) == self.value}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalWcagLevelSelectProps {
+    fn render_wcaglevel(&self, a: &WcagLevelValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={Some(a.name) == self.value}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalBindingStyleSelectProps {
+    fn render_bindingstyle(&self, p: &BindingStyleValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalPaperTypeSelectProps {
+    fn render_papertype(&self, p: &PaperTypeValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalInteriorColourSelectProps {
+    fn render_interiorcolour(&self, p: &InteriorColourValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalTrimCategorySelectProps {
+    fn render_trimcategory(&self, p: &TrimCategoryValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalCoverFinishSelectProps {
+    fn render_coverfinish(&self, p: &CoverFinishValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={Some(p.name) == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormTechnicalProtectionMultiSelectProps {
+    fn render_technicalprotection(&self, t: &TechnicalProtectionValues) -> VNode {
+        html! {
+            <option value={t.name.to_string()} selected={self.value.contains(&t.name)}>
+                {&t.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalDigitalLayoutSelectProps {
+    fn render_digitallayout(&self, l: &DigitalLayoutValues) -> VNode {
+        html! {
+            <option value={l.name.to_string()} selected={Some(l.name) == self.value}>
+                {&l.name}
+            </option>
+        }
+    }
+}
+
+impl FormUsageTypeSelectProps {
+    fn render_usagetype(&self, u: &UsageTypeValues) -> VNode {
+        html! {
+            <option value={u.name.to_string()} selected={u.name == self.value}>
+                {&u.name}
+            </option>
+        }
+    }
+}
+
+impl FormUsageStatusSelectProps {
+    fn render_usagestatus(&self, u: &UsageStatusValues) -> VNode {
+        html! {
+            <option value={u.name.to_string()} selected={u.name == self.value}>
+                {&u.name}
+            </option>
+        }
+    }
+}
+
+impl FormOptionalUsageUnitSelectProps {
+    fn render_usageunit(&self, u: &UsageUnitValues) -> VNode {
+        html! {
+            <option value={u.name.to_string()} selected={Some(u.name) == self.value}>
+                {&u.name}
+            </option>
+        }
+    }
+}
+
+impl FormPrizeCodeSelectProps {
+    fn render_prizecode(&self, p: &PrizeCodeValues) -> VNode {
+        html! {
+            <option value={p.name.to_string()} selected={p.name == self.value}>
+                {&p.name}
+            </option>
+        }
+    }
+}
+
+impl FormTextContentTypeSelectProps {
+    fn render_textcontenttype(&self, t: &TextContentTypeValues) -> VNode {
+        html! {
+            <option value={t.name.to_string()} selected={t.name == self.value}>
+                {&t.name}
+            </option>
+        }
+    }
+}
+
+impl FormContentAudienceSelectProps {
+    fn render_contentaudience(&self, a: &ContentAudienceValues) -> VNode {
+        html! {
+            <option value={a.name.to_string()} selected={a.name == self.value}>
+                {&a.name}
+            </option>
+        }
+    }
+}
+
+impl FormResourceContentTypeSelectProps {
+    fn render_resourcecontenttype(&self, t: &ResourceContentTypeValues) -> VNode {
+        html! {
+            <option value={t.name.to_string()} selected={t.name == self.value}>
+                {&t.name}
+            </option>
+        }
+    }
+}
+
+impl FormResourceModeSelectProps {
+    fn render_resourcemode(&self, m: &ResourceModeValues) -> VNode {
+        html! {
+            <option value={m.name.to_string()} selected={m.name == self.value}>
+                {&m.name}
+            </option>
+        }
+    }
+}
+
+impl FormResourceFormSelectProps {
+    fn render_resourceform(&self, f: &ResourceFormValues) -> VNode {
+        html! {
+            <option value={f.name.to_string()} selected={f.name == self.value}>
+                {&f.name}
+            </option>
+        }
+    }
+}
+
 impl FormRelationTypeSelectProps {
     fn render_relationtype(&self, r: &RelationTypeValues) -> VNode {
         html! {
@@ -982,6 +2275,16 @@ impl FormImprintSelectProps {
     }
 }
 
+impl FormParentSeriesSelectProps {
+    fn render_series(&self, s: &SeriesWithImprint) -> VNode {
+        html! {
+            <option value={s.series_id.to_string()} selected={Some(s.series_id) == self.value}>
+                {&s.series_name}
+            </option>
+        }
+    }
+}
+
 impl FormPublisherSelectProps {
     fn render_publisher(&self, p: &Publisher) -> VNode {
         let value = &self.value.unwrap_or_default();
@@ -3,18 +3,25 @@ use std::str::FromStr;
 use thoth_api::account::model::AccountAccess;
 use thoth_api::account::model::AccountDetails;
 use thoth_api::model::contribution::Contribution;
+use thoth_api::model::event::EventWithSponsors;
 use thoth_api::model::funding::FundingWithInstitution;
 use thoth_api::model::imprint::ImprintWithPublisher;
 use thoth_api::model::issue::IssueWithSeries;
 use thoth_api::model::language::Language;
+use thoth_api::model::prize::Prize;
 use thoth_api::model::publication::Publication;
 use thoth_api::model::reference::Reference;
 use thoth_api::model::subject::Subject;
+use thoth_api::model::supporting_resource::SupportingResource;
+use thoth_api::model::text_content::TextContent;
 use thoth_api::model::work::WorkProperties;
 use thoth_api::model::work::WorkStatus;
 use thoth_api::model::work::WorkType;
 use thoth_api::model::work::WorkWithRelations;
+use thoth_api::model::work_abstract::WorkAbstract;
+use thoth_api::model::work_identifier::WorkIdentifier;
 use thoth_api::model::work_relation::WorkRelationWithRelatedWork;
+use thoth_api::model::work_title::WorkTitle;
 use thoth_api::model::{Doi, DOI_DOMAIN};
 use thoth_errors::ThothError;
 use uuid::Uuid;
@@ -34,14 +41,18 @@ use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::contributions_form::ContributionsFormComponent;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
+use crate::component::events_form::EventsFormComponent;
 use crate::component::fundings_form::FundingsFormComponent;
 use crate::component::issues_form::IssuesFormComponent;
 use crate::component::languages_form::LanguagesFormComponent;
 use crate::component::new_chapter::NewChapterComponent;
+use crate::component::prizes_form::PrizesFormComponent;
 use crate::component::publications_form::PublicationsFormComponent;
 use crate::component::references_form::ReferencesFormComponent;
 use crate::component::related_works_form::RelatedWorksFormComponent;
 use crate::component::subjects_form::SubjectsFormComponent;
+use crate::component::supporting_resources_form::SupportingResourcesFormComponent;
+use crate::component::text_contents_form::TextContentsFormComponent;
 use crate::component::utils::FormDateInput;
 use crate::component::utils::FormImprintSelect;
 use crate::component::utils::FormNumberInput;
@@ -52,7 +63,10 @@ use crate::component::utils::FormUrlInput;
 use crate::component::utils::FormWorkStatusSelect;
 use crate::component::utils::FormWorkTypeSelect;
 use crate::component::utils::Loader;
+use crate::component::work_abstracts_form::WorkAbstractsFormComponent;
+use crate::component::work_identifiers_form::WorkIdentifiersFormComponent;
 use crate::component::work_status_modal::ConfirmWorkStatusComponent;
+use crate::component::work_titles_form::WorkTitlesFormComponent;
 use crate::models::work::delete_work_mutation::DeleteWorkRequest;
 use crate::models::work::delete_work_mutation::DeleteWorkRequestBody;
 use crate::models::work::delete_work_mutation::PushActionDeleteWork;
@@ -130,6 +144,7 @@ pub enum Msg {
     ChangePageBreakdown(String),
     ChangeFirstPage(String),
     ChangeLastPage(String),
+    ChangeArticleNumber(String),
     ChangeImageCount(String),
     ChangeTableCount(String),
     ChangeAudioCount(String),
@@ -149,8 +164,15 @@ pub enum Msg {
     UpdateRelatedWorks(Option<Vec<WorkRelationWithRelatedWork>>),
     UpdateContributions(Option<Vec<Contribution>>),
     UpdateFundings(Option<Vec<FundingWithInstitution>>),
+    UpdatePrizes(Option<Vec<Prize>>),
+    UpdateEvents(Option<Vec<EventWithSponsors>>),
+    UpdateTextContents(Option<Vec<TextContent>>),
+    UpdateSupportingResources(Option<Vec<SupportingResource>>),
     UpdatePublications(Option<Vec<Publication>>),
     UpdateLanguages(Option<Vec<Language>>),
+    UpdateTitles(Option<Vec<WorkTitle>>),
+    UpdateAbstracts(Option<Vec<WorkAbstract>>),
+    UpdateIdentifiers(Option<Vec<WorkIdentifier>>),
     UpdateSubjects(Option<Vec<Subject>>),
     UpdateIssues(Option<Vec<IssueWithSeries>>),
     UpdateReferences(Option<Vec<Reference>>),
@@ -315,7 +337,7 @@ impl Component for WorkComponent {
                 }
                 // Clear any fields which are not applicable to the currently selected work type.
                 // (Do not clear them before the save point as the user may change the type again.)
-                if self.work.work_type == WorkType::BookChapter {
+                if self.work.work_type.is_component() {
                     self.work.edition = None;
                     self.work.toc = None;
                     self.work.lccn = None;
@@ -325,6 +347,9 @@ impl Component for WorkComponent {
                     self.work.last_page = None;
                     self.work.page_interval = None;
                 }
+                if self.work.work_type != WorkType::JournalArticle {
+                    self.work.article_number = None;
+                }
                 if !self.work.is_out_of_print() {
                     self.work.withdrawn_date = None;
                 }
@@ -364,6 +389,7 @@ impl Component for WorkComponent {
                         first_page: self.work.first_page.clone(),
                         last_page: self.work.last_page.clone(),
                         page_interval: self.work.page_interval.clone(),
+                        article_number: self.work.article_number.clone(),
                     },
                     ..Default::default()
                 };
@@ -514,6 +540,9 @@ impl Component for WorkComponent {
                     false
                 }
             }
+            Msg::ChangeArticleNumber(value) => {
+                self.work.article_number.neq_assign(value.to_opt_string())
+            }
             Msg::ChangeImageCount(value) => self.work.image_count.neq_assign(value.to_opt_int()),
             Msg::ChangeTableCount(value) => self.work.table_count.neq_assign(value.to_opt_int()),
             Msg::ChangeAudioCount(value) => self.work.audio_count.neq_assign(value.to_opt_int()),
@@ -549,10 +578,22 @@ impl Component for WorkComponent {
                 self.work.contributions.neq_assign(contributions)
             }
             Msg::UpdateFundings(fundings) => self.work.fundings.neq_assign(fundings),
+            Msg::UpdatePrizes(prizes) => self.work.prizes.neq_assign(prizes),
+            Msg::UpdateEvents(events) => self.work.events.neq_assign(events),
+            Msg::UpdateTextContents(text_contents) => {
+                self.work.text_contents.neq_assign(text_contents)
+            }
+            Msg::UpdateSupportingResources(supporting_resources) => self
+                .work
+                .supporting_resources
+                .neq_assign(supporting_resources),
             Msg::UpdatePublications(publications) => {
                 self.work.publications.neq_assign(publications)
             }
             Msg::UpdateLanguages(languages) => self.work.languages.neq_assign(languages),
+            Msg::UpdateTitles(titles) => self.work.titles.neq_assign(titles),
+            Msg::UpdateAbstracts(abstracts) => self.work.abstracts.neq_assign(abstracts),
+            Msg::UpdateIdentifiers(identifiers) => self.work.identifiers.neq_assign(identifiers),
             Msg::UpdateSubjects(subjects) => self.work.subjects.neq_assign(subjects),
             Msg::UpdateIssues(issues) => self.work.issues.neq_assign(issues),
             Msg::UpdateReferences(references) => self.work.references.neq_assign(references),
@@ -606,7 +647,7 @@ impl Component for WorkComponent {
                     false => vec![self.work.imprint.clone()],
                 };
                 // FormWorkTypeSelect: while the work has any publications with ISBNs,
-                // the work type cannot be changed to Book Chapter.
+                // the work type cannot be changed to Book Chapter or Journal Article.
                 let deactivated_types = match self
                     .work
                     .publications
@@ -615,13 +656,14 @@ impl Component for WorkComponent {
                     .iter()
                     .any(|p| p.isbn.is_some())
                 {
-                    true => vec![WorkType::BookChapter],
+                    true => vec![WorkType::BookChapter, WorkType::JournalArticle],
                     false => vec![],
                 };
 
                 // Variables required to grey out chapter-specific or "book"-specific fields
                 // based on currently selected work type.
-                let is_chapter = self.work.work_type == WorkType::BookChapter;
+                let is_component = self.work.work_type.is_component();
+                let is_article = self.work.work_type == WorkType::JournalArticle;
 
                 // deactivates Delete button when true to prevent non-superusers from deleting published works
                 let is_delete_deactivated = !is_superuser && self.work.is_published();
@@ -694,7 +736,7 @@ impl Component for WorkComponent {
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeEdition(e.to_value())) }
                                 required = true
                                 min={ "1".to_string() }
-                                deactivated={ is_chapter }
+                                deactivated={ is_component }
                             />
                             <FormDateInput
                                 label = "Publication Date"
@@ -755,13 +797,13 @@ impl Component for WorkComponent {
                                         label = "LCCN"
                                         value={ self.work.lccn.clone() }
                                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLccn(e.to_value())) }
-                                        deactivated={ is_chapter }
+                                        deactivated={ is_component }
                                     />
                                     <FormTextInput
                                         label = "OCLC Number"
                                         value={ self.work.oclc.clone() }
                                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeOclc(e.to_value())) }
-                                        deactivated={ is_chapter }
+                                        deactivated={ is_component }
                                     />
                                     <FormTextInput
                                         label = "Internal Reference"
@@ -786,13 +828,19 @@ impl Component for WorkComponent {
                                         label = "First Page"
                                         value={ self.work.first_page.clone() }
                                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeFirstPage(e.to_value())) }
-                                        deactivated={ !is_chapter }
+                                        deactivated={ !is_component }
                                     />
                                     <FormTextInput
                                         label = "Last Page"
                                         value={ self.work.last_page.clone() }
                                         oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeLastPage(e.to_value())) }
-                                        deactivated={ !is_chapter }
+                                        deactivated={ !is_component }
+                                    />
+                                    <FormTextInput
+                                        label = "Article Number"
+                                        value={ self.work.article_number.clone() }
+                                        oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeArticleNumber(e.to_value())) }
+                                        deactivated={ !is_article }
                                     />
                                 </div>
                             </div>
@@ -859,7 +907,7 @@ impl Component for WorkComponent {
                                 label = "Table of Content"
                                 value={ self.work.toc.clone() }
                                 oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeToc(e.to_value())) }
-                                deactivated={ is_chapter }
+                                deactivated={ is_component }
                             />
 
                             <div class="field">
@@ -929,6 +977,21 @@ impl Component for WorkComponent {
                             work_id={ self.work.work_id }
                             update_languages={ ctx.link().callback(Msg::UpdateLanguages) }
                         />
+                        <WorkTitlesFormComponent
+                            titles={ self.work.titles.clone() }
+                            work_id={ self.work.work_id }
+                            update_titles={ ctx.link().callback(Msg::UpdateTitles) }
+                        />
+                        <WorkAbstractsFormComponent
+                            abstracts={ self.work.abstracts.clone() }
+                            work_id={ self.work.work_id }
+                            update_abstracts={ ctx.link().callback(Msg::UpdateAbstracts) }
+                        />
+                        <WorkIdentifiersFormComponent
+                            identifiers={ self.work.identifiers.clone() }
+                            work_id={ self.work.work_id }
+                            update_identifiers={ ctx.link().callback(Msg::UpdateIdentifiers) }
+                        />
                         <SubjectsFormComponent
                             subjects={ self.work.subjects.clone() }
                             work_id={ self.work.work_id }
@@ -946,6 +1009,26 @@ impl Component for WorkComponent {
                             work_id={ self.work.work_id }
                             update_fundings={ ctx.link().callback(Msg::UpdateFundings) }
                         />
+                        <PrizesFormComponent
+                            prizes={ self.work.prizes.clone() }
+                            work_id={ self.work.work_id }
+                            update_prizes={ ctx.link().callback(Msg::UpdatePrizes) }
+                        />
+                        <EventsFormComponent
+                            events={ self.work.events.clone() }
+                            work_id={ self.work.work_id }
+                            update_events={ ctx.link().callback(Msg::UpdateEvents) }
+                        />
+                        <TextContentsFormComponent
+                            text_contents={ self.work.text_contents.clone() }
+                            work_id={ self.work.work_id }
+                            update_text_contents={ ctx.link().callback(Msg::UpdateTextContents) }
+                        />
+                        <SupportingResourcesFormComponent
+                            supporting_resources={ self.work.supporting_resources.clone() }
+                            work_id={ self.work.work_id }
+                            update_supporting_resources={ ctx.link().callback(Msg::UpdateSupportingResources) }
+                        />
                         <ReferencesFormComponent
                             references={ self.work.references.clone() }
                             work_id={ self.work.work_id }
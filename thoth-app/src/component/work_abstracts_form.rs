@@ -0,0 +1,453 @@
+use std::str::FromStr;
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::work_abstract::AbstractType;
+use thoth_api::model::work_abstract::WorkAbstract;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormAbstractTypeSelect;
+use crate::component::utils::FormBooleanSelect;
+use crate::component::utils::FormLanguageCodeSelect;
+use crate::component::utils::FormTextarea;
+use crate::models::language::language_codes_query::FetchActionLanguageCodes;
+use crate::models::language::language_codes_query::FetchLanguageCodes;
+use crate::models::language::LanguageCodeValues;
+use crate::models::work_abstract::abstract_types_query::FetchAbstractTypes;
+use crate::models::work_abstract::abstract_types_query::FetchActionAbstractTypes;
+use crate::models::work_abstract::create_work_abstract_mutation::CreateWorkAbstractRequest;
+use crate::models::work_abstract::create_work_abstract_mutation::CreateWorkAbstractRequestBody;
+use crate::models::work_abstract::create_work_abstract_mutation::PushActionCreateWorkAbstract;
+use crate::models::work_abstract::create_work_abstract_mutation::PushCreateWorkAbstract;
+use crate::models::work_abstract::create_work_abstract_mutation::Variables;
+use crate::models::work_abstract::delete_work_abstract_mutation::DeleteWorkAbstractRequest;
+use crate::models::work_abstract::delete_work_abstract_mutation::DeleteWorkAbstractRequestBody;
+use crate::models::work_abstract::delete_work_abstract_mutation::PushActionDeleteWorkAbstract;
+use crate::models::work_abstract::delete_work_abstract_mutation::PushDeleteWorkAbstract;
+use crate::models::work_abstract::delete_work_abstract_mutation::Variables as DeleteVariables;
+use crate::models::work_abstract::AbstractTypeValues;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_ABSTRACTS;
+use crate::string::NO;
+use crate::string::REMOVE_BUTTON;
+use crate::string::YES;
+
+use super::ToElementValue;
+
+pub struct WorkAbstractsFormComponent {
+    data: WorkAbstractsFormData,
+    new_abstract: WorkAbstract,
+    show_add_form: bool,
+    fetch_language_codes: FetchLanguageCodes,
+    fetch_abstract_types: FetchAbstractTypes,
+    push_abstract: PushCreateWorkAbstract,
+    delete_abstract: PushDeleteWorkAbstract,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct WorkAbstractsFormData {
+    language_codes: Vec<LanguageCodeValues>,
+    abstract_types: Vec<AbstractTypeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetLanguageCodesFetchState(FetchActionLanguageCodes),
+    GetLanguageCodes,
+    SetAbstractTypesFetchState(FetchActionAbstractTypes),
+    GetAbstractTypes,
+    SetAbstractPushState(PushActionCreateWorkAbstract),
+    CreateAbstract,
+    SetAbstractDeleteState(PushActionDeleteWorkAbstract),
+    DeleteAbstract(Uuid),
+    ChangeLanguageCode(LanguageCode),
+    ChangeAbstractType(AbstractType),
+    ChangeContent(String),
+    ChangeCanonical(bool),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub abstracts: Option<Vec<WorkAbstract>>,
+    pub work_id: Uuid,
+    pub update_abstracts: Callback<Option<Vec<WorkAbstract>>>,
+}
+
+impl Component for WorkAbstractsFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: WorkAbstractsFormData = Default::default();
+        let show_add_form = false;
+        let new_abstract: WorkAbstract = Default::default();
+        let fetch_language_codes = Default::default();
+        let fetch_abstract_types = Default::default();
+        let push_abstract = Default::default();
+        let delete_abstract = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetLanguageCodes);
+        ctx.link().send_message(Msg::GetAbstractTypes);
+
+        WorkAbstractsFormComponent {
+            data,
+            new_abstract,
+            show_add_form,
+            fetch_language_codes,
+            fetch_abstract_types,
+            push_abstract,
+            delete_abstract,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetLanguageCodesFetchState(fetch_state) => {
+                self.fetch_language_codes.apply(fetch_state);
+                self.data.language_codes = match self.fetch_language_codes.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.language_codes.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetLanguageCodes => {
+                ctx.link().send_future(
+                    self.fetch_language_codes
+                        .fetch(Msg::SetLanguageCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetLanguageCodesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetAbstractTypesFetchState(fetch_state) => {
+                self.fetch_abstract_types.apply(fetch_state);
+                self.data.abstract_types = match self.fetch_abstract_types.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.abstract_types.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetAbstractTypes => {
+                ctx.link().send_future(
+                    self.fetch_abstract_types
+                        .fetch(Msg::SetAbstractTypesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetAbstractTypesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetAbstractPushState(fetch_state) => {
+                self.push_abstract.apply(fetch_state);
+                match self.push_abstract.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_work_abstract {
+                        Some(a) => {
+                            let work_abstract = a.clone();
+                            let mut abstracts: Vec<WorkAbstract> =
+                                ctx.props().abstracts.clone().unwrap_or_default();
+                            // Only one abstract of each type can be canonical: the database demotes the others
+                            if work_abstract.canonical {
+                                for other in abstracts
+                                    .iter_mut()
+                                    .filter(|o| o.abstract_type == work_abstract.abstract_type)
+                                {
+                                    other.canonical = false;
+                                }
+                            }
+                            abstracts.push(work_abstract);
+                            ctx.props().update_abstracts.emit(Some(abstracts));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateAbstract => {
+                let body = CreateWorkAbstractRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        language_code: self.new_abstract.language_code,
+                        abstract_type: self.new_abstract.abstract_type,
+                        content: self.new_abstract.content.clone(),
+                        canonical: self.new_abstract.canonical,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateWorkAbstractRequest { body };
+                self.push_abstract = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_abstract.fetch(Msg::SetAbstractPushState));
+                ctx.link()
+                    .send_message(Msg::SetAbstractPushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetAbstractDeleteState(fetch_state) => {
+                self.delete_abstract.apply(fetch_state);
+                match self.delete_abstract.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_work_abstract {
+                        Some(work_abstract) => {
+                            let to_keep: Vec<WorkAbstract> = ctx
+                                .props()
+                                .abstracts
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|a| a.work_abstract_id != work_abstract.work_abstract_id)
+                                .collect();
+                            ctx.props().update_abstracts.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteAbstract(work_abstract_id) => {
+                let body = DeleteWorkAbstractRequestBody {
+                    variables: DeleteVariables { work_abstract_id },
+                    ..Default::default()
+                };
+                let request = DeleteWorkAbstractRequest { body };
+                self.delete_abstract = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_abstract.fetch(Msg::SetAbstractDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetAbstractDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeLanguageCode(code) => self.new_abstract.language_code.neq_assign(code),
+            Msg::ChangeAbstractType(abstract_type) => {
+                self.new_abstract.abstract_type.neq_assign(abstract_type)
+            }
+            Msg::ChangeContent(value) => self
+                .new_abstract
+                .content
+                .neq_assign(value.trim().to_owned()),
+            Msg::ChangeCanonical(val) => self.new_abstract.canonical.neq_assign(val),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let abstracts = ctx.props().abstracts.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Abstracts" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Abstract" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Abstract" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="abstracts-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateAbstract
+                            }) }
+                            >
+                                <FormLanguageCodeSelect
+                                    label = "Language Code"
+                                    value={ self.new_abstract.language_code }
+                                    data={ self.data.language_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeLanguageCode(LanguageCode::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormAbstractTypeSelect
+                                    label = "Abstract Type"
+                                    value={ self.new_abstract.abstract_type }
+                                    data={ self.data.abstract_types.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeAbstractType(AbstractType::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormTextarea
+                                    label = "Content"
+                                    value={ Some(self.new_abstract.content.clone()) }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeContent(e.to_value())) }
+                                    required = true
+                                />
+                                <FormBooleanSelect
+                                    label = "Canonical"
+                                    value={ self.new_abstract.canonical }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeCanonical(e.to_value() == "true")
+                                    ) }
+                                    required = true
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="abstracts-form"
+                            >
+                                { "Add Abstract" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !abstracts.is_empty() {
+                        html!{{for abstracts.iter().map(|a| self.render_abstract(ctx, a))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_ABSTRACTS }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl WorkAbstractsFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_abstract(&self, ctx: &Context<Self>, a: &WorkAbstract) -> Html {
+        let work_abstract_id = a.work_abstract_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-align-left" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Language Code" }</label>
+                        <div class="control is-expanded">
+                            {&a.language_code}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Abstract Type" }</label>
+                        <div class="control is-expanded">
+                            {&a.abstract_type}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Content" }</label>
+                        <div class="control is-expanded">
+                            {&a.content}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Canonical" }</label>
+                        <div class="control is-expanded">
+                            {
+                                match &a.canonical {
+                                    true => { YES },
+                                    false => { NO }
+                                }
+                            }
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteAbstract(work_abstract_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use std::str::FromStr;
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::work_title::WorkTitle;
+use thoth_errors::ThothError;
+use uuid::Uuid;
+use yew::html;
+use yew::prelude::*;
+use yew_agent::Dispatched;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::NeqAssign;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormBooleanSelect;
+use crate::component::utils::FormLanguageCodeSelect;
+use crate::component::utils::FormTextInput;
+use crate::models::language::language_codes_query::FetchActionLanguageCodes;
+use crate::models::language::language_codes_query::FetchLanguageCodes;
+use crate::models::language::LanguageCodeValues;
+use crate::models::work_title::create_work_title_mutation::CreateWorkTitleRequest;
+use crate::models::work_title::create_work_title_mutation::CreateWorkTitleRequestBody;
+use crate::models::work_title::create_work_title_mutation::PushActionCreateWorkTitle;
+use crate::models::work_title::create_work_title_mutation::PushCreateWorkTitle;
+use crate::models::work_title::create_work_title_mutation::Variables;
+use crate::models::work_title::delete_work_title_mutation::DeleteWorkTitleRequest;
+use crate::models::work_title::delete_work_title_mutation::DeleteWorkTitleRequestBody;
+use crate::models::work_title::delete_work_title_mutation::PushActionDeleteWorkTitle;
+use crate::models::work_title::delete_work_title_mutation::PushDeleteWorkTitle;
+use crate::models::work_title::delete_work_title_mutation::Variables as DeleteVariables;
+use crate::string::CANCEL_BUTTON;
+use crate::string::EMPTY_TITLES;
+use crate::string::NO;
+use crate::string::REMOVE_BUTTON;
+use crate::string::YES;
+
+use super::ToElementValue;
+use super::ToOption;
+
+pub struct WorkTitlesFormComponent {
+    data: WorkTitlesFormData,
+    new_title: WorkTitle,
+    show_add_form: bool,
+    fetch_language_codes: FetchLanguageCodes,
+    push_title: PushCreateWorkTitle,
+    delete_title: PushDeleteWorkTitle,
+    notification_bus: NotificationDispatcher,
+}
+
+#[derive(Default)]
+struct WorkTitlesFormData {
+    language_codes: Vec<LanguageCodeValues>,
+}
+
+pub enum Msg {
+    ToggleAddFormDisplay(bool),
+    SetLanguageCodesFetchState(FetchActionLanguageCodes),
+    GetLanguageCodes,
+    SetTitlePushState(PushActionCreateWorkTitle),
+    CreateTitle,
+    SetTitleDeleteState(PushActionDeleteWorkTitle),
+    DeleteTitle(Uuid),
+    ChangeLanguageCode(LanguageCode),
+    ChangeTitle(String),
+    ChangeSubtitle(String),
+    ChangeCanonical(bool),
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub titles: Option<Vec<WorkTitle>>,
+    pub work_id: Uuid,
+    pub update_titles: Callback<Option<Vec<WorkTitle>>>,
+}
+
+impl Component for WorkTitlesFormComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let data: WorkTitlesFormData = Default::default();
+        let show_add_form = false;
+        let new_title: WorkTitle = Default::default();
+        let fetch_language_codes = Default::default();
+        let push_title = Default::default();
+        let delete_title = Default::default();
+        let notification_bus = NotificationBus::dispatcher();
+
+        ctx.link().send_message(Msg::GetLanguageCodes);
+
+        WorkTitlesFormComponent {
+            data,
+            new_title,
+            show_add_form,
+            fetch_language_codes,
+            push_title,
+            delete_title,
+            notification_bus,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleAddFormDisplay(value) => {
+                self.show_add_form = value;
+                true
+            }
+            Msg::SetLanguageCodesFetchState(fetch_state) => {
+                self.fetch_language_codes.apply(fetch_state);
+                self.data.language_codes = match self.fetch_language_codes.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.language_codes.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetLanguageCodes => {
+                ctx.link().send_future(
+                    self.fetch_language_codes
+                        .fetch(Msg::SetLanguageCodesFetchState),
+                );
+                ctx.link()
+                    .send_message(Msg::SetLanguageCodesFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetTitlePushState(fetch_state) => {
+                self.push_title.apply(fetch_state);
+                match self.push_title.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.create_work_title {
+                        Some(t) => {
+                            let title = t.clone();
+                            let mut titles: Vec<WorkTitle> =
+                                ctx.props().titles.clone().unwrap_or_default();
+                            // Only one title can be canonical: the database demotes the others
+                            if title.canonical {
+                                for other in titles.iter_mut() {
+                                    other.canonical = false;
+                                }
+                            }
+                            titles.push(title);
+                            ctx.props().update_titles.emit(Some(titles));
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            true
+                        }
+                        None => {
+                            ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        ctx.link().send_message(Msg::ToggleAddFormDisplay(false));
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateTitle => {
+                let body = CreateWorkTitleRequestBody {
+                    variables: Variables {
+                        work_id: ctx.props().work_id,
+                        language_code: self.new_title.language_code,
+                        full_title: self.new_title.compile_fulltitle(),
+                        title: self.new_title.title.clone(),
+                        subtitle: self.new_title.subtitle.clone(),
+                        canonical: self.new_title.canonical,
+                    },
+                    ..Default::default()
+                };
+                let request = CreateWorkTitleRequest { body };
+                self.push_title = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.push_title.fetch(Msg::SetTitlePushState));
+                ctx.link()
+                    .send_message(Msg::SetTitlePushState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetTitleDeleteState(fetch_state) => {
+                self.delete_title.apply(fetch_state);
+                match self.delete_title.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => match &body.data.delete_work_title {
+                        Some(title) => {
+                            let to_keep: Vec<WorkTitle> = ctx
+                                .props()
+                                .titles
+                                .clone()
+                                .unwrap_or_default()
+                                .into_iter()
+                                .filter(|t| t.work_title_id != title.work_title_id)
+                                .collect();
+                            ctx.props().update_titles.emit(Some(to_keep));
+                            true
+                        }
+                        None => {
+                            self.notification_bus.send(Request::NotificationBusMsg((
+                                "Failed to save".to_string(),
+                                NotificationStatus::Danger,
+                            )));
+                            false
+                        }
+                    },
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            ThothError::from(err).to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::DeleteTitle(work_title_id) => {
+                let body = DeleteWorkTitleRequestBody {
+                    variables: DeleteVariables { work_title_id },
+                    ..Default::default()
+                };
+                let request = DeleteWorkTitleRequest { body };
+                self.delete_title = Fetch::new(request);
+                ctx.link()
+                    .send_future(self.delete_title.fetch(Msg::SetTitleDeleteState));
+                ctx.link()
+                    .send_message(Msg::SetTitleDeleteState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeLanguageCode(code) => self.new_title.language_code.neq_assign(code),
+            Msg::ChangeTitle(value) => self.new_title.title.neq_assign(value.trim().to_owned()),
+            Msg::ChangeSubtitle(value) => self.new_title.subtitle.neq_assign(value.to_opt_string()),
+            Msg::ChangeCanonical(val) => self.new_title.canonical.neq_assign(val),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let titles = ctx.props().titles.clone().unwrap_or_default();
+        let open_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(true)
+        });
+        let close_modal = ctx.link().callback(|e: MouseEvent| {
+            e.prevent_default();
+            Msg::ToggleAddFormDisplay(false)
+        });
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "Titles" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined is-success is-fullwidth"
+                        onclick={ open_modal }
+                    >
+                        { "Add Title" }
+                    </button>
+                </div>
+                <div class={ self.add_form_status() }>
+                    <div class="modal-background" onclick={ &close_modal }></div>
+                    <div class="modal-card">
+                        <header class="modal-card-head">
+                            <p class="modal-card-title">{ "New Title" }</p>
+                            <button
+                                class="delete"
+                                aria-label="close"
+                                onclick={ &close_modal }
+                            ></button>
+                        </header>
+                        <section class="modal-card-body">
+                            <form id="titles-form" onsubmit={ ctx.link().callback(|e: FocusEvent| {
+                                e.prevent_default();
+                                Msg::CreateTitle
+                            }) }
+                            >
+                                <FormLanguageCodeSelect
+                                    label = "Language Code"
+                                    value={ self.new_title.language_code }
+                                    data={ self.data.language_codes.clone() }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeLanguageCode(LanguageCode::from_str(&e.to_value()).unwrap())
+                                    ) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Title"
+                                    value={ self.new_title.title.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeTitle(e.to_value())) }
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Subtitle"
+                                    value={ self.new_title.subtitle.clone() }
+                                    oninput={ ctx.link().callback(|e: InputEvent| Msg::ChangeSubtitle(e.to_value())) }
+                                />
+                                <FormBooleanSelect
+                                    label = "Canonical"
+                                    value={ self.new_title.canonical }
+                                    onchange={ ctx.link().callback(|e: Event|
+                                        Msg::ChangeCanonical(e.to_value() == "true")
+                                    ) }
+                                    required = true
+                                />
+                            </form>
+                        </section>
+                        <footer class="modal-card-foot">
+                            <button
+                                class="button is-success"
+                                type="submit"
+                                form="titles-form"
+                            >
+                                { "Add Title" }
+                            </button>
+                            <button
+                                class="button"
+                                onclick={ &close_modal }
+                            >
+                                { CANCEL_BUTTON }
+                            </button>
+                        </footer>
+                    </div>
+                </div>
+                {
+                    if !titles.is_empty() {
+                        html!{{for titles.iter().map(|t| self.render_title(ctx, t))}}
+                    } else {
+                        html! {
+                            <div class="notification is-warning is-light">
+                                { EMPTY_TITLES }
+                            </div>
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl WorkTitlesFormComponent {
+    fn add_form_status(&self) -> String {
+        match self.show_add_form {
+            true => "modal is-active".to_string(),
+            false => "modal".to_string(),
+        }
+    }
+
+    fn render_title(&self, ctx: &Context<Self>, t: &WorkTitle) -> Html {
+        let work_title_id = t.work_title_id;
+        html! {
+            <div class="panel-block field is-horizontal">
+                <span class="panel-icon">
+                    <i class="fas fa-heading" aria-hidden="true"></i>
+                </span>
+                <div class="field-body">
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Language Code" }</label>
+                        <div class="control is-expanded">
+                            {&t.language_code}
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label">{ "Full Title" }</label>
+                        <div class="control is-expanded">
+                            {&t.full_title}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Canonical" }</label>
+                        <div class="control is-expanded">
+                            {
+                                match &t.canonical {
+                                    true => { YES },
+                                    false => { NO }
+                                }
+                            }
+                        </div>
+                    </div>
+
+                    <div class="field">
+                        <label class="label"></label>
+                        <div class="control is-expanded">
+                            <a
+                                class="button is-danger"
+                                onclick={ ctx.link().callback(move |_| Msg::DeleteTitle(work_title_id)) }
+                            >
+                                { REMOVE_BUTTON }
+                            </a>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
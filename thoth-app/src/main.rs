@@ -16,6 +16,8 @@ use crate::component::root::RootComponent;
 pub const THOTH_GRAPHQL_API: &str = env!("THOTH_GRAPHQL_API");
 pub const THOTH_EXPORT_API: &str = env!("THOTH_EXPORT_API");
 const SESSION_KEY: &str = "thoth.token";
+/// Single sign-on is offered on the login page when an identity provider is configured at compile time
+const SSO_ENABLED: bool = option_env!("OIDC_ISSUER_URL").is_some();
 /// Default number of milliseconds to wait before sending a search query
 const DEFAULT_DEBOUNCING_TIMEOUT: u32 = 500;
 
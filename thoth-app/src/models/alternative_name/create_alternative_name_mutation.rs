@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::alternative_name::AlternativeName;
+use thoth_api::model::alternative_name::NameType;
+use thoth_api::model::language::LanguageCode;
+use uuid::Uuid;
+
+const CREATE_ALTERNATIVE_NAME_MUTATION: &str = "
+    mutation CreateAlternativeName(
+        $contributorId: Uuid!,
+        $nameType: NameType!,
+        $firstName: String,
+        $lastName: String!,
+        $fullName: String!,
+        $languageCode: LanguageCode,
+        $scriptCode: String
+    ) {
+        createAlternativeName(data: {
+            contributorId: $contributorId
+            nameType: $nameType
+            firstName: $firstName
+            lastName: $lastName
+            fullName: $fullName
+            languageCode: $languageCode
+            scriptCode: $scriptCode
+        }){
+            alternativeNameId
+            contributorId
+            nameType
+            firstName
+            lastName
+            fullName
+            languageCode
+            scriptCode
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateAlternativeNameRequest,
+    CreateAlternativeNameRequestBody,
+    Variables,
+    CREATE_ALTERNATIVE_NAME_MUTATION,
+    CreateAlternativeNameResponseBody,
+    CreateAlternativeNameResponseData,
+    PushCreateAlternativeName,
+    PushActionCreateAlternativeName
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub contributor_id: Uuid,
+    pub name_type: NameType,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub language_code: Option<LanguageCode>,
+    pub script_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAlternativeNameResponseData {
+    pub create_alternative_name: Option<AlternativeName>,
+}
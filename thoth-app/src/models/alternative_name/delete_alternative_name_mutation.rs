@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::alternative_name::AlternativeName;
+use uuid::Uuid;
+
+const DELETE_ALTERNATIVE_NAME_MUTATION: &str = "
+    mutation DeleteAlternativeName(
+        $alternativeNameId: Uuid!
+    ) {
+        deleteAlternativeName(
+            alternativeNameId: $alternativeNameId
+        ){
+            alternativeNameId
+            contributorId
+            nameType
+            firstName
+            lastName
+            fullName
+            languageCode
+            scriptCode
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteAlternativeNameRequest,
+    DeleteAlternativeNameRequestBody,
+    Variables,
+    DELETE_ALTERNATIVE_NAME_MUTATION,
+    DeleteAlternativeNameResponseBody,
+    DeleteAlternativeNameResponseData,
+    PushDeleteAlternativeName,
+    PushActionDeleteAlternativeName
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub alternative_name_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteAlternativeNameResponseData {
+    pub delete_alternative_name: Option<AlternativeName>,
+}
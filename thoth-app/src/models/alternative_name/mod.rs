@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::alternative_name::NameType;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NameTypeDefinition {
+    pub enum_values: Vec<NameTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NameTypeValues {
+    pub name: NameType,
+}
+
+pub mod create_alternative_name_mutation;
+pub mod delete_alternative_name_mutation;
+pub mod name_types_query;
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::NameTypeDefinition;
+
+const NAME_TYPES_QUERY: &str = "
+    {
+        name_types: __type(name: \"NameType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    NameTypesRequest,
+    NameTypesRequestBody,
+    Variables,
+    NAME_TYPES_QUERY,
+    NameTypesResponseBody,
+    NameTypesResponseData,
+    FetchNameTypes,
+    FetchActionNameTypes
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NameTypesResponseData {
+    pub name_types: NameTypeDefinition,
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 use serde::Serialize;
-use thoth_api::model::contributor::Contributor;
+use thoth_api::model::contributor::ContributorWithAlternativeNames;
 use uuid::Uuid;
 
 pub const CONTRIBUTOR_QUERY: &str = "
@@ -12,8 +12,24 @@ pub const CONTRIBUTOR_QUERY: &str = "
             fullName
             orcid
             website
+            isni
+            viaf
+            wikidata
+            lcnaf
             createdAt
             updatedAt
+            alternativeNames {
+                alternativeNameId
+                contributorId
+                nameType
+                firstName
+                lastName
+                fullName
+                languageCode
+                scriptCode
+                createdAt
+                updatedAt
+            }
         }
     }
 ";
@@ -37,5 +53,5 @@ pub struct Variables {
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ContributorResponseData {
-    pub contributor: Option<Contributor>,
+    pub contributor: Option<ContributorWithAlternativeNames>,
 }
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 use thoth_api::model::contributor::Contributor;
+use thoth_api::model::identifier::{Isni, Lcnaf, Viaf, WikidataId};
 use thoth_api::model::Orcid;
 use uuid::Uuid;
 
@@ -11,7 +12,11 @@ const UPDATE_CONTRIBUTOR_MUTATION: &str = "
         $lastName: String!,
         $fullName: String!,
         $orcid: Orcid,
-        $website: String
+        $website: String,
+        $isni: Isni,
+        $viaf: Viaf,
+        $wikidata: WikidataId,
+        $lcnaf: Lcnaf
     ) {
         updateContributor(data: {
             contributorId: $contributorId
@@ -20,6 +25,10 @@ const UPDATE_CONTRIBUTOR_MUTATION: &str = "
             fullName: $fullName
             orcid: $orcid
             website: $website
+            isni: $isni
+            viaf: $viaf
+            wikidata: $wikidata
+            lcnaf: $lcnaf
         }){
             contributorId
             lastName
@@ -50,6 +59,10 @@ pub struct Variables {
     pub full_name: String,
     pub orcid: Option<Orcid>,
     pub website: Option<String>,
+    pub isni: Option<Isni>,
+    pub viaf: Option<Viaf>,
+    pub wikidata: Option<WikidataId>,
+    pub lcnaf: Option<Lcnaf>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::event::EventWithSponsors;
+use thoth_api::model::institution::CountryCode;
+use uuid::Uuid;
+
+const CREATE_EVENT_MUTATION: &str = "
+    mutation CreateEvent(
+        $workId: Uuid!,
+        $eventName: String!,
+        $eventAcronym: String,
+        $eventNumber: String,
+        $eventLocation: String,
+        $eventCountry: CountryCode,
+        $eventStartDate: Date,
+        $eventEndDate: Date
+    ) {
+        createEvent(data: {
+            workId: $workId
+            eventName: $eventName
+            eventAcronym: $eventAcronym
+            eventNumber: $eventNumber
+            eventLocation: $eventLocation
+            eventCountry: $eventCountry
+            eventStartDate: $eventStartDate
+            eventEndDate: $eventEndDate
+        }){
+            eventId
+            workId
+            eventName
+            eventAcronym
+            eventNumber
+            eventLocation
+            eventCountry
+            eventStartDate
+            eventEndDate
+            sponsors {
+                eventSponsorId
+                eventId
+                institutionId
+                sponsorOrdinal
+                institution {
+                    institutionId
+                    institutionName
+                    createdAt
+                    updatedAt
+                }
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateEventRequest,
+    CreateEventRequestBody,
+    Variables,
+    CREATE_EVENT_MUTATION,
+    CreateEventResponseBody,
+    CreateEventResponseData,
+    PushCreateEvent,
+    PushActionCreateEvent
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub event_name: String,
+    pub event_acronym: Option<String>,
+    pub event_number: Option<String>,
+    pub event_location: Option<String>,
+    pub event_country: Option<CountryCode>,
+    pub event_start_date: Option<NaiveDate>,
+    pub event_end_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEventResponseData {
+    pub create_event: Option<EventWithSponsors>,
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::event::Event;
+use uuid::Uuid;
+
+const DELETE_EVENT_MUTATION: &str = "
+    mutation DeleteEvent(
+        $eventId: Uuid!
+    ) {
+        deleteEvent(
+            eventId: $eventId
+        ){
+            eventId
+            workId
+            eventName
+            eventAcronym
+            eventNumber
+            eventLocation
+            eventCountry
+            eventStartDate
+            eventEndDate
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteEventRequest,
+    DeleteEventRequestBody,
+    Variables,
+    DELETE_EVENT_MUTATION,
+    DeleteEventResponseBody,
+    DeleteEventResponseData,
+    PushDeleteEvent,
+    PushActionDeleteEvent
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub event_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEventResponseData {
+    pub delete_event: Option<Event>,
+}
@@ -0,0 +1,2 @@
+pub mod create_event_mutation;
+pub mod delete_event_mutation;
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::event_sponsor::EventSponsorWithInstitution;
+use uuid::Uuid;
+
+const CREATE_EVENT_SPONSOR_MUTATION: &str = "
+    mutation CreateEventSponsor(
+        $eventId: Uuid!,
+        $institutionId: Uuid!,
+        $sponsorOrdinal: Int!
+    ) {
+        createEventSponsor(data: {
+            eventId: $eventId
+            institutionId: $institutionId
+            sponsorOrdinal: $sponsorOrdinal
+        }){
+            eventSponsorId
+            eventId
+            institutionId
+            sponsorOrdinal
+            institution {
+                institutionId
+                institutionName
+                createdAt
+                updatedAt
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateEventSponsorRequest,
+    CreateEventSponsorRequestBody,
+    Variables,
+    CREATE_EVENT_SPONSOR_MUTATION,
+    CreateEventSponsorResponseBody,
+    CreateEventSponsorResponseData,
+    PushCreateEventSponsor,
+    PushActionCreateEventSponsor
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub event_id: Uuid,
+    pub institution_id: Uuid,
+    pub sponsor_ordinal: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEventSponsorResponseData {
+    pub create_event_sponsor: Option<EventSponsorWithInstitution>,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::event_sponsor::EventSponsor;
+use uuid::Uuid;
+
+const DELETE_EVENT_SPONSOR_MUTATION: &str = "
+    mutation DeleteEventSponsor(
+        $eventSponsorId: Uuid!
+    ) {
+        deleteEventSponsor(
+            eventSponsorId: $eventSponsorId
+        ){
+            eventSponsorId
+            eventId
+            institutionId
+            sponsorOrdinal
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteEventSponsorRequest,
+    DeleteEventSponsorRequestBody,
+    Variables,
+    DELETE_EVENT_SPONSOR_MUTATION,
+    DeleteEventSponsorResponseBody,
+    DeleteEventSponsorResponseData,
+    PushDeleteEventSponsor,
+    PushActionDeleteEventSponsor
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub event_sponsor_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEventSponsorResponseData {
+    pub delete_event_sponsor: Option<EventSponsor>,
+}
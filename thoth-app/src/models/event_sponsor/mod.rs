@@ -0,0 +1,2 @@
+pub mod create_event_sponsor_mutation;
+pub mod delete_event_sponsor_mutation;
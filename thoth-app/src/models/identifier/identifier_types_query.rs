@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::IdentifierTypeDefinition;
+
+const IDENTIFIER_TYPES_QUERY: &str = "
+    {
+        identifier_types: __type(name: \"IdentifierType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    IdentifierTypesRequest,
+    IdentifierTypesRequestBody,
+    Variables,
+    IDENTIFIER_TYPES_QUERY,
+    IdentifierTypesResponseBody,
+    IdentifierTypesResponseData,
+    FetchIdentifierTypes,
+    FetchActionIdentifierTypes
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IdentifierTypesResponseData {
+    pub identifier_types: IdentifierTypeDefinition,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::identifier::IdentifierType;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentifierTypeDefinition {
+    pub enum_values: Vec<IdentifierTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentifierTypeValues {
+    pub name: IdentifierType,
+}
+
+pub mod identifier_types_query;
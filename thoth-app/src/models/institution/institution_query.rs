@@ -11,6 +11,9 @@ pub const INSTITUTION_QUERY: &str = "
             institutionDoi
             ror
             countryCode
+            crossrefFunderId
+            isni
+            ringgold
             createdAt
             updatedAt
         }
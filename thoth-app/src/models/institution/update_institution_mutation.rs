@@ -1,5 +1,6 @@
 use serde::Deserialize;
 use serde::Serialize;
+use thoth_api::model::identifier::{FunderId, Isni, Ringgold};
 use thoth_api::model::institution::CountryCode;
 use thoth_api::model::institution::Institution;
 use thoth_api::model::Doi;
@@ -12,7 +13,10 @@ const UPDATE_INSTITUTION_MUTATION: &str = "
         $institutionName: String!,
         $institutionDoi: Doi,
         $ror: Ror,
-        $countryCode: CountryCode
+        $countryCode: CountryCode,
+        $crossrefFunderId: FunderId,
+        $isni: Isni,
+        $ringgold: Ringgold
     ) {
         updateInstitution(data: {
             institutionId: $institutionId
@@ -20,6 +24,9 @@ const UPDATE_INSTITUTION_MUTATION: &str = "
             institutionDoi: $institutionDoi
             ror: $ror
             countryCode: $countryCode
+            crossrefFunderId: $crossrefFunderId
+            isni: $isni
+            ringgold: $ringgold
         }){
             institutionId
             institutionName
@@ -48,6 +55,9 @@ pub struct Variables {
     pub institution_doi: Option<Doi>,
     pub ror: Option<Ror>,
     pub country_code: Option<CountryCode>,
+    pub crossref_funder_id: Option<FunderId>,
+    pub isni: Option<Isni>,
+    pub ringgold: Option<Ringgold>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
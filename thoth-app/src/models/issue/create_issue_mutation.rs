@@ -8,16 +8,25 @@ const CREATE_ISSUE_MUTATION: &str = "
         $workId: Uuid!,
         $seriesId: Uuid!,
         $issueOrdinal: Int!,
+        $volume: String,
+        $issueNumber: String,
+        $part: String,
     ) {
         createIssue(data: {
             workId: $workId
             seriesId: $seriesId
             issueOrdinal: $issueOrdinal
+            volume: $volume
+            issueNumber: $issueNumber
+            part: $part
         }){
             issueId
             workId
             seriesId
             issueOrdinal
+            volume
+            issueNumber
+            part
             series {
                 seriesId
                 seriesType
@@ -61,6 +70,9 @@ pub struct Variables {
     pub work_id: Uuid,
     pub series_id: Uuid,
     pub issue_ordinal: i32,
+    pub volume: Option<String>,
+    pub issue_number: Option<String>,
+    pub part: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -176,22 +176,35 @@ impl GraphqlFieldList {
 }
 
 pub mod affiliation;
+pub mod alternative_name;
 pub mod book;
 pub mod chapter;
 pub mod contribution;
 pub mod contributor;
+pub mod event;
+pub mod event_sponsor;
 pub mod funding;
+pub mod identifier;
 pub mod imprint;
 pub mod institution;
 pub mod issue;
 pub mod language;
 pub mod location;
 pub mod price;
+pub mod prize;
 pub mod publication;
+pub mod publication_identifier;
 pub mod publisher;
 pub mod reference;
+pub mod sales_rights;
 pub mod series;
 pub mod stats;
 pub mod subject;
+pub mod supporting_resource;
+pub mod text_content;
+pub mod usage_constraint;
 pub mod work;
+pub mod work_abstract;
+pub mod work_identifier;
 pub mod work_relation;
+pub mod work_title;
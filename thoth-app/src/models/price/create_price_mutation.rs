@@ -1,24 +1,58 @@
+use chrono::NaiveDate;
 use serde::Deserialize;
 use serde::Serialize;
+use thoth_api::model::institution::CountryCode;
 use thoth_api::model::price::CurrencyCode;
 use thoth_api::model::price::Price;
+use thoth_api::model::price::PriceQualifier;
+use thoth_api::model::price::PriceType;
 use uuid::Uuid;
 
 const CREATE_PRICE_MUTATION: &str = "
     mutation CreatePrice(
         $publicationId: Uuid!,
         $currencyCode: CurrencyCode!,
-        $unitPrice: Float!
+        $unitPrice: Float!,
+        $priceType: PriceType!,
+        $priceQualifier: PriceQualifier,
+        $countriesIncluded: [CountryCode!]!,
+        $countriesExcluded: [CountryCode!]!,
+        $regionsIncluded: [String!]!,
+        $regionsExcluded: [String!]!,
+        $taxRatePercent: Float,
+        $discountCode: String,
+        $priceEffectiveFrom: Date,
+        $priceEffectiveUntil: Date
     ) {
         createPrice(data: {
             publicationId: $publicationId
             currencyCode: $currencyCode
             unitPrice: $unitPrice
+            priceType: $priceType
+            priceQualifier: $priceQualifier
+            countriesIncluded: $countriesIncluded
+            countriesExcluded: $countriesExcluded
+            regionsIncluded: $regionsIncluded
+            regionsExcluded: $regionsExcluded
+            taxRatePercent: $taxRatePercent
+            discountCode: $discountCode
+            priceEffectiveFrom: $priceEffectiveFrom
+            priceEffectiveUntil: $priceEffectiveUntil
         }){
             priceId
             publicationId
             currencyCode
             unitPrice
+            priceType
+            priceQualifier
+            countriesIncluded
+            countriesExcluded
+            regionsIncluded
+            regionsExcluded
+            taxRatePercent
+            discountCode
+            priceEffectiveFrom
+            priceEffectiveUntil
             createdAt
             updatedAt
         }
@@ -42,6 +76,16 @@ pub struct Variables {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    pub price_type: PriceType,
+    pub price_qualifier: Option<PriceQualifier>,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub tax_rate_percent: Option<f64>,
+    pub discount_code: Option<String>,
+    pub price_effective_from: Option<NaiveDate>,
+    pub price_effective_until: Option<NaiveDate>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
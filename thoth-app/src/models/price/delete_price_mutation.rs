@@ -14,6 +14,16 @@ const DELETE_PRICE_MUTATION: &str = "
             publicationId
             currencyCode
             unitPrice
+            priceType
+            priceQualifier
+            countriesIncluded
+            countriesExcluded
+            regionsIncluded
+            regionsExcluded
+            taxRatePercent
+            discountCode
+            priceEffectiveFrom
+            priceEffectiveUntil
             createdAt
             updatedAt
         }
@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use serde::Serialize;
 use thoth_api::model::price::CurrencyCode;
+use thoth_api::model::price::PriceQualifier;
+use thoth_api::model::price::PriceType;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -14,6 +16,32 @@ pub struct CurrencyCodeValues {
     pub name: CurrencyCode,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTypeDefinition {
+    pub enum_values: Vec<PriceTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTypeValues {
+    pub name: PriceType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceQualifierDefinition {
+    pub enum_values: Vec<PriceQualifierValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceQualifierValues {
+    pub name: PriceQualifier,
+}
+
 pub mod create_price_mutation;
 pub mod currency_codes_query;
 pub mod delete_price_mutation;
+pub mod price_qualifiers_query;
+pub mod price_types_query;
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::PriceQualifierDefinition;
+
+const PRICE_QUALIFIERS_QUERY: &str = "
+    {
+        price_qualifiers: __type(name: \"PriceQualifier\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    PriceQualifiersRequest,
+    PriceQualifiersRequestBody,
+    Variables,
+    PRICE_QUALIFIERS_QUERY,
+    PriceQualifiersResponseBody,
+    PriceQualifiersResponseData,
+    FetchPriceQualifiers,
+    FetchActionPriceQualifiers
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PriceQualifiersResponseData {
+    pub price_qualifiers: PriceQualifierDefinition,
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::institution::CountryCode;
+use thoth_api::model::prize::Prize;
+use thoth_api::model::prize::PrizeCode;
+use uuid::Uuid;
+
+const CREATE_PRIZE_MUTATION: &str = "
+    mutation CreatePrize(
+        $workId: Uuid!,
+        $prizeName: String!,
+        $prizeYear: Int,
+        $prizeCountry: CountryCode,
+        $prizeCode: PrizeCode!,
+        $prizeStatement: String,
+        $prizeJury: String
+    ) {
+        createPrize(data: {
+            workId: $workId
+            prizeName: $prizeName
+            prizeYear: $prizeYear
+            prizeCountry: $prizeCountry
+            prizeCode: $prizeCode
+            prizeStatement: $prizeStatement
+            prizeJury: $prizeJury
+        }){
+            prizeId
+            workId
+            prizeName
+            prizeYear
+            prizeCountry
+            prizeCode
+            prizeStatement
+            prizeJury
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreatePrizeRequest,
+    CreatePrizeRequestBody,
+    Variables,
+    CREATE_PRIZE_MUTATION,
+    CreatePrizeResponseBody,
+    CreatePrizeResponseData,
+    PushCreatePrize,
+    PushActionCreatePrize
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub prize_name: String,
+    pub prize_year: Option<i32>,
+    pub prize_country: Option<CountryCode>,
+    pub prize_code: PrizeCode,
+    pub prize_statement: Option<String>,
+    pub prize_jury: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePrizeResponseData {
+    pub create_prize: Option<Prize>,
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::prize::Prize;
+use uuid::Uuid;
+
+const DELETE_PRIZE_MUTATION: &str = "
+    mutation DeletePrize(
+        $prizeId: Uuid!
+    ) {
+        deletePrize(
+            prizeId: $prizeId
+        ){
+            prizeId
+            workId
+            prizeName
+            prizeYear
+            prizeCountry
+            prizeCode
+            prizeStatement
+            prizeJury
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeletePrizeRequest,
+    DeletePrizeRequestBody,
+    Variables,
+    DELETE_PRIZE_MUTATION,
+    DeletePrizeResponseBody,
+    DeletePrizeResponseData,
+    PushDeletePrize,
+    PushActionDeletePrize
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub prize_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletePrizeResponseData {
+    pub delete_prize: Option<Prize>,
+}
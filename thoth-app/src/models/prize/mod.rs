@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::prize::PrizeCode;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrizeCodeDefinition {
+    pub enum_values: Vec<PrizeCodeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrizeCodeValues {
+    pub name: PrizeCode,
+}
+
+pub mod create_prize_mutation;
+pub mod delete_prize_mutation;
+pub mod prize_values_query;
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::PrizeCodeDefinition;
+use crate::models::institution::CountryCodeDefinition;
+
+const PRIZE_VALUES_QUERY: &str = "
+    {
+        prize_codes: __type(name: \"PrizeCode\") {
+            enumValues {
+                name
+            }
+        }
+        country_codes: __type(name: \"CountryCode\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    PrizeValuesRequest,
+    PrizeValuesRequestBody,
+    Variables,
+    PRIZE_VALUES_QUERY,
+    PrizeValuesResponseBody,
+    PrizeValuesResponseData,
+    FetchPrizeValues,
+    FetchActionPrizeValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrizeValuesResponseData {
+    pub prize_codes: PrizeCodeDefinition,
+    pub country_codes: CountryCodeDefinition,
+}
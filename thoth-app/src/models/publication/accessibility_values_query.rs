@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AccessibilityFeatureDefinition;
+use super::AccessibilityHazardDefinition;
+use super::AccessibilityStandardDefinition;
+use super::WcagLevelDefinition;
+use super::WcagVersionDefinition;
+
+const ACCESSIBILITY_VALUES_QUERY: &str = "
+    {
+        accessibility_features: __type(name: \"AccessibilityFeature\") {
+            enumValues {
+                name
+            }
+        }
+        accessibility_hazards: __type(name: \"AccessibilityHazard\") {
+            enumValues {
+                name
+            }
+        }
+        accessibility_standards: __type(name: \"AccessibilityStandard\") {
+            enumValues {
+                name
+            }
+        }
+        wcag_versions: __type(name: \"WcagVersion\") {
+            enumValues {
+                name
+            }
+        }
+        wcag_levels: __type(name: \"WcagLevel\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    AccessibilityValuesRequest,
+    AccessibilityValuesRequestBody,
+    Variables,
+    ACCESSIBILITY_VALUES_QUERY,
+    AccessibilityValuesResponseBody,
+    AccessibilityValuesResponseData,
+    FetchAccessibilityValues,
+    FetchActionAccessibilityValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccessibilityValuesResponseData {
+    pub accessibility_features: AccessibilityFeatureDefinition,
+    pub accessibility_hazards: AccessibilityHazardDefinition,
+    pub accessibility_standards: AccessibilityStandardDefinition,
+    pub wcag_versions: WcagVersionDefinition,
+    pub wcag_levels: WcagLevelDefinition,
+}
@@ -14,6 +14,9 @@ const DELETE_PUBLICATION_MUTATION: &str = "
             publicationType
             workId
             isbn
+            accessibilityFeatures
+            accessibilityHazards
+            technicalProtections
             createdAt
             updatedAt
         }
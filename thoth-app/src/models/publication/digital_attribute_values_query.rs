@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::DigitalLayoutDefinition;
+use super::TechnicalProtectionDefinition;
+
+const DIGITAL_ATTRIBUTE_VALUES_QUERY: &str = "
+    {
+        technical_protections: __type(name: \"TechnicalProtection\") {
+            enumValues {
+                name
+            }
+        }
+        layouts: __type(name: \"DigitalLayout\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    DigitalAttributeValuesRequest,
+    DigitalAttributeValuesRequestBody,
+    Variables,
+    DIGITAL_ATTRIBUTE_VALUES_QUERY,
+    DigitalAttributeValuesResponseBody,
+    DigitalAttributeValuesResponseData,
+    FetchDigitalAttributeValues,
+    FetchActionDigitalAttributeValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DigitalAttributeValuesResponseData {
+    pub technical_protections: TechnicalProtectionDefinition,
+    pub layouts: DigitalLayoutDefinition,
+}
@@ -1,8 +1,20 @@
 use serde::Deserialize;
 use serde::Serialize;
+use thoth_api::model::publication::AccessibilityFeature;
+use thoth_api::model::publication::AccessibilityHazard;
+use thoth_api::model::publication::AccessibilityStandard;
+use thoth_api::model::publication::BindingStyle;
+use thoth_api::model::publication::CoverFinish;
+use thoth_api::model::publication::DigitalLayout;
+use thoth_api::model::publication::InteriorColour;
+use thoth_api::model::publication::PaperType;
 use thoth_api::model::publication::Publication;
 use thoth_api::model::publication::PublicationType;
 use thoth_api::model::publication::PublicationWithRelations;
+use thoth_api::model::publication::TechnicalProtection;
+use thoth_api::model::publication::TrimCategory;
+use thoth_api::model::publication::WcagLevel;
+use thoth_api::model::publication::WcagVersion;
 use yew::html;
 use yew::prelude::Html;
 use yew::Callback;
@@ -23,6 +35,150 @@ pub struct PublicationTypeValues {
     pub name: PublicationType,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityFeatureDefinition {
+    pub enum_values: Vec<AccessibilityFeatureValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityFeatureValues {
+    pub name: AccessibilityFeature,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityHazardDefinition {
+    pub enum_values: Vec<AccessibilityHazardValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityHazardValues {
+    pub name: AccessibilityHazard,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityStandardDefinition {
+    pub enum_values: Vec<AccessibilityStandardValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibilityStandardValues {
+    pub name: AccessibilityStandard,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WcagVersionDefinition {
+    pub enum_values: Vec<WcagVersionValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WcagVersionValues {
+    pub name: WcagVersion,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WcagLevelDefinition {
+    pub enum_values: Vec<WcagLevelValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WcagLevelValues {
+    pub name: WcagLevel,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingStyleDefinition {
+    pub enum_values: Vec<BindingStyleValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingStyleValues {
+    pub name: BindingStyle,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaperTypeDefinition {
+    pub enum_values: Vec<PaperTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaperTypeValues {
+    pub name: PaperType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InteriorColourDefinition {
+    pub enum_values: Vec<InteriorColourValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct InteriorColourValues {
+    pub name: InteriorColour,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimCategoryDefinition {
+    pub enum_values: Vec<TrimCategoryValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrimCategoryValues {
+    pub name: TrimCategory,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverFinishDefinition {
+    pub enum_values: Vec<CoverFinishValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverFinishValues {
+    pub name: CoverFinish,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TechnicalProtectionDefinition {
+    pub enum_values: Vec<TechnicalProtectionValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TechnicalProtectionValues {
+    pub name: TechnicalProtection,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DigitalLayoutDefinition {
+    pub enum_values: Vec<DigitalLayoutValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DigitalLayoutValues {
+    pub name: DigitalLayout,
+}
+
 impl EditRoute for Publication {
     fn edit_route(&self) -> AdminRoute {
         AdminRoute::Publication {
@@ -75,8 +231,11 @@ impl CreateRoute for PublicationWithRelations {
     }
 }
 
+pub mod accessibility_values_query;
 pub mod create_publication_mutation;
 pub mod delete_publication_mutation;
+pub mod digital_attribute_values_query;
+pub mod print_attribute_values_query;
 pub mod publication_query;
 pub mod publication_types_query;
 pub mod publications_query;
@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::BindingStyleDefinition;
+use super::CoverFinishDefinition;
+use super::InteriorColourDefinition;
+use super::PaperTypeDefinition;
+use super::TrimCategoryDefinition;
+
+const PRINT_ATTRIBUTE_VALUES_QUERY: &str = "
+    {
+        binding_styles: __type(name: \"BindingStyle\") {
+            enumValues {
+                name
+            }
+        }
+        paper_types: __type(name: \"PaperType\") {
+            enumValues {
+                name
+            }
+        }
+        interior_colours: __type(name: \"InteriorColour\") {
+            enumValues {
+                name
+            }
+        }
+        trim_categories: __type(name: \"TrimCategory\") {
+            enumValues {
+                name
+            }
+        }
+        cover_finishes: __type(name: \"CoverFinish\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    PrintAttributeValuesRequest,
+    PrintAttributeValuesRequestBody,
+    Variables,
+    PRINT_ATTRIBUTE_VALUES_QUERY,
+    PrintAttributeValuesResponseBody,
+    PrintAttributeValuesResponseData,
+    FetchPrintAttributeValues,
+    FetchActionPrintAttributeValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrintAttributeValuesResponseData {
+    pub binding_styles: BindingStyleDefinition,
+    pub paper_types: PaperTypeDefinition,
+    pub interior_colours: InteriorColourDefinition,
+    pub trim_categories: TrimCategoryDefinition,
+    pub cover_finishes: CoverFinishDefinition,
+}
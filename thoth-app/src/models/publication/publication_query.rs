@@ -19,11 +19,39 @@ pub const PUBLICATION_QUERY: &str = "
             heightIn: height(units: IN)
             depthMm: depth(units: MM)
             depthIn: depth(units: IN)
+            accessibilityFeatures
+            accessibilityHazards
+            accessibilityStandard
+            wcagVersion
+            wcagLevel
+            accessibilityCertifier
+            accessibilitySummary
+            bindingStyle
+            paperType
+            interiorColour
+            trimCategory
+            pageCount
+            coverFinish
+            cartonQuantity
+            formatVersion
+            fileSizeKb
+            technicalProtections
+            layout
             prices {
                 priceId
                 publicationId
                 currencyCode
                 unitPrice
+                priceType
+                priceQualifier
+                countriesIncluded
+                countriesExcluded
+                regionsIncluded
+                regionsExcluded
+                taxRatePercent
+                discountCode
+                priceEffectiveFrom
+                priceEffectiveUntil
                 createdAt
                 updatedAt
             }
@@ -37,6 +65,37 @@ pub const PUBLICATION_QUERY: &str = "
                 createdAt
                 updatedAt
             }
+            identifiers {
+                publicationIdentifierId
+                publicationId
+                identifierType
+                identifierValue
+                createdAt
+                updatedAt
+            }
+            salesRights {
+                salesRightsId
+                publicationId
+                salesRightsType
+                countriesIncluded
+                countriesExcluded
+                regionsIncluded
+                regionsExcluded
+                salesRestrictionType
+                salesRestrictionNote
+                createdAt
+                updatedAt
+            }
+            usageConstraints {
+                usageConstraintId
+                publicationId
+                usageType
+                usageStatus
+                usageLimit
+                usageUnit
+                createdAt
+                updatedAt
+            }
             work {
                 workId
                 workType
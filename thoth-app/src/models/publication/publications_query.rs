@@ -10,6 +10,9 @@ pub const PUBLICATIONS_QUERY: &str = "
             publicationType
             workId
             isbn
+            accessibilityFeatures
+            accessibilityHazards
+            technicalProtections
             updatedAt
             work {
                 workId
@@ -1,7 +1,19 @@
 use serde::Deserialize;
 use serde::Serialize;
+use thoth_api::model::publication::AccessibilityFeature;
+use thoth_api::model::publication::AccessibilityHazard;
+use thoth_api::model::publication::AccessibilityStandard;
+use thoth_api::model::publication::BindingStyle;
+use thoth_api::model::publication::CoverFinish;
+use thoth_api::model::publication::DigitalLayout;
+use thoth_api::model::publication::InteriorColour;
+use thoth_api::model::publication::PaperType;
 use thoth_api::model::publication::Publication;
 use thoth_api::model::publication::PublicationType;
+use thoth_api::model::publication::TechnicalProtection;
+use thoth_api::model::publication::TrimCategory;
+use thoth_api::model::publication::WcagLevel;
+use thoth_api::model::publication::WcagVersion;
 use thoth_api::model::Isbn;
 use uuid::Uuid;
 
@@ -19,6 +31,24 @@ const UPDATE_PUBLICATION_MUTATION: &str = "
         $heightIn: Float,
         $depthMm: Float,
         $depthIn: Float,
+        $accessibilityFeatures: [AccessibilityFeature!]!,
+        $accessibilityHazards: [AccessibilityHazard!]!,
+        $accessibilityStandard: AccessibilityStandard,
+        $wcagVersion: WcagVersion,
+        $wcagLevel: WcagLevel,
+        $accessibilityCertifier: String,
+        $accessibilitySummary: String,
+        $bindingStyle: BindingStyle,
+        $paperType: PaperType,
+        $interiorColour: InteriorColour,
+        $trimCategory: TrimCategory,
+        $pageCount: Int,
+        $coverFinish: CoverFinish,
+        $cartonQuantity: Int,
+        $formatVersion: String,
+        $fileSizeKb: Int,
+        $technicalProtections: [TechnicalProtection!]!,
+        $layout: DigitalLayout,
     ) {
         updatePublication(
             data: {
@@ -34,6 +64,24 @@ const UPDATE_PUBLICATION_MUTATION: &str = "
             heightIn: $heightIn
             depthMm: $depthMm
             depthIn: $depthIn
+            accessibilityFeatures: $accessibilityFeatures
+            accessibilityHazards: $accessibilityHazards
+            accessibilityStandard: $accessibilityStandard
+            wcagVersion: $wcagVersion
+            wcagLevel: $wcagLevel
+            accessibilityCertifier: $accessibilityCertifier
+            accessibilitySummary: $accessibilitySummary
+            bindingStyle: $bindingStyle
+            paperType: $paperType
+            interiorColour: $interiorColour
+            trimCategory: $trimCategory
+            pageCount: $pageCount
+            coverFinish: $coverFinish
+            cartonQuantity: $cartonQuantity
+            formatVersion: $formatVersion
+            fileSizeKb: $fileSizeKb
+            technicalProtections: $technicalProtections
+            layout: $layout
         }){
             publicationId
             publicationType
@@ -49,6 +97,24 @@ const UPDATE_PUBLICATION_MUTATION: &str = "
             heightIn: height(units: IN)
             depthMm: depth(units: MM)
             depthIn: depth(units: IN)
+            accessibilityFeatures
+            accessibilityHazards
+            accessibilityStandard
+            wcagVersion
+            wcagLevel
+            accessibilityCertifier
+            accessibilitySummary
+            bindingStyle
+            paperType
+            interiorColour
+            trimCategory
+            pageCount
+            coverFinish
+            cartonQuantity
+            formatVersion
+            fileSizeKb
+            technicalProtections
+            layout
         }
     }
 ";
@@ -79,6 +145,24 @@ pub struct Variables {
     pub depth_in: Option<f64>,
     pub weight_g: Option<f64>,
     pub weight_oz: Option<f64>,
+    pub accessibility_features: Vec<AccessibilityFeature>,
+    pub accessibility_hazards: Vec<AccessibilityHazard>,
+    pub accessibility_standard: Option<AccessibilityStandard>,
+    pub wcag_version: Option<WcagVersion>,
+    pub wcag_level: Option<WcagLevel>,
+    pub accessibility_certifier: Option<String>,
+    pub accessibility_summary: Option<String>,
+    pub binding_style: Option<BindingStyle>,
+    pub paper_type: Option<PaperType>,
+    pub interior_colour: Option<InteriorColour>,
+    pub trim_category: Option<TrimCategory>,
+    pub page_count: Option<i32>,
+    pub cover_finish: Option<CoverFinish>,
+    pub carton_quantity: Option<i32>,
+    pub format_version: Option<String>,
+    pub file_size_kb: Option<i32>,
+    pub technical_protections: Vec<TechnicalProtection>,
+    pub layout: Option<DigitalLayout>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
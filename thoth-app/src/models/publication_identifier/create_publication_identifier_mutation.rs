@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::identifier::IdentifierType;
+use thoth_api::model::publication_identifier::PublicationIdentifier;
+use uuid::Uuid;
+
+const CREATE_PUBLICATION_IDENTIFIER_MUTATION: &str = "
+    mutation CreatePublicationIdentifier(
+        $publicationId: Uuid!,
+        $identifierType: IdentifierType!,
+        $identifierValue: String!
+    ) {
+        createPublicationIdentifier(data: {
+            publicationId: $publicationId
+            identifierType: $identifierType
+            identifierValue: $identifierValue
+        }){
+            publicationIdentifierId
+            publicationId
+            identifierType
+            identifierValue
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreatePublicationIdentifierRequest,
+    CreatePublicationIdentifierRequestBody,
+    Variables,
+    CREATE_PUBLICATION_IDENTIFIER_MUTATION,
+    CreatePublicationIdentifierResponseBody,
+    CreatePublicationIdentifierResponseData,
+    PushCreatePublicationIdentifier,
+    PushActionCreatePublicationIdentifier
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePublicationIdentifierResponseData {
+    pub create_publication_identifier: Option<PublicationIdentifier>,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::publication_identifier::PublicationIdentifier;
+use uuid::Uuid;
+
+const DELETE_PUBLICATION_IDENTIFIER_MUTATION: &str = "
+    mutation DeletePublicationIdentifier(
+        $publicationIdentifierId: Uuid!
+    ) {
+        deletePublicationIdentifier(
+            publicationIdentifierId: $publicationIdentifierId
+        ){
+            publicationIdentifierId
+            publicationId
+            identifierType
+            identifierValue
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeletePublicationIdentifierRequest,
+    DeletePublicationIdentifierRequestBody,
+    Variables,
+    DELETE_PUBLICATION_IDENTIFIER_MUTATION,
+    DeletePublicationIdentifierResponseBody,
+    DeletePublicationIdentifierResponseData,
+    PushDeletePublicationIdentifier,
+    PushActionDeletePublicationIdentifier
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_identifier_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletePublicationIdentifierResponseData {
+    pub delete_publication_identifier: Option<PublicationIdentifier>,
+}
@@ -0,0 +1,2 @@
+pub mod create_publication_identifier_mutation;
+pub mod delete_publication_identifier_mutation;
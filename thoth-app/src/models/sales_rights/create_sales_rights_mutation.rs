@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::institution::CountryCode;
+use thoth_api::model::sales_rights::SalesRestrictionType;
+use thoth_api::model::sales_rights::SalesRights;
+use thoth_api::model::sales_rights::SalesRightsType;
+use uuid::Uuid;
+
+const CREATE_SALES_RIGHTS_MUTATION: &str = "
+    mutation CreateSalesRights(
+        $publicationId: Uuid!,
+        $salesRightsType: SalesRightsType!,
+        $countriesIncluded: [CountryCode!]!,
+        $countriesExcluded: [CountryCode!]!,
+        $regionsIncluded: [String!]!,
+        $regionsExcluded: [String!]!,
+        $salesRestrictionType: SalesRestrictionType,
+        $salesRestrictionNote: String
+    ) {
+        createSalesRights(data: {
+            publicationId: $publicationId
+            salesRightsType: $salesRightsType
+            countriesIncluded: $countriesIncluded
+            countriesExcluded: $countriesExcluded
+            regionsIncluded: $regionsIncluded
+            regionsExcluded: $regionsExcluded
+            salesRestrictionType: $salesRestrictionType
+            salesRestrictionNote: $salesRestrictionNote
+        }){
+            salesRightsId
+            publicationId
+            salesRightsType
+            countriesIncluded
+            countriesExcluded
+            regionsIncluded
+            regionsExcluded
+            salesRestrictionType
+            salesRestrictionNote
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateSalesRightsRequest,
+    CreateSalesRightsRequestBody,
+    Variables,
+    CREATE_SALES_RIGHTS_MUTATION,
+    CreateSalesRightsResponseBody,
+    CreateSalesRightsResponseData,
+    PushCreateSalesRights,
+    PushActionCreateSalesRights
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_id: Uuid,
+    pub sales_rights_type: SalesRightsType,
+    pub countries_included: Vec<CountryCode>,
+    pub countries_excluded: Vec<CountryCode>,
+    pub regions_included: Vec<String>,
+    pub regions_excluded: Vec<String>,
+    pub sales_restriction_type: Option<SalesRestrictionType>,
+    pub sales_restriction_note: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSalesRightsResponseData {
+    pub create_sales_rights: Option<SalesRights>,
+}
@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::sales_rights::SalesRights;
+use uuid::Uuid;
+
+const DELETE_SALES_RIGHTS_MUTATION: &str = "
+    mutation DeleteSalesRights(
+        $salesRightsId: Uuid!
+    ) {
+        deleteSalesRights(
+            salesRightsId: $salesRightsId
+        ){
+            salesRightsId
+            publicationId
+            salesRightsType
+            countriesIncluded
+            countriesExcluded
+            regionsIncluded
+            regionsExcluded
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteSalesRightsRequest,
+    DeleteSalesRightsRequestBody,
+    Variables,
+    DELETE_SALES_RIGHTS_MUTATION,
+    DeleteSalesRightsResponseBody,
+    DeleteSalesRightsResponseData,
+    PushDeleteSalesRights,
+    PushActionDeleteSalesRights
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub sales_rights_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteSalesRightsResponseData {
+    pub delete_sales_rights: Option<SalesRights>,
+}
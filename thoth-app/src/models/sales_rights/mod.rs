@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::sales_rights::SalesRestrictionType;
+use thoth_api::model::sales_rights::SalesRightsType;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRightsTypeDefinition {
+    pub enum_values: Vec<SalesRightsTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRightsTypeValues {
+    pub name: SalesRightsType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRestrictionTypeDefinition {
+    pub enum_values: Vec<SalesRestrictionTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SalesRestrictionTypeValues {
+    pub name: SalesRestrictionType,
+}
+
+pub mod create_sales_rights_mutation;
+pub mod delete_sales_rights_mutation;
+pub mod sales_rights_types_query;
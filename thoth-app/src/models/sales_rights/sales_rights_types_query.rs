@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::SalesRestrictionTypeDefinition;
+use super::SalesRightsTypeDefinition;
+
+const SALES_RIGHTS_TYPES_QUERY: &str = "
+    {
+        sales_rights_types: __type(name: \"SalesRightsType\") {
+            enumValues {
+                name
+            }
+        }
+        sales_restriction_types: __type(name: \"SalesRestrictionType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    SalesRightsTypesRequest,
+    SalesRightsTypesRequestBody,
+    Variables,
+    SALES_RIGHTS_TYPES_QUERY,
+    SalesRightsTypesResponseBody,
+    SalesRightsTypesResponseData,
+    FetchSalesRightsTypes,
+    FetchActionSalesRightsTypes
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SalesRightsTypesResponseData {
+    pub sales_rights_types: SalesRightsTypeDefinition,
+    pub sales_restriction_types: SalesRestrictionTypeDefinition,
+}
@@ -13,7 +13,8 @@ const CREATE_SERIES_MUTATION: &str = "
             $seriesUrl: String,
             $seriesDescription: String,
             $seriesCfpUrl: String,
-            $imprintId: Uuid!
+            $imprintId: Uuid!,
+            $parentSeriesId: Uuid
     ) {
         createSeries(data: {
             seriesType: $seriesType
@@ -24,6 +25,7 @@ const CREATE_SERIES_MUTATION: &str = "
             seriesDescription: $seriesDescription
             seriesCfpUrl: $seriesCfpUrl
             imprintId: $imprintId
+            parentSeriesId: $parentSeriesId
         }){
             seriesId
             seriesType
@@ -57,6 +59,7 @@ pub struct Variables {
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
     pub imprint_id: Uuid,
+    pub parent_series_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
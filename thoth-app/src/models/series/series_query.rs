@@ -14,6 +14,7 @@ pub const SERIES_QUERY: &str = "
             seriesUrl
             seriesDescription
             seriesCfpUrl
+            parentSeriesId
             updatedAt
             imprint {
                 imprintId
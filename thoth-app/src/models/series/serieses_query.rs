@@ -12,6 +12,7 @@ pub const SERIESES_QUERY: &str = "
             issnPrint
             issnDigital
             seriesUrl
+            parentSeriesId
             updatedAt
             imprint {
                 imprintId
@@ -14,7 +14,8 @@ const UPDATE_SERIES_MUTATION: &str = "
             $seriesUrl: String,
             $seriesDescription: String,
             $seriesCfpUrl: String,
-            $imprintId: Uuid!
+            $imprintId: Uuid!,
+            $parentSeriesId: Uuid
     ) {
         updateSeries(data: {
             seriesId: $seriesId
@@ -26,6 +27,7 @@ const UPDATE_SERIES_MUTATION: &str = "
             seriesDescription: $seriesDescription
             seriesCfpUrl: $seriesCfpUrl
             imprintId: $imprintId
+            parentSeriesId: $parentSeriesId
         }){
             seriesId
             seriesType
@@ -60,6 +62,7 @@ pub struct Variables {
     pub series_description: Option<String>,
     pub series_cfp_url: Option<String>,
     pub imprint_id: Uuid,
+    pub parent_series_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::supporting_resource::ResourceContentType;
+use thoth_api::model::supporting_resource::ResourceForm;
+use thoth_api::model::supporting_resource::ResourceMode;
+use thoth_api::model::supporting_resource::SupportingResource;
+use thoth_api::model::text_content::ContentAudience;
+use uuid::Uuid;
+
+const CREATE_SUPPORTING_RESOURCE_MUTATION: &str = "
+    mutation CreateSupportingResource(
+        $workId: Uuid!,
+        $resourceContentType: ResourceContentType!,
+        $contentAudience: ContentAudience!,
+        $resourceMode: ResourceMode!,
+        $resourceForm: ResourceForm!,
+        $resourceUrl: String!,
+        $caption: String,
+        $credit: String,
+        $widthPx: Int,
+        $heightPx: Int
+    ) {
+        createSupportingResource(data: {
+            workId: $workId
+            resourceContentType: $resourceContentType
+            contentAudience: $contentAudience
+            resourceMode: $resourceMode
+            resourceForm: $resourceForm
+            resourceUrl: $resourceUrl
+            caption: $caption
+            credit: $credit
+            widthPx: $widthPx
+            heightPx: $heightPx
+        }){
+            supportingResourceId
+            workId
+            resourceContentType
+            contentAudience
+            resourceMode
+            resourceForm
+            resourceUrl
+            caption
+            credit
+            widthPx
+            heightPx
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateSupportingResourceRequest,
+    CreateSupportingResourceRequestBody,
+    Variables,
+    CREATE_SUPPORTING_RESOURCE_MUTATION,
+    CreateSupportingResourceResponseBody,
+    CreateSupportingResourceResponseData,
+    PushCreateSupportingResource,
+    PushActionCreateSupportingResource
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub resource_content_type: ResourceContentType,
+    pub content_audience: ContentAudience,
+    pub resource_mode: ResourceMode,
+    pub resource_form: ResourceForm,
+    pub resource_url: String,
+    pub caption: Option<String>,
+    pub credit: Option<String>,
+    pub width_px: Option<i32>,
+    pub height_px: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSupportingResourceResponseData {
+    pub create_supporting_resource: Option<SupportingResource>,
+}
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::supporting_resource::SupportingResource;
+use uuid::Uuid;
+
+const DELETE_SUPPORTING_RESOURCE_MUTATION: &str = "
+    mutation DeleteSupportingResource(
+        $supportingResourceId: Uuid!
+    ) {
+        deleteSupportingResource(
+            supportingResourceId: $supportingResourceId
+        ){
+            supportingResourceId
+            workId
+            resourceContentType
+            contentAudience
+            resourceMode
+            resourceForm
+            resourceUrl
+            caption
+            credit
+            widthPx
+            heightPx
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteSupportingResourceRequest,
+    DeleteSupportingResourceRequestBody,
+    Variables,
+    DELETE_SUPPORTING_RESOURCE_MUTATION,
+    DeleteSupportingResourceResponseBody,
+    DeleteSupportingResourceResponseData,
+    PushDeleteSupportingResource,
+    PushActionDeleteSupportingResource
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub supporting_resource_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteSupportingResourceResponseData {
+    pub delete_supporting_resource: Option<SupportingResource>,
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::supporting_resource::ResourceContentType;
+use thoth_api::model::supporting_resource::ResourceForm;
+use thoth_api::model::supporting_resource::ResourceMode;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceContentTypeDefinition {
+    pub enum_values: Vec<ResourceContentTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceContentTypeValues {
+    pub name: ResourceContentType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceModeDefinition {
+    pub enum_values: Vec<ResourceModeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceModeValues {
+    pub name: ResourceMode,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceFormDefinition {
+    pub enum_values: Vec<ResourceFormValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceFormValues {
+    pub name: ResourceForm,
+}
+
+pub mod create_supporting_resource_mutation;
+pub mod delete_supporting_resource_mutation;
+pub mod supporting_resource_values_query;
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ResourceContentTypeDefinition;
+use super::ResourceFormDefinition;
+use super::ResourceModeDefinition;
+use crate::models::text_content::ContentAudienceDefinition;
+
+const SUPPORTING_RESOURCE_VALUES_QUERY: &str = "
+    {
+        resource_content_types: __type(name: \"ResourceContentType\") {
+            enumValues {
+                name
+            }
+        }
+        content_audiences: __type(name: \"ContentAudience\") {
+            enumValues {
+                name
+            }
+        }
+        resource_modes: __type(name: \"ResourceMode\") {
+            enumValues {
+                name
+            }
+        }
+        resource_forms: __type(name: \"ResourceForm\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    SupportingResourceValuesRequest,
+    SupportingResourceValuesRequestBody,
+    Variables,
+    SUPPORTING_RESOURCE_VALUES_QUERY,
+    SupportingResourceValuesResponseBody,
+    SupportingResourceValuesResponseData,
+    FetchSupportingResourceValues,
+    FetchActionSupportingResourceValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SupportingResourceValuesResponseData {
+    pub resource_content_types: ResourceContentTypeDefinition,
+    pub content_audiences: ContentAudienceDefinition,
+    pub resource_modes: ResourceModeDefinition,
+    pub resource_forms: ResourceFormDefinition,
+}
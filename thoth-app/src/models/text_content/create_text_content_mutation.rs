@@ -0,0 +1,73 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::text_content::ContentAudience;
+use thoth_api::model::text_content::TextContent;
+use thoth_api::model::text_content::TextContentType;
+use uuid::Uuid;
+
+const CREATE_TEXT_CONTENT_MUTATION: &str = "
+    mutation CreateTextContent(
+        $workId: Uuid!,
+        $textType: TextContentType!,
+        $contentAudience: ContentAudience!,
+        $content: String!,
+        $textAuthor: String,
+        $sourceTitle: String,
+        $sourceUrl: String,
+        $contentDate: Date
+    ) {
+        createTextContent(data: {
+            workId: $workId
+            textType: $textType
+            contentAudience: $contentAudience
+            content: $content
+            textAuthor: $textAuthor
+            sourceTitle: $sourceTitle
+            sourceUrl: $sourceUrl
+            contentDate: $contentDate
+        }){
+            textContentId
+            workId
+            textType
+            contentAudience
+            content
+            textAuthor
+            sourceTitle
+            sourceUrl
+            contentDate
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateTextContentRequest,
+    CreateTextContentRequestBody,
+    Variables,
+    CREATE_TEXT_CONTENT_MUTATION,
+    CreateTextContentResponseBody,
+    CreateTextContentResponseData,
+    PushCreateTextContent,
+    PushActionCreateTextContent
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub text_type: TextContentType,
+    pub content_audience: ContentAudience,
+    pub content: String,
+    pub text_author: Option<String>,
+    pub source_title: Option<String>,
+    pub source_url: Option<String>,
+    pub content_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTextContentResponseData {
+    pub create_text_content: Option<TextContent>,
+}
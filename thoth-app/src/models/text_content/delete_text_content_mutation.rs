@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::text_content::TextContent;
+use uuid::Uuid;
+
+const DELETE_TEXT_CONTENT_MUTATION: &str = "
+    mutation DeleteTextContent(
+        $textContentId: Uuid!
+    ) {
+        deleteTextContent(
+            textContentId: $textContentId
+        ){
+            textContentId
+            workId
+            textType
+            contentAudience
+            content
+            textAuthor
+            sourceTitle
+            sourceUrl
+            contentDate
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteTextContentRequest,
+    DeleteTextContentRequestBody,
+    Variables,
+    DELETE_TEXT_CONTENT_MUTATION,
+    DeleteTextContentResponseBody,
+    DeleteTextContentResponseData,
+    PushDeleteTextContent,
+    PushActionDeleteTextContent
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub text_content_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTextContentResponseData {
+    pub delete_text_content: Option<TextContent>,
+}
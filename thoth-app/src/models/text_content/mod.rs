@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::text_content::ContentAudience;
+use thoth_api::model::text_content::TextContentType;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextContentTypeDefinition {
+    pub enum_values: Vec<TextContentTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextContentTypeValues {
+    pub name: TextContentType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentAudienceDefinition {
+    pub enum_values: Vec<ContentAudienceValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentAudienceValues {
+    pub name: ContentAudience,
+}
+
+pub mod create_text_content_mutation;
+pub mod delete_text_content_mutation;
+pub mod text_content_values_query;
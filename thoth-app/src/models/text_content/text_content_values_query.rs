@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ContentAudienceDefinition;
+use super::TextContentTypeDefinition;
+
+const TEXT_CONTENT_VALUES_QUERY: &str = "
+    {
+        text_types: __type(name: \"TextContentType\") {
+            enumValues {
+                name
+            }
+        }
+        content_audiences: __type(name: \"ContentAudience\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    TextContentValuesRequest,
+    TextContentValuesRequestBody,
+    Variables,
+    TEXT_CONTENT_VALUES_QUERY,
+    TextContentValuesResponseBody,
+    TextContentValuesResponseData,
+    FetchTextContentValues,
+    FetchActionTextContentValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TextContentValuesResponseData {
+    pub text_types: TextContentTypeDefinition,
+    pub content_audiences: ContentAudienceDefinition,
+}
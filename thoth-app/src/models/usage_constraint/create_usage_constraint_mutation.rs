@@ -0,0 +1,61 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::usage_constraint::UsageConstraint;
+use thoth_api::model::usage_constraint::UsageStatus;
+use thoth_api::model::usage_constraint::UsageType;
+use thoth_api::model::usage_constraint::UsageUnit;
+use uuid::Uuid;
+
+const CREATE_USAGE_CONSTRAINT_MUTATION: &str = "
+    mutation CreateUsageConstraint(
+        $publicationId: Uuid!,
+        $usageType: UsageType!,
+        $usageStatus: UsageStatus!,
+        $usageLimit: Int,
+        $usageUnit: UsageUnit
+    ) {
+        createUsageConstraint(data: {
+            publicationId: $publicationId
+            usageType: $usageType
+            usageStatus: $usageStatus
+            usageLimit: $usageLimit
+            usageUnit: $usageUnit
+        }){
+            usageConstraintId
+            publicationId
+            usageType
+            usageStatus
+            usageLimit
+            usageUnit
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateUsageConstraintRequest,
+    CreateUsageConstraintRequestBody,
+    Variables,
+    CREATE_USAGE_CONSTRAINT_MUTATION,
+    CreateUsageConstraintResponseBody,
+    CreateUsageConstraintResponseData,
+    PushCreateUsageConstraint,
+    PushActionCreateUsageConstraint
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub publication_id: Uuid,
+    pub usage_type: UsageType,
+    pub usage_status: UsageStatus,
+    pub usage_limit: Option<i32>,
+    pub usage_unit: Option<UsageUnit>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateUsageConstraintResponseData {
+    pub create_usage_constraint: Option<UsageConstraint>,
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::usage_constraint::UsageConstraint;
+use uuid::Uuid;
+
+const DELETE_USAGE_CONSTRAINT_MUTATION: &str = "
+    mutation DeleteUsageConstraint(
+        $usageConstraintId: Uuid!
+    ) {
+        deleteUsageConstraint(
+            usageConstraintId: $usageConstraintId
+        ){
+            usageConstraintId
+            publicationId
+            usageType
+            usageStatus
+            usageLimit
+            usageUnit
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteUsageConstraintRequest,
+    DeleteUsageConstraintRequestBody,
+    Variables,
+    DELETE_USAGE_CONSTRAINT_MUTATION,
+    DeleteUsageConstraintResponseBody,
+    DeleteUsageConstraintResponseData,
+    PushDeleteUsageConstraint,
+    PushActionDeleteUsageConstraint
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub usage_constraint_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteUsageConstraintResponseData {
+    pub delete_usage_constraint: Option<UsageConstraint>,
+}
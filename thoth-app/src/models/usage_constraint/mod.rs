@@ -0,0 +1,45 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::usage_constraint::UsageStatus;
+use thoth_api::model::usage_constraint::UsageType;
+use thoth_api::model::usage_constraint::UsageUnit;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTypeDefinition {
+    pub enum_values: Vec<UsageTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTypeValues {
+    pub name: UsageType,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageStatusDefinition {
+    pub enum_values: Vec<UsageStatusValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageStatusValues {
+    pub name: UsageStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageUnitDefinition {
+    pub enum_values: Vec<UsageUnitValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageUnitValues {
+    pub name: UsageUnit,
+}
+
+pub mod create_usage_constraint_mutation;
+pub mod delete_usage_constraint_mutation;
+pub mod usage_constraint_values_query;
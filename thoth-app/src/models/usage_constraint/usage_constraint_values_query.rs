@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::UsageStatusDefinition;
+use super::UsageTypeDefinition;
+use super::UsageUnitDefinition;
+
+const USAGE_CONSTRAINT_VALUES_QUERY: &str = "
+    {
+        usage_types: __type(name: \"UsageType\") {
+            enumValues {
+                name
+            }
+        }
+        usage_statuses: __type(name: \"UsageStatus\") {
+            enumValues {
+                name
+            }
+        }
+        usage_units: __type(name: \"UsageUnit\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    UsageConstraintValuesRequest,
+    UsageConstraintValuesRequestBody,
+    Variables,
+    USAGE_CONSTRAINT_VALUES_QUERY,
+    UsageConstraintValuesResponseBody,
+    UsageConstraintValuesResponseData,
+    FetchUsageConstraintValues,
+    FetchActionUsageConstraintValues
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UsageConstraintValuesResponseData {
+    pub usage_types: UsageTypeDefinition,
+    pub usage_statuses: UsageStatusDefinition,
+    pub usage_units: UsageUnitDefinition,
+}
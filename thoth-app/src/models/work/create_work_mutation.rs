@@ -41,7 +41,8 @@ const CREATE_WORK_MUTATION: &str = "
         $coverCaption: String,
         $firstPage: String,
         $lastPage: String,
-        $pageInterval: String
+        $pageInterval: String,
+        $articleNumber: String
     ) {
         createWork(
             data: {
@@ -78,6 +79,7 @@ const CREATE_WORK_MUTATION: &str = "
             firstPage: $firstPage
             lastPage: $lastPage
             pageInterval: $pageInterval
+            articleNumber: $articleNumber
         }){
             workId
             workType
@@ -139,6 +141,7 @@ pub struct Variables {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    pub article_number: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -42,7 +42,8 @@ const UPDATE_WORK_MUTATION: &str = "
         $coverCaption: String,
         $firstPage: String,
         $lastPage: String,
-        $pageInterval: String
+        $pageInterval: String,
+        $articleNumber: String
     ) {
         updateWork(
             data: {
@@ -80,6 +81,7 @@ const UPDATE_WORK_MUTATION: &str = "
             firstPage: $firstPage
             lastPage: $lastPage
             pageInterval: $pageInterval
+            articleNumber: $articleNumber
         }){
             workId
             workType
@@ -142,6 +144,7 @@ pub struct Variables {
     pub first_page: Option<String>,
     pub last_page: Option<String>,
     pub page_interval: Option<String>,
+    pub article_number: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
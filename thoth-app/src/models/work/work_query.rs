@@ -44,6 +44,7 @@ pub const WORK_QUERY: &str = "
             firstPage
             lastPage
             pageInterval
+            articleNumber
             relations(order: {field: RELATION_ORDINAL, direction: ASC}) {
                 workRelationId
                 relatorWorkId
@@ -93,6 +94,24 @@ pub const WORK_QUERY: &str = "
                 heightIn: height(units: IN)
                 depthMm: depth(units: MM)
                 depthIn: depth(units: IN)
+                accessibilityFeatures
+                accessibilityHazards
+                accessibilityStandard
+                wcagVersion
+                wcagLevel
+                accessibilityCertifier
+                accessibilitySummary
+                bindingStyle
+                paperType
+                interiorColour
+                trimCategory
+                pageCount
+                coverFinish
+                cartonQuantity
+                formatVersion
+                fileSizeKb
+                technicalProtections
+                layout
             }
             languages {
                 languageId
@@ -103,6 +122,35 @@ pub const WORK_QUERY: &str = "
                 createdAt
                 updatedAt
             }
+            titles {
+                workTitleId
+                workId
+                languageCode
+                fullTitle
+                title
+                subtitle
+                canonical
+                createdAt
+                updatedAt
+            }
+            abstracts {
+                workAbstractId
+                workId
+                languageCode
+                abstractType
+                content
+                canonical
+                createdAt
+                updatedAt
+            }
+            identifiers {
+                workIdentifierId
+                workId
+                identifierType
+                identifierValue
+                createdAt
+                updatedAt
+            }
             fundings {
                 fundingId
                 workId
@@ -119,6 +167,71 @@ pub const WORK_QUERY: &str = "
                     updatedAt
                 }
             }
+            prizes {
+                prizeId
+                workId
+                prizeName
+                prizeYear
+                prizeCountry
+                prizeCode
+                prizeStatement
+                prizeJury
+                createdAt
+                updatedAt
+            }
+            events {
+                eventId
+                workId
+                eventName
+                eventAcronym
+                eventNumber
+                eventLocation
+                eventCountry
+                eventStartDate
+                eventEndDate
+                sponsors {
+                    eventSponsorId
+                    eventId
+                    institutionId
+                    sponsorOrdinal
+                    institution {
+                        institutionId
+                        institutionName
+                        createdAt
+                        updatedAt
+                    }
+                }
+            }
+            textContents {
+                textContentId
+                workId
+                textType
+                contentAudience
+                content
+                textAuthor
+                sourceTitle
+                sourceUrl
+                contentDate
+                createdAt
+                updatedAt
+            }
+            supportingResources {
+                supportingResourceId
+                workId
+                resourceContentType
+                contentAudience
+                resourceMode
+                resourceForm
+                resourceUrl
+                caption
+                credit
+                widthPx
+                heightPx
+                coverRendition
+                assetId
+                createdAt
+                updatedAt
+            }
             subjects {
                 subjectId
                 workId
@@ -133,6 +246,9 @@ pub const WORK_QUERY: &str = "
                 workId
                 seriesId
                 issueOrdinal
+                volume
+                issueNumber
+                part
                 series {
                     seriesId
                     seriesType
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AbstractTypeDefinition;
+
+const ABSTRACT_TYPES_QUERY: &str = "
+    {
+        abstract_types: __type(name: \"AbstractType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    AbstractTypesRequest,
+    AbstractTypesRequestBody,
+    Variables,
+    ABSTRACT_TYPES_QUERY,
+    AbstractTypesResponseBody,
+    AbstractTypesResponseData,
+    FetchAbstractTypes,
+    FetchActionAbstractTypes
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AbstractTypesResponseData {
+    pub abstract_types: AbstractTypeDefinition,
+}
@@ -0,0 +1,60 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::work_abstract::AbstractType;
+use thoth_api::model::work_abstract::WorkAbstract;
+use uuid::Uuid;
+
+const CREATE_WORK_ABSTRACT_MUTATION: &str = "
+    mutation CreateWorkAbstract(
+        $workId: Uuid!,
+        $languageCode: LanguageCode!,
+        $abstractType: AbstractType!,
+        $content: String!,
+        $canonical: Boolean!
+    ) {
+        createWorkAbstract(data: {
+            workId: $workId
+            languageCode: $languageCode
+            abstractType: $abstractType
+            content: $content
+            canonical: $canonical
+        }){
+            workAbstractId
+            workId
+            languageCode
+            abstractType
+            content
+            canonical
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateWorkAbstractRequest,
+    CreateWorkAbstractRequestBody,
+    Variables,
+    CREATE_WORK_ABSTRACT_MUTATION,
+    CreateWorkAbstractResponseBody,
+    CreateWorkAbstractResponseData,
+    PushCreateWorkAbstract,
+    PushActionCreateWorkAbstract
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub content: String,
+    pub canonical: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWorkAbstractResponseData {
+    pub create_work_abstract: Option<WorkAbstract>,
+}
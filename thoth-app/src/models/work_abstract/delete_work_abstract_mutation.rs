@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::work_abstract::WorkAbstract;
+use uuid::Uuid;
+
+const DELETE_WORK_ABSTRACT_MUTATION: &str = "
+    mutation DeleteWorkAbstract(
+        $workAbstractId: Uuid!
+    ) {
+        deleteWorkAbstract(
+            workAbstractId: $workAbstractId
+        ){
+            workAbstractId
+            workId
+            languageCode
+            abstractType
+            content
+            canonical
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteWorkAbstractRequest,
+    DeleteWorkAbstractRequestBody,
+    Variables,
+    DELETE_WORK_ABSTRACT_MUTATION,
+    DeleteWorkAbstractResponseBody,
+    DeleteWorkAbstractResponseData,
+    PushDeleteWorkAbstract,
+    PushActionDeleteWorkAbstract
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_abstract_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWorkAbstractResponseData {
+    pub delete_work_abstract: Option<WorkAbstract>,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::work_abstract::AbstractType;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AbstractTypeDefinition {
+    pub enum_values: Vec<AbstractTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AbstractTypeValues {
+    pub name: AbstractType,
+}
+
+pub mod abstract_types_query;
+pub mod create_work_abstract_mutation;
+pub mod delete_work_abstract_mutation;
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::identifier::IdentifierType;
+use thoth_api::model::work_identifier::WorkIdentifier;
+use uuid::Uuid;
+
+const CREATE_WORK_IDENTIFIER_MUTATION: &str = "
+    mutation CreateWorkIdentifier(
+        $workId: Uuid!,
+        $identifierType: IdentifierType!,
+        $identifierValue: String!
+    ) {
+        createWorkIdentifier(data: {
+            workId: $workId
+            identifierType: $identifierType
+            identifierValue: $identifierValue
+        }){
+            workIdentifierId
+            workId
+            identifierType
+            identifierValue
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateWorkIdentifierRequest,
+    CreateWorkIdentifierRequestBody,
+    Variables,
+    CREATE_WORK_IDENTIFIER_MUTATION,
+    CreateWorkIdentifierResponseBody,
+    CreateWorkIdentifierResponseData,
+    PushCreateWorkIdentifier,
+    PushActionCreateWorkIdentifier
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub identifier_type: IdentifierType,
+    pub identifier_value: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWorkIdentifierResponseData {
+    pub create_work_identifier: Option<WorkIdentifier>,
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::work_identifier::WorkIdentifier;
+use uuid::Uuid;
+
+const DELETE_WORK_IDENTIFIER_MUTATION: &str = "
+    mutation DeleteWorkIdentifier(
+        $workIdentifierId: Uuid!
+    ) {
+        deleteWorkIdentifier(
+            workIdentifierId: $workIdentifierId
+        ){
+            workIdentifierId
+            workId
+            identifierType
+            identifierValue
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteWorkIdentifierRequest,
+    DeleteWorkIdentifierRequestBody,
+    Variables,
+    DELETE_WORK_IDENTIFIER_MUTATION,
+    DeleteWorkIdentifierResponseBody,
+    DeleteWorkIdentifierResponseData,
+    PushDeleteWorkIdentifier,
+    PushActionDeleteWorkIdentifier
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_identifier_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWorkIdentifierResponseData {
+    pub delete_work_identifier: Option<WorkIdentifier>,
+}
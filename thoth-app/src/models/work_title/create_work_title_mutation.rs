@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::work_title::WorkTitle;
+use uuid::Uuid;
+
+const CREATE_WORK_TITLE_MUTATION: &str = "
+    mutation CreateWorkTitle(
+        $workId: Uuid!,
+        $languageCode: LanguageCode!,
+        $fullTitle: String!,
+        $title: String!,
+        $subtitle: String,
+        $canonical: Boolean!
+    ) {
+        createWorkTitle(data: {
+            workId: $workId
+            languageCode: $languageCode
+            fullTitle: $fullTitle
+            title: $title
+            subtitle: $subtitle
+            canonical: $canonical
+        }){
+            workTitleId
+            workId
+            languageCode
+            fullTitle
+            title
+            subtitle
+            canonical
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateWorkTitleRequest,
+    CreateWorkTitleRequestBody,
+    Variables,
+    CREATE_WORK_TITLE_MUTATION,
+    CreateWorkTitleResponseBody,
+    CreateWorkTitleResponseData,
+    PushCreateWorkTitle,
+    PushActionCreateWorkTitle
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub full_title: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub canonical: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWorkTitleResponseData {
+    pub create_work_title: Option<WorkTitle>,
+}
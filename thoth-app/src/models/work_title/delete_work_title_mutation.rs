@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::model::work_title::WorkTitle;
+use uuid::Uuid;
+
+const DELETE_WORK_TITLE_MUTATION: &str = "
+    mutation DeleteWorkTitle(
+        $workTitleId: Uuid!
+    ) {
+        deleteWorkTitle(
+            workTitleId: $workTitleId
+        ){
+            workTitleId
+            workId
+            languageCode
+            fullTitle
+            title
+            subtitle
+            canonical
+            createdAt
+            updatedAt
+        }
+    }
+";
+
+graphql_query_builder! {
+    DeleteWorkTitleRequest,
+    DeleteWorkTitleRequestBody,
+    Variables,
+    DELETE_WORK_TITLE_MUTATION,
+    DeleteWorkTitleResponseBody,
+    DeleteWorkTitleResponseData,
+    PushDeleteWorkTitle,
+    PushActionDeleteWorkTitle
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_title_id: Uuid,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWorkTitleResponseData {
+    pub delete_work_title: Option<WorkTitle>,
+}
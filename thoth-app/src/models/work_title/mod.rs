@@ -0,0 +1,2 @@
+pub mod create_work_title_mutation;
+pub mod delete_work_title_mutation;
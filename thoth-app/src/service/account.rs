@@ -7,6 +7,7 @@ use std::str::FromStr;
 use thiserror::Error;
 use thoth_api::account::model::AccountDetails;
 use thoth_api::account::model::LoginCredentials;
+use thoth_api::account::model::TwoFactorLogin;
 
 use crate::string::STORAGE_ERROR;
 use crate::SESSION_KEY;
@@ -17,12 +18,21 @@ type HttpFuture = Result<reqwest::Response, reqwest::Error>;
 pub enum AccountError {
     #[error("Authentication error")]
     AuthenticationError,
+    #[error("Account locked")]
+    AccountLocked,
     #[error("Response error")]
     ResponseError,
+    #[error("Too many requests")]
+    TooManyRequests,
+    #[error("Two-factor authentication code required")]
+    TwoFactorRequired,
 }
 
 const HTTP_UNAUTHORIZED: u16 = 401;
 const HTTP_FORBIDDEN: u16 = 403;
+const HTTP_LOCKED: u16 = 423;
+const HTTP_TOO_MANY_REQUESTS: u16 = 429;
+const TWO_FACTOR_HEADER: &str = "X-Thoth-Two-Factor";
 
 #[derive(Clone)]
 pub struct AccountService {
@@ -71,6 +81,18 @@ impl AccountService {
         .await
     }
 
+    /// Complete a single sign-on login that requires a second factor
+    pub async fn login_two_factor(
+        &mut self,
+        two_factor_login: TwoFactorLogin,
+    ) -> Result<AccountDetails, AccountError> {
+        self.post_request::<TwoFactorLogin, AccountDetails>(
+            "/account/oidc/2fa".to_string(),
+            two_factor_login,
+        )
+        .await
+    }
+
     pub async fn renew_token(&mut self) -> Result<AccountDetails, AccountError> {
         self.bodyless_post_request::<AccountDetails>("/account/token/renew".to_string())
             .await
@@ -103,8 +125,13 @@ impl AccountService {
                     }
                 } else {
                     match response.status().as_u16() {
+                        HTTP_UNAUTHORIZED if response.headers().contains_key(TWO_FACTOR_HEADER) => {
+                            Err(AccountError::TwoFactorRequired)
+                        }
                         HTTP_UNAUTHORIZED => Err(AccountError::AuthenticationError),
                         HTTP_FORBIDDEN => Err(AccountError::AuthenticationError),
+                        HTTP_LOCKED => Err(AccountError::AccountLocked),
+                        HTTP_TOO_MANY_REQUESTS => Err(AccountError::TooManyRequests),
                         _ => Err(AccountError::ResponseError),
                     }
                 }
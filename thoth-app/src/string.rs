@@ -9,7 +9,9 @@ strings! {
     NO => "No",
     INPUT_EMAIL => "Email",
     INPUT_PASSWORD => "Password",
+    INPUT_TWO_FACTOR_CODE => "Authentication or recovery code",
     TEXT_LOGIN => "Login",
+    TEXT_LOGIN_SSO => "Log in with single sign-on",
     SAVE_BUTTON => "Save",
     DELETE_BUTTON => "Delete",
     CANCEL_BUTTON => "Cancel",
@@ -29,11 +31,26 @@ strings! {
     PAGINATION_COUNT_CONTRIBUTORS => "Displaying contributors",
     PAGINATION_COUNT_PUBLICATIONS => "Displaying publications",
     AUTHENTICATION_ERROR => "Authentication failed",
+    ACCOUNT_LOCKED_ERROR => "Your account has been locked after too many failed login attempts. Please try again later.",
+    SSO_ERROR => "Single sign-on failed. Your identity provider account may not be linked to a Thoth account.",
     RESPONSE_ERROR => "Failed to obtain a valid response from the server.",
+    RATE_LIMIT_ERROR => "Too many attempts. Please wait a few minutes and try again.",
+    TWO_FACTOR_REQUIRED => "Enter the code from your authenticator app, or one of your recovery codes.",
     EMPTY_RELATIONS => "This work does not have any related works. Search works above to add a new relation.",
     EMPTY_CONTRIBUTIONS => "This work does not have any contributions. Search contributors above to add its contributions.",
     EMPTY_ISSUES => "This work is not part of a series. Search above to add a new series issue.",
     EMPTY_LANGUAGES => "This work does not have any languages. Search above to add a new language.",
+    EMPTY_TITLES => "This work does not have any titles recorded in specific languages. Click above to add titles.",
+    EMPTY_ABSTRACTS => "This work does not have any abstracts recorded in specific languages. Click above to add abstracts.",
+    EMPTY_WORK_IDENTIFIERS => "This work does not have any additional identifiers. Click above to add identifiers.",
+    EMPTY_EVENTS => "This work does not publish the proceedings of any conference or other event. Click above to add events.",
+    EMPTY_PRIZES => "This work has not been recorded as winning, or being shortlisted for, any prizes. Click above to add prizes.",
+    EMPTY_TEXT_CONTENTS => "This work does not have any review quotes, endorsements or other promotional text. Click above to add text.",
+    EMPTY_SUPPORTING_RESOURCES => "This work does not have any supporting resources such as cover images, sample content or author photos. Click above to add one.",
+    EMPTY_ALTERNATIVE_NAMES => "This contributor does not have any alternative names. Click above to add alternative names.",
+    EMPTY_PUBLICATION_IDENTIFIERS => "This publication does not have any additional identifiers. Click above to add identifiers.",
+    EMPTY_SALES_RIGHTS => "This publication does not have any sales rights, so world rights are assumed. Click above to add sales rights.",
+    EMPTY_USAGE_CONSTRAINTS => "This publication does not have any usage constraints. Click above to add usage constraints.",
     EMPTY_LOCATIONS => "This publication does not have any location information. Click above to add locations.",
     EMPTY_PUBLICATIONS => "This work does not have any publications. Click above to add associated publications",
     EMPTY_SUBJECTS => "This work does not have any subjects. Click above to add associated subjects",
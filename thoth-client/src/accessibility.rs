@@ -0,0 +1,180 @@
+use std::fmt;
+use thoth_api::model::publication as api;
+
+use crate::queries::work_query::{
+    AccessibilityFeature, AccessibilityHazard, AccessibilityStandard, WcagLevel, WcagVersion,
+    WorkPublications,
+};
+
+// Display the human-readable labels of the original enums, e.g. "No Flashing Hazard"
+macro_rules! display_via_api {
+    ($client:ty, $api:ty) => {
+        impl fmt::Display for $client {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match serde_json::to_value(self).and_then(serde_json::from_value::<$api>) {
+                    Ok(value) => write!(f, "{value}"),
+                    Err(_) => write!(f, "{self:?}"),
+                }
+            }
+        }
+    };
+}
+
+display_via_api!(AccessibilityFeature, api::AccessibilityFeature);
+display_via_api!(AccessibilityHazard, api::AccessibilityHazard);
+display_via_api!(AccessibilityStandard, api::AccessibilityStandard);
+display_via_api!(WcagVersion, api::WcagVersion);
+display_via_api!(WcagLevel, api::WcagLevel);
+
+impl AccessibilityFeature {
+    /// Equivalent schema.org `accessibilityFeature` value
+    pub fn schema_org(&self) -> &'static str {
+        match self {
+            AccessibilityFeature::TABLE_OF_CONTENTS => "tableOfContents",
+            AccessibilityFeature::INDEX_NAVIGATION => "index",
+            AccessibilityFeature::READING_ORDER => "readingOrder",
+            AccessibilityFeature::SHORT_ALTERNATIVE_TEXT => "alternativeText",
+            AccessibilityFeature::FULL_ALTERNATIVE_TEXT => "longDescription",
+            AccessibilityFeature::MATH_ML => "MathML",
+            AccessibilityFeature::CHEM_ML => "ChemML",
+            AccessibilityFeature::PRINT_PAGE_NUMBERS => "printPageNumbers",
+            AccessibilityFeature::SYNCHRONISED_AUDIO => "synchronizedAudioText",
+            AccessibilityFeature::TEXT_TO_SPEECH_HINTING => "ttsMarkup",
+            AccessibilityFeature::HIGH_CONTRAST_DISPLAY => "highContrastDisplay",
+            AccessibilityFeature::HIGH_CONTRAST_AUDIO => "highContrastAudio",
+            AccessibilityFeature::STRUCTURAL_NAVIGATION => "structuralNavigation",
+            AccessibilityFeature::ARIA_ROLES => "ARIA",
+            AccessibilityFeature::DISPLAY_TRANSFORMABILITY => "displayTransformability",
+            AccessibilityFeature::Other(_) => unreachable!(),
+        }
+    }
+}
+
+impl AccessibilityHazard {
+    /// Equivalent schema.org `accessibilityHazard` value
+    pub fn schema_org(&self) -> &'static str {
+        match self {
+            AccessibilityHazard::NONE => "none",
+            AccessibilityHazard::FLASHING => "flashing",
+            AccessibilityHazard::NO_FLASHING_HAZARD => "noFlashingHazard",
+            AccessibilityHazard::UNKNOWN_FLASHING_HAZARD => "unknownFlashingHazard",
+            AccessibilityHazard::SOUND => "sound",
+            AccessibilityHazard::NO_SOUND_HAZARD => "noSoundHazard",
+            AccessibilityHazard::UNKNOWN_SOUND_HAZARD => "unknownSoundHazard",
+            AccessibilityHazard::MOTION_SIMULATION => "motionSimulation",
+            AccessibilityHazard::NO_MOTION_SIMULATION_HAZARD => "noMotionSimulationHazard",
+            AccessibilityHazard::UNKNOWN_MOTION_SIMULATION_HAZARD => {
+                "unknownMotionSimulationHazard"
+            }
+            AccessibilityHazard::Other(_) => unreachable!(),
+        }
+    }
+}
+
+impl WorkPublications {
+    pub fn has_accessibility(&self) -> bool {
+        !self.accessibility_features.is_empty()
+            || !self.accessibility_hazards.is_empty()
+            || self.accessibility_standard.is_some()
+            || self.wcag_version.is_some()
+            || self.wcag_level.is_some()
+            || self.accessibility_certifier.is_some()
+            || self.accessibility_summary.is_some()
+    }
+
+    /// Conformance statement in the form recommended by the EPUB Accessibility specifications
+    /// for `dcterms:conformsTo`, e.g. "EPUB Accessibility 1.1 - WCAG 2.1 Level AA"
+    pub fn accessibility_conformance(&self) -> Option<String> {
+        let wcag = match (&self.wcag_version, &self.wcag_level) {
+            (Some(version), Some(level)) => Some(format!("{version} Level {level}")),
+            _ => None,
+        };
+        match (&self.accessibility_standard, wcag) {
+            (Some(AccessibilityStandard::EPUB_1_0_A), _) => {
+                Some("http://www.idpf.org/epub/a11y/accessibility-20170105.html#wcag-a".to_string())
+            }
+            (Some(AccessibilityStandard::EPUB_1_0_AA), _) => Some(
+                "http://www.idpf.org/epub/a11y/accessibility-20170105.html#wcag-aa".to_string(),
+            ),
+            (Some(standard), Some(wcag)) => Some(format!("{standard} - {wcag}")),
+            (Some(standard), None) => Some(standard.to_string()),
+            (None, wcag) => wcag,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_publication() -> WorkPublications {
+        serde_json::from_value(serde_json::json!({
+            "publicationId": "00000000-0000-0000-dddd-000000000001",
+            "publicationType": "EPUB",
+            "isbn": null,
+            "weightG": null,
+            "weightOz": null,
+            "widthMm": null,
+            "widthCm": null,
+            "widthIn": null,
+            "heightMm": null,
+            "heightCm": null,
+            "heightIn": null,
+            "depthMm": null,
+            "depthCm": null,
+            "depthIn": null,
+            "accessibilityFeatures": [],
+            "accessibilityHazards": [],
+            "accessibilityStandard": null,
+            "wcagVersion": null,
+            "wcagLevel": null,
+            "accessibilityCertifier": null,
+            "accessibilitySummary": null,
+            "technicalProtections": [],
+            "prices": [],
+            "locations": [],
+            "identifiers": [],
+            "salesRights": [],
+            "usageConstraints": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_accessibility_display() {
+        assert_eq!(
+            AccessibilityHazard::NO_FLASHING_HAZARD.to_string(),
+            "No Flashing Hazard"
+        );
+        assert_eq!(AccessibilityFeature::MATH_ML.to_string(), "MathML");
+        assert_eq!(WcagVersion::WCAG_2_1.to_string(), "WCAG 2.1");
+        assert_eq!(WcagLevel::AA.to_string(), "AA");
+    }
+
+    #[test]
+    fn test_accessibility_conformance() {
+        let mut publication = test_publication();
+        assert!(!publication.has_accessibility());
+        assert_eq!(publication.accessibility_conformance(), None);
+
+        publication.wcag_version = Some(WcagVersion::WCAG_2_1);
+        publication.wcag_level = Some(WcagLevel::AA);
+        assert!(publication.has_accessibility());
+        assert_eq!(
+            publication.accessibility_conformance(),
+            Some("WCAG 2.1 Level AA".to_string())
+        );
+
+        publication.accessibility_standard = Some(AccessibilityStandard::EPUB_1_1);
+        assert_eq!(
+            publication.accessibility_conformance(),
+            Some("EPUB Accessibility 1.1 - WCAG 2.1 Level AA".to_string())
+        );
+
+        publication.accessibility_standard = Some(AccessibilityStandard::EPUB_1_0_AA);
+        assert_eq!(
+            publication.accessibility_conformance(),
+            Some("http://www.idpf.org/epub/a11y/accessibility-20170105.html#wcag-aa".to_string())
+        );
+    }
+}
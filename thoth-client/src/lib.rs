@@ -1,8 +1,11 @@
+mod accessibility;
+mod localised;
 mod parameters;
 // GraphQLQuery derive macro breaks this linting rule - ignore while awaiting fix
 #[allow(clippy::derive_partial_eq_without_eq)]
 mod queries;
 
+pub use crate::localised::{LocalisedAbstract, LocalisedTitle};
 pub use crate::parameters::QueryParameters;
 use crate::parameters::{WorkQueryVariables, WorksQueryVariables};
 pub use crate::queries::work_query::*;
@@ -12,19 +15,24 @@ use crate::queries::{
 };
 pub use chrono::NaiveDate;
 use graphql_client::GraphQLQuery;
-use graphql_client::Response;
+use graphql_client::{QueryBody, Response};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
-use serde::Serialize;
-use std::future::Future;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 use thoth_api::model::Timestamp;
 use thoth_errors::{ThothError, ThothResult};
 use uuid::Uuid;
 
 /// Maximum number of allowed request retries attempts.
 const MAX_REQUEST_RETRIES: u32 = 5;
-
-type HttpFuture = Result<reqwest::Response, reqwest_middleware::Error>;
+/// Version of the automatic persisted query protocol
+const PERSISTED_QUERY_VERSION: i32 = 1;
+/// Error message returned by the server when it does not know a persisted query's hash
+const PERSISTED_QUERY_NOT_FOUND: &str = "PersistedQueryNotFound";
+/// Query document sent with every request, shared by all of the client's operations
+pub const QUERY_DOCUMENT: &str = work_query::QUERY;
 
 /// A GraphQL `ThothClient` to query metadata
 pub struct ThothClient {
@@ -46,14 +54,49 @@ impl ThothClient {
         }
     }
 
-    async fn post_request<T: Serialize + ?Sized>(
+    /// Send a query following the automatic persisted query protocol: only the hash of the query
+    /// document is sent at first, and the full document is sent if the server does not know it
+    async fn post_request<V: Serialize, R: DeserializeOwned>(
         &self,
-        request_body: &T,
-    ) -> impl Future<Output = HttpFuture> {
+        request_body: &QueryBody<V>,
+    ) -> ThothResult<Response<R>> {
+        let extensions = json!({
+            "persistedQuery": {
+                "version": PERSISTED_QUERY_VERSION,
+                "sha256Hash": query_hash(request_body.query),
+            }
+        });
+        let persisted_request = json!({
+            "operationName": request_body.operation_name,
+            "variables": request_body.variables,
+            "extensions": extensions,
+        });
+        // Servers without persisted query support reject requests without a query document
+        if let Ok(response) = self.send(&persisted_request).await?.json::<Value>().await {
+            if !is_persisted_query_not_found(&response) {
+                return serde_json::from_value(response).map_err(Into::into);
+            }
+        }
+        let full_request = json!({
+            "query": request_body.query,
+            "operationName": request_body.operation_name,
+            "variables": request_body.variables,
+            "extensions": extensions,
+        });
+        self.send(&full_request)
+            .await?
+            .json()
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn send(&self, request_body: &Value) -> ThothResult<reqwest::Response> {
         self.http_client
             .post(&self.graphql_endpoint)
-            .json(&request_body)
+            .json(request_body)
             .send()
+            .await
+            .map_err(Into::into)
     }
 
     /// Get a `Work` from Thoth given its `work_id`
@@ -80,8 +123,8 @@ impl ThothClient {
     pub async fn get_work(&self, work_id: Uuid, parameters: QueryParameters) -> ThothResult<Work> {
         let variables: work_query::Variables = WorkQueryVariables::new(work_id, parameters).into();
         let request_body = WorkQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
-        let response_body: Response<work_query::ResponseData> = res.json().await?;
+        let response_body: Response<work_query::ResponseData> =
+            self.post_request(&request_body).await?;
         match response_body.data {
             Some(data) => Ok(data.work),
             None => Err(ThothError::EntityNotFound),
@@ -118,8 +161,8 @@ impl ThothClient {
         let variables: works_query::Variables =
             WorksQueryVariables::new(publishers, limit, offset, parameters).into();
         let request_body = WorksQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
-        let response_body: Response<works_query::ResponseData> = res.json().await?;
+        let response_body: Response<works_query::ResponseData> =
+            self.post_request(&request_body).await?;
         match response_body.data {
             Some(data) => Ok(data.works.iter().map(|w| w.clone().into()).collect()), // convert works_query::Work into work_query::Work
             None => Err(ThothError::EntityNotFound),
@@ -149,8 +192,8 @@ impl ThothClient {
     pub async fn get_work_count(&self, publishers: Option<Vec<Uuid>>) -> ThothResult<i64> {
         let variables = work_count_query::Variables { publishers };
         let request_body = WorkCountQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
-        let response_body: Response<work_count_query::ResponseData> = res.json().await?;
+        let response_body: Response<work_count_query::ResponseData> =
+            self.post_request(&request_body).await?;
         match response_body.data {
             Some(data) => Ok(data.work_count),
             None => Err(ThothError::EntityNotFound),
@@ -181,8 +224,8 @@ impl ThothClient {
     pub async fn get_work_last_updated(&self, work_id: Uuid) -> ThothResult<Timestamp> {
         let variables = work_last_updated_query::Variables { work_id };
         let request_body = WorkLastUpdatedQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
-        let response_body: Response<work_last_updated_query::ResponseData> = res.json().await?;
+        let response_body: Response<work_last_updated_query::ResponseData> =
+            self.post_request(&request_body).await?;
         match response_body.data {
             Some(data) => Ok(data.work.updated_at_with_relations),
             None => Err(ThothError::EntityNotFound),
@@ -216,8 +259,8 @@ impl ThothClient {
     ) -> ThothResult<Timestamp> {
         let variables = works_last_updated_query::Variables { publishers };
         let request_body = WorksLastUpdatedQuery::build_query(variables);
-        let res = self.post_request(&request_body).await.await?;
-        let response_body: Response<works_last_updated_query::ResponseData> = res.json().await?;
+        let response_body: Response<works_last_updated_query::ResponseData> =
+            self.post_request(&request_body).await?;
         match response_body.data {
             Some(data) => {
                 if let Some(work) = data.works.first() {
@@ -230,3 +273,47 @@ impl ThothClient {
         }
     }
 }
+
+/// Hex encoded SHA-256 digest of a query document, used to identify it as a persisted query
+fn query_hash(query: &str) -> String {
+    Sha256::digest(query.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn is_persisted_query_not_found(response: &Value) -> bool {
+    response["errors"].as_array().is_some_and(|errors| {
+        errors
+            .iter()
+            .any(|error| error["message"] == PERSISTED_QUERY_NOT_FOUND)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_hash() {
+        assert_eq!(
+            query_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_is_persisted_query_not_found() {
+        assert!(is_persisted_query_not_found(&json!({
+            "data": null,
+            "errors": [{ "message": "PersistedQueryNotFound" }]
+        })));
+        assert!(!is_persisted_query_not_found(&json!({
+            "data": null,
+            "errors": [{ "message": "Invalid UUID" }]
+        })));
+        assert!(!is_persisted_query_not_found(
+            &json!({ "data": { "workCount": 1 } })
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+use std::str::FromStr;
+use thoth_api::model::language::LanguageCode;
+
+use crate::queries::work_query::{
+    self, AbstractType, LanguageRelation, Work, WorkRelationsRelatedWork,
+};
+
+/// Title of a work in a given language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalisedTitle<'a> {
+    /// Unknown when the work has no titles recorded in specific languages
+    pub language_code: Option<LanguageCode>,
+    pub full_title: &'a str,
+    pub title: &'a str,
+    pub subtitle: Option<&'a str>,
+    pub canonical: bool,
+}
+
+/// Abstract of a work in a given language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalisedAbstract<'a> {
+    /// Unknown when the work has no abstracts recorded in specific languages
+    pub language_code: Option<LanguageCode>,
+    pub content: &'a str,
+    pub canonical: bool,
+}
+
+fn language_code(code: &work_query::LanguageCode) -> Option<LanguageCode> {
+    LanguageCode::from_str(&code.to_string()).ok()
+}
+
+// `Work` and `WorkRelationsRelatedWork` are distinct types generated from the same fields
+macro_rules! impl_localised {
+    ($work:ty) => {
+        impl $work {
+            /// Titles of the work in each of its languages, with the canonical title first.
+            /// Works without a canonical title recorded in a specific language fall back to
+            /// their own fields for it.
+            pub fn localised_titles(&self) -> Vec<LocalisedTitle<'_>> {
+                let mut titles: Vec<LocalisedTitle> = self
+                    .titles
+                    .iter()
+                    .map(|t| LocalisedTitle {
+                        language_code: language_code(&t.language_code),
+                        full_title: &t.full_title,
+                        title: &t.title,
+                        subtitle: t.subtitle.as_deref(),
+                        canonical: t.canonical,
+                    })
+                    .collect();
+                if !titles.iter().any(|t| t.canonical) {
+                    titles.push(LocalisedTitle {
+                        language_code: None,
+                        full_title: &self.full_title,
+                        title: &self.title,
+                        subtitle: self.subtitle.as_deref(),
+                        canonical: true,
+                    });
+                }
+                titles.sort_by_key(|t| !t.canonical);
+                titles
+            }
+
+            /// Abstracts of the given type in each of the work's languages, with the canonical
+            /// abstract first. Works without a canonical abstract of this type recorded in a
+            /// specific language fall back to their own fields for it.
+            pub fn localised_abstracts(
+                &self,
+                abstract_type: AbstractType,
+            ) -> Vec<LocalisedAbstract<'_>> {
+                let mut abstracts: Vec<LocalisedAbstract> = self
+                    .abstracts
+                    .iter()
+                    .filter(|a| a.abstract_type == abstract_type)
+                    .map(|a| LocalisedAbstract {
+                        language_code: language_code(&a.language_code),
+                        content: &a.content,
+                        canonical: a.canonical,
+                    })
+                    .collect();
+                if !abstracts.iter().any(|a| a.canonical) {
+                    let content = match abstract_type {
+                        AbstractType::SHORT => self.short_abstract.as_deref(),
+                        _ => self.long_abstract.as_deref(),
+                    };
+                    abstracts.extend(content.map(|content| LocalisedAbstract {
+                        language_code: None,
+                        content,
+                        canonical: true,
+                    }));
+                }
+                abstracts.sort_by_key(|a| !a.canonical);
+                abstracts
+            }
+
+            /// Language of the canonical title, if recorded
+            pub fn canonical_language(&self) -> Option<LanguageCode> {
+                self.titles
+                    .iter()
+                    .find(|t| t.canonical)
+                    .and_then(|t| language_code(&t.language_code))
+            }
+
+            /// Main language of the work's text, if recorded
+            pub fn main_language(&self) -> Option<LanguageCode> {
+                self.languages
+                    .iter()
+                    .find(|l| l.main_language)
+                    .and_then(|l| language_code(&l.language_code))
+            }
+
+            /// Title of the work in the language it was translated from, if recorded
+            pub fn original_language_title(&self) -> Option<LocalisedTitle<'_>> {
+                let original_language = self
+                    .languages
+                    .iter()
+                    .find(|l| l.language_relation == LanguageRelation::TRANSLATED_FROM)
+                    .and_then(|l| language_code(&l.language_code))?;
+                self.localised_titles()
+                    .into_iter()
+                    .find(|t| !t.canonical && t.language_code == Some(original_language))
+            }
+        }
+    };
+}
+
+impl_localised!(Work);
+impl_localised!(WorkRelationsRelatedWork);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::work_query::{WorkAbstracts, WorkLanguages, WorkTitles};
+
+    fn test_work() -> Work {
+        serde_json::from_value(serde_json::json!({
+            "workId": "00000000-0000-0000-aaaa-000000000001",
+            "workStatus": "ACTIVE",
+            "fullTitle": "Title: Subtitle",
+            "title": "Title",
+            "subtitle": "Subtitle",
+            "workType": "MONOGRAPH",
+            "edition": 1,
+            "copyrightHolder": null,
+            "shortAbstract": "Short",
+            "longAbstract": "Long",
+            "imprint": {
+                "imprintName": "Imprint",
+                "imprintUrl": null,
+                "crossmarkDoi": null,
+                "publisher": {
+                    "publisherName": "Publisher",
+                    "publisherShortname": null,
+                    "publisherUrl": null
+                }
+            },
+            "issues": [],
+            "contributions": [],
+            "languages": [],
+            "titles": [],
+            "abstracts": [],
+            "identifiers": [],
+            "publications": [],
+            "subjects": [],
+            "fundings": [],
+            "prizes": [],
+            "events": [],
+            "textContents": [],
+            "supportingResources": [],
+            "relations": [],
+            "references": []
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_localised_fallback() {
+        let work = test_work();
+        assert_eq!(
+            work.localised_titles(),
+            vec![LocalisedTitle {
+                language_code: None,
+                full_title: "Title: Subtitle",
+                title: "Title",
+                subtitle: Some("Subtitle"),
+                canonical: true,
+            }]
+        );
+        assert_eq!(
+            work.localised_abstracts(AbstractType::SHORT),
+            vec![LocalisedAbstract {
+                language_code: None,
+                content: "Short",
+                canonical: true,
+            }]
+        );
+        assert_eq!(work.canonical_language(), None);
+    }
+
+    #[test]
+    fn test_localised_languages() {
+        let mut work = test_work();
+        work.titles = vec![
+            WorkTitles {
+                language_code: work_query::LanguageCode::ENG,
+                full_title: "Title".to_string(),
+                title: "Title".to_string(),
+                subtitle: None,
+                canonical: false,
+            },
+            WorkTitles {
+                language_code: work_query::LanguageCode::FRE,
+                full_title: "Titre".to_string(),
+                title: "Titre".to_string(),
+                subtitle: None,
+                canonical: true,
+            },
+        ];
+        work.abstracts = vec![WorkAbstracts {
+            language_code: work_query::LanguageCode::ENG,
+            abstract_type: AbstractType::LONG,
+            content: "Long".to_string(),
+            canonical: true,
+        }];
+        let titles = work.localised_titles();
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles[0].language_code, Some(LanguageCode::Fre));
+        assert_eq!(titles[0].title, "Titre");
+        assert_eq!(titles[1].language_code, Some(LanguageCode::Eng));
+        assert_eq!(work.canonical_language(), Some(LanguageCode::Fre));
+        assert_eq!(work.original_language_title(), None);
+        work.languages = vec![WorkLanguages {
+            language_code: work_query::LanguageCode::ENG,
+            language_relation: LanguageRelation::TRANSLATED_FROM,
+            main_language: false,
+        }];
+        assert_eq!(
+            work.original_language_title().map(|t| t.title),
+            Some("Title")
+        );
+        assert_eq!(work.main_language(), None);
+        assert_eq!(
+            work.localised_abstracts(AbstractType::LONG),
+            vec![LocalisedAbstract {
+                language_code: Some(LanguageCode::Eng),
+                content: "Long",
+                canonical: true,
+            }]
+        );
+        // No short abstracts recorded in specific languages
+        assert_eq!(
+            work.localised_abstracts(AbstractType::SHORT)[0].language_code,
+            None
+        );
+    }
+
+    #[test]
+    fn test_localised_without_canonical_entry() {
+        // A newly created work with a translated title and abstract, but no canonical entries
+        let mut work = test_work();
+        work.titles = vec![WorkTitles {
+            language_code: work_query::LanguageCode::FRE,
+            full_title: "Titre".to_string(),
+            title: "Titre".to_string(),
+            subtitle: None,
+            canonical: false,
+        }];
+        work.abstracts = vec![WorkAbstracts {
+            language_code: work_query::LanguageCode::FRE,
+            abstract_type: AbstractType::LONG,
+            content: "Long en français".to_string(),
+            canonical: false,
+        }];
+        let titles = work.localised_titles();
+        assert_eq!(titles.len(), 2);
+        assert_eq!(
+            titles[0],
+            LocalisedTitle {
+                language_code: None,
+                full_title: "Title: Subtitle",
+                title: "Title",
+                subtitle: Some("Subtitle"),
+                canonical: true,
+            }
+        );
+        assert_eq!(titles[1].language_code, Some(LanguageCode::Fre));
+        assert!(!titles[1].canonical);
+        let abstracts = work.localised_abstracts(AbstractType::LONG);
+        assert_eq!(abstracts.len(), 2);
+        assert_eq!(abstracts[0].content, "Long");
+        assert!(abstracts[0].canonical);
+        assert_eq!(abstracts[1].language_code, Some(LanguageCode::Fre));
+    }
+}
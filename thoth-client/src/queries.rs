@@ -3,8 +3,11 @@ use std::fmt;
 use chrono::naive::NaiveDate;
 use graphql_client::GraphQLQuery;
 use thoth_api::model::contribution::ContributionType;
+use thoth_api::model::identifier::{FunderId, Isni, Lcnaf, Ringgold, Viaf, WikidataId};
+use thoth_api::model::institution::CountryCode;
 use thoth_api::model::language::LanguageRelation;
 use thoth_api::model::publication::PublicationType;
+use thoth_api::model::work::WorkType;
 use thoth_api::model::Doi;
 use thoth_api::model::Isbn;
 use thoth_api::model::Orcid;
@@ -55,6 +58,69 @@ impl fmt::Display for work_query::LocationPlatform {
     }
 }
 
+impl work_query::CountryCode {
+    /// Two-letter ISO 3166-1 code of the country, as required by ONIX
+    pub fn alpha_2(&self) -> Option<&'static str> {
+        serde_json::to_value(self)
+            .and_then(serde_json::from_value::<CountryCode>)
+            .map(|country_code| country_code.alpha_2())
+            .ok()
+    }
+
+    /// English name of the country, e.g. "United Kingdom"
+    pub fn name(&self) -> Option<String> {
+        serde_json::to_value(self)
+            .and_then(serde_json::from_value::<CountryCode>)
+            .map(|country_code| country_code.to_string())
+            .ok()
+    }
+}
+
+impl work_query::WorkType {
+    /// Whether works of this type are part of a larger work, like chapters or journal articles
+    pub fn is_component(&self) -> bool {
+        serde_json::to_value(self)
+            .and_then(serde_json::from_value::<WorkType>)
+            .is_ok_and(|work_type| work_type.is_component())
+    }
+}
+
+impl work_query::WorkIssues {
+    /// Whether the issue records any printed volume, issue number or part
+    pub fn has_numbering(&self) -> bool {
+        self.volume.is_some() || self.issue_number.is_some() || self.part.is_some()
+    }
+
+    /// Volume of the issue as printed. Issues without any printed numbering fall back to
+    /// their position in the series.
+    pub fn volume_designation(&self) -> Option<String> {
+        match &self.volume {
+            Some(volume) => Some(volume.clone()),
+            None if !self.has_numbering() => Some(self.issue_ordinal.to_string()),
+            None => None,
+        }
+    }
+
+    /// Full numbering of the issue as printed, e.g. "12a, no. 3, pt. 1". Issues without any
+    /// printed numbering fall back to their position in the series.
+    pub fn numbering(&self) -> String {
+        if !self.has_numbering() {
+            return self.issue_ordinal.to_string();
+        }
+        let mut numbering = vec![];
+        if let Some(volume) = &self.volume {
+            numbering.push(volume.clone());
+        }
+        if let Some(issue_number) = &self.issue_number {
+            numbering.push(format!("no. {issue_number}"));
+        }
+        if let Some(part) = &self.part {
+            numbering.push(format!("pt. {part}"));
+        }
+        numbering.join(", ")
+    }
+}
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "assets/schema.graphql",
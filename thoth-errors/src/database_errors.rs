@@ -26,6 +26,18 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "affiliation_affiliation_ordinal_check" => "An affiliation ordinal number must be greater than 0.",
     "affiliation_position_check" => "Position must not be an empty string.",
     "affiliation_uniq_ord_in_contribution_idx" => "An affiliation with this ordinal number already exists.",
+    "alternative_name_first_name_check" => "First name must not be an empty string.",
+    "alternative_name_full_name_check" => "Full name must not be an empty string.",
+    "alternative_name_last_name_check" => "Last name must not be an empty string.",
+    "alternative_name_script_code_check" => "Invalid ISO 15924 script code.",
+    "asset_asset_url_check" => "Asset URL must not be an empty string.",
+    "asset_checksum_check" => "Checksum must be a hex-encoded SHA-256 digest.",
+    "asset_file_size_check" => "File size must be greater than 0.",
+    "asset_filename_check" => "Filename must not be an empty string.",
+    "asset_mime_type_check" => "Invalid MIME type.",
+    "asset_publication_id_check" => "Publication files must belong to a publication, and covers must not.",
+    "asset_storage_key_check" => "Storage key must not be an empty string.",
+    "asset_storage_key_uniq_idx" => "This file has already been uploaded.",
     "contribution_biography_check" => "Biography must not be an empty string.",
     "contribution_contribution_ordinal_check" => "A contribution ordinal number must be greater than 0.",
     "contribution_contribution_ordinal_work_id_uniq" => "A contribution with this ordinal number already exists.",
@@ -35,11 +47,27 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "contribution_work_id_contributor_id_contribution_type_uniq" => "A contribution of this type already exists for this contributor.",
     "contributor_first_name_check" => "First name must not be an empty string.",
     "contributor_full_name_check" => "Full name must not be an empty string.",
+    "contributor_isni_check" => "Invalid ISNI.",
+    "contributor_isni_uniq_idx" => "A contributor with this ISNI already exists.",
     "contributor_last_name_check" => "Last name must not be an empty string.",
+    "contributor_lcnaf_check" => "Invalid Library of Congress Name Authority File identifier.",
+    "contributor_lcnaf_uniq_idx" => "A contributor with this Library of Congress Name Authority File identifier already exists.",
     "contributor_orcid_check" => "Invalid ORCID ID.",
+    "contributor_viaf_check" => "Invalid VIAF ID.",
+    "contributor_viaf_uniq_idx" => "A contributor with this VIAF ID already exists.",
     "contributor_website_check" => "Website must not be an empty string.",
+    "contributor_wikidata_check" => "Invalid Wikidata ID.",
+    "contributor_wikidata_uniq_idx" => "A contributor with this Wikidata ID already exists.",
     "doi_uniq_idx" => "A work with this DOI already exists.",
     "email_uniq_idx" => "An account with this email already exists.",
+    "event_event_acronym_check" => "Event acronym must not be an empty string.",
+    "event_event_end_date_check" => "Event end date must not be earlier than its start date, which must also be set.",
+    "event_event_location_check" => "Event location must not be an empty string.",
+    "event_event_name_check" => "Event name must not be an empty string.",
+    "event_event_number_check" => "Event number must not be an empty string.",
+    "event_sponsor_event_id_institution_id_uniq" => "This institution is already a sponsor of this event.",
+    "event_sponsor_sponsor_ordinal_check" => "A sponsor ordinal number must be greater than 0.",
+    "event_sponsor_sponsor_ordinal_event_id_uniq" => "A sponsor with this ordinal number already exists.",
     "funding_grant_number_check" => "Grant number must not be an empty string.",
     "funding_jurisdiction_check" => "Jurisdiction must not be an empty string.",
     "funding_program_check" => "Program must not be an empty string.",
@@ -48,13 +76,27 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "imprint_imprint_name_check" => "Imprint name must not be an empty string.",
     "imprint_imprint_url_check" => "Invalid URL.",
     "imprint_uniq_idx" => "An imprint with this name already exists.",
+    "institution_crossref_funder_id_check" => "Invalid Crossref Funder ID.",
+    "institution_crossref_funder_id_uniq_idx" => "An institution with this Crossref Funder ID already exists.",
     "institution_doi_uniq_idx" => "An institution with this DOI already exists.",
+    "institution_identifier_mapping_crossref_funder_id_check" => "Invalid Crossref Funder ID.",
+    "institution_identifier_mapping_identifier_check" => "An institution identifier mapping must relate at least two identifiers.",
+    "institution_identifier_mapping_isni_check" => "Invalid ISNI.",
+    "institution_identifier_mapping_ringgold_check" => "Invalid Ringgold ID.",
+    "institution_identifier_mapping_ror_check" => "Invalid ROR.",
     "institution_institution_doi_check" => "Invalid DOI.",
     "institution_institution_name_check" => "Name must not be an empty string.",
+    "institution_isni_check" => "Invalid ISNI.",
+    "institution_isni_uniq_idx" => "An institution with this ISNI already exists.",
+    "institution_ringgold_check" => "Invalid Ringgold ID.",
+    "institution_ringgold_uniq_idx" => "An institution with this Ringgold ID already exists.",
     "institution_ror_check" => "Invalid ROR.",
+    "issue_issue_number_check" => "Issue number must not be an empty string.",
     "issue_issue_ordinal_check" => "An issue ordinal number must be greater than 0.",
+    "issue_part_check" => "Part must not be an empty string.",
     "issue_series_id_work_id_uniq" => "An issue on the selected series already exists for the this work.",
     "issue_uniq_ord_in_series_idx" => "An issue with this ordinal number already exists.",
+    "issue_volume_check" => "Volume must not be an empty string.",
     "language_uniq_work_idx" => "Duplicate language code.",
     "location_full_text_url_check" => "Invalid URL.",
     "location_landing_page_check" => "Invalid URL.",
@@ -62,12 +104,27 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "location_uniq_platform_idx" => "A location on the selected platform already exists.",
     "location_url_check" => "A location must have a landing page and/or a full text URL.",
     "orcid_uniq_idx" => "A contributor with this ORCID ID already exists.",
-    "price_publication_id_currency_code_uniq" => "A price in this currency already exists for this publication.",
+    "price_discount_code_check" => "Discount code must not be empty.",
+    "price_effective_dates_check" => "Price effective from date must not be later than its effective until date.",
+    "price_exclusion_check" => "Countries and Regions can only be excluded from an included Region.",
+    "price_publication_id_currency_code_price_type_uniq" => "A price of this type in this currency, territory and effective date already exists for this publication.",
+    "price_regions_excluded_check" => "Invalid excluded region code.",
+    "price_regions_included_check" => "Invalid included region code.",
+    "price_tax_rate_percent_check" => "Tax rate must be between 0 and 100 percent.",
     "price_unit_price_check" => "Price values must be greater than zero. To indicate an unpriced Publication, omit all Prices.",
+    "prize_prize_jury_check" => "Prize jury must not be an empty string.",
+    "prize_prize_name_check" => "Prize name must not be an empty string.",
+    "prize_prize_statement_check" => "Prize statement must not be an empty string.",
+    "prize_prize_year_check" => "Prize year must be a four-digit year.",
+    "publication_accessibility_certifier_check" => "Accessibility certifier must not be an empty string.",
+    "publication_accessibility_summary_check" => "Accessibility summary must not be an empty string.",
+    "publication_carton_quantity_check" => "Carton quantity must be greater than 0.",
     "publication_depth_in_check" => "Publication depth must be greater than 0.0.",
     "publication_depth_in_not_missing" => "When specifying Depth, both values (mm and in) must be supplied.",
     "publication_depth_mm_check" => "Publication depth must be greater than 0.0.",
     "publication_depth_mm_not_missing" => "When specifying Depth, both values (mm and in) must be supplied.",
+    "publication_file_size_kb_check" => "File size must be greater than 0.",
+    "publication_format_version_check" => "Format version must not be an empty string.",
     "publication_height_in_check" => "Publication height must be greater than 0.0.",
     "publication_height_in_not_missing" => "When specifying Height, both values (mm and in) must be supplied.",
     "publication_height_mm_check" => "Publication height must be greater than 0.0.",
@@ -75,7 +132,13 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "publication_isbn_check" => "A valid ISBN must be exactly 17 characters.",
     "publication_isbn_idx" => "A publication with this ISBN already exists.",
     "publication_non_physical_no_dimensions" => "Width/Height/Depth/Weight are only applicable to physical (Paperback/Hardback) Publications.",
+    "publication_non_physical_no_print_attributes" => "Binding, paper, colour, trim, page count, cover finish and carton quantity are only applicable to physical (Paperback/Hardback) Publications.",
+    "publication_page_count_check" => "Publication page count must be greater than 0.",
+    "publication_physical_no_accessibility" => "Accessibility metadata is only applicable to digital Publications.",
+    "publication_physical_no_digital_attributes" => "Format version, file size, technical protection and layout are only applicable to digital Publications.",
     "publication_publication_type_work_id_uniq" => "A publication with the selected type already exists.",
+    "publication_technical_protections_none_check" => "A technical protection of None must not be combined with any other.",
+    "publication_wcag_conformance_check" => "WCAG version and level must be given together, and are required for EPUB Accessibility 1.1 conformance.",
     "publication_weight_g_check" => "Publication weight must be greater than 0.0.",
     "publication_weight_g_not_missing" => "When specifying Weight, both values (g and oz) must be supplied.",
     "publication_weight_oz_check" => "Publication weight must be greater than 0.0.",
@@ -110,24 +173,52 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "reference_url_check" => "Invalid URL.",
     "reference_volume_check" => "Volume must not be an empty string.",
     "reference_volume_title_check" => "Volume title must not be an empty string.",
+    "sales_rights_exclusion_check" => "Countries and Regions can only be excluded from an included Region.",
+    "sales_rights_regions_excluded_check" => "Invalid excluded region code.",
+    "sales_rights_regions_included_check" => "Invalid included region code.",
+    "sales_rights_restriction_check" => "A Sales Restriction note requires a Sales Restriction type, and an Unspecified Sales Restriction must be described by a note.",
+    "sales_rights_sales_restriction_note_check" => "Sales restriction note must not be an empty string.",
+    "sales_rights_territory_check" => "Sales Rights must apply to at least one Country or Region.",
+    "series_contribution_contribution_ordinal_check" => "A contribution ordinal number must be greater than 0.",
+    "series_contribution_contribution_ordinal_series_id_uniq" => "A series contribution with this ordinal number already exists.",
+    "series_contribution_first_name_check" => "First name must not be an empty string.",
+    "series_contribution_full_name_check" => "Full name must not be an empty string.",
+    "series_contribution_last_name_check" => "Last name must not be an empty string.",
+    "series_contribution_series_id_contributor_id_contribution_type_uniq" => "A series contribution of this type already exists for this contributor.",
     "series_issn_digital_check" => "Invalid digital ISSN.",
     "series_issn_digital_idx" => "A series with this digital ISSN already exists.",
     "series_issn_print_check" => "Invalid print ISSN.",
     "series_issn_print_idx" => "A series with this print ISSN already exists.",
+    "series_not_own_parent" => "A series cannot be its own parent series.",
     "series_series_cfp_url_check" => "Invalid CFP URL.",
     "series_series_description_check" => "Series description must not be an empty string.",
     "series_series_name_check" => "Series name must not be an empty string.",
     "series_series_url_check" => "Invalid series URL.",
     "subject_subject_code_check" => "Subject codes must not be an empty string.",
     "subject_subject_ordinal_check" => "A subject ordinal number must be greater than 0.",
+    "supporting_resource_caption_check" => "Caption must not be an empty string.",
+    "supporting_resource_cover_rendition_check" => "Only front covers and cover thumbnails can be cover renditions.",
+    "supporting_resource_cover_rendition_uniq_idx" => "A rendition of this size already exists for this work's cover.",
+    "supporting_resource_credit_check" => "Credit must not be an empty string.",
+    "supporting_resource_height_px_check" => "Image height must be greater than 0.",
+    "supporting_resource_resource_url_check" => "Invalid resource URL.",
+    "supporting_resource_width_px_check" => "Image width must be greater than 0.",
+    "text_content_content_check" => "Text content must not be an empty string.",
+    "text_content_source_title_check" => "Source title must not be an empty string.",
+    "text_content_source_url_check" => "Invalid source URL.",
+    "text_content_text_author_check" => "Text author must not be an empty string.",
+    "usage_constraint_limit_check" => "A usage limit and its unit must be given together, and only when usage is permitted subject to a limit.",
+    "usage_constraint_publication_id_usage_type_uniq" => "A usage constraint of this type already exists for this publication.",
+    "usage_constraint_usage_limit_check" => "Usage limit must be greater than 0.",
     "work_active_withdrawn_date_check" => "Withdrawn Date can only be set for a withdrawn or superseded Work.",
     "work_active_publication_date_check" => "Active, superseded and withdrawn works must have a publication date.",
+    "work_article_number_check" => "Article number must not be an empty string.",
     "work_audio_count_check" => "An audio count must be greater than 0.",
     "work_bibliography_note_check" => "Bibliography note must not be an empty string.",
-    "work_chapter_no_edition" => "Chapters must not have an edition number.",
-    "work_chapter_no_lccn" => "Chapters must not have a LCCN.",
-    "work_chapter_no_oclc" => "Chapters must not have an OCLC number.",
-    "work_chapter_no_toc" => "Chapters must not have a table of contents.",
+    "work_chapter_no_edition" => "Chapters and journal articles must not have an edition number.",
+    "work_chapter_no_lccn" => "Chapters and journal articles must not have a LCCN.",
+    "work_chapter_no_oclc" => "Chapters and journal articles must not have an OCLC number.",
+    "work_chapter_no_toc" => "Chapters and journal articles must not have a table of contents.",
     "work_copyright_holder_check" => "Copyright holder must not be an empty string.",
     "work_cover_caption_check" => "Cover caption must not be an empty string.",
     "work_cover_url_check" => "Invalid cover URL.",
@@ -143,10 +234,11 @@ static DATABASE_CONSTRAINT_ERRORS: Map<&'static str, &'static str> = phf_map! {
     "work_lccn_check" => "LCCN must not be an empty string.",
     "work_license_check" => "Invalid license URL.",
     "work_long_abstract_check" => "Long abstract must not be an empty string.",
-    "work_non_chapter_has_edition" => "Edition number is required (except for chapters).",
-    "work_non_chapter_no_first_page" => "First page can only be set for book chapters.",
-    "work_non_chapter_no_last_page" => "Last page can only be set for book chapters.",
-    "work_non_chapter_no_page_interval" => "Page interval can only be set for book chapters.",
+    "work_non_article_no_article_number" => "Article number can only be set for journal articles.",
+    "work_non_chapter_has_edition" => "Edition number is required (except for chapters and journal articles).",
+    "work_non_chapter_no_first_page" => "First page can only be set for book chapters and journal articles.",
+    "work_non_chapter_no_last_page" => "Last page can only be set for book chapters and journal articles.",
+    "work_non_chapter_no_page_interval" => "Page interval can only be set for book chapters and journal articles.",
     "work_oclc_check" => "OCLC number must not be an empty string.",
     "work_page_breakdown_check" => "Page breakdown must not be an empty string.",
     "work_page_count_check" => "A page count must be greater than 0.",
@@ -29,10 +29,24 @@ pub enum ThothError {
     Unauthorised,
     #[error("Failed to validate token.")]
     InvalidToken,
+    #[error("Too many failed login attempts. Please try again later.")]
+    AccountLocked,
+    #[error("Password does not meet the password policy: {0}")]
+    PasswordPolicyError(String),
+    #[error("A two-factor authentication code is required.")]
+    TwoFactorRequired,
+    #[error("Two-factor authentication error: {0}")]
+    TwoFactorError(String),
     #[error("No record was found for the given ID.")]
     EntityNotFound,
     #[error("Issue's Work and Series cannot have different Imprints.")]
     IssueImprintsError,
+    #[error("A Series and its parent Series cannot have different Imprints.")]
+    SeriesImprintsError,
+    #[error(
+        "Series can only be nested one level deep, and a Series cannot be a subseries of itself."
+    )]
+    SeriesHierarchyError,
     #[error("{0} is not a valid metadata specification")]
     InvalidMetadataSpecification(String),
     #[error("Invalid UUID supplied.")]
@@ -55,6 +69,20 @@ pub enum ThothError {
     IsbnParseError(String),
     #[error("{0} is not a validly formatted ROR ID and will not be saved")]
     RorParseError(String),
+    #[error("{0} is not a recognised licence URL or SPDX identifier and will not be saved")]
+    LicenseParseError(String),
+    #[error("{0} is not a validly formatted {1} and will not be saved")]
+    IdentifierParseError(String, String),
+    #[error("{0} identifiers cannot be assigned to {1}")]
+    IdentifierTypeNotAllowed(String, String),
+    #[error("{0} is not a valid ISO 15924 script code")]
+    ScriptCodeParseError(String),
+    #[error("{0} is not a valid ONIX region code")]
+    RegionCodeParseError(String),
+    #[error("Invalid markup: {0}")]
+    InvalidMarkup(String),
+    #[error("Line {0} of the identifier mapping file is invalid: {1}")]
+    IdentifierMappingError(usize, String),
     #[error("Cannot parse ORCID: no value provided")]
     OrcidEmptyError,
     #[error("Cannot parse DOI: no value provided")]
@@ -63,10 +91,14 @@ pub enum ThothError {
     IsbnEmptyError,
     #[error("Cannot parse ROR ID: no value provided")]
     RorEmptyError,
-    #[error("Works of type Book Chapter cannot have ISBNs in their Publications.")]
+    #[error("Cannot parse {0}: no value provided")]
+    IdentifierEmptyError(String),
+    #[error(
+        "Works of type Book Chapter or Journal Article cannot have ISBNs in their Publications."
+    )]
     ChapterIsbnError,
     #[error(
-        "Works of type Book Chapter cannot have Width, Height, Depth or Weight in their Publications."
+        "Works of type Book Chapter or Journal Article cannot have Width, Height, Depth or Weight in their Publications."
     )]
     ChapterDimensionError,
     #[error("Each Publication must have exactly one canonical Location.")]
@@ -87,12 +119,65 @@ pub enum ThothError {
         "Width/Height/Depth/Weight are only applicable to physical (Paperback/Hardback) Publications."
     )]
     DimensionDigitalError,
+    #[error("Accessibility metadata is only applicable to digital Publications.")]
+    AccessibilityPhysicalError,
+    #[error("WCAG version and level must be given together, and are required for EPUB Accessibility 1.1 conformance.")]
+    WcagConformanceError,
+    #[error("Accessibility hazards must not contradict each other.")]
+    AccessibilityHazardsError,
+    #[error(
+        "Binding, paper, colour, trim, page count, cover finish and carton quantity are only applicable to physical (Paperback/Hardback) Publications."
+    )]
+    PrintAttributesDigitalError,
+    #[error("Publication page count must be greater than 0.")]
+    PublicationPageCountError,
+    #[error("Carton quantity must be greater than 0.")]
+    CartonQuantityError,
+    #[error(
+        "Format version, file size, technical protection and layout are only applicable to digital Publications."
+    )]
+    DigitalAttributesPhysicalError,
+    #[error("File size must be greater than 0.")]
+    FileSizeError,
+    #[error("A technical protection of None must not be combined with any other.")]
+    TechnicalProtectionsError,
+    #[error("Usage constraints are only applicable to digital Publications.")]
+    UsageConstraintPhysicalError,
+    #[error("A usage limit and its unit must be given together, and only when usage is permitted subject to a limit.")]
+    UsageLimitError,
     #[error(
         "Price values must be greater than zero. To indicate an unpriced Publication, omit all Prices."
     )]
     PriceZeroError,
+    #[error("Price effective from date must not be later than its effective until date.")]
+    PriceEffectiveDatesError,
+    #[error("Sales Rights must apply to at least one Country or Region.")]
+    SalesRightsTerritoryError,
+    #[error("Countries and Regions can only be excluded from an included Region other than WORLD or ECZ.")]
+    TerritoryExclusionError,
+    #[error("A Sales Restriction note requires a Sales Restriction type, and an Unspecified Sales Restriction must be described by a note.")]
+    SalesRestrictionNoteError,
     #[error("Publication Date is required for Active, Withdrawn, and Superseded Works.")]
     PublicationDateError,
+    #[error("Query exceeds the maximum allowed depth of {0}.")]
+    QueryDepthLimitError(u32),
+    #[error("Query exceeds the maximum allowed complexity of {0}.")]
+    QueryComplexityLimitError(u64),
+    // The message is part of the automatic persisted query protocol, clients match on it
+    #[error("PersistedQueryNotFound")]
+    PersistedQueryNotFound,
+    #[error("Provided sha256Hash does not match query.")]
+    PersistedQueryHashMismatch,
+    #[error("Unsupported persisted query version.")]
+    PersistedQueryVersionError,
+    #[error("Anonymous requests must use a registered persisted query.")]
+    PersistedQueryRequired,
+    #[error("Invalid asset: {0}")]
+    InvalidAssetError(String),
+    #[error("Asset storage error: {0}")]
+    AssetStorageError(String),
+    #[error("Image processing error: {0}")]
+    ImageProcessingError(String),
     #[error("{0}")]
     RequestError(String),
     #[error("{0}")]
@@ -157,11 +242,16 @@ impl actix_web::error::ResponseError for ThothError {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::HttpResponse;
         match self {
-            ThothError::Unauthorised | ThothError::InvalidToken => {
-                HttpResponse::Unauthorized().json(self.to_string())
-            }
+            ThothError::Unauthorised
+            | ThothError::InvalidToken
+            | ThothError::TwoFactorRequired
+            | ThothError::TwoFactorError(_) => HttpResponse::Unauthorized().json(self.to_string()),
+            ThothError::AccountLocked => HttpResponse::Locked().json(self.to_string()),
             ThothError::EntityNotFound => HttpResponse::NotFound().json(self.to_string()),
-            ThothError::InvalidMetadataSpecification(_) | ThothError::InvalidUuid => {
+            ThothError::InvalidMetadataSpecification(_)
+            | ThothError::InvalidUuid
+            | ThothError::InvalidAssetError(_)
+            | ThothError::PasswordPolicyError(_) => {
                 HttpResponse::BadRequest().json(self.to_string())
             }
             ThothError::DatabaseError { .. } => {
@@ -378,6 +468,31 @@ mod tests {
         assert!(json.contains("\"BIC\""));
     }
 
+    #[test]
+    fn test_account_error_status_codes() {
+        use actix_web::{http::StatusCode, ResponseError};
+        assert_eq!(
+            ThothError::AccountLocked.error_response().status(),
+            StatusCode::LOCKED
+        );
+        assert_eq!(
+            ThothError::PasswordPolicyError("too short".to_string())
+                .error_response()
+                .status(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            ThothError::TwoFactorRequired.error_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            ThothError::TwoFactorError("Invalid code".to_string())
+                .error_response()
+                .status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
     #[test]
     fn test_invalid_json_deserialisation() {
         let invalid_json = r#"{"UnknownError":"Unexpected field"}"#;
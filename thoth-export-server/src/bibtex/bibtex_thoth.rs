@@ -1,6 +1,7 @@
 use std::convert::TryFrom;
 use std::fmt;
 use std::io::Write;
+use thoth_api::markup::to_plain_text;
 use thoth_client::{
     ContributionType, PublicationType, RelationType, Work, WorkContributions, WorkType,
 };
@@ -26,7 +27,8 @@ struct BibtexThothEntry {
     publisher: String,
     address: Option<String>,
     series: Option<String>,
-    volume: Option<i64>,
+    volume: Option<String>,
+    number: Option<String>,
     booktitle: Option<String>,
     chapter: Option<i64>,
     pages: Option<String>,
@@ -49,9 +51,9 @@ impl BibtexSpecification for BibtexThoth {
             [work] => BibtexEntry::<BibtexThoth>::bibtex_entry(work, w),
             _ => {
                 for work in works.iter() {
-                    // Do not include Chapters in full publisher metadata record
+                    // Do not include Chapters or journal articles in full publisher metadata record
                     // (assumes that a publisher will always have more than one work)
-                    if work.work_type != WorkType::BOOK_CHAPTER {
+                    if !work.work_type.is_component() {
                         BibtexEntry::<BibtexThoth>::bibtex_entry(work, w).ok();
                     }
                 }
@@ -92,7 +94,8 @@ impl fmt::Display for BibtexThothEntry {
         write_field!(f, self, publisher);
         write_optional_field!(f, self, address);
         write_optional_field!(f, self, series);
-        write_optional_field!(f, self, volume, i64);
+        write_optional_field!(f, self, volume);
+        write_optional_field!(f, self, number);
         write_optional_field!(f, self, booktitle);
         write_optional_field!(f, self, chapter, i64);
         write_optional_field!(f, self, pages);
@@ -175,7 +178,8 @@ impl TryFrom<Work> for BibtexThothEntry {
                 .issues
                 .first()
                 .map(|i| i.series.series_name.to_string()),
-            volume: work.issues.first().map(|i| i.issue_ordinal),
+            volume: work.issues.first().and_then(|i| i.volume_designation()),
+            number: work.issues.first().and_then(|i| i.issue_number.clone()),
             booktitle,
             chapter,
             pages,
@@ -192,7 +196,7 @@ impl TryFrom<Work> for BibtexThothEntry {
                 .and_then(|i| i.series.issn_digital.as_ref().map(|s| s.to_string())),
             url: work.landing_page,
             copyright: work.license,
-            long_abstract: work.long_abstract,
+            long_abstract: work.long_abstract.as_deref().map(to_plain_text),
         })
     }
 }
@@ -261,7 +265,7 @@ mod tests {
 \tpublisher = {OA Editions},
 \taddress = {León, Spain},
 \tseries = {Name of series},
-\tvolume = 5,
+\tvolume = {5},
 \tdoi = {10.00001/BOOK.0001},
 \tisbn = {978-1-56619-909-4},
 \tissn = {8765-4321},
@@ -288,6 +292,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus.".to_string()),
             long_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: Some("This is a general note".to_string()),
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -296,6 +302,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: Some(20),
             audio_count: Some(25),
@@ -318,6 +325,9 @@ mod tests {
             },
             issues: vec![WorkIssues {
                 issue_ordinal: 5,
+                volume: None,
+                issue_number: None,
+                part: None,
                 series: WorkIssuesSeries {
                     series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                     series_type: SeriesType::JOURNAL,
@@ -327,6 +337,8 @@ mod tests {
                     series_url: Some("https://www.series.com".to_string()),
                     series_description: Some("Description of series".to_string()),
                     series_cfp_url: Some("https://www.series.com/cfp".to_string()),
+                    parent_series: None,
+                    contributions: vec![],
                 },
             }],
             contributions: vec![
@@ -341,6 +353,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -355,6 +372,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -369,6 +391,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -383,6 +410,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -397,6 +429,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -411,6 +448,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -425,6 +467,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -448,6 +495,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -466,10 +534,35 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![WorkRelations {
                 relation_type: RelationType::IS_CHILD_OF,
                 relation_ordinal: 7,
@@ -486,12 +579,15 @@ mod tests {
                     copyright_holder: None,
                     short_abstract: None,
                     long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
                     general_note: None,
                     place: None,
                     first_page: None,
                     last_page: None,
                     page_count: None,
                     page_interval: None,
+                    article_number: None,
                     landing_page: None,
                     imprint: WorkRelationsRelatedWorkImprint {
                         crossmark_doi: None,
@@ -522,12 +618,15 @@ mod tests {
                     copyright_holder: None,
                     short_abstract: None,
                     long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
                     general_note: None,
                     place: None,
                     first_page: None,
                     last_page: None,
                     page_count: None,
                     page_interval: None,
+                    article_number: None,
                     landing_page: None,
                     imprint: WorkRelationsRelatedWorkImprint {
                         crossmark_doi: None,
@@ -542,7 +641,8 @@ mod tests {
                     languages: vec![],
                 },
             }],
-            references: vec![]
+            references: vec![],
+            identifiers: vec![],
         }
     }
 
@@ -597,6 +697,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_work_with_issue_numbering() {
+        let mut test_work = test_work();
+        // Record printed numbering: volume replaces the series ordinal and number is added
+        test_work.issues[0].volume = Some("12a".to_string());
+        test_work.issues[0].issue_number = Some("3".to_string());
+        let to_test = BibtexThoth.generate(&[test_work.clone()]);
+        assert_eq!(
+            to_test,
+            Ok(TEST_RESULT
+                .to_string()
+                .replace("\tvolume = {5},", "\tvolume = {12a},\n\tnumber = {3},"))
+        );
+    }
+
     #[test]
     fn test_work_without_subtitle() {
         let mut test_work = test_work();
@@ -2,9 +2,10 @@ use csv::Writer;
 use serde::Serialize;
 use std::io::Write;
 use thoth_client::{
-    SubjectType, Work, WorkContributions, WorkContributionsAffiliations, WorkFundings, WorkIssues,
-    WorkLanguages, WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices,
-    WorkReferences, WorkRelations, WorkSubjects,
+    SubjectType, Work, WorkContributions, WorkContributionsAffiliations, WorkFundings,
+    WorkIdentifiers, WorkIssues, WorkLanguages, WorkPrizes, WorkPublications,
+    WorkPublicationsIdentifiers, WorkPublicationsLocations, WorkPublicationsPrices, WorkReferences,
+    WorkRelations, WorkSubjects,
 };
 use thoth_errors::ThothResult;
 
@@ -41,6 +42,8 @@ struct CsvThothRow {
     video_count: Option<i64>,
     lccn: Option<String>,
     oclc: Option<String>,
+    #[serde(rename = "identifiers [(type, value)]")]
+    identifiers: String,
     short_abstract: Option<String>,
     long_abstract: Option<String>,
     general_note: Option<String>,
@@ -56,7 +59,7 @@ struct CsvThothRow {
     )]
     contributions: String,
     #[serde(
-        rename = "publications [(type, isbn, width (mm), width (cm), width (in), height (mm), height (cm), height (in), depth (mm), depth (cm), depth (in), weight (g), weight (oz), [(ISO_4217_currency, price)], [(landing_page, full_text, platform, is_canonical)])]"
+        rename = "publications [(type, isbn, width (mm), width (cm), width (in), height (mm), height (cm), height (in), depth (mm), depth (cm), depth (in), weight (g), weight (oz), binding, paper, interior_colour, trim, page_count, cover_finish, carton_quantity, [(ISO_4217_currency, price)], [(landing_page, full_text, platform, is_canonical)], [(identifier_type, identifier_value)])]"
     )]
     publications: String,
     #[serde(
@@ -81,6 +84,8 @@ struct CsvThothRow {
         rename = "funding [(institution, institution_doi, ror, country, program, project, grant, jurisdiction)]"
     )]
     funding: String,
+    #[serde(rename = "prizes [(name, year, country, achievement, statement, jury)]")]
+    prizes: String,
     #[serde(rename = "relations [(related_work, doi, relation_type, relation_number)]")]
     relations: String,
     #[serde(
@@ -137,6 +142,13 @@ impl From<Work> for CsvThothRow {
             video_count: work.video_count,
             lccn: work.lccn,
             oclc: work.oclc,
+            identifiers: CsvCell::<CsvThoth>::csv_cell(
+                &work
+                    .identifiers
+                    .iter()
+                    .map(CsvCell::<CsvThoth>::csv_cell)
+                    .collect::<Vec<String>>(),
+            ),
             short_abstract: work.short_abstract,
             long_abstract: work.long_abstract,
             general_note: work.general_note,
@@ -221,6 +233,13 @@ impl From<Work> for CsvThothRow {
                     .map(CsvCell::<CsvThoth>::csv_cell)
                     .collect::<Vec<String>>(),
             ),
+            prizes: CsvCell::<CsvThoth>::csv_cell(
+                &work
+                    .prizes
+                    .iter()
+                    .map(CsvCell::<CsvThoth>::csv_cell)
+                    .collect::<Vec<String>>(),
+            ),
             relations: CsvCell::<CsvThoth>::csv_cell(
                 &work
                     .relations
@@ -252,7 +271,7 @@ impl CsvCell<CsvThoth> for Vec<String> {
 impl CsvCell<CsvThoth> for WorkPublications {
     fn csv_cell(&self) -> String {
         format!(
-            "(\"{:?}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", {}, {})",
+            "(\"{:?}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", {}, {}, {})",
             self.publication_type,
             self.isbn
                 .as_ref()
@@ -302,6 +321,34 @@ impl CsvCell<CsvThoth> for WorkPublications {
                 .as_ref()
                 .map(|w| w.to_string())
                 .unwrap_or_default(),
+            self.binding_style
+                .as_ref()
+                .map(|b| format!("{b:?}"))
+                .unwrap_or_default(),
+            self.paper_type
+                .as_ref()
+                .map(|p| format!("{p:?}"))
+                .unwrap_or_default(),
+            self.interior_colour
+                .as_ref()
+                .map(|c| format!("{c:?}"))
+                .unwrap_or_default(),
+            self.trim_category
+                .as_ref()
+                .map(|t| format!("{t:?}"))
+                .unwrap_or_default(),
+            self.page_count
+                .as_ref()
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            self.cover_finish
+                .as_ref()
+                .map(|c| format!("{c:?}"))
+                .unwrap_or_default(),
+            self.carton_quantity
+                .as_ref()
+                .map(|q| q.to_string())
+                .unwrap_or_default(),
             CsvCell::<CsvThoth>::csv_cell(
                 &self
                     .prices
@@ -316,6 +363,31 @@ impl CsvCell<CsvThoth> for WorkPublications {
                     .map(CsvCell::<CsvThoth>::csv_cell)
                     .collect::<Vec<String>>(),
             ),
+            CsvCell::<CsvThoth>::csv_cell(
+                &self
+                    .identifiers
+                    .iter()
+                    .map(CsvCell::<CsvThoth>::csv_cell)
+                    .collect::<Vec<String>>(),
+            ),
+        )
+    }
+}
+
+impl CsvCell<CsvThoth> for WorkIdentifiers {
+    fn csv_cell(&self) -> String {
+        format!(
+            "(\"{:?}\", \"{}\")",
+            self.identifier_type, self.identifier_value,
+        )
+    }
+}
+
+impl CsvCell<CsvThoth> for WorkPublicationsIdentifiers {
+    fn csv_cell(&self) -> String {
+        format!(
+            "(\"{:?}\", \"{}\")",
+            self.identifier_type, self.identifier_value,
         )
     }
 }
@@ -393,7 +465,7 @@ impl CsvCell<CsvThoth> for WorkContributionsAffiliations {
 impl CsvCell<CsvThoth> for WorkIssues {
     fn csv_cell(&self) -> String {
         format!(
-            "(\"{:?}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\")",
+            "(\"{:?}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\", \"{}\")",
             self.series.series_type,
             self.series.series_name,
             self.series.issn_print.clone().unwrap_or_default(),
@@ -402,6 +474,9 @@ impl CsvCell<CsvThoth> for WorkIssues {
             self.series.series_cfp_url.clone().unwrap_or_default(),
             self.series.series_description.clone().unwrap_or_default(),
             self.issue_ordinal,
+            self.volume.clone().unwrap_or_default(),
+            self.issue_number.clone().unwrap_or_default(),
+            self.part.clone().unwrap_or_default(),
         )
     }
 }
@@ -449,6 +524,26 @@ impl CsvCell<CsvThoth> for WorkFundings {
     }
 }
 
+impl CsvCell<CsvThoth> for WorkPrizes {
+    fn csv_cell(&self) -> String {
+        format!(
+            "(\"{}\", \"{}\", \"{}\", \"{:?}\", \"{}\", \"{}\")",
+            self.prize_name,
+            self.prize_year
+                .as_ref()
+                .map(|y| y.to_string())
+                .unwrap_or_default(),
+            self.prize_country
+                .as_ref()
+                .map(|c| format!("{c:?}"))
+                .unwrap_or_default(),
+            self.prize_code,
+            self.prize_statement.clone().unwrap_or_default(),
+            self.prize_jury.clone().unwrap_or_default(),
+        )
+    }
+}
+
 impl CsvCell<CsvThoth> for WorkRelations {
     fn csv_cell(&self) -> String {
         format!(
@@ -503,8 +598,9 @@ mod tests {
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
-        LanguageRelation, LocationPlatform, PublicationType, RelationType, SeriesType,
+        BindingStyle, ContributionType, CountryCode, CoverFinish, CurrencyCode, FundingInstitution,
+        IdentifierType, InteriorColour, LanguageCode, LanguageRelation, LocationPlatform,
+        PaperType, PriceType, PrizeCode, PublicationType, RelationType, SeriesType, TrimCategory,
         WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
         WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssuesSeries,
         WorkPublicationsLocations, WorkPublicationsPrices, WorkRelationsRelatedWork,
@@ -530,6 +626,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus.".to_string()),
             long_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: Some("This is a general note".to_string()),
             bibliography_note: Some("This is a bibliography note".to_string()),
             place: Some("León, Spain".to_string()),
@@ -538,6 +636,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: Some(20),
             audio_count: Some(25),
@@ -560,6 +659,9 @@ mod tests {
             },
             issues: vec![WorkIssues {
                 issue_ordinal: 1,
+                volume: None,
+                issue_number: None,
+                part: None,
                 series: WorkIssuesSeries {
                     series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                     series_type: SeriesType::JOURNAL,
@@ -569,6 +671,8 @@ mod tests {
                     series_url: Some("https://www.series.com".to_string()),
                     series_description: Some("Description of series".to_string()),
                     series_cfp_url: Some("https://www.series.com/cfp".to_string()),
+                    parent_series: None,
+                    contributions: vec![],
                 },
             }],
             contributions: vec![
@@ -583,6 +687,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![
                         WorkContributionsAffiliations {
@@ -608,6 +717,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -639,14 +753,44 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 25.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 22.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 31.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![
@@ -663,6 +807,30 @@ mod tests {
                             canonical: false,
                         },
                     ],
+                    identifiers: vec![WorkPublicationsIdentifiers {
+                        identifier_type: IdentifierType::GTIN,
+                        identifier_value: "4006381333931".to_string(),
+                    }],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -683,17 +851,68 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 36.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 32.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 40.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -717,6 +936,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -740,6 +980,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -758,6 +1019,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![
@@ -818,8 +1100,22 @@ mod tests {
                     institution_doi: Some(Doi::from_str("https://doi.org/10.00001/INSTITUTION.0001").unwrap()),
                     ror: Some(Ror::from_str("https://ror.org/0aaaaaa00").unwrap()),
                     country_code: Some(CountryCode::MDA),
+                    crossref_funder_id: None,
+                    isni: None,
+                    ringgold: None,
                 },
             }],
+            prizes: vec![WorkPrizes {
+                prize_name: "Name of prize".to_string(),
+                prize_year: Some(2021),
+                prize_country: Some(CountryCode::GBR),
+                prize_code: PrizeCode::SHORT_LISTED,
+                prize_statement: Some("Statement from the jury".to_string()),
+                prize_jury: Some("Members of the jury".to_string()),
+            }],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![WorkRelations {
                 relation_type: RelationType::HAS_CHILD,
                 relation_ordinal: 1,
@@ -836,12 +1132,15 @@ mod tests {
                     copyright_holder: None,
                     short_abstract: None,
                     long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
                     general_note: None,
                     place: None,
                     first_page: None,
                     last_page: None,
                     page_count: None,
                     page_interval: None,
+                    article_number: None,
                     landing_page: None,
                     imprint: WorkRelationsRelatedWorkImprint {
                         crossmark_doi: None,
@@ -878,11 +1177,15 @@ mod tests {
                 publication_date: chrono::NaiveDate::from_ymd_opt(2022, 1, 1),
                 retrieval_date: chrono::NaiveDate::from_ymd_opt(2022, 12, 31),
             }],
+            identifiers: vec![WorkIdentifiers {
+                identifier_type: IdentifierType::HANDLE,
+                identifier_value: "20.500.12345/678".to_string(),
+            }],
         };
     }
 
-    const TEST_RESULT: &str = r#""publisher","imprint","work_type","work_status","title","subtitle","edition","doi","reference","publication_date","withdrawn_date","publication_place","license","copyright_holder","landing_page","page_count","page_breakdown","first_page","last_page","page_interval","image_count","table_count","audio_count","video_count","lccn","oclc","short_abstract","long_abstract","general_note","bibliography_note","toc","cover_url","cover_caption","contributions [(type, first_name, last_name, full_name, is_main, biography, orcid, website, [(position, institution, institution_doi, ror, country)])]","publications [(type, isbn, width (mm), width (cm), width (in), height (mm), height (cm), height (in), depth (mm), depth (cm), depth (in), weight (g), weight (oz), [(ISO_4217_currency, price)], [(landing_page, full_text, platform, is_canonical)])]","series [(type, name, issn_print, issn_digital, url, cfp_url, description, issue)]","languages [(relation, ISO_639-3/B_language, is_main)]","BIC [code]","THEMA [code]","BISAC [code]","LCC [code]","custom_categories [category]","keywords [keyword]","funding [(institution, institution_doi, ror, country, program, project, grant, jurisdiction)]","relations [(related_work, doi, relation_type, relation_number)]","references [(doi, citation, issn, isbn, journal_title, article_title, series_title, volume_title, edition, author, volume, issue, first_page, component_number, standard_designator, standards_body, publication_date, retrieval_date)]"
-"OA Editions","OA Editions Imprint","MONOGRAPH","ACTIVE","Book Title","Book Subtitle","1","10.00001/BOOK.0001","IntRef1","1999-12-31","","León, Spain","http://creativecommons.org/licenses/by/4.0/","Author 1; Author 2","https://www.book.com","334","x+334","","","","15","20","25","30","123456789","987654321","Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus.","Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.","This is a general note","This is a bibliography note","1. Chapter 1","https://www.book.com/cover","This is a cover caption","[(""AUTHOR"", ""Author"", ""1"", ""Author 1"", ""true"", ""Author 1 is an author"", ""0000-0002-0000-0001"", """", [(""Manager"", ""University of Life"", """", ""0abcdef12"", """")]),(""AUTHOR"", ""Author"", ""2"", ""Author 2"", ""true"", """", """", """", )]","[(""PAPERBACK"", ""978-3-16-148410-0"", ""156"", ""15.6"", ""6.14"", ""234"", ""23.4"", ""9.21"", ""25"", ""2.5"", ""1"", ""152"", ""5.3616"", [(""EUR"", ""25.95""),(""GBP"", ""22.95""),(""USD"", ""31.95"")], [(""https://www.book.com/paperback"", """", ""OTHER"", ""true""),(""https://www.jstor.com/paperback"", """", ""JSTOR"", ""false"")]),(""HARDBACK"", ""978-1-4028-9462-6"", """", """", """", """", """", """", """", """", """", """", """", [(""EUR"", ""36.95""),(""GBP"", ""32.95""),(""USD"", ""40.95"")], ),(""PDF"", ""978-1-56619-909-4"", """", """", """", """", """", """", """", """", """", """", """", , [(""https://www.book.com/pdf_landing"", ""https://www.book.com/pdf_fulltext"", ""OTHER"", ""true"")]),(""HTML"", """", """", """", """", """", """", """", """", """", """", """", """", , [(""https://www.book.com/html_landing"", ""https://www.book.com/html_fulltext"", ""OTHER"", ""true"")]),(""XML"", ""978-92-95055-02-5"", """", """", """", """", """", """", """", """", """", """", """", , )]","[(""JOURNAL"", ""Name of series"", ""1234-5678"", ""8765-4321"", ""https://www.series.com"", ""https://www.series.com/cfp"", ""Description of series"", ""1"")]","[(""ORIGINAL"", ""SPA"", ""true"")]","[""AAA"",""AAB""]","[""JWA""]","[""AAA000000"",""AAA000001""]","[""JA85""]","[""Category1""]","[""keyword1"",""keyword2""]","[(""Name of institution"", ""10.00001/INSTITUTION.0001"", ""0aaaaaa00"", ""MDA"", ""Name of program"", ""Name of project"", ""Number of grant"", ""Funding jurisdiction"")]","[(""Related work title"", ""10.00001/RELATION.0001"", ""HAS_CHILD"", ""1"")]","[(""10.00001/reference"", ""Author, A. (2022) Article, Journal."", ""1111-2222"", """", ""Journal"", ""Article"", """", """", """", ""Author, A"", """", """", ""3"", """", """", """", ""2022-01-01"", ""2022-12-31"")]"
+    const TEST_RESULT: &str = r#""publisher","imprint","work_type","work_status","title","subtitle","edition","doi","reference","publication_date","withdrawn_date","publication_place","license","copyright_holder","landing_page","page_count","page_breakdown","first_page","last_page","page_interval","image_count","table_count","audio_count","video_count","lccn","oclc","identifiers [(type, value)]","short_abstract","long_abstract","general_note","bibliography_note","toc","cover_url","cover_caption","contributions [(type, first_name, last_name, full_name, is_main, biography, orcid, website, [(position, institution, institution_doi, ror, country)])]","publications [(type, isbn, width (mm), width (cm), width (in), height (mm), height (cm), height (in), depth (mm), depth (cm), depth (in), weight (g), weight (oz), binding, paper, interior_colour, trim, page_count, cover_finish, carton_quantity, [(ISO_4217_currency, price)], [(landing_page, full_text, platform, is_canonical)], [(identifier_type, identifier_value)])]","series [(type, name, issn_print, issn_digital, url, cfp_url, description, issue)]","languages [(relation, ISO_639-3/B_language, is_main)]","BIC [code]","THEMA [code]","BISAC [code]","LCC [code]","custom_categories [category]","keywords [keyword]","funding [(institution, institution_doi, ror, country, program, project, grant, jurisdiction)]","prizes [(name, year, country, achievement, statement, jury)]","relations [(related_work, doi, relation_type, relation_number)]","references [(doi, citation, issn, isbn, journal_title, article_title, series_title, volume_title, edition, author, volume, issue, first_page, component_number, standard_designator, standards_body, publication_date, retrieval_date)]"
+"OA Editions","OA Editions Imprint","MONOGRAPH","ACTIVE","Book Title","Book Subtitle","1","10.00001/BOOK.0001","IntRef1","1999-12-31","","León, Spain","http://creativecommons.org/licenses/by/4.0/","Author 1; Author 2","https://www.book.com","334","x+334","","","","15","20","25","30","123456789","987654321","[(""HANDLE"", ""20.500.12345/678"")]","Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus.","Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.","This is a general note","This is a bibliography note","1. Chapter 1","https://www.book.com/cover","This is a cover caption","[(""AUTHOR"", ""Author"", ""1"", ""Author 1"", ""true"", ""Author 1 is an author"", ""0000-0002-0000-0001"", """", [(""Manager"", ""University of Life"", """", ""0abcdef12"", """")]),(""AUTHOR"", ""Author"", ""2"", ""Author 2"", ""true"", """", """", """", )]","[(""PAPERBACK"", ""978-3-16-148410-0"", ""156"", ""15.6"", ""6.14"", ""234"", ""23.4"", ""9.21"", ""25"", ""2.5"", ""1"", ""152"", ""5.3616"", """", """", """", """", """", """", """", [(""EUR"", ""25.95""),(""GBP"", ""22.95""),(""USD"", ""31.95"")], [(""https://www.book.com/paperback"", """", ""OTHER"", ""true""),(""https://www.jstor.com/paperback"", """", ""JSTOR"", ""false"")], [(""GTIN"", ""4006381333931"")]),(""HARDBACK"", ""978-1-4028-9462-6"", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", [(""EUR"", ""36.95""),(""GBP"", ""32.95""),(""USD"", ""40.95"")], , ),(""PDF"", ""978-1-56619-909-4"", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", , [(""https://www.book.com/pdf_landing"", ""https://www.book.com/pdf_fulltext"", ""OTHER"", ""true"")], ),(""HTML"", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", , [(""https://www.book.com/html_landing"", ""https://www.book.com/html_fulltext"", ""OTHER"", ""true"")], ),(""XML"", ""978-92-95055-02-5"", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", """", , , )]","[(""JOURNAL"", ""Name of series"", ""1234-5678"", ""8765-4321"", ""https://www.series.com"", ""https://www.series.com/cfp"", ""Description of series"", ""1"", """", """", """")]","[(""ORIGINAL"", ""SPA"", ""true"")]","[""AAA"",""AAB""]","[""JWA""]","[""AAA000000"",""AAA000001""]","[""JA85""]","[""Category1""]","[""keyword1"",""keyword2""]","[(""Name of institution"", ""10.00001/INSTITUTION.0001"", ""0aaaaaa00"", ""MDA"", ""Name of program"", ""Name of project"", ""Number of grant"", ""Funding jurisdiction"")]","[(""Name of prize"", ""2021"", ""GBR"", ""SHORT_LISTED"", ""Statement from the jury"", ""Members of the jury"")]","[(""Related work title"", ""10.00001/RELATION.0001"", ""HAS_CHILD"", ""1"")]","[(""10.00001/reference"", ""Author, A. (2022) Article, Journal."", ""1111-2222"", """", ""Journal"", ""Article"", """", """", """", ""Author, A"", """", """", ""3"", """", """", """", ""2022-01-01"", ""2022-12-31"")]"
 "#;
 
     #[test]
@@ -925,6 +1228,16 @@ mod tests {
             prices: vec![WorkPublicationsPrices {
                 currency_code: CurrencyCode::EUR,
                 unit_price: 25.95,
+                price_type: PriceType::RRP_INCLUDING_TAX,
+                price_qualifier: None,
+                countries_included: vec![],
+                countries_excluded: vec![],
+                regions_included: vec![],
+                regions_excluded: vec![],
+                tax_rate_percent: None,
+                discount_code: None,
+                price_effective_from: None,
+                price_effective_until: None,
             }],
             locations: vec![WorkPublicationsLocations {
                 landing_page: Some("https://www.book.com/paperback".to_string()),
@@ -932,9 +1245,33 @@ mod tests {
                 location_platform: LocationPlatform::PROJECT_MUSE,
                 canonical: true,
             }],
+            identifiers: vec![WorkPublicationsIdentifiers {
+                identifier_type: IdentifierType::SKU,
+                identifier_value: "PB-0001".to_string(),
+            }],
+            sales_rights: vec![],
+            accessibility_features: vec![],
+            accessibility_hazards: vec![],
+            accessibility_standard: None,
+            wcag_version: None,
+            wcag_level: None,
+            accessibility_certifier: None,
+            accessibility_summary: None,
+            binding_style: Some(BindingStyle::PERFECT_BOUND),
+            paper_type: Some(PaperType::CREAM),
+            interior_colour: Some(InteriorColour::BLACK_AND_WHITE),
+            trim_category: Some(TrimCategory::US_TRADE),
+            page_count: Some(352),
+            cover_finish: Some(CoverFinish::MATTE_LAMINATE),
+            carton_quantity: Some(24),
+            format_version: None,
+            file_size_kb: None,
+            technical_protections: vec![],
+            layout: None,
+            usage_constraints: vec![],
         };
         assert_eq!(CsvCell::<CsvThoth>::csv_cell(&publication),
-            r#"("PAPERBACK", "978-3-16-148410-0", "156", "15.6", "6.14", "234", "23.4", "9.21", "25", "2.5", "1", "152", "5.3616", [("EUR", "25.95")], [("https://www.book.com/paperback", "", "PROJECT_MUSE", "true")])"#.to_string());
+            r#"("PAPERBACK", "978-3-16-148410-0", "156", "15.6", "6.14", "234", "23.4", "9.21", "25", "2.5", "1", "152", "5.3616", "PERFECT_BOUND", "CREAM", "BLACK_AND_WHITE", "US_TRADE", "352", "MATTE_LAMINATE", "24", [("EUR", "25.95")], [("https://www.book.com/paperback", "", "PROJECT_MUSE", "true")], [("SKU", "PB-0001")])"#.to_string());
         publication.publication_type = PublicationType::HARDBACK;
         publication.isbn = None;
         publication.width_mm = None;
@@ -948,11 +1285,19 @@ mod tests {
         publication.depth_in = None;
         publication.weight_g = None;
         publication.weight_oz = None;
+        publication.binding_style = None;
+        publication.paper_type = None;
+        publication.interior_colour = None;
+        publication.trim_category = None;
+        publication.page_count = None;
+        publication.cover_finish = None;
+        publication.carton_quantity = None;
         publication.prices.clear();
         publication.locations.clear();
+        publication.identifiers.clear();
         assert_eq!(
             CsvCell::<CsvThoth>::csv_cell(&publication),
-            r#"("HARDBACK", "", "", "", "", "", "", "", "", "", "", "", "", , )"#.to_string()
+            r#"("HARDBACK", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", , , )"#.to_string()
         );
     }
 
@@ -969,6 +1314,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://www.author1.org".to_string()),
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -1023,6 +1373,16 @@ mod tests {
         let mut price = WorkPublicationsPrices {
             currency_code: CurrencyCode::GBP,
             unit_price: 22.95,
+            price_type: PriceType::RRP_INCLUDING_TAX,
+            price_qualifier: None,
+            countries_included: vec![],
+            countries_excluded: vec![],
+            regions_included: vec![],
+            regions_excluded: vec![],
+            tax_rate_percent: None,
+            discount_code: None,
+            price_effective_from: None,
+            price_effective_until: None,
         };
         assert_eq!(
             CsvCell::<CsvThoth>::csv_cell(&price),
@@ -1061,6 +1421,9 @@ mod tests {
     fn test_csv_thoth_issues() {
         let mut issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: SeriesType::JOURNAL,
@@ -1070,18 +1433,23 @@ mod tests {
                 series_url: Some("https://www.series.com".to_string()),
                 series_description: Some("Description of series".to_string()),
                 series_cfp_url: Some("https://www.series.com/cfp".to_string()),
+                parent_series: None,
+                contributions: vec![],
             },
         };
         assert_eq!(CsvCell::<CsvThoth>::csv_cell(&issue),
-            r#"("JOURNAL", "Name of series", "1234-5678", "8765-4321", "https://www.series.com", "https://www.series.com/cfp", "Description of series", "1")"#.to_string());
+            r#"("JOURNAL", "Name of series", "1234-5678", "8765-4321", "https://www.series.com", "https://www.series.com/cfp", "Description of series", "1", "", "", "")"#.to_string());
         issue.issue_ordinal = 2;
         issue.series.series_type = SeriesType::BOOK_SERIES;
         issue.series.series_url = None;
         issue.series.series_description = Some("Different description".to_string());
         issue.series.series_cfp_url = None;
+        issue.volume = Some("12a".to_string());
+        issue.issue_number = Some("3".to_string());
+        issue.part = Some("2".to_string());
         assert_eq!(
             CsvCell::<CsvThoth>::csv_cell(&issue),
-            r#"("BOOK_SERIES", "Name of series", "1234-5678", "8765-4321", "", "", "Different description", "2")"#.to_string()
+            r#"("BOOK_SERIES", "Name of series", "1234-5678", "8765-4321", "", "", "Different description", "2", "12a", "3", "2")"#.to_string()
         );
     }
 
@@ -1133,6 +1501,9 @@ mod tests {
                 ),
                 ror: Some(Ror::from_str("https://ror.org/0aaaaaa00").unwrap()),
                 country_code: Some(CountryCode::MDA),
+                crossref_funder_id: None,
+                isni: None,
+                ringgold: None,
             },
         };
         assert_eq!(CsvCell::<CsvThoth>::csv_cell(&funding),
@@ -1150,6 +1521,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_csv_thoth_prizes() {
+        let mut prize = WorkPrizes {
+            prize_name: "Name of prize".to_string(),
+            prize_year: Some(2021),
+            prize_country: Some(CountryCode::GBR),
+            prize_code: PrizeCode::WINNER,
+            prize_statement: Some("Statement from the jury".to_string()),
+            prize_jury: Some("Members of the jury".to_string()),
+        };
+        assert_eq!(
+            CsvCell::<CsvThoth>::csv_cell(&prize),
+            r#"("Name of prize", "2021", "GBR", "WINNER", "Statement from the jury", "Members of the jury")"#
+                .to_string()
+        );
+        prize.prize_year = None;
+        prize.prize_country = None;
+        prize.prize_code = PrizeCode::LONG_LISTED;
+        prize.prize_statement = None;
+        prize.prize_jury = None;
+        assert_eq!(
+            CsvCell::<CsvThoth>::csv_cell(&prize),
+            r#"("Name of prize", "", "", "LONG_LISTED", "", "")"#.to_string()
+        );
+    }
+
     #[test]
     fn test_csv_thoth_relations() {
         let mut relation = WorkRelations {
@@ -1168,12 +1565,15 @@ mod tests {
                 copyright_holder: None,
                 short_abstract: None,
                 long_abstract: None,
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: None,
                 first_page: None,
                 last_page: None,
                 page_count: None,
                 page_interval: None,
+                article_number: None,
                 landing_page: None,
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: None,
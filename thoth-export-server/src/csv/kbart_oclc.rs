@@ -18,11 +18,11 @@ struct KbartOclcRow {
     print_identifier: Option<String>,
     online_identifier: Option<String>,
     date_first_issue_online: Option<i64>,
-    num_first_vol_online: Option<i64>,
-    num_first_issue_online: Option<i64>,
+    num_first_vol_online: Option<String>,
+    num_first_issue_online: Option<String>,
     date_last_issue_online: Option<i64>,
-    num_last_vol_online: Option<i64>,
-    num_last_issue_online: Option<i64>,
+    num_last_vol_online: Option<String>,
+    num_last_issue_online: Option<String>,
     title_url: String,
     first_author: Option<String>,
     title_id: String,
@@ -33,7 +33,7 @@ struct KbartOclcRow {
     publication_type: String,
     date_monograph_published_print: Option<i64>,
     date_monograph_published_online: i64,
-    monograph_volume: Option<i64>,
+    monograph_volume: Option<String>,
     monograph_edition: Option<i64>,
     first_editor: Option<String>,
     parent_publication_title_id: Option<String>,
@@ -51,9 +51,9 @@ impl CsvSpecification for KbartOclc {
             [work] => CsvRow::<KbartOclc>::csv_row(work, w),
             _ => {
                 for work in works.iter() {
-                    // Do not include Chapters in full publisher metadata record
+                    // Do not include Chapters or journal articles in full publisher metadata record
                     // (assumes that a publisher will always have more than one work)
-                    if work.work_type != WorkType::BOOK_CHAPTER {
+                    if !work.work_type.is_component() {
                         CsvRow::<KbartOclc>::csv_row(work, w).ok();
                     }
                 }
@@ -141,16 +141,25 @@ impl TryFrom<Work> for KbartOclcRow {
                 true => Some(date_monograph_published_online),
                 false => None,
             };
+            // Note that it is possible for a work to belong to more than one series.
+            // Only one series can be listed in KBART, so we select the first one found (if any).
+            let issue = work.issues.first();
+            let volume = issue.and_then(|i| i.volume_designation());
+            // Serial coverage is the single volume and issue this work represents
+            let (serial_volume, serial_issue) = match work.work_type {
+                WorkType::BOOK_SET => (volume.clone(), issue.and_then(|i| i.issue_number.clone())),
+                _ => (None, None),
+            };
             Ok(KbartOclcRow {
                 publication_title: work.full_title,
                 print_identifier,
                 online_identifier,
                 date_first_issue_online: None,
-                num_first_vol_online: None,
-                num_first_issue_online: None,
+                num_first_vol_online: serial_volume.clone(),
+                num_first_issue_online: serial_issue.clone(),
                 date_last_issue_online: None,
-                num_last_vol_online: None,
-                num_last_issue_online: None,
+                num_last_vol_online: serial_volume,
+                num_last_issue_online: serial_issue,
                 title_url: work.landing_page.unwrap(),
                 first_author,
                 title_id: work
@@ -167,15 +176,11 @@ impl TryFrom<Work> for KbartOclcRow {
                 },
                 date_monograph_published_print,
                 date_monograph_published_online,
-                // Note that it is possible for a work to belong to more than one series.
-                // Only one series can be listed in KBART, so we select the first one found (if any).
-                monograph_volume: work.issues.first().map(|i| i.issue_ordinal),
+                monograph_volume: volume,
                 monograph_edition: work.edition,
                 first_editor,
                 // This should match the series' `title_id` if also provided in the KBART.
-                parent_publication_title_id: work
-                    .issues
-                    .first()
+                parent_publication_title_id: issue
                     .and_then(|i| i.series.issn_digital.as_ref().map(|s| s.to_string())),
                 preceding_publication_title_id: None,
                 access_type: "F".to_string(),
@@ -206,6 +211,8 @@ mod tests {
         title: String,
         print_identifier: String,
         online_identifier: String,
+        num_vol_online: String,
+        num_issue_online: String,
         title_url: String,
         first_author: String,
         title_id: String,
@@ -222,11 +229,15 @@ mod tests {
     impl fmt::Display for TestResult {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             writeln!(f,
-                "{}{}\t{}\t{}\t\t\t\t\t\t\t{}\t{}\t{}\t\tfulltext\t\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t\tF",
+                "{}{}\t{}\t{}\t\t{}\t{}\t\t{}\t{}\t{}\t{}\t{}\t\tfulltext\t\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t\tF",
                 self.headers,
                 self.title,
                 self.print_identifier,
                 self.online_identifier,
+                self.num_vol_online,
+                self.num_issue_online,
+                self.num_vol_online,
+                self.num_issue_online,
                 self.title_url,
                 self.first_author,
                 self.title_id,
@@ -264,6 +275,8 @@ mod tests {
             long_abstract: Some(
                 "Lorem ipsum dolor sit amet, consectetur adipiscing elit".to_string(),
             ),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -272,6 +285,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: None,
             audio_count: None,
@@ -295,6 +309,9 @@ mod tests {
             issues: vec![
                 WorkIssues {
                     issue_ordinal: 20,
+                    volume: None,
+                    issue_number: None,
+                    part: None,
                     series: WorkIssuesSeries {
                         series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                         series_type: thoth_client::SeriesType::BOOK_SERIES,
@@ -304,10 +321,15 @@ mod tests {
                         series_url: None,
                         series_description: None,
                         series_cfp_url: None,
+                        parent_series: None,
+                        contributions: vec![],
                     },
                 },
                 WorkIssues {
                     issue_ordinal: 50,
+                    volume: None,
+                    issue_number: None,
+                    part: None,
                     series: WorkIssuesSeries {
                         series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                         series_type: thoth_client::SeriesType::BOOK_SERIES,
@@ -317,6 +339,8 @@ mod tests {
                         series_url: None,
                         series_description: None,
                         series_cfp_url: None,
+                        parent_series: None,
+                        contributions: vec![],
                     },
                 },
             ],
@@ -334,6 +358,11 @@ mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -348,6 +377,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -362,6 +396,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -385,6 +424,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -403,6 +463,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
@@ -421,6 +502,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -439,6 +541,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -457,18 +580,46 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
         let mut test_result = TestResult {
             headers: "publication_title\tprint_identifier\tonline_identifier\tdate_first_issue_online\tnum_first_vol_online\tnum_first_issue_online\tdate_last_issue_online\tnum_last_vol_online\tnum_last_issue_online\ttitle_url\tfirst_author\ttitle_id\tembargo_info\tcoverage_depth\tnotes\tpublisher_name\tpublication_type\tdate_monograph_published_print\tdate_monograph_published_online\tmonograph_volume\tmonograph_edition\tfirst_editor\tparent_publication_title_id\tpreceding_publication_title_id\taccess_type\n".to_string(),
             title: "Book Title: Book Subtitle".to_string(),
             print_identifier: "978-3-16-148410-0".to_string(),
             online_identifier: "978-1-56619-909-4".to_string(),
+            num_vol_online: "".to_string(),
+            num_issue_online: "".to_string(),
             title_url: "https://www.book.com".to_string(),
             first_author: "First".to_string(),
             title_id: "10.00001/BOOK.0001".to_string(),
@@ -499,6 +650,17 @@ mod tests {
         // Make work a book set: publication_type becomes Serial
         test_work.work_type = WorkType::BOOK_SET;
         test_result.publication_type = "Serial".to_string();
+        test_result.num_vol_online = "20".to_string();
+        let to_test =
+            KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
+        assert_eq!(to_test, Ok(test_result.to_string()));
+
+        // Record printed numbering: volume replaces the series ordinal
+        test_work.issues[0].volume = Some("12a".to_string());
+        test_work.issues[0].issue_number = Some("3".to_string());
+        test_result.num_vol_online = "12a".to_string();
+        test_result.num_issue_online = "3".to_string();
+        test_result.monograph_volume = "12a".to_string();
         let to_test =
             KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
         assert_eq!(to_test, Ok(test_result.to_string()));
@@ -511,11 +673,13 @@ mod tests {
         test_result.online_identifier = "".to_string();
         // Make work an edited book: first_author becomes empty,
         // first_editor lists first-numbered editor,
-        // publication_type reverts to Monograph
+        // publication_type reverts to Monograph and serial coverage is empty
         test_work.work_type = WorkType::EDITED_BOOK;
         test_result.first_author = "".to_string();
         test_result.first_editor = "FirstEd".to_string();
         test_result.publication_type = "Monograph".to_string();
+        test_result.num_vol_online = "".to_string();
+        test_result.num_issue_online = "".to_string();
         let to_test =
             KbartOclc.generate(&[test_work.clone()], QuoteStyle::Necessary, DELIMITER_TAB);
         assert_eq!(to_test, Ok(test_result.to_string()));
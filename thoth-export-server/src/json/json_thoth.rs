@@ -1,6 +1,7 @@
 use chrono::SecondsFormat;
 use serde::Serialize;
-use thoth_client::Work;
+use serde_json::{Map, Value};
+use thoth_client::{Work, WorkPublications};
 use thoth_errors::{ThothError, ThothResult};
 
 use super::JsonSpecification;
@@ -10,6 +11,17 @@ pub(crate) struct JsonThoth;
 
 const JSON_ERROR: &str = "json::thoth";
 
+/// Keys of the accessibility fields as queried, replaced in the output by schema.org properties
+const ACCESSIBILITY_KEYS: [&str; 7] = [
+    "accessibilityFeatures",
+    "accessibilityHazards",
+    "accessibilityStandard",
+    "wcagVersion",
+    "wcagLevel",
+    "accessibilityCertifier",
+    "accessibilitySummary",
+];
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct JsonWrapper<T: Serialize> {
@@ -39,7 +51,18 @@ impl JsonSpecification for JsonThoth {
                         ordered_work
                     },
                 };
-                serde_json::to_string_pretty(&wrapper)
+                let mut output = serde_json::to_value(&wrapper)
+                    .map_err(|e| ThothError::InternalError(e.to_string()))?;
+                if let Some(publications) =
+                    output.get_mut("publications").and_then(Value::as_array_mut)
+                {
+                    for (value, publication) in publications.iter_mut().zip(&work.publications) {
+                        if let Value::Object(fields) = value {
+                            *fields = with_schema_org_accessibility(fields, publication);
+                        }
+                    }
+                }
+                serde_json::to_string_pretty(&output)
                     .map_err(|e| ThothError::InternalError(e.to_string()))
             }
             // handler::by_publisher() prevents generation of output for multiple records
@@ -48,6 +71,50 @@ impl JsonSpecification for JsonThoth {
     }
 }
 
+/// Replace a publication's accessibility fields with their schema.org equivalents, as used
+/// in EPUB package metadata, keeping the position of the fields in the output
+fn with_schema_org_accessibility(
+    fields: &Map<String, Value>,
+    publication: &WorkPublications,
+) -> Map<String, Value> {
+    let mut output = Map::new();
+    for (key, value) in fields {
+        if key == ACCESSIBILITY_KEYS[0] {
+            output.insert(
+                "accessibilityFeature".to_string(),
+                publication
+                    .accessibility_features
+                    .iter()
+                    .map(|f| Value::from(f.schema_org()))
+                    .collect(),
+            );
+            output.insert(
+                "accessibilityHazard".to_string(),
+                publication
+                    .accessibility_hazards
+                    .iter()
+                    .map(|h| Value::from(h.schema_org()))
+                    .collect(),
+            );
+            output.insert(
+                "accessibilitySummary".to_string(),
+                publication.accessibility_summary.clone().into(),
+            );
+            output.insert(
+                "conformsTo".to_string(),
+                publication.accessibility_conformance().into(),
+            );
+            output.insert(
+                "certifiedBy".to_string(),
+                publication.accessibility_certifier.clone().into(),
+            );
+        } else if !ACCESSIBILITY_KEYS.contains(&key.as_str()) {
+            output.insert(key.clone(), value.clone());
+        }
+    }
+    output
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,14 +126,19 @@ mod tests {
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
-        LanguageRelation, LocationPlatform, PublicationType, RelationType, SeriesType, SubjectType,
-        Work, WorkContributions, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkFundings,
-        WorkImprint, WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkLanguages,
-        WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices, WorkReferences,
-        WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkImprint,
-        WorkRelationsRelatedWorkImprintPublisher, WorkStatus, WorkSubjects, WorkType,
+        AccessibilityFeature, AccessibilityHazard, AccessibilityStandard, BindingStyle,
+        ContentAudience, ContributionType, CountryCode, CoverFinish, CurrencyCode, DigitalLayout,
+        FundingInstitution, InteriorColour, LanguageCode, LanguageRelation, LocationPlatform,
+        PaperType, PriceType, PrizeCode, PublicationType, RelationType, ResourceContentType,
+        ResourceForm, ResourceMode, SeriesType, SubjectType, TechnicalProtection, TextContentType,
+        TrimCategory, UsageStatus, UsageType, WcagLevel, WcagVersion, Work, WorkContributions,
+        WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
+        WorkContributionsContributor, WorkFundings, WorkImprint, WorkImprintPublisher, WorkIssues,
+        WorkIssuesSeries, WorkLanguages, WorkPrizes, WorkPublications, WorkPublicationsLocations,
+        WorkPublicationsPrices, WorkPublicationsUsageConstraints, WorkReferences, WorkRelations,
+        WorkRelationsRelatedWork, WorkRelationsRelatedWorkImprint,
+        WorkRelationsRelatedWorkImprintPublisher, WorkStatus, WorkSubjects,
+        WorkSupportingResources, WorkTextContents, WorkTitles, WorkType,
     };
     use uuid::Uuid;
 
@@ -91,6 +163,14 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus.".to_string()),
             long_abstract: Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.".to_string()),
+            titles: vec![WorkTitles {
+                language_code: LanguageCode::SPA,
+                full_title: "Book Title: Book Subtitle".to_string(),
+                title: "Book Title".to_string(),
+                subtitle: Some("Book Subtitle".to_string()),
+                canonical: true,
+            }],
+            abstracts: vec![],
             general_note: Some("This is a general note".to_string()),
             bibliography_note: Some("This is a bibliography note".to_string()),
             place: Some("León, Spain".to_string()),
@@ -99,6 +179,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: Some(20),
             audio_count: Some(25),
@@ -121,6 +202,9 @@ mod tests {
             },
             issues: vec![WorkIssues {
                 issue_ordinal: 1,
+                volume: None,
+                issue_number: None,
+                part: None,
                 series: WorkIssuesSeries {
                     series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                     series_type: SeriesType::JOURNAL,
@@ -130,6 +214,8 @@ mod tests {
                     series_url: Some("https://www.series.com".to_string()),
                     series_description: Some("Description of series".to_string()),
                     series_cfp_url: Some("https://www.series.com/cfp".to_string()),
+                    parent_series: None,
+                    contributions: vec![],
                 },
             }],
             contributions: vec![
@@ -144,6 +230,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![
                         WorkContributionsAffiliations {
@@ -169,6 +260,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -200,14 +296,44 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 25.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 22.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 31.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![
@@ -224,6 +350,27 @@ mod tests {
                             canonical: false,
                         },
                     ],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: Some(BindingStyle::PERFECT_BOUND),
+                    paper_type: Some(PaperType::CREAM),
+                    interior_colour: Some(InteriorColour::BLACK_AND_WHITE),
+                    trim_category: Some(TrimCategory::US_TRADE),
+                    page_count: Some(352),
+                    cover_finish: Some(CoverFinish::MATTE_LAMINATE),
+                    carton_quantity: Some(24),
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -244,17 +391,68 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 36.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 32.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 40.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -278,6 +476,35 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![
+                        AccessibilityFeature::TABLE_OF_CONTENTS,
+                        AccessibilityFeature::SHORT_ALTERNATIVE_TEXT,
+                    ],
+                    accessibility_hazards: vec![AccessibilityHazard::NONE],
+                    accessibility_standard: Some(AccessibilityStandard::EPUB_1_1),
+                    wcag_version: Some(WcagVersion::WCAG_2_1),
+                    wcag_level: Some(WcagLevel::AA),
+                    accessibility_certifier: Some("Accessibility Certifier".to_string()),
+                    accessibility_summary: Some("Meets WCAG 2.1 level AA.".to_string()),
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: Some("1.7".to_string()),
+                    file_size_kb: Some(5120),
+                    technical_protections: vec![TechnicalProtection::NONE],
+                    layout: Some(DigitalLayout::FIXED_FORMAT),
+                    usage_constraints: vec![WorkPublicationsUsageConstraints {
+                        usage_type: UsageType::PRINT,
+                        usage_status: UsageStatus::PERMITTED_UNLIMITED,
+                        usage_limit: None,
+                        usage_unit: None,
+                    }],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -301,6 +528,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -319,6 +567,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![
@@ -379,8 +648,41 @@ mod tests {
                     institution_doi: Some(Doi::from_str("https://doi.org/10.00001/INSTITUTION.0001").unwrap()),
                     ror: Some(Ror::from_str("https://ror.org/0aaaaaa00").unwrap()),
                     country_code: Some(CountryCode::MDA),
+                    crossref_funder_id: None,
+                    isni: None,
+                    ringgold: None,
                 },
             }],
+            prizes: vec![WorkPrizes {
+                prize_name: "Name of prize".to_string(),
+                prize_year: Some(2021),
+                prize_country: Some(CountryCode::GBR),
+                prize_code: PrizeCode::SHORT_LISTED,
+                prize_statement: Some("Statement from the jury".to_string()),
+                prize_jury: Some("Members of the jury".to_string()),
+            }],
+            events: vec![],
+            text_contents: vec![WorkTextContents {
+                text_type: TextContentType::REVIEW_QUOTE,
+                content_audience: ContentAudience::UNRESTRICTED,
+                content: "A landmark study".to_string(),
+                text_author: Some("Name of reviewer".to_string()),
+                source_title: Some("Review Journal".to_string()),
+                source_url: Some("https://www.reviews.com/review".to_string()),
+                content_date: chrono::NaiveDate::from_ymd_opt(2022, 1, 15),
+            }],
+            supporting_resources: vec![WorkSupportingResources {
+                resource_content_type: ResourceContentType::CONTRIBUTOR_PICTURE,
+                content_audience: ContentAudience::UNRESTRICTED,
+                resource_mode: ResourceMode::IMAGE,
+                resource_form: ResourceForm::DOWNLOADABLE_FILE,
+                resource_url: "https://www.book.com/author.jpg".to_string(),
+                caption: Some("Portrait of the author".to_string()),
+                credit: Some("Photo by Name of photographer".to_string()),
+                width_px: Some(800),
+                height_px: Some(1200),
+                cover_rendition: None,
+            }],
             relations: vec![WorkRelations {
                 relation_type: RelationType::HAS_CHILD,
                 relation_ordinal: 1,
@@ -397,12 +699,15 @@ mod tests {
                     copyright_holder: None,
                     short_abstract: None,
                     long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
                     general_note: None,
                     place: None,
                     first_page: None,
                     last_page: None,
                     page_count: None,
                     page_interval: None,
+                    article_number: None,
                     landing_page: None,
                     imprint: WorkRelationsRelatedWorkImprint {
                         crossmark_doi: None,
@@ -439,6 +744,7 @@ mod tests {
                 publication_date: chrono::NaiveDate::from_ymd_opt(2022, 1, 1),
                 retrieval_date: None,
             }],
+            identifiers: vec![],
         };
     }
 
@@ -466,6 +772,7 @@ mod tests {
   "firstPage": null,
   "lastPage": null,
   "pageInterval": null,
+  "articleNumber": null,
   "imageCount": 15,
   "tableCount": 20,
   "audioCount": 25,
@@ -489,6 +796,9 @@ mod tests {
   "issues": [
     {
       "issueOrdinal": 1,
+      "volume": null,
+      "issueNumber": null,
+      "part": null,
       "series": {
         "seriesId": "00000000-0000-0000-bbbb-000000000002",
         "seriesType": "JOURNAL",
@@ -497,7 +807,9 @@ mod tests {
         "issnDigital": "8765-4321",
         "seriesUrl": "https://www.series.com",
         "seriesDescription": "Description of series",
-        "seriesCfpUrl": "https://www.series.com/cfp"
+        "seriesCfpUrl": "https://www.series.com/cfp",
+        "parentSeries": null,
+        "contributions": []
       }
     }
   ],
@@ -512,7 +824,12 @@ mod tests {
       "contributionOrdinal": 1,
       "contributor": {
         "orcid": "https://orcid.org/0000-0002-0000-0001",
-        "website": null
+        "website": null,
+        "isni": null,
+        "viaf": null,
+        "wikidata": null,
+        "lcnaf": null,
+        "alternativeNames": []
       },
       "affiliations": [
         {
@@ -537,7 +854,12 @@ mod tests {
       "contributionOrdinal": 2,
       "contributor": {
         "orcid": null,
-        "website": null
+        "website": null,
+        "isni": null,
+        "viaf": null,
+        "wikidata": null,
+        "lcnaf": null,
+        "alternativeNames": []
       },
       "affiliations": []
     }
@@ -549,6 +871,17 @@ mod tests {
       "mainLanguage": true
     }
   ],
+  "titles": [
+    {
+      "languageCode": "SPA",
+      "fullTitle": "Book Title: Book Subtitle",
+      "title": "Book Title",
+      "subtitle": "Book Subtitle",
+      "canonical": true
+    }
+  ],
+  "abstracts": [],
+  "identifiers": [],
   "publications": [
     {
       "publicationId": "00000000-0000-0000-bbbb-000000000002",
@@ -565,18 +898,64 @@ mod tests {
       "depthMm": 25.0,
       "depthCm": 2.5,
       "depthIn": 1.0,
+      "accessibilityFeature": [],
+      "accessibilityHazard": [],
+      "accessibilitySummary": null,
+      "conformsTo": null,
+      "certifiedBy": null,
+      "bindingStyle": "PERFECT_BOUND",
+      "paperType": "CREAM",
+      "interiorColour": "BLACK_AND_WHITE",
+      "trimCategory": "US_TRADE",
+      "pageCount": 352,
+      "coverFinish": "MATTE_LAMINATE",
+      "cartonQuantity": 24,
+      "formatVersion": null,
+      "fileSizeKb": null,
+      "technicalProtections": [],
+      "layout": null,
       "prices": [
         {
           "currencyCode": "EUR",
-          "unitPrice": 25.95
+          "unitPrice": 25.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         },
         {
           "currencyCode": "GBP",
-          "unitPrice": 22.95
+          "unitPrice": 22.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         },
         {
           "currencyCode": "USD",
-          "unitPrice": 31.95
+          "unitPrice": 31.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         }
       ],
       "locations": [
@@ -592,7 +971,10 @@ mod tests {
           "locationPlatform": "JSTOR",
           "canonical": false
         }
-      ]
+      ],
+      "identifiers": [],
+      "salesRights": [],
+      "usageConstraints": []
     },
     {
       "publicationId": "00000000-0000-0000-cccc-000000000003",
@@ -609,21 +991,70 @@ mod tests {
       "depthMm": null,
       "depthCm": null,
       "depthIn": null,
+      "accessibilityFeature": [],
+      "accessibilityHazard": [],
+      "accessibilitySummary": null,
+      "conformsTo": null,
+      "certifiedBy": null,
+      "bindingStyle": null,
+      "paperType": null,
+      "interiorColour": null,
+      "trimCategory": null,
+      "pageCount": null,
+      "coverFinish": null,
+      "cartonQuantity": null,
+      "formatVersion": null,
+      "fileSizeKb": null,
+      "technicalProtections": [],
+      "layout": null,
       "prices": [
         {
           "currencyCode": "EUR",
-          "unitPrice": 36.95
+          "unitPrice": 36.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         },
         {
           "currencyCode": "GBP",
-          "unitPrice": 32.95
+          "unitPrice": 32.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         },
         {
           "currencyCode": "USD",
-          "unitPrice": 40.95
+          "unitPrice": 40.95,
+          "priceType": "RRP_INCLUDING_TAX",
+          "priceQualifier": null,
+          "countriesIncluded": [],
+          "countriesExcluded": [],
+          "regionsIncluded": [],
+          "regionsExcluded": [],
+          "taxRatePercent": null,
+          "discountCode": null,
+          "priceEffectiveFrom": null,
+          "priceEffectiveUntil": null
         }
       ],
-      "locations": []
+      "locations": [],
+      "identifiers": [],
+      "salesRights": [],
+      "usageConstraints": []
     },
     {
       "publicationId": "00000000-0000-0000-dddd-000000000004",
@@ -640,6 +1071,29 @@ mod tests {
       "depthMm": null,
       "depthCm": null,
       "depthIn": null,
+      "accessibilityFeature": [
+        "tableOfContents",
+        "alternativeText"
+      ],
+      "accessibilityHazard": [
+        "none"
+      ],
+      "accessibilitySummary": "Meets WCAG 2.1 level AA.",
+      "conformsTo": "EPUB Accessibility 1.1 - WCAG 2.1 Level AA",
+      "certifiedBy": "Accessibility Certifier",
+      "bindingStyle": null,
+      "paperType": null,
+      "interiorColour": null,
+      "trimCategory": null,
+      "pageCount": null,
+      "coverFinish": null,
+      "cartonQuantity": null,
+      "formatVersion": "1.7",
+      "fileSizeKb": 5120,
+      "technicalProtections": [
+        "NONE"
+      ],
+      "layout": "FIXED_FORMAT",
       "prices": [],
       "locations": [
         {
@@ -648,6 +1102,16 @@ mod tests {
           "locationPlatform": "OTHER",
           "canonical": true
         }
+      ],
+      "identifiers": [],
+      "salesRights": [],
+      "usageConstraints": [
+        {
+          "usageType": "PRINT",
+          "usageStatus": "PERMITTED_UNLIMITED",
+          "usageLimit": null,
+          "usageUnit": null
+        }
       ]
     },
     {
@@ -665,6 +1129,22 @@ mod tests {
       "depthMm": null,
       "depthCm": null,
       "depthIn": null,
+      "accessibilityFeature": [],
+      "accessibilityHazard": [],
+      "accessibilitySummary": null,
+      "conformsTo": null,
+      "certifiedBy": null,
+      "bindingStyle": null,
+      "paperType": null,
+      "interiorColour": null,
+      "trimCategory": null,
+      "pageCount": null,
+      "coverFinish": null,
+      "cartonQuantity": null,
+      "formatVersion": null,
+      "fileSizeKb": null,
+      "technicalProtections": [],
+      "layout": null,
       "prices": [],
       "locations": [
         {
@@ -673,7 +1153,10 @@ mod tests {
           "locationPlatform": "OTHER",
           "canonical": true
         }
-      ]
+      ],
+      "identifiers": [],
+      "salesRights": [],
+      "usageConstraints": []
     },
     {
       "publicationId": "00000000-0000-0000-ffff-000000000006",
@@ -690,8 +1173,27 @@ mod tests {
       "depthMm": null,
       "depthCm": null,
       "depthIn": null,
+      "accessibilityFeature": [],
+      "accessibilityHazard": [],
+      "accessibilitySummary": null,
+      "conformsTo": null,
+      "certifiedBy": null,
+      "bindingStyle": null,
+      "paperType": null,
+      "interiorColour": null,
+      "trimCategory": null,
+      "pageCount": null,
+      "coverFinish": null,
+      "cartonQuantity": null,
+      "formatVersion": null,
+      "fileSizeKb": null,
+      "technicalProtections": [],
+      "layout": null,
       "prices": [],
-      "locations": []
+      "locations": [],
+      "identifiers": [],
+      "salesRights": [],
+      "usageConstraints": []
     }
   ],
   "subjects": [
@@ -752,10 +1254,49 @@ mod tests {
         "institutionName": "Name of institution",
         "institutionDoi": "https://doi.org/10.00001/INSTITUTION.0001",
         "ror": "https://ror.org/0aaaaaa00",
-        "countryCode": "MDA"
+        "countryCode": "MDA",
+        "crossrefFunderId": null,
+        "isni": null,
+        "ringgold": null
       }
     }
   ],
+  "prizes": [
+    {
+      "prizeName": "Name of prize",
+      "prizeYear": 2021,
+      "prizeCountry": "GBR",
+      "prizeCode": "SHORT_LISTED",
+      "prizeStatement": "Statement from the jury",
+      "prizeJury": "Members of the jury"
+    }
+  ],
+  "events": [],
+  "textContents": [
+    {
+      "textType": "REVIEW_QUOTE",
+      "contentAudience": "UNRESTRICTED",
+      "content": "A landmark study",
+      "textAuthor": "Name of reviewer",
+      "sourceTitle": "Review Journal",
+      "sourceUrl": "https://www.reviews.com/review",
+      "contentDate": "2022-01-15"
+    }
+  ],
+  "supportingResources": [
+    {
+      "resourceContentType": "CONTRIBUTOR_PICTURE",
+      "contentAudience": "UNRESTRICTED",
+      "resourceMode": "IMAGE",
+      "resourceForm": "DOWNLOADABLE_FILE",
+      "resourceUrl": "https://www.book.com/author.jpg",
+      "caption": "Portrait of the author",
+      "credit": "Photo by Name of photographer",
+      "widthPx": 800,
+      "heightPx": 1200,
+      "coverRendition": null
+    }
+  ],
   "relations": [
     {
       "relationType": "HAS_CHILD",
@@ -779,6 +1320,7 @@ mod tests {
         "lastPage": null,
         "pageCount": null,
         "pageInterval": null,
+        "articleNumber": null,
         "landingPage": null,
         "imprint": {
           "crossmarkDoi": null,
@@ -788,6 +1330,8 @@ mod tests {
         },
         "contributions": [],
         "languages": [],
+        "titles": [],
+        "abstracts": [],
         "publications": [],
         "fundings": [],
         "references": []
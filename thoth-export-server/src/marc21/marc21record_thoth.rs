@@ -1,13 +1,16 @@
 use crate::marc21::{Marc21Field, MARC_ORGANIZATION_CODE};
-use cc_license::License;
 use chrono::{Datelike, Utc};
 use marc::{DescriptiveCatalogingForm, EncodingLevel, FieldRepr, Record, RecordBuilder};
+use std::str::FromStr;
+use thoth_api::markup::{to_plain_text, Markup};
 use thoth_api::model::contribution::ContributionType;
+use thoth_api::model::license::{is_open_access, License};
 use thoth_api::model::publication::PublicationType;
 use thoth_api::model::IdentifierWithDomain;
 use thoth_client::{
-    LanguageRelation, RelationType, SubjectType, Work, WorkContributions, WorkFundings, WorkIssues,
-    WorkLanguages, WorkPublications, WorkRelations, WorkSubjects, WorkType,
+    AbstractType, AccessibilityFeature, IdentifierType, LanguageRelation, RelationType,
+    SubjectType, Work, WorkContributions, WorkFundings, WorkIssues, WorkLanguages,
+    WorkPublications, WorkRelations, WorkSubjects,
 };
 use thoth_errors::{ThothError, ThothResult};
 
@@ -36,10 +39,10 @@ impl Marc21Specification for Marc21RecordThoth {
 
 impl Marc21Entry<Marc21RecordThoth> for Work {
     fn to_record(&self) -> ThothResult<Record> {
-        if self.work_type == WorkType::BOOK_CHAPTER {
+        if self.work_type.is_component() {
             return Err(ThothError::IncompleteMetadataRecord(
                 MARC_ERROR.to_string(),
-                "MARC records are not available for chapters".to_string(),
+                "MARC records are not available for chapters or journal articles".to_string(),
             ));
         }
 
@@ -118,7 +121,7 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
             Marc21Field::<Marc21RecordThoth>::to_field(publication, &mut builder)?;
         }
 
-        // 024 - standard identifiers (DOI, OCLC)
+        // 024 - standard identifiers (DOI, OCLC, and any additional work and publication identifiers)
         if let Some(doi) = &self.doi {
             FieldRepr::from((b"024", "7\\"))
                 .add_subfield(b"a", doi.to_string().as_bytes())
@@ -131,6 +134,27 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
                 .and_then(|f| f.add_subfield(b"2", "worldcat"))
                 .and_then(|f| builder.add_field(f))?;
         }
+        for identifier in &self.identifiers {
+            if let Some(field) = standard_identifier_field(
+                &identifier.identifier_type,
+                &identifier.identifier_value,
+                None,
+            )? {
+                builder.add_field(field)?;
+            }
+        }
+        for publication in &self.publications {
+            let publication_type: PublicationType = publication.publication_type.clone().into();
+            for identifier in &publication.identifiers {
+                if let Some(field) = standard_identifier_field(
+                    &identifier.identifier_type,
+                    &identifier.identifier_value,
+                    Some(format!("({publication_type})")),
+                )? {
+                    builder.add_field(field)?;
+                }
+            }
+        }
 
         // 040 - cataloging source field
         FieldRepr::from((b"040", "\\\\"))
@@ -206,6 +230,20 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
             .add_subfield(b"c", contributors_string(&self.contributions).as_bytes())
             .and_then(|f| builder.add_field(f))?;
 
+        // 246 - parallel titles, in the work's other languages
+        for title in self.localised_titles().iter().filter(|t| !t.canonical) {
+            let mut parallel_title_field = FieldRepr::from((b"246", "31"));
+            if let Some(subtitle) = title.subtitle {
+                parallel_title_field = parallel_title_field
+                    .add_subfield(b"a", title.title.as_bytes())
+                    .and_then(|f| f.add_subfield(b"b", subtitle.as_bytes()))?;
+            } else {
+                parallel_title_field =
+                    parallel_title_field.add_subfield(b"a", title.title.as_bytes())?;
+            }
+            builder.add_field(parallel_title_field)?;
+        }
+
         // 250 - edition statement
         if let Some(edition) = &self.edition {
             if let Some(edition_statement) = match edition {
@@ -283,6 +321,13 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
             .and_then(|f| f.add_subfield(b"2", "rdacarrier"))
             .and_then(|f| builder.add_field(f))?;
 
+        // 341 - accessibility content, for each digital publication
+        for publication in &self.publications {
+            if let Some(field) = accessibility_content_field(publication)? {
+                builder.add_field(field)?;
+            }
+        }
+
         // 409 and 830 - series
         for issue in &self.issues {
             Marc21Field::<Marc21RecordThoth>::to_field(issue, &mut builder)?;
@@ -290,7 +335,7 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
 
         // 500 - general note
         let note_field = match &self.general_note {
-            Some(general_note) => general_note.clone().into_bytes(),
+            Some(general_note) => to_plain_text(general_note).into_bytes(),
             None => format!(
                 "Available through {}.",
                 self.imprint.publisher.publisher_name.clone()
@@ -304,7 +349,7 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
         // 504 - bibliography note
         if let Some(bibliography_note) = &self.bibliography_note {
             FieldRepr::from((b"504", "\\\\"))
-                .add_subfield(b"a", bibliography_note.clone().into_bytes())
+                .add_subfield(b"a", to_plain_text(bibliography_note).into_bytes())
                 .and_then(|f| builder.add_field(f))?;
         }
 
@@ -320,7 +365,7 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
         }
 
         // Assume omission of licence means work is non-OA
-        if self.license.is_some() {
+        if is_open_access(self.license.as_deref()) {
             // 506 - restrictions on access
             FieldRepr::from((b"506", "0\\"))
                 .add_subfield(b"a", "Open Access")
@@ -329,15 +374,26 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
                 .and_then(|f| builder.add_field(f))?;
         }
 
-        // 520 - abstract
-        if let Some(mut long_abstract) = self.long_abstract.clone() {
-            // Strip out formatting marks as these may stop records loading successfully
+        // 520 - abstract, in each of the work's languages
+        for long_abstract in self.localised_abstracts(AbstractType::LONG) {
+            // Strip out markup and formatting marks as these may stop records loading successfully
+            let mut long_abstract = match Markup::from_str(long_abstract.content) {
+                Ok(markup) if markup.is_formatted() => markup.to_plain_text().replace('\n', " "),
+                _ => long_abstract.content.to_string(),
+            };
             long_abstract.retain(|c| c != '\n' && c != '\r' && c != '\t');
             FieldRepr::from((b"520", "\\\\"))
                 .add_subfield(b"a", long_abstract.into_bytes())
                 .and_then(|f| builder.add_field(f))?;
         }
 
+        // 532 - accessibility note, for each digital publication
+        for publication in &self.publications {
+            for field in accessibility_note_fields(publication)? {
+                builder.add_field(field)?;
+            }
+        }
+
         // 536 - funding
         for funding in &self.fundings {
             Marc21Field::<Marc21RecordThoth>::to_field(funding, &mut builder)?;
@@ -349,10 +405,10 @@ impl Marc21Entry<Marc21RecordThoth> for Work {
             .and_then(|f| builder.add_field(f))?;
 
         // 540 - license
-        if let Some(license_url) = self.license.clone() {
-            let license_text = match License::from_url(&license_url) {
-                Ok(license) => format!("The text of this book is licensed under a {} For more detailed information consult the publisher's website.", license.to_string()),
-                Err(_) => "The text of this book is licensed under a custom license. For more detailed information consult the publisher's website.".to_string(),
+        if let Some(license) = &self.license {
+            let (license_text, license_url) = match License::from_str(license) {
+                Ok(license) => (format!("The text of this book is licensed under a {} For more detailed information consult the publisher's website.", license.description()), license.url()),
+                Err(_) => ("The text of this book is licensed under a custom license. For more detailed information consult the publisher's website.".to_string(), license.to_string()),
             };
             FieldRepr::from((b"540", "\\\\"))
                 .add_subfield(b"a", license_text.as_bytes())
@@ -475,16 +531,35 @@ fn contributor_fields(contributions: &[WorkContributions]) -> ThothResult<Vec<Fi
                 format!("{}.", affiliation.institution.institution_name.clone()).as_bytes(),
             )?;
         }
-        if let Some(orcid) = &contributions.first().unwrap().contributor.orcid {
-            // $0 Authority Record Control Number (ORCID is a permitted source)
+        let contributor = &contributions.first().unwrap().contributor;
+        // $0 Authority Record Control Number or URI (ORCID is a permitted source)
+        if let Some(orcid) = &contributor.orcid {
             contributor_field = contributor_field.add_subfield(
                 b"0",
                 format!("(orcid){}", orcid.to_string().replace('-', "")),
             )?;
-            // $1 Real World Object URI
+        }
+        if let Some(lcnaf) = &contributor.lcnaf {
+            contributor_field =
+                contributor_field.add_subfield(b"0", lcnaf.with_domain().as_bytes())?;
+        }
+        if let Some(viaf) = &contributor.viaf {
+            contributor_field =
+                contributor_field.add_subfield(b"0", viaf.with_domain().as_bytes())?;
+        }
+        // $1 Real World Object URI
+        if let Some(orcid) = &contributor.orcid {
             contributor_field =
                 contributor_field.add_subfield(b"1", orcid.with_domain().as_bytes())?;
         }
+        if let Some(isni) = &contributor.isni {
+            contributor_field =
+                contributor_field.add_subfield(b"1", isni.with_domain().as_bytes())?;
+        }
+        if let Some(wikidata) = &contributor.wikidata {
+            contributor_field =
+                contributor_field.add_subfield(b"1", wikidata.with_domain().as_bytes())?;
+        }
         contributor_fields.push(contributor_field);
     }
     Ok(contributor_fields)
@@ -556,6 +631,116 @@ fn language_field(languages: &[WorkLanguages]) -> Option<FieldRepr> {
     Some(language_field)
 }
 
+/// 024 field for an additional identifier, with the publication it applies to as qualifier.
+/// Publisher SKUs are not standard identifiers and are omitted.
+fn standard_identifier_field(
+    identifier_type: &IdentifierType,
+    identifier_value: &str,
+    qualifier: Option<String>,
+) -> ThothResult<Option<FieldRepr>> {
+    // First indicator 7 requires the source of the identifier in subfield $2
+    let (indicator, source) = match identifier_type {
+        IdentifierType::GTIN => match identifier_value.len() {
+            12 => ("1\\", None),
+            13 => ("3\\", None),
+            14 => ("7\\", Some("gtin-14")),
+            _ => ("8\\", None),
+        },
+        IdentifierType::HANDLE => ("7\\", Some("hdl")),
+        IdentifierType::ARK => ("7\\", Some("ark")),
+        IdentifierType::URN_NBN => ("7\\", Some("urn")),
+        IdentifierType::ISTC => ("7\\", Some("istc")),
+        IdentifierType::WIKIDATA => ("7\\", Some("wikidata")),
+        IdentifierType::SKU | IdentifierType::Other(_) => return Ok(None),
+    };
+    let mut field = FieldRepr::from((b"024", indicator)).add_subfield(b"a", identifier_value)?;
+    if let Some(qualifier) = qualifier {
+        field = field.add_subfield(b"q", qualifier)?;
+    }
+    if let Some(source) = source {
+        field = field.add_subfield(b"2", source)?;
+    }
+    Ok(Some(field))
+}
+
+/// 341 field listing the assistive features of a publication as schema.org terms, grouped into
+/// textual ($b), visual ($c) and auditory ($d) features
+fn accessibility_content_field(publication: &WorkPublications) -> ThothResult<Option<FieldRepr>> {
+    if publication.accessibility_features.is_empty() {
+        return Ok(None);
+    }
+    let publication_type: PublicationType = publication.publication_type.clone().into();
+    let mut field = FieldRepr::from((b"341", "0\\"))
+        .add_subfield(b"3", publication_type.to_string())
+        .and_then(|f| f.add_subfield(b"a", "textual"))?;
+    for subfield in [b"b", b"c", b"d"] {
+        for feature in publication
+            .accessibility_features
+            .iter()
+            .filter(|f| accessibility_content_subfield(f) == subfield)
+        {
+            field = field.add_subfield(subfield, feature.schema_org())?;
+        }
+    }
+    Ok(Some(field.add_subfield(b"2", "sapdv")?))
+}
+
+fn accessibility_content_subfield(feature: &AccessibilityFeature) -> &'static [u8; 1] {
+    match feature {
+        AccessibilityFeature::HIGH_CONTRAST_DISPLAY
+        | AccessibilityFeature::DISPLAY_TRANSFORMABILITY => b"c",
+        AccessibilityFeature::SYNCHRONISED_AUDIO
+        | AccessibilityFeature::HIGH_CONTRAST_AUDIO
+        | AccessibilityFeature::TEXT_TO_SPEECH_HINTING => b"d",
+        _ => b"b",
+    }
+}
+
+/// 532 fields for the accessibility summary, conformance and hazards of a publication
+fn accessibility_note_fields(publication: &WorkPublications) -> ThothResult<Vec<FieldRepr>> {
+    let publication_type: PublicationType = publication.publication_type.clone().into();
+    let mut notes: Vec<(&str, String)> = vec![];
+    if let Some(mut summary) = publication.accessibility_summary.clone() {
+        // Strip out formatting marks as these may stop records loading successfully
+        summary.retain(|c| c != '\n' && c != '\r' && c != '\t');
+        // No display constant
+        notes.push(("8\\", summary));
+    }
+    let conformance = publication
+        .accessibility_conformance()
+        .map(|c| format!("Conforms to {c}."));
+    let certification = publication
+        .accessibility_certifier
+        .as_ref()
+        .map(|c| format!("Certified by {c}."));
+    let technical_details = [conformance, certification]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<String>>();
+    if !technical_details.is_empty() {
+        // Accessibility technical details
+        notes.push(("0\\", technical_details.join(" ")));
+    }
+    if !publication.accessibility_hazards.is_empty() {
+        let hazards = publication
+            .accessibility_hazards
+            .iter()
+            .map(|h| h.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        notes.push(("8\\", format!("Hazards: {hazards}.")));
+    }
+    notes
+        .into_iter()
+        .map(|(indicator, note)| {
+            FieldRepr::from((b"532", indicator))
+                .add_subfield(b"3", publication_type.to_string())
+                .and_then(|f| f.add_subfield(b"a", note.into_bytes()))
+                .map_err(Into::into)
+        })
+        .collect()
+}
+
 fn toc_field(relations: &[WorkRelations]) -> ThothResult<FieldRepr> {
     let mut chapters = relations
         .iter()
@@ -630,13 +815,33 @@ impl Marc21Field<Marc21RecordThoth> for WorkPublications {
 
 impl Marc21Field<Marc21RecordThoth> for WorkIssues {
     fn to_field(&self, builder: &mut RecordBuilder) -> ThothResult<()> {
-        let fields = [(b"490", "1\\"), (b"830", "\\0")];
-        for (field, indicator) in fields {
-            FieldRepr::from((field, indicator))
-                .add_subfield(b"a", format!("{} ;", self.series.series_name).as_bytes())
-                .and_then(|f| {
-                    f.add_subfield(b"v", format!("vol. {}.", self.issue_ordinal).as_bytes())
-                })
+        // Numbering without a volume, e.g. "no. 3", is given as printed
+        let numbering = if self.volume.is_some() || !self.has_numbering() {
+            format!("vol. {}.", self.numbering())
+        } else {
+            format!("{}.", self.numbering())
+        };
+        let series_name = format!("{} ;", self.series.series_name);
+        // Subseries are transcribed after their parent series in the series statement,
+        // and given as a section of the parent series in the series added entry
+        let (statement, added_entry) = match &self.series.parent_series {
+            Some(parent_series) => (
+                FieldRepr::from((b"490", "1\\")).add_subfield(
+                    b"a",
+                    format!("{}. {}", parent_series.series_name, series_name).as_bytes(),
+                ),
+                FieldRepr::from((b"830", "\\0"))
+                    .add_subfield(b"a", format!("{}.", parent_series.series_name).as_bytes())
+                    .and_then(|f| f.add_subfield(b"p", series_name.as_bytes())),
+            ),
+            None => (
+                FieldRepr::from((b"490", "1\\")).add_subfield(b"a", series_name.as_bytes()),
+                FieldRepr::from((b"830", "\\0")).add_subfield(b"a", series_name.as_bytes()),
+            ),
+        };
+        for field in [statement, added_entry] {
+            field
+                .and_then(|f| f.add_subfield(b"v", numbering.as_bytes()))
                 .and_then(|f| {
                     self.series
                         .issn_digital
@@ -786,13 +991,16 @@ fn nonfiling_char_count(title: &str, language: &str) -> String {
 pub(crate) mod tests {
     use super::*;
     use std::str::FromStr;
+    use thoth_api::model::identifier::{Isni, Lcnaf, Viaf, WikidataId};
     use thoth_api::model::{Doi, Isbn, Orcid};
     use thoth_client::{
-        FundingInstitution, LanguageCode, SeriesType, WorkContributionsAffiliations,
+        AccessibilityHazard, AccessibilityStandard, FundingInstitution, LanguageCode, SeriesType,
+        WcagLevel, WcagVersion, WorkContributionsAffiliations,
         WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkRelationsRelatedWork,
-        WorkRelationsRelatedWorkContributions, WorkRelationsRelatedWorkContributionsContributor,
-        WorkRelationsRelatedWorkImprint, WorkRelationsRelatedWorkImprintPublisher, WorkStatus,
+        WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkIssuesSeriesParentSeries,
+        WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
+        WorkRelationsRelatedWorkContributionsContributor, WorkRelationsRelatedWorkImprint,
+        WorkRelationsRelatedWorkImprintPublisher, WorkStatus, WorkType,
     };
     use uuid::Uuid;
 
@@ -813,6 +1021,8 @@ pub(crate) mod tests {
             copyright_holder: None,
             short_abstract: None,
             long_abstract: Some("Lorem\tipsum\r\ndolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: Some(
                 "Please note that in this book the mathematical formulas are encoded in MathML."
                     .to_string(),
@@ -824,6 +1034,7 @@ pub(crate) mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -846,6 +1057,9 @@ pub(crate) mod tests {
             },
             issues: vec![WorkIssues {
                 issue_ordinal: 11,
+                volume: None,
+                issue_number: None,
+                part: None,
                 series: WorkIssuesSeries {
                     series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                     series_type: SeriesType::BOOK_SERIES,
@@ -855,6 +1069,8 @@ pub(crate) mod tests {
                     series_url: None,
                     series_description: None,
                     series_cfp_url: None,
+                    parent_series: None,
+                    contributions: vec![],
                 },
             }],
             contributions: vec![
@@ -871,6 +1087,11 @@ pub(crate) mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -896,6 +1117,11 @@ pub(crate) mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0004").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -910,6 +1136,11 @@ pub(crate) mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -953,6 +1184,27 @@ pub(crate) mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Default::default(),
@@ -971,6 +1223,27 @@ pub(crate) mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Default::default(),
@@ -989,6 +1262,27 @@ pub(crate) mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![
@@ -1039,10 +1333,18 @@ pub(crate) mod tests {
                     institution_doi: None,
                     ror: None,
                     country_code: None,
+                    crossref_funder_id: None,
+                    isni: None,
+                    ringgold: None,
                 },
             }],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         }
     }
 
@@ -1058,6 +1360,11 @@ pub(crate) mod tests {
             contributor: WorkContributionsContributor {
                 orcid: None,
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         }
@@ -1080,12 +1387,15 @@ pub(crate) mod tests {
                 copyright_holder: None,
                 short_abstract: None,
                 long_abstract: None,
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: None,
                 first_page: None,
                 last_page: None,
                 page_count: None,
                 page_interval: None,
+                article_number: None,
                 landing_page: None,
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: None,
@@ -1316,6 +1626,37 @@ pub(crate) mod tests {
         contribution.contributor = WorkContributionsContributor {
             orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0011").unwrap()),
             website: None,
+            isni: None,
+            viaf: None,
+            wikidata: None,
+            lcnaf: None,
+            alternative_names: vec![],
+        };
+        let contributions = [contribution];
+
+        let expected = Ok(vec![FieldRepr::from((b"100", "1\\"))
+            .add_subfield(b"a", "Doe, Jane,".as_bytes())
+            .and_then(|f| f.add_subfield(b"e", "author.".as_bytes()))
+            .and_then(|f| f.add_subfield(b"0", "(orcid)0000000200000011".as_bytes()))
+            .and_then(|f| f.add_subfield(b"1", "https://orcid.org/0000-0002-0000-0011".as_bytes()))
+            .unwrap()]);
+        assert_eq!(contributor_fields(&contributions), expected);
+    }
+
+    #[test]
+    fn test_contributor_fields_single_author_with_authority_identifiers() {
+        let mut contribution = test_contribution();
+        contribution.first_name = Some("Jane".to_string());
+        contribution.last_name = "Doe".to_string();
+        contribution.full_name = "Jane Doe".to_string();
+        contribution.contributor = WorkContributionsContributor {
+            orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0011").unwrap()),
+            website: None,
+            isni: Some(Isni::from_str("0000 0001 2103 2683").unwrap()),
+            viaf: Some(Viaf::from_str("44300643").unwrap()),
+            wikidata: Some(WikidataId::from_str("Q935").unwrap()),
+            lcnaf: Some(Lcnaf::from_str("n79021164").unwrap()),
+            alternative_names: vec![],
         };
         let contributions = [contribution];
 
@@ -1323,7 +1664,16 @@ pub(crate) mod tests {
             .add_subfield(b"a", "Doe, Jane,".as_bytes())
             .and_then(|f| f.add_subfield(b"e", "author.".as_bytes()))
             .and_then(|f| f.add_subfield(b"0", "(orcid)0000000200000011".as_bytes()))
+            .and_then(|f| {
+                f.add_subfield(
+                    b"0",
+                    "https://id.loc.gov/authorities/names/n79021164".as_bytes(),
+                )
+            })
+            .and_then(|f| f.add_subfield(b"0", "https://viaf.org/viaf/44300643".as_bytes()))
             .and_then(|f| f.add_subfield(b"1", "https://orcid.org/0000-0002-0000-0011".as_bytes()))
+            .and_then(|f| f.add_subfield(b"1", "https://isni.org/isni/0000000121032683".as_bytes()))
+            .and_then(|f| f.add_subfield(b"1", "https://www.wikidata.org/wiki/Q935".as_bytes()))
             .unwrap()]);
         assert_eq!(contributor_fields(&contributions), expected);
     }
@@ -1916,6 +2266,162 @@ pub(crate) mod tests {
         assert_eq!(Marc21RecordThoth {}.generate(&[work]), Ok(expected))
     }
 
+    #[test]
+    fn test_generate_marc_multilingual() {
+        let mut work = test_work();
+        work.titles = vec![
+            thoth_client::WorkTitles {
+                language_code: thoth_client::LanguageCode::ENG,
+                full_title: "Book Title: Book Subtitle".to_string(),
+                title: "Book Title".to_string(),
+                subtitle: Some("Book Subtitle".to_string()),
+                canonical: true,
+            },
+            thoth_client::WorkTitles {
+                language_code: thoth_client::LanguageCode::SPA,
+                full_title: "Título".to_string(),
+                title: "Título".to_string(),
+                subtitle: None,
+                canonical: false,
+            },
+        ];
+        work.abstracts = vec![
+            thoth_client::WorkAbstracts {
+                language_code: thoth_client::LanguageCode::ENG,
+                abstract_type: AbstractType::LONG,
+                content: "Lorem ipsum".to_string(),
+                canonical: true,
+            },
+            thoth_client::WorkAbstracts {
+                language_code: thoth_client::LanguageCode::SPA,
+                abstract_type: AbstractType::LONG,
+                content: "Resumen\nlargo".to_string(),
+                canonical: false,
+            },
+        ];
+        let output = Marc21RecordThoth {}.generate(&[work]).unwrap();
+        assert!(output.contains("\u{1e}31\u{1f}aTítulo\u{1e}"));
+        assert!(output.contains("\u{1e}\\\\\u{1f}aLorem ipsum\u{1e}\\\\\u{1f}aResumenlargo\u{1e}"));
+        assert!(!output.contains("Loremipsumdolor sit amet"));
+    }
+
+    #[test]
+    fn test_standard_identifier_field() {
+        assert_eq!(
+            standard_identifier_field(&IdentifierType::GTIN, "9780262134729", None)
+                .unwrap()
+                .unwrap()
+                .get_data(),
+            b"3\\\x1fa9780262134729"
+        );
+        assert_eq!(
+            standard_identifier_field(
+                &IdentifierType::HANDLE,
+                "20.500.12657/47011",
+                Some("(Paperback)".to_string())
+            )
+            .unwrap()
+            .unwrap()
+            .get_data(),
+            b"7\\\x1fa20.500.12657/47011\x1fq(Paperback)\x1f2hdl"
+        );
+        assert!(
+            standard_identifier_field(&IdentifierType::SKU, "ABC-123", None)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_accessibility_fields() {
+        let mut publication = test_work().publications[0].clone();
+        assert!(accessibility_content_field(&publication).unwrap().is_none());
+        assert!(accessibility_note_fields(&publication).unwrap().is_empty());
+
+        publication.accessibility_features = vec![
+            AccessibilityFeature::HIGH_CONTRAST_DISPLAY,
+            AccessibilityFeature::TABLE_OF_CONTENTS,
+            AccessibilityFeature::TEXT_TO_SPEECH_HINTING,
+        ];
+        publication.accessibility_hazards = vec![
+            AccessibilityHazard::NO_FLASHING_HAZARD,
+            AccessibilityHazard::NO_SOUND_HAZARD,
+        ];
+        publication.accessibility_standard = Some(AccessibilityStandard::EPUB_1_1);
+        publication.wcag_version = Some(WcagVersion::WCAG_2_2);
+        publication.wcag_level = Some(WcagLevel::AA);
+        publication.accessibility_certifier = Some("Certifier".to_string());
+        publication.accessibility_summary = Some("Fully accessible.\n".to_string());
+        assert_eq!(
+            accessibility_content_field(&publication)
+                .unwrap()
+                .unwrap()
+                .get_data(),
+            b"0\\\x1f3PDF\x1fatextual\x1fbtableOfContents\x1fchighContrastDisplay\x1fdttsMarkup\x1f2sapdv"
+        );
+        let notes = accessibility_note_fields(&publication).unwrap();
+        assert_eq!(notes.len(), 3);
+        assert_eq!(notes[0].get_data(), b"8\\\x1f3PDF\x1faFully accessible.");
+        assert_eq!(
+            notes[1].get_data(),
+            b"0\\\x1f3PDF\x1faConforms to EPUB Accessibility 1.1 - WCAG 2.2 Level AA. Certified by Certifier."
+        );
+        assert_eq!(
+            notes[2].get_data(),
+            b"8\\\x1f3PDF\x1faHazards: No Flashing Hazard, No Sound Hazard."
+        );
+    }
+
+    #[test]
+    fn test_series_fields_numbering() {
+        let series_fields = |issue: &WorkIssues| {
+            let mut builder = RecordBuilder::new();
+            Marc21Field::<Marc21RecordThoth>::to_field(issue, &mut builder).unwrap();
+            builder
+                .iter_fields()
+                .map(|f| String::from_utf8_lossy(f.get_data()).to_string())
+                .collect::<Vec<String>>()
+        };
+        let mut issue = test_work().issues[0].clone();
+        let fields = series_fields(&issue);
+        assert_eq!(fields.len(), 2);
+        assert!(fields.iter().all(|f| f.contains("\x1fvvol. 11.\x1f")));
+
+        issue.volume = Some("12a".to_string());
+        issue.issue_number = Some("3".to_string());
+        issue.part = Some("2".to_string());
+        let fields = series_fields(&issue);
+        assert!(fields
+            .iter()
+            .all(|f| f.contains("\x1fvvol. 12a, no. 3, pt. 2.\x1f")));
+
+        issue.volume = None;
+        let fields = series_fields(&issue);
+        assert!(fields.iter().all(|f| f.contains("\x1fvno. 3, pt. 2.\x1f")));
+    }
+
+    #[test]
+    fn test_series_fields_subseries() {
+        let mut issue = test_work().issues[0].clone();
+        issue.series.parent_series = Some(WorkIssuesSeriesParentSeries {
+            series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
+            series_name: "Name of parent series".to_string(),
+            issn_print: None,
+            issn_digital: None,
+        });
+        let mut builder = RecordBuilder::new();
+        Marc21Field::<Marc21RecordThoth>::to_field(&issue, &mut builder).unwrap();
+        let fields = builder
+            .iter_fields()
+            .map(|f| String::from_utf8_lossy(f.get_data()).to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(fields.len(), 2);
+        assert!(fields[0]
+            .starts_with("1\\\x1faName of parent series. Name of series ;\x1fvvol. 11.\x1f"));
+        assert!(fields[1]
+            .starts_with("\\0\x1faName of parent series.\x1fpName of series ;\x1fvvol. 11.\x1f"));
+    }
+
     #[test]
     fn test_generate_no_work_error() {
         assert!(Marc21RecordThoth {}.generate(&[]).is_err())
@@ -1928,6 +2434,13 @@ pub(crate) mod tests {
         assert!(Marc21RecordThoth {}.generate(&[work]).is_err())
     }
 
+    #[test]
+    fn test_generate_journal_article_error() {
+        let mut work = test_work();
+        work.work_type = WorkType::JOURNAL_ARTICLE;
+        assert!(Marc21RecordThoth {}.generate(&[work]).is_err())
+    }
+
     #[test]
     fn test_generate_no_publications_error() {
         let mut work = test_work();
@@ -1,15 +1,21 @@
 use chrono::Utc;
 use std::io::Write;
+use std::str::FromStr;
+use thoth_api::markup::{Markup, MarkupNode, MarkupTag};
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::license::License;
 use thoth_api::model::IdentifierWithDomain;
 use thoth_client::{
-    ContributionType, Funding, PublicationType, Reference, RelationType, Work, WorkContributions,
-    WorkContributionsAffiliationsInstitution, WorkFundings, WorkIssuesSeries, WorkPublications,
-    WorkReferences, WorkRelations, WorkRelationsRelatedWorkContributions,
+    AbstractType, ContributionType, Funding, LocalisedAbstract, LocalisedTitle, PublicationType,
+    Reference, RelationType, SeriesType, Work, WorkContributions,
+    WorkContributionsAffiliationsInstitution, WorkEvents, WorkFundings, WorkIssues,
+    WorkIssuesSeries, WorkPublications, WorkReferences, WorkRelations,
+    WorkRelationsRelatedWorkContributions,
     WorkRelationsRelatedWorkContributionsAffiliationsInstitution, WorkType,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlSpecification};
+use super::{funder_doi, write_element_block, XmlSpecification};
 use crate::xml::{write_full_element_block, XmlElementBlock};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -79,6 +85,16 @@ impl XmlSpecification for DoiDepositCrossref {
 
 impl XmlElementBlock<DoiDepositCrossref> for Work {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
+        match self.work_type {
+            WorkType::JOURNAL_ISSUE => return write_journal(self, w),
+            WorkType::JOURNAL_ARTICLE => {
+                return Err(ThothError::IncompleteMetadataRecord(
+                    DEPOSIT_ERROR.to_string(),
+                    "Journal articles are deposited as part of their journal issue".to_string(),
+                ))
+            }
+            _ => {}
+        }
         if self.doi.is_none()
             && !self
                 .relations
@@ -90,12 +106,19 @@ impl XmlElementBlock<DoiDepositCrossref> for Work {
                 "No work or chapter DOIs to deposit".to_string(),
             ));
         }
+        // Proceedings of a conference are deposited as `conference` records, their chapters as papers
+        if let Some(event) = self.events.first() {
+            return write_conference(self, event, w);
+        }
         let work_type = match &self.work_type {
             WorkType::MONOGRAPH => "monograph",
             WorkType::EDITED_BOOK => "edited_book",
             WorkType::TEXTBOOK => "reference",
-            WorkType::JOURNAL_ISSUE | WorkType::BOOK_SET | WorkType::BOOK_CHAPTER => "other",
-            WorkType::Other(_) => unreachable!(),
+            WorkType::BOOK_SET | WorkType::BOOK_CHAPTER => "other",
+            // Journal issues and articles are deposited as `journal` records above
+            WorkType::JOURNAL_ISSUE | WorkType::JOURNAL_ARTICLE | WorkType::Other(_) => {
+                unreachable!()
+            }
         };
         // As an alternative to `book_metadata` and `book_series_metadata` below,
         // `book_set_metadata` can be used for works which are part of a set.
@@ -105,39 +128,43 @@ impl XmlElementBlock<DoiDepositCrossref> for Work {
         } else {
             "book_series_metadata"
         };
+        let language =
+            crossref_language(self.canonical_language(), self.main_language()).unwrap_or("en");
         write_element_block("body", w, |w| {
             write_full_element_block("book", Some(vec![("book_type", work_type)]), w, |w| {
-                write_full_element_block(element_name, Some(vec![("language", "en")]), w, |w| {
-                    // Only one series can be listed, so we select the first one found (if any).
-                    let mut ordinal = None;
-                    if let Some((series, ord)) =
-                        self.issues.first().map(|i| (&i.series, i.issue_ordinal))
-                    {
-                        XmlElementBlock::<DoiDepositCrossref>::xml_element(series, w)?;
-                        ordinal = Some(ord);
-                    }
-                    write_work_contributions(self, w)?;
-                    write_work_title(self, w)?;
-                    write_work_abstract(self, w)?;
+                write_full_element_block(
+                    element_name,
+                    Some(vec![("language", language)]),
+                    w,
+                    |w| {
+                        // Only one series can be listed, so we select the first one found (if any).
+                        let issue = self.issues.first();
+                        if let Some(issue) = issue {
+                            XmlElementBlock::<DoiDepositCrossref>::xml_element(&issue.series, w)?;
+                        }
+                        write_work_contributions(self, w)?;
+                        write_work_title(self, w)?;
+                        write_work_abstract(self, w)?;
 
-                    if ordinal.is_some() {
-                        let ordinal_i64 = ordinal.unwrap_or(0);
-                        write_work_volume(ordinal_i64, w)?;
-                    }
+                        if let Some(volume) = issue.and_then(|i| i.volume_designation()) {
+                            write_work_volume(&volume, w)?;
+                        }
 
-                    write_work_edition(self, w)?;
-                    write_work_publication_date(self, w)?;
-                    write_work_publications(self, w)?;
-                    write_publisher(self, w)?;
-                    write_crossmark_funding_access(self, w)?;
-                    write_doi_collection(self, w)?;
-                    write_work_references(self, w)?;
-                    Ok(())
-                })?;
+                        write_work_edition(self, w)?;
+                        write_work_publication_date(self, w)?;
+                        write_work_publications(self, w)?;
+                        write_publisher(self, w)?;
+                        write_crossmark_funding_access(self, w)?;
+                        write_doi_collection(self, w)?;
+                        write_work_references(self, w)?;
+                        Ok(())
+                    },
+                )?;
 
                 let mut chapters = self.relations.clone();
                 // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
                 chapters.sort_by(|a, b| a.relation_ordinal.cmp(&b.relation_ordinal));
+                inherit_license(&mut chapters, &self.license);
                 for chapter in chapters
                     .iter()
                     .filter(|r| r.relation_type == RelationType::HAS_CHILD)
@@ -154,6 +181,237 @@ impl XmlElementBlock<DoiDepositCrossref> for Work {
     }
 }
 
+// A journal issue is deposited together with the articles which are part of it,
+// the issue itself representing the volume and number of the journal it belongs to
+fn write_journal<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
+    let mut articles: Vec<WorkRelations> = work
+        .relations
+        .iter()
+        .filter(|r| r.relation_type == RelationType::HAS_PART)
+        .cloned()
+        .collect();
+    // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
+    articles.sort_by(|a, b| a.relation_ordinal.cmp(&b.relation_ordinal));
+    inherit_license(&mut articles, &work.license);
+    if work.doi.is_none() && !articles.iter().any(|a| a.related_work.doi.is_some()) {
+        return Err(ThothError::IncompleteMetadataRecord(
+            DEPOSIT_ERROR.to_string(),
+            "No issue or article DOIs to deposit".to_string(),
+        ));
+    }
+    let issue = work
+        .issues
+        .iter()
+        .find(|i| i.series.series_type == SeriesType::JOURNAL)
+        .ok_or_else(|| {
+            ThothError::IncompleteMetadataRecord(
+                DEPOSIT_ERROR.to_string(),
+                "Journal issues must be part of a journal".to_string(),
+            )
+        })?;
+    let language =
+        crossref_language(work.canonical_language(), work.main_language()).unwrap_or("en");
+    write_element_block("body", w, |w| {
+        write_element_block("journal", w, |w| {
+            write_journal_metadata(&issue.series, language, w)?;
+            write_element_block("journal_issue", w, |w| {
+                write_work_contributions(work, w)?;
+                write_work_title(work, w)?;
+                write_work_publication_date(work, w)?;
+                write_journal_issue_numbering(issue, w)?;
+                write_doi_collection(work, w)
+            })?;
+            for article in &articles {
+                // As with chapters, `journal_article` must contain a `doi_data` element with `doi`
+                if article.related_work.doi.is_some() {
+                    write_journal_article(article, work, w)?;
+                }
+            }
+            Ok(())
+        })
+    })
+}
+
+// Only one event can be listed, so we select the first one found
+fn write_conference<W: Write>(
+    work: &Work,
+    event: &WorkEvents,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let language =
+        crossref_language(work.canonical_language(), work.main_language()).unwrap_or("en");
+    // Without an ISSN the series cannot be listed, so proceedings are deposited on their own
+    let series = work
+        .issues
+        .first()
+        .map(|i| &i.series)
+        .filter(|s| s.issn_digital.is_some() || s.issn_print.is_some());
+    let element_name = if series.is_none() {
+        "proceedings_metadata"
+    } else {
+        "proceedings_series_metadata"
+    };
+    write_element_block("body", w, |w| {
+        write_element_block("conference", w, |w| {
+            write_work_contributions(work, w)?;
+            XmlElementBlock::<DoiDepositCrossref>::xml_element(event, w)?;
+            write_full_element_block(element_name, Some(vec![("language", language)]), w, |w| {
+                if let Some(series) = series {
+                    XmlElementBlock::<DoiDepositCrossref>::xml_element(series, w)?;
+                }
+                write_element_block("proceedings_title", w, |w| {
+                    w.write(XmlEvent::Characters(&work.full_title))
+                        .map_err(|e| e.into())
+                })?;
+                if let Some(volume) = series
+                    .and(work.issues.first())
+                    .and_then(|i| i.volume_designation())
+                {
+                    write_work_volume(&volume, w)?;
+                }
+                write_publisher(work, w)?;
+                write_work_publication_date(work, w)?;
+                write_work_publications(work, w)?;
+                write_crossmark_funding_access(work, w)?;
+                write_doi_collection(work, w)
+            })?;
+
+            let mut papers = work.relations.clone();
+            // WorkQuery should already have retrieved these sorted by ordinal, but sort again for safety
+            papers.sort_by_key(|p| p.relation_ordinal);
+            inherit_license(&mut papers, &work.license);
+            for paper in papers
+                .iter()
+                .filter(|r| r.relation_type == RelationType::HAS_CHILD)
+            {
+                // As with chapters, `conference_paper` must contain a `doi_data` element with `doi`
+                if paper.related_work.doi.is_some() {
+                    write_conference_paper(paper, w)?;
+                }
+            }
+            Ok(())
+        })
+    })
+}
+
+fn write_conference_paper<W: Write>(
+    paper: &WorkRelations,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let mut attr = vec![("publication_type", "full_text")];
+    if let Some(language) = crossref_language(
+        paper.related_work.canonical_language(),
+        paper.related_work.main_language(),
+    ) {
+        attr.push(("language", language));
+    }
+    write_full_element_block("conference_paper", Some(attr), w, |w| {
+        write_chapter_contributions(paper, w)?;
+        write_chapter_title(paper, w)?;
+        write_chapter_abstract(paper, w)?;
+        write_chapter_publication_date(paper, w)?;
+        write_chapter_pages(paper, w)?;
+        write_chapter_funding_access(paper, w)?;
+        write_chapter_doi_collection(paper, w)?;
+        write_chapter_references(paper, w)
+    })
+}
+
+fn write_journal_metadata<W: Write>(
+    journal: &WorkIssuesSeries,
+    language: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_full_element_block(
+        "journal_metadata",
+        Some(vec![("language", language)]),
+        w,
+        |w| {
+            write_element_block("full_title", w, |w| {
+                w.write(XmlEvent::Characters(&journal.series_name))
+                    .map_err(|e| e.into())
+            })?;
+            write_issns(journal, w)
+        },
+    )
+}
+
+fn write_journal_issue_numbering<W: Write>(
+    issue: &WorkIssues,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(volume) = &issue.volume {
+        write_element_block("journal_volume", w, |w| {
+            write_element_block("volume", w, |w| {
+                w.write(XmlEvent::Characters(volume)).map_err(|e| e.into())
+            })
+        })?;
+    }
+    if let Some(issue_number) = &issue.issue_number {
+        write_element_block("issue", w, |w| {
+            w.write(XmlEvent::Characters(issue_number))
+                .map_err(|e| e.into())
+        })?;
+    }
+    if let Some(part) = &issue.part {
+        write_element_block("special_numbering", w, |w| {
+            w.write(XmlEvent::Characters(&format!("pt. {part}")))
+                .map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
+fn write_journal_article<W: Write>(
+    article: &WorkRelations,
+    issue: &Work,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let mut attr = vec![("publication_type", "full_text")];
+    if let Some(language) = crossref_language(
+        article.related_work.canonical_language(),
+        article.related_work.main_language(),
+    ) {
+        attr.push(("language", language));
+    }
+    write_full_element_block("journal_article", Some(attr), w, |w| {
+        write_chapter_title(article, w)?;
+        write_chapter_contributions(article, w)?;
+        write_chapter_abstract(article, w)?;
+        // `publication_date` is mandatory for `journal_article`: fall back to the issue's
+        match article
+            .related_work
+            .publication_date
+            .or(issue.publication_date)
+        {
+            Some(date) => write_publication_date_content(&date, w)?,
+            None => {
+                return Err(ThothError::IncompleteMetadataRecord(
+                    DEPOSIT_ERROR.to_string(),
+                    "Missing Publication Date".to_string(),
+                ))
+            }
+        }
+        write_chapter_pages(article, w)?;
+        if let Some(article_number) = &article.related_work.article_number {
+            write_element_block("publisher_item", w, |w| {
+                write_full_element_block(
+                    "item_number",
+                    Some(vec![("item_number_type", "article_number")]),
+                    w,
+                    |w| {
+                        w.write(XmlEvent::Characters(article_number))
+                            .map_err(|e| e.into())
+                    },
+                )
+            })?;
+        }
+        write_chapter_funding_access(article, w)?;
+        write_chapter_doi_collection(article, w)?;
+        write_chapter_references(article, w)
+    })
+}
+
 fn write_work_contributions<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
     let contributions: Vec<WorkContributions> = work
         .contributions
@@ -204,36 +462,66 @@ fn write_chapter_contributions<W: Write>(
     Ok(())
 }
 
+// Crossref identifies languages by their ISO 639-1 codes
+fn crossref_language(
+    canonical_language: Option<LanguageCode>,
+    main_language: Option<LanguageCode>,
+) -> Option<&'static str> {
+    canonical_language
+        .or(main_language)
+        .and_then(|language| language.iso_639_1())
+}
+
 fn write_work_title<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    write_title_content(&work.title, work.subtitle.as_deref(), w)
+    let titles = work.localised_titles();
+    write_title_content(&titles[0], work.original_language_title(), w)
 }
 
 fn write_chapter_title<W: Write>(
     chapter: &WorkRelations,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
+    let titles = chapter.related_work.localised_titles();
     write_title_content(
-        &chapter.related_work.title,
-        chapter.related_work.subtitle.as_deref(),
+        &titles[0],
+        chapter.related_work.original_language_title(),
         w,
     )
 }
 
 fn write_title_content<W: Write>(
-    title: &str,
-    subtitle: Option<&str>,
+    title: &LocalisedTitle,
+    original_language_title: Option<LocalisedTitle>,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
     write_element_block("titles", w, |w| {
         write_element_block("title", w, |w| {
-            w.write(XmlEvent::Characters(title)).map_err(|e| e.into())
+            w.write(XmlEvent::Characters(title.title))
+                .map_err(|e| e.into())
         })?;
-        if let Some(subtitle) = subtitle {
+        if let Some(subtitle) = title.subtitle {
             write_element_block("subtitle", w, |w| {
                 w.write(XmlEvent::Characters(subtitle))
                     .map_err(|e| e.into())
             })?;
         }
+        // A work's title in the language it was translated from
+        if let Some(original) = &original_language_title {
+            let language = original
+                .language_code
+                .and_then(|l| l.iso_639_1())
+                .map(|l| vec![("language", l)]);
+            write_full_element_block("original_language_title", language, w, |w| {
+                w.write(XmlEvent::Characters(original.title))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(subtitle) = original.subtitle {
+                write_element_block("subtitle", w, |w| {
+                    w.write(XmlEvent::Characters(subtitle))
+                        .map_err(|e| e.into())
+                })?;
+            }
+        }
         Ok(())
     })?;
     Ok(())
@@ -244,11 +532,11 @@ fn write_work_abstract<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothRe
     // which can be set to any value. In our case we use "long" or "short".
     // Abstracts must be output in JATS, we simply convert them into JATS by extracting its
     // paragraphs and tagging them with <jats:p>
-    if let Some(long_abstract) = &work.long_abstract {
-        write_abstract_content(long_abstract, "long", w)?;
+    for long_abstract in work.localised_abstracts(AbstractType::LONG) {
+        write_abstract_content(&long_abstract, "long", w)?;
     }
-    if let Some(short_abstract) = &work.short_abstract {
-        write_abstract_content(short_abstract, "short", w)?;
+    for short_abstract in work.localised_abstracts(AbstractType::SHORT) {
+        write_abstract_content(&short_abstract, "short", w)?;
     }
     Ok(())
 }
@@ -261,42 +549,102 @@ fn write_chapter_abstract<W: Write>(
     // which can be set to any value. In our case we use "long" or "short".
     // Abstracts must be output in JATS, we simply convert them into JATS by extracting its
     // paragraphs and tagging them with <jats:p>
-    if let Some(long_abstract) = &chapter.related_work.long_abstract {
-        write_abstract_content(long_abstract, "long", w)?;
+    for long_abstract in chapter.related_work.localised_abstracts(AbstractType::LONG) {
+        write_abstract_content(&long_abstract, "long", w)?;
     }
-    if let Some(short_abstract) = &chapter.related_work.short_abstract {
-        write_abstract_content(short_abstract, "short", w)?;
+    for short_abstract in chapter
+        .related_work
+        .localised_abstracts(AbstractType::SHORT)
+    {
+        write_abstract_content(&short_abstract, "short", w)?;
     }
     Ok(())
 }
 
 fn write_abstract_content<W: Write>(
-    abstract_content: &str,
+    abstract_content: &LocalisedAbstract,
     abstract_type: &str,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
-    write_full_element_block(
-        "jats:abstract",
-        Some(vec![("abstract-type", abstract_type)]),
-        w,
-        |w| {
-            for paragraph in abstract_content.lines() {
-                if !paragraph.is_empty() {
-                    write_element_block("jats:p", w, |w| {
-                        w.write(XmlEvent::Characters(paragraph))
-                            .map_err(|e| e.into())
-                    })?;
-                }
+    let mut attr = vec![("abstract-type", abstract_type)];
+    if let Some(language) = abstract_content.language_code.and_then(|l| l.iso_639_1()) {
+        attr.push(("xml:lang", language));
+    }
+    write_full_element_block("jats:abstract", Some(attr), w, |w| {
+        if let Ok(markup) = Markup::from_str(abstract_content.content) {
+            if markup.is_formatted() {
+                return write_jats_blocks(markup.blocks(), w);
             }
-            Ok(())
-        },
-    )
+        }
+        for paragraph in abstract_content.content.lines() {
+            if !paragraph.is_empty() {
+                write_element_block("jats:p", w, |w| {
+                    w.write(XmlEvent::Characters(paragraph))
+                        .map_err(|e| e.into())
+                })?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn is_block_node(node: &MarkupNode) -> bool {
+    matches!(node, MarkupNode::Element(tag, _) if tag.is_block() || *tag == MarkupTag::ListItem)
+}
+
+// JATS requires list items to hold paragraphs, so runs of inline content found
+// alongside block elements are wrapped in <jats:p>
+fn write_jats_blocks<W: Write>(nodes: &[MarkupNode], w: &mut EventWriter<W>) -> ThothResult<()> {
+    let mut inline_start = 0;
+    for (index, node) in nodes.iter().enumerate() {
+        if !is_block_node(node) {
+            continue;
+        }
+        write_jats_paragraph(&nodes[inline_start..index], w)?;
+        inline_start = index + 1;
+        if let MarkupNode::Element(tag, children) = node {
+            let name = tag.jats_name().unwrap_or("jats:p");
+            if let Some(list_type) = tag.jats_list_type() {
+                write_full_element_block(name, Some(vec![("list-type", list_type)]), w, |w| {
+                    write_jats_blocks(children, w)
+                })?;
+            } else if *tag == MarkupTag::ListItem {
+                write_element_block(name, w, |w| write_jats_blocks(children, w))?;
+            } else {
+                write_element_block(name, w, |w| write_jats_inline(children, w))?;
+            }
+        }
+    }
+    write_jats_paragraph(&nodes[inline_start..], w)
+}
+
+fn write_jats_paragraph<W: Write>(nodes: &[MarkupNode], w: &mut EventWriter<W>) -> ThothResult<()> {
+    let is_blank = nodes
+        .iter()
+        .all(|node| matches!(node, MarkupNode::Text(text) if text.trim().is_empty()));
+    if is_blank {
+        return Ok(());
+    }
+    write_element_block("jats:p", w, |w| write_jats_inline(nodes, w))
 }
 
-fn write_work_volume<W: Write>(ordinal: i64, w: &mut EventWriter<W>) -> ThothResult<()> {
+fn write_jats_inline<W: Write>(nodes: &[MarkupNode], w: &mut EventWriter<W>) -> ThothResult<()> {
+    for node in nodes {
+        match node {
+            MarkupNode::Text(text) => w.write(XmlEvent::Characters(text))?,
+            MarkupNode::Element(tag, children) => match tag.jats_name() {
+                Some(name) => write_element_block(name, w, |w| write_jats_inline(children, w))?,
+                // JATS has no line break element within paragraphs
+                None => w.write(XmlEvent::Characters(" "))?,
+            },
+        }
+    }
+    Ok(())
+}
+
+fn write_work_volume<W: Write>(volume: &str, w: &mut EventWriter<W>) -> ThothResult<()> {
     write_element_block("volume", w, |w| {
-        w.write(XmlEvent::Characters(&ordinal.to_string()))
-            .map_err(|e| e.into())
+        w.write(XmlEvent::Characters(volume)).map_err(|e| e.into())
     })?;
     Ok(())
 }
@@ -514,6 +862,16 @@ fn write_chapter_funding_access<W: Write>(
     Ok(())
 }
 
+// Chapters without a licence of their own are published under the licence of the parent work
+fn inherit_license(relations: &mut [WorkRelations], license: &Option<String>) {
+    for relation in relations
+        .iter_mut()
+        .filter(|r| r.related_work.license.is_none())
+    {
+        relation.related_work.license.clone_from(license);
+    }
+}
+
 fn write_funding_content<W: Write>(
     fundings: &[Funding],
     w: &mut EventWriter<W>,
@@ -535,14 +893,23 @@ fn write_access_content<W: Write>(
 ) -> ThothResult<()> {
     // Assume works without licences are non-OA
     if let Some(license) = license {
+        // Licences which are neither from the controlled vocabulary nor a web address
+        // are not known to be open
+        let (is_open_access, license_url) = match License::from_str(license) {
+            Ok(license) => (true, license.url()),
+            Err(_) => (false, license.to_string()),
+        };
         write_full_element_block(
             "ai:program",
             Some(vec![("name", "AccessIndicators")]),
             w,
             |w| {
-                write_element_block("ai:free_to_read", w, |_w| Ok(()))?;
+                if is_open_access {
+                    write_element_block("ai:free_to_read", w, |_w| Ok(()))?;
+                }
                 write_element_block("ai:license_ref", w, |w| {
-                    w.write(XmlEvent::Characters(license)).map_err(|e| e.into())
+                    w.write(XmlEvent::Characters(&license_url))
+                        .map_err(|e| e.into())
                 })
             },
         )?;
@@ -783,6 +1150,72 @@ fn write_chapter_pages<W: Write>(
     Ok(())
 }
 
+impl XmlElementBlock<DoiDepositCrossref> for WorkEvents {
+    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
+        write_element_block("event_metadata", w, |w| {
+            write_element_block("conference_name", w, |w| {
+                w.write(XmlEvent::Characters(&self.event_name))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(acronym) = &self.event_acronym {
+                write_element_block("conference_acronym", w, |w| {
+                    w.write(XmlEvent::Characters(acronym)).map_err(|e| e.into())
+                })?;
+            }
+            let mut sponsors = self.sponsors.clone();
+            sponsors.sort_by_key(|s| s.sponsor_ordinal);
+            for sponsor in &sponsors {
+                write_element_block("conference_sponsor", w, |w| {
+                    w.write(XmlEvent::Characters(&sponsor.institution.institution_name))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if let Some(number) = &self.event_number {
+                write_element_block("conference_number", w, |w| {
+                    w.write(XmlEvent::Characters(number)).map_err(|e| e.into())
+                })?;
+            }
+            let location = [
+                self.event_location.clone(),
+                self.event_country.as_ref().and_then(|c| c.name()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<String>>()
+            .join(", ");
+            if !location.is_empty() {
+                write_element_block("conference_location", w, |w| {
+                    w.write(XmlEvent::Characters(&location))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if let Some(start_date) = &self.event_start_date {
+                let start_day = start_date.format("%d").to_string();
+                let start_month = start_date.format("%m").to_string();
+                let start_year = start_date.format("%Y").to_string();
+                let end_date = self.event_end_date.unwrap_or(*start_date);
+                let end_day = end_date.format("%d").to_string();
+                let end_month = end_date.format("%m").to_string();
+                let end_year = end_date.format("%Y").to_string();
+                write_full_element_block(
+                    "conference_date",
+                    Some(vec![
+                        ("start_day", start_day.as_str()),
+                        ("start_month", start_month.as_str()),
+                        ("start_year", start_year.as_str()),
+                        ("end_day", end_day.as_str()),
+                        ("end_month", end_month.as_str()),
+                        ("end_year", end_year.as_str()),
+                    ]),
+                    w,
+                    |_| Ok(()),
+                )?;
+            }
+            Ok(())
+        })
+    }
+}
+
 impl XmlElementBlock<DoiDepositCrossref> for WorkIssuesSeries {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
         if self.issn_digital.is_some() || self.issn_print.is_some() {
@@ -793,29 +1226,7 @@ impl XmlElementBlock<DoiDepositCrossref> for WorkIssuesSeries {
                             .map_err(|e| e.into())
                     })
                 })?;
-                if let Some(issn_print) = &self.issn_print {
-                    write_full_element_block(
-                        "issn",
-                        Some(vec![("media_type", "print")]),
-                        w,
-                        |w| {
-                            w.write(XmlEvent::Characters(issn_print))
-                                .map_err(|e| e.into())
-                        },
-                    )?;
-                }
-                if let Some(issn_digital) = &self.issn_digital {
-                    write_full_element_block(
-                        "issn",
-                        Some(vec![("media_type", "electronic")]),
-                        w,
-                        |w| {
-                            w.write(XmlEvent::Characters(issn_digital))
-                                .map_err(|e| e.into())
-                        },
-                    )?;
-                }
-                Ok(())
+                write_issns(self, w)
             })
         } else {
             Ok(())
@@ -823,30 +1234,48 @@ impl XmlElementBlock<DoiDepositCrossref> for WorkIssuesSeries {
     }
 }
 
+fn write_issns<W: Write>(series: &WorkIssuesSeries, w: &mut EventWriter<W>) -> ThothResult<()> {
+    if let Some(issn_print) = &series.issn_print {
+        write_full_element_block("issn", Some(vec![("media_type", "print")]), w, |w| {
+            w.write(XmlEvent::Characters(issn_print))
+                .map_err(|e| e.into())
+        })?;
+    }
+    if let Some(issn_digital) = &series.issn_digital {
+        write_full_element_block("issn", Some(vec![("media_type", "electronic")]), w, |w| {
+            w.write(XmlEvent::Characters(issn_digital))
+                .map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
 impl XmlElementBlock<DoiDepositCrossref> for WorkRelations {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
         if !self.relation_type.eq(&RelationType::HAS_CHILD) {
             // Caller should only pass in child works (chapters), not any other relations.
             unreachable!()
         }
-        write_full_element_block(
-            "content_item",
-            Some(vec![("component_type", "chapter")]),
-            w,
-            |w| {
-                write_chapter_contributions(self, w)?;
-                write_chapter_title(self, w)?;
-                write_chapter_abstract(self, w)?;
-                write_chapter_component_number(self, w)?;
-                check_chapter_has_no_edition(self)?;
-                write_chapter_publication_date(self, w)?;
-                write_chapter_pages(self, w)?;
-                write_chapter_funding_access(self, w)?;
-                write_chapter_doi_collection(self, w)?;
-                write_chapter_references(self, w)?;
-                Ok(())
-            },
-        )?;
+        let mut attr = vec![("component_type", "chapter")];
+        if let Some(language) = crossref_language(
+            self.related_work.canonical_language(),
+            self.related_work.main_language(),
+        ) {
+            attr.push(("language", language));
+        }
+        write_full_element_block("content_item", Some(attr), w, |w| {
+            write_chapter_contributions(self, w)?;
+            write_chapter_title(self, w)?;
+            write_chapter_abstract(self, w)?;
+            write_chapter_component_number(self, w)?;
+            check_chapter_has_no_edition(self)?;
+            write_chapter_publication_date(self, w)?;
+            write_chapter_pages(self, w)?;
+            write_chapter_funding_access(self, w)?;
+            write_chapter_doi_collection(self, w)?;
+            write_chapter_references(self, w)?;
+            Ok(())
+        })?;
         Ok(())
     }
 }
@@ -1047,13 +1476,17 @@ impl XmlElementBlock<DoiDepositCrossref> for WorkFundings {
                 w,
                 |w| {
                     w.write(XmlEvent::Characters(&self.institution.institution_name))?;
-                    if let Some(doi) = &self.institution.institution_doi {
+                    // Prefer a Funder Registry DOI, falling back to the ROR ID
+                    let funder_identifier = funder_doi(&self.institution)
+                        .map(|doi| doi.with_domain())
+                        .or_else(|| self.institution.ror.as_ref().map(|ror| ror.with_domain()));
+                    if let Some(funder_identifier) = &funder_identifier {
                         write_full_element_block(
                             "fr:assertion",
                             Some(vec![("name", "funder_identifier")]),
                             w,
                             |w| {
-                                w.write(XmlEvent::Characters(&doi.with_domain()))
+                                w.write(XmlEvent::Characters(funder_identifier))
                                     .map_err(|e| e.into())
                             },
                         )?;
@@ -1204,18 +1637,22 @@ mod tests {
     // We therefore rely on `assert!(contains)` rather than `assert_eq!`
     use super::*;
     use std::str::FromStr;
+    use thoth_api::model::identifier::FunderId;
     use thoth_api::model::{Doi, Isbn, Orcid, Ror};
     use thoth_client::{
-        ContributionType, FundingInstitution, LocationPlatform, PublicationType, SeriesType,
+        AbstractType, ContributionType, CountryCode, FundingInstitution, LanguageCode,
+        LanguageRelation, LocationPlatform, PublicationType, SeriesType, WorkAbstracts,
         WorkContributions, WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
-        WorkContributionsContributor, WorkFundings, WorkImprint, WorkImprintPublisher, WorkIssues,
-        WorkIssuesSeries, WorkPublications, WorkPublicationsLocations, WorkReferences,
-        WorkRelations, WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
-        WorkRelationsRelatedWorkContributionsAffiliations,
+        WorkContributionsContributor, WorkEvents, WorkEventsSponsors,
+        WorkEventsSponsorsInstitution, WorkFundings, WorkImprint, WorkImprintPublisher, WorkIssues,
+        WorkIssuesSeries, WorkLanguages, WorkPublications, WorkPublicationsLocations,
+        WorkReferences, WorkRelations, WorkRelationsRelatedWork,
+        WorkRelationsRelatedWorkContributions, WorkRelationsRelatedWorkContributionsAffiliations,
         WorkRelationsRelatedWorkContributionsAffiliationsInstitution,
         WorkRelationsRelatedWorkContributionsContributor, WorkRelationsRelatedWorkImprint,
-        WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkPublications,
-        WorkRelationsRelatedWorkPublicationsLocations, WorkStatus, WorkType,
+        WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkLanguages,
+        WorkRelationsRelatedWorkPublications, WorkRelationsRelatedWorkPublicationsLocations,
+        WorkStatus, WorkTitles, WorkType,
     };
     use uuid::Uuid;
 
@@ -1243,6 +1680,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_doideposit_crossref_funder_identifier() {
+        let mut test_funding = WorkFundings {
+            program: None,
+            project_name: None,
+            project_shortname: None,
+            grant_number: None,
+            jurisdiction: None,
+            institution: FundingInstitution {
+                institution_name: "European Commission".to_string(),
+                institution_doi: Some(Doi::from_str("https://doi.org/10.00001/funder").unwrap()),
+                ror: Some(Ror::from_str("https://ror.org/00k4n6c32").unwrap()),
+                country_code: None,
+                crossref_funder_id: Some(FunderId::from_str("501100000780").unwrap()),
+                isni: None,
+                ringgold: None,
+            },
+        };
+        let identifier = |output: &str| {
+            output
+                .split(r#"<fr:assertion name="funder_identifier">"#)
+                .nth(1)
+                .and_then(|rest| rest.split("</fr:assertion>").next())
+                .map(str::to_string)
+        };
+
+        // The Funder Registry DOI formed from the Crossref Funder ID is preferred
+        let output = generate_test_output(true, &test_funding);
+        assert_eq!(
+            identifier(&output).as_deref(),
+            Some("https://doi.org/10.13039/501100000780")
+        );
+
+        // Followed by the institution DOI
+        test_funding.institution.crossref_funder_id = None;
+        let output = generate_test_output(true, &test_funding);
+        assert_eq!(
+            identifier(&output).as_deref(),
+            Some("https://doi.org/10.00001/funder")
+        );
+
+        // And finally the ROR ID
+        test_funding.institution.institution_doi = None;
+        let output = generate_test_output(true, &test_funding);
+        assert_eq!(
+            identifier(&output).as_deref(),
+            Some("https://ror.org/00k4n6c32")
+        );
+
+        test_funding.institution.ror = None;
+        let output = generate_test_output(true, &test_funding);
+        assert_eq!(identifier(&output), None);
+        assert!(output
+            .contains(r#"<fr:assertion name="funder_name">European Commission</fr:assertion>"#));
+    }
+
     #[test]
     fn test_doideposit_crossref_relatedworks() {
         let mut test_relations = WorkRelations {
@@ -1261,12 +1754,15 @@ mod tests {
                 copyright_holder: None,
                 short_abstract: Some("A shorter abstract".to_string()),
                 long_abstract: Some("First paragraph.\n\nSecond paragraph.".to_string()),
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: Some("Other Place".to_string()),
                 first_page: Some("10".to_string()),
                 last_page: Some("20".to_string()),
                 page_count: Some(11),
                 page_interval: Some("10–20".to_string()),
+                article_number: None,
                 landing_page: Some("https://www.book.com/chapter_one".to_string()),
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: Some(
@@ -1471,6 +1967,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -1479,6 +1977,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -1502,6 +2001,9 @@ mod tests {
             issues: vec![
                 WorkIssues {
                     issue_ordinal: 11,
+                    volume: None,
+                    issue_number: None,
+                    part: None,
                     series: WorkIssuesSeries {
                         series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                         series_type: SeriesType::BOOK_SERIES,
@@ -1511,10 +2013,15 @@ mod tests {
                         series_url: None,
                         series_description: None,
                         series_cfp_url: None,
+                        parent_series: None,
+                        contributions: vec![],
                     },
                 },
                 WorkIssues {
                     issue_ordinal: 22,
+                    volume: None,
+                    issue_number: None,
+                    part: None,
                     series: WorkIssuesSeries {
                         series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                         series_type: SeriesType::BOOK_SERIES,
@@ -1524,6 +2031,8 @@ mod tests {
                         series_url: None,
                         series_description: None,
                         series_cfp_url: None,
+                        parent_series: None,
+                        contributions: vec![],
                     },
                 },
             ],
@@ -1541,6 +2050,11 @@ mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0004").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1557,6 +2071,11 @@ mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -1582,6 +2101,11 @@ mod tests {
                             Orcid::from_str("https://orcid.org/0000-0002-0000-0002").unwrap(),
                         ),
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -1596,6 +2120,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![WorkContributionsAffiliations {
                         position: None,
@@ -1633,6 +2162,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -1656,6 +2206,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -1674,6 +2245,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
@@ -1689,6 +2281,9 @@ mod tests {
                         institution_doi: None,
                         ror: None,
                         country_code: None,
+                        crossref_funder_id: None,
+                        isni: None,
+                        ringgold: None,
                     },
                 },
                 WorkFundings {
@@ -1704,9 +2299,16 @@ mod tests {
                         ),
                         ror: None,
                         country_code: None,
+                        crossref_funder_id: None,
+                        isni: None,
+                        ringgold: None,
                     },
                 },
             ],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![WorkRelations {
                 relation_type: RelationType::HAS_PART,
                 relation_ordinal: 1,
@@ -1723,12 +2325,15 @@ mod tests {
                     copyright_holder: None,
                     short_abstract: None,
                     long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
                     general_note: None,
                     place: Some("Other Place".to_string()),
                     first_page: Some("10".to_string()),
                     last_page: Some("20".to_string()),
                     page_count: Some(11),
                     page_interval: Some("10–20".to_string()),
+                    article_number: None,
                     landing_page: Some("https://www.book.com/part_one".to_string()),
                     imprint: WorkRelationsRelatedWorkImprint {
                         crossmark_doi: None,
@@ -1765,6 +2370,7 @@ mod tests {
                 publication_date: chrono::NaiveDate::from_ymd_opt(2022, 1, 1),
                 retrieval_date: None,
             }],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1939,12 +2545,15 @@ mod tests {
                 copyright_holder: None,
                 short_abstract: None,
                 long_abstract: None,
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: Some("Other Place".to_string()),
                 first_page: Some("10".to_string()),
                 last_page: Some("20".to_string()),
                 page_count: Some(11),
                 page_interval: Some("10–20".to_string()),
+                article_number: None,
                 landing_page: Some("https://www.book.com/part_one".to_string()),
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: None,
@@ -1973,6 +2582,12 @@ mod tests {
         assert!(output.contains(r#"        </custom_metadata>"#));
         assert!(output.contains(r#"      </crossmark>"#));
 
+        // Printed volume replaces the series ordinal
+        test_work.issues[0].volume = Some("3b".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"      <volume>11</volume>"#));
+        assert!(output.contains(r#"      <volume>3b</volume>"#));
+
         // Remove/change some values to test variations/non-output of optional blocks
         test_work.work_type = WorkType::EDITED_BOOK;
         test_work.issues.clear();
@@ -2100,7 +2715,7 @@ mod tests {
         );
 
         // Change work type again, replace landing page, replace chapter DOI
-        test_work.work_type = WorkType::JOURNAL_ISSUE;
+        test_work.work_type = WorkType::BOOK_SET;
         test_work.landing_page = Some("https://www.book.com".to_string());
         test_work.relations[0].related_work.doi =
             Some(Doi::from_str("https://doi.org/10.00001/PART.0001").unwrap());
@@ -2116,6 +2731,180 @@ mod tests {
         assert!(output.contains(r#"        <doi>10.00001/PART.0001</doi>"#));
         assert!(output.contains(r#"        <resource>https://www.book.com/part_one</resource>"#));
 
+        // Licences are deposited in their canonical form, and chapters keep their own licence
+        test_work.license =
+            Some("http://www.creativecommons.org/licenses/by/4.0/legalcode".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"<ai:license_ref>https://creativecommons.org/licenses/by/4.0/</ai:license_ref>"#
+        ));
+        assert!(output.contains(
+            r#"<ai:license_ref>https://creativecommons.org/licenses/by-nd/4.0/</ai:license_ref>"#
+        ));
+        assert!(!output.contains(r#"legalcode"#));
+        // Chapters without a licence inherit the work's licence
+        test_work.relations[0].related_work.license = None;
+        let output = generate_test_output(true, &test_work);
+        assert_eq!(
+            output
+                .matches(
+                    r#"<ai:license_ref>https://creativecommons.org/licenses/by/4.0/</ai:license_ref>"#
+                )
+                .count(),
+            2
+        );
+        assert!(!output.contains(
+            r#"<ai:license_ref>https://creativecommons.org/licenses/by-nd/4.0/</ai:license_ref>"#
+        ));
+        // Custom licences are still free to read
+        test_work.license = Some("https://www.book.com/license".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"<ai:license_ref>https://www.book.com/license</ai:license_ref>"#));
+        assert!(output.contains(r#"<ai:free_to_read />"#));
+        // Anything else saved before the controlled vocabulary was introduced is not
+        test_work.license = Some("All rights reserved".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"<ai:license_ref>All rights reserved</ai:license_ref>"#));
+        assert!(!output.contains(r#"<ai:free_to_read />"#));
+        test_work.license = None;
+        test_work.relations[0].related_work.license =
+            Some("https://creativecommons.org/licenses/by-nd/4.0/".to_string());
+
+        // Record titles, abstracts and languages: the deposit language follows the canonical title
+        test_work.titles = vec![
+            WorkTitles {
+                language_code: LanguageCode::ENG,
+                full_title: "Book Title".to_string(),
+                title: "Book Title".to_string(),
+                subtitle: None,
+                canonical: false,
+            },
+            WorkTitles {
+                language_code: LanguageCode::FRE,
+                full_title: "Titre".to_string(),
+                title: "Titre".to_string(),
+                subtitle: None,
+                canonical: true,
+            },
+        ];
+        test_work.abstracts = vec![WorkAbstracts {
+            language_code: LanguageCode::FRE,
+            abstract_type: AbstractType::LONG,
+            content: "Résumé".to_string(),
+            canonical: true,
+        }];
+        test_work.languages = vec![WorkLanguages {
+            language_code: LanguageCode::ENG,
+            language_relation: LanguageRelation::TRANSLATED_FROM,
+            main_language: false,
+        }];
+        test_work.relations[0].related_work.languages = vec![WorkRelationsRelatedWorkLanguages {
+            language_code: LanguageCode::SPA,
+            language_relation: LanguageRelation::ORIGINAL,
+            main_language: true,
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"    <book_metadata language="fr">"#));
+        assert!(output.contains(r#"        <title>Titre</title>"#));
+        assert!(output.contains(
+            r#"        <original_language_title language="en">Book Title</original_language_title>"#
+        ));
+        assert!(output.contains(r#"      <jats:abstract abstract-type="long" xml:lang="fr">"#));
+        assert!(output.contains(r#"        <jats:p>Résumé</jats:p>"#));
+        assert!(!output.contains(r#"<jats:p>Lorem ipsum dolor sit amet</jats:p>"#));
+        // Chapters without recorded titles fall back to their main language
+        assert!(output.contains(r#"    <content_item component_type="chapter" language="es">"#));
+
+        // Abstracts containing markup are converted into JATS
+        test_work.abstracts[0].content =
+            "<p>Un <em>résumé</em> H<sub>2</sub>O</p><ul><li>Premier</li></ul>".to_string();
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"        <jats:p>Un <jats:italic>résumé</jats:italic> H<jats:sub>2</jats:sub>O</jats:p>"#
+        ));
+        assert!(output.contains(r#"        <jats:list list-type="bullet">"#));
+        assert!(output.contains(r#"            <jats:p>Premier</jats:p>"#));
+
+        // Record a conference: proceedings are deposited as a `conference`, chapters as papers
+        test_work.events = vec![WorkEvents {
+            event_name: "International Conference on Metadata".to_string(),
+            event_acronym: Some("ICM".to_string()),
+            event_number: Some("12".to_string()),
+            event_location: Some("Cambridge".to_string()),
+            event_country: Some(CountryCode::GBR),
+            event_start_date: chrono::NaiveDate::from_ymd_opt(1999, 6, 1),
+            event_end_date: chrono::NaiveDate::from_ymd_opt(1999, 6, 3),
+            sponsors: vec![WorkEventsSponsors {
+                sponsor_ordinal: 1,
+                institution: WorkEventsSponsorsInstitution {
+                    institution_name: "Metadata Society".to_string(),
+                    ror: None,
+                },
+            }],
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"  <conference>"#));
+        assert!(!output.contains(r#"<book "#));
+        assert!(!output.contains(r#"<content_item"#));
+        assert!(output.contains(r#"    <event_metadata>"#));
+        assert!(output.contains(
+            r#"      <conference_name>International Conference on Metadata</conference_name>"#
+        ));
+        assert!(output.contains(r#"      <conference_acronym>ICM</conference_acronym>"#));
+        assert!(
+            output.contains(r#"      <conference_sponsor>Metadata Society</conference_sponsor>"#)
+        );
+        assert!(output.contains(r#"      <conference_number>12</conference_number>"#));
+        assert!(output.contains(
+            r#"      <conference_location>Cambridge, United Kingdom</conference_location>"#
+        ));
+        assert!(output.contains(
+            r#"      <conference_date start_day="01" start_month="06" start_year="1999" end_day="03" end_month="06" end_year="1999" />"#
+        ));
+        assert!(output.contains(r#"    <proceedings_metadata language="fr">"#));
+        assert!(!output.contains(r#"<series_metadata>"#));
+        assert!(output
+            .contains(r#"      <proceedings_title>Book Title: Book Subtitle</proceedings_title>"#));
+        assert!(
+            output.contains(r#"    <conference_paper publication_type="full_text" language="es">"#)
+        );
+        assert!(output.contains(r#"        <doi>10.00001/PART.0001</doi>"#));
+
+        // Add a series with an ISSN: proceedings deposited with series metadata
+        test_work.issues = vec![WorkIssues {
+            issue_ordinal: 7,
+            volume: None,
+            issue_number: None,
+            part: None,
+            series: WorkIssuesSeries {
+                series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000003").unwrap(),
+                series_type: SeriesType::BOOK_SERIES,
+                series_name: "Conference Series".to_string(),
+                issn_print: None,
+                issn_digital: Some("8765-4321".to_string()),
+                series_url: None,
+                series_description: None,
+                series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
+            },
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"    <proceedings_series_metadata language="fr">"#));
+        assert!(output.contains(r#"      <series_metadata>"#));
+        assert!(output.contains(r#"          <title>Conference Series</title>"#));
+        assert!(output.contains(r#"      <volume>7</volume>"#));
+
+        // Remove event location, country and dates: elements omitted
+        test_work.events[0].event_location = None;
+        test_work.events[0].event_country = None;
+        test_work.events[0].event_start_date = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"<conference_location>"#));
+        assert!(!output.contains(r#"<conference_date"#));
+        test_work.issues.clear();
+        test_work.events.clear();
+
         // Remove publication date. Result: error
         test_work.publication_date = None;
         let output = generate_test_output(false, &test_work);
@@ -2135,6 +2924,196 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_doideposit_crossref_journal() {
+        let mut test_work = Work {
+            work_id: Uuid::from_str("00000000-0000-0000-AAAA-000000000001").unwrap(),
+            work_status: WorkStatus::ACTIVE,
+            full_title: "Special Issue: On Journals".to_string(),
+            title: "Special Issue".to_string(),
+            subtitle: Some("On Journals".to_string()),
+            work_type: WorkType::JOURNAL_ISSUE,
+            reference: None,
+            edition: Some(1),
+            doi: Some(Doi::from_str("https://doi.org/10.00001/ISSUE.0001").unwrap()),
+            publication_date: chrono::NaiveDate::from_ymd_opt(2024, 3, 1),
+            withdrawn_date: None,
+            license: None,
+            copyright_holder: None,
+            short_abstract: None,
+            long_abstract: None,
+            titles: vec![],
+            abstracts: vec![],
+            general_note: None,
+            bibliography_note: None,
+            place: None,
+            page_count: None,
+            page_breakdown: None,
+            first_page: None,
+            last_page: None,
+            page_interval: None,
+            article_number: None,
+            image_count: None,
+            table_count: None,
+            audio_count: None,
+            video_count: None,
+            landing_page: Some("https://www.journal.com/issue".to_string()),
+            toc: None,
+            lccn: None,
+            oclc: None,
+            cover_url: None,
+            cover_caption: None,
+            imprint: WorkImprint {
+                imprint_name: "OA Journals Imprint".to_string(),
+                imprint_url: None,
+                crossmark_doi: None,
+                publisher: WorkImprintPublisher {
+                    publisher_name: "OA Journals".to_string(),
+                    publisher_shortname: None,
+                    publisher_url: None,
+                },
+            },
+            issues: vec![WorkIssues {
+                issue_ordinal: 7,
+                volume: Some("12".to_string()),
+                issue_number: Some("3-4".to_string()),
+                part: None,
+                series: WorkIssuesSeries {
+                    series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
+                    series_type: SeriesType::JOURNAL,
+                    series_name: "Journal of Metadata".to_string(),
+                    issn_print: None,
+                    issn_digital: Some("8765-4321".to_string()),
+                    series_url: None,
+                    series_description: None,
+                    series_cfp_url: None,
+                    parent_series: None,
+                    contributions: vec![],
+                },
+            }],
+            contributions: vec![],
+            languages: vec![],
+            publications: vec![],
+            subjects: vec![],
+            fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
+            relations: vec![WorkRelations {
+                relation_type: RelationType::HAS_PART,
+                relation_ordinal: 1,
+                related_work: WorkRelationsRelatedWork {
+                    work_status: WorkStatus::ACTIVE,
+                    full_title: "Article Title".to_string(),
+                    title: "Article Title".to_string(),
+                    subtitle: None,
+                    edition: None,
+                    doi: Some(Doi::from_str("https://doi.org/10.00001/ARTICLE.0001").unwrap()),
+                    publication_date: None,
+                    withdrawn_date: None,
+                    license: Some("https://creativecommons.org/licenses/by/4.0/".to_string()),
+                    copyright_holder: None,
+                    short_abstract: None,
+                    long_abstract: None,
+                    titles: vec![],
+                    abstracts: vec![],
+                    general_note: None,
+                    place: None,
+                    first_page: Some("101".to_string()),
+                    last_page: Some("120".to_string()),
+                    page_count: Some(20),
+                    page_interval: Some("101–120".to_string()),
+                    article_number: Some("e1234".to_string()),
+                    landing_page: Some("https://www.journal.com/article".to_string()),
+                    imprint: WorkRelationsRelatedWorkImprint {
+                        crossmark_doi: None,
+                        publisher: WorkRelationsRelatedWorkImprintPublisher {
+                            publisher_name: "OA Journals".to_string(),
+                        },
+                    },
+                    contributions: vec![],
+                    publications: vec![],
+                    references: vec![],
+                    fundings: vec![],
+                    languages: vec![],
+                },
+            }],
+            references: vec![],
+            identifiers: vec![],
+        };
+
+        // Test standard output
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"  <journal>"#));
+        assert!(!output.contains(r#"<book "#));
+        assert!(output.contains(r#"    <journal_metadata language="en">"#));
+        assert!(output.contains(r#"      <full_title>Journal of Metadata</full_title>"#));
+        assert!(output.contains(r#"      <issn media_type="electronic">8765-4321</issn>"#));
+        assert!(output.contains(r#"    <journal_issue>"#));
+        assert!(output.contains(r#"        <title>Special Issue</title>"#));
+        assert!(output.contains(r#"        <year>2024</year>"#));
+        assert!(output.contains(r#"      <journal_volume>"#));
+        assert!(output.contains(r#"        <volume>12</volume>"#));
+        assert!(output.contains(r#"      <issue>3-4</issue>"#));
+        assert!(output.contains(r#"        <doi>10.00001/ISSUE.0001</doi>"#));
+        assert!(output.contains(r#"    <journal_article publication_type="full_text">"#));
+        assert!(output.contains(r#"        <title>Article Title</title>"#));
+        assert!(output.contains(r#"        <first_page>101</first_page>"#));
+        assert!(output.contains(r#"        <last_page>120</last_page>"#));
+        assert!(output.contains(r#"      <publisher_item>"#));
+        assert!(output.contains(
+            r#"        <item_number item_number_type="article_number">e1234</item_number>"#
+        ));
+        assert!(output.contains(
+            r#"        <ai:license_ref>https://creativecommons.org/licenses/by/4.0/</ai:license_ref>"#
+        ));
+        assert!(output.contains(r#"        <doi>10.00001/ARTICLE.0001</doi>"#));
+        assert!(output.contains(r#"        <resource>https://www.journal.com/article</resource>"#));
+
+        // Remove volume, issue number and article number: elements omitted
+        test_work.issues[0].volume = None;
+        test_work.issues[0].issue_number = None;
+        test_work.relations[0].related_work.article_number = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"<journal_volume>"#));
+        assert!(!output.contains(r#"<issue>"#));
+        assert!(!output.contains(r#"<publisher_item>"#));
+
+        // Remove article DOI: article omitted
+        test_work.relations[0].related_work.doi = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"<journal_article"#));
+
+        // Remove issue DOI too. Result: error
+        test_work.doi = None;
+        let output = generate_test_output(false, &test_work);
+        assert_eq!(
+            output,
+            "Could not generate doideposit::crossref: No issue or article DOIs to deposit"
+                .to_string()
+        );
+
+        // Restore DOI and remove the journal. Result: error
+        test_work.doi = Some(Doi::from_str("https://doi.org/10.00001/ISSUE.0001").unwrap());
+        test_work.issues[0].series.series_type = SeriesType::BOOK_SERIES;
+        let output = generate_test_output(false, &test_work);
+        assert_eq!(
+            output,
+            "Could not generate doideposit::crossref: Journal issues must be part of a journal"
+                .to_string()
+        );
+
+        // Journal articles cannot be deposited on their own. Result: error
+        test_work.work_type = WorkType::JOURNAL_ARTICLE;
+        let output = generate_test_output(false, &test_work);
+        assert_eq!(
+            output,
+            "Could not generate doideposit::crossref: Journal articles are deposited as part of their journal issue"
+                .to_string()
+        );
+    }
+
     #[test]
     // Test that no more than 6 ISBNs are ever output.
     // Remove/change this test once the CrossRef 6-ISBN limit is removed/increased -
@@ -2156,6 +3135,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -2164,6 +3145,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -2205,6 +3187,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
@@ -2223,6 +3226,27 @@ mod tests {
                     weight_oz: Some(5.3616),
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-CCCC-000000000003").unwrap(),
@@ -2241,6 +3265,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -2259,6 +3304,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-EEEE-000000000005").unwrap(),
@@ -2277,6 +3343,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-FFFF-000000000006").unwrap(),
@@ -2295,6 +3382,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-AAAB-000000000007").unwrap(),
@@ -2313,12 +3421,38 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // 7 ISBNs are present and one is HTML - confirm that it is omitted
@@ -1,6 +1,19 @@
 use crate::record::XML_DECLARATION;
 use std::io::Write;
-use thoth_client::Work;
+use std::str::FromStr;
+use thoth_api::markup::{Markup, MarkupNode};
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::{Doi, IdentifierWithDomain};
+use thoth_client::{
+    AccessibilityFeature, AccessibilityHazard, AccessibilityStandard, BindingStyle,
+    ContentAudience, CountryCode, CoverFinish, DigitalLayout, FundingInstitution, IdentifierType,
+    NaiveDate, NameType, PriceQualifier, PriceType, PrizeCode, PublicationType,
+    ResourceContentType, ResourceForm, ResourceMode, SalesRestrictionType, SalesRightsType,
+    SeriesContributionType, TechnicalProtection, TextContentType, TrimCategory, UsageStatus,
+    UsageType, UsageUnit, WcagLevel, WcagVersion, Work, WorkContributionsContributor, WorkIssues,
+    WorkPrizes, WorkPublications, WorkPublicationsPrices, WorkPublicationsSalesRights,
+    WorkPublicationsUsageConstraints, WorkSupportingResources, WorkTextContents,
+};
 use thoth_errors::{ThothError, ThothResult};
 use xml::writer::events::StartElementBuilder;
 use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
@@ -44,6 +57,1514 @@ fn write_full_element_block<W: Write, F: Fn(&mut EventWriter<W>) -> ThothResult<
     w.write(event).map_err(|e| e.into())
 }
 
+/// Write an element holding a rich text value, such as an ONIX `Text` or `BiographicalNote`.
+/// Values containing markup are output as XHTML, flagged with ONIX text format code 05.
+fn write_rich_text_element<W: Write>(
+    element: &str,
+    mut attr: Vec<(&str, &str)>,
+    content: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    match Markup::from_str(content) {
+        Ok(markup) if markup.is_formatted() => {
+            attr.push(("textformat", "05"));
+            write_full_element_block(element, Some(attr), w, |w| write_xhtml(markup.blocks(), w))
+        }
+        _ => write_full_element_block(element, Some(attr), w, |w| {
+            w.write(XmlEvent::Characters(content)).map_err(|e| e.into())
+        }),
+    }
+}
+
+fn write_xhtml<W: Write>(nodes: &[MarkupNode], w: &mut EventWriter<W>) -> ThothResult<()> {
+    for node in nodes {
+        match node {
+            MarkupNode::Text(text) => w.write(XmlEvent::Characters(text))?,
+            MarkupNode::Element(tag, children) => {
+                write_element_block(tag.xhtml_name(), w, |w| write_xhtml(children, w))?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Best available Funder Registry DOI of a funding institution: the one formed from its
+/// Crossref Funder ID, or else its recorded DOI
+fn funder_doi(institution: &FundingInstitution) -> Option<Doi> {
+    institution
+        .crossref_funder_id
+        .as_ref()
+        .and_then(|funder_id| Doi::from_str(&funder_id.with_domain()).ok())
+        .or_else(|| institution.institution_doi.clone())
+}
+
+/// Write an ONIX `PublisherIdentifier` composite for each of the funding institution's identifiers
+fn write_onix_funder_identifiers<W: Write>(
+    institution: &FundingInstitution,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 40 ROR, 32 FundRef DOI, 16 ISNI, 01 Proprietary
+    let identifiers = [
+        (
+            "40",
+            None,
+            institution.ror.as_ref().map(|id| id.to_string()),
+        ),
+        ("32", None, funder_doi(institution).map(|id| id.to_string())),
+        (
+            "16",
+            None,
+            institution.isni.as_ref().map(|id| id.to_string()),
+        ),
+        (
+            "01",
+            Some("Ringgold"),
+            institution.ringgold.as_ref().map(|id| id.to_string()),
+        ),
+    ];
+    for (id_type, id_type_name, value) in identifiers {
+        if let Some(value) = value {
+            write_element_block("PublisherIdentifier", w, |w| {
+                write_element_block("PublisherIDType", w, |w| {
+                    w.write(XmlEvent::Characters(id_type)).map_err(|e| e.into())
+                })?;
+                if let Some(id_type_name) = id_type_name {
+                    write_element_block("IDTypeName", w, |w| {
+                        w.write(XmlEvent::Characters(id_type_name))
+                            .map_err(|e| e.into())
+                    })?;
+                }
+                write_element_block("IDValue", w, |w| {
+                    w.write(XmlEvent::Characters(&value)).map_err(|e| e.into())
+                })
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Write an ONIX `NameIdentifier` composite for each of the contributor's authority identifiers
+fn write_onix_name_identifiers<W: Write>(
+    contributor: &WorkContributionsContributor,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 21 ORCID, 16 ISNI, 31 VIAF ID, 18 LCCN, 01 Proprietary
+    let identifiers = [
+        (
+            "21",
+            None,
+            contributor.orcid.as_ref().map(|id| id.to_string()),
+        ),
+        (
+            "16",
+            None,
+            contributor.isni.as_ref().map(|id| id.to_string()),
+        ),
+        (
+            "31",
+            None,
+            contributor.viaf.as_ref().map(|id| id.to_string()),
+        ),
+        (
+            "18",
+            None,
+            contributor.lcnaf.as_ref().map(|id| id.to_string()),
+        ),
+        (
+            "01",
+            Some("Wikidata"),
+            contributor.wikidata.as_ref().map(|id| id.to_string()),
+        ),
+    ];
+    for (id_type, id_type_name, id_value) in identifiers {
+        if let Some(id_value) = id_value {
+            write_element_block("NameIdentifier", w, |w| {
+                write_element_block("NameIDType", w, |w| {
+                    w.write(XmlEvent::Characters(id_type)).map_err(|e| e.into())
+                })?;
+                if let Some(id_type_name) = id_type_name {
+                    write_element_block("IDTypeName", w, |w| {
+                        w.write(XmlEvent::Characters(id_type_name))
+                            .map_err(|e| e.into())
+                    })?;
+                }
+                write_element_block("IDValue", w, |w| {
+                    w.write(XmlEvent::Characters(&id_value))
+                        .map_err(|e| e.into())
+                })
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn onix_name_type(name_type: &NameType) -> &'static str {
+    match name_type {
+        NameType::VARIANT => "00",
+        NameType::PSEUDONYM => "01",
+        NameType::EARLIER => "03",
+        NameType::REAL => "04",
+        NameType::TRANSLITERATED => "05",
+        NameType::LATER => "06",
+        NameType::Other(_) => unreachable!(),
+    }
+}
+
+/// Write an ONIX `AlternativeName` composite for each of the contributor's name variants
+fn write_onix_alternative_names<W: Write>(
+    contributor: &WorkContributionsContributor,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for alternative_name in &contributor.alternative_names {
+        let language = alternative_name
+            .language_code
+            .as_ref()
+            .map(|code| code.to_string().to_lowercase());
+        let mut attr = vec![];
+        if let Some(language) = language.as_deref() {
+            attr.push(("language", language));
+        }
+        if let Some(script_code) = alternative_name.script_code.as_deref() {
+            attr.push(("textscript", script_code));
+        }
+        write_element_block("AlternativeName", w, |w| {
+            write_element_block("NameType", w, |w| {
+                w.write(XmlEvent::Characters(onix_name_type(
+                    &alternative_name.name_type,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            write_full_element_block("PersonName", Some(attr.clone()), w, |w| {
+                w.write(XmlEvent::Characters(&alternative_name.full_name))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(first_name) = &alternative_name.first_name {
+                write_element_block("NamesBeforeKey", w, |w| {
+                    w.write(XmlEvent::Characters(first_name))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            write_element_block("KeyNames", w, |w| {
+                w.write(XmlEvent::Characters(&alternative_name.last_name))
+                    .map_err(|e| e.into())
+            })
+        })?;
+    }
+    Ok(())
+}
+
+fn onix_series_contribution_role(contribution_type: &SeriesContributionType) -> &'static str {
+    match contribution_type {
+        SeriesContributionType::SERIES_EDITOR => "B09",
+        SeriesContributionType::EDITOR_IN_CHIEF => "B11",
+        SeriesContributionType::MANAGING_EDITOR => "B16",
+        SeriesContributionType::ASSOCIATE_EDITOR => "B19",
+        SeriesContributionType::EDITORIAL_BOARD_MEMBER => "B14",
+        SeriesContributionType::Other(_) => unreachable!(),
+    }
+}
+
+/// Write the ONIX 3 `TitleDetail` of a collection. Subseries are given as a subcollection
+/// title beneath the title of their parent series, which carries no part number of its own.
+fn write_onix3_collection_title_detail<W: Write>(
+    issue: &WorkIssues,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("TitleDetail", w, |w| {
+        // 01 Cover title (serial)
+        write_element_block("TitleType", w, |w| {
+            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+        })?;
+        let series_level = match &issue.series.parent_series {
+            Some(parent_series) => {
+                write_element_block("TitleElement", w, |w| {
+                    // 02 Collection level
+                    write_element_block("TitleElementLevel", w, |w| {
+                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
+                    })?;
+                    write_element_block("TitleText", w, |w| {
+                        w.write(XmlEvent::Characters(&parent_series.series_name))
+                            .map_err(|e| e.into())
+                    })
+                })?;
+                // 03 Subcollection
+                "03"
+            }
+            // 02 Collection level
+            None => "02",
+        };
+        write_element_block("TitleElement", w, |w| {
+            write_element_block("TitleElementLevel", w, |w| {
+                w.write(XmlEvent::Characters(series_level))
+                    .map_err(|e| e.into())
+            })?;
+            write_element_block("PartNumber", w, |w| {
+                w.write(XmlEvent::Characters(&issue.numbering()))
+                    .map_err(|e| e.into())
+            })?;
+            write_element_block("TitleText", w, |w| {
+                w.write(XmlEvent::Characters(&issue.series.series_name))
+                    .map_err(|e| e.into())
+            })
+        })
+    })
+}
+
+/// Write an ONIX 3 `Contributor` composite for each of the series editors of a collection
+fn write_onix3_collection_contributors<W: Write>(
+    issue: &WorkIssues,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for contribution in &issue.series.contributions {
+        write_element_block("Contributor", w, |w| {
+            write_element_block("SequenceNumber", w, |w| {
+                w.write(XmlEvent::Characters(
+                    &contribution.contribution_ordinal.to_string(),
+                ))
+                .map_err(|e| e.into())
+            })?;
+            write_element_block("ContributorRole", w, |w| {
+                w.write(XmlEvent::Characters(onix_series_contribution_role(
+                    &contribution.contribution_type,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            if let Some(orcid) = &contribution.contributor.orcid {
+                write_element_block("NameIdentifier", w, |w| {
+                    // 21 ORCID
+                    write_element_block("NameIDType", w, |w| {
+                        w.write(XmlEvent::Characters("21")).map_err(|e| e.into())
+                    })?;
+                    write_element_block("IDValue", w, |w| {
+                        w.write(XmlEvent::Characters(&orcid.to_string()))
+                            .map_err(|e| e.into())
+                    })
+                })?;
+            }
+            write_element_block("PersonName", w, |w| {
+                w.write(XmlEvent::Characters(&contribution.full_name))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(first_name) = &contribution.first_name {
+                write_element_block("NamesBeforeKey", w, |w| {
+                    w.write(XmlEvent::Characters(first_name))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            write_element_block("KeyNames", w, |w| {
+                w.write(XmlEvent::Characters(&contribution.last_name))
+                    .map_err(|e| e.into())
+            })
+        })?;
+    }
+    Ok(())
+}
+
+/// Lowercase ISO 639-2/B code of a localised element, as used in `language` attributes
+fn language_attr(language_code: &Option<LanguageCode>) -> Option<String> {
+    language_code.map(|code| code.to_string().to_lowercase())
+}
+
+/// ONIX identifier type code (lists 5 and 16), and the name of the scheme if it is proprietary
+fn onix_identifier_type<'a>(
+    identifier_type: &'a IdentifierType,
+    identifier_value: &str,
+) -> (&'static str, Option<&'a str>) {
+    match identifier_type {
+        IdentifierType::ISTC => ("11", None),
+        IdentifierType::URN_NBN => ("22", None),
+        IdentifierType::GTIN => match identifier_value.len() {
+            // 04 UPC-A
+            12 => ("04", None),
+            // 03 GTIN-13
+            13 => ("03", None),
+            // 14 GTIN-14
+            14 => ("14", None),
+            _ => ("01", Some("GTIN-8")),
+        },
+        IdentifierType::HANDLE => ("01", Some("Handle")),
+        IdentifierType::ARK => ("01", Some("ARK")),
+        IdentifierType::WIKIDATA => ("01", Some("Wikidata")),
+        IdentifierType::SKU => ("01", Some("SKU")),
+        IdentifierType::Other(name) => ("01", Some(name)),
+    }
+}
+
+/// Write an ONIX `ProductIdentifier` or `WorkIdentifier` composite
+fn write_onix_identifier<W: Write>(
+    element: &str,
+    type_element: &str,
+    identifier_type: &IdentifierType,
+    identifier_value: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let (id_type, id_type_name) = onix_identifier_type(identifier_type, identifier_value);
+    write_element_block(element, w, |w| {
+        write_element_block(type_element, w, |w| {
+            w.write(XmlEvent::Characters(id_type)).map_err(|e| e.into())
+        })?;
+        if let Some(id_type_name) = id_type_name {
+            write_element_block("IDTypeName", w, |w| {
+                w.write(XmlEvent::Characters(id_type_name))
+                    .map_err(|e| e.into())
+            })?;
+        }
+        write_element_block("IDValue", w, |w| {
+            w.write(XmlEvent::Characters(identifier_value))
+                .map_err(|e| e.into())
+        })
+    })
+}
+
+fn onix_sales_rights_type(sales_rights_type: &SalesRightsType) -> &'static str {
+    match sales_rights_type {
+        SalesRightsType::FOR_SALE_WITH_EXCLUSIVE_RIGHTS => "01",
+        SalesRightsType::FOR_SALE_WITH_NON_EXCLUSIVE_RIGHTS => "02",
+        SalesRightsType::NOT_FOR_SALE => "03",
+        SalesRightsType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_sales_restriction_type(sales_restriction_type: &SalesRestrictionType) -> &'static str {
+    match sales_restriction_type {
+        SalesRestrictionType::UNSPECIFIED => "00",
+        SalesRestrictionType::INTERNAL_PUBLISHER_USE_ONLY => "03",
+        SalesRestrictionType::RETAILER_EXCLUSIVE => "04",
+        SalesRestrictionType::RETAILER_OWN_BRAND => "05",
+        SalesRestrictionType::Other(_) => unreachable!(),
+    }
+}
+
+/// Write a `SalesRestriction` composite for sales rights which carry a restriction. The note
+/// is written as `SalesRestrictionNote` in ONIX 3 and `SalesRestrictionDetail` in ONIX 2.1.
+fn write_onix_sales_restriction<W: Write>(
+    rights: &WorkPublicationsSalesRights,
+    note_element: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(restriction_type) = &rights.sales_restriction_type {
+        write_element_block("SalesRestriction", w, |w| {
+            write_element_block("SalesRestrictionType", w, |w| {
+                w.write(XmlEvent::Characters(onix_sales_restriction_type(
+                    restriction_type,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            if let Some(note) = &rights.sales_restriction_note {
+                write_element_block(note_element, w, |w| {
+                    w.write(XmlEvent::Characters(note)).map_err(|e| e.into())
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+/// Space-separated list of two-letter country codes, as used in ONIX territories
+fn onix_countries(countries: &[CountryCode]) -> String {
+    countries
+        .iter()
+        .filter_map(|country| country.alpha_2())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Write an ONIX 3 `SalesRights` composite for each of the publication's sales rights,
+/// assuming non-exclusive world rights where none are recorded
+fn write_onix3_sales_rights<W: Write>(
+    sales_rights: &[WorkPublicationsSalesRights],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if sales_rights.is_empty() {
+        return write_element_block("SalesRights", w, |w| {
+            // 02 For sale with non-exclusive rights in the specified countries or territories
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
+            })?;
+            write_element_block("Territory", w, |w| {
+                write_element_block("RegionsIncluded", w, |w| {
+                    w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
+                })
+            })
+        });
+    }
+    for rights in sales_rights {
+        write_element_block("SalesRights", w, |w| {
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters(onix_sales_rights_type(
+                    &rights.sales_rights_type,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            write_onix3_territory(
+                &rights.countries_included,
+                &rights.regions_included,
+                &rights.countries_excluded,
+                &rights.regions_excluded,
+                w,
+            )?;
+            write_onix_sales_restriction(rights, "SalesRestrictionNote", w)
+        })?;
+    }
+    Ok(())
+}
+
+/// Write an ONIX 3 `Territory` composite, falling back to `WORLD` when no countries or
+/// regions are included
+fn write_onix3_territory<W: Write>(
+    countries_included: &[CountryCode],
+    regions_included: &[String],
+    countries_excluded: &[CountryCode],
+    regions_excluded: &[String],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("Territory", w, |w| {
+        if countries_included.is_empty() && regions_included.is_empty() {
+            return write_element_block("RegionsIncluded", w, |w| {
+                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
+            });
+        }
+        for (element, codes) in [
+            ("CountriesIncluded", onix_countries(countries_included)),
+            ("RegionsIncluded", regions_included.join(" ")),
+            ("CountriesExcluded", onix_countries(countries_excluded)),
+            ("RegionsExcluded", regions_excluded.join(" ")),
+        ] {
+            if !codes.is_empty() {
+                write_element_block(element, w, |w| {
+                    w.write(XmlEvent::Characters(&codes)).map_err(|e| e.into())
+                })?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Write an ONIX 2.1 `SalesRights` composite for each of the publication's sales rights,
+/// assuming non-exclusive world rights where none are recorded.
+///
+/// ONIX 2.1 cannot exclude parts of a territory, so exclusions from a territory which is
+/// for sale are written as a separate composite of type 03 (not for sale). Sales
+/// restrictions are not tied to a territory in ONIX 2.1, so they follow the rights.
+fn write_onix21_sales_rights<W: Write>(
+    sales_rights: &[WorkPublicationsSalesRights],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let mut composites: Vec<(&str, String, String)> = Vec::new();
+    for rights in sales_rights {
+        let rights_type = onix_sales_rights_type(&rights.sales_rights_type);
+        composites.push((
+            rights_type,
+            onix_countries(&rights.countries_included),
+            rights.regions_included.join(" "),
+        ));
+        if rights_type != "03" {
+            composites.push((
+                "03",
+                onix_countries(&rights.countries_excluded),
+                rights.regions_excluded.join(" "),
+            ));
+        }
+    }
+    if composites.is_empty() {
+        // 02 For sale with non-exclusive rights in the specified countries or territories
+        composites.push(("02", String::new(), "WORLD".to_string()));
+    }
+    for (rights_type, countries, territories) in composites
+        .iter()
+        .filter(|(_, countries, territories)| !(countries.is_empty() && territories.is_empty()))
+    {
+        write_element_block("SalesRights", w, |w| {
+            write_element_block("SalesRightsType", w, |w| {
+                w.write(XmlEvent::Characters(rights_type))
+                    .map_err(|e| e.into())
+            })?;
+            if !countries.is_empty() {
+                write_element_block("RightsCountry", w, |w| {
+                    w.write(XmlEvent::Characters(countries))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if !territories.is_empty() {
+                write_element_block("RightsTerritory", w, |w| {
+                    w.write(XmlEvent::Characters(territories))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    for rights in sales_rights {
+        write_onix_sales_restriction(rights, "SalesRestrictionDetail", w)?;
+    }
+    Ok(())
+}
+
+fn onix_price_type(price_type: &PriceType) -> &'static str {
+    match price_type {
+        PriceType::RRP_EXCLUDING_TAX => "01",
+        PriceType::RRP_INCLUDING_TAX => "02",
+        PriceType::FIXED_RETAIL_PRICE_EXCLUDING_TAX => "03",
+        PriceType::FIXED_RETAIL_PRICE_INCLUDING_TAX => "04",
+        PriceType::SUPPLIERS_NET_PRICE_EXCLUDING_TAX => "05",
+        // Publisher's retail price, for products supplied on agency terms
+        PriceType::AGENCY_PRICE_EXCLUDING_TAX => "41",
+        PriceType::AGENCY_PRICE_INCLUDING_TAX => "42",
+        PriceType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_price_qualifier(price_qualifier: &PriceQualifier) -> &'static str {
+    match price_qualifier {
+        PriceQualifier::CONSUMER_PRICE => "05",
+        PriceQualifier::CORPORATE_PRICE => "06",
+        PriceQualifier::LIBRARY_PRICE => "10",
+        PriceQualifier::EDUCATION_PRICE => "11",
+        PriceQualifier::Other(_) => unreachable!(),
+    }
+}
+
+/// Write an ONIX 3 `Price` composite. Discount codes are the publisher's own, so they are
+/// written as proprietary codes under the publisher's name.
+fn write_onix3_price<W: Write>(
+    price: &WorkPublicationsPrices,
+    publisher_name: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let unit_price = price.unit_price;
+    let formatted_price = format!("{unit_price:.2}");
+    write_element_block("Price", w, |w| {
+        write_element_block("PriceType", w, |w| {
+            w.write(XmlEvent::Characters(onix_price_type(&price.price_type)))
+                .map_err(|e| e.into())
+        })?;
+        if let Some(qualifier) = &price.price_qualifier {
+            write_element_block("PriceQualifier", w, |w| {
+                w.write(XmlEvent::Characters(onix_price_qualifier(qualifier)))
+                    .map_err(|e| e.into())
+            })?;
+        }
+        if let Some(discount_code) = &price.discount_code {
+            write_element_block("DiscountCoded", w, |w| {
+                // 02 Proprietary discount code
+                write_element_block("DiscountCodeType", w, |w| {
+                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
+                })?;
+                write_element_block("DiscountCodeTypeName", w, |w| {
+                    w.write(XmlEvent::Characters(publisher_name))
+                        .map_err(|e| e.into())
+                })?;
+                write_element_block("DiscountCode", w, |w| {
+                    w.write(XmlEvent::Characters(discount_code))
+                        .map_err(|e| e.into())
+                })
+            })?;
+        }
+        write_element_block("PriceAmount", w, |w| {
+            w.write(XmlEvent::Characters(&formatted_price))
+                .map_err(|e| e.into())
+        })?;
+        if let Some(tax_rate_percent) = price.tax_rate_percent {
+            write_element_block("Tax", w, |w| {
+                // 01 VAT (Value-added tax)
+                write_element_block("TaxType", w, |w| {
+                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+                })?;
+                write_element_block("TaxRatePercent", w, |w| {
+                    w.write(XmlEvent::Characters(&tax_rate_percent.to_string()))
+                        .map_err(|e| e.into())
+                })
+            })?;
+        }
+        write_element_block("CurrencyCode", w, |w| {
+            w.write(XmlEvent::Characters(&price.currency_code.to_string()))
+                .map_err(|e| e.into())
+        })?;
+        write_onix3_territory(
+            &price.countries_included,
+            &price.regions_included,
+            &price.countries_excluded,
+            &price.regions_excluded,
+            w,
+        )?;
+        for (role, date) in [
+            // 14 From date
+            ("14", price.price_effective_from),
+            // 15 Until date
+            ("15", price.price_effective_until),
+        ] {
+            if let Some(date) = date {
+                write_element_block("PriceDate", w, |w| {
+                    write_element_block("PriceDateRole", w, |w| {
+                        w.write(XmlEvent::Characters(role)).map_err(|e| e.into())
+                    })?;
+                    // dateformat="00" YYYYMMDD
+                    write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                        w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                            .map_err(|e| e.into())
+                    })
+                })?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Write an ONIX 2.1 `Price` composite, using the given price type code where the recipient
+/// requires a particular one
+fn write_onix21_price<W: Write>(
+    price: &WorkPublicationsPrices,
+    price_type_code: Option<&str>,
+    publisher_name: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let unit_price = price.unit_price;
+    let formatted_price = format!("{unit_price:.2}");
+    write_element_block("Price", w, |w| {
+        write_element_block("PriceTypeCode", w, |w| {
+            w.write(XmlEvent::Characters(
+                price_type_code.unwrap_or(onix_price_type(&price.price_type)),
+            ))
+            .map_err(|e| e.into())
+        })?;
+        if let Some(qualifier) = &price.price_qualifier {
+            write_element_block("PriceQualifier", w, |w| {
+                w.write(XmlEvent::Characters(onix_price_qualifier(qualifier)))
+                    .map_err(|e| e.into())
+            })?;
+        }
+        if let Some(discount_code) = &price.discount_code {
+            write_element_block("DiscountCoded", w, |w| {
+                // 02 Proprietary discount code
+                write_element_block("DiscountCodeType", w, |w| {
+                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
+                })?;
+                write_element_block("DiscountCodeTypeName", w, |w| {
+                    w.write(XmlEvent::Characters(publisher_name))
+                        .map_err(|e| e.into())
+                })?;
+                write_element_block("DiscountCode", w, |w| {
+                    w.write(XmlEvent::Characters(discount_code))
+                        .map_err(|e| e.into())
+                })
+            })?;
+        }
+        write_element_block("PriceAmount", w, |w| {
+            w.write(XmlEvent::Characters(&formatted_price))
+                .map_err(|e| e.into())
+        })?;
+        write_element_block("CurrencyCode", w, |w| {
+            w.write(XmlEvent::Characters(&price.currency_code.to_string()))
+                .map_err(|e| e.into())
+        })?;
+        for country in price.countries_included.iter().filter_map(|c| c.alpha_2()) {
+            write_element_block("CountryCode", w, |w| {
+                w.write(XmlEvent::Characters(country)).map_err(|e| e.into())
+            })?;
+        }
+        for (element, codes) in [
+            ("Territory", price.regions_included.join(" ")),
+            ("CountryExcluded", onix_countries(&price.countries_excluded)),
+            ("TerritoryExcluded", price.regions_excluded.join(" ")),
+        ] {
+            if !codes.is_empty() {
+                write_element_block(element, w, |w| {
+                    w.write(XmlEvent::Characters(&codes)).map_err(|e| e.into())
+                })?;
+            }
+        }
+        if let Some(tax_rate_percent) = price.tax_rate_percent {
+            write_element_block("TaxRatePercent1", w, |w| {
+                w.write(XmlEvent::Characters(&tax_rate_percent.to_string()))
+                    .map_err(|e| e.into())
+            })?;
+        }
+        for (element, date) in [
+            ("PriceEffectiveFrom", price.price_effective_from),
+            ("PriceEffectiveUntil", price.price_effective_until),
+        ] {
+            if let Some(date) = date {
+                write_element_block(element, w, |w| {
+                    w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                        .map_err(|e| e.into())
+                })?;
+            }
+        }
+        Ok(())
+    })
+}
+
+fn onix_accessibility_feature(feature: &AccessibilityFeature) -> &'static str {
+    match feature {
+        AccessibilityFeature::TABLE_OF_CONTENTS => "11",
+        AccessibilityFeature::INDEX_NAVIGATION => "12",
+        AccessibilityFeature::READING_ORDER => "13",
+        AccessibilityFeature::SHORT_ALTERNATIVE_TEXT => "14",
+        AccessibilityFeature::FULL_ALTERNATIVE_TEXT => "15",
+        AccessibilityFeature::MATH_ML => "17",
+        AccessibilityFeature::CHEM_ML => "18",
+        AccessibilityFeature::PRINT_PAGE_NUMBERS => "19",
+        AccessibilityFeature::SYNCHRONISED_AUDIO => "20",
+        AccessibilityFeature::TEXT_TO_SPEECH_HINTING => "21",
+        AccessibilityFeature::HIGH_CONTRAST_DISPLAY => "26",
+        AccessibilityFeature::HIGH_CONTRAST_AUDIO => "27",
+        AccessibilityFeature::STRUCTURAL_NAVIGATION => "29",
+        AccessibilityFeature::ARIA_ROLES => "30",
+        AccessibilityFeature::DISPLAY_TRANSFORMABILITY => "36",
+        AccessibilityFeature::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_accessibility_hazard(hazard: &AccessibilityHazard) -> &'static str {
+    match hazard {
+        AccessibilityHazard::NONE => "00",
+        AccessibilityHazard::FLASHING => "13",
+        AccessibilityHazard::NO_FLASHING_HAZARD => "14",
+        AccessibilityHazard::SOUND => "15",
+        AccessibilityHazard::NO_SOUND_HAZARD => "16",
+        AccessibilityHazard::MOTION_SIMULATION => "17",
+        AccessibilityHazard::NO_MOTION_SIMULATION_HAZARD => "18",
+        AccessibilityHazard::UNKNOWN_FLASHING_HAZARD => "19",
+        AccessibilityHazard::UNKNOWN_SOUND_HAZARD => "20",
+        AccessibilityHazard::UNKNOWN_MOTION_SIMULATION_HAZARD => "21",
+        AccessibilityHazard::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_accessibility_standard(standard: &AccessibilityStandard) -> &'static str {
+    match standard {
+        AccessibilityStandard::EPUB_1_0_A => "02",
+        AccessibilityStandard::EPUB_1_0_AA => "03",
+        AccessibilityStandard::EPUB_1_1 => "04",
+        AccessibilityStandard::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_wcag_version(version: &WcagVersion) -> &'static str {
+    match version {
+        WcagVersion::WCAG_2_0 => "80",
+        WcagVersion::WCAG_2_1 => "81",
+        WcagVersion::WCAG_2_2 => "82",
+        WcagVersion::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_wcag_level(level: &WcagLevel) -> &'static str {
+    match level {
+        WcagLevel::A => "84",
+        WcagLevel::AA => "85",
+        WcagLevel::AAA => "86",
+        WcagLevel::Other(_) => unreachable!(),
+    }
+}
+
+fn write_onix3_product_form_feature<W: Write>(
+    feature_type: &str,
+    feature_value: &str,
+    description: Option<&str>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ProductFormFeature", w, |w| {
+        write_element_block("ProductFormFeatureType", w, |w| {
+            w.write(XmlEvent::Characters(feature_type))
+                .map_err(|e| e.into())
+        })?;
+        write_element_block("ProductFormFeatureValue", w, |w| {
+            w.write(XmlEvent::Characters(feature_value))
+                .map_err(|e| e.into())
+        })?;
+        if let Some(description) = description {
+            write_element_block("ProductFormFeatureDescription", w, |w| {
+                w.write(XmlEvent::Characters(description))
+                    .map_err(|e| e.into())
+            })?;
+        }
+        Ok(())
+    })
+}
+
+/// Write the accessibility details of a digital publication as ONIX 3 `ProductFormFeature`
+/// composites: e-publication accessibility details (type 09) and hazard warnings (type 12)
+fn write_onix3_accessibility<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    // 09 E-publication accessibility detail
+    if let Some(summary) = &publication.accessibility_summary {
+        // 00 Accessibility summary
+        write_onix3_product_form_feature("09", "00", Some(summary), w)?;
+    }
+    if let Some(standard) = &publication.accessibility_standard {
+        write_onix3_product_form_feature("09", onix_accessibility_standard(standard), None, w)?;
+    }
+    if let Some(version) = &publication.wcag_version {
+        write_onix3_product_form_feature("09", onix_wcag_version(version), None, w)?;
+    }
+    if let Some(level) = &publication.wcag_level {
+        write_onix3_product_form_feature("09", onix_wcag_level(level), None, w)?;
+    }
+    for feature in &publication.accessibility_features {
+        write_onix3_product_form_feature("09", onix_accessibility_feature(feature), None, w)?;
+    }
+    if let Some(certifier) = &publication.accessibility_certifier {
+        // 90 Compliance certification by (name)
+        write_onix3_product_form_feature("09", "90", Some(certifier), w)?;
+    }
+    // 12 Hazard warning
+    for hazard in &publication.accessibility_hazards {
+        write_onix3_product_form_feature("12", onix_accessibility_hazard(hazard), None, w)?;
+    }
+    Ok(())
+}
+
+fn onix_binding_style(binding_style: &BindingStyle) -> &'static str {
+    match binding_style {
+        BindingStyle::PERFECT_BOUND => "B305",
+        BindingStyle::SECTION_SEWN => "B304",
+        BindingStyle::SADDLE_STITCHED => "B310",
+        BindingStyle::LIBRARY_BOUND => "B306",
+        BindingStyle::COMB_BOUND => "B311",
+        BindingStyle::WIRE_O_BOUND => "B312",
+        BindingStyle::SPIRAL_BOUND => "B314",
+        BindingStyle::LAY_FLAT => "B318",
+        BindingStyle::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_trim_category(trim_category: &TrimCategory) -> Option<&'static str> {
+    match trim_category {
+        TrimCategory::MASS_MARKET => Some("B101"),
+        TrimCategory::US_TRADE => Some("B102"),
+        TrimCategory::DIGEST => Some("B103"),
+        TrimCategory::A_FORMAT => Some("B104"),
+        TrimCategory::B_FORMAT => Some("B105"),
+        TrimCategory::UK_TRADE => Some("B106"),
+        TrimCategory::TALL_RACK => Some("B107"),
+        // Conveyed by the Measure composites alone
+        TrimCategory::CUSTOM => None,
+        TrimCategory::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_cover_finish(cover_finish: &CoverFinish) -> Option<&'static str> {
+    match cover_finish {
+        CoverFinish::CLOTH => Some("B401"),
+        // No codelist entries for laminated or uncoated covers
+        CoverFinish::GLOSS_LAMINATE
+        | CoverFinish::MATTE_LAMINATE
+        | CoverFinish::SOFT_TOUCH_LAMINATE
+        | CoverFinish::UNCOATED => None,
+        CoverFinish::Other(_) => unreachable!(),
+    }
+}
+
+// Binding, trim and cover ProductFormDetail codes (ONIX codelist 175) of a physical publication
+fn write_onix3_print_form_details<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let codes = [
+        publication.binding_style.as_ref().map(onix_binding_style),
+        publication
+            .trim_category
+            .as_ref()
+            .and_then(onix_trim_category),
+        publication
+            .cover_finish
+            .as_ref()
+            .and_then(onix_cover_finish),
+    ];
+    for code in codes.into_iter().flatten() {
+        write_element_block("ProductFormDetail", w, |w| {
+            w.write(XmlEvent::Characters(code)).map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
+fn write_onix3_pack_quantity<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(carton_quantity) = publication.carton_quantity {
+        write_element_block("PackQuantity", w, |w| {
+            w.write(XmlEvent::Characters(&carton_quantity.to_string()))
+                .map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
+fn onix_digital_layout(layout: &DigitalLayout) -> &'static str {
+    match layout {
+        DigitalLayout::REFLOWABLE => "E200",
+        DigitalLayout::FIXED_FORMAT => "E201",
+        DigitalLayout::Other(_) => unreachable!(),
+    }
+}
+
+// Reflowable or fixed-format ProductFormDetail code (ONIX codelist 175) of a digital publication
+fn write_onix3_digital_form_details<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(layout) = &publication.layout {
+        write_element_block("ProductFormDetail", w, |w| {
+            w.write(XmlEvent::Characters(onix_digital_layout(layout)))
+                .map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
+fn digital_format_name(publication_type: &PublicationType) -> Option<&'static str> {
+    match publication_type {
+        PublicationType::PDF => Some("PDF"),
+        PublicationType::EPUB => Some("EPUB"),
+        PublicationType::HTML => Some("HTML"),
+        PublicationType::XML => Some("XML"),
+        PublicationType::MOBI => Some("Mobipocket"),
+        PublicationType::AZW3 => Some("AZW3"),
+        PublicationType::DOCX => Some("DOCX"),
+        PublicationType::FICTION_BOOK => Some("FictionBook"),
+        PublicationType::MP3 => Some("MP3"),
+        PublicationType::WAV => Some("WAV"),
+        PublicationType::PAPERBACK | PublicationType::HARDBACK => None,
+        PublicationType::Other(_) => unreachable!(),
+    }
+}
+
+fn format_file_size(file_size_kb: i64) -> String {
+    if file_size_kb >= 1024 {
+        format!("{:.1} MB", file_size_kb as f64 / 1024.0)
+    } else {
+        format!("{file_size_kb} KB")
+    }
+}
+
+/// Describe the format, layout and file size of a digital publication in a single line,
+/// e.g. "EPUB 3.0, reflowable, 2.4 MB"
+pub(crate) fn digital_format_description(publication: &WorkPublications) -> Option<String> {
+    if publication.format_version.is_none()
+        && publication.layout.is_none()
+        && publication.file_size_kb.is_none()
+    {
+        return None;
+    }
+    let format_name = digital_format_name(&publication.publication_type)?;
+    let mut parts = vec![match &publication.format_version {
+        Some(version) => format!("{format_name} {version}"),
+        None => format_name.to_string(),
+    }];
+    match &publication.layout {
+        Some(DigitalLayout::REFLOWABLE) => parts.push("reflowable".to_string()),
+        Some(DigitalLayout::FIXED_FORMAT) => parts.push("fixed format".to_string()),
+        Some(DigitalLayout::Other(_)) => unreachable!(),
+        None => {}
+    }
+    if let Some(file_size_kb) = publication.file_size_kb {
+        parts.push(format_file_size(file_size_kb));
+    }
+    Some(parts.join(", "))
+}
+
+fn write_onix3_product_form_description<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(description) = digital_format_description(publication) {
+        write_element_block("ProductFormDescription", w, |w| {
+            w.write(XmlEvent::Characters(&description))
+                .map_err(|e| e.into())
+        })?;
+    }
+    Ok(())
+}
+
+fn write_onix3_file_size<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(file_size_kb) = publication.file_size_kb {
+        write_element_block("Extent", w, |w| {
+            // 22 Filesize
+            write_element_block("ExtentType", w, |w| {
+                w.write(XmlEvent::Characters("22")).map_err(|e| e.into())
+            })?;
+            write_element_block("ExtentValue", w, |w| {
+                w.write(XmlEvent::Characters(&file_size_kb.to_string()))
+                    .map_err(|e| e.into())
+            })?;
+            // 18 Kbytes
+            write_element_block("ExtentUnit", w, |w| {
+                w.write(XmlEvent::Characters("18")).map_err(|e| e.into())
+            })
+        })?;
+    }
+    Ok(())
+}
+
+fn onix_technical_protection(protection: &TechnicalProtection) -> &'static str {
+    match protection {
+        TechnicalProtection::NONE => "00",
+        TechnicalProtection::DRM => "01",
+        TechnicalProtection::DIGITAL_WATERMARKING => "02",
+        TechnicalProtection::ADOBE_DRM => "03",
+        TechnicalProtection::APPLE_DRM => "04",
+        TechnicalProtection::OMA_DRM => "05",
+        TechnicalProtection::READIUM_LCP => "06",
+        TechnicalProtection::SONY_DRM => "07",
+        TechnicalProtection::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_usage_type(usage_type: &UsageType) -> &'static str {
+    match usage_type {
+        UsageType::PREVIEW => "01",
+        UsageType::PRINT => "02",
+        UsageType::COPY_PASTE => "03",
+        UsageType::SHARE => "04",
+        UsageType::TEXT_TO_SPEECH => "05",
+        UsageType::LEND => "06",
+        UsageType::TIME_LIMITED_LICENCE => "07",
+        UsageType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_usage_status(usage_status: &UsageStatus) -> &'static str {
+    match usage_status {
+        UsageStatus::PERMITTED_UNLIMITED => "01",
+        UsageStatus::PERMITTED_SUBJECT_TO_LIMIT => "02",
+        UsageStatus::PROHIBITED => "03",
+        UsageStatus::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_usage_unit(usage_unit: &UsageUnit) -> &'static str {
+    match usage_unit {
+        UsageUnit::COPIES => "01",
+        UsageUnit::CHARACTERS => "02",
+        UsageUnit::WORDS => "03",
+        UsageUnit::PAGES => "04",
+        UsageUnit::PERCENTAGE => "05",
+        UsageUnit::DEVICES => "06",
+        UsageUnit::CONCURRENT_USERS => "07",
+        UsageUnit::DAYS => "09",
+        UsageUnit::WEEKS => "10",
+        UsageUnit::MONTHS => "11",
+        UsageUnit::Other(_) => unreachable!(),
+    }
+}
+
+fn write_onix3_usage_constraint<W: Write>(
+    usage_constraint: &WorkPublicationsUsageConstraints,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("EpubUsageConstraint", w, |w| {
+        write_element_block("EpubUsageType", w, |w| {
+            w.write(XmlEvent::Characters(onix_usage_type(
+                &usage_constraint.usage_type,
+            )))
+            .map_err(|e| e.into())
+        })?;
+        write_element_block("EpubUsageStatus", w, |w| {
+            w.write(XmlEvent::Characters(onix_usage_status(
+                &usage_constraint.usage_status,
+            )))
+            .map_err(|e| e.into())
+        })?;
+        if let (Some(limit), Some(unit)) =
+            (usage_constraint.usage_limit, &usage_constraint.usage_unit)
+        {
+            write_element_block("EpubUsageLimit", w, |w| {
+                write_element_block("Quantity", w, |w| {
+                    w.write(XmlEvent::Characters(&limit.to_string()))
+                        .map_err(|e| e.into())
+                })?;
+                write_element_block("EpubUsageUnit", w, |w| {
+                    w.write(XmlEvent::Characters(onix_usage_unit(unit)))
+                        .map_err(|e| e.into())
+                })
+            })?;
+        }
+        Ok(())
+    })
+}
+
+/// Write the technical protection (ONIX codelist 144) and usage constraints (codelists 145-147)
+/// of a digital publication, which precede any `EpubLicense` in `DescriptiveDetail`
+fn write_onix3_epub_usage<W: Write>(
+    publication: &WorkPublications,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for protection in &publication.technical_protections {
+        write_element_block("EpubTechnicalProtection", w, |w| {
+            w.write(XmlEvent::Characters(onix_technical_protection(protection)))
+                .map_err(|e| e.into())
+        })?;
+    }
+    for usage_constraint in &publication.usage_constraints {
+        write_onix3_usage_constraint(usage_constraint, w)?;
+    }
+    Ok(())
+}
+
+fn onix_text_type(text_type: &TextContentType) -> &'static str {
+    match text_type {
+        TextContentType::DESCRIPTION => "03",
+        TextContentType::PRIMARY_COVER_COPY => "05",
+        TextContentType::REVIEW_QUOTE => "06",
+        TextContentType::PREVIOUS_EDITION_REVIEW_QUOTE => "07",
+        TextContentType::PREVIOUS_WORK_REVIEW_QUOTE => "08",
+        TextContentType::ENDORSEMENT => "09",
+        TextContentType::PROMOTIONAL_HEADLINE => "10",
+        TextContentType::FEATURE => "11",
+        TextContentType::BIOGRAPHICAL_NOTE => "12",
+        TextContentType::PUBLISHERS_NOTICE => "13",
+        TextContentType::EXCERPT => "14",
+        TextContentType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_content_audience(content_audience: &ContentAudience) -> &'static str {
+    match content_audience {
+        ContentAudience::UNRESTRICTED => "00",
+        ContentAudience::RESTRICTED => "01",
+        ContentAudience::BOOKTRADE => "02",
+        ContentAudience::END_CUSTOMERS => "03",
+        ContentAudience::LIBRARIANS => "04",
+        ContentAudience::TEACHERS => "05",
+        ContentAudience::STUDENTS => "06",
+        ContentAudience::PRESS => "07",
+        ContentAudience::SHOPPING_COMPARISON_SERVICES => "08",
+        ContentAudience::SEARCH_ENGINE_INDEX => "09",
+        ContentAudience::Other(_) => unreachable!(),
+    }
+}
+
+fn write_onix3_content_date<W: Write>(
+    content_date: &Option<NaiveDate>,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    if let Some(date) = content_date {
+        write_element_block("ContentDate", w, |w| {
+            // 01 Publication date
+            write_element_block("ContentDateRole", w, |w| {
+                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+            })?;
+            // dateformat="00" YYYYMMDD
+            write_full_element_block("Date", Some(vec![("dateformat", "00")]), w, |w| {
+                w.write(XmlEvent::Characters(&date.format("%Y%m%d").to_string()))
+                    .map_err(|e| e.into())
+            })
+        })?;
+    }
+    Ok(())
+}
+
+/// Write an ONIX 3 `TextContent` composite for each review quote, endorsement or other text
+/// recorded against the work, following its abstracts, table of contents and notes
+fn write_onix3_text_contents<W: Write>(
+    text_contents: &[WorkTextContents],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for text_content in text_contents {
+        write_element_block("TextContent", w, |w| {
+            write_element_block("TextType", w, |w| {
+                w.write(XmlEvent::Characters(onix_text_type(
+                    &text_content.text_type,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            write_element_block("ContentAudience", w, |w| {
+                w.write(XmlEvent::Characters(onix_content_audience(
+                    &text_content.content_audience,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            write_rich_text_element("Text", vec![], &text_content.content, w)?;
+            if let Some(text_author) = &text_content.text_author {
+                write_element_block("TextAuthor", w, |w| {
+                    w.write(XmlEvent::Characters(text_author))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if let Some(source_title) = &text_content.source_title {
+                write_element_block("SourceTitle", w, |w| {
+                    w.write(XmlEvent::Characters(source_title))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            write_onix3_content_date(&text_content.content_date, w)
+        })?;
+    }
+    Ok(())
+}
+
+/// Write an ONIX 3 `CitedContent` composite for each review quote which links to its source,
+/// so that recipients can point readers to the full review
+fn write_onix3_cited_contents<W: Write>(
+    text_contents: &[WorkTextContents],
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    for text_content in text_contents.iter().filter(|t| {
+        matches!(
+            t.text_type,
+            TextContentType::REVIEW_QUOTE
+                | TextContentType::PREVIOUS_EDITION_REVIEW_QUOTE
+                | TextContentType::PREVIOUS_WORK_REVIEW_QUOTE
+        )
+    }) {
+        if let Some(source_url) = &text_content.source_url {
+            write_element_block("CitedContent", w, |w| {
+                // 01 Review
+                write_element_block("CitedContentType", w, |w| {
+                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+                })?;
+                write_element_block("ContentAudience", w, |w| {
+                    w.write(XmlEvent::Characters(onix_content_audience(
+                        &text_content.content_audience,
+                    )))
+                    .map_err(|e| e.into())
+                })?;
+                if let Some(source_title) = &text_content.source_title {
+                    write_element_block("SourceTitle", w, |w| {
+                        w.write(XmlEvent::Characters(source_title))
+                            .map_err(|e| e.into())
+                    })?;
+                }
+                write_element_block("ResourceLink", w, |w| {
+                    w.write(XmlEvent::Characters(source_url))
+                        .map_err(|e| e.into())
+                })?;
+                write_onix3_content_date(&text_content.content_date, w)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn onix_prize_code(prize_code: &PrizeCode) -> &'static str {
+    match prize_code {
+        PrizeCode::WINNER => "01",
+        PrizeCode::RUNNER_UP => "02",
+        PrizeCode::COMMENDED => "03",
+        PrizeCode::SHORT_LISTED => "04",
+        PrizeCode::LONG_LISTED => "05",
+        PrizeCode::JOINT_WINNER => "06",
+        PrizeCode::NOMINATED => "07",
+        PrizeCode::Other(_) => unreachable!(),
+    }
+}
+
+/// Write an ONIX 3 `Prize` composite for each prize or award achieved by the work,
+/// which close the `CollateralDetail` block
+fn write_onix3_prizes<W: Write>(prizes: &[WorkPrizes], w: &mut EventWriter<W>) -> ThothResult<()> {
+    for prize in prizes {
+        write_element_block("Prize", w, |w| {
+            write_element_block("PrizeName", w, |w| {
+                w.write(XmlEvent::Characters(&prize.prize_name))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(prize_year) = prize.prize_year {
+                write_element_block("PrizeYear", w, |w| {
+                    w.write(XmlEvent::Characters(&prize_year.to_string()))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if let Some(country) = prize.prize_country.as_ref().and_then(|c| c.alpha_2()) {
+                write_element_block("PrizeCountry", w, |w| {
+                    w.write(XmlEvent::Characters(country)).map_err(|e| e.into())
+                })?;
+            }
+            write_element_block("PrizeCode", w, |w| {
+                w.write(XmlEvent::Characters(onix_prize_code(&prize.prize_code)))
+                    .map_err(|e| e.into())
+            })?;
+            if let Some(prize_statement) = &prize.prize_statement {
+                write_element_block("PrizeStatement", w, |w| {
+                    w.write(XmlEvent::Characters(prize_statement))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            if let Some(prize_jury) = &prize.prize_jury {
+                write_element_block("PrizeJury", w, |w| {
+                    w.write(XmlEvent::Characters(prize_jury))
+                        .map_err(|e| e.into())
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn onix_resource_content_type(resource_content_type: &ResourceContentType) -> &'static str {
+    match resource_content_type {
+        ResourceContentType::FRONT_COVER => "01",
+        ResourceContentType::BACK_COVER => "02",
+        ResourceContentType::COVER_PACKSHOT => "03",
+        ResourceContentType::CONTRIBUTOR_PICTURE => "04",
+        ResourceContentType::CONTRIBUTOR_INTERVIEW => "11",
+        ResourceContentType::SAMPLE_CONTENT => "15",
+        ResourceContentType::READING_GROUP_GUIDE => "19",
+        ResourceContentType::TEACHERS_GUIDE => "20",
+        ResourceContentType::PRESS_RELEASE => "24",
+        ResourceContentType::TRAILER => "26",
+        ResourceContentType::COVER_THUMBNAIL => "27",
+        ResourceContentType::FULL_COVER => "29",
+        ResourceContentType::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_resource_mode(resource_mode: &ResourceMode) -> &'static str {
+    match resource_mode {
+        ResourceMode::APPLICATION => "01",
+        ResourceMode::AUDIO => "02",
+        ResourceMode::IMAGE => "03",
+        ResourceMode::TEXT => "04",
+        ResourceMode::VIDEO => "05",
+        ResourceMode::MULTI_MODE => "06",
+        ResourceMode::Other(_) => unreachable!(),
+    }
+}
+
+fn onix_resource_form(resource_form: &ResourceForm) -> &'static str {
+    match resource_form {
+        ResourceForm::LINKABLE_RESOURCE => "01",
+        ResourceForm::DOWNLOADABLE_FILE => "02",
+        ResourceForm::EMBEDDABLE_APPLICATION => "03",
+        ResourceForm::Other(_) => unreachable!(),
+    }
+}
+
+fn write_onix3_resource_feature<W: Write>(
+    feature_type: &str,
+    feature_note: &str,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ResourceFeature", w, |w| {
+        write_element_block("ResourceFeatureType", w, |w| {
+            w.write(XmlEvent::Characters(feature_type))
+                .map_err(|e| e.into())
+        })?;
+        write_element_block("FeatureNote", w, |w| {
+            w.write(XmlEvent::Characters(feature_note))
+                .map_err(|e| e.into())
+        })
+    })
+}
+
+fn write_onix3_resource_version_feature<W: Write>(
+    feature_type: &str,
+    feature_value: i64,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("ResourceVersionFeature", w, |w| {
+        write_element_block("ResourceVersionFeatureType", w, |w| {
+            w.write(XmlEvent::Characters(feature_type))
+                .map_err(|e| e.into())
+        })?;
+        write_element_block("FeatureValue", w, |w| {
+            w.write(XmlEvent::Characters(&feature_value.to_string()))
+                .map_err(|e| e.into())
+        })
+    })
+}
+
+fn write_onix3_supporting_resource<W: Write>(
+    resource: &WorkSupportingResources,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    write_element_block("SupportingResource", w, |w| {
+        write_element_block("ResourceContentType", w, |w| {
+            w.write(XmlEvent::Characters(onix_resource_content_type(
+                &resource.resource_content_type,
+            )))
+            .map_err(|e| e.into())
+        })?;
+        write_element_block("ContentAudience", w, |w| {
+            w.write(XmlEvent::Characters(onix_content_audience(
+                &resource.content_audience,
+            )))
+            .map_err(|e| e.into())
+        })?;
+        write_element_block("ResourceMode", w, |w| {
+            w.write(XmlEvent::Characters(onix_resource_mode(
+                &resource.resource_mode,
+            )))
+            .map_err(|e| e.into())
+        })?;
+        if let Some(credit) = &resource.credit {
+            // 01 Required credit
+            write_onix3_resource_feature("01", credit, w)?;
+        }
+        if let Some(caption) = &resource.caption {
+            // 02 Caption
+            write_onix3_resource_feature("02", caption, w)?;
+        }
+        write_element_block("ResourceVersion", w, |w| {
+            write_element_block("ResourceForm", w, |w| {
+                w.write(XmlEvent::Characters(onix_resource_form(
+                    &resource.resource_form,
+                )))
+                .map_err(|e| e.into())
+            })?;
+            if let Some(height_px) = resource.height_px {
+                // 02 Image height in pixels
+                write_onix3_resource_version_feature("02", height_px, w)?;
+            }
+            if let Some(width_px) = resource.width_px {
+                // 03 Image width in pixels
+                write_onix3_resource_version_feature("03", width_px, w)?;
+            }
+            write_element_block("ResourceLink", w, |w| {
+                w.write(XmlEvent::Characters(&resource.resource_url))
+                    .map_err(|e| e.into())
+            })
+        })
+    })
+}
+
+// Works which predate supporting resources only have a single cover recorded against
+// the work itself: output it as the front cover unless one has been recorded explicitly.
+// Renditions generated from an uploaded cover are left out, as they duplicate the work cover.
+fn write_onix3_supporting_resources<W: Write>(
+    work: &Work,
+    w: &mut EventWriter<W>,
+) -> ThothResult<()> {
+    let resources: Vec<&WorkSupportingResources> = work
+        .supporting_resources
+        .iter()
+        .filter(|r| r.cover_rendition.is_none())
+        .collect();
+    let has_front_cover = resources
+        .iter()
+        .any(|r| r.resource_content_type == ResourceContentType::FRONT_COVER);
+    if let (false, Some(cover_url)) = (has_front_cover, &work.cover_url) {
+        write_onix3_supporting_resource(
+            &WorkSupportingResources {
+                resource_content_type: ResourceContentType::FRONT_COVER,
+                content_audience: ContentAudience::UNRESTRICTED,
+                resource_mode: ResourceMode::IMAGE,
+                resource_form: ResourceForm::DOWNLOADABLE_FILE,
+                resource_url: cover_url.clone(),
+                caption: work.cover_caption.clone(),
+                credit: None,
+                width_px: None,
+                height_px: None,
+                cover_rendition: None,
+            },
+            w,
+        )?;
+    }
+    for resource in resources {
+        write_onix3_supporting_resource(resource, w)?;
+    }
+    Ok(())
+}
+
 pub(crate) trait XmlSpecification {
     fn generate(&self, works: &[Work], doctype: Option<&str>) -> ThothResult<String> {
         let mut buffer = format!("{}{}", XML_DECLARATION, doctype.unwrap_or_default())
@@ -1,13 +1,18 @@
 use chrono::Utc;
 use std::collections::HashMap;
 use std::io::Write;
+use thoth_api::markup::to_plain_text;
+use thoth_api::model::license::is_open_access;
 use thoth_client::{
     ContributionType, LanguageRelation, PublicationType, SubjectType, Work, WorkContributions,
-    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkSubjects, WorkType,
+    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkSubjects,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    write_element_block, write_onix21_price, write_onix21_sales_rights, XmlElement,
+    XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -43,9 +48,9 @@ impl XmlSpecification for Onix21EbscoHost {
                 [work] => XmlElementBlock::<Onix21EbscoHost>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix21EbscoHost>::xml_element(work, w).ok();
                         }
                     }
@@ -80,7 +85,7 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
             ));
         }
         // EBSCO Host can only process works as Open Access if they are unpriced
-        let is_open_access = self.license.is_some();
+        let is_open_access = is_open_access(self.license.as_deref());
         if is_open_access &&
             // Thoth database only accepts non-zero prices
             !(pdf_publication.is_some_and(|p| p.prices.is_empty()) ||
@@ -304,7 +309,7 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
                         w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
                     })?;
                     write_element_block("Text", w, |w| {
-                        w.write(XmlEvent::Characters(labstract))
+                        w.write(XmlEvent::Characters(&to_plain_text(labstract)))
                             .map_err(|e| e.into())
                     })
                 })?;
@@ -366,15 +371,13 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
                         .map_err(|e| e.into())
                 })?;
             }
-            write_element_block("SalesRights", w, |w| {
-                // 02 For sale with non-exclusive rights in the specified countries or territories
-                write_element_block("SalesRightsType", w, |w| {
-                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                })?;
-                write_element_block("RightsTerritory", w, |w| {
-                    w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                })
-            })?;
+            write_onix21_sales_rights(
+                pdf_publication
+                    .or(epub_publication)
+                    .map(|p| p.sales_rights.as_slice())
+                    .unwrap_or_default(),
+                w,
+            )?;
             if !isbns.is_empty() {
                 for (publication_type, isbn) in &isbns {
                     let relation_code = match publication_type {
@@ -449,22 +452,13 @@ impl XmlElementBlock<Onix21EbscoHost> for Work {
                     })
                 } else {
                     for price in prices {
-                        let unit_price = price.unit_price;
-                        let formatted_price = format!("{unit_price:.2}");
-                        write_element_block("Price", w, |w| {
-                            // 01 RRP excluding tax (price code requested by EBSCO)
-                            write_element_block("PriceTypeCode", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("PriceAmount", w, |w| {
-                                w.write(XmlEvent::Characters(&formatted_price))
-                                    .map_err(|e| e.into())
-                            })?;
-                            write_element_block("CurrencyCode", w, |w| {
-                                w.write(XmlEvent::Characters(&price.currency_code.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
+                        // 01 RRP excluding tax (price code requested by EBSCO)
+                        write_onix21_price(
+                            &price,
+                            Some("01"),
+                            &self.imprint.publisher.publisher_name,
+                            w,
+                        )?;
                     }
                     Ok(())
                 }
@@ -638,7 +632,7 @@ impl XmlElementBlock<Onix21EbscoHost> for WorkIssues {
                     .map_err(|e| e.into())
             })?;
             write_element_block("NumberWithinSeries", w, |w| {
-                w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
+                w.write(XmlEvent::Characters(&self.numbering()))
                     .map_err(|e| e.into())
             })
         })
@@ -675,9 +669,11 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, CurrencyCode, LanguageCode, LanguageRelation, LocationPlatform,
-        PublicationType, WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
-        WorkIssuesSeries, WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkType,
+        ContributionType, CountryCode, CurrencyCode, LanguageCode, LanguageRelation,
+        LocationPlatform, PriceType, PublicationType, SalesRestrictionType, SalesRightsType,
+        WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssuesSeries,
+        WorkPublicationsLocations, WorkPublicationsPrices, WorkPublicationsSalesRights, WorkStatus,
+        WorkType,
     };
     use uuid::Uuid;
 
@@ -718,6 +714,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -824,6 +825,9 @@ mod tests {
     fn test_onix21_ebsco_host_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -833,6 +837,8 @@ mod tests {
                 series_url: None,
                 series_description: None,
                 series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -923,6 +929,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -931,6 +939,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -977,6 +986,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -997,10 +1027,30 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 5.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![WorkPublicationsLocations {
@@ -1009,6 +1059,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-AAAA-000000000001").unwrap(),
@@ -1029,23 +1100,79 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 5.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 4.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1147,6 +1274,38 @@ mod tests {
         let output = generate_test_output(true, &test_work);
         assert!(output.contains(r#"  <OutofPrintDate>20201231</OutofPrintDate>"#));
 
+        // Add sales rights to the PDF: world rights except for North America
+        test_work.publications[1].sales_rights = vec![WorkPublicationsSalesRights {
+            sales_rights_type: SalesRightsType::FOR_SALE_WITH_EXCLUSIVE_RIGHTS,
+            countries_included: vec![],
+            countries_excluded: vec![CountryCode::USA, CountryCode::CAN],
+            regions_included: vec!["WORLD".to_string()],
+            regions_excluded: vec![],
+            sales_restriction_type: None,
+            sales_restriction_note: None,
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"    <SalesRightsType>02</SalesRightsType>"#));
+        assert!(output.contains(r#"    <SalesRightsType>01</SalesRightsType>"#));
+        assert!(output.contains(r#"    <RightsTerritory>WORLD</RightsTerritory>"#));
+        assert!(output.contains(r#"    <SalesRightsType>03</SalesRightsType>"#));
+        assert!(output.contains(r#"    <RightsCountry>US CA</RightsCountry>"#));
+        assert!(!output.contains(r#"  <SalesRestriction>"#));
+
+        // Restrict the sales rights to a single retailer
+        test_work.publications[1].sales_rights[0].sales_restriction_type =
+            Some(SalesRestrictionType::RETAILER_EXCLUSIVE);
+        test_work.publications[1].sales_rights[0].sales_restriction_note =
+            Some("Exclusive to Bookshop Ltd".to_string());
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+  <SalesRestriction>
+    <SalesRestrictionType>04</SalesRestrictionType>
+    <SalesRestrictionDetail>Exclusive to Bookshop Ltd</SalesRestrictionDetail>
+  </SalesRestriction>"#
+        ));
+
         // Remove some values to test non-output of optional blocks
         test_work.doi = None;
         test_work.license = None;
@@ -1239,6 +1398,16 @@ mod tests {
         test_work.publications[0].prices = vec![WorkPublicationsPrices {
             currency_code: CurrencyCode::AUD,
             unit_price: 10.00,
+            price_type: PriceType::RRP_INCLUDING_TAX,
+            price_qualifier: None,
+            countries_included: vec![],
+            countries_excluded: vec![],
+            regions_included: vec![],
+            regions_excluded: vec![],
+            tax_rate_percent: None,
+            discount_code: None,
+            price_effective_from: None,
+            price_effective_until: None,
         }];
         let output = generate_test_output(true, &test_work);
         assert!(!output.contains(r#"      <PriceAmount>5.99</PriceAmount>"#));
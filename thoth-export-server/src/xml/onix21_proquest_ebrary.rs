@@ -1,13 +1,18 @@
 use chrono::Utc;
 use std::collections::HashMap;
 use std::io::Write;
+use thoth_api::markup::to_plain_text;
+use thoth_api::model::license::is_open_access;
 use thoth_client::{
     ContributionType, LanguageRelation, PublicationType, SubjectType, Work, WorkContributions,
-    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkSubjects, WorkType,
+    WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkSubjects,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    write_element_block, write_onix21_price, write_onix21_sales_rights, XmlElement,
+    XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -45,9 +50,9 @@ impl XmlSpecification for Onix21ProquestEbrary {
                 [work] => XmlElementBlock::<Onix21ProquestEbrary>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix21ProquestEbrary>::xml_element(work, w).ok();
                         }
                     }
@@ -82,7 +87,7 @@ impl XmlElementBlock<Onix21ProquestEbrary> for Work {
             ));
         }
         // ProQuest Ebrary can only process works as Open Access if they are unpriced
-        let is_open_access = self.license.is_some();
+        let is_open_access = is_open_access(self.license.as_deref());
         if is_open_access &&
             // Thoth database only accepts non-zero prices
             !(pdf_publication.is_some_and(|p| p.prices.is_empty()) ||
@@ -303,7 +308,7 @@ impl XmlElementBlock<Onix21ProquestEbrary> for Work {
                         w.write(XmlEvent::Characters("06")).map_err(|e| e.into())
                     })?;
                     write_element_block("Text", w, |w| {
-                        w.write(XmlEvent::Characters(labstract))
+                        w.write(XmlEvent::Characters(&to_plain_text(labstract)))
                             .map_err(|e| e.into())
                     })
                 })?;
@@ -365,15 +370,13 @@ impl XmlElementBlock<Onix21ProquestEbrary> for Work {
                         .map_err(|e| e.into())
                 })?;
             }
-            write_element_block("SalesRights", w, |w| {
-                // 02 For sale with non-exclusive rights in the specified countries or territories
-                write_element_block("SalesRightsType", w, |w| {
-                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                })?;
-                write_element_block("RightsTerritory", w, |w| {
-                    w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                })
-            })?;
+            write_onix21_sales_rights(
+                pdf_publication
+                    .or(epub_publication)
+                    .map(|p| p.sales_rights.as_slice())
+                    .unwrap_or_default(),
+                w,
+            )?;
             if !isbns.is_empty() {
                 for isbn in &isbns {
                     write_element_block("RelatedProduct", w, |w| {
@@ -433,22 +436,12 @@ impl XmlElementBlock<Onix21ProquestEbrary> for Work {
                     })
                 } else {
                     for price in prices {
-                        let unit_price = price.unit_price;
-                        let formatted_price = format!("{unit_price:.2}");
-                        write_element_block("Price", w, |w| {
-                            // 02 RRP including tax
-                            write_element_block("PriceTypeCode", w, |w| {
-                                w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("PriceAmount", w, |w| {
-                                w.write(XmlEvent::Characters(&formatted_price))
-                                    .map_err(|e| e.into())
-                            })?;
-                            write_element_block("CurrencyCode", w, |w| {
-                                w.write(XmlEvent::Characters(&price.currency_code.to_string()))
-                                    .map_err(|e| e.into())
-                            })
-                        })?;
+                        write_onix21_price(
+                            &price,
+                            None,
+                            &self.imprint.publisher.publisher_name,
+                            w,
+                        )?;
                     }
                     Ok(())
                 }
@@ -615,7 +608,7 @@ impl XmlElementBlock<Onix21ProquestEbrary> for WorkIssues {
                     .map_err(|e| e.into())
             })?;
             write_element_block("NumberWithinSeries", w, |w| {
-                w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
+                w.write(XmlEvent::Characters(&self.numbering()))
                     .map_err(|e| e.into())
             })
         })
@@ -652,9 +645,10 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, CurrencyCode, LanguageCode, LanguageRelation, LocationPlatform,
-        PublicationType, WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
-        WorkIssuesSeries, WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkType,
+        ContributionType, CountryCode, CurrencyCode, LanguageCode, LanguageRelation,
+        LocationPlatform, PriceType, PublicationType, WorkContributionsContributor, WorkImprint,
+        WorkImprintPublisher, WorkIssuesSeries, WorkPublicationsLocations, WorkPublicationsPrices,
+        WorkStatus, WorkType,
     };
     use uuid::Uuid;
 
@@ -696,6 +690,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -802,6 +801,9 @@ mod tests {
     fn test_onix21_proquest_ebrary_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -811,6 +813,8 @@ mod tests {
                 series_url: None,
                 series_description: None,
                 series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -901,6 +905,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -909,6 +915,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -955,6 +962,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -975,10 +1003,30 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 5.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![WorkPublicationsLocations {
@@ -987,6 +1035,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-AAAA-000000000001").unwrap(),
@@ -1007,23 +1076,79 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 5.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 4.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::USD,
                             unit_price: 7.99,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1213,6 +1338,16 @@ mod tests {
         test_work.publications[0].prices = vec![WorkPublicationsPrices {
             currency_code: CurrencyCode::AUD,
             unit_price: 10.00,
+            price_type: PriceType::RRP_INCLUDING_TAX,
+            price_qualifier: None,
+            countries_included: vec![],
+            countries_excluded: vec![],
+            regions_included: vec![],
+            regions_excluded: vec![],
+            tax_rate_percent: None,
+            discount_code: None,
+            price_effective_from: None,
+            price_effective_until: None,
         }];
         let output = generate_test_output(true, &test_work);
         assert!(!output.contains(r#"    <UnpricedItemType>01</UnpricedItemType>"#));
@@ -1224,6 +1359,22 @@ mod tests {
         assert!(output.contains(r#"      <PriceTypeCode>02</PriceTypeCode>"#));
         assert!(output.contains(r#"      <PriceAmount>10.00</PriceAmount>"#));
         assert!(output.contains(r#"      <CurrencyCode>AUD</CurrencyCode>"#));
+        assert!(!output.contains(r#"      <CountryCode>"#));
+
+        // Restrict the price to Australia and New Zealand from a given date, with tax
+        test_work.publications[0].prices[0].price_type = PriceType::RRP_EXCLUDING_TAX;
+        test_work.publications[0].prices[0].countries_included =
+            vec![CountryCode::AUS, CountryCode::NZL];
+        test_work.publications[0].prices[0].tax_rate_percent = Some(10.0);
+        test_work.publications[0].prices[0].price_effective_from =
+            chrono::NaiveDate::from_ymd_opt(2025, 7, 1);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"      <PriceTypeCode>01</PriceTypeCode>"#));
+        assert!(output.contains(r#"      <CountryCode>AU</CountryCode>"#));
+        assert!(output.contains(r#"      <CountryCode>NZ</CountryCode>"#));
+        assert!(output.contains(r#"      <TaxRatePercent1>10</TaxRatePercent1>"#));
+        assert!(output.contains(r#"      <PriceEffectiveFrom>20250701</PriceEffectiveFrom>"#));
+        assert!(!output.contains(r#"      <PriceEffectiveUntil>"#));
 
         // Replace licence: error
         test_work.license = Some("https://creativecommons.org/licenses/by/4.0/".to_string());
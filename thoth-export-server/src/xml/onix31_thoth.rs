@@ -1,15 +1,28 @@
-use cc_license::License;
 use chrono::Utc;
 use std::io::Write;
+use std::str::FromStr;
+use thoth_api::markup::to_plain_text;
+use thoth_api::model::language::LanguageCode;
+use thoth_api::model::license::{is_open_access, License};
 use thoth_client::{
-    ContributionType, LanguageRelation, LocationPlatform, PublicationType, RelationType,
-    SubjectType, Work, WorkContributions, WorkFundings, WorkIssues, WorkLanguages,
-    WorkPublicationsLocations, WorkReferences, WorkRelations, WorkRelationsRelatedWork,
-    WorkRelationsRelatedWorkContributions, WorkRelationsRelatedWorkLanguages, WorkStatus, WorkType,
+    AbstractType, ContributionType, LanguageRelation, LocalisedTitle, LocationPlatform,
+    PublicationType, RelationType, SubjectType, Work, WorkContributions, WorkEvents, WorkFundings,
+    WorkIssues, WorkLanguages, WorkPublicationsLocations, WorkReferences, WorkRelations,
+    WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributions,
+    WorkRelationsRelatedWorkLanguages, WorkStatus,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility, write_onix3_cited_contents,
+    write_onix3_collection_contributors, write_onix3_collection_title_detail,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_pack_quantity, write_onix3_price, write_onix3_print_form_details,
+    write_onix3_prizes, write_onix3_product_form_description, write_onix3_sales_rights,
+    write_onix3_supporting_resources, write_onix3_text_contents, write_onix_alternative_names,
+    write_onix_funder_identifiers, write_onix_identifier, write_onix_name_identifiers,
+    write_rich_text_element, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX31_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -56,9 +69,9 @@ impl XmlSpecification for Onix31Thoth {
                 [work] => XmlElementBlock::<Onix31Thoth>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix31Thoth>::xml_element(work, w).ok();
                         }
                     }
@@ -189,6 +202,15 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                         })
                     })?;
                 }
+                for identifier in &publication.identifiers {
+                    write_onix_identifier(
+                        "ProductIdentifier",
+                        "ProductIDType",
+                        &identifier.identifier_type,
+                        &identifier.identifier_value,
+                        w,
+                    )?;
+                }
                 write_element_block("DescriptiveDetail", w, |w| {
                     // 00 Single-component retail product
                     write_element_block("ProductComposition", w, |w| {
@@ -203,6 +225,10 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                             w.write(XmlEvent::Characters(code)).map_err(Into::into)
                         })?;
                     }
+                    write_onix3_print_form_details(publication, w)?;
+                    write_onix3_digital_form_details(publication, w)?;
+                    write_onix3_accessibility(publication, w)?;
+                    write_onix3_product_form_description(publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(Into::into)
@@ -236,16 +262,20 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                             .ok();
                         }
                     }
+                    write_onix3_epub_usage(publication, w)?;
                     if let Some(license_url) = &self.license {
-                        write_license(license_url.to_string(), w)?;
+                        write_license(license_url, w)?;
                     }
                     for issue in &self.issues {
                         XmlElementBlock::<Onix31Thoth>::xml_element(issue, w).ok();
                     }
-                    write_title(self.title.clone(), self.subtitle.clone(), w)?;
+                    write_titles(self.localised_titles(), w)?;
                     for contribution in &self.contributions {
                         XmlElementBlock::<Onix31Thoth>::xml_element(contribution, w).ok();
                     }
+                    for event in &self.events {
+                        XmlElementBlock::<Onix31Thoth>::xml_element(event, w)?;
+                    }
                     if let Some(edition) = &self.edition {
                         // "Normally sent only for the second and subsequent editions"
                         if edition > &1 {
@@ -260,7 +290,7 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                     for language in &self.languages {
                         XmlElementBlock::<Onix31Thoth>::xml_element(language, w).ok();
                     }
-                    if let Some(page_count) = self.page_count {
+                    if let Some(page_count) = publication.page_count.or(self.page_count) {
                         write_element_block("Extent", w, |w| {
                             // 00 Main content
                             write_element_block("ExtentType", w, |w| {
@@ -276,6 +306,7 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(publication, w)?;
                     if let Some(bibliography_note) = &self.bibliography_note {
                         // "This data element carries text stating the number and type of
                         // illustrations. The text may also include other content items,
@@ -381,10 +412,14 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                 })?;
                 if self.short_abstract.is_some()
                     || self.long_abstract.is_some()
+                    || !self.abstracts.is_empty()
                     || self.toc.is_some()
                     || self.general_note.is_some()
                     || self.cover_url.is_some()
-                    || self.license.is_some()
+                    || !self.supporting_resources.is_empty()
+                    || is_open_access(self.license.as_deref())
+                    || !self.text_contents.is_empty()
+                    || !self.prizes.is_empty()
                 {
                     write_element_block("CollateralDetail", w, |w| {
                         write_work_short_abstract(self, w)?;
@@ -406,43 +441,10 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                         }
                         write_work_open_access_statement(self, w)?;
                         write_work_general_note(self, w)?;
-                        if let Some(cover_url) = &self.cover_url {
-                            write_element_block("SupportingResource", w, |w| {
-                                // 01 Front cover
-                                write_element_block("ResourceContentType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(Into::into)
-                                })?;
-                                // 03 Image
-                                write_element_block("ResourceMode", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(Into::into)
-                                })?;
-                                if let Some(cover_caption) = &self.cover_caption {
-                                    write_element_block("ResourceFeature", w, |w| {
-                                        // 02 Caption
-                                        write_element_block("ResourceFeatureType", w, |w| {
-                                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("FeatureNote", w, |w| {
-                                            w.write(XmlEvent::Characters(cover_caption))
-                                                .map_err(Into::into)
-                                        })
-                                    })?;
-                                }
-                                write_element_block("ResourceVersion", w, |w| {
-                                    // 02 Downloadable file
-                                    write_element_block("ResourceForm", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                    })?;
-                                    write_element_block("ResourceLink", w, |w| {
-                                        w.write(XmlEvent::Characters(cover_url)).map_err(Into::into)
-                                    })
-                                })
-                            })?;
-                        }
+                        write_onix3_text_contents(&self.text_contents, w)?;
+                        write_onix3_cited_contents(&self.text_contents, w)?;
+                        write_onix3_supporting_resources(self, w)?;
+                        write_onix3_prizes(&self.prizes, w)?;
                         Ok(())
                     })?;
                 }
@@ -458,6 +460,8 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                     write_element_block("ContentDetail", w, |w| {
                         for relation in &chapter_relations {
                             let chapter = &relation.related_work;
+                            // Chapters without a licence of their own carry that of the book
+                            let license = chapter.license.as_ref().or(self.license.as_ref());
                             write_element_block("ContentItem", w, |w| {
                                 write_element_block("LevelSequenceNumber", w, |w| {
                                     w.write(XmlEvent::Characters(
@@ -505,13 +509,13 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                                     }
                                     Ok(())
                                 })?;
-                                if let Some(license_url) = &chapter.license {
-                                    write_license(license_url.to_string(), w)?;
+                                if let Some(license_url) = license {
+                                    write_license(license_url, w)?;
                                 }
                                 write_element_block("ComponentTypeName", w, |w| {
                                     w.write(XmlEvent::Characters("Chapter")).map_err(Into::into)
                                 })?;
-                                write_title(chapter.title.clone(), chapter.subtitle.clone(), w)?;
+                                write_titles(chapter.localised_titles(), w)?;
                                 for contribution in &chapter.contributions {
                                     XmlElementBlock::<Onix31Thoth>::xml_element(contribution, w)
                                         .ok();
@@ -519,14 +523,10 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                                 for language in &chapter.languages {
                                     XmlElementBlock::<Onix31Thoth>::xml_element(language, w).ok();
                                 }
-                                if chapter.short_abstract.is_some() {
-                                    write_chapter_short_abstract(chapter, w)?;
-                                }
-                                if chapter.long_abstract.is_some() {
-                                    write_chapter_long_abstract(chapter, w)?;
-                                }
-                                if chapter.license.is_some() {
-                                    write_chapter_open_access_statement(chapter, w)?;
+                                write_chapter_short_abstract(chapter, w)?;
+                                write_chapter_long_abstract(chapter, w)?;
+                                if is_open_access(license.map(String::as_str)) {
+                                    write_open_access_statement_content(w)?;
                                 }
                                 if chapter.general_note.is_some() {
                                     write_chapter_general_note(chapter, w)?;
@@ -656,17 +656,7 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                         })?;
                     }
                     write_work_copyright(self, w)?;
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(Into::into)
-                            })
-                        })
-                    })
+                    write_onix3_sales_rights(&publication.sales_rights, w)
                 })?;
                 let non_child_relations: Vec<WorkRelations> = self
                     .relations
@@ -683,9 +673,28 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                 if (!isbns.is_empty() && !isbns.eq(&vec![current_isbn.clone().unwrap_or_default()]))
                     || !non_child_relations.is_empty()
                     || !self.references.is_empty()
+                    || !self.identifiers.is_empty()
                 {
                     write_element_block("RelatedMaterial", w, |w| {
                         // RelatedWorks should be listed before RelatedProducts
+                        if !self.identifiers.is_empty() {
+                            write_element_block("RelatedWork", w, |w| {
+                                // 01 Manifestation of
+                                write_element_block("WorkRelationCode", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+                                })?;
+                                for identifier in &self.identifiers {
+                                    write_onix_identifier(
+                                        "WorkIdentifier",
+                                        "WorkIDType",
+                                        &identifier.identifier_type,
+                                        &identifier.identifier_value,
+                                        w,
+                                    )?;
+                                }
+                                Ok(())
+                            })?;
+                        }
                         for relation in &non_child_relations {
                             if relation.relation_type == RelationType::HAS_TRANSLATION
                                 || relation.relation_type == RelationType::IS_TRANSLATION_OF
@@ -855,6 +864,7 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                                     )
                                 })?;
                             }
+                            write_onix3_pack_quantity(publication, w)?;
                             if publication.prices.is_empty() {
                                 // 01 Free of charge
                                 write_element_block("UnpricedItemType", w, |w| {
@@ -862,30 +872,11 @@ impl XmlElementBlock<Onix31Thoth> for Work {
                                 })
                             } else {
                                 for price in &publication.prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(Into::into)
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(Into::into)
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(Into::into)
-                                            })
-                                        })
-                                    })?;
+                                    write_onix3_price(
+                                        price,
+                                        &self.imprint.publisher.publisher_name,
+                                        w,
+                                    )?;
                                 }
                                 Ok(())
                             }
@@ -899,10 +890,12 @@ impl XmlElementBlock<Onix31Thoth> for Work {
     }
 }
 
-fn write_license<W: Write>(license: String, w: &mut EventWriter<W>) -> ThothResult<()> {
-    let license_text = match License::from_url(&license) {
-        Ok(license) => license.to_string(),
-        Err(_) => "Unspecified".to_string(),
+fn write_license<W: Write>(license: &str, w: &mut EventWriter<W>) -> ThothResult<()> {
+    // Licences from the controlled vocabulary are named and linked in their canonical form,
+    // while custom licences can only be linked
+    let (license_text, license_url) = match License::from_str(license) {
+        Ok(license) if license.is_registered() => (license.description(), license.url()),
+        _ => ("Unspecified".to_string(), license.trim().to_string()),
     };
     write_element_block("EpubLicense", w, |w| {
         write_element_block("EpubLicenseName", w, |w| {
@@ -914,39 +907,43 @@ fn write_license<W: Write>(license: String, w: &mut EventWriter<W>) -> ThothResu
                 w.write(XmlEvent::Characters("02")).map_err(Into::into)
             })?;
             write_element_block("EpubLicenseExpressionLink", w, |w| {
-                w.write(XmlEvent::Characters(&license)).map_err(Into::into)
+                w.write(XmlEvent::Characters(&license_url))
+                    .map_err(Into::into)
             })
         })
     })?;
     Ok(())
 }
 
-fn write_title<W: Write>(
-    title: String,
-    subtitle: Option<String>,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    write_element_block("TitleDetail", w, |w| {
-        // 01 Distinctive title (book)
-        write_element_block("TitleType", w, |w| {
-            w.write(XmlEvent::Characters("01")).map_err(Into::into)
-        })?;
-        write_element_block("TitleElement", w, |w| {
-            // 01 Product
-            write_element_block("TitleElementLevel", w, |w| {
-                w.write(XmlEvent::Characters("01")).map_err(Into::into)
-            })?;
-            write_element_block("TitleText", w, |w| {
-                w.write(XmlEvent::Characters(&title)).map_err(Into::into)
+fn write_titles<W: Write>(titles: Vec<LocalisedTitle>, w: &mut EventWriter<W>) -> ThothResult<()> {
+    for title in titles {
+        let language = language_attr(&title.language_code);
+        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+        write_element_block("TitleDetail", w, |w| {
+            // 01 Distinctive title (book), 06 Title in other language
+            let title_type = if title.canonical { "01" } else { "06" };
+            write_element_block("TitleType", w, |w| {
+                w.write(XmlEvent::Characters(title_type))
+                    .map_err(Into::into)
             })?;
-            if let Some(subtitle) = &subtitle {
-                write_element_block("Subtitle", w, |w| {
-                    w.write(XmlEvent::Characters(subtitle)).map_err(Into::into)
+            write_element_block("TitleElement", w, |w| {
+                // 01 Product
+                write_element_block("TitleElementLevel", w, |w| {
+                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
                 })?;
-            }
-            Ok(())
-        })
-    })?;
+                write_full_element_block("TitleText", language_attr.clone(), w, |w| {
+                    w.write(XmlEvent::Characters(title.title))
+                        .map_err(Into::into)
+                })?;
+                if let Some(subtitle) = title.subtitle {
+                    write_full_element_block("Subtitle", language_attr.clone(), w, |w| {
+                        w.write(XmlEvent::Characters(subtitle)).map_err(Into::into)
+                    })?;
+                }
+                Ok(())
+            })
+        })?;
+    }
     Ok(())
 }
 
@@ -980,8 +977,13 @@ fn write_copyright_content<W: Write>(
 }
 
 fn write_work_short_abstract<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    let short_abstract = work.short_abstract.clone().unwrap_or_default();
-    write_short_abstract_content(short_abstract, w)?;
+    let short_abstracts = work.localised_abstracts(AbstractType::SHORT);
+    if short_abstracts.is_empty() {
+        write_short_abstract_content("", &None, w)?;
+    }
+    for short_abstract in short_abstracts {
+        write_short_abstract_content(short_abstract.content, &short_abstract.language_code, w)?;
+    }
     Ok(())
 }
 
@@ -989,22 +991,27 @@ fn write_chapter_short_abstract<W: Write>(
     chapter: &WorkRelationsRelatedWork,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
-    let short_abstract = chapter.short_abstract.clone().unwrap_or_default();
-    write_short_abstract_content(short_abstract, w)?;
+    for short_abstract in chapter.localised_abstracts(AbstractType::SHORT) {
+        write_short_abstract_content(short_abstract.content, &short_abstract.language_code, w)?;
+    }
     Ok(())
 }
 
 fn write_short_abstract_content<W: Write>(
-    mut short_abstract: String,
+    short_abstract: &str,
+    language_code: &Option<LanguageCode>,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
+    let plain_text = to_plain_text(short_abstract);
+    let mut short_abstract = plain_text.as_str();
     // Short description field may not exceed 350 characters.
     // Ensure that the string is truncated at a valid UTF-8 boundary
     // by finding the byte index of the 350th character and then truncating
     // the string at that index, to avoid creating invalid UTF-8 sequences.
     if let Some((byte_index, _)) = short_abstract.char_indices().nth(350) {
-        short_abstract.truncate(byte_index);
+        short_abstract = &short_abstract[..byte_index];
     }
+    let language = language_attr(language_code);
     write_element_block("TextContent", w, |w| {
         // 02 Short description
         write_element_block("TextType", w, |w| {
@@ -1014,17 +1021,22 @@ fn write_short_abstract_content<W: Write>(
         write_element_block("ContentAudience", w, |w| {
             w.write(XmlEvent::Characters("00")).map_err(Into::into)
         })?;
-        write_element_block("Text", w, |w| {
-            w.write(XmlEvent::Characters(&short_abstract))
-                .map_err(Into::into)
-        })
+        write_full_element_block(
+            "Text",
+            language.as_deref().map(|l| vec![("language", l)]),
+            w,
+            |w| {
+                w.write(XmlEvent::Characters(short_abstract))
+                    .map_err(Into::into)
+            },
+        )
     })?;
     Ok(())
 }
 
 fn write_work_long_abstract<W: Write>(work: &Work, w: &mut EventWriter<W>) -> ThothResult<()> {
-    if let Some(long_abstract) = work.long_abstract.clone() {
-        write_long_abstract_content(long_abstract, w)?;
+    for long_abstract in work.localised_abstracts(AbstractType::LONG) {
+        write_long_abstract_content(long_abstract.content, &long_abstract.language_code, w)?;
     }
     Ok(())
 }
@@ -1033,16 +1045,18 @@ fn write_chapter_long_abstract<W: Write>(
     chapter: &WorkRelationsRelatedWork,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
-    if let Some(long_abstract) = chapter.long_abstract.clone() {
-        write_long_abstract_content(long_abstract, w)?;
+    for long_abstract in chapter.localised_abstracts(AbstractType::LONG) {
+        write_long_abstract_content(long_abstract.content, &long_abstract.language_code, w)?;
     }
     Ok(())
 }
 
 fn write_long_abstract_content<W: Write>(
-    long_abstract: String,
+    long_abstract: &str,
+    language_code: &Option<LanguageCode>,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
+    let language = language_attr(language_code);
     // 03 Description, 30 Abstract
     for text_type in ["03", "30"] {
         write_element_block("TextContent", w, |w| {
@@ -1053,10 +1067,14 @@ fn write_long_abstract_content<W: Write>(
             write_element_block("ContentAudience", w, |w| {
                 w.write(XmlEvent::Characters("00")).map_err(Into::into)
             })?;
-            write_element_block("Text", w, |w| {
-                w.write(XmlEvent::Characters(&long_abstract))
-                    .map_err(Into::into)
-            })
+            write_rich_text_element(
+                "Text",
+                language
+                    .as_deref()
+                    .map_or(vec![], |l| vec![("language", l)]),
+                long_abstract,
+                w,
+            )
         })?;
     }
     Ok(())
@@ -1094,10 +1112,7 @@ fn write_general_note_content<W: Write>(
         write_element_block("ContentAudience", w, |w| {
             w.write(XmlEvent::Characters("00")).map_err(Into::into)
         })?;
-        write_element_block("Text", w, |w| {
-            w.write(XmlEvent::Characters(&general_note))
-                .map_err(Into::into)
-        })
+        write_rich_text_element("Text", vec![], &general_note, w)
     })?;
     Ok(())
 }
@@ -1106,17 +1121,7 @@ fn write_work_open_access_statement<W: Write>(
     work: &Work,
     w: &mut EventWriter<W>,
 ) -> ThothResult<()> {
-    if work.license.is_some() {
-        write_open_access_statement_content(w)?;
-    }
-    Ok(())
-}
-
-fn write_chapter_open_access_statement<W: Write>(
-    chapter: &WorkRelationsRelatedWork,
-    w: &mut EventWriter<W>,
-) -> ThothResult<()> {
-    if chapter.license.is_some() {
+    if is_open_access(work.license.as_deref()) {
         write_open_access_statement_content(w)?;
     }
     Ok(())
@@ -1239,17 +1244,7 @@ impl XmlElementBlock<Onix31Thoth> for WorkContributions {
             })?;
             XmlElement::<Onix31Thoth>::xml_element(&self.contribution_type, w)?;
 
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
+            write_onix_name_identifiers(&self.contributor, w)?;
             write_element_block("PersonName", w, |w| {
                 w.write(XmlEvent::Characters(&self.full_name))
                     .map_err(Into::into)
@@ -1264,6 +1259,7 @@ impl XmlElementBlock<Onix31Thoth> for WorkContributions {
                 w.write(XmlEvent::Characters(&self.last_name))
                     .map_err(Into::into)
             })?;
+            write_onix_alternative_names(&self.contributor, w)?;
             for affiliation in &self.affiliations {
                 write_element_block("ProfessionalAffiliation", w, |w| {
                     if let Some(position) = &affiliation.position {
@@ -1292,9 +1288,7 @@ impl XmlElementBlock<Onix31Thoth> for WorkContributions {
                 })?;
             }
             if let Some(biography) = &self.biography {
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography)).map_err(Into::into)
-                })?;
+                write_rich_text_element("BiographicalNote", vec![], biography, w)?;
             }
             if let Some(website) = &self.contributor.website {
                 write_element_block("Website", w, |w| {
@@ -1376,9 +1370,7 @@ impl XmlElementBlock<Onix31Thoth> for WorkRelationsRelatedWorkContributions {
                 })?;
             }
             if let Some(biography) = &self.biography {
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography)).map_err(Into::into)
-                })?;
+                write_rich_text_element("BiographicalNote", vec![], biography, w)?;
             }
             if let Some(website) = &self.contributor.website {
                 write_element_block("Website", w, |w| {
@@ -1399,6 +1391,90 @@ impl XmlElementBlock<Onix31Thoth> for WorkRelationsRelatedWorkContributions {
     }
 }
 
+impl XmlElementBlock<Onix31Thoth> for WorkEvents {
+    fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
+        write_element_block("Event", w, |w| {
+            // 02 Publication linked to conference (complete proceedings)
+            write_element_block("EventRole", w, |w| {
+                w.write(XmlEvent::Characters("02")).map_err(Into::into)
+            })?;
+            write_element_block("EventName", w, |w| {
+                w.write(XmlEvent::Characters(&self.event_name))
+                    .map_err(Into::into)
+            })?;
+            if let Some(acronym) = &self.event_acronym {
+                write_element_block("EventAcronym", w, |w| {
+                    w.write(XmlEvent::Characters(acronym)).map_err(Into::into)
+                })?;
+            }
+            if let Some(number) = &self.event_number {
+                write_element_block("EventNumber", w, |w| {
+                    w.write(XmlEvent::Characters(number)).map_err(Into::into)
+                })?;
+            }
+            if let Some(start_date) = &self.event_start_date {
+                // 06 YYYYMMDDYYYYMMDD (a range of dates), 00 YYYYMMDD
+                let (format, date) = match &self.event_end_date {
+                    Some(end_date) => (
+                        "06",
+                        format!(
+                            "{}{}",
+                            start_date.format("%Y%m%d"),
+                            end_date.format("%Y%m%d")
+                        ),
+                    ),
+                    None => ("00", start_date.format("%Y%m%d").to_string()),
+                };
+                write_full_element_block(
+                    "EventDate",
+                    Some(vec![("dateformat", format)]),
+                    w,
+                    |w| w.write(XmlEvent::Characters(&date)).map_err(Into::into),
+                )?;
+            }
+            let country = self.event_country.as_ref().and_then(|c| c.alpha_2());
+            if country.is_some() || self.event_location.is_some() {
+                write_element_block("EventPlace", w, |w| {
+                    if let Some(country) = country {
+                        write_element_block("CountryCode", w, |w| {
+                            w.write(XmlEvent::Characters(country)).map_err(Into::into)
+                        })?;
+                    }
+                    if let Some(location) = &self.event_location {
+                        write_element_block("LocationName", w, |w| {
+                            w.write(XmlEvent::Characters(location)).map_err(Into::into)
+                        })?;
+                    }
+                    Ok(())
+                })?;
+            }
+            let mut sponsors = self.sponsors.clone();
+            sponsors.sort_by_key(|s| s.sponsor_ordinal);
+            for sponsor in &sponsors {
+                write_element_block("EventSponsor", w, |w| {
+                    if let Some(ror) = &sponsor.institution.ror {
+                        write_element_block("EventSponsorIdentifier", w, |w| {
+                            // 40: ROR ID
+                            write_element_block("EventSponsorIDType", w, |w| {
+                                w.write(XmlEvent::Characters("40")).map_err(Into::into)
+                            })?;
+                            write_element_block("IDValue", w, |w| {
+                                w.write(XmlEvent::Characters(&ror.to_string()))
+                                    .map_err(Into::into)
+                            })
+                        })?;
+                    }
+                    write_element_block("CorporateName", w, |w| {
+                        w.write(XmlEvent::Characters(&sponsor.institution.institution_name))
+                            .map_err(Into::into)
+                    })
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
 impl XmlElementBlock<Onix31Thoth> for WorkLanguages {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
         write_element_block("Language", w, |w| {
@@ -1506,26 +1582,8 @@ impl XmlElementBlock<Onix31Thoth> for WorkIssues {
                         .map_err(Into::into)
                 })
             })?;
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(Into::into)
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(Into::into)
-                    })?;
-                    write_element_block("PartNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
-                            .map_err(Into::into)
-                    })?;
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(Into::into)
-                    })
-                })
-            })
+            write_onix3_collection_title_detail(self, w)?;
+            write_onix3_collection_contributors(self, w)
         })
     }
 }
@@ -1537,30 +1595,7 @@ impl XmlElementBlock<Onix31Thoth> for WorkFundings {
             write_element_block("PublishingRole", w, |w| {
                 w.write(XmlEvent::Characters("16")).map_err(Into::into)
             })?;
-            if let Some(ror) = &self.institution.ror {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 40 ROR
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("40")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&ror.to_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
-            if let Some(doi) = &self.institution.institution_doi {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 32 FundRef DOI
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("32")).map_err(Into::into)
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&doi.to_string()))
-                            .map_err(Into::into)
-                    })
-                })?;
-            }
+            write_onix_funder_identifiers(&self.institution, w)?;
             write_element_block("PublisherName", w, |w| {
                 w.write(XmlEvent::Characters(&self.institution.institution_name))
                     .map_err(Into::into)
@@ -1730,17 +1765,19 @@ mod tests {
     // println!s throughout will only be printed if test fails - this assists debugging
     use super::*;
     use std::str::FromStr;
+    use thoth_api::model::identifier::{FunderId, Isni, Lcnaf, Ringgold, Viaf, WikidataId};
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CurrencyCode, FundingInstitution, LanguageCode, LanguageRelation,
-        LocationPlatform, PublicationType, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkIssuesSeries, WorkPublications, WorkPublicationsLocations,
-        WorkPublicationsPrices, WorkRelationsRelatedWork,
-        WorkRelationsRelatedWorkContributionsAffiliations,
+        ContributionType, CountryCode, CurrencyCode, FundingInstitution, LanguageCode,
+        LanguageRelation, LocationPlatform, NameType, PriceType, PublicationType,
+        WorkContributionsAffiliations, WorkContributionsAffiliationsInstitution,
+        WorkContributionsContributor, WorkContributionsContributorAlternativeNames,
+        WorkEventsSponsors, WorkEventsSponsorsInstitution, WorkImprint, WorkImprintPublisher,
+        WorkIssuesSeries, WorkPublications, WorkPublicationsLocations, WorkPublicationsPrices,
+        WorkRelationsRelatedWork, WorkRelationsRelatedWorkContributionsAffiliations,
         WorkRelationsRelatedWorkContributionsAffiliationsInstitution,
         WorkRelationsRelatedWorkContributionsContributor, WorkRelationsRelatedWorkImprint,
         WorkRelationsRelatedWorkImprintPublisher, WorkRelationsRelatedWorkReferences, WorkStatus,
@@ -1782,6 +1819,18 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://contributor.site".to_string()),
+                isni: Some(Isni::from_str("0000000121032683").unwrap()),
+                viaf: Some(Viaf::from_str("44300643").unwrap()),
+                wikidata: Some(WikidataId::from_str("Q935").unwrap()),
+                lcnaf: Some(Lcnaf::from_str("n79021164").unwrap()),
+                alternative_names: vec![WorkContributionsContributorAlternativeNames {
+                    name_type: NameType::TRANSLITERATED,
+                    first_name: Some("Автор".to_string()),
+                    last_name: "1".to_string(),
+                    full_name: "Автор 1".to_string(),
+                    language_code: Some(LanguageCode::RUS),
+                    script_code: Some("Cyrl".to_string()),
+                }],
             },
             affiliations: vec![WorkContributionsAffiliations {
                 position: Some("Manager".to_string()),
@@ -1808,9 +1857,32 @@ mod tests {
     <NameIDType>21</NameIDType>
     <IDValue>0000-0002-0000-0001</IDValue>
   </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>16</NameIDType>
+    <IDValue>0000000121032683</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>31</NameIDType>
+    <IDValue>44300643</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>18</NameIDType>
+    <IDValue>n79021164</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>01</NameIDType>
+    <IDTypeName>Wikidata</IDTypeName>
+    <IDValue>Q935</IDValue>
+  </NameIdentifier>
   <PersonName>Author N. 1</PersonName>
   <NamesBeforeKey>Author</NamesBeforeKey>
   <KeyNames>1</KeyNames>
+  <AlternativeName>
+    <NameType>05</NameType>
+    <PersonName language="rus" textscript="Cyrl">Автор 1</PersonName>
+    <NamesBeforeKey>Автор</NamesBeforeKey>
+    <KeyNames>1</KeyNames>
+  </AlternativeName>
   <ProfessionalAffiliation>
     <ProfessionalPosition>Manager</ProfessionalPosition>
     <AffiliationIdentifier>
@@ -1833,6 +1905,11 @@ mod tests {
         test_contribution.contribution_ordinal = 2;
         test_contribution.contributor.orcid = None;
         test_contribution.contributor.website = None;
+        test_contribution.contributor.isni = None;
+        test_contribution.contributor.viaf = None;
+        test_contribution.contributor.wikidata = None;
+        test_contribution.contributor.lcnaf = None;
+        test_contribution.contributor.alternative_names = vec![];
         test_contribution.first_name = None;
         test_contribution.biography = None;
         test_contribution.affiliations[0].position = None;
@@ -1953,10 +2030,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_onix31_thoth_events() {
+        let mut test_event = WorkEvents {
+            event_name: "International Conference on Metadata".to_string(),
+            event_acronym: Some("ICM".to_string()),
+            event_number: Some("12".to_string()),
+            event_location: Some("Cambridge".to_string()),
+            event_country: Some(CountryCode::GBR),
+            event_start_date: chrono::NaiveDate::from_ymd_opt(2024, 6, 1),
+            event_end_date: chrono::NaiveDate::from_ymd_opt(2024, 6, 3),
+            sponsors: vec![
+                WorkEventsSponsors {
+                    sponsor_ordinal: 2,
+                    institution: WorkEventsSponsorsInstitution {
+                        institution_name: "Second Sponsor".to_string(),
+                        ror: None,
+                    },
+                },
+                WorkEventsSponsors {
+                    sponsor_ordinal: 1,
+                    institution: WorkEventsSponsorsInstitution {
+                        institution_name: "Metadata Society".to_string(),
+                        ror: Some(Ror::from_str("https://ror.org/0abcdef12").unwrap()),
+                    },
+                },
+            ],
+        };
+
+        // Test standard output
+        let output = generate_test_output(true, &test_event);
+        assert_eq!(
+            output,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Event>
+  <EventRole>02</EventRole>
+  <EventName>International Conference on Metadata</EventName>
+  <EventAcronym>ICM</EventAcronym>
+  <EventNumber>12</EventNumber>
+  <EventDate dateformat="06">2024060120240603</EventDate>
+  <EventPlace>
+    <CountryCode>GB</CountryCode>
+    <LocationName>Cambridge</LocationName>
+  </EventPlace>
+  <EventSponsor>
+    <EventSponsorIdentifier>
+      <EventSponsorIDType>40</EventSponsorIDType>
+      <IDValue>0abcdef12</IDValue>
+    </EventSponsorIdentifier>
+    <CorporateName>Metadata Society</CorporateName>
+  </EventSponsor>
+  <EventSponsor>
+    <CorporateName>Second Sponsor</CorporateName>
+  </EventSponsor>
+</Event>"#
+        );
+
+        // Single-day event without place or sponsors: date in plain format, elements omitted
+        test_event.event_end_date = None;
+        test_event.event_location = None;
+        test_event.event_country = None;
+        test_event.sponsors.clear();
+        let output = generate_test_output(true, &test_event);
+        assert!(output.contains(r#"  <EventDate dateformat="00">20240601</EventDate>"#));
+        assert!(!output.contains(r#"<EventPlace>"#));
+        assert!(!output.contains(r#"<EventSponsor>"#));
+
+        // No start date: date omitted
+        test_event.event_start_date = None;
+        let output = generate_test_output(true, &test_event);
+        assert!(!output.contains(r#"<EventDate"#));
+    }
+
     #[test]
     fn test_onix31_thoth_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -1966,6 +2118,8 @@ mod tests {
                 series_url: Some("https://series.url".to_string()),
                 series_description: None,
                 series_cfp_url: Some("https://series.cfp.url".to_string()),
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -2061,6 +2215,19 @@ mod tests {
   </TitleDetail>
 </Collection>"#
         );
+
+        // Printed numbering replaces the part number but not the sequence number
+        test_issue.volume = Some("Vol. 3".to_string());
+        test_issue.issue_number = Some("2".to_string());
+        let output = generate_test_output(true, &test_issue);
+        assert!(output.contains(
+            r#"
+    <CollectionSequenceNumber>2</CollectionSequenceNumber>"#
+        ));
+        assert!(output.contains(
+            r#"
+      <PartNumber>Vol. 3, no. 2</PartNumber>"#
+        ));
     }
 
     #[test]
@@ -2078,6 +2245,9 @@ mod tests {
                 ),
                 ror: Some(Ror::from_str("https://ror.org/0aaaaaa00").unwrap()),
                 country_code: None,
+                crossref_funder_id: None,
+                isni: None,
+                ringgold: None,
             },
         };
 
@@ -2243,6 +2413,23 @@ mod tests {
         assert!(!output.contains(r#"      <IDValue>Number of grant</IDValue>"#));
         assert!(!output.contains(r#"      <IDTypeName>jurisdiction</IDTypeName>"#));
         assert!(!output.contains(r#"      <IDValue>Republic of Moldova</IDValue>"#));
+
+        // A Crossref Funder ID takes precedence over the institution DOI
+        test_funding.institution.institution_doi =
+            Some(Doi::from_str("https://doi.org/10.00001/INSTITUTION.0001").unwrap());
+        test_funding.institution.crossref_funder_id =
+            Some(FunderId::from_str("501100000780").unwrap());
+        test_funding.institution.isni = Some(Isni::from_str("000000012146438X").unwrap());
+        test_funding.institution.ringgold = Some(Ringgold::from_str("6396").unwrap());
+        let output = generate_test_output(true, &test_funding);
+        assert!(output.contains(r#"    <PublisherIDType>32</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDValue>10.13039/501100000780</IDValue>"#));
+        assert!(!output.contains(r#"    <IDValue>10.00001/INSTITUTION.0001</IDValue>"#));
+        assert!(output.contains(r#"    <PublisherIDType>16</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDValue>000000012146438X</IDValue>"#));
+        assert!(output.contains(r#"    <PublisherIDType>01</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDTypeName>Ringgold</IDTypeName>"#));
+        assert!(output.contains(r#"    <IDValue>6396</IDValue>"#));
     }
 
     #[test]
@@ -2321,12 +2508,15 @@ mod tests {
                 copyright_holder: None,
                 short_abstract: None,
                 long_abstract: None,
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: None,
                 first_page: None,
                 last_page: None,
                 page_count: None,
                 page_interval: None,
+                article_number: None,
                 landing_page: None,
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: None,
@@ -2410,6 +2600,8 @@ mod tests {
             long_abstract: Some(
                 "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
             ),
+            titles: vec![],
+            abstracts: vec![],
             general_note: Some("This is a general note".to_string()),
             bibliography_note: Some("This is a bibliography note".to_string()),
             place: Some("León, Spain".to_string()),
@@ -2418,6 +2610,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: Some(20),
             audio_count: Some(25),
@@ -2460,14 +2653,44 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 8.0,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                 ],
                 locations: vec![
@@ -2485,6 +2708,27 @@ mod tests {
                         canonical: false,
                     },
                 ],
+                identifiers: vec![],
+                sales_rights: vec![],
+                accessibility_features: vec![],
+                accessibility_hazards: vec![],
+                accessibility_standard: None,
+                wcag_version: None,
+                wcag_level: None,
+                accessibility_certifier: None,
+                accessibility_summary: None,
+                binding_style: None,
+                paper_type: None,
+                interior_colour: None,
+                trim_category: None,
+                page_count: None,
+                cover_finish: None,
+                carton_quantity: None,
+                format_version: None,
+                file_size_kb: None,
+                technical_protections: vec![],
+                layout: None,
+                usage_constraints: vec![],
             }],
             subjects: vec![
                 WorkSubjects {
@@ -2524,6 +2768,10 @@ mod tests {
                 },
             ],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![
                 WorkRelations {
                     relation_type: RelationType::HAS_CHILD,
@@ -2548,12 +2796,15 @@ mod tests {
                             "This is a chapter's somewhat longer abstract. It has two sentences."
                                 .to_string(),
                         ),
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: Some("This is a chapter general note.".to_string()),
                         place: None,
                         first_page: Some("10".to_string()),
                         last_page: Some("20".to_string()),
                         page_count: Some(11),
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2635,12 +2886,15 @@ mod tests {
                         copyright_holder: None,
                         short_abstract: None,
                         long_abstract: None,
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: None,
                         place: None,
                         first_page: None,
                         last_page: None,
                         page_count: None,
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2671,12 +2925,15 @@ mod tests {
                         copyright_holder: None,
                         short_abstract: None,
                         long_abstract: None,
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: None,
                         place: None,
                         first_page: None,
                         last_page: None,
                         page_count: None,
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2714,6 +2971,7 @@ mod tests {
                 publication_date: None,
                 retrieval_date: None,
             }],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -3444,6 +3702,26 @@ mod tests {
         ));
         test_work.publications[0].publication_type = PublicationType::PAPERBACK;
 
+        // Chapters without a licence of their own carry the book's licence, in its canonical form
+        test_work.license = Some("http://creativecommons.org/licenses/by/4.0/deed.en".to_string());
+        test_work.relations[0].related_work.license = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+      <EpubLicense>
+        <EpubLicenseName>Creative Commons Attribution 4.0 International license (CC BY 4.0).</EpubLicenseName>
+        <EpubLicenseExpression>
+          <EpubLicenseExpressionType>02</EpubLicenseExpressionType>
+          <EpubLicenseExpressionLink>https://creativecommons.org/licenses/by/4.0/</EpubLicenseExpressionLink>
+        </EpubLicenseExpression>
+      </EpubLicense>"#
+        ));
+        assert!(!output.contains("CC BY-SA 4.0"));
+        assert!(!output.contains("deed.en"));
+        test_work.license = Some("https://creativecommons.org/licenses/by/4.0/".to_string());
+        test_work.relations[0].related_work.license =
+            Some("https://creativecommons.org/licenses/by-sa/4.0/".to_string());
+
         // Remove/change some values to test (non-)output of optional blocks
         test_work.doi = None;
         test_work.lccn = None;
@@ -3863,6 +4141,27 @@ mod tests {
             weight_oz: None,
             prices: vec![],
             locations: vec![],
+            identifiers: vec![],
+            sales_rights: vec![],
+            accessibility_features: vec![],
+            accessibility_hazards: vec![],
+            accessibility_standard: None,
+            wcag_version: None,
+            wcag_level: None,
+            accessibility_certifier: None,
+            accessibility_summary: None,
+            binding_style: None,
+            paper_type: None,
+            interior_colour: None,
+            trim_category: None,
+            page_count: None,
+            cover_finish: None,
+            carton_quantity: None,
+            format_version: None,
+            file_size_kb: None,
+            technical_protections: vec![],
+            layout: None,
+            usage_constraints: vec![],
         });
         let output = generate_test_output(true, &test_work);
         println!("{output}");
@@ -1,12 +1,17 @@
 use chrono::Utc;
 use std::io::Write;
 use thoth_client::{
-    ContributionType, CurrencyCode, LanguageRelation, PublicationType, SubjectType, Work,
-    WorkContributions, WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkType,
+    AbstractType, ContributionType, CurrencyCode, LanguageRelation, PublicationType, SubjectType,
+    Work, WorkContributions, WorkIssues, WorkLanguages, WorkPublications, WorkStatus,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_price, write_onix3_product_form_description, write_onix3_sales_rights,
+    write_rich_text_element, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -51,9 +56,9 @@ impl XmlSpecification for Onix3GoogleBooks {
                 [work] => XmlElementBlock::<Onix3GoogleBooks>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix3GoogleBooks>::xml_element(work, w).ok();
                         }
                     }
@@ -159,36 +164,56 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                         w.write(XmlEvent::Characters(digital_type))
                             .map_err(|e| e.into())
                     })?;
+                    write_onix3_digital_form_details(main_publication, w)?;
+                    write_onix3_accessibility(main_publication, w)?;
+                    write_onix3_product_form_description(main_publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
                     })?;
+                    write_onix3_epub_usage(main_publication, w)?;
                     for issue in &self.issues {
                         XmlElementBlock::<Onix3GoogleBooks>::xml_element(issue, w).ok();
                     }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.title))
+                    for title in self.localised_titles() {
+                        let language = language_attr(&title.language_code);
+                        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+                        write_element_block("TitleDetail", w, |w| {
+                            // 01 Distinctive title (book), 06 Title in other language
+                            let title_type = if title.canonical { "01" } else { "06" };
+                            write_element_block("TitleType", w, |w| {
+                                w.write(XmlEvent::Characters(title_type))
                                     .map_err(|e| e.into())
                             })?;
-                            if let Some(subtitle) = &self.subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
+                            write_element_block("TitleElement", w, |w| {
+                                // 01 Product
+                                write_element_block("TitleElementLevel", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
                                 })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
+                                write_full_element_block(
+                                    "TitleText",
+                                    language_attr.clone(),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(title.title))
+                                            .map_err(|e| e.into())
+                                    },
+                                )?;
+                                if let Some(subtitle) = title.subtitle {
+                                    write_full_element_block(
+                                        "Subtitle",
+                                        language_attr.clone(),
+                                        w,
+                                        |w| {
+                                            w.write(XmlEvent::Characters(subtitle))
+                                                .map_err(|e| e.into())
+                                        },
+                                    )?;
+                                }
+                                Ok(())
+                            })
+                        })?;
+                    }
                     // Google Books requires at least one contributor coded as A01 (Author) -
                     // if this is e.g. a wholly edited book, code the first main contributor as an author.
                     let mut contributions = self.contributions.clone();
@@ -235,6 +260,7 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(main_publication, w)?;
                     for subject in &self.subjects {
                         // Google Books doesn't support Thema codes
                         if subject.subject_type != SubjectType::THEMA {
@@ -261,9 +287,13 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                         })
                     })
                 })?;
-                if self.long_abstract.is_some() || self.toc.is_some() {
+                if self.long_abstract.is_some() || !self.abstracts.is_empty() || self.toc.is_some()
+                {
                     write_element_block("CollateralDetail", w, |w| {
-                        if let Some(labstract) = &self.long_abstract {
+                        for labstract in self.localised_abstracts(AbstractType::LONG) {
+                            // Abstracts without a recorded language are assumed to be in English
+                            let language = language_attr(&labstract.language_code)
+                                .unwrap_or_else(|| "eng".to_string());
                             write_element_block("TextContent", w, |w| {
                                 // 03 Description ("30 Abstract" not implemented in Google Books)
                                 write_element_block("TextType", w, |w| {
@@ -273,14 +303,11 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                                 write_element_block("ContentAudience", w, |w| {
                                     w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                 })?;
-                                write_full_element_block(
+                                write_rich_text_element(
                                     "Text",
-                                    Some(vec![("language", "eng")]),
+                                    vec![("language", language.as_str())],
+                                    labstract.content,
                                     w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(labstract))
-                                            .map_err(|e| e.into())
-                                    },
                                 )
                             })?;
                         }
@@ -358,17 +385,7 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                             )
                         })?;
                     }
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })
+                    write_onix3_sales_rights(&main_publication.sales_rights, w)
                 })?;
                 if !isbns.is_empty() {
                     write_element_block("RelatedMaterial", w, |w| {
@@ -421,34 +438,11 @@ impl XmlElementBlock<Onix3GoogleBooks> for Work {
                         // Assume that the GBP price is the canonical one, currency conversion is
                         // turned on (a Google Books account setting which cannot be specified in the ONIX),
                         // and all other prices will be automatically derived from the GBP price.
-                        if let Some(price) = main_publication
-                            .prices
-                            .iter()
-                            .find(|pr| {
-                                // Thoth database only accepts non-zero prices
-                                pr.currency_code.eq(&CurrencyCode::GBP)
-                            })
-                            .map(|pr| pr.unit_price)
-                        {
-                            let formatted_price = format!("{price:.2}");
-                            write_element_block("Price", w, |w| {
-                                // 02 RRP including tax
-                                write_element_block("PriceType", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("PriceAmount", w, |w| {
-                                    w.write(XmlEvent::Characters(&formatted_price))
-                                        .map_err(|e| e.into())
-                                })?;
-                                write_element_block("CurrencyCode", w, |w| {
-                                    w.write(XmlEvent::Characters("GBP")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("Territory", w, |w| {
-                                    write_element_block("RegionsIncluded", w, |w| {
-                                        w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                                    })
-                                })
-                            })
+                        if let Some(price) = main_publication.prices.iter().find(|pr| {
+                            // Thoth database only accepts non-zero prices
+                            pr.currency_code.eq(&CurrencyCode::GBP)
+                        }) {
+                            write_onix3_price(price, &self.imprint.publisher.publisher_name, w)
                         } else {
                             // 01 Free of charge (this is the only UnpricedItemType code supported by Google Books)
                             write_element_block("UnpricedItemType", w, |w| {
@@ -581,10 +575,7 @@ impl XmlElementBlock<Onix3GoogleBooks> for WorkContributions {
                     .map_err(|e| e.into())
             })?;
             if let Some(biography) = &self.biography {
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography))
-                        .map_err(|e| e.into())
-                })?;
+                write_rich_text_element("BiographicalNote", vec![], biography, w)?;
             }
             Ok(())
         })
@@ -639,7 +630,7 @@ impl XmlElementBlock<Onix3GoogleBooks> for WorkIssues {
                         w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
                     })?;
                     write_element_block("PartNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
+                        w.write(XmlEvent::Characters(&self.numbering()))
                             .map_err(|e| e.into())
                     })?;
                     write_element_block("TitleText", w, |w| {
@@ -662,9 +653,10 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, LanguageCode, LanguageRelation, LocationPlatform, PublicationType,
-        WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssuesSeries,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkSubjects, WorkType,
+        ContributionType, LanguageCode, LanguageRelation, LocationPlatform, PriceType,
+        PublicationType, WorkContributionsContributor, WorkImprint, WorkImprintPublisher,
+        WorkIssuesSeries, WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus,
+        WorkSubjects, WorkType,
     };
     use uuid::Uuid;
 
@@ -705,6 +697,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -789,6 +786,9 @@ mod tests {
     fn test_onix3_google_books_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -798,6 +798,8 @@ mod tests {
                 series_url: None,
                 series_description: None,
                 series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -851,6 +853,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -859,6 +863,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -892,6 +897,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -906,6 +916,11 @@ mod tests {
                     contributor: WorkContributionsContributor {
                         orcid: None,
                         website: None,
+                        isni: None,
+                        viaf: None,
+                        wikidata: None,
+                        lcnaf: None,
+                        alternative_names: vec![],
                     },
                     affiliations: vec![],
                 },
@@ -930,14 +945,44 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 7.99,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 5.0,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                 ],
                 locations: vec![WorkPublicationsLocations {
@@ -946,6 +991,27 @@ mod tests {
                     location_platform: LocationPlatform::OTHER,
                     canonical: true,
                 }],
+                identifiers: vec![],
+                sales_rights: vec![],
+                accessibility_features: vec![],
+                accessibility_hazards: vec![],
+                accessibility_standard: None,
+                wcag_version: None,
+                wcag_level: None,
+                accessibility_certifier: None,
+                accessibility_summary: None,
+                binding_style: None,
+                paper_type: None,
+                interior_colour: None,
+                trim_category: None,
+                page_count: None,
+                cover_finish: None,
+                carton_quantity: None,
+                format_version: None,
+                file_size_kb: None,
+                technical_protections: vec![],
+                layout: None,
+                usage_constraints: vec![],
             }],
             subjects: vec![
                 WorkSubjects {
@@ -980,8 +1046,13 @@ mod tests {
                 },
             ],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1,13 +1,19 @@
 use chrono::Utc;
 use std::collections::HashMap;
 use std::io::Write;
+use thoth_api::model::license::is_open_access;
 use thoth_client::{
-    ContributionType, LanguageRelation, PublicationType, SubjectType, Work, WorkContributions,
-    WorkLanguages, WorkPublications, WorkStatus, WorkType,
+    AbstractType, ContributionType, LanguageRelation, PublicationType, SubjectType, Work,
+    WorkContributions, WorkLanguages, WorkPublications, WorkStatus,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_price, write_onix3_product_form_description, write_rich_text_element, XmlElement,
+    XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -45,9 +51,9 @@ impl XmlSpecification for Onix3Jstor {
                 [work] => XmlElementBlock::<Onix3Jstor>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix3Jstor>::xml_element(work, w).ok();
                         }
                     }
@@ -73,15 +79,20 @@ impl XmlElementBlock<Onix3Jstor> for Work {
             ));
         }
         // We can only generate the document if there's a PDF
-        if let Some(pdf_url) = self
+        if let Some((pdf_publication, pdf_url)) = self
             .publications
             .iter()
             .find(|p| p.publication_type.eq(&PublicationType::PDF) && !p.locations.is_empty())
-            .and_then(|p| p.locations.iter().find(|l| l.canonical))
-            .and_then(|l| l.full_text_url.as_ref())
+            .and_then(|p| {
+                p.locations
+                    .iter()
+                    .find(|l| l.canonical)
+                    .and_then(|l| l.full_text_url.as_ref())
+                    .map(|url| (p, url))
+            })
         {
             let work_id = format!("urn:uuid:{}", self.work_id);
-            let is_open_access = self.license.is_some();
+            let is_open_access = is_open_access(self.license.as_deref());
             let (main_isbn, print_isbn) = get_publications_data(&self.publications);
             write_element_block("Product", w, |w| {
                 write_element_block("RecordReference", w, |w| {
@@ -140,10 +151,14 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                     write_element_block("ProductFormDetail", w, |w| {
                         w.write(XmlEvent::Characters("E107")).map_err(|e| e.into())
                     })?;
+                    write_onix3_digital_form_details(pdf_publication, w)?;
+                    write_onix3_accessibility(pdf_publication, w)?;
+                    write_onix3_product_form_description(pdf_publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
                     })?;
+                    write_onix3_epub_usage(pdf_publication, w)?;
                     if let Some(license) = &self.license {
                         write_element_block("EpubLicense", w, |w| {
                             write_element_block("EpubLicenseName", w, |w| {
@@ -160,29 +175,45 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                             })
                         })?;
                     }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.title))
+                    for title in self.localised_titles() {
+                        let language = language_attr(&title.language_code);
+                        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+                        write_element_block("TitleDetail", w, |w| {
+                            // 01 Distinctive title (book), 06 Title in other language
+                            let title_type = if title.canonical { "01" } else { "06" };
+                            write_element_block("TitleType", w, |w| {
+                                w.write(XmlEvent::Characters(title_type))
                                     .map_err(|e| e.into())
                             })?;
-                            if let Some(subtitle) = &self.subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
+                            write_element_block("TitleElement", w, |w| {
+                                // 01 Product
+                                write_element_block("TitleElementLevel", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
                                 })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
+                                write_full_element_block(
+                                    "TitleText",
+                                    language_attr.clone(),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(title.title))
+                                            .map_err(|e| e.into())
+                                    },
+                                )?;
+                                if let Some(subtitle) = title.subtitle {
+                                    write_full_element_block(
+                                        "Subtitle",
+                                        language_attr.clone(),
+                                        w,
+                                        |w| {
+                                            w.write(XmlEvent::Characters(subtitle))
+                                                .map_err(|e| e.into())
+                                        },
+                                    )?;
+                                }
+                                Ok(())
+                            })
+                        })?;
+                    }
                     for contribution in &self.contributions {
                         XmlElementBlock::<Onix3Jstor>::xml_element(contribution, w).ok();
                     }
@@ -205,6 +236,7 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(pdf_publication, w)?;
                     for subject in &self.subjects {
                         write_element_block("Subject", w, |w| {
                             XmlElement::<Onix3Jstor>::xml_element(&subject.subject_type, w)?;
@@ -216,9 +248,16 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                     }
                     Ok(())
                 })?;
-                if self.long_abstract.is_some() || self.toc.is_some() || is_open_access {
+                if self.long_abstract.is_some()
+                    || !self.abstracts.is_empty()
+                    || self.toc.is_some()
+                    || is_open_access
+                {
                     write_element_block("CollateralDetail", w, |w| {
-                        if let Some(labstract) = &self.long_abstract {
+                        for labstract in self.localised_abstracts(AbstractType::LONG) {
+                            // Abstracts without a recorded language are assumed to be in English
+                            let language = language_attr(&labstract.language_code)
+                                .unwrap_or_else(|| "eng".to_string());
                             write_element_block("TextContent", w, |w| {
                                 // 03 Description ("30 Abstract" not implemented in OAPEN)
                                 write_element_block("TextType", w, |w| {
@@ -228,14 +267,11 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                                 write_element_block("ContentAudience", w, |w| {
                                     w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                 })?;
-                                write_full_element_block(
+                                write_rich_text_element(
                                     "Text",
-                                    Some(vec![("language", "eng")]),
+                                    vec![("language", language.as_str())],
+                                    labstract.content,
                                     w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(labstract))
-                                            .map_err(|e| e.into())
-                                    },
                                 )
                             })?;
                         }
@@ -421,31 +457,11 @@ impl XmlElementBlock<Onix3Jstor> for Work {
                                 })
                             } else {
                                 for price in prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(|e| e.into())
-                                            })
-                                        })
-                                    })?;
+                                    write_onix3_price(
+                                        &price,
+                                        &self.imprint.publisher.publisher_name,
+                                        w,
+                                    )?;
                                 }
                                 Ok(())
                             }
@@ -625,7 +641,7 @@ mod tests {
     use thoth_api::model::Orcid;
     use thoth_client::{
         ContributionType, CurrencyCode, LanguageCode, LanguageRelation, LocationPlatform,
-        PublicationType, WorkContributionsContributor, WorkFundings, WorkImprint,
+        PriceType, PublicationType, WorkContributionsContributor, WorkFundings, WorkImprint,
         WorkImprintPublisher, WorkIssues, WorkIssuesSeries, WorkPublicationsLocations,
         WorkPublicationsPrices, WorkStatus, WorkSubjects, WorkType,
     };
@@ -665,6 +681,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -774,6 +795,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -782,6 +805,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -804,6 +828,9 @@ mod tests {
             },
             issues: vec![WorkIssues {
                 issue_ordinal: 1,
+                volume: None,
+                issue_number: None,
+                part: None,
                 series: WorkIssuesSeries {
                     series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                     series_type: thoth_client::SeriesType::JOURNAL,
@@ -813,6 +840,8 @@ mod tests {
                     series_url: None,
                     series_description: None,
                     series_cfp_url: None,
+                    parent_series: None,
+                    contributions: vec![],
                 },
             }],
             contributions: vec![],
@@ -835,6 +864,27 @@ mod tests {
                     weight_oz: None,
                     prices: vec![],
                     locations: vec![],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
                 WorkPublications {
                     publication_id: Uuid::from_str("00000000-0000-0000-DDDD-000000000004").unwrap(),
@@ -855,10 +905,30 @@ mod tests {
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::EUR,
                             unit_price: 5.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                         WorkPublicationsPrices {
                             currency_code: CurrencyCode::GBP,
                             unit_price: 4.95,
+                            price_type: PriceType::RRP_INCLUDING_TAX,
+                            price_qualifier: None,
+                            countries_included: vec![],
+                            countries_excluded: vec![],
+                            regions_included: vec![],
+                            regions_excluded: vec![],
+                            tax_rate_percent: None,
+                            discount_code: None,
+                            price_effective_from: None,
+                            price_effective_until: None,
                         },
                     ],
                     locations: vec![WorkPublicationsLocations {
@@ -867,6 +937,27 @@ mod tests {
                         location_platform: LocationPlatform::OTHER,
                         canonical: true,
                     }],
+                    identifiers: vec![],
+                    sales_rights: vec![],
+                    accessibility_features: vec![],
+                    accessibility_hazards: vec![],
+                    accessibility_standard: None,
+                    wcag_version: None,
+                    wcag_level: None,
+                    accessibility_certifier: None,
+                    accessibility_summary: None,
+                    binding_style: None,
+                    paper_type: None,
+                    interior_colour: None,
+                    trim_category: None,
+                    page_count: None,
+                    cover_finish: None,
+                    carton_quantity: None,
+                    format_version: None,
+                    file_size_kb: None,
+                    technical_protections: vec![],
+                    layout: None,
+                    usage_constraints: vec![],
                 },
             ],
             subjects: vec![
@@ -912,10 +1003,18 @@ mod tests {
                     institution_doi: None,
                     ror: None,
                     country_code: None,
+                    crossref_funder_id: None,
+                    isni: None,
+                    ringgold: None,
                 },
             }],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
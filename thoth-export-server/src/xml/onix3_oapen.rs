@@ -1,13 +1,21 @@
 use chrono::Utc;
 use std::collections::HashMap;
 use std::io::Write;
+use std::str::FromStr;
+use thoth_api::model::license::License;
 use thoth_client::{
-    ContributionType, LanguageRelation, PublicationType, SubjectType, Work, WorkContributions,
-    WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkStatus, WorkSubjects, WorkType,
+    AbstractType, ContributionType, LanguageRelation, PublicationType, SubjectType, Work,
+    WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkStatus,
+    WorkSubjects,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_product_form_description, write_onix3_supporting_resources,
+    write_rich_text_element, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -45,9 +53,9 @@ impl XmlSpecification for Onix3Oapen {
                 [work] => XmlElementBlock::<Onix3Oapen>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix3Oapen>::xml_element(work, w).ok();
                         }
                     }
@@ -61,19 +69,34 @@ impl XmlSpecification for Onix3Oapen {
 impl XmlElementBlock<Onix3Oapen> for Work {
     fn xml_element<W: Write>(&self, w: &mut EventWriter<W>) -> ThothResult<()> {
         // Don't output works with no licence, as we assume these are non-OA
-        if self.license.is_none() {
-            return Err(ThothError::IncompleteMetadataRecord(
-                ONIX_ERROR.to_string(),
-                "Missing License".to_string(),
-            ));
-        }
+        let license = match self.license.as_deref().map(License::from_str) {
+            Some(Ok(license)) if license.is_registered() => license,
+            Some(_) => {
+                // OAPEN needs to know exactly which open licence applies
+                return Err(ThothError::IncompleteMetadataRecord(
+                    ONIX_ERROR.to_string(),
+                    "Unrecognised License".to_string(),
+                ));
+            }
+            None => {
+                return Err(ThothError::IncompleteMetadataRecord(
+                    ONIX_ERROR.to_string(),
+                    "Missing License".to_string(),
+                ));
+            }
+        };
         // We can only generate the document if there's a PDF
-        if let Some(pdf_url) = self
+        if let Some((pdf_publication, pdf_url)) = self
             .publications
             .iter()
             .find(|p| p.publication_type.eq(&PublicationType::PDF) && !p.locations.is_empty())
-            .and_then(|p| p.locations.iter().find(|l| l.canonical))
-            .and_then(|l| l.full_text_url.as_ref())
+            .and_then(|p| {
+                p.locations
+                    .iter()
+                    .find(|l| l.canonical)
+                    .and_then(|l| l.full_text_url.as_ref())
+                    .map(|url| (p, url))
+            })
         {
             let work_id = format!("urn:uuid:{}", self.work_id);
             let (main_isbn, isbns) = get_publications_data(&self.publications);
@@ -134,13 +157,17 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                     write_element_block("ProductFormDetail", w, |w| {
                         w.write(XmlEvent::Characters("E107")).map_err(|e| e.into())
                     })?;
+                    write_onix3_digital_form_details(pdf_publication, w)?;
+                    write_onix3_accessibility(pdf_publication, w)?;
+                    write_onix3_product_form_description(pdf_publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
                     })?;
+                    write_onix3_epub_usage(pdf_publication, w)?;
                     write_element_block("EpubLicense", w, |w| {
                         write_element_block("EpubLicenseName", w, |w| {
-                            w.write(XmlEvent::Characters("Creative Commons License"))
+                            w.write(XmlEvent::Characters(&license.description()))
                                 .map_err(|e| e.into())
                         })?;
                         write_element_block("EpubLicenseExpression", w, |w| {
@@ -148,7 +175,7 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                                 w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
                             })?;
                             write_element_block("EpubLicenseExpressionLink", w, |w| {
-                                w.write(XmlEvent::Characters(self.license.as_ref().unwrap()))
+                                w.write(XmlEvent::Characters(&license.url()))
                                     .map_err(|e| e.into())
                             })
                         })
@@ -156,29 +183,45 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                     for issue in &self.issues {
                         XmlElementBlock::<Onix3Oapen>::xml_element(issue, w).ok();
                     }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.title))
+                    for title in self.localised_titles() {
+                        let language = language_attr(&title.language_code);
+                        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+                        write_element_block("TitleDetail", w, |w| {
+                            // 01 Distinctive title (book), 06 Title in other language
+                            let title_type = if title.canonical { "01" } else { "06" };
+                            write_element_block("TitleType", w, |w| {
+                                w.write(XmlEvent::Characters(title_type))
                                     .map_err(|e| e.into())
                             })?;
-                            if let Some(subtitle) = &self.subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
+                            write_element_block("TitleElement", w, |w| {
+                                // 01 Product
+                                write_element_block("TitleElementLevel", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
                                 })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
+                                write_full_element_block(
+                                    "TitleText",
+                                    language_attr.clone(),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(title.title))
+                                            .map_err(|e| e.into())
+                                    },
+                                )?;
+                                if let Some(subtitle) = title.subtitle {
+                                    write_full_element_block(
+                                        "Subtitle",
+                                        language_attr.clone(),
+                                        w,
+                                        |w| {
+                                            w.write(XmlEvent::Characters(subtitle))
+                                                .map_err(|e| e.into())
+                                        },
+                                    )?;
+                                }
+                                Ok(())
+                            })
+                        })?;
+                    }
                     for contribution in &self.contributions {
                         XmlElementBlock::<Onix3Oapen>::xml_element(contribution, w).ok();
                     }
@@ -201,6 +244,7 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(pdf_publication, w)?;
                     for subject in &self.subjects {
                         XmlElementBlock::<Onix3Oapen>::xml_element(subject, w).ok();
                     }
@@ -215,9 +259,16 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                         })
                     })
                 })?;
-                if self.long_abstract.is_some() || self.cover_url.is_some() {
+                if self.long_abstract.is_some()
+                    || !self.abstracts.is_empty()
+                    || self.cover_url.is_some()
+                    || !self.supporting_resources.is_empty()
+                {
                     write_element_block("CollateralDetail", w, |w| {
-                        if let Some(labstract) = &self.long_abstract {
+                        for labstract in self.localised_abstracts(AbstractType::LONG) {
+                            // Abstracts without a recorded language are assumed to be in English
+                            let language = language_attr(&labstract.language_code)
+                                .unwrap_or_else(|| "eng".to_string());
                             write_element_block("TextContent", w, |w| {
                                 // 03 Description ("30 Abstract" not implemented in OAPEN)
                                 write_element_block("TextType", w, |w| {
@@ -227,43 +278,15 @@ impl XmlElementBlock<Onix3Oapen> for Work {
                                 write_element_block("ContentAudience", w, |w| {
                                     w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                 })?;
-                                write_full_element_block(
+                                write_rich_text_element(
                                     "Text",
-                                    Some(vec![("language", "eng")]),
+                                    vec![("language", language.as_str())],
+                                    labstract.content,
                                     w,
-                                    |w| {
-                                        w.write(XmlEvent::Characters(labstract))
-                                            .map_err(|e| e.into())
-                                    },
                                 )
                             })?;
                         }
-                        if let Some(cover_url) = &self.cover_url {
-                            write_element_block("SupportingResource", w, |w| {
-                                // 01 Front cover
-                                write_element_block("ResourceContentType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                // 03 Image
-                                write_element_block("ResourceMode", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("ResourceVersion", w, |w| {
-                                    // 02 Downloadable file
-                                    write_element_block("ResourceForm", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("ResourceLink", w, |w| {
-                                        w.write(XmlEvent::Characters(cover_url))
-                                            .map_err(|e| e.into())
-                                    })
-                                })
-                            })?;
-                        }
+                        write_onix3_supporting_resources(self, w)?;
                         Ok(())
                     })?;
                 }
@@ -587,7 +610,7 @@ impl XmlElementBlock<Onix3Oapen> for WorkIssues {
                         w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
                     })?;
                     write_element_block("PartNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
+                        w.write(XmlEvent::Characters(&self.numbering()))
                             .map_err(|e| e.into())
                     })?;
                     write_element_block("TitleText", w, |w| {
@@ -716,6 +739,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -801,6 +829,9 @@ mod tests {
     fn test_onix3_oapen_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -810,6 +841,8 @@ mod tests {
                 series_url: None,
                 series_description: None,
                 series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -851,6 +884,9 @@ mod tests {
                 institution_doi: None,
                 ror: None,
                 country_code: None,
+                crossref_funder_id: None,
+                isni: None,
+                ringgold: None,
             },
         };
 
@@ -993,6 +1029,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -1001,6 +1039,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -1046,11 +1085,37 @@ mod tests {
                     location_platform: LocationPlatform::OTHER,
                     canonical: true,
                 }],
+                identifiers: vec![],
+                sales_rights: vec![],
+                accessibility_features: vec![],
+                accessibility_hazards: vec![],
+                accessibility_standard: None,
+                wcag_version: None,
+                wcag_level: None,
+                accessibility_certifier: None,
+                accessibility_summary: None,
+                binding_style: None,
+                paper_type: None,
+                interior_colour: None,
+                trim_category: None,
+                page_count: None,
+                cover_finish: None,
+                carton_quantity: None,
+                format_version: None,
+                file_size_kb: None,
+                technical_protections: vec![],
+                layout: None,
+                usage_constraints: vec![],
             }],
             subjects: vec![],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1075,9 +1140,7 @@ mod tests {
         assert!(output.contains(r#"    <ProductFormDetail>E107</ProductFormDetail>"#));
         assert!(output.contains(r#"    <PrimaryContentType>10</PrimaryContentType>"#));
         assert!(output.contains(r#"    <EpubLicense>"#));
-        assert!(
-            output.contains(r#"      <EpubLicenseName>Creative Commons License</EpubLicenseName>"#)
-        );
+        assert!(output.contains(r#"      <EpubLicenseName>Creative Commons Attribution 4.0 International license (CC BY 4.0).</EpubLicenseName>"#));
         assert!(output.contains(r#"      <EpubLicenseExpression>"#));
         assert!(
             output.contains(r#"        <EpubLicenseExpressionType>02</EpubLicenseExpressionType>"#)
@@ -1246,6 +1309,15 @@ mod tests {
             "Could not generate onix_3.0::oapen: Missing License".to_string()
         );
 
+        // Replace licence with one outside the controlled vocabulary
+        // Result: error (OAPEN requires the exact licence)
+        test_work.license = Some("https://www.book.com/license".to_string());
+        let output = generate_test_output(false, &test_work);
+        assert_eq!(
+            output,
+            "Could not generate onix_3.0::oapen: Unrecognised License".to_string()
+        );
+
         // Replace licence, but remove the only publication, which is the PDF
         // Result: error (can't generate OAPEN ONIX without PDF URL)
         test_work.license = Some("https://creativecommons.org/licenses/by/4.0/".to_string());
@@ -2,13 +2,17 @@ use chrono::Utc;
 use std::collections::HashMap;
 use std::io::Write;
 use thoth_client::{
-    ContributionType, CurrencyCode, LanguageRelation, PublicationType, SubjectType, Work,
-    WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkStatus,
-    WorkType,
+    AbstractType, ContributionType, CurrencyCode, LanguageRelation, PublicationType, SubjectType,
+    Work, WorkContributions, WorkFundings, WorkIssues, WorkLanguages, WorkPublications, WorkStatus,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_price, write_onix3_product_form_description, write_onix3_sales_rights,
+    write_onix3_supporting_resources, write_rich_text_element, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -46,9 +50,9 @@ impl XmlSpecification for Onix3Overdrive {
                 [work] => XmlElementBlock::<Onix3Overdrive>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix3Overdrive>::xml_element(work, w).ok();
                         }
                     }
@@ -169,10 +173,14 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                         w.write(XmlEvent::Characters(digital_type))
                             .map_err(|e| e.into())
                     })?;
+                    write_onix3_digital_form_details(main_publication, w)?;
+                    write_onix3_accessibility(main_publication, w)?;
+                    write_onix3_product_form_description(main_publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
                     })?;
+                    write_onix3_epub_usage(main_publication, w)?;
                     if let Some(license) = &self.license {
                         write_element_block("EpubLicense", w, |w| {
                             write_element_block("EpubLicenseName", w, |w| {
@@ -192,29 +200,45 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                     for issue in &self.issues {
                         XmlElementBlock::<Onix3Overdrive>::xml_element(issue, w).ok();
                     }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.title))
+                    for title in self.localised_titles() {
+                        let language = language_attr(&title.language_code);
+                        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+                        write_element_block("TitleDetail", w, |w| {
+                            // 01 Distinctive title (book), 06 Title in other language
+                            let title_type = if title.canonical { "01" } else { "06" };
+                            write_element_block("TitleType", w, |w| {
+                                w.write(XmlEvent::Characters(title_type))
                                     .map_err(|e| e.into())
                             })?;
-                            if let Some(subtitle) = &self.subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
+                            write_element_block("TitleElement", w, |w| {
+                                // 01 Product
+                                write_element_block("TitleElementLevel", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
                                 })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
+                                write_full_element_block(
+                                    "TitleText",
+                                    language_attr.clone(),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(title.title))
+                                            .map_err(|e| e.into())
+                                    },
+                                )?;
+                                if let Some(subtitle) = title.subtitle {
+                                    write_full_element_block(
+                                        "Subtitle",
+                                        language_attr.clone(),
+                                        w,
+                                        |w| {
+                                            w.write(XmlEvent::Characters(subtitle))
+                                                .map_err(|e| e.into())
+                                        },
+                                    )?;
+                                }
+                                Ok(())
+                            })
+                        })?;
+                    }
                     for contribution in &self.contributions {
                         XmlElementBlock::<Onix3Overdrive>::xml_element(contribution, w).ok();
                     }
@@ -237,6 +261,7 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(main_publication, w)?;
                     for subject in &self.subjects {
                         write_element_block("Subject", w, |w| {
                             XmlElement::<Onix3Overdrive>::xml_element(&subject.subject_type, w)?;
@@ -258,64 +283,46 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                     })
                 })?;
                 write_element_block("CollateralDetail", w, |w| {
-                    write_element_block("TextContent", w, |w| {
-                        // 03 Description ("30 Abstract" not implemented in OverDrive)
-                        write_element_block("TextType", w, |w| {
-                            w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                        })?;
-                        // 00 Unrestricted
-                        write_element_block("ContentAudience", w, |w| {
-                            w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                        })?;
-                        write_full_element_block("Text", Some(vec![("language", "eng")]), w, |w| {
-                            w.write(XmlEvent::Characters(self.long_abstract.as_ref().unwrap()))
-                                .map_err(|e| e.into())
-                        })
-                    })?;
-                    if let Some(toc) = &self.toc {
+                    for labstract in self.localised_abstracts(AbstractType::LONG) {
+                        // Abstracts without a recorded language are assumed to be in English
+                        let language = language_attr(&labstract.language_code)
+                            .unwrap_or_else(|| "eng".to_string());
                         write_element_block("TextContent", w, |w| {
-                            // 04 Table of contents
+                            // 03 Description ("30 Abstract" not implemented in OverDrive)
                             write_element_block("TextType", w, |w| {
-                                w.write(XmlEvent::Characters("04")).map_err(|e| e.into())
+                                w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
                             })?;
                             // 00 Unrestricted
                             write_element_block("ContentAudience", w, |w| {
                                 w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                             })?;
-                            write_full_element_block(
+                            write_rich_text_element(
                                 "Text",
-                                Some(vec![("language", "eng")]),
+                                vec![("language", language.as_str())],
+                                labstract.content,
                                 w,
-                                |w| w.write(XmlEvent::Characters(toc)).map_err(|e| e.into()),
                             )
                         })?;
                     }
-                    if let Some(cover_url) = &self.cover_url {
-                        write_element_block("SupportingResource", w, |w| {
-                            // 01 Front cover
-                            write_element_block("ResourceContentType", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+                    if let Some(toc) = &self.toc {
+                        write_element_block("TextContent", w, |w| {
+                            // 04 Table of contents
+                            write_element_block("TextType", w, |w| {
+                                w.write(XmlEvent::Characters("04")).map_err(|e| e.into())
                             })?;
                             // 00 Unrestricted
                             write_element_block("ContentAudience", w, |w| {
                                 w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                             })?;
-                            // 03 Image
-                            write_element_block("ResourceMode", w, |w| {
-                                w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("ResourceVersion", w, |w| {
-                                // 02 Downloadable file
-                                write_element_block("ResourceForm", w, |w| {
-                                    w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                })?;
-                                write_element_block("ResourceLink", w, |w| {
-                                    w.write(XmlEvent::Characters(cover_url))
-                                        .map_err(|e| e.into())
-                                })
-                            })
+                            write_full_element_block(
+                                "Text",
+                                Some(vec![("language", "eng")]),
+                                w,
+                                |w| w.write(XmlEvent::Characters(toc)).map_err(|e| e.into()),
+                            )
                         })?;
                     }
+                    write_onix3_supporting_resources(self, w)?;
                     Ok(())
                 })?;
                 write_element_block("PublishingDetail", w, |w| {
@@ -377,17 +384,7 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                             )
                         })?;
                     }
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })
+                    write_onix3_sales_rights(&main_publication.sales_rights, w)
                 })?;
                 if !isbns.is_empty() {
                     write_element_block("RelatedMaterial", w, |w| {
@@ -497,35 +494,11 @@ impl XmlElementBlock<Onix3Overdrive> for Work {
                                 )
                             })?;
                             // Price element is required for OverDrive. Assume the USD price is canonical.
-                            if let Some(price) = main_publication
-                                .prices
-                                .iter()
-                                .find(|pr| {
-                                    // Thoth database only accepts non-zero prices
-                                    pr.currency_code.eq(&CurrencyCode::USD)
-                                })
-                                .map(|pr| pr.unit_price)
-                            {
-                                let formatted_price = format!("{price:.2}");
-                                write_element_block("Price", w, |w| {
-                                    // 02 RRP including tax
-                                    write_element_block("PriceType", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("PriceAmount", w, |w| {
-                                        w.write(XmlEvent::Characters(&formatted_price))
-                                            .map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("CurrencyCode", w, |w| {
-                                        w.write(XmlEvent::Characters("USD")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("Territory", w, |w| {
-                                        write_element_block("RegionsIncluded", w, |w| {
-                                            w.write(XmlEvent::Characters("WORLD"))
-                                                .map_err(|e| e.into())
-                                        })
-                                    })
-                                })
+                            if let Some(price) = main_publication.prices.iter().find(|pr| {
+                                // Thoth database only accepts non-zero prices
+                                pr.currency_code.eq(&CurrencyCode::USD)
+                            }) {
+                                write_onix3_price(price, &self.imprint.publisher.publisher_name, w)
                             } else {
                                 Err(ThothError::IncompleteMetadataRecord(
                                     ONIX_ERROR.to_string(),
@@ -734,7 +707,7 @@ impl XmlElementBlock<Onix3Overdrive> for WorkIssues {
                         w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
                     })?;
                     write_element_block("PartNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
+                        w.write(XmlEvent::Characters(&self.numbering()))
                             .map_err(|e| e.into())
                     })?;
                     write_element_block("TitleText", w, |w| {
@@ -800,9 +773,11 @@ mod tests {
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_client::{
-        ContributionType, LanguageCode, LanguageRelation, LocationPlatform, PublicationType,
+        ContributionType, CountryCode, LanguageCode, LanguageRelation, LocationPlatform,
+        PriceQualifier, PriceType, PublicationType, SalesRestrictionType, SalesRightsType,
         WorkContributionsContributor, WorkImprint, WorkImprintPublisher, WorkIssuesSeries,
-        WorkPublicationsLocations, WorkPublicationsPrices, WorkStatus, WorkSubjects, WorkType,
+        WorkPublicationsLocations, WorkPublicationsPrices, WorkPublicationsSalesRights, WorkStatus,
+        WorkSubjects, WorkType,
     };
     use uuid::Uuid;
 
@@ -843,6 +818,11 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: None,
+                isni: None,
+                viaf: None,
+                wikidata: None,
+                lcnaf: None,
+                alternative_names: vec![],
             },
             affiliations: vec![],
         };
@@ -950,6 +930,9 @@ mod tests {
     fn test_onix3_overdrive_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -959,6 +942,8 @@ mod tests {
                 series_url: None,
                 series_description: None,
                 series_cfp_url: None,
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -1007,6 +992,9 @@ mod tests {
                 institution_doi: None,
                 ror: None,
                 country_code: None,
+                crossref_funder_id: None,
+                isni: None,
+                ringgold: None,
             },
         };
 
@@ -1096,6 +1084,8 @@ mod tests {
             copyright_holder: Some("Author 1; Author 2".to_string()),
             short_abstract: None,
             long_abstract: Some("Lorem ipsum dolor sit amet".to_string()),
+            titles: vec![],
+            abstracts: vec![],
             general_note: None,
             bibliography_note: None,
             place: Some("León, Spain".to_string()),
@@ -1104,6 +1094,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: None,
             table_count: None,
             audio_count: None,
@@ -1150,14 +1141,44 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 8.0,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                 ],
                 locations: vec![WorkPublicationsLocations {
@@ -1166,6 +1187,27 @@ mod tests {
                     location_platform: LocationPlatform::OTHER,
                     canonical: true,
                 }],
+                identifiers: vec![],
+                sales_rights: vec![],
+                accessibility_features: vec![],
+                accessibility_hazards: vec![],
+                accessibility_standard: None,
+                wcag_version: None,
+                wcag_level: None,
+                accessibility_certifier: None,
+                accessibility_summary: None,
+                binding_style: None,
+                paper_type: None,
+                interior_colour: None,
+                trim_category: None,
+                page_count: None,
+                cover_finish: None,
+                carton_quantity: None,
+                format_version: None,
+                file_size_kb: None,
+                technical_protections: vec![],
+                layout: None,
+                usage_constraints: vec![],
             }],
             subjects: vec![
                 WorkSubjects {
@@ -1200,8 +1242,13 @@ mod tests {
                 },
             ],
             fundings: vec![],
+            prizes: vec![],
+            events: vec![],
+            text_contents: vec![],
+            supporting_resources: vec![],
             relations: vec![],
             references: vec![],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -1338,8 +1385,62 @@ mod tests {
         test_work.place = None;
         test_work.landing_page = None;
         test_work.publications[0].publication_type = PublicationType::EPUB;
+        test_work.publications[0].sales_rights = vec![WorkPublicationsSalesRights {
+            sales_rights_type: SalesRightsType::FOR_SALE_WITH_EXCLUSIVE_RIGHTS,
+            countries_included: vec![],
+            countries_excluded: vec![CountryCode::USA, CountryCode::CAN],
+            regions_included: vec!["WORLD".to_string()],
+            regions_excluded: vec![],
+            sales_restriction_type: Some(SalesRestrictionType::RETAILER_EXCLUSIVE),
+            sales_restriction_note: Some("Exclusive to OverDrive".to_string()),
+        }];
+        test_work.publications[0].prices[2] = WorkPublicationsPrices {
+            currency_code: CurrencyCode::USD,
+            unit_price: 8.0,
+            price_type: PriceType::AGENCY_PRICE_INCLUDING_TAX,
+            price_qualifier: Some(PriceQualifier::LIBRARY_PRICE),
+            countries_included: vec![CountryCode::USA],
+            countries_excluded: vec![],
+            regions_included: vec![],
+            regions_excluded: vec![],
+            tax_rate_percent: Some(7.5),
+            discount_code: Some("LIB1".to_string()),
+            price_effective_from: chrono::NaiveDate::from_ymd_opt(2025, 1, 1),
+            price_effective_until: chrono::NaiveDate::from_ymd_opt(2025, 12, 31),
+        };
         test_work.subjects.clear();
         let output = generate_test_output(true, &test_work);
+        // Price details supplied
+        assert!(output.contains(r#"        <PriceType>42</PriceType>"#));
+        assert!(output.contains(r#"        <PriceQualifier>10</PriceQualifier>"#));
+        assert!(output.contains(r#"        <DiscountCoded>"#));
+        assert!(output.contains(r#"          <DiscountCodeType>02</DiscountCodeType>"#));
+        assert!(output
+            .contains(r#"          <DiscountCodeTypeName>OA Editions</DiscountCodeTypeName>"#));
+        assert!(output.contains(r#"          <DiscountCode>LIB1</DiscountCode>"#));
+        assert!(output.contains(r#"          <TaxType>01</TaxType>"#));
+        assert!(output.contains(r#"          <TaxRatePercent>7.5</TaxRatePercent>"#));
+        assert!(output.contains(r#"          <CountriesIncluded>US</CountriesIncluded>"#));
+        assert!(!output.contains(r#"          <RegionsIncluded>WORLD</RegionsIncluded>"#));
+        assert!(output.contains(r#"          <PriceDateRole>14</PriceDateRole>"#));
+        assert!(output.contains(r#"          <Date dateformat="00">20250101</Date>"#));
+        assert!(output.contains(r#"          <PriceDateRole>15</PriceDateRole>"#));
+        assert!(output.contains(r#"          <Date dateformat="00">20251231</Date>"#));
+        // Sales rights supplied: world rights except for North America
+        assert!(!output.contains(r#"      <SalesRightsType>02</SalesRightsType>"#));
+        assert!(output.contains(r#"      <SalesRightsType>01</SalesRightsType>"#));
+        assert!(output.contains(r#"        <RegionsIncluded>WORLD</RegionsIncluded>"#));
+        assert!(output.contains(r#"        <CountriesExcluded>US CA</CountriesExcluded>"#));
+        assert!(output.contains(
+            r#"
+        <CountriesExcluded>US CA</CountriesExcluded>
+      </Territory>
+      <SalesRestriction>
+        <SalesRestrictionType>04</SalesRestrictionType>
+        <SalesRestrictionNote>Exclusive to OverDrive</SalesRestrictionNote>
+      </SalesRestriction>
+    </SalesRights>"#
+        ));
         // Ebook type changed
         assert!(!output.contains(r#"    <ProductFormDetail>E107</ProductFormDetail>"#));
         assert!(output.contains(r#"    <ProductFormDetail>E101</ProductFormDetail>"#));
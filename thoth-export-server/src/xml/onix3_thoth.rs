@@ -1,14 +1,25 @@
-use cc_license::License;
 use chrono::Utc;
 use std::io::Write;
+use std::str::FromStr;
+use thoth_api::markup::to_plain_text;
+use thoth_api::model::license::{is_open_access, License};
 use thoth_client::{
-    ContributionType, LanguageRelation, LocationPlatform, PublicationType, RelationType,
-    SubjectType, Work, WorkContributions, WorkFundings, WorkIssues, WorkLanguages,
-    WorkPublicationsLocations, WorkReferences, WorkRelations, WorkStatus, WorkType,
+    AbstractType, ContributionType, LanguageRelation, LocationPlatform, PublicationType,
+    RelationType, SubjectType, Work, WorkContributions, WorkFundings, WorkIssues, WorkLanguages,
+    WorkPublicationsLocations, WorkReferences, WorkRelations, WorkStatus,
 };
 use xml::writer::{EventWriter, XmlEvent};
 
-use super::{write_element_block, XmlElement, XmlSpecification};
+use super::{
+    language_attr, write_element_block, write_onix3_accessibility, write_onix3_cited_contents,
+    write_onix3_collection_contributors, write_onix3_collection_title_detail,
+    write_onix3_digital_form_details, write_onix3_epub_usage, write_onix3_file_size,
+    write_onix3_pack_quantity, write_onix3_price, write_onix3_print_form_details,
+    write_onix3_prizes, write_onix3_product_form_description, write_onix3_sales_rights,
+    write_onix3_supporting_resources, write_onix3_text_contents, write_onix_alternative_names,
+    write_onix_funder_identifiers, write_onix_identifier, write_onix_name_identifiers,
+    write_rich_text_element, XmlElement, XmlSpecification,
+};
 use crate::xml::{write_full_element_block, XmlElementBlock, ONIX3_NS};
 use thoth_errors::{ThothError, ThothResult};
 
@@ -55,9 +66,9 @@ impl XmlSpecification for Onix3Thoth {
                 [work] => XmlElementBlock::<Onix3Thoth>::xml_element(work, w),
                 _ => {
                     for work in works.iter() {
-                        // Do not include Chapters in full publisher metadata record
+                        // Do not include Chapters or journal articles in full publisher metadata record
                         // (assumes that a publisher will always have more than one work)
-                        if work.work_type != WorkType::BOOK_CHAPTER {
+                        if !work.work_type.is_component() {
                             XmlElementBlock::<Onix3Thoth>::xml_element(work, w).ok();
                         }
                     }
@@ -78,7 +89,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
             ));
         }
         let work_id = format!("urn:uuid:{}", self.work_id);
-        let is_open_access = self.license.is_some();
+        let is_open_access = is_open_access(self.license.as_deref());
         let isbns: Vec<String> = self
             .publications
             .iter()
@@ -190,6 +201,15 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                         })
                     })?;
                 }
+                for identifier in &publication.identifiers {
+                    write_onix_identifier(
+                        "ProductIdentifier",
+                        "ProductIDType",
+                        &identifier.identifier_type,
+                        &identifier.identifier_value,
+                        w,
+                    )?;
+                }
                 write_element_block("DescriptiveDetail", w, |w| {
                     // 00 Single-component retail product
                     write_element_block("ProductComposition", w, |w| {
@@ -204,6 +224,10 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                             w.write(XmlEvent::Characters(code)).map_err(|e| e.into())
                         })?;
                     }
+                    write_onix3_print_form_details(publication, w)?;
+                    write_onix3_digital_form_details(publication, w)?;
+                    write_onix3_accessibility(publication, w)?;
+                    write_onix3_product_form_description(publication, w)?;
                     // 10 Text (eye-readable)
                     write_element_block("PrimaryContentType", w, |w| {
                         w.write(XmlEvent::Characters("10")).map_err(|e| e.into())
@@ -237,10 +261,13 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                             .ok();
                         }
                     }
-                    if let Some(license_url) = &self.license {
-                        let license_text = match License::from_url(license_url) {
-                            Ok(license) => license.to_string(),
-                            Err(_) => "Unspecified".to_string(),
+                    write_onix3_epub_usage(publication, w)?;
+                    if let Some(license) = &self.license {
+                        let (license_text, license_url) = match License::from_str(license) {
+                            Ok(license) if license.is_registered() => {
+                                (license.description(), license.url())
+                            }
+                            _ => ("Unspecified".to_string(), license.trim().to_string()),
                         };
                         write_element_block("EpubLicense", w, |w| {
                             write_element_block("EpubLicenseName", w, |w| {
@@ -252,7 +279,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                     w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
                                 })?;
                                 write_element_block("EpubLicenseExpressionLink", w, |w| {
-                                    w.write(XmlEvent::Characters(license_url))
+                                    w.write(XmlEvent::Characters(&license_url))
                                         .map_err(|e| e.into())
                                 })
                             })
@@ -261,29 +288,45 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                     for issue in &self.issues {
                         XmlElementBlock::<Onix3Thoth>::xml_element(issue, w).ok();
                     }
-                    write_element_block("TitleDetail", w, |w| {
-                        // 01 Distinctive title (book)
-                        write_element_block("TitleType", w, |w| {
-                            w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("TitleElement", w, |w| {
-                            // 01 Product
-                            write_element_block("TitleElementLevel", w, |w| {
-                                w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                            })?;
-                            write_element_block("TitleText", w, |w| {
-                                w.write(XmlEvent::Characters(&self.title))
+                    for title in self.localised_titles() {
+                        let language = language_attr(&title.language_code);
+                        let language_attr = language.as_deref().map(|l| vec![("language", l)]);
+                        write_element_block("TitleDetail", w, |w| {
+                            // 01 Distinctive title (book), 06 Title in other language
+                            let title_type = if title.canonical { "01" } else { "06" };
+                            write_element_block("TitleType", w, |w| {
+                                w.write(XmlEvent::Characters(title_type))
                                     .map_err(|e| e.into())
                             })?;
-                            if let Some(subtitle) = &self.subtitle {
-                                write_element_block("Subtitle", w, |w| {
-                                    w.write(XmlEvent::Characters(subtitle))
-                                        .map_err(|e| e.into())
+                            write_element_block("TitleElement", w, |w| {
+                                // 01 Product
+                                write_element_block("TitleElementLevel", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
                                 })?;
-                            }
-                            Ok(())
-                        })
-                    })?;
+                                write_full_element_block(
+                                    "TitleText",
+                                    language_attr.clone(),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(title.title))
+                                            .map_err(|e| e.into())
+                                    },
+                                )?;
+                                if let Some(subtitle) = title.subtitle {
+                                    write_full_element_block(
+                                        "Subtitle",
+                                        language_attr.clone(),
+                                        w,
+                                        |w| {
+                                            w.write(XmlEvent::Characters(subtitle))
+                                                .map_err(|e| e.into())
+                                        },
+                                    )?;
+                                }
+                                Ok(())
+                            })
+                        })?;
+                    }
                     for contribution in &self.contributions {
                         XmlElementBlock::<Onix3Thoth>::xml_element(contribution, w).ok();
                     }
@@ -301,7 +344,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                     for language in &self.languages {
                         XmlElementBlock::<Onix3Thoth>::xml_element(language, w).ok();
                     }
-                    if let Some(page_count) = self.page_count {
+                    if let Some(page_count) = publication.page_count.or(self.page_count) {
                         write_element_block("Extent", w, |w| {
                             // 00 Main content
                             write_element_block("ExtentType", w, |w| {
@@ -317,6 +360,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                             })
                         })?;
                     }
+                    write_onix3_file_size(publication, w)?;
                     if let Some(bibliography_note) = &self.bibliography_note {
                         // "This data element carries text stating the number and type of
                         // illustrations. The text may also include other content items,
@@ -423,20 +467,27 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                 })?;
                 if self.short_abstract.is_some()
                     || self.long_abstract.is_some()
+                    || !self.abstracts.is_empty()
                     || self.toc.is_some()
                     || self.general_note.is_some()
                     || self.cover_url.is_some()
+                    || !self.supporting_resources.is_empty()
                     || is_open_access
+                    || !self.text_contents.is_empty()
+                    || !self.prizes.is_empty()
                 {
                     write_element_block("CollateralDetail", w, |w| {
-                        if let Some(mut short_abstract) = self.short_abstract.clone() {
+                        for short_abstract in self.localised_abstracts(AbstractType::SHORT) {
                             // Short description field may not exceed 350 characters.
                             // Ensure that the string is truncated at a valid UTF-8 boundary
                             // by finding the byte index of the 350th character and then truncating
                             // the string at that index, to avoid creating invalid UTF-8 sequences.
-                            if let Some((byte_index, _)) = short_abstract.char_indices().nth(350) {
-                                short_abstract.truncate(byte_index);
+                            let plain_text = to_plain_text(short_abstract.content);
+                            let mut content = plain_text.as_str();
+                            if let Some((byte_index, _)) = content.char_indices().nth(350) {
+                                content = &content[..byte_index];
                             }
+                            let language = language_attr(&short_abstract.language_code);
                             write_element_block("TextContent", w, |w| {
                                 // 02 Short description
                                 write_element_block("TextType", w, |w| {
@@ -446,13 +497,18 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                 write_element_block("ContentAudience", w, |w| {
                                     w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                 })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(&short_abstract))
-                                        .map_err(|e| e.into())
-                                })
+                                write_full_element_block(
+                                    "Text",
+                                    language.as_deref().map(|l| vec![("language", l)]),
+                                    w,
+                                    |w| {
+                                        w.write(XmlEvent::Characters(content)).map_err(|e| e.into())
+                                    },
+                                )
                             })?;
                         }
-                        if let Some(long_abstract) = &self.long_abstract {
+                        for long_abstract in self.localised_abstracts(AbstractType::LONG) {
+                            let language = language_attr(&long_abstract.language_code);
                             // 03 Description, 30 Abstract
                             for text_type in ["03", "30"] {
                                 write_element_block("TextContent", w, |w| {
@@ -464,10 +520,14 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                     write_element_block("ContentAudience", w, |w| {
                                         w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                     })?;
-                                    write_element_block("Text", w, |w| {
-                                        w.write(XmlEvent::Characters(long_abstract))
-                                            .map_err(|e| e.into())
-                                    })
+                                    write_rich_text_element(
+                                        "Text",
+                                        language
+                                            .as_deref()
+                                            .map_or(vec![], |l| vec![("language", l)]),
+                                        long_abstract.content,
+                                        w,
+                                    )
                                 })?;
                             }
                         }
@@ -519,51 +579,13 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                 write_element_block("ContentAudience", w, |w| {
                                     w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
                                 })?;
-                                write_element_block("Text", w, |w| {
-                                    w.write(XmlEvent::Characters(general_note))
-                                        .map_err(|e| e.into())
-                                })
-                            })?;
-                        }
-                        if let Some(cover_url) = &self.cover_url {
-                            write_element_block("SupportingResource", w, |w| {
-                                // 01 Front cover
-                                write_element_block("ResourceContentType", w, |w| {
-                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                                })?;
-                                // 00 Unrestricted
-                                write_element_block("ContentAudience", w, |w| {
-                                    w.write(XmlEvent::Characters("00")).map_err(|e| e.into())
-                                })?;
-                                // 03 Image
-                                write_element_block("ResourceMode", w, |w| {
-                                    w.write(XmlEvent::Characters("03")).map_err(|e| e.into())
-                                })?;
-                                if let Some(cover_caption) = &self.cover_caption {
-                                    write_element_block("ResourceFeature", w, |w| {
-                                        // 02 Caption
-                                        write_element_block("ResourceFeatureType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("FeatureNote", w, |w| {
-                                            w.write(XmlEvent::Characters(cover_caption))
-                                                .map_err(|e| e.into())
-                                        })
-                                    })?;
-                                }
-                                write_element_block("ResourceVersion", w, |w| {
-                                    // 02 Downloadable file
-                                    write_element_block("ResourceForm", w, |w| {
-                                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                                    })?;
-                                    write_element_block("ResourceLink", w, |w| {
-                                        w.write(XmlEvent::Characters(cover_url))
-                                            .map_err(|e| e.into())
-                                    })
-                                })
+                                write_rich_text_element("Text", vec![], general_note, w)
                             })?;
                         }
+                        write_onix3_text_contents(&self.text_contents, w)?;
+                        write_onix3_cited_contents(&self.text_contents, w)?;
+                        write_onix3_supporting_resources(self, w)?;
+                        write_onix3_prizes(&self.prizes, w)?;
                         Ok(())
                     })?;
                 }
@@ -755,17 +777,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                             })
                         })?;
                     }
-                    write_element_block("SalesRights", w, |w| {
-                        // 02 For sale with non-exclusive rights in the specified countries or territories
-                        write_element_block("SalesRightsType", w, |w| {
-                            w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                        })?;
-                        write_element_block("Territory", w, |w| {
-                            write_element_block("RegionsIncluded", w, |w| {
-                                w.write(XmlEvent::Characters("WORLD")).map_err(|e| e.into())
-                            })
-                        })
-                    })
+                    write_onix3_sales_rights(&publication.sales_rights, w)
                 })?;
                 let non_child_relations: Vec<WorkRelations> = self
                     .relations
@@ -782,9 +794,28 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                 if (!isbns.is_empty() && !isbns.eq(&vec![current_isbn.clone().unwrap_or_default()]))
                     || !non_child_relations.is_empty()
                     || !self.references.is_empty()
+                    || !self.identifiers.is_empty()
                 {
                     write_element_block("RelatedMaterial", w, |w| {
                         // RelatedWorks should be listed before RelatedProducts
+                        if !self.identifiers.is_empty() {
+                            write_element_block("RelatedWork", w, |w| {
+                                // 01 Manifestation of
+                                write_element_block("WorkRelationCode", w, |w| {
+                                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
+                                })?;
+                                for identifier in &self.identifiers {
+                                    write_onix_identifier(
+                                        "WorkIdentifier",
+                                        "WorkIDType",
+                                        &identifier.identifier_type,
+                                        &identifier.identifier_value,
+                                        w,
+                                    )?;
+                                }
+                                Ok(())
+                            })?;
+                        }
                         for relation in &non_child_relations {
                             if relation.relation_type == RelationType::HAS_TRANSLATION
                                 || relation.relation_type == RelationType::IS_TRANSLATION_OF
@@ -957,6 +988,7 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                     )
                                 })?;
                             }
+                            write_onix3_pack_quantity(publication, w)?;
                             if publication.prices.is_empty() {
                                 // 01 Free of charge
                                 write_element_block("UnpricedItemType", w, |w| {
@@ -964,31 +996,11 @@ impl XmlElementBlock<Onix3Thoth> for Work {
                                 })
                             } else {
                                 for price in &publication.prices {
-                                    let unit_price = price.unit_price;
-                                    let formatted_price = format!("{unit_price:.2}");
-                                    write_element_block("Price", w, |w| {
-                                        // 02 RRP including tax
-                                        write_element_block("PriceType", w, |w| {
-                                            w.write(XmlEvent::Characters("02"))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("PriceAmount", w, |w| {
-                                            w.write(XmlEvent::Characters(&formatted_price))
-                                                .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("CurrencyCode", w, |w| {
-                                            w.write(XmlEvent::Characters(
-                                                &price.currency_code.to_string(),
-                                            ))
-                                            .map_err(|e| e.into())
-                                        })?;
-                                        write_element_block("Territory", w, |w| {
-                                            write_element_block("RegionsIncluded", w, |w| {
-                                                w.write(XmlEvent::Characters("WORLD"))
-                                                    .map_err(|e| e.into())
-                                            })
-                                        })
-                                    })?;
+                                    write_onix3_price(
+                                        price,
+                                        &self.imprint.publisher.publisher_name,
+                                        w,
+                                    )?;
                                 }
                                 Ok(())
                             }
@@ -1101,17 +1113,7 @@ impl XmlElementBlock<Onix3Thoth> for WorkContributions {
             })?;
             XmlElement::<Onix3Thoth>::xml_element(&self.contribution_type, w)?;
 
-            if let Some(orcid) = &self.contributor.orcid {
-                write_element_block("NameIdentifier", w, |w| {
-                    write_element_block("NameIDType", w, |w| {
-                        w.write(XmlEvent::Characters("21")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&orcid.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
+            write_onix_name_identifiers(&self.contributor, w)?;
             write_element_block("PersonName", w, |w| {
                 w.write(XmlEvent::Characters(&self.full_name))
                     .map_err(|e| e.into())
@@ -1126,6 +1128,7 @@ impl XmlElementBlock<Onix3Thoth> for WorkContributions {
                 w.write(XmlEvent::Characters(&self.last_name))
                     .map_err(|e| e.into())
             })?;
+            write_onix_alternative_names(&self.contributor, w)?;
             for affiliation in &self.affiliations {
                 write_element_block("ProfessionalAffiliation", w, |w| {
                     if let Some(position) = &affiliation.position {
@@ -1143,10 +1146,7 @@ impl XmlElementBlock<Onix3Thoth> for WorkContributions {
                 })?;
             }
             if let Some(biography) = &self.biography {
-                write_element_block("BiographicalNote", w, |w| {
-                    w.write(XmlEvent::Characters(biography))
-                        .map_err(|e| e.into())
-                })?;
+                write_rich_text_element("BiographicalNote", vec![], biography, w)?;
             }
             if let Some(website) = &self.contributor.website {
                 write_element_block("Website", w, |w| {
@@ -1258,26 +1258,8 @@ impl XmlElementBlock<Onix3Thoth> for WorkIssues {
                         .map_err(|e| e.into())
                 })
             })?;
-            write_element_block("TitleDetail", w, |w| {
-                // 01 Cover title (serial)
-                write_element_block("TitleType", w, |w| {
-                    w.write(XmlEvent::Characters("01")).map_err(|e| e.into())
-                })?;
-                write_element_block("TitleElement", w, |w| {
-                    // 02 Collection level
-                    write_element_block("TitleElementLevel", w, |w| {
-                        w.write(XmlEvent::Characters("02")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("PartNumber", w, |w| {
-                        w.write(XmlEvent::Characters(&self.issue_ordinal.to_string()))
-                            .map_err(|e| e.into())
-                    })?;
-                    write_element_block("TitleText", w, |w| {
-                        w.write(XmlEvent::Characters(&self.series.series_name))
-                            .map_err(|e| e.into())
-                    })
-                })
-            })
+            write_onix3_collection_title_detail(self, w)?;
+            write_onix3_collection_contributors(self, w)
         })
     }
 }
@@ -1289,30 +1271,7 @@ impl XmlElementBlock<Onix3Thoth> for WorkFundings {
             write_element_block("PublishingRole", w, |w| {
                 w.write(XmlEvent::Characters("16")).map_err(|e| e.into())
             })?;
-            if let Some(ror) = &self.institution.ror {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 40 ROR
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("40")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&ror.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
-            if let Some(doi) = &self.institution.institution_doi {
-                write_element_block("PublisherIdentifier", w, |w| {
-                    // 32 FundRef DOI
-                    write_element_block("PublisherIDType", w, |w| {
-                        w.write(XmlEvent::Characters("32")).map_err(|e| e.into())
-                    })?;
-                    write_element_block("IDValue", w, |w| {
-                        w.write(XmlEvent::Characters(&doi.to_string()))
-                            .map_err(|e| e.into())
-                    })
-                })?;
-            }
+            write_onix_funder_identifiers(&self.institution, w)?;
             write_element_block("PublisherName", w, |w| {
                 w.write(XmlEvent::Characters(&self.institution.institution_name))
                     .map_err(|e| e.into())
@@ -1487,17 +1446,27 @@ mod tests {
     // println!s throughout will only be printed if test fails - this assists debugging
     use super::*;
     use std::str::FromStr;
+    use thoth_api::model::identifier::{FunderId, Isni, Lcnaf, Ringgold, Viaf, WikidataId};
     use thoth_api::model::Doi;
     use thoth_api::model::Isbn;
     use thoth_api::model::Orcid;
     use thoth_api::model::Ror;
     use thoth_client::{
-        ContributionType, CurrencyCode, FundingInstitution, LanguageCode, LanguageRelation,
-        LocationPlatform, PublicationType, WorkContributionsAffiliations,
-        WorkContributionsAffiliationsInstitution, WorkContributionsContributor, WorkImprint,
-        WorkImprintPublisher, WorkIssuesSeries, WorkPublications, WorkPublicationsLocations,
-        WorkPublicationsPrices, WorkRelationsRelatedWork, WorkRelationsRelatedWorkImprint,
-        WorkRelationsRelatedWorkImprintPublisher, WorkStatus, WorkSubjects, WorkType,
+        AccessibilityFeature, AccessibilityHazard, AccessibilityStandard, BindingStyle,
+        ContentAudience, ContributionType, CountryCode, CoverFinish, CoverRendition, CurrencyCode,
+        DigitalLayout, FundingInstitution, IdentifierType, LanguageCode, LanguageRelation,
+        LocationPlatform, NameType, PaperType, PriceType, PrizeCode, PublicationType,
+        ResourceContentType, ResourceForm, ResourceMode, SeriesContributionType,
+        TechnicalProtection, TextContentType, TrimCategory, UsageStatus, UsageType, UsageUnit,
+        WcagLevel, WcagVersion, WorkAbstracts, WorkContributionsAffiliations,
+        WorkContributionsAffiliationsInstitution, WorkContributionsContributor,
+        WorkContributionsContributorAlternativeNames, WorkIdentifiers, WorkImprint,
+        WorkImprintPublisher, WorkIssuesSeries, WorkIssuesSeriesContributions,
+        WorkIssuesSeriesContributionsContributor, WorkIssuesSeriesParentSeries, WorkPrizes,
+        WorkPublications, WorkPublicationsIdentifiers, WorkPublicationsLocations,
+        WorkPublicationsPrices, WorkPublicationsUsageConstraints, WorkRelationsRelatedWork,
+        WorkRelationsRelatedWorkImprint, WorkRelationsRelatedWorkImprintPublisher, WorkStatus,
+        WorkSubjects, WorkSupportingResources, WorkTextContents, WorkTitles, WorkType,
     };
     use uuid::Uuid;
 
@@ -1535,6 +1504,18 @@ mod tests {
             contributor: WorkContributionsContributor {
                 orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
                 website: Some("https://contributor.site".to_string()),
+                isni: Some(Isni::from_str("0000000121032683").unwrap()),
+                viaf: Some(Viaf::from_str("44300643").unwrap()),
+                wikidata: Some(WikidataId::from_str("Q935").unwrap()),
+                lcnaf: Some(Lcnaf::from_str("n79021164").unwrap()),
+                alternative_names: vec![WorkContributionsContributorAlternativeNames {
+                    name_type: NameType::TRANSLITERATED,
+                    first_name: Some("Автор".to_string()),
+                    last_name: "1".to_string(),
+                    full_name: "Автор 1".to_string(),
+                    language_code: Some(LanguageCode::RUS),
+                    script_code: Some("Cyrl".to_string()),
+                }],
             },
             affiliations: vec![WorkContributionsAffiliations {
                 position: Some("Manager".to_string()),
@@ -1561,9 +1542,32 @@ mod tests {
     <NameIDType>21</NameIDType>
     <IDValue>0000-0002-0000-0001</IDValue>
   </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>16</NameIDType>
+    <IDValue>0000000121032683</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>31</NameIDType>
+    <IDValue>44300643</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>18</NameIDType>
+    <IDValue>n79021164</IDValue>
+  </NameIdentifier>
+  <NameIdentifier>
+    <NameIDType>01</NameIDType>
+    <IDTypeName>Wikidata</IDTypeName>
+    <IDValue>Q935</IDValue>
+  </NameIdentifier>
   <PersonName>Author N. 1</PersonName>
   <NamesBeforeKey>Author</NamesBeforeKey>
   <KeyNames>1</KeyNames>
+  <AlternativeName>
+    <NameType>05</NameType>
+    <PersonName language="rus" textscript="Cyrl">Автор 1</PersonName>
+    <NamesBeforeKey>Автор</NamesBeforeKey>
+    <KeyNames>1</KeyNames>
+  </AlternativeName>
   <ProfessionalAffiliation>
     <ProfessionalPosition>Manager</ProfessionalPosition>
     <Affiliation>University of Life</Affiliation>
@@ -1577,11 +1581,26 @@ mod tests {
 </Contributor>"#
         );
 
+        // Biographies containing markup are output as XHTML
+        test_contribution.biography =
+            Some("<p>Author N. 1 is a <em>made-up</em> author</p>".to_string());
+        let output = generate_test_output(true, &test_contribution);
+        assert!(output.contains(
+            r#"  <BiographicalNote textformat="05">
+    <p>Author N. 1 is a <em>made-up</em> author</p>
+  </BiographicalNote>"#
+        ));
+
         // Change all possible values to test that output is updated
         test_contribution.contribution_type = ContributionType::EDITOR;
         test_contribution.contribution_ordinal = 2;
         test_contribution.contributor.orcid = None;
         test_contribution.contributor.website = None;
+        test_contribution.contributor.isni = None;
+        test_contribution.contributor.viaf = None;
+        test_contribution.contributor.wikidata = None;
+        test_contribution.contributor.lcnaf = None;
+        test_contribution.contributor.alternative_names = vec![];
         test_contribution.first_name = None;
         test_contribution.biography = None;
         test_contribution.affiliations[0].position = None;
@@ -1701,6 +1720,9 @@ mod tests {
     fn test_onix3_thoth_issues() {
         let mut test_issue = WorkIssues {
             issue_ordinal: 1,
+            volume: None,
+            issue_number: None,
+            part: None,
             series: WorkIssuesSeries {
                 series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000002").unwrap(),
                 series_type: thoth_client::SeriesType::JOURNAL,
@@ -1710,6 +1732,8 @@ mod tests {
                 series_url: Some("https://series.url".to_string()),
                 series_description: None,
                 series_cfp_url: Some("https://series.cfp.url".to_string()),
+                parent_series: None,
+                contributions: vec![],
             },
         };
 
@@ -1805,6 +1829,83 @@ mod tests {
   </TitleDetail>
 </Collection>"#
         );
+
+        // Printed numbering replaces the part number but not the sequence number
+        test_issue.volume = Some("Vol. 3".to_string());
+        test_issue.issue_number = Some("2".to_string());
+        let output = generate_test_output(true, &test_issue);
+        assert!(output.contains(
+            r#"
+    <CollectionSequenceNumber>2</CollectionSequenceNumber>"#
+        ));
+        assert!(output.contains(
+            r#"
+      <PartNumber>Vol. 3, no. 2</PartNumber>"#
+        ));
+
+        // Subseries are given beneath their parent series, along with the series editors
+        test_issue.volume = None;
+        test_issue.issue_number = None;
+        test_issue.series.parent_series = Some(WorkIssuesSeriesParentSeries {
+            series_id: Uuid::parse_str("00000000-0000-0000-BBBB-000000000003").unwrap(),
+            series_name: "Parent series".to_string(),
+            issn_print: None,
+            issn_digital: None,
+        });
+        test_issue.series.contributions = vec![
+            WorkIssuesSeriesContributions {
+                contribution_type: SeriesContributionType::SERIES_EDITOR,
+                first_name: Some("Series".to_string()),
+                last_name: "Editor".to_string(),
+                full_name: "Series Editor".to_string(),
+                contribution_ordinal: 1,
+                contributor: WorkIssuesSeriesContributionsContributor {
+                    orcid: Some(Orcid::from_str("https://orcid.org/0000-0002-0000-0001").unwrap()),
+                },
+            },
+            WorkIssuesSeriesContributions {
+                contribution_type: SeriesContributionType::EDITORIAL_BOARD_MEMBER,
+                first_name: None,
+                last_name: "Board".to_string(),
+                full_name: "Board".to_string(),
+                contribution_ordinal: 2,
+                contributor: WorkIssuesSeriesContributionsContributor { orcid: None },
+            },
+        ];
+        let output = generate_test_output(true, &test_issue);
+        assert!(output.contains(
+            r#"
+  <TitleDetail>
+    <TitleType>01</TitleType>
+    <TitleElement>
+      <TitleElementLevel>02</TitleElementLevel>
+      <TitleText>Parent series</TitleText>
+    </TitleElement>
+    <TitleElement>
+      <TitleElementLevel>03</TitleElementLevel>
+      <PartNumber>2</PartNumber>
+      <TitleText>Different series</TitleText>
+    </TitleElement>
+  </TitleDetail>
+  <Contributor>
+    <SequenceNumber>1</SequenceNumber>
+    <ContributorRole>B09</ContributorRole>
+    <NameIdentifier>
+      <NameIDType>21</NameIDType>
+      <IDValue>0000-0002-0000-0001</IDValue>
+    </NameIdentifier>
+    <PersonName>Series Editor</PersonName>
+    <NamesBeforeKey>Series</NamesBeforeKey>
+    <KeyNames>Editor</KeyNames>
+  </Contributor>
+  <Contributor>
+    <SequenceNumber>2</SequenceNumber>
+    <ContributorRole>B14</ContributorRole>
+    <PersonName>Board</PersonName>
+    <KeyNames>Board</KeyNames>
+  </Contributor>
+</Collection>"#
+        ));
     }
 
     #[test]
@@ -1822,6 +1923,9 @@ mod tests {
                 ),
                 ror: Some(Ror::from_str("https://ror.org/0aaaaaa00").unwrap()),
                 country_code: None,
+                crossref_funder_id: None,
+                isni: None,
+                ringgold: None,
             },
         };
 
@@ -1987,6 +2091,23 @@ mod tests {
         assert!(!output.contains(r#"      <IDValue>Number of grant</IDValue>"#));
         assert!(!output.contains(r#"      <IDTypeName>jurisdiction</IDTypeName>"#));
         assert!(!output.contains(r#"      <IDValue>Republic of Moldova</IDValue>"#));
+
+        // A Crossref Funder ID takes precedence over the institution DOI
+        test_funding.institution.institution_doi =
+            Some(Doi::from_str("https://doi.org/10.00001/INSTITUTION.0001").unwrap());
+        test_funding.institution.crossref_funder_id =
+            Some(FunderId::from_str("501100000780").unwrap());
+        test_funding.institution.isni = Some(Isni::from_str("000000012146438X").unwrap());
+        test_funding.institution.ringgold = Some(Ringgold::from_str("6396").unwrap());
+        let output = generate_test_output(true, &test_funding);
+        assert!(output.contains(r#"    <PublisherIDType>32</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDValue>10.13039/501100000780</IDValue>"#));
+        assert!(!output.contains(r#"    <IDValue>10.00001/INSTITUTION.0001</IDValue>"#));
+        assert!(output.contains(r#"    <PublisherIDType>16</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDValue>000000012146438X</IDValue>"#));
+        assert!(output.contains(r#"    <PublisherIDType>01</PublisherIDType>"#));
+        assert!(output.contains(r#"    <IDTypeName>Ringgold</IDTypeName>"#));
+        assert!(output.contains(r#"    <IDValue>6396</IDValue>"#));
     }
 
     #[test]
@@ -2065,12 +2186,15 @@ mod tests {
                 copyright_holder: None,
                 short_abstract: None,
                 long_abstract: None,
+                titles: vec![],
+                abstracts: vec![],
                 general_note: None,
                 place: None,
                 first_page: None,
                 last_page: None,
                 page_count: None,
                 page_interval: None,
+                article_number: None,
                 landing_page: None,
                 imprint: WorkRelationsRelatedWorkImprint {
                     crossmark_doi: None,
@@ -2154,6 +2278,8 @@ mod tests {
             long_abstract: Some(
                 "Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string(),
             ),
+            titles: vec![],
+            abstracts: vec![],
             general_note: Some("This is a general note".to_string()),
             bibliography_note: Some("This is a bibliography note".to_string()),
             place: Some("León, Spain".to_string()),
@@ -2162,6 +2288,7 @@ mod tests {
             first_page: None,
             last_page: None,
             page_interval: None,
+            article_number: None,
             image_count: Some(15),
             table_count: Some(20),
             audio_count: Some(25),
@@ -2204,14 +2331,44 @@ mod tests {
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::EUR,
                         unit_price: 5.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::GBP,
                         unit_price: 4.95,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                     WorkPublicationsPrices {
                         currency_code: CurrencyCode::USD,
                         unit_price: 8.0,
+                        price_type: PriceType::RRP_INCLUDING_TAX,
+                        price_qualifier: None,
+                        countries_included: vec![],
+                        countries_excluded: vec![],
+                        regions_included: vec![],
+                        regions_excluded: vec![],
+                        tax_rate_percent: None,
+                        discount_code: None,
+                        price_effective_from: None,
+                        price_effective_until: None,
                     },
                 ],
                 locations: vec![
@@ -2229,6 +2386,27 @@ mod tests {
                         canonical: false,
                     },
                 ],
+                identifiers: vec![],
+                sales_rights: vec![],
+                accessibility_features: vec![],
+                accessibility_hazards: vec![],
+                accessibility_standard: None,
+                wcag_version: None,
+                wcag_level: None,
+                accessibility_certifier: None,
+                accessibility_summary: None,
+                binding_style: None,
+                paper_type: None,
+                interior_colour: None,
+                trim_category: None,
+                page_count: None,
+                cover_finish: None,
+                carton_quantity: None,
+                format_version: None,
+                file_size_kb: None,
+                technical_protections: vec![],
+                layout: None,
+                usage_constraints: vec![],
             }],
             subjects: vec![
                 WorkSubjects {
@@ -2268,6 +2446,47 @@ mod tests {
                 },
             ],
             fundings: vec![],
+            prizes: vec![WorkPrizes {
+                prize_name: "Name of prize".to_string(),
+                prize_year: Some(2021),
+                prize_country: Some(CountryCode::GBR),
+                prize_code: PrizeCode::SHORT_LISTED,
+                prize_statement: Some("Statement from the jury".to_string()),
+                prize_jury: Some("Members of the jury".to_string()),
+            }],
+            events: vec![],
+            text_contents: vec![
+                WorkTextContents {
+                    text_type: TextContentType::REVIEW_QUOTE,
+                    content_audience: ContentAudience::UNRESTRICTED,
+                    content: "A landmark study".to_string(),
+                    text_author: Some("Name of reviewer".to_string()),
+                    source_title: Some("Review Journal".to_string()),
+                    source_url: Some("https://www.reviews.com/review".to_string()),
+                    content_date: chrono::NaiveDate::from_ymd_opt(2022, 1, 15),
+                },
+                WorkTextContents {
+                    text_type: TextContentType::ENDORSEMENT,
+                    content_audience: ContentAudience::END_CUSTOMERS,
+                    content: "Essential reading".to_string(),
+                    text_author: Some("Name of endorser".to_string()),
+                    source_title: None,
+                    source_url: None,
+                    content_date: None,
+                },
+            ],
+            supporting_resources: vec![WorkSupportingResources {
+                resource_content_type: ResourceContentType::BACK_COVER,
+                content_audience: ContentAudience::UNRESTRICTED,
+                resource_mode: ResourceMode::IMAGE,
+                resource_form: ResourceForm::DOWNLOADABLE_FILE,
+                resource_url: "https://www.book.com/back-cover".to_string(),
+                caption: None,
+                credit: Some("Cover design by Name of designer".to_string()),
+                width_px: Some(1600),
+                height_px: Some(2400),
+                cover_rendition: None,
+            }],
             relations: vec![
                 WorkRelations {
                     relation_type: RelationType::HAS_CHILD,
@@ -2285,12 +2504,15 @@ mod tests {
                         copyright_holder: None,
                         short_abstract: None,
                         long_abstract: None,
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: None,
                         place: None,
                         first_page: Some("10".to_string()),
                         last_page: Some("20".to_string()),
                         page_count: Some(11),
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2321,12 +2543,15 @@ mod tests {
                         copyright_holder: None,
                         short_abstract: None,
                         long_abstract: None,
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: None,
                         place: None,
                         first_page: None,
                         last_page: None,
                         page_count: None,
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2357,12 +2582,15 @@ mod tests {
                         copyright_holder: None,
                         short_abstract: None,
                         long_abstract: None,
+                        titles: vec![],
+                        abstracts: vec![],
                         general_note: None,
                         place: None,
                         first_page: None,
                         last_page: None,
                         page_count: None,
                         page_interval: None,
+                        article_number: None,
                         landing_page: None,
                         imprint: WorkRelationsRelatedWorkImprint {
                             crossmark_doi: None,
@@ -2400,6 +2628,7 @@ mod tests {
                 publication_date: None,
                 retrieval_date: None,
             }],
+            identifiers: vec![],
         };
 
         // Test standard output
@@ -2721,7 +2950,34 @@ mod tests {
       <TextType>13</TextType>
       <ContentAudience>00</ContentAudience>
       <Text>This is a general note</Text>
-    </TextContent>"#
+    </TextContent>
+    <TextContent>
+      <TextType>06</TextType>
+      <ContentAudience>00</ContentAudience>
+      <Text>A landmark study</Text>
+      <TextAuthor>Name of reviewer</TextAuthor>
+      <SourceTitle>Review Journal</SourceTitle>
+      <ContentDate>
+        <ContentDateRole>01</ContentDateRole>
+        <Date dateformat="00">20220115</Date>
+      </ContentDate>
+    </TextContent>
+    <TextContent>
+      <TextType>09</TextType>
+      <ContentAudience>03</ContentAudience>
+      <Text>Essential reading</Text>
+      <TextAuthor>Name of endorser</TextAuthor>
+    </TextContent>
+    <CitedContent>
+      <CitedContentType>01</CitedContentType>
+      <ContentAudience>00</ContentAudience>
+      <SourceTitle>Review Journal</SourceTitle>
+      <ResourceLink>https://www.reviews.com/review</ResourceLink>
+      <ContentDate>
+        <ContentDateRole>01</ContentDateRole>
+        <Date dateformat="00">20220115</Date>
+      </ContentDate>
+    </CitedContent>"#
         ));
         assert!(output.contains(
             r#"
@@ -2738,6 +2994,35 @@ mod tests {
         <ResourceLink>https://www.book.com/cover</ResourceLink>
       </ResourceVersion>
     </SupportingResource>
+    <SupportingResource>
+      <ResourceContentType>02</ResourceContentType>
+      <ContentAudience>00</ContentAudience>
+      <ResourceMode>03</ResourceMode>
+      <ResourceFeature>
+        <ResourceFeatureType>01</ResourceFeatureType>
+        <FeatureNote>Cover design by Name of designer</FeatureNote>
+      </ResourceFeature>
+      <ResourceVersion>
+        <ResourceForm>02</ResourceForm>
+        <ResourceVersionFeature>
+          <ResourceVersionFeatureType>02</ResourceVersionFeatureType>
+          <FeatureValue>2400</FeatureValue>
+        </ResourceVersionFeature>
+        <ResourceVersionFeature>
+          <ResourceVersionFeatureType>03</ResourceVersionFeatureType>
+          <FeatureValue>1600</FeatureValue>
+        </ResourceVersionFeature>
+        <ResourceLink>https://www.book.com/back-cover</ResourceLink>
+      </ResourceVersion>
+    </SupportingResource>
+    <Prize>
+      <PrizeName>Name of prize</PrizeName>
+      <PrizeYear>2021</PrizeYear>
+      <PrizeCountry>GB</PrizeCountry>
+      <PrizeCode>04</PrizeCode>
+      <PrizeStatement>Statement from the jury</PrizeStatement>
+      <PrizeJury>Members of the jury</PrizeJury>
+    </Prize>
   </CollateralDetail>
   <ContentDetail>
     <ContentItem>
@@ -2931,12 +3216,101 @@ mod tests {
     </PublishingDate>"#
         ));
 
+        // Add work and publication identifiers
+        test_work.identifiers = vec![
+            WorkIdentifiers {
+                identifier_type: IdentifierType::ISTC,
+                identifier_value: "0A9-2002-12B4A105-7".to_string(),
+            },
+            WorkIdentifiers {
+                identifier_type: IdentifierType::WIKIDATA,
+                identifier_value: "Q42".to_string(),
+            },
+        ];
+        test_work.publications[0].identifiers = vec![WorkPublicationsIdentifiers {
+            identifier_type: IdentifierType::GTIN,
+            identifier_value: "4006381333931".to_string(),
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+  <ProductIdentifier>
+    <ProductIDType>03</ProductIDType>
+    <IDValue>4006381333931</IDValue>
+  </ProductIdentifier>"#
+        ));
+        assert!(output.contains(
+            r#"
+    <RelatedWork>
+      <WorkRelationCode>01</WorkRelationCode>
+      <WorkIdentifier>
+        <WorkIDType>11</WorkIDType>
+        <IDValue>0A9-2002-12B4A105-7</IDValue>
+      </WorkIdentifier>
+      <WorkIdentifier>
+        <WorkIDType>01</WorkIDType>
+        <IDTypeName>Wikidata</IDTypeName>
+        <IDValue>Q42</IDValue>
+      </WorkIdentifier>
+    </RelatedWork>"#
+        ));
+        test_work.identifiers.clear();
+        test_work.publications[0].identifiers.clear();
+
         // Test ProductForm[Detail] with different publication types
         test_work.publications[0].publication_type = PublicationType::HARDBACK;
         let output = generate_test_output(true, &test_work);
         assert!(!output.contains(r#"    <ProductForm>BC</ProductForm>"#));
         assert!(output.contains(r#"    <ProductForm>BB</ProductForm>"#));
         assert!(!output.contains(r#"    <ProductFormDetail>"#));
+        // Test print production attributes of a physical publication
+        test_work.publications[0].binding_style = Some(BindingStyle::SECTION_SEWN);
+        test_work.publications[0].trim_category = Some(TrimCategory::UK_TRADE);
+        test_work.publications[0].cover_finish = Some(CoverFinish::CLOTH);
+        test_work.publications[0].paper_type = Some(PaperType::CREAM);
+        test_work.publications[0].page_count = Some(352);
+        test_work.publications[0].carton_quantity = Some(24);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <ProductForm>BB</ProductForm>
+    <ProductFormDetail>B304</ProductFormDetail>
+    <ProductFormDetail>B106</ProductFormDetail>
+    <ProductFormDetail>B401</ProductFormDetail>
+    <PrimaryContentType>10</PrimaryContentType>"#
+        ));
+        assert!(output.contains(
+            r#"
+    <Extent>
+      <ExtentType>00</ExtentType>
+      <ExtentValue>352</ExtentValue>
+      <ExtentUnit>03</ExtentUnit>
+    </Extent>"#
+        ));
+        assert!(output.contains(
+            r#"
+      </SupplyDate>
+      <PackQuantity>24</PackQuantity>"#
+        ));
+        // Laminated covers and custom trim sizes have no ProductFormDetail code
+        test_work.publications[0].trim_category = Some(TrimCategory::CUSTOM);
+        test_work.publications[0].cover_finish = Some(CoverFinish::GLOSS_LAMINATE);
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <ProductForm>BB</ProductForm>
+    <ProductFormDetail>B304</ProductFormDetail>
+    <PrimaryContentType>10</PrimaryContentType>"#
+        ));
+        test_work.publications[0].binding_style = None;
+        test_work.publications[0].trim_category = None;
+        test_work.publications[0].cover_finish = None;
+        test_work.publications[0].paper_type = None;
+        test_work.publications[0].page_count = None;
+        test_work.publications[0].carton_quantity = None;
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(r#"      <ExtentValue>334</ExtentValue>"#));
+        assert!(!output.contains(r#"      <PackQuantity>"#));
         test_work.publications[0].publication_type = PublicationType::PDF;
         let output = generate_test_output(true, &test_work);
         assert!(output.contains(
@@ -2965,6 +3339,124 @@ mod tests {
     <ProductForm>EB</ProductForm>
     <ProductFormDetail>E101</ProductFormDetail>"#
         ));
+        // Test accessibility details of a digital publication
+        test_work.publications[0].accessibility_summary = Some("Fully accessible".to_string());
+        test_work.publications[0].accessibility_standard = Some(AccessibilityStandard::EPUB_1_1);
+        test_work.publications[0].wcag_version = Some(WcagVersion::WCAG_2_1);
+        test_work.publications[0].wcag_level = Some(WcagLevel::AA);
+        test_work.publications[0].accessibility_features =
+            vec![AccessibilityFeature::TABLE_OF_CONTENTS];
+        test_work.publications[0].accessibility_certifier = Some("Certifier".to_string());
+        test_work.publications[0].accessibility_hazards = vec![AccessibilityHazard::NONE];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <ProductFormDetail>E101</ProductFormDetail>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>00</ProductFormFeatureValue>
+      <ProductFormFeatureDescription>Fully accessible</ProductFormFeatureDescription>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>04</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>81</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>85</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>11</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>09</ProductFormFeatureType>
+      <ProductFormFeatureValue>90</ProductFormFeatureValue>
+      <ProductFormFeatureDescription>Certifier</ProductFormFeatureDescription>
+    </ProductFormFeature>
+    <ProductFormFeature>
+      <ProductFormFeatureType>12</ProductFormFeatureType>
+      <ProductFormFeatureValue>00</ProductFormFeatureValue>
+    </ProductFormFeature>
+    <PrimaryContentType>10</PrimaryContentType>"#
+        ));
+        test_work.publications[0].accessibility_summary = None;
+        test_work.publications[0].accessibility_standard = None;
+        test_work.publications[0].wcag_version = None;
+        test_work.publications[0].wcag_level = None;
+        test_work.publications[0].accessibility_features.clear();
+        test_work.publications[0].accessibility_certifier = None;
+        test_work.publications[0].accessibility_hazards.clear();
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"    <ProductFormFeature>"#));
+        // Test technical attributes and usage constraints of a digital publication
+        test_work.publications[0].format_version = Some("3.0".to_string());
+        test_work.publications[0].layout = Some(DigitalLayout::REFLOWABLE);
+        test_work.publications[0].file_size_kb = Some(2458);
+        test_work.publications[0].technical_protections =
+            vec![TechnicalProtection::DIGITAL_WATERMARKING];
+        test_work.publications[0].usage_constraints = vec![
+            WorkPublicationsUsageConstraints {
+                usage_type: UsageType::PRINT,
+                usage_status: UsageStatus::PERMITTED_SUBJECT_TO_LIMIT,
+                usage_limit: Some(10),
+                usage_unit: Some(UsageUnit::PERCENTAGE),
+            },
+            WorkPublicationsUsageConstraints {
+                usage_type: UsageType::LEND,
+                usage_status: UsageStatus::PROHIBITED,
+                usage_limit: None,
+                usage_unit: None,
+            },
+        ];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <ProductFormDetail>E101</ProductFormDetail>
+    <ProductFormDetail>E200</ProductFormDetail>
+    <ProductFormDescription>EPUB 3.0, reflowable, 2.4 MB</ProductFormDescription>
+    <PrimaryContentType>10</PrimaryContentType>"#
+        ));
+        assert!(output.contains(
+            r#"
+    </Measure>
+    <EpubTechnicalProtection>02</EpubTechnicalProtection>
+    <EpubUsageConstraint>
+      <EpubUsageType>02</EpubUsageType>
+      <EpubUsageStatus>02</EpubUsageStatus>
+      <EpubUsageLimit>
+        <Quantity>10</Quantity>
+        <EpubUsageUnit>05</EpubUsageUnit>
+      </EpubUsageLimit>
+    </EpubUsageConstraint>
+    <EpubUsageConstraint>
+      <EpubUsageType>06</EpubUsageType>
+      <EpubUsageStatus>03</EpubUsageStatus>
+    </EpubUsageConstraint>
+    <EpubLicense>"#
+        ));
+        assert!(output.contains(
+            r#"
+    <Extent>
+      <ExtentType>22</ExtentType>
+      <ExtentValue>2458</ExtentValue>
+      <ExtentUnit>18</ExtentUnit>
+    </Extent>"#
+        ));
+        test_work.publications[0].format_version = None;
+        test_work.publications[0].layout = None;
+        test_work.publications[0].file_size_kb = None;
+        test_work.publications[0].technical_protections.clear();
+        test_work.publications[0].usage_constraints.clear();
+        let output = generate_test_output(true, &test_work);
+        assert!(!output.contains(r#"    <ProductFormDescription>"#));
+        assert!(!output.contains(r#"    <EpubTechnicalProtection>"#));
+        assert!(!output.contains(r#"    <EpubUsageConstraint>"#));
+        assert!(!output.contains(r#"      <ExtentType>22</ExtentType>"#));
         test_work.publications[0].publication_type = PublicationType::MOBI;
         let output = generate_test_output(true, &test_work);
         assert!(output.contains(
@@ -3295,6 +3787,24 @@ mod tests {
       </Price>"#
         ));
 
+        // A front cover recorded as a supporting resource replaces the legacy work cover
+        test_work.supporting_resources[0].resource_content_type = ResourceContentType::FRONT_COVER;
+        let output = generate_test_output(true, &test_work);
+        assert!(output
+            .contains(r#"        <ResourceLink>https://www.book.com/back-cover</ResourceLink>"#));
+        assert!(
+            !output.contains(r#"        <ResourceLink>https://www.book.com/cover</ResourceLink>"#)
+        );
+
+        // Renditions generated from an uploaded cover are left out
+        test_work.supporting_resources[0].cover_rendition = Some(CoverRendition::AMAZON);
+        let output = generate_test_output(true, &test_work);
+        assert!(!output
+            .contains(r#"        <ResourceLink>https://www.book.com/back-cover</ResourceLink>"#));
+        assert!(
+            output.contains(r#"        <ResourceLink>https://www.book.com/cover</ResourceLink>"#)
+        );
+
         // Test truncation of short abstract
         test_work.short_abstract = Some("Lorem ipsum dolor sit amet, consectetur adipiscing elit. Vestibulum vel libero eleifend, ultrices purus vitae, suscipit ligula. Aliquam ornare quam et nulla vestibulum, id euismod tellus malesuada. Orci varius natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Nullam ornare bibendum ex nec dapibus. Proin porta risus elementum odio feugiat tempus. Etiam eu felis ac metus viverra ornare. In consectetur neque sed feugiat ornare. Mauris at purus fringilla orci tincidunt pulvinar sed a massa. Nullam vestibulum posuere augue, sit amet tincidunt nisl pulvinar ac.".to_string());
         // Remove even more values
@@ -3303,6 +3813,9 @@ mod tests {
         test_work.audio_count = None;
         test_work.video_count = None;
         test_work.cover_url = None;
+        test_work.prizes.clear();
+        test_work.text_contents.clear();
+        test_work.supporting_resources.clear();
         test_work.relations[0].related_work.first_page = None;
         // If first page is missing, last page isn't included even if present
         test_work.relations[0].related_work.last_page = Some("20".to_string());
@@ -3344,6 +3857,8 @@ mod tests {
   </CollateralDetail>"#
         ));
         assert!(!output.contains(r#"    <SupportingResource>"#));
+        assert!(!output.contains(r#"    <Prize>"#));
+        assert!(!output.contains(r#"    <CitedContent>"#));
         assert!(!output.contains(r#"    <PageRun>"#));
         assert!(!output.contains(r#"      <FirstPageNumber>10</FirstPageNumber>"#));
         assert!(!output.contains(r#"      <LastPageNumber>20</LastPageNumber>"#));
@@ -3428,6 +3943,27 @@ mod tests {
             weight_oz: None,
             prices: vec![],
             locations: vec![],
+            identifiers: vec![],
+            sales_rights: vec![],
+            accessibility_features: vec![],
+            accessibility_hazards: vec![],
+            accessibility_standard: None,
+            wcag_version: None,
+            wcag_level: None,
+            accessibility_certifier: None,
+            accessibility_summary: None,
+            binding_style: None,
+            paper_type: None,
+            interior_colour: None,
+            trim_category: None,
+            page_count: None,
+            cover_finish: None,
+            carton_quantity: None,
+            format_version: None,
+            file_size_kb: None,
+            technical_protections: vec![],
+            layout: None,
+            usage_constraints: vec![],
         });
         let output = generate_test_output(true, &test_work);
         println!("{output}");
@@ -3464,6 +4000,58 @@ mod tests {
   <ProductSupply>"#
         ));
 
+        // Titles and abstracts recorded in specific languages replace the work's own fields
+        test_work.titles = vec![
+            WorkTitles {
+                language_code: LanguageCode::ENG,
+                full_title: "Book Title: Book Subtitle".to_string(),
+                title: "Book Title".to_string(),
+                subtitle: Some("Book Subtitle".to_string()),
+                canonical: false,
+            },
+            WorkTitles {
+                language_code: LanguageCode::FRE,
+                full_title: "Titre".to_string(),
+                title: "Titre".to_string(),
+                subtitle: None,
+                canonical: true,
+            },
+        ];
+        test_work.abstracts = vec![WorkAbstracts {
+            language_code: LanguageCode::FRE,
+            abstract_type: AbstractType::LONG,
+            content: "Résumé".to_string(),
+            canonical: true,
+        }];
+        let output = generate_test_output(true, &test_work);
+        assert!(output.contains(
+            r#"
+    <TitleDetail>
+      <TitleType>01</TitleType>
+      <TitleElement>
+        <TitleElementLevel>01</TitleElementLevel>
+        <TitleText language="fre">Titre</TitleText>
+      </TitleElement>
+    </TitleDetail>
+    <TitleDetail>
+      <TitleType>06</TitleType>
+      <TitleElement>
+        <TitleElementLevel>01</TitleElementLevel>
+        <TitleText language="eng">Book Title</TitleText>
+        <Subtitle language="eng">Book Subtitle</Subtitle>
+      </TitleElement>
+    </TitleDetail>"#
+        ));
+        assert!(output.contains(
+            r#"
+    <TextContent>
+      <TextType>30</TextType>
+      <ContentAudience>00</ContentAudience>
+      <Text language="fre">Résumé</Text>
+    </TextContent>"#
+        ));
+        assert!(!output.contains("<TextType>02</TextType>"));
+
         // Remove all publications and test that result is error
         test_work.publications.clear();
         let output = generate_test_output(false, &test_work);